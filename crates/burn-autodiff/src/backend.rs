@@ -7,6 +7,7 @@ use alloc::{format, string::String};
 use core::marker::PhantomData;
 
 use burn_backend::{
+    MemoryQuota, MemoryQuotaError,
     backend::{AutodiffBackend, Backend, BackendTypes, ExecutionError},
     tensor::{BoolTensor, IntTensor, QuantizedTensor},
 };
@@ -68,6 +69,14 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
         B::memory_persistent_allocations(device, input, func)
     }
 
+    fn memory_quota<Output, Func: FnOnce() -> Output>(
+        device: &Self::Device,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        B::memory_quota(device, quota, func)
+    }
+
     fn memory_cleanup(device: &Self::Device) {
         B::memory_cleanup(device)
     }
@@ -90,6 +99,10 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
     fn device_count(type_id: u16) -> usize {
         B::device_count(type_id)
     }
+
+    fn device_properties(device: &Self::Device) -> burn_backend::DeviceProperties {
+        B::device_properties(device)
+    }
 }
 
 #[cfg(not(feature = "distributed"))]
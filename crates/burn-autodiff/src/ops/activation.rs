@@ -164,4 +164,40 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
             OpsKind::UnTracked(prep) => prep.finish(B::log_sigmoid(tensor.primitive)),
         }
     }
+
+    fn logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct LogSumExp;
+
+        impl<B: Backend> Backward<B, 1> for LogSumExp {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output) = ops.state;
+
+                // The gradient is the softmax of the input along the reduced dimension.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let softmax = B::float_exp(B::float_sub(input, output));
+                    B::float_mul(grad, softmax)
+                });
+            }
+        }
+
+        match LogSumExp
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::logsumexp(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::logsumexp(tensor.primitive, dim)),
+        }
+    }
 }
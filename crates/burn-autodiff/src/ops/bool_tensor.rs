@@ -97,6 +97,10 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_flip(tensor, axes)
     }
 
+    fn bool_roll(tensor: BoolTensor<B>, shifts: &[usize], dims: &[usize]) -> BoolTensor<B> {
+        B::bool_roll(tensor, shifts, dims)
+    }
+
     async fn bool_argwhere(tensor: BoolTensor<B>, out_dtype: burn_std::IntDType) -> IntTensor<B> {
         B::bool_argwhere(tensor, out_dtype).await
     }
@@ -320,6 +320,10 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_flip(tensor, axes)
     }
 
+    fn int_roll(tensor: IntTensor<Self>, shifts: &[usize], dims: &[usize]) -> IntTensor<Self> {
+        B::int_roll(tensor, shifts, dims)
+    }
+
     fn int_sign(tensor: IntTensor<Self>) -> IntTensor<Self> {
         B::int_sign(tensor)
     }
@@ -898,6 +898,75 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_roll(
+        tensor: FloatTensor<Self>,
+        shifts: &[usize],
+        dims: &[usize],
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct RollDims;
+
+        #[derive(new, Debug)]
+        struct RetroRollDims<B: Backend> {
+            input_id: NodeId,
+            shifts: Vec<usize>,
+            dims: Vec<usize>,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroRollDims<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeId) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_roll(input, &self.shifts, &self.dims);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for RollDims {
+            type State = (Vec<usize>, Vec<usize>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shifts, dims) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_roll(grad, &shifts, &dims)
+                });
+            }
+        }
+
+        match RollDims
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroRollDims::<B>::new(
+                tensor.node.id,
+                shifts.to_vec(),
+                dims.to_vec(),
+            ))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                // Rolling the gradient back by the complementary shifts undoes the roll.
+                let shape = tensor.primitive.shape();
+                let inverse_shifts = shifts
+                    .iter()
+                    .zip(dims)
+                    .map(|(&shift, &dim)| shape[dim] - shift)
+                    .collect();
+                prep.finish(
+                    (inverse_shifts, dims.to_vec()),
+                    B::float_roll(tensor.primitive, shifts, dims),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_roll(tensor.primitive, shifts, dims)),
+        }
+    }
+
     fn float_reshape(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct ReshapeDim;
@@ -1174,15 +1243,19 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 }
             }
             IndexingUpdateOp::Mul => {
-                // Forward: out[idx[i]] = data[idx[i]] * values[i] (unique indices assumed).
+                // Forward: out[p] = data[p] * prod(values[i] for idx[i] == p), so duplicate
+                // indices multiply every value into the same position.
                 // Backward:
-                //   grad_data   = grad * scatter_nd(ones_like(data), idx, values, Assign)
-                //   grad_values = gather_nd(grad, idx) * gather_nd(data, idx)
+                //   grad_data   = grad * scatter_nd(ones_like(data), idx, values, Mul)
+                //   grad_values = gather_nd(grad, idx) * (product of the other factors of idx[i])
+                // The product of the other factors isn't computed as out / values[i], which would
+                // divide by zero: the zeros are counted separately from the product of the
+                // non-zero factors, and the product is zero when a factor other than values[i] is.
                 #[derive(Debug)]
                 struct ScatterNdMul;
 
                 impl<B: Backend> Backward<B, 2> for ScatterNdMul {
-                    type State = (Option<FloatTensor<B>>, Option<FloatTensor<B>>, IntTensor<B>);
+                    type State = (Option<FloatTensor<B>>, FloatTensor<B>, IntTensor<B>);
 
                     fn backward(
                         self,
@@ -1190,8 +1263,9 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                         grads: &mut Gradients,
                         _checkpointer: &mut Checkpointer,
                     ) {
-                        let (data_state, values_state, indices) = ops.state;
+                        let (data_state, values, indices) = ops.state;
                         let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                        let [values_4lhs, values_4rhs] = duplicate(&ops.parents, Some(values));
 
                         binary::<B, _, _>(
                             ops.parents,
@@ -1205,22 +1279,57 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                                 let mult = B::float_scatter_nd(
                                     ones,
                                     indices_4lhs.unwrap(),
-                                    values_state.unwrap(),
-                                    IndexingUpdateOp::Assign,
+                                    values_4lhs.unwrap(),
+                                    IndexingUpdateOp::Mul,
                                 );
                                 B::float_mul(grad, mult)
                             },
                             |grad| {
                                 let indices = indices_4rhs.unwrap();
-                                let g_idx = B::float_gather_nd(grad, indices.clone());
-                                let d_idx = B::float_gather_nd(data_state.unwrap(), indices);
-                                B::float_mul(g_idx, d_idx)
+                                let values = values_4rhs.unwrap();
+                                let data = data_state.unwrap();
+                                let device = B::float_device(&data);
+                                let dtype = data.dtype();
+                                let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+
+                                let data_zero =
+                                    B::float_equal_elem(data.clone(), 0f32.into(), bool_dtype);
+                                let values_zero =
+                                    B::float_equal_elem(values.clone(), 0f32.into(), bool_dtype);
+                                let values_zero_float =
+                                    B::bool_into_float(values_zero.clone(), dtype.into());
+                                let values_nonzero =
+                                    B::float_mask_fill(values, values_zero, 1f32.into());
+
+                                // The product of the non-zero factors of each position.
+                                let product = B::float_scatter_nd(
+                                    B::float_mask_fill(data, data_zero.clone(), 1f32.into()),
+                                    indices.clone(),
+                                    values_nonzero.clone(),
+                                    IndexingUpdateOp::Mul,
+                                );
+                                // The number of zero factors of each position.
+                                let num_zeros = B::float_scatter_nd(
+                                    B::bool_into_float(data_zero, dtype.into()),
+                                    indices.clone(),
+                                    values_zero_float.clone(),
+                                    IndexingUpdateOp::Add,
+                                );
+
+                                let product = B::float_gather_nd(product, indices.clone());
+                                let num_zeros = B::float_gather_nd(num_zeros, indices.clone());
+                                let others = B::float_mask_fill(
+                                    B::float_div(product, values_nonzero),
+                                    B::float_greater(num_zeros, values_zero_float, bool_dtype),
+                                    0f32.into(),
+                                );
+
+                                B::float_mul(B::float_gather_nd(grad, indices), others)
                             },
                         );
                     }
                 }
 
-                let data_tracked = data.is_tracked();
                 let values_tracked = values.is_tracked();
 
                 match ScatterNdMul
@@ -1230,9 +1339,8 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 {
                     OpsKind::Tracked(prep) => {
                         let data_state = values_tracked.then(|| data.primitive.clone());
-                        let values_state = data_tracked.then(|| values.primitive.clone());
                         prep.finish(
-                            (data_state, values_state, indices.clone()),
+                            (data_state, values.primitive.clone(), indices.clone()),
                             B::float_scatter_nd(
                                 data.primitive,
                                 indices,
@@ -1250,21 +1358,17 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 }
             }
             IndexingUpdateOp::Min | IndexingUpdateOp::Max => {
-                // Unique indices are required for this backward formula; duplicate indices have
-                // undefined behavior for scatter_nd Min/Max gradients.
-                // Forward (Max): out[idx] = max(data[idx], values); non-scattered: out = data.
-                // Backward, with ties contributing to both sides (matches cummin/cummax convention):
-                //   data_at_idx  = gather_nd(data, idx)
-                //   data_won     = data_at_idx >= values   (Max)  /  <= values (Min)
-                //   values_won   = values >= data_at_idx   (Max)  /  <= data_at_idx (Min)
-                //   data_mask    = scatter_nd(ones_like(data), idx, data_won, Assign)
-                //   grad_data    = grad * data_mask
-                //   grad_values  = gather_nd(grad, idx) * values_won
+                // Forward (Max): out[p] = max(data[p], values[i] for idx[i] == p), so duplicate
+                // indices compete for the same position; non-scattered: out = data.
+                // Backward, with ties contributing to every tied input (matches the cummin/cummax
+                // convention), comparing each input to the output instead of to the other inputs:
+                //   grad_data    = grad * (data == out)
+                //   grad_values  = gather_nd(grad, idx) * (values == gather_nd(out, idx))
                 #[derive(Debug)]
                 struct ScatterNdMinMax;
 
                 impl<B: Backend> Backward<B, 2> for ScatterNdMinMax {
-                    type State = (FloatTensor<B>, FloatTensor<B>, IntTensor<B>, bool);
+                    type State = (FloatTensor<B>, FloatTensor<B>, IntTensor<B>, FloatTensor<B>);
 
                     fn backward(
                         self,
@@ -1272,79 +1376,51 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                         grads: &mut Gradients,
                         _checkpointer: &mut Checkpointer,
                     ) {
-                        let (data, values, indices, is_max) = ops.state;
+                        let (data, values, indices, output) = ops.state;
                         let device = B::float_device(&data);
-                        let data_dtype = data.dtype();
-                        let data_shape = data.shape();
-                        let settings = get_device_settings::<B>(&device);
-                        let bool_dtype = settings.bool_dtype;
-
-                        // data_at_idx — needed for both winner masks
-                        let data_at_idx = B::float_gather_nd(data, indices.clone());
-
-                        let (data_won_bool, values_won_bool) = if is_max {
-                            (
-                                B::float_greater_equal(
-                                    data_at_idx.clone(),
-                                    values.clone(),
-                                    bool_dtype,
-                                ),
-                                B::float_greater_equal(values, data_at_idx, bool_dtype),
-                            )
-                        } else {
-                            (
-                                B::float_lower_equal(
-                                    data_at_idx.clone(),
-                                    values.clone(),
-                                    bool_dtype,
-                                ),
-                                B::float_lower_equal(values, data_at_idx, bool_dtype),
-                            )
-                        };
-
-                        let data_won_float = B::bool_into_float(data_won_bool, data_dtype.into());
-                        let values_won_float =
-                            B::bool_into_float(values_won_bool, data_dtype.into());
-
-                        // Build the full-shape data mask: 1 at non-scattered positions,
-                        // data_won_float at scattered positions.
-                        let ones = B::float_ones(data_shape, &device, data_dtype.into());
-                        let data_mask = B::float_scatter_nd(
-                            ones,
-                            indices.clone(),
-                            data_won_float,
-                            IndexingUpdateOp::Assign,
+                        let dtype = data.dtype();
+                        let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+
+                        let output_at_idx = B::float_gather_nd(output.clone(), indices.clone());
+                        let data_won = B::bool_into_float(
+                            B::float_equal(data, output, bool_dtype),
+                            dtype.into(),
+                        );
+                        let values_won = B::bool_into_float(
+                            B::float_equal(values, output_at_idx, bool_dtype),
+                            dtype.into(),
                         );
 
                         binary::<B, _, _>(
                             ops.parents,
                             ops.node,
                             grads,
-                            |grad| B::float_mul(grad, data_mask),
+                            |grad| B::float_mul(grad, data_won),
                             |grad| {
                                 let g_idx = B::float_gather_nd(grad, indices);
-                                B::float_mul(g_idx, values_won_float)
+                                B::float_mul(g_idx, values_won)
                             },
                         );
                     }
                 }
 
-                let is_max = matches!(reduction, IndexingUpdateOp::Max);
-
                 match ScatterNdMinMax
                     .prepare::<C>([data.node, values.node])
                     .compute_bound()
                     .stateful()
                 {
-                    OpsKind::Tracked(prep) => prep.finish(
-                        (
+                    OpsKind::Tracked(prep) => {
+                        let output = B::float_scatter_nd(
                             data.primitive.clone(),
-                            values.primitive.clone(),
                             indices.clone(),
-                            is_max,
-                        ),
-                        B::float_scatter_nd(data.primitive, indices, values.primitive, reduction),
-                    ),
+                            values.primitive.clone(),
+                            reduction,
+                        );
+                        prep.finish(
+                            (data.primitive, values.primitive, indices, output.clone()),
+                            output,
+                        )
+                    }
                     OpsKind::UnTracked(prep) => prep.finish(B::float_scatter_nd(
                         data.primitive,
                         indices,
@@ -1353,6 +1429,22 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                     )),
                 }
             }
+            IndexingUpdateOp::Mean => {
+                // The mean is the sum of the existing and scattered values divided by the number
+                // of terms of each position, which doesn't depend on the inputs, so the gradients
+                // flow through the additive scatter and the division.
+                let device = B::float_device(&data.primitive);
+                let dtype = data.primitive.dtype();
+                let counts = B::float_scatter_nd(
+                    B::float_ones(data.primitive.shape(), &device, dtype.into()),
+                    indices.clone(),
+                    B::float_ones(values.primitive.shape(), &device, dtype.into()),
+                    IndexingUpdateOp::Add,
+                );
+                let sum = Self::float_scatter_nd(data, indices, values, IndexingUpdateOp::Add);
+
+                Self::float_div(sum, AutodiffTensor::new(counts))
+            }
         }
     }
 
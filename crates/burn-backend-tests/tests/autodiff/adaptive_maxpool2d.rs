@@ -0,0 +1,37 @@
+use super::*;
+use burn_tensor::module::adaptive_max_pool2d;
+use burn_tensor::{Shape, Tolerance};
+
+#[test]
+fn test_adaptive_max_pool2d_overlapping_windows() {
+    let device = AutodiffDevice::new();
+    let shape_x = Shape::new([1, 1, 5, 5]);
+    let x = TestTensor::from_data(
+        TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+            .reshape::<4, _>(shape_x)
+            .into_data(),
+        &device,
+    )
+    .require_grad();
+
+    // Windows span rows and columns [0, 2), [1, 4) and [3, 5), so the maxima of an increasing
+    // input sit at rows and columns 1, 3 and 4.
+    let output = adaptive_max_pool2d(x.clone(), [3, 3]);
+    let grads = output.backward();
+    let x_grad_actual = x.grad(&grads).unwrap();
+
+    let x_grad = TestTensor::<4>::from_data(
+        [[[
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 1.0, 1.0],
+            [0.0, 1.0, 0.0, 1.0, 1.0],
+        ]]],
+        &device,
+    );
+
+    x_grad
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.into_data(), Tolerance::default());
+}
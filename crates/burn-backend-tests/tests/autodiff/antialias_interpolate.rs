@@ -0,0 +1,45 @@
+use super::*;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_antialias_bilinear_interpolate() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<4>::from_data([[[[0.0, 1.0, 2.0, 3.0]]]], &device).require_grad();
+
+    let output = interpolate(
+        x.clone(),
+        [1, 2],
+        InterpolateOptions::new(InterpolateMode::Bilinear)
+            .with_align_corners(false)
+            .with_antialias(true),
+    );
+    let grads = output.backward();
+    let x_grad = x.grad(&grads).unwrap();
+
+    // The sums of the weights [3, 3, 1, 0] / 7 and [0, 1, 3, 3] / 7 of each input.
+    let expected = TensorData::from([[[[3.0 / 7.0, 4.0 / 7.0, 4.0 / 7.0, 3.0 / 7.0]]]]);
+    x_grad
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_diff_area_interpolate() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<4>::ones([1, 1, 4, 4], &device).require_grad();
+
+    let output = interpolate(
+        x.clone(),
+        [2, 2],
+        InterpolateOptions::new(InterpolateMode::Area),
+    );
+    let grads = output.backward();
+    let x_grad = x.grad(&grads).unwrap();
+
+    let expected = TestTensor::<4>::full([1, 1, 4, 4], 0.25, &device);
+    x_grad
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
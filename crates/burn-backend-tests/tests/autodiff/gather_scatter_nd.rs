@@ -159,13 +159,13 @@ fn test_scatter_nd_mul_grad() {
     let grad_data = data.grad(&grads).unwrap();
     let grad_values = values.grad(&grads).unwrap();
 
-    // grad_data = grad * scatter_nd(ones, indices, values, Assign)
+    // grad_data = grad * scatter_nd(ones, indices, values, Mul)
     //   row 1 gets the values, others stay at 1.
     grad_data.to_data().assert_eq(
         &TensorData::from([[1.0, 1.0, 1.0], [10.0, 20.0, 30.0], [1.0, 1.0, 1.0]]),
         false,
     );
-    // grad_values = gather_nd(grad, indices) * gather_nd(data, indices)
+    // grad_values = gather_nd(grad, indices) * (the other factors of each position)
     //             = ones * data[1, :] = [4.0, 5.0, 6.0]
     grad_values
         .to_data()
@@ -451,3 +451,83 @@ fn test_scatter_nd_assign_grad_k_equals_d() {
         .to_data()
         .assert_eq(&TensorData::from([1.0, 1.0]), false);
 }
+
+#[test]
+fn test_scatter_nd_mul_grad_duplicate_indices() {
+    // The first two values multiply the first element, including a zero.
+    let device = AutodiffDevice::new();
+    let data: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([2.0, 3.0]), &device).require_grad();
+    let values: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([4.0, 0.0, 5.0]), &device).require_grad();
+    let indices = TestTensorInt::<2>::from_data(TensorData::from([[0], [0], [1]]), &device);
+
+    // result = [2 * 4 * 0, 3 * 5]
+    let result: TestTensor<1> =
+        data.clone()
+            .scatter_nd(indices, values.clone(), IndexingUpdateOp::Mul);
+    let grads = result.sum().backward();
+
+    let grad_data = data.grad(&grads).unwrap();
+    let grad_values = values.grad(&grads).unwrap();
+
+    // Each input receives the product of the other factors of its position.
+    grad_data
+        .to_data()
+        .assert_eq(&TensorData::from([0.0, 5.0]), false);
+    grad_values
+        .to_data()
+        .assert_eq(&TensorData::from([0.0, 8.0, 3.0]), false);
+}
+
+#[test]
+fn test_scatter_nd_max_grad_duplicate_indices() {
+    // The first value wins the first element, and the data ties with a value on the second.
+    let device = AutodiffDevice::new();
+    let data: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([1.0, 5.0]), &device).require_grad();
+    let values: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([3.0, 2.0, 5.0, 4.0]), &device).require_grad();
+    let indices = TestTensorInt::<2>::from_data(TensorData::from([[0], [0], [1], [1]]), &device);
+
+    let result: TestTensor<1> =
+        data.clone()
+            .scatter_nd(indices, values.clone(), IndexingUpdateOp::Max);
+    let grads = result.sum().backward();
+
+    let grad_data = data.grad(&grads).unwrap();
+    let grad_values = values.grad(&grads).unwrap();
+
+    grad_data
+        .to_data()
+        .assert_eq(&TensorData::from([0.0, 1.0]), false);
+    grad_values
+        .to_data()
+        .assert_eq(&TensorData::from([1.0, 0.0, 1.0, 0.0]), false);
+}
+
+#[test]
+fn test_scatter_nd_min_grad_duplicate_indices() {
+    // The data wins the first element, and the last value wins the second.
+    let device = AutodiffDevice::new();
+    let data: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([1.0, 5.0]), &device).require_grad();
+    let values: TestTensor<1> =
+        TestTensor::from_data(TensorData::from([3.0, 2.0, 5.0, 4.0]), &device).require_grad();
+    let indices = TestTensorInt::<2>::from_data(TensorData::from([[0], [0], [1], [1]]), &device);
+
+    let result: TestTensor<1> =
+        data.clone()
+            .scatter_nd(indices, values.clone(), IndexingUpdateOp::Min);
+    let grads = result.sum().backward();
+
+    let grad_data = data.grad(&grads).unwrap();
+    let grad_values = values.grad(&grads).unwrap();
+
+    grad_data
+        .to_data()
+        .assert_eq(&TensorData::from([1.0, 0.0]), false);
+    grad_values
+        .to_data()
+        .assert_eq(&TensorData::from([0.0, 0.0, 0.0, 1.0]), false);
+}
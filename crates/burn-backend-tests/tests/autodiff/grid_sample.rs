@@ -0,0 +1,49 @@
+use super::*;
+use burn_tensor::{
+    TensorData, Tolerance,
+    ops::{GridSampleOptions, InterpolateMode},
+};
+
+#[test]
+fn should_diff_grid_sample_2d_bilinear() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]], &device).require_grad();
+    let grid = TestTensor::<4>::from_data([[[[0.0, 0.0], [0.5, -0.5]]]], &device).require_grad();
+
+    let options = GridSampleOptions::new(InterpolateMode::Bilinear).with_align_corners(true);
+    let output = tensor.clone().grid_sample_2d(grid.clone(), options);
+    let grads = output.backward();
+
+    let tensor_grad = tensor.grad(&grads).unwrap();
+    let grid_grad = grid.grad(&grads).unwrap();
+
+    // The samples at (0.5, 0.5) and (0.75, 0.25) in pixels.
+    tensor_grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[[0.4375, 0.8125], [0.3125, 0.4375]]]]),
+        Tolerance::default(),
+    );
+    // The input is linear with slopes 1 along x and 2 along y, and a grid step of 1 is half the
+    // image.
+    grid_grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[[0.5, 1.0], [0.5, 1.0]]]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_diff_grid_sample_2d_nearest() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]], &device).require_grad();
+    let grid = TestTensor::<4>::from_data([[[[-0.8, -0.9], [0.7, 0.6], [0.9, 0.8]]]], &device);
+
+    let options = GridSampleOptions::new(InterpolateMode::Nearest).with_align_corners(true);
+    let output = tensor.clone().grid_sample_2d(grid, options);
+    let grads = output.backward();
+
+    let tensor_grad = tensor.grad(&grads).unwrap();
+
+    tensor_grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[[1.0, 0.0], [0.0, 2.0]]]]),
+        Tolerance::default(),
+    );
+}
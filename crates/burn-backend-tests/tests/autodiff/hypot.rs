@@ -0,0 +1,24 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::Tolerance;
+
+#[test]
+fn should_diff_hypot() {
+    let device = AutodiffDevice::new();
+    let lhs = TestTensor::<1>::from_data([3.0, -5.0], &device).require_grad();
+    let rhs = TestTensor::<1>::from_data([4.0, 12.0], &device).require_grad();
+
+    let output = lhs.clone().hypot(rhs.clone());
+    let grads = output.backward();
+
+    // The gradients of `sqrt(x^2 + y^2)` are `x / hypot` and `y / hypot`.
+    let lhs_grad = lhs.grad(&grads).unwrap();
+    let rhs_grad = rhs.grad(&grads).unwrap();
+
+    lhs_grad
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.6, -0.384615]), Tolerance::default());
+    rhs_grad
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.8, 0.923077]), Tolerance::default());
+}
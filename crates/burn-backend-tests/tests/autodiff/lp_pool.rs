@@ -0,0 +1,22 @@
+use super::*;
+use burn_tensor::Tolerance;
+use burn_tensor::module::lp_pool2d;
+
+#[test]
+fn test_lp_pool2d_euclidean() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<4>::from_data([[[[3.0, 0.0, 1.0, 2.0], [4.0, 0.0, 2.0, 4.0]]]], &device)
+        .require_grad();
+
+    // The gradient of a euclidean norm is `x / ||x||`, and both windows have a norm of 5.
+    let output = lp_pool2d(x.clone(), 2.0, [2, 2], [2, 2], false);
+    let grads = output.backward();
+    let x_grad_actual = x.grad(&grads).unwrap();
+
+    let x_grad =
+        TestTensor::<4>::from_data([[[[0.6, 0.0, 0.2, 0.4], [0.8, 0.0, 0.4, 0.8]]]], &device);
+
+    x_grad
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.into_data(), Tolerance::default());
+}
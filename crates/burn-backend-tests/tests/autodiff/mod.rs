@@ -4,10 +4,12 @@ pub use super::*;
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
 #[cfg(feature = "distributed")]
 mod all_reduce;
+mod antialias_interpolate;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
@@ -42,9 +44,12 @@ mod gather_scatter;
 mod gather_scatter_nd;
 mod gelu;
 mod gradients;
+mod hypot;
+mod grid_sample;
 mod log;
 mod log1p;
 mod log_sigmoid;
+mod lp_pool;
 mod mask;
 mod matmul;
 mod maxmin;
@@ -56,23 +61,29 @@ mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod pad;
 mod permute;
 mod pow;
+mod random;
 mod recip;
 mod relu;
 mod remainder;
 mod repeat_dim;
 mod reshape;
+mod roll;
 mod rfft;
 mod round;
 mod select;
 mod sigmoid;
 mod sign;
+mod sinkhorn;
 mod slice;
 mod slice_assign;
+mod soft_sort;
 mod softmax;
 mod sort;
 mod sqrt;
+mod straight_through;
 mod sub;
 mod transpose;
 mod trig;
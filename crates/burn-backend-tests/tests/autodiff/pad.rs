@@ -0,0 +1,36 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::ops::PadMode;
+
+fn pad_grad(mode: PadMode) -> TensorData {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0, 4.0]], &device).require_grad();
+
+    let output = tensor.clone().pad([(2, 1)], mode);
+    let grads = output.sum().backward();
+
+    tensor.grad(&grads).unwrap().into_data()
+}
+
+#[test]
+fn should_diff_pad_constant() {
+    pad_grad(PadMode::Constant(5.0)).assert_eq(&TensorData::from([[1.0, 1.0, 1.0, 1.0]]), false);
+}
+
+#[test]
+fn should_diff_pad_reflect() {
+    // Output: [3, 2, 1, 2, 3, 4, 3]
+    pad_grad(PadMode::Reflect).assert_eq(&TensorData::from([[1.0, 2.0, 3.0, 1.0]]), false);
+}
+
+#[test]
+fn should_diff_pad_edge() {
+    // Output: [1, 1, 1, 2, 3, 4, 4]
+    pad_grad(PadMode::Edge).assert_eq(&TensorData::from([[3.0, 1.0, 1.0, 2.0]]), false);
+}
+
+#[test]
+fn should_diff_pad_circular() {
+    // Output: [3, 4, 1, 2, 3, 4, 1]
+    pad_grad(PadMode::Circular).assert_eq(&TensorData::from([[2.0, 1.0, 2.0, 2.0]]), false);
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn should_reparameterize_gamma_samples() {
+    let device = AutodiffDevice::new();
+    let concentration = TestTensor::<1>::full([4096], 3.0, &device).require_grad();
+
+    // E[x] = concentration, so the expected gradient of the mean is one for each sample.
+    let samples = TestTensor::random_gamma(concentration.clone());
+    let grads = samples.sum().backward();
+    let grad = concentration.grad(&grads).unwrap();
+
+    let mean = grad.mean().into_scalar::<f32>();
+    assert!(
+        (mean - 1.0).abs() < 0.1,
+        "Expected a mean gradient of 1, got {mean}"
+    );
+}
@@ -0,0 +1,25 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_diff_roll() {
+    let data_1 = TensorData::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let data_2 = TensorData::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let device = AutodiffDevice::new();
+    let tensor_1 = TestTensor::<2>::from_data(data_1, &device).require_grad();
+    let tensor_2 = TestTensor::from_data(data_2, &device).require_grad();
+
+    let tensor_3 = tensor_1.clone().roll(&[1], &[1]).mul(tensor_2.clone());
+    let grads = tensor_3.sum().backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    grad_1
+        .into_data()
+        .assert_eq(&TensorData::from([[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]), false);
+    grad_2
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0, 3.0, 1.0], [5.0, 6.0, 4.0]]), false);
+}
@@ -0,0 +1,24 @@
+use super::*;
+use burn_tensor::transport::sinkhorn;
+
+#[test]
+fn should_diff_sinkhorn() {
+    let device = AutodiffDevice::new();
+    let cost = TestTensor::<3>::from_data([[[0.0, 1.0], [1.0, 0.0]]], &device).require_grad();
+    let uniform = TestTensor::<2>::from_data([[0.5, 0.5]], &device);
+
+    let plan = sinkhorn(cost.clone(), uniform.clone(), uniform, 0.5, 50);
+    let transport_cost = (plan * cost.clone()).sum();
+    let grads = transport_cost.backward();
+
+    // The problem is symmetric, and so is the gradient.
+    let grad = cost
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .to_vec::<f32>()
+        .unwrap();
+    assert!(grad.iter().all(|value| value.is_finite()), "{grad:?}");
+    assert!((grad[0] - grad[3]).abs() < 1e-4, "{grad:?}");
+    assert!((grad[1] - grad[2]).abs() < 1e-4, "{grad:?}");
+}
@@ -0,0 +1,37 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::ranking::{soft_rank, soft_sort};
+
+#[test]
+fn should_diff_soft_sort() {
+    let device = AutodiffDevice::new();
+    let tensor_1 = TestTensor::<2>::from_data([[0.5, 3.0, -1.0]], &device).require_grad();
+    let weights = TestTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+    let tensor_2 = soft_sort(tensor_1.clone(), 1e-2, false);
+    let grads = (tensor_2 * weights).sum().backward();
+
+    // At a low temperature, the gradient of each element is the weight of its sorted position.
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    grad_1
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[2.0, 3.0, 1.0]]), Default::default());
+}
+
+#[test]
+fn should_diff_soft_rank() {
+    let device = AutodiffDevice::new();
+    let tensor_1 = TestTensor::<2>::from_data([[0.5, 0.6, -1.0]], &device).require_grad();
+
+    // Increasing the rank of the first element pushes it up.
+    let tensor_2 = soft_rank(tensor_1.clone(), 1.0, false);
+    let grads = tensor_2.slice([0..1, 0..1]).sum().backward();
+
+    let grad_1 = tensor_1
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .to_vec::<f32>()
+        .unwrap();
+    assert!(grad_1[0] > 0.0, "{grad_1:?}");
+}
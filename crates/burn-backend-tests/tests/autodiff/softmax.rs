@@ -88,3 +88,20 @@ fn test_quiet_softmax_grad() {
         .to_data()
         .assert_approx_eq::<FloatElem>(&expected, tolerance);
 }
+
+#[test]
+fn test_logsumexp_grad() {
+    let data = TensorData::from([[1.0, 2.0], [3.0, 4.0]]);
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data(data, &device).require_grad();
+
+    // The gradient of logsumexp is the softmax over the reduced dims.
+    let output = activation::logsumexp(tensor.clone(), &[0, 1]);
+
+    let grads = output.backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([[0.0320586, 0.0871443], [0.2368828, 0.6439143]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::rel_abs(0.05, 0.01));
+}
@@ -0,0 +1,41 @@
+use super::*;
+use burn_tensor::{TensorData, activation};
+
+#[test]
+fn should_diff_straight_through() {
+    let data = TensorData::from([[-1.9751, 0.0714], [0.5643, 1.2406]]);
+    let device = AutodiffDevice::new();
+    let tensor_1 = TestTensor::<2>::from_data(data, &device).require_grad();
+    let tensor_2 = tensor_1.clone().straight_through(tensor_1.clone().round());
+    let tensor_3 = tensor_2.clone().mul_scalar(3.0);
+    let grads = tensor_3.backward();
+
+    // Rounded in the forward pass, identity in the backward pass.
+    tensor_2
+        .to_data()
+        .assert_eq(&TensorData::from([[-2.0, 0.0], [1.0, 1.0]]), false);
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    grad_1
+        .to_data()
+        .assert_eq(&TensorData::from([[3.0, 3.0], [3.0, 3.0]]), false);
+}
+
+#[test]
+fn should_diff_hard_gumbel_softmax_as_soft() {
+    let device = AutodiffDevice::new();
+    let logits =
+        TestTensor::<2>::from_data([[1.0, 2.0, 0.5], [0.0, -1.0, 3.0]], &device).require_grad();
+    let weights = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+    let samples = activation::gumbel_softmax(logits.clone(), 0.5, true, 1);
+    let grads = (samples.clone() * weights).sum().backward();
+
+    // One-hot samples, with the non-zero gradients of the soft samples.
+    samples
+        .clone()
+        .sum_dim(1)
+        .to_data()
+        .assert_eq(&TensorData::from([[1.0], [1.0]]), false);
+    let grad = logits.grad(&grads).unwrap();
+    assert!(grad.abs().sum().into_scalar::<f32>() > 0.0);
+}
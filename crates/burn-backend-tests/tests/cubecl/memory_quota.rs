@@ -0,0 +1,41 @@
+use super::*;
+use burn_tensor::{Device, MemoryQuota};
+
+#[test]
+fn should_fail_recoverably_over_memory_quota() {
+    let device: Device = Default::default();
+    let quota = MemoryQuota::new(1024);
+
+    let result = device.memory_quota(&quota, || {
+        TestTensor::<2>::ones([64, 64], &device).into_data()
+    });
+
+    let error = result.unwrap_err();
+    assert_eq!(error.limit, 1024);
+    assert!(error.requested + error.used > 1024);
+    assert_eq!(quota.used(), 0);
+
+    // The device is still usable after the error.
+    let tensor = TestTensor::<1>::ones([4], &device);
+    assert_eq!(tensor.sum().into_scalar::<f32>(), 4.0);
+}
+
+#[test]
+fn should_charge_the_scopes_sharing_a_quota() {
+    let device: Device = Default::default();
+    let quota = MemoryQuota::new(64 * 64 * 4 * 4);
+
+    let result = device.memory_quota(&quota, || {
+        let tensor = TestTensor::<2>::ones([64, 64], &device);
+        let inner = device.memory_quota(&quota, || {
+            TestTensor::<2>::ones([64, 64], &device).into_data()
+        });
+
+        assert!(inner.is_ok());
+        assert!(quota.used() > 0);
+        tensor.into_data()
+    });
+
+    assert!(result.is_ok());
+    assert_eq!(quota.used(), 0);
+}
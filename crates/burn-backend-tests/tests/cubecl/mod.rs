@@ -18,6 +18,7 @@ mod mask_fill;
 mod mask_where;
 mod max_pool2d;
 mod max_pool2d_backward;
+mod memory_quota;
 mod normal;
 mod quantization;
 mod reduce;
@@ -19,6 +19,7 @@ mod permute;
 mod repeat;
 mod repeat_dim;
 mod reshape;
+mod roll;
 mod select;
 mod stack;
 mod take;
@@ -0,0 +1,12 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn roll_bool() {
+    let tensor = TestTensorBool::<2>::from([[true, false, false], [false, true, false]]);
+
+    tensor.roll(&[1], &[1]).into_data().assert_eq(
+        &TensorData::from([[false, false, true], [true, false, false]]),
+        false,
+    );
+}
@@ -0,0 +1,63 @@
+use super::*;
+use burn_tensor::{DType, DTypeUsage, Device, MemoryQuota};
+
+#[test]
+fn should_query_device_capabilities() {
+    let device: Device = Default::default();
+
+    assert!(device.supports_dtype(DType::F32));
+    assert!(
+        device
+            .dtype_usage(DType::F32)
+            .is_superset(DTypeUsage::general())
+    );
+
+    let properties = device.properties();
+    if let (Some(min), Some(max)) = (properties.plane_size_min, properties.plane_size_max) {
+        assert!(min <= max);
+    }
+}
+
+#[test]
+fn should_release_memory_quota_when_tensors_are_dropped() {
+    let device: Device = Default::default();
+    let quota = MemoryQuota::new(1024 * 1024);
+
+    let output = device.memory_quota(&quota, || {
+        let tensor = TestTensor::<2>::ones([16, 16], &device);
+        (tensor.clone() + tensor).sum().into_scalar::<f32>()
+    });
+
+    assert_eq!(output.unwrap(), 512.0);
+    assert_eq!(quota.used(), 0);
+}
+
+#[test]
+fn should_keep_tensor_on_same_device_async() {
+    let device: Device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    let expected = tensor.to_data();
+
+    let moved = burn_tensor::read_sync(tensor.to_device_async(&device)).unwrap();
+
+    assert_eq!(moved.device(), device);
+    moved.into_data().assert_eq(&expected, true);
+}
+
+#[cfg(feature = "cube")]
+#[test]
+fn should_move_tensor_to_device_async() {
+    let device: Device = Default::default();
+    let host: Device = burn_ndarray::NdArrayDevice::Cpu.into();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    let expected = tensor.to_data();
+
+    // Round trip through the host so both transfers go through the staging path.
+    let on_host = burn_tensor::read_sync(tensor.to_device_async(&host)).unwrap();
+    assert_eq!(on_host.device(), host);
+    on_host.to_data().assert_eq(&expected, true);
+
+    let moved = burn_tensor::read_sync(on_host.to_device_async(&device)).unwrap();
+    assert_eq!(moved.device(), device);
+    moved.into_data().assert_eq(&expected, true);
+}
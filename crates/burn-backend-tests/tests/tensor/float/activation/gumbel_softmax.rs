@@ -0,0 +1,38 @@
+use super::*;
+use burn_tensor::Tolerance;
+use burn_tensor::{TensorData, activation};
+
+#[test]
+fn test_gumbel_softmax_samples_are_distributions() {
+    let logits = TestTensor::<2>::from([[1.0, 2.0, 0.5, -1.0], [0.0, 0.0, 0.0, 0.0]]);
+
+    let samples = activation::gumbel_softmax(logits, 1.0, false, 1);
+
+    samples
+        .clone()
+        .sum_dim(1)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[1.0], [1.0]]), Tolerance::default());
+    assert!(samples.greater_equal_elem(0.0).all().into_scalar::<bool>());
+}
+
+#[test]
+fn test_gumbel_softmax_hard_samples_are_one_hot() {
+    // The Gumbel noise can't compensate such a gap between the logits.
+    let logits = TestTensor::<2>::from([[0.0, 100.0, 0.0], [100.0, 0.0, 0.0]]);
+
+    let samples = activation::gumbel_softmax(logits, 0.5, true, 1);
+
+    samples.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+#[should_panic = "temperature must be positive"]
+fn test_gumbel_softmax_invalid_temperature() {
+    let logits = TestTensor::<2>::from([[0.0, 1.0]]);
+
+    let _samples = activation::gumbel_softmax(logits, 0.0, false, 1);
+}
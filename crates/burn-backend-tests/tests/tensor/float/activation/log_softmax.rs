@@ -14,3 +14,16 @@ fn test_log_softmax_d2() {
         .into_data()
         .assert_approx_eq::<FloatElem>(&expected, tolerance);
 }
+
+#[test]
+fn test_log_softmax_dims() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let output = activation::log_softmax_dims(tensor, &[0, 1]);
+    let expected = TensorData::from([[-3.4401897, -2.4401897], [-1.4401897, -0.4401897]]);
+
+    let tolerance = Tolerance::rel_abs(0.01, 0.0001);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance);
+}
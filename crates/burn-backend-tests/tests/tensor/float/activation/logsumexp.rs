@@ -0,0 +1,76 @@
+use super::*;
+use burn_tensor::Tolerance;
+use burn_tensor::{ElementConversion, TensorData, activation};
+
+#[test]
+fn test_logsumexp_d2() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let output = activation::logsumexp(tensor, &[1]);
+    let expected = TensorData::from([[2.3132617], [4.3132617]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_logsumexp_multiple_dims() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let output = activation::logsumexp(tensor, &[0, 1]);
+    let expected = TensorData::from([[4.4401897]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_logsumexp_first_dim() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let output = activation::logsumexp(tensor, &[0]);
+    let expected = TensorData::from([[3.1269280, 4.1269280]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_logsumexp_non_adjacent_dims() {
+    let tensor = TestTensor::<3>::from([[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]);
+
+    let output = activation::logsumexp(tensor, &[2, 0]);
+    let expected = TensorData::from([[[5.3314116], [7.3314116]]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_logsumexp_large_values() {
+    // The naive composition overflows to `inf` on these values.
+    let tensor = TestTensor::<1>::from([1000.0, 1000.0]);
+
+    let output = activation::logsumexp(tensor, &[0]);
+    let expected = TensorData::from([1000.6931]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::permissive());
+}
+
+#[test]
+fn test_logsumexp_negative_infinity() {
+    let data = TensorData::from([[f32::NEG_INFINITY, f32::NEG_INFINITY], [0.0, 0.0]]);
+    let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+    let output = activation::logsumexp(tensor, &[1]);
+    let values = output.into_data().as_slice::<FloatElem>().unwrap().to_vec();
+
+    assert!(values[0].is_infinite() && values[0].is_sign_negative());
+    assert!((values[1].elem::<f32>() - core::f32::consts::LN_2).abs() < 1e-2);
+}
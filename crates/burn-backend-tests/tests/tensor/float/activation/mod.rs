@@ -4,10 +4,12 @@ mod celu;
 mod elu;
 mod gelu;
 mod glu;
+mod gumbel_softmax;
 mod hard_sigmoid;
 mod leaky_relu;
 mod log_sigmoid;
 mod log_softmax;
+mod logsumexp;
 mod mish;
 mod prelu;
 mod quiet_softmax;
@@ -127,3 +127,30 @@ fn test_softmax_non_last_axis_d4_dim0() {
         .into_data()
         .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
 }
+
+#[test]
+fn test_softmax_dims() {
+    let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.5, 0.5], [0.5, 0.5]]]);
+
+    let output = activation::softmax_dims(tensor, &[1, 2]);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([
+            [[0.03205860, 0.08714432], [0.23688282, 0.64391426]],
+            [[0.25, 0.25], [0.25, 0.25]],
+        ]),
+        Tolerance::default().set_half_precision_absolute(2e-3),
+    );
+}
+
+#[test]
+fn test_softmax_dims_single_dim_matches_softmax() {
+    let tensor = TestTensor::<2>::from([[1.0, 7.0], [13.0, -3.0]]);
+
+    let output = activation::softmax_dims(tensor.clone(), &[1]);
+    let expected = activation::softmax(tensor, 1);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
@@ -0,0 +1,146 @@
+use super::*;
+use burn_tensor::{
+    TensorData,
+    linalg::{diag_embed, diagonal, trace_dims},
+};
+
+#[test]
+fn test_diagonal_main() {
+    let device = Default::default();
+    let tensor =
+        TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], &device);
+
+    let result = diagonal::<2, 1, _>(tensor, 0, 0, 1);
+    let expected = TensorData::from([1.0, 5.0, 9.0]);
+
+    result.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_diagonal_offsets() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+    let above = diagonal::<2, 1, _>(tensor.clone(), 1, 0, 1);
+    let below = diagonal::<2, 1, _>(tensor.clone(), -1, 0, 1);
+    let outside = diagonal::<2, 1, _>(tensor, 3, 0, 1);
+
+    above
+        .into_data()
+        .assert_eq(&TensorData::from([2.0, 6.0]), false);
+    below
+        .into_data()
+        .assert_eq(&TensorData::from([4.0]), false);
+    assert_eq!(outside.dims(), [0]);
+}
+
+#[test]
+fn test_diagonal_batched_dims() {
+    let device = Default::default();
+    // Shape [2, 2, 2], with the matrices in the first and last dimensions.
+    let tensor = TestTensor::<3>::from_data(
+        [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+        &device,
+    );
+
+    let result = diagonal::<3, 2, _>(tensor, 0, 0, -1);
+    // result[j, i] = tensor[i, j, i]
+    let expected = TensorData::from([[1.0, 6.0], [3.0, 8.0]]);
+
+    result.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_diagonal_int() {
+    let device = Default::default();
+    let tensor = TestTensorInt::<3>::from_data([[[1, 2], [3, 4]], [[5, 6], [7, 8]]], &device);
+
+    let result = diagonal::<3, 2, _>(tensor, 0, -2, -1);
+    let expected = TensorData::from([[1, 4], [5, 8]]);
+
+    result.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_diag_embed_main() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+    let result = diag_embed::<2, 3, _>(tensor, 0, -2, -1);
+
+    result.into_data().assert_eq(
+        &TensorData::from([[[1.0, 0.0], [0.0, 2.0]], [[3.0, 0.0], [0.0, 4.0]]]),
+        false,
+    );
+}
+
+#[test]
+fn test_diag_embed_offsets() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0], &device);
+
+    let above = diag_embed::<1, 2, _>(tensor.clone(), 1, 0, 1);
+    let below = diag_embed::<1, 2, _>(tensor, -1, 0, 1);
+
+    above.into_data().assert_eq(
+        &TensorData::from([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [0.0, 0.0, 0.0]]),
+        false,
+    );
+    below.into_data().assert_eq(
+        &TensorData::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0]]),
+        false,
+    );
+}
+
+#[test]
+fn test_diag_embed_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+    // The matrices are in the first and last dimensions, the batch in the middle.
+    let result = diag_embed::<2, 3, _>(tensor, 0, 0, 2);
+
+    result.into_data().assert_eq(
+        &TensorData::from([[[1.0, 0.0], [3.0, 0.0]], [[0.0, 2.0], [0.0, 4.0]]]),
+        false,
+    );
+}
+
+#[test]
+fn test_diag_embed_diagonal_roundtrip() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+    let matrices = diag_embed::<2, 3, _>(tensor.clone(), -2, 1, 2);
+    let result = diagonal::<3, 2, _>(matrices, -2, 1, 2);
+
+    result.into_data().assert_eq(&tensor.into_data(), false);
+}
+
+#[test]
+fn test_trace_dims_offset() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::from_data(
+        [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+        &device,
+    );
+
+    let main_diagonal = trace_dims::<3, 2>(tensor.clone(), 0, 1, 2);
+    let above = trace_dims::<3, 2>(tensor, 1, 1, 2);
+
+    main_diagonal
+        .into_data()
+        .assert_eq(&TensorData::from([[5.0], [13.0]]), false);
+    above
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0], [6.0]]), false);
+}
+
+#[test]
+#[should_panic]
+fn test_diagonal_same_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::zeros([2, 2], &device);
+
+    let _ = diagonal::<2, 1, _>(tensor, 0, 1, -1);
+}
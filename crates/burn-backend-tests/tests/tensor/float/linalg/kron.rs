@@ -0,0 +1,100 @@
+use super::*;
+use burn_tensor::{TensorData, linalg};
+
+#[test]
+fn test_kron_vectors() {
+    let lhs = TestTensor::<1>::from([1.0, 2.0]);
+    let rhs = TestTensor::<1>::from([3.0, 4.0, 5.0]);
+
+    let out = linalg::kron(lhs, rhs).into_data();
+    let expected = TensorData::from([3.0, 4.0, 5.0, 6.0, 8.0, 10.0]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+fn test_kron_matrices() {
+    let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let rhs = TestTensor::<2>::from([[0.0, 5.0], [6.0, 7.0]]);
+
+    let out = linalg::kron(lhs, rhs).into_data();
+    let expected = TensorData::from([
+        [0.0, 5.0, 0.0, 10.0],
+        [6.0, 7.0, 12.0, 14.0],
+        [0.0, 15.0, 0.0, 20.0],
+        [18.0, 21.0, 24.0, 28.0],
+    ]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+fn test_kron_non_square_shapes() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::ones([2, 3], &device);
+    let rhs = TestTensor::<2>::ones([4, 1], &device);
+
+    let out = linalg::kron(lhs, rhs);
+
+    assert_eq!(out.shape().dims(), [8, 3]);
+}
+
+#[test]
+fn test_kron_identity() {
+    let device = Default::default();
+    let eye = TestTensor::<2>::eye(2, &device);
+    let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let out = linalg::kron(eye, rhs).into_data();
+    let expected = TensorData::from([
+        [1.0, 2.0, 0.0, 0.0],
+        [3.0, 4.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 2.0],
+        [0.0, 0.0, 3.0, 4.0],
+    ]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+fn test_kron_batched_matrices() {
+    let lhs = TestTensor::<3>::from([[[1.0], [2.0]], [[3.0], [4.0]]]);
+    let rhs = TestTensor::<3>::from([[[1.0, -1.0]], [[2.0, 0.0]]]);
+
+    let out = linalg::kron_batched(lhs, rhs, 1).into_data();
+    let expected = TensorData::from([[[1.0, -1.0], [2.0, -2.0]], [[6.0, 0.0], [8.0, 0.0]]]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+fn test_kron_batched_broadcast() {
+    let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let rhs = TestTensor::<2>::from([[1.0, 10.0]]);
+
+    let out = linalg::kron_batched(lhs, rhs, 1).into_data();
+    let expected = TensorData::from([[1.0, 10.0, 2.0, 20.0], [3.0, 30.0, 4.0, 40.0]]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+fn test_kron_int() {
+    let lhs = TestTensorInt::<1>::from([1, 2]);
+    let rhs = TestTensorInt::<1>::from([1, 3]);
+
+    let out = linalg::kron(lhs, rhs).into_data();
+    let expected = TensorData::from([1, 3, 2, 6]);
+
+    out.assert_eq(&expected, false);
+}
+
+#[test]
+#[should_panic]
+fn test_kron_batched_incompatible_batch() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::ones([2, 2], &device);
+    let rhs = TestTensor::<2>::ones([3, 2], &device);
+
+    let _ = linalg::kron_batched(lhs, rhs, 1);
+}
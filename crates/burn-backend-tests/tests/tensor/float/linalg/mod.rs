@@ -3,6 +3,8 @@ use super::*;
 pub(crate) mod cosine_similarity;
 pub(crate) mod det;
 pub(crate) mod diag;
+pub(crate) mod diagonal;
+pub(crate) mod kron;
 pub(crate) mod lu;
 pub(crate) mod matvec;
 pub(crate) mod outer;
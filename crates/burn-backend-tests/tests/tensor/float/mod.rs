@@ -7,7 +7,9 @@ mod linalg;
 mod module;
 mod ops;
 mod primitive;
+mod ranking;
 mod stats;
+mod transport;
 
 #[cfg(feature = "quantization")]
 mod quantization;
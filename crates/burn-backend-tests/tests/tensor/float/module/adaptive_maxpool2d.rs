@@ -0,0 +1,50 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::Tolerance;
+use burn_tensor::module::{
+    adaptive_max_pool1d_with_indices, adaptive_max_pool2d, adaptive_max_pool2d_with_indices,
+};
+
+fn input() -> TestTensor<4> {
+    TestTensor::from([[[
+        [3.0, 9.0, 1.0, 4.0, 7.0],
+        [8.0, 2.0, 6.0, 0.0, 5.0],
+        [1.0, 7.0, 3.0, 9.0, 2.0],
+        [6.0, 4.0, 8.0, 5.0, 0.0],
+    ]]])
+}
+
+#[test]
+fn test_adaptive_max_pool2d_with_indices_overlapping_windows() {
+    let y = TestTensor::<4>::from([[[[9.0, 9.0, 7.0], [7.0, 9.0, 9.0]]]]);
+    let indices = TensorData::from([[[[1, 1, 4], [11, 13, 13]]]]);
+
+    let (output, output_indices) = adaptive_max_pool2d_with_indices(input(), [2, 3]);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+    output_indices.into_data().assert_eq(&indices, false);
+}
+
+#[test]
+fn test_adaptive_max_pool2d_dyn_filter_size() {
+    let y = TestTensor::<4>::from([[[[9.0, 7.0], [8.0, 9.0], [8.0, 9.0]]]]);
+
+    let output = adaptive_max_pool2d(input(), [3, 2]);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_adaptive_max_pool1d_with_indices() {
+    let x = TestTensor::<3>::from([[[3.0, 9.0, 1.0, 4.0, 7.0, 8.0, 2.0]]]);
+    let y = TestTensor::<3>::from([[[9.0, 9.0, 8.0, 8.0]]]);
+    let indices = TensorData::from([[[1, 1, 5, 5]]]);
+
+    let (output, output_indices) = adaptive_max_pool1d_with_indices(x, 4);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+    output_indices.into_data().assert_eq(&indices, false);
+}
@@ -0,0 +1,59 @@
+use super::*;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_antialias_bilinear_downsample() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..16, &device)
+        .reshape([1, 1, 4, 4])
+        .float();
+
+    let output = interpolate(
+        x,
+        [2, 2],
+        InterpolateOptions::new(InterpolateMode::Bilinear)
+            .with_align_corners(false)
+            .with_antialias(true),
+    );
+
+    // The filter spans 3 inputs per output, with the weights [3, 3, 1] / 7 and [1, 3, 3] / 7,
+    // like `F.interpolate(x, size=(2, 2), mode="bilinear", antialias=True)`.
+    let expected = TensorData::from([[[[25.0 / 7.0, 36.0 / 7.0], [69.0 / 7.0, 80.0 / 7.0]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_antialias_bicubic_preserves_constant() {
+    let device = Default::default();
+    let x = TestTensor::<4>::ones([2, 3, 9, 7], &device);
+
+    let output = interpolate(
+        x,
+        [4, 3],
+        InterpolateOptions::new(InterpolateMode::Bicubic)
+            .with_align_corners(false)
+            .with_antialias(true),
+    );
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TestTensor::<4>::ones([2, 3, 4, 3], &device).into_data(),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+#[should_panic = "Antialiasing is only supported by the bilinear and bicubic modes"]
+fn test_antialias_nearest_unsupported() {
+    let device = Default::default();
+    let x = TestTensor::<4>::ones([1, 1, 4, 4], &device);
+
+    let _output = interpolate(
+        x,
+        [2, 2],
+        InterpolateOptions::new(InterpolateMode::Nearest).with_antialias(true),
+    );
+}
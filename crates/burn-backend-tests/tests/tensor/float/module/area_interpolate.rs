@@ -0,0 +1,20 @@
+use super::*;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_area_interpolate_downsample() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..16, &device)
+        .reshape([1, 1, 4, 4])
+        .float();
+
+    let output = interpolate(x, [2, 2], InterpolateOptions::new(InterpolateMode::Area));
+
+    // Each output value is the average of a 2x2 block.
+    let expected = TensorData::from([[[[2.5, 4.5], [10.5, 12.5]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
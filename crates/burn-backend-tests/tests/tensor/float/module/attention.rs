@@ -475,6 +475,7 @@ fn test_attention_all_options() {
         scale: Some(0.05),
         softcap: Some(30.0),
         is_causal: true,
+        sliding_window: None,
     };
 
     let output = attention(
@@ -640,3 +641,69 @@ fn test_attention_bool_mask_broadcast_batch_and_heads() {
         Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
     );
 }
+
+/// Sliding window + causal masking must match an explicit band mask, on both the fused and
+/// fallback paths. Cross-attention checks that the window is aligned like the causal boundary.
+#[test]
+fn test_attention_sliding_window_matches_explicit_mask() {
+    let [num_batches, num_heads, seq_q, seq_k, head_dim, window] = [2, 2, 8, 40, 16, 5];
+
+    let query = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_q, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let key = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_k, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let value = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_k, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+
+    // Query i sees the keys `i + offset - window + 1..=i + offset`.
+    let offset = (seq_k - seq_q) as i64;
+    let mask = TestTensorBool::<2>::tril_mask([seq_q, seq_k], offset, &Default::default())
+        .bool_or(TestTensorBool::<2>::triu_mask(
+            [seq_q, seq_k],
+            offset - window as i64 + 1,
+            &Default::default(),
+        ))
+        .reshape([1, 1, seq_q, seq_k])
+        .expand([num_batches, num_heads, seq_q, seq_k]);
+
+    let options = AttentionModuleOptions {
+        is_causal: true,
+        sliding_window: Some(window),
+        ..Default::default()
+    };
+
+    let output = attention(
+        query.clone(),
+        key.clone(),
+        value.clone(),
+        None,
+        None,
+        options,
+    );
+    let fallback = attention_fallback(
+        query.clone(),
+        key.clone(),
+        value.clone(),
+        None,
+        None,
+        options,
+    );
+    let expected = attention_fallback(query, key, value, Some(mask), None, Default::default());
+
+    let tolerance = Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1);
+    fallback
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.clone().into_data(), tolerance);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
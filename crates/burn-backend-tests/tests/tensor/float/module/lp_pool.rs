@@ -0,0 +1,39 @@
+use super::*;
+use burn_tensor::Tolerance;
+use burn_tensor::module::{lp_pool1d, lp_pool2d};
+
+#[test]
+fn test_lp_pool2d_euclidean() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..16, &device)
+        .reshape([1, 1, 4, 4])
+        .float();
+    let y = TestTensor::<4>::from([[[[6.480741, 9.899495], [21.400935, 25.337719]]]]);
+
+    let output = lp_pool2d(x, 2.0, [2, 2], [2, 2], false);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_lp_pool1d_overlapping_windows() {
+    let x = TestTensor::<3>::from([[[1.0, 2.0, 3.0, 4.0]]]);
+    let y = TestTensor::<3>::from([[[2.080084, 3.271066, 4.497941]]]);
+
+    let output = lp_pool1d(x, 3.0, 2, 1, false);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_lp_pool1d_sum() {
+    let x = TestTensor::<3>::from([[[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]]);
+    let y = TestTensor::<3>::from([[[6.0, 15.0]]]);
+
+    let output = lp_pool1d(x, 1.0, 3, 3, false);
+
+    y.to_data()
+        .assert_approx_eq::<FloatElem>(&output.into_data(), Tolerance::default());
+}
@@ -2,6 +2,9 @@ use super::*;
 
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
+mod antialias_interpolate;
+mod area_interpolate;
 mod attention;
 mod avgpool1d;
 mod avgpool2d;
@@ -18,7 +21,10 @@ mod deform_conv2d;
 mod forward;
 mod lanczos3_interpolate;
 mod linear;
+mod lp_pool;
 mod maxpool1d;
 mod maxpool2d;
 mod nearest_interpolate;
+mod pixel_shuffle;
+mod trilinear_interpolate;
 mod unfold4d;
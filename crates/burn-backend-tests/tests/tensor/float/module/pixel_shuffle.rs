@@ -0,0 +1,54 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+
+#[test]
+fn test_pixel_shuffle() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..16, &device)
+        .reshape([1, 4, 2, 2])
+        .float();
+
+    let output = pixel_shuffle(x, 2);
+    let expected = TensorData::from([[[
+        [0.0, 4.0, 1.0, 5.0],
+        [8.0, 12.0, 9.0, 13.0],
+        [2.0, 6.0, 3.0, 7.0],
+        [10.0, 14.0, 11.0, 15.0],
+    ]]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_pixel_unshuffle_inverts_pixel_shuffle() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..72, &device)
+        .reshape([2, 9, 2, 2])
+        .float();
+
+    let shuffled = pixel_shuffle(x.clone(), 3);
+    assert_eq!(shuffled.dims(), [2, 1, 6, 6]);
+
+    let output = pixel_unshuffle(shuffled, 3);
+
+    output.into_data().assert_eq(&x.into_data(), false);
+}
+
+#[test]
+fn test_pixel_unshuffle() {
+    let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]]]);
+
+    let output = pixel_unshuffle(x, 2);
+    let expected = TensorData::from([[[[0.0, 2.0]], [[1.0, 3.0]], [[4.0, 6.0]], [[5.0, 7.0]]]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+#[should_panic]
+fn test_pixel_shuffle_should_panic_when_channels_are_not_divisible() {
+    let x = TestTensor::<4>::zeros([1, 3, 2, 2], &Default::default());
+
+    let _output = pixel_shuffle(x, 2);
+}
@@ -0,0 +1,48 @@
+use super::*;
+use burn_tensor::module::interpolate3d;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_trilinear_interpolate_upsample() {
+    let device = Default::default();
+    // x[d, h, w] = 4d + 2h + w
+    let x = TestTensorInt::arange(0..8, &device)
+        .reshape([1, 1, 2, 2, 2])
+        .float();
+
+    let output = interpolate3d(
+        x,
+        [3, 3, 3],
+        InterpolateOptions::new(InterpolateMode::Bilinear),
+    );
+
+    // A linear function is reproduced exactly: output[d, h, w] = 2d + h + w / 2.
+    let expected = TensorData::from([[[
+        [[0.0, 0.5, 1.0], [1.0, 1.5, 2.0], [2.0, 2.5, 3.0]],
+        [[2.0, 2.5, 3.0], [3.0, 3.5, 4.0], [4.0, 4.5, 5.0]],
+        [[4.0, 4.5, 5.0], [5.0, 5.5, 6.0], [6.0, 6.5, 7.0]],
+    ]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_interpolate3d_nearest_downsample() {
+    let device = Default::default();
+    let x = TestTensorInt::arange(0..16, &device)
+        .reshape([1, 1, 2, 2, 4])
+        .float();
+
+    let output = interpolate3d(
+        x,
+        [1, 2, 2],
+        InterpolateOptions::new(InterpolateMode::Nearest),
+    );
+
+    let expected = TensorData::from([[[[[0.0, 2.0], [4.0, 6.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
@@ -79,3 +79,29 @@ fn clamp_min_max_vec_should_compile() {
         false,
     );
 }
+
+#[test]
+fn clamp_tensor_should_broadcast_bounds() {
+    let tensor = TestTensor::<2>::from([[1.0, 5.0, 9.0], [-1.0, 4.0, 7.0]]);
+    let min = TestTensor::<2>::from([[0.0, 0.0, 8.0]]);
+    let max = TestTensor::<2>::from([[2.0, 4.0, 10.0]]);
+
+    let output = tensor.clamp_tensor(min, max);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[1.0, 4.0, 9.0], [0.0, 4.0, 8.0]]), false);
+}
+
+#[test]
+fn clamp_tensor_should_support_int_tensors() {
+    let tensor = TestTensorInt::<1>::from([0, 3, 6, 9]);
+    let min = TestTensorInt::<1>::from([1, 1, 7, 7]);
+    let max = TestTensorInt::<1>::from([2, 5, 8, 8]);
+
+    let output = tensor.clamp_tensor(min, max);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([1, 3, 7, 8]), false);
+}
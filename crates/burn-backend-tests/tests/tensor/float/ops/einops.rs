@@ -0,0 +1,106 @@
+use super::*;
+use burn_tensor::{PatternReduction, TensorData, Tolerance};
+
+#[test]
+fn should_support_rearrange_transpose_and_merge() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::from_data(
+        [
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ],
+        &device,
+    );
+
+    let output = tensor.rearrange::<2>("b c w -> (b w) c", &[]);
+    let expected = TensorData::from([
+        [0.0, 3.0],
+        [1.0, 4.0],
+        [2.0, 5.0],
+        [6.0, 9.0],
+        [7.0, 10.0],
+        [8.0, 11.0],
+    ]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_rearrange_split() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([0.0, 1.0, 2.0, 3.0, 4.0, 5.0], &device);
+
+    let output = tensor.rearrange::<3>("(h w) -> w 1 h", &[("h", 2)]);
+    let expected = TensorData::from([[[0.0, 3.0]], [[1.0, 4.0]], [[2.0, 5.0]]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_rearrange_ellipsis() {
+    let device = Default::default();
+    let tensor = TestTensor::<4>::ones([2, 3, 4, 5], &device);
+
+    let output = tensor.rearrange::<4>("b ... c -> c b ...", &[]);
+
+    assert_eq!(output.dims(), [5, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic = "The axis 'w' is missing from the output"]
+fn should_panic_when_rearrange_drops_an_axis() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([2, 3], &device);
+
+    let _output = tensor.rearrange::<1>("h w -> h", &[]);
+}
+
+#[test]
+#[should_panic = "but the tensor rank is 2"]
+fn should_panic_when_rearrange_rank_mismatch() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([2, 3], &device);
+
+    let _output = tensor.rearrange::<3>("a b c -> c b a", &[]);
+}
+
+#[test]
+fn should_support_repeat_pattern() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+    let output = tensor.repeat_pattern::<3>("h w -> n h (w b)", &[("n", 2), ("b", 2)]);
+    let expected = TensorData::from([
+        [[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]],
+        [[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]],
+    ]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_reduce_pattern() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 5.0, 2.0, -1.0], [3.0, 0.0, 4.0, 6.0]], &device);
+
+    // 2x2 pooling of a single image.
+    let max = tensor.clone().reduce_pattern::<2>(
+        "(h p) (w q) -> h w",
+        PatternReduction::Max,
+        &[("p", 2), ("q", 2)],
+    );
+    max.into_data()
+        .assert_eq(&TensorData::from([[5.0, 6.0]]), false);
+
+    let mean = tensor
+        .clone()
+        .reduce_pattern::<1>("h w -> w", PatternReduction::Mean, &[]);
+    mean.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([2.0, 2.5, 3.0, 2.5]),
+        Tolerance::default(),
+    );
+
+    let sum = tensor.reduce_pattern::<2>("h (w q) -> q h", PatternReduction::Sum, &[("q", 2)]);
+    sum.into_data()
+        .assert_eq(&TensorData::from([[3.0, 7.0], [4.0, 6.0]]), false);
+}
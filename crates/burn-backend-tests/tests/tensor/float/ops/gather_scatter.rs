@@ -161,6 +161,115 @@ fn should_scatter_add_2d_dim1_diff_shape() {
         .assert_eq(&TensorData::from([[0.0, 1.0, 0.0], [0.0, 0.0, 4.0]]), false);
 }
 
+#[test]
+fn should_scatter_assign_2d_dim1() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]], &device);
+    let values = TestTensor::from_data([[2.0], [4.0]], &device);
+    let indices = TestTensorInt::from_ints([[1], [2]], &device);
+
+    let output = tensor.scatter(1, indices, values, IndexingUpdateOp::Assign);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[1.0, 2.0, 1.0], [1.0, 1.0, 4.0]]), false);
+}
+
+#[test]
+fn should_scatter_mul_2d_dim1() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let values = TestTensor::from_data([[2.0], [3.0]], &device);
+    let indices = TestTensorInt::from_ints([[1], [2]], &device);
+
+    let output = tensor.scatter(1, indices, values, IndexingUpdateOp::Mul);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[1.0, 4.0, 3.0], [4.0, 5.0, 18.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_scatter_min_1d() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([4.0, 5.0, 6.0], &device);
+    let values = TestTensor::from_data([1.0, 9.0], &device);
+    let indices = TestTensorInt::from_ints([2, 0], &device);
+
+    let output = tensor.scatter(0, indices, values, IndexingUpdateOp::Min);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([4.0, 5.0, 1.0]), false);
+}
+
+#[test]
+fn should_scatter_max_2d_dim0() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+    let values = TestTensor::from_data([[7.0, 1.0, 9.0]], &device);
+    let indices = TestTensorInt::from_ints([[1, 0, 0]], &device);
+
+    let output = tensor.scatter(0, indices, values, IndexingUpdateOp::Max);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[1.0, 5.0, 9.0], [7.0, 2.0, 6.0]]), false);
+}
+
+#[test]
+fn should_scatter_mean_1d_duplicates() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let values = TestTensor::from_data([3.0, 5.0, 4.0], &device);
+    let indices = TestTensorInt::from_ints([0, 0, 2], &device);
+
+    let output = tensor.scatter(0, indices, values, IndexingUpdateOp::Mean);
+
+    // The existing value is part of the mean: (1 + 3 + 5) / 3 and (3 + 4) / 2.
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([3.0, 2.0, 3.5]), false);
+}
+
+#[test]
+fn should_scatter_reduce_many_duplicates() {
+    // Every update goes to one of two positions, which must not lose updates to races.
+    let device = Default::default();
+    let positions = TestTensorInt::<1>::arange(0..512, &device);
+    let indices = positions.clone().remainder_scalar(2);
+    let values = positions.clone().float();
+    let tensor = TestTensor::<1>::from_data([100.0, -100.0], &device);
+
+    let output = |update| {
+        tensor
+            .clone()
+            .scatter(0, indices.clone(), values.clone(), update)
+            .into_data()
+    };
+
+    output(IndexingUpdateOp::Add).assert_eq(&TensorData::from([65380.0, 65436.0]), false);
+    output(IndexingUpdateOp::Min).assert_eq(&TensorData::from([0.0, -100.0]), false);
+    output(IndexingUpdateOp::Max).assert_eq(&TensorData::from([510.0, 511.0]), false);
+
+    // Multiply the first position by 2 for every multiple of 64, and the second one by 1.
+    let factors = positions
+        .remainder_scalar(64)
+        .equal_elem(0)
+        .float()
+        .add_scalar(1.0);
+    let output = TestTensor::<1>::from_data([1.0, 3.0], &device).scatter(
+        0,
+        indices,
+        factors,
+        IndexingUpdateOp::Mul,
+    );
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([256.0, 3.0]), false);
+}
+
 #[test]
 #[should_panic]
 fn scatter_should_panic_on_mismatch_of_shapes() {
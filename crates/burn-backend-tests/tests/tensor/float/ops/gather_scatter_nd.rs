@@ -354,3 +354,18 @@ fn test_gather_nd_3d_k2_partial_slices() {
         false,
     );
 }
+
+#[test]
+fn should_scatter_nd_max_duplicates() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 0.0], [0.0, 0.0]], &device);
+    let indices = TestTensorInt::<2>::from_ints([[1], [0], [1], [1]], &device);
+    let values =
+        TestTensor::<2>::from_data([[1.0, 8.0], [2.0, 3.0], [7.0, 4.0], [5.0, 6.0]], &device);
+
+    let output = tensor.scatter_nd(indices, values, IndexingUpdateOp::Max);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0, 3.0], [7.0, 8.0]]), false);
+}
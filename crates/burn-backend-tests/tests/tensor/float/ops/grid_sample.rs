@@ -124,3 +124,64 @@ fn should_pad_reflection_grid_sample_2d() {
         .to_data()
         .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
 }
+
+/// Tests nearest interpolation with default options (align_corners=false, zeros padding).
+///
+/// For a 3x3 input with grid coordinates:
+/// - (0.0, 0.0) maps to pixel (1.0, 1.0) -> center pixel = 4.0
+/// - (-0.9, 0.3) maps to pixel (-0.35, 1.45) -> nearest pixel (0, 1) = 3.0
+/// - (0.7, -0.6) maps to pixel (2.05, 0.1) -> nearest pixel (2, 0) = 2.0
+/// - (1.5, 0.0) maps to pixel (3.25, 1.0) -> out of bounds = 0.0
+#[test]
+fn should_grid_sample_2d_nearest() {
+    let device = Default::default();
+    let tensor = TestTensor::<4>::from_data(
+        [[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]],
+        &device,
+    );
+    let grid = TestTensor::<4>::from_data(
+        [[[[0.0, 0.0], [-0.9, 0.3]], [[0.7, -0.6], [1.5, 0.0]]]],
+        &device,
+    );
+
+    let output = tensor.grid_sample_2d(grid, InterpolateMode::Nearest);
+
+    let expected = TensorData::from([[[[4.0, 3.0], [2.0, 0.0]]]]);
+    output
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+/// Tests nearest interpolation with align_corners=true and the border and reflection padding.
+///
+/// Grid (1.8, -2.0) maps to pixel (2.8, -1.0), which is clamped to (2, 0) = 2.0 with border
+/// padding. Grid (1.8, 2.0) maps to pixel (2.8, 3.0), which is reflected to (1.2, 1.0) = 4.0.
+#[test]
+fn should_pad_grid_sample_2d_nearest() {
+    let device = Default::default();
+    let tensor = TestTensor::<4>::from_data(
+        [[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]],
+        &device,
+    );
+
+    let options = GridSampleOptions::new(InterpolateMode::Nearest).with_align_corners(true);
+    let grid = TestTensor::<4>::from_data([[[[1.8, -2.0]]]], &device);
+    let output = tensor.clone().grid_sample_2d(
+        grid,
+        options
+            .clone()
+            .with_padding_mode(GridSamplePaddingMode::Border),
+    );
+    output
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[[[2.0]]]]), Tolerance::default());
+
+    let grid = TestTensor::<4>::from_data([[[[1.8, 2.0]]]], &device);
+    let output = tensor.grid_sample_2d(
+        grid,
+        options.with_padding_mode(GridSamplePaddingMode::Reflection),
+    );
+    output
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[[[4.0]]]]), Tolerance::default());
+}
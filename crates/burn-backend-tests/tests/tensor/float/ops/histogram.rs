@@ -0,0 +1,49 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_support_histc() {
+    let tensor = TestTensor::<1>::from([1.0, 2.0, 1.0, 4.0, 5.0, -1.0]);
+
+    let output = tensor.histc(4, 0.0, 4.0);
+    let expected = TensorData::from([0, 2, 1, 1]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_histc_multi_dim_input() {
+    let tensor = TestTensor::<2>::from([[0.0, 0.25, 0.5], [0.75, 1.0, 0.1]]);
+
+    let output = tensor.histc(2, 0.0, 1.0);
+    let expected = TensorData::from([3, 3]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+#[should_panic]
+fn histc_should_panic_when_range_is_empty() {
+    let tensor = TestTensor::<1>::from([1.0, 2.0]);
+    let _output = tensor.histc(4, 1.0, 1.0);
+}
+
+#[test]
+fn should_support_histogramdd() {
+    let samples = TestTensor::<2>::from([[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [2.5, 0.5]]);
+
+    let output = samples.histogramdd([2, 2], [(0.0, 2.0), (0.0, 2.0)]);
+    let expected = TensorData::from([[1, 0], [1, 1]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_histogramdd_uneven_bins() {
+    let samples = TestTensor::<2>::from([[0.0, 0.0], [1.0, 3.0], [0.2, 2.9], [0.9, 1.0]]);
+
+    let output = samples.histogramdd([2, 3], [(0.0, 1.0), (0.0, 3.0)]);
+    let expected = TensorData::from([[1, 0, 1], [0, 1, 1]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
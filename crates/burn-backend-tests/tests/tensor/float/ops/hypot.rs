@@ -0,0 +1,39 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_hypot() {
+    let lhs = TestTensor::<2>::from([[3.0, -5.0], [0.0, -8.0]]);
+    let rhs = TestTensor::<2>::from([[4.0, 12.0], [0.0, 0.0]]);
+
+    let output = lhs.hypot(rhs);
+    let expected = TensorData::from([[5.0, 13.0], [0.0, 8.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_hypot_infinite_legs() {
+    let lhs = TestTensor::<1>::from([f32::INFINITY, 1.0, f32::NEG_INFINITY]);
+    let rhs = TestTensor::<1>::from([1.0, f32::NEG_INFINITY, f32::INFINITY]);
+
+    let output = lhs.hypot(rhs);
+    let expected = TensorData::from([true, true, true]);
+
+    output.is_inf().into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_copysign() {
+    let lhs = TestTensor::<1>::from([1.0, -2.0, 3.0, -4.0, 5.0]);
+    let rhs = TestTensor::<1>::from([-1.0, 1.0, 0.0, -0.0, -0.5]);
+
+    let output = lhs.copysign(rhs);
+    let expected = TensorData::from([-1.0, 2.0, 3.0, -4.0, -5.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
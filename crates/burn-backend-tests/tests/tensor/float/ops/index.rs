@@ -0,0 +1,159 @@
+use super::*;
+use burn_tensor::{IndexingUpdateOp, TensorData};
+
+#[test]
+fn should_index_leading_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::from_data(
+        [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+        &device,
+    );
+    let rows = TestTensorInt::<1>::from_data([0, 1, 1], &device);
+    let cols = TestTensorInt::<1>::from_data([1, 0, 1], &device);
+
+    let output: TestTensor<2> = tensor.index([rows, cols]);
+    let expected = TensorData::from([[3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_all_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let rows = TestTensorInt::<1>::from_data([1, 0], &device);
+    let cols = TestTensorInt::<1>::from_data([2, 1], &device);
+
+    let output: TestTensor<1> = tensor.index([rows, cols]);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([5.0, 1.0]), false);
+}
+
+#[test]
+fn should_index_with_negative_indices() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let rows = TestTensorInt::<1>::from_data([-1, 0], &device);
+    let cols = TestTensorInt::<1>::from_data([-1, -3], &device);
+
+    let output: TestTensor<1> = tensor.index([rows, cols]);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([5.0, 0.0]), false);
+}
+
+#[test]
+fn should_index_with_broadcast_indices() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let rows = TestTensorInt::<2>::from_data([[0], [1]], &device);
+    let cols = TestTensorInt::<2>::from_data([[2, 0]], &device);
+
+    let output: TestTensor<2> = tensor.index([rows, cols]);
+    let expected = TensorData::from([[2.0, 0.0], [5.0, 3.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_with_multi_dim_indices() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]], &device);
+    let rows = TestTensorInt::<2>::from_data([[2, 0], [1, 1]], &device);
+
+    let output: TestTensor<3> = tensor.index([rows]);
+    let expected = TensorData::from([[[4.0, 5.0], [0.0, 1.0]], [[2.0, 3.0], [2.0, 3.0]]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_put_values() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::zeros([2, 3], &device);
+    let rows = TestTensorInt::<1>::from_data([0, 1], &device);
+    let cols = TestTensorInt::<1>::from_data([2, 0], &device);
+    let values = TestTensor::<1>::from_data([1.0, 2.0], &device);
+
+    let output = tensor.index_put([rows, cols], values, IndexingUpdateOp::Assign);
+    let expected = TensorData::from([[0.0, 0.0, 1.0], [2.0, 0.0, 0.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_put_broadcast_rows() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([3, 2], &device);
+    let rows = TestTensorInt::<1>::from_data([0, 2], &device);
+    let values = TestTensor::<2>::from_data([[5.0, 6.0]], &device);
+
+    let output = tensor.index_put([rows], values, IndexingUpdateOp::Add);
+    let expected = TensorData::from([[6.0, 7.0], [1.0, 1.0], [6.0, 7.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_mask_leading_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::from_data(
+        [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+        &device,
+    );
+    let mask = TestTensorBool::<2>::from_data([[true, false], [true, true]], &device);
+
+    let output: TestTensor<2> = tensor.index_mask(mask);
+    let expected = TensorData::from([[1.0, 2.0], [5.0, 6.0], [7.0, 8.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_index_mask_all_dims() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let mask = tensor.clone().greater_elem(2.5);
+
+    let output: TestTensor<1> = tensor.index_mask(mask);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([3.0, 4.0, 5.0]), false);
+}
+
+#[test]
+fn should_index_put_mask_values() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([3, 2], &device);
+    let mask = TestTensorBool::<1>::from_data([true, false, true], &device);
+    let values = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+    let assigned =
+        tensor
+            .clone()
+            .index_put_mask(mask.clone(), values.clone(), IndexingUpdateOp::Assign);
+    let accumulated = tensor.index_put_mask(mask, values, IndexingUpdateOp::Add);
+
+    assigned.into_data().assert_eq(
+        &TensorData::from([[1.0, 2.0], [1.0, 1.0], [3.0, 4.0]]),
+        false,
+    );
+    accumulated.into_data().assert_eq(
+        &TensorData::from([[2.0, 3.0], [1.0, 1.0], [4.0, 5.0]]),
+        false,
+    );
+}
+
+#[test]
+#[should_panic]
+fn should_panic_index_mask_wrong_shape() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([3, 2], &device);
+    let mask = TestTensorBool::<1>::from_data([true, false], &device);
+
+    let _output: TestTensor<2> = tensor.index_mask(mask);
+}
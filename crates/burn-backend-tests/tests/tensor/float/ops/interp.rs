@@ -0,0 +1,80 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_polyval() {
+    let tensor = TestTensor::<2>::from([[1.0, -1.0], [0.5, 2.0]]);
+    let coefficients = TestTensor::<1>::from([1.0, -2.0, 0.0, 3.0]);
+
+    let output = tensor.polyval(coefficients);
+    let expected = TensorData::from([[2.0, 0.0], [2.625, 3.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_polyval_constant() {
+    let tensor = TestTensor::<1>::from([1.0, -4.0]);
+    let coefficients = TestTensor::<1>::from([7.0]);
+
+    let output = tensor.polyval(coefficients);
+    let expected = TensorData::from([7.0, 7.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_interp() {
+    let tensor = TestTensor::<1>::from([-1.0, 0.0, 0.5, 1.0, 1.5, 3.0]);
+    let xp = TestTensor::<1>::from([0.0, 1.0, 2.0]);
+    let fp = TestTensor::<1>::from([0.0, 10.0, 0.0]);
+
+    let output = tensor.interp(xp, fp);
+    let expected = TensorData::from([0.0, 0.0, 5.0, 10.0, 5.0, 0.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_interp_batched_tables() {
+    let tensor = TestTensor::<2>::from([[0.5, 2.5, -1.0], [0.25, 1.0, 4.0]]);
+    let xp = TestTensor::<2>::from([[0.0, 1.0, 2.0], [0.0, 2.0, 4.0]]);
+    let fp = TestTensor::<2>::from([[0.0, 10.0, 20.0], [1.0, 3.0, 5.0]]);
+
+    let output = tensor.interp(xp, fp);
+    let expected = TensorData::from([[5.0, 20.0, 0.0], [1.25, 2.0, 5.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_interp_shared_table() {
+    let tensor = TestTensor::<2>::from([[0.5, 1.5], [2.0, 0.0]]);
+    let xp = TestTensor::<2>::from([[0.0, 1.0, 2.0]]);
+    let fp = TestTensor::<2>::from([[0.0, 10.0, 0.0]]);
+
+    let output = tensor.interp(xp, fp);
+    let expected = TensorData::from([[5.0, 5.0], [0.0, 0.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+#[should_panic]
+fn interp_should_panic_with_a_single_sample_point() {
+    let tensor = TestTensor::<1>::from([1.0, 2.0]);
+    let xp = TestTensor::<1>::from([0.0]);
+    let fp = TestTensor::<1>::from([1.0]);
+
+    let _output = tensor.interp(xp, fp);
+}
@@ -0,0 +1,35 @@
+use super::*;
+use burn_tensor::signal::kaiser_window;
+use burn_tensor::{DType, TensorData, Tolerance};
+
+#[test]
+fn should_support_kaiser_window_symmetric() {
+    let tensor: TestTensor<1> = kaiser_window(6, 5.0, false, &Default::default());
+    let expected = TensorData::from([0.036711, 0.414904, 0.913812, 0.913812, 0.414904, 0.036711]);
+
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_kaiser_window_periodic() {
+    let tensor: TestTensor<1> = kaiser_window(6, 5.0, true, &Default::default());
+    let expected = TensorData::from([0.036711, 0.328202, 0.775322, 1.0, 0.775322, 0.328202]);
+
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_kaiser_window_options_dtype() {
+    let tensor: TestTensor<1> = kaiser_window(4, 12.0, true, (&Default::default(), DType::F32));
+    assert_eq!(tensor.dtype(), DType::F32);
+}
+
+#[test]
+fn should_support_kaiser_window_empty() {
+    let tensor: TestTensor<1> = kaiser_window(0, 12.0, true, &Default::default());
+    assert_eq!(tensor.shape().dims(), [0]);
+}
@@ -0,0 +1,47 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_lerp() {
+    let start = TestTensor::<1>::from([0.0, 1.0, 2.0, 4.0]);
+    let end = TestTensor::<1>::from([10.0, 1.0, -2.0, 8.0]);
+    let weight = TestTensor::<1>::from([0.5, 0.3, 1.0, 0.25]);
+
+    let output = start.lerp(end, weight);
+    let expected = TensorData::from([5.0, 1.0, -2.0, 5.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_lerp_broadcast_weight() {
+    let start = TestTensor::<2>::from([[0.0, 4.0]]);
+    let end = TestTensor::<2>::from([[2.0, 8.0]]);
+    let weight = TestTensor::<2>::from([[0.25], [0.75]]);
+
+    let output = start.lerp(end, weight);
+    let expected = TensorData::from([[0.5, 5.0], [1.5, 7.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_lerp_scalar() {
+    let average = TestTensor::<1>::from([1.0, 2.0]);
+    let value = TestTensor::<1>::from([3.0, 6.0]);
+
+    let output = average.clone().lerp_scalar(value.clone(), 0.25);
+    let expected = TensorData::from([1.5, 3.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+
+    let output = average.lerp_scalar(value.clone(), 1.0);
+
+    output.into_data().assert_eq(&value.into_data(), false);
+}
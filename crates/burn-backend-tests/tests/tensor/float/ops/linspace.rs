@@ -0,0 +1,60 @@
+use super::*;
+use burn_tensor::{DType, TensorData, Tolerance};
+
+#[test]
+fn should_support_arange_float() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::arange_float(0.0..1.0, 0.25, &device);
+    let decreasing = TestTensor::<1>::arange_float(1.0..-0.1, -0.5, &device);
+
+    tensor.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([0.0, 0.25, 0.5, 0.75]),
+        Tolerance::default(),
+    );
+    decreasing
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([1.0, 0.5, 0.0]), Tolerance::default());
+}
+
+#[test]
+fn should_support_arange_float_empty() {
+    let tensor = TestTensor::<1>::arange_float(1.0..0.0, 0.5, &Default::default());
+
+    assert_eq!(tensor.dims(), [0]);
+}
+
+#[test]
+fn should_support_linspace() {
+    let tensor = TestTensor::<1>::linspace(-1.0, 1.0, 5, &Default::default());
+    let expected = TensorData::from([-1.0, -0.5, 0.0, 0.5, 1.0]);
+
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_linspace_single_step() {
+    let tensor = TestTensor::<1>::linspace(3.0, 7.0, 1, &Default::default());
+
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([3.0]), Tolerance::default());
+}
+
+#[test]
+fn should_support_linspace_options_dtype() {
+    let tensor = TestTensor::<1>::linspace(0.0, 1.0, 3, (&Default::default(), DType::F32));
+
+    assert_eq!(tensor.dtype(), DType::F32);
+}
+
+#[test]
+fn should_support_logspace() {
+    let tensor = TestTensor::<1>::logspace(0.0, 3.0, 4, 2.0, &Default::default());
+    let expected = TensorData::from([1.0, 2.0, 4.0, 8.0]);
+
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
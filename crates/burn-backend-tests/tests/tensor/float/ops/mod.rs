@@ -20,6 +20,7 @@ mod cross;
 mod cumulative;
 mod div;
 mod dot;
+mod einops;
 mod erf;
 mod exp;
 mod expand;
@@ -34,9 +35,16 @@ mod gather_scatter_nd;
 mod grid_sample;
 mod hamming_window;
 mod hann_window;
+mod histogram;
+mod hypot;
+mod index;
 mod inf;
 mod init;
+mod interp;
 mod iter_dim;
+mod kaiser_window;
+mod lerp;
+mod linspace;
 mod log;
 mod log1p;
 mod mask;
@@ -59,12 +67,15 @@ mod remainder;
 mod repeat;
 mod repeat_dim;
 mod reshape;
+mod roll;
 mod round;
+mod segment;
 mod select;
 mod sign;
 mod slice;
 mod slice_assign;
 mod sort_argsort;
+mod special;
 mod split;
 mod sqrt;
 mod square;
@@ -248,6 +248,47 @@ fn padding_edge_asymmetric_test() {
     padded_tensor.into_data().assert_eq(&expected, false);
 }
 
+#[test]
+fn padding_circular_2d_test() {
+    // Test circular padding on a 2D tensor
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let padded_tensor = tensor.pad((1, 1, 1, 1), PadMode::Circular);
+
+    // Circular padding wraps values around from the opposite boundary
+    let expected = TensorData::from([
+        [6.0, 4.0, 5.0, 6.0, 4.0],
+        [3.0, 1.0, 2.0, 3.0, 1.0],
+        [6.0, 4.0, 5.0, 6.0, 4.0],
+        [3.0, 1.0, 2.0, 3.0, 1.0],
+    ]);
+    padded_tensor.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn padding_circular_width_only_test() {
+    // Test circular padding on width dimension only
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+
+    let padded_tensor = tensor.pad((2, 4, 0, 0), PadMode::Circular);
+
+    // Input: [1, 2, 3, 4]
+    // Left 2: [3, 4]
+    // Right 4 (whole dimension): [1, 2, 3, 4]
+    // Result: [3, 4, 1, 2, 3, 4, 1, 2, 3, 4]
+    let expected = TensorData::from([[3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]]);
+    padded_tensor.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+#[should_panic(expected = "Circular padding")]
+fn padding_circular_exceeds_dimension_test() {
+    // Test that circular padding panics when padding > dim_size
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0]]);
+
+    let _ = tensor.pad((0, 4, 0, 0), PadMode::Circular);
+}
+
 #[test]
 fn padding_zero_padding_test() {
     // Test that zero padding returns the original tensor unchanged
@@ -256,11 +297,13 @@ fn padding_zero_padding_test() {
     let padded_constant = tensor.clone().pad((0, 0, 0, 0), PadMode::Constant(5.0));
     let padded_reflect = tensor.clone().pad((0, 0, 0, 0), PadMode::Reflect);
     let padded_edge = tensor.clone().pad((0, 0, 0, 0), PadMode::Edge);
+    let padded_circular = tensor.clone().pad((0, 0, 0, 0), PadMode::Circular);
 
     let expected = TensorData::from([[1.0, 2.0], [3.0, 4.0]]);
     padded_constant.into_data().assert_eq(&expected, false);
     padded_reflect.into_data().assert_eq(&expected, false);
     padded_edge.into_data().assert_eq(&expected, false);
+    padded_circular.into_data().assert_eq(&expected, false);
 }
 
 #[test]
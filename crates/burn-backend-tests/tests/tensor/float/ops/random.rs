@@ -51,3 +51,66 @@ fn test_seed_reproducibility() {
     t1.into_data()
         .assert_approx_eq::<FloatElem>(&t2.into_data(), Tolerance::default());
 }
+
+/// Asserts that the mean of the samples is within `tolerance` of `expected`, relatively.
+fn assert_mean(tensor: TestTensor<1>, expected: f32, tolerance: f32) {
+    let mean = tensor.mean().into_scalar::<f32>();
+    assert!(
+        (mean - expected).abs() <= tolerance * expected,
+        "Expected a mean of {expected}, got {mean}"
+    );
+}
+
+#[test]
+fn rand_gamma() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::random([4096], Distribution::Gamma(2., 3.), &device);
+
+    let positive = tensor.clone().greater_elem(0.).all().into_scalar::<bool>();
+    assert!(positive);
+    assert_mean(tensor, 6., 0.1);
+
+    // Concentrations below one are boosted.
+    let tensor = TestTensor::<1>::random([4096], Distribution::Gamma(0.5, 1.), &device);
+    assert_mean(tensor, 0.5, 0.1);
+}
+
+#[test]
+fn rand_beta() {
+    let tensor = TestTensor::<1>::random([4096], Distribution::Beta(2., 6.), &Default::default());
+
+    tensor
+        .clone()
+        .into_data()
+        .assert_within_range_inclusive(0.elem::<FloatElem>()..=1.elem::<FloatElem>());
+    assert_mean(tensor, 0.25, 0.1);
+}
+
+#[test]
+fn rand_poisson() {
+    let device = Default::default();
+
+    for rate in [3., 50.] {
+        let tensor = TestTensor::<1>::random([4096], Distribution::Poisson(rate), &device);
+
+        let integral = tensor
+            .clone()
+            .equal(tensor.clone().round())
+            .all()
+            .into_scalar::<bool>();
+        assert!(integral);
+        assert_mean(tensor, rate as f32, 0.1);
+    }
+}
+
+#[test]
+fn rand_dirichlet() {
+    let concentration = TestTensor::<2>::from([[1.0, 2.0, 3.0], [0.5, 0.5, 0.5]]);
+
+    let samples = TestTensor::random_dirichlet(concentration);
+
+    samples
+        .sum_dim(1)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[1.0], [1.0]]), Tolerance::permissive());
+}
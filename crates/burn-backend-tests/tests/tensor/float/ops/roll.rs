@@ -0,0 +1,35 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn roll_float() {
+    let device = Default::default();
+    let tensor = TestTensorInt::<1>::arange(0..6, &device)
+        .reshape([2, 3])
+        .float();
+
+    tensor
+        .clone()
+        .roll(&[1, 1], &[0, 1])
+        .into_data()
+        .assert_eq(&TensorData::from([[4.0, 5.0, 3.0], [1.0, 2.0, 0.0]]), false);
+
+    tensor
+        .roll_dim(-1, 1)
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0, 0.0, 1.0], [5.0, 3.0, 4.0]]), false);
+}
+
+#[test]
+fn roll_float_non_contiguous() {
+    let device = Default::default();
+    let tensor = TestTensorInt::<1>::arange(0..6, &device)
+        .reshape([2, 3])
+        .float()
+        .transpose();
+
+    tensor
+        .roll(&[2], &[0])
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0, 5.0], [0.0, 3.0], [1.0, 4.0]]), false);
+}
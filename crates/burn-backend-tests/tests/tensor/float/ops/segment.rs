@@ -0,0 +1,69 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_segment_sum() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data(
+        [[1.0, -6.0], [3.0, 4.0], [5.0, -2.0], [-7.0, 0.0], [2.0, 1.0]],
+        &device,
+    );
+    let segment_ids = TestTensorInt::from_ints([1, 0, 1, 3, 1], &device);
+
+    let output = tensor.segment_sum(segment_ids, 4);
+    let expected = TensorData::from([[3.0, 4.0], [8.0, -7.0], [0.0, 0.0], [-7.0, 0.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_segment_mean() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 9.0]], &device);
+    let segment_ids = TestTensorInt::from_ints([1, 0, 1], &device);
+
+    let output = tensor.segment_mean(segment_ids, 3);
+    let expected = TensorData::from([[3.0, 4.0], [3.0, 5.5], [0.0, 0.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_segment_max() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data(
+        [[1.0, -6.0], [3.0, 4.0], [5.0, -2.0], [-7.0, 0.0], [2.0, 1.0]],
+        &device,
+    );
+    let segment_ids = TestTensorInt::from_ints([1, 0, 1, 3, 1], &device);
+
+    let output = tensor.segment_max(segment_ids, 4);
+    let expected = TensorData::from([[3.0, 4.0], [5.0, 1.0], [0.0, 0.0], [-7.0, 0.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_segment_max_1d() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([4.0, 1.0, 9.0, 2.0, 7.0, 3.0], &device);
+    let segment_ids = TestTensorInt::from_ints([2, 0, 2, 0, 2, 1], &device);
+
+    let output = tensor.segment_max(segment_ids, 3);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([2.0, 3.0, 9.0]), false);
+}
+
+#[test]
+#[should_panic]
+fn segment_sum_should_panic_on_mismatch_of_shapes() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let segment_ids = TestTensorInt::from_ints([0, 1], &device);
+
+    let _output = tensor.segment_sum(segment_ids, 2);
+}
@@ -119,6 +119,32 @@ fn should_select_add_2d_dim1() {
     output.into_data().assert_eq(&expected, false);
 }
 
+#[test]
+fn should_select_max_2d_dim0() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let values = TestTensor::from_data([[1.0, 8.0, 1.0], [9.0, 0.0, 9.0]], &device);
+    let indices = TestTensorInt::from_data(TensorData::from([1, 0]), &device);
+
+    let output = tensor.select_assign(0, indices, values, IndexingUpdateOp::Max);
+    let expected = TensorData::from([[9.0, 1.0, 9.0], [3.0, 8.0, 5.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_select_mean_2d_dim1_duplicates() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+    let values = TestTensor::from_data([[1.0, 2.0], [4.0, 5.0]], &device);
+    let indices = TestTensorInt::from_data(TensorData::from([0, 0]), &device);
+
+    let output = tensor.select_assign(1, indices, values, IndexingUpdateOp::Mean);
+    let expected = TensorData::from([[1.0, 1.0, 2.0], [4.0, 4.0, 5.0]]);
+
+    output.into_data().assert_eq(&expected, false);
+}
+
 #[test]
 fn should_select_3d_dim1_vec() {
     let device = Default::default();
@@ -589,3 +589,41 @@ fn test_slice_with_steps_3d() {
     ]);
     sliced.into_data().assert_eq(&expected, false);
 }
+
+#[test]
+fn test_slice_with_python_bounds() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data(
+        [[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]],
+        &device,
+    );
+
+    // x[:, 3:0:-2]
+    let sliced = tensor
+        .clone()
+        .slice([Slice::full(), Slice::python(Some(3), Some(0), -2)]);
+    let expected = TensorData::from([[3.0, 1.0], [8.0, 6.0]]);
+    sliced.into_data().assert_eq(&expected, false);
+
+    // x[::-1, -2:]
+    let slices = [
+        Slice::parse_python("::-1").unwrap(),
+        Slice::parse_python("-2:").unwrap(),
+    ];
+    let sliced = tensor.slice(slices);
+    let expected = TensorData::from([[8.0, 9.0], [3.0, 4.0]]);
+    sliced.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_slice_of_reversed_slice() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([0.0, 1.0, 2.0, 3.0, 4.0, 5.0], &device);
+
+    // Slicing and computing on a reversed view
+    let reversed = tensor.slice([s![1..;-1]]);
+    let sliced = reversed.clone().slice([s![1..4;2]]) + reversed.slice([s![0..2]]);
+
+    let expected = TensorData::from([9.0, 6.0]);
+    sliced.into_data().assert_eq(&expected, false);
+}
@@ -214,3 +214,24 @@ fn test_sort_descending_1d() {
         .into_data()
         .assert_approx_eq::<FloatElem>(&values_expected, Tolerance::default());
 }
+
+#[test]
+fn test_sort_stable_with_indices_keeps_ties_in_order() {
+    let tensor = TestTensor::<2>::from([[2., 1., 2., 1., 2., 0.], [1., 1., 1., 1., 1., 1.]]);
+
+    let (values, indices) = tensor.clone().sort_stable_with_indices(1, false);
+    values.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[0., 1., 1., 2., 2., 2.], [1., 1., 1., 1., 1., 1.]]),
+        Tolerance::default(),
+    );
+    indices.into_data().assert_eq(
+        &TensorData::from([[5, 1, 3, 0, 2, 4], [0, 1, 2, 3, 4, 5]]),
+        false,
+    );
+
+    let indices = tensor.argsort_stable(1, true);
+    indices.into_data().assert_eq(
+        &TensorData::from([[0, 2, 4, 1, 3, 5], [0, 1, 2, 3, 4, 5]]),
+        false,
+    );
+}
@@ -0,0 +1,107 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+fn tolerance() -> Tolerance<FloatElem> {
+    Tolerance::default().set_half_precision_relative(2e-2)
+}
+
+#[test]
+fn should_support_erfinv_ops() {
+    let tensor = TestTensor::<1>::from([0.0, 0.5, -0.9, 0.99]);
+
+    let output = tensor.erfinv();
+    let expected = TensorData::from([0.0, 0.4769363, -1.1630872, 1.8213864]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_erfinv_bounds() {
+    let tensor = TestTensor::<1>::from([1.0, -1.0]);
+
+    let output = tensor.erfinv().into_data();
+    let values = output.iter::<f32>().collect::<Vec<_>>();
+
+    assert_eq!(values, [f32::INFINITY, f32::NEG_INFINITY]);
+}
+
+#[test]
+fn should_support_erfinv_erf_roundtrip() {
+    let tensor = TestTensor::<2>::from([[-0.7, -0.2, 0.1], [0.4, 0.8, 0.95]]);
+
+    let output = tensor.clone().erfinv().erf();
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance());
+}
+
+#[test]
+fn should_support_lgamma_ops() {
+    let tensor = TestTensor::<1>::from([0.5, 1.0, 2.5, 10.0, -0.5, -2.3]);
+
+    let output = tensor.lgamma();
+    let expected = TensorData::from([0.5723649, 0.0, 0.2846829, 12.8018275, 1.2655121, 0.3695667]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_digamma_ops() {
+    let tensor = TestTensor::<1>::from([1.0, 0.5, 2.5, 10.0, -0.5]);
+
+    let output = tensor.digamma();
+    let expected = TensorData::from([-0.5772157, -1.9635100, 0.7031566, 2.2517526, 0.0364900]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_i0_ops() {
+    let tensor = TestTensor::<1>::from([0.0, 1.0, -2.0, 5.0]);
+
+    let output = tensor.i0();
+    let expected = TensorData::from([1.0, 1.266066, 2.279585, 27.239872]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_gammainc_ops() {
+    let a = TestTensor::<1>::from([1.0, 2.0, 0.5, 5.0]);
+    let x = TestTensor::<1>::from([1.0, 1.0, 2.0, 10.0]);
+
+    let lower = a.clone().gammainc(x.clone());
+    let upper = a.gammaincc(x);
+
+    lower.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([0.6321206, 0.2642411, 0.9544997, 0.9707473]),
+        tolerance(),
+    );
+    upper.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([0.3678794, 0.7357589, 0.0455003, 0.0292527]),
+        tolerance(),
+    );
+}
+
+#[test]
+fn should_support_betainc_ops() {
+    let a = TestTensor::<1>::from([2.0, 1.0, 0.5, 5.0]);
+    let b = TestTensor::<1>::from([3.0, 1.0, 0.5, 2.0]);
+    let x = TestTensor::<1>::from([0.4, 0.3, 0.5, 0.9]);
+
+    let output = a.betainc(b, x);
+    let expected = TensorData::from([0.5248, 0.3, 0.5, 0.885735]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
@@ -19,3 +19,26 @@ fn test_topk_with_indices_3d() {
 
     indices.into_data().assert_eq(&indices_expected, false);
 }
+
+#[test]
+fn test_topk_with_indices_sorted() {
+    let tensor = TestTensor::<2>::from([[1., 3., 2., 3.], [4., 0., 4., 4.]]);
+
+    let (values, indices) = tensor.clone().topk_with_indices_sorted(3, 1, true);
+    values.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[3., 3., 2.], [4., 4., 4.]]),
+        Tolerance::default(),
+    );
+    indices
+        .into_data()
+        .assert_eq(&TensorData::from([[1, 3, 2], [0, 2, 3]]), false);
+
+    let (values, indices) = tensor.topk_with_indices_sorted(2, 0, false);
+    values.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1., 3., 2., 3.], [4., 0., 4., 4.]]),
+        Tolerance::default(),
+    );
+    indices
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 0, 0, 0], [1, 1, 1, 1]]), false);
+}
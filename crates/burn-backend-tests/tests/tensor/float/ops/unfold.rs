@@ -66,3 +66,40 @@ fn test_unfold_reshape_overlap() {
 
     data.assert_eq(&expected, false);
 }
+
+#[test]
+fn test_fold_inverse_of_unfold() {
+    let device = Default::default();
+    let input = TestTensor::<3>::random([2, 6, 4], Distribution::Default, &device);
+
+    let windows: TestTensor<4> = input.clone().unfold(1, 2, 2);
+    let folded: TestTensor<3> = windows.fold(1, 6, 2);
+
+    folded.into_data().assert_eq(&input.into_data(), true);
+}
+
+#[test]
+fn test_fold_sums_overlapping_windows() {
+    let device = Default::default();
+    let windows = TestTensor::<3>::from_data(
+        [[[1., 2., 3.], [3., 4., 5.]], [[6., 7., 8.], [8., 9., 10.]]],
+        &device,
+    );
+
+    // Two windows of size 3 with a step of 2, and a trailing position not covered by any window.
+    let folded: TestTensor<2> = windows.fold(1, 6, 2);
+
+    folded.into_data().assert_eq(
+        &TensorData::from([[1., 2., 6., 4., 5., 0.], [6., 7., 16., 9., 10., 0.]]),
+        false,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_fold_should_panic_when_length_is_too_small() {
+    let device = Default::default();
+    let windows = TestTensor::<2>::ones([2, 3], &device);
+
+    let _folded: TestTensor<1> = windows.fold(0, 4, 2);
+}
@@ -0,0 +1,3 @@
+use super::*;
+
+pub(crate) mod neural_sort;
@@ -0,0 +1,80 @@
+use super::*;
+use burn_tensor::ranking::{neural_sort, soft_rank, soft_sort, soft_top_k};
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_neural_sort_low_temperature_is_permutation() {
+    let scores = TestTensor::<2>::from([[0.5, 3.0, -1.0]]);
+
+    let permutation = neural_sort(scores, 1e-2, true);
+
+    permutation.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_soft_sort_low_temperature() {
+    let tensor = TestTensor::<3>::from([[[0.5, 3.0, -1.0], [2.0, 1.0, 4.0]]]);
+
+    let ascending = soft_sort(tensor.clone(), 1e-2, false);
+    let descending = soft_sort(tensor, 1e-2, true);
+
+    ascending.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[-1.0, 0.5, 3.0], [1.0, 2.0, 4.0]]]),
+        Tolerance::default(),
+    );
+    descending.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[3.0, 0.5, -1.0], [4.0, 2.0, 1.0]]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_soft_sort_high_temperature_averages() {
+    let tensor = TestTensor::<1>::from([0.5, 3.0, -1.0]);
+
+    let sorted = soft_sort(tensor, 1e6, false);
+
+    sorted.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([2.5 / 3.0, 2.5 / 3.0, 2.5 / 3.0]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_soft_rank_low_temperature() {
+    let tensor = TestTensor::<2>::from([[0.5, 3.0, -1.0], [2.0, 1.0, 4.0]]);
+
+    let ranks = soft_rank(tensor, 1e-2, false);
+
+    ranks.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, 2.0, 0.0], [1.0, 0.0, 2.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_soft_top_k() {
+    let tensor = TestTensor::<2>::from([[0.5, 3.0, -1.0, 2.0]]);
+
+    let hard = soft_top_k(tensor.clone(), 2, 1e-2);
+    let soft = soft_top_k(tensor, 2, 1.0);
+
+    hard.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[0.0, 1.0, 0.0, 1.0]]),
+        Tolerance::default(),
+    );
+    soft.sum()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([2.0]), Tolerance::default());
+}
+
+#[test]
+#[should_panic = "temperature must be positive"]
+fn test_neural_sort_invalid_temperature() {
+    let scores = TestTensor::<2>::from([[0.5, 3.0]]);
+
+    let _permutation = neural_sort(scores, 0.0, true);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use burn_tensor::TensorData;
 
 #[test]
 fn test_eye_float() {
@@ -15,3 +16,15 @@ fn test_eye_int() {
     let rhs = TestTensorInt::<2>::eye(3, &device);
     assert_eq!(tensor.to_data(), rhs.to_data());
 }
+
+#[test]
+fn test_eye_batched_rectangular() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::eye_batched([2, 2, 3], &device);
+    let expected = TensorData::from([
+        [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    ]);
+
+    tensor.into_data().assert_eq(&expected, false);
+}
@@ -4,4 +4,6 @@ mod cov;
 mod display;
 mod eye;
 mod median;
+mod mode;
+mod quantile;
 mod var;
@@ -0,0 +1,28 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn test_mode_dim() {
+    let tensor = TestTensor::<2>::from([[1.0, 3.0, 2.0, 3.0], [0.5, 0.5, -1.0, -1.0]]);
+
+    let (values, indices) = tensor.clone().mode_dim_with_indices(1);
+    let expected = TensorData::from([[3.0], [-1.0]]).convert::<FloatElem>();
+    values.into_data().assert_eq(&expected, false);
+
+    // Gathering with the indices yields the mode values
+    let gathered = tensor.clone().gather(1, indices);
+    gathered.into_data().assert_eq(&expected, false);
+
+    let output = tensor.mode_dim(0);
+    let expected = TensorData::from([[0.5, 0.5, -1.0, -1.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_median_dim() {
+    let tensor = TestTensor::<2>::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+
+    let output = tensor.median_dim(-1);
+    let expected = TensorData::from([[0.2], [0.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+}
@@ -0,0 +1,67 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::Tolerance;
+use burn_tensor::ops::QuantileInterpolation;
+
+fn tensor() -> TestTensor<2> {
+    TestTensor::from([[1.0, 4.0, 2.0, 3.0], [-1.0, 0.0, 10.0, 5.0]])
+}
+
+#[test]
+fn test_quantile_linear() {
+    let output = tensor().quantile(0.5, 1, QuantileInterpolation::Linear);
+    let expected = TensorData::from([[2.5], [2.5]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+
+    let output = tensor().quantile(0.25, 1, QuantileInterpolation::Linear);
+    let expected = TensorData::from([[1.75], [-0.25]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_quantile_lower_higher() {
+    let output = tensor().quantile(0.5, 1, QuantileInterpolation::Lower);
+    let expected = TensorData::from([[2.0], [0.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+
+    let output = tensor().quantile(0.5, 1, QuantileInterpolation::Higher);
+    let expected = TensorData::from([[3.0], [5.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_quantile_nearest_midpoint() {
+    // Position 1.5 rounds to the even index 2.
+    let output = tensor().quantile(0.5, 1, QuantileInterpolation::Nearest);
+    let expected = TensorData::from([[3.0], [5.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+
+    let output = tensor().quantile(0.5, 1, QuantileInterpolation::Midpoint);
+    let expected = TensorData::from([[2.5], [2.5]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_quantile_bounds() {
+    let output = tensor().quantile(0.0, 0, QuantileInterpolation::Linear);
+    let expected = TensorData::from([[-1.0, 0.0, 2.0, 3.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+
+    let output = tensor().quantile(1.0, 0, QuantileInterpolation::Linear);
+    let expected = TensorData::from([[1.0, 4.0, 10.0, 5.0]]).convert::<FloatElem>();
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+#[should_panic]
+fn test_quantile_out_of_range() {
+    let _output = tensor().quantile(1.5, 1, QuantileInterpolation::Linear);
+}
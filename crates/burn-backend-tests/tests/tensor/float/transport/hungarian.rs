@@ -0,0 +1,57 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::transport::{linear_sum_assignment, linear_sum_assignment_batched};
+
+#[test]
+fn test_linear_sum_assignment_square() {
+    let cost = TestTensor::<2>::from([[4.0, 1.0, 3.0], [2.0, 0.0, 5.0], [3.0, 2.0, 2.0]]);
+
+    let (rows, columns) = linear_sum_assignment(cost);
+
+    rows.into_data()
+        .assert_eq(&TensorData::from([0, 1, 2]), false);
+    columns
+        .into_data()
+        .assert_eq(&TensorData::from([1, 0, 2]), false);
+}
+
+#[test]
+fn test_linear_sum_assignment_more_rows_than_columns() {
+    let cost = TestTensor::<2>::from([[1.0, 2.0], [0.0, 3.0], [5.0, 1.0]]);
+
+    let (rows, columns) = linear_sum_assignment(cost);
+
+    rows.into_data().assert_eq(&TensorData::from([1, 2]), false);
+    columns
+        .into_data()
+        .assert_eq(&TensorData::from([0, 1]), false);
+}
+
+#[test]
+fn test_linear_sum_assignment_more_columns_than_rows() {
+    let cost = TestTensor::<2>::from([[3.0, 1.0, 0.5, 4.0], [0.2, 2.0, 0.1, 5.0]]);
+
+    let (rows, columns) = linear_sum_assignment(cost);
+
+    // 0.5 + 0.2 is cheaper than 1.0 + 0.1.
+    rows.into_data().assert_eq(&TensorData::from([0, 1]), false);
+    columns
+        .into_data()
+        .assert_eq(&TensorData::from([2, 0]), false);
+}
+
+#[test]
+fn test_linear_sum_assignment_batched() {
+    let cost = TestTensor::<3>::from([
+        [[4.0, 1.0, 3.0], [2.0, 0.0, 5.0], [3.0, 2.0, 2.0]],
+        [[0.0, 1.0, 1.0], [1.0, 1.0, 0.0], [1.0, 0.0, 1.0]],
+    ]);
+
+    let (rows, columns) = linear_sum_assignment_batched(cost);
+
+    rows.into_data()
+        .assert_eq(&TensorData::from([[0, 1, 2], [0, 1, 2]]), false);
+    columns
+        .into_data()
+        .assert_eq(&TensorData::from([[1, 0, 2], [0, 2, 1]]), false);
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+pub(crate) mod hungarian;
+pub(crate) mod sinkhorn;
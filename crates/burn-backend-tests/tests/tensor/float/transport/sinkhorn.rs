@@ -0,0 +1,37 @@
+use super::*;
+use burn_tensor::transport::sinkhorn;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_sinkhorn_plan_marginals() {
+    let cost = TestTensor::<3>::from([[[0.0, 1.0, 2.0], [1.0, 0.5, 1.0]]]);
+    let source = TestTensor::<2>::from([[0.25, 0.75]]);
+    let target = TestTensor::<2>::from([[0.5, 0.25, 0.25]]);
+
+    let plan = sinkhorn(cost, source, target, 0.5, 200);
+
+    plan.clone()
+        .sum_dim(2)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[[0.25], [0.75]]]), Tolerance::default());
+    plan.sum_dim(1).into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[0.5, 0.25, 0.25]]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_sinkhorn_small_regularization_is_permutation() {
+    // The optimal transport between uniform weights is the optimal assignment.
+    let cost = TestTensor::<3>::from([[[4.0, 1.0, 3.0], [2.0, 0.0, 5.0], [3.0, 2.0, 2.0]]]);
+    let uniform = TestTensor::<2>::from([[1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0]]);
+
+    let plan = sinkhorn(cost, uniform.clone(), uniform, 1e-2, 500);
+
+    plan.mul_scalar(3.0)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([[[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]]),
+            Tolerance::absolute(1e-3),
+        );
+}
@@ -171,3 +171,26 @@ fn should_apply_bitwise_right_shift_scalar_2d() {
         .into_data()
         .assert_eq(&TensorData::from([[0, 1, 1], [2, 0, 2]]), false);
 }
+
+#[test]
+fn should_apply_popcount() {
+    let tensor = TestTensorInt::<2>::from([[0, 1, 7, 8], [15, 63, 85, 127]]);
+
+    let output = tensor.popcount();
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 1, 3, 1], [4, 6, 4, 7]]), false);
+}
+
+#[test]
+fn should_apply_popcount_negative() {
+    let tensor = TestTensorInt::<1>::from([-1, -2]);
+    let bits = tensor.dtype().size() as i64 * 8;
+
+    let output = tensor.popcount();
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([bits, bits - 1]), false);
+}
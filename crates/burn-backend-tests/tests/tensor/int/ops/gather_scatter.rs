@@ -67,3 +67,52 @@ fn should_scatter_add_1d_int() {
         .into_data()
         .assert_eq(&TensorData::from([4, 5, 3]), false);
 }
+
+#[test]
+fn should_scatter_max_1d_int() {
+    let device = Default::default();
+    let tensor = TestTensorInt::<1>::from_ints([1, 5, 3], &device);
+    let values = TestTensorInt::from_ints([4, 4], &device);
+    let indices = TestTensorInt::from_ints([0, 1], &device);
+
+    let output = tensor.scatter(0, indices, values, IndexingUpdateOp::Max);
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([4, 5, 3]), false);
+}
+
+#[test]
+fn should_scatter_reduce_many_duplicates_int() {
+    // Every update goes to one of two positions, which must not lose updates to races.
+    let device = Default::default();
+    let positions = TestTensorInt::<1>::arange(0..512, &device);
+    let indices = positions.clone().remainder_scalar(2);
+    let tensor = TestTensorInt::<1>::from_ints([100, -100], &device);
+
+    let output = |update| {
+        tensor
+            .clone()
+            .scatter(0, indices.clone(), positions.clone(), update)
+            .into_data()
+    };
+
+    output(IndexingUpdateOp::Add).assert_eq(&TensorData::from([65380, 65436]), false);
+    output(IndexingUpdateOp::Min).assert_eq(&TensorData::from([0, -100]), false);
+    output(IndexingUpdateOp::Max).assert_eq(&TensorData::from([510, 511]), false);
+}
+
+#[test]
+fn should_scatter_mean_1d_int() {
+    let device = Default::default();
+    let tensor = TestTensorInt::<1>::from_ints([1, 2, 3], &device);
+    let values = TestTensorInt::from_ints([2, 4, 8], &device);
+    let indices = TestTensorInt::from_ints([0, 0, 2], &device);
+
+    let output = tensor.scatter(0, indices, values, IndexingUpdateOp::Mean);
+
+    // Integer division of (1 + 2 + 4) / 3 and (3 + 8) / 2.
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([2, 2, 5]), false);
+}
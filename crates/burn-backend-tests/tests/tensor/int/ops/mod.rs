@@ -24,6 +24,7 @@ mod gather_scatter_nd;
 mod init;
 mod mask;
 mod matmul;
+mod mode;
 mod movedim;
 mod mul;
 mod one_hot;
@@ -0,0 +1,29 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn test_int_mode_dim() {
+    let tensor = TestTensorInt::<2>::from([[1, 3, 3, 2], [4, 4, 1, 1]]);
+
+    let output = tensor.mode_dim(1);
+    let expected = TensorData::from([[3], [1]]);
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_int_mode_dim_3d() {
+    let tensor = TestTensorInt::<3>::from([[[1, 2], [1, 3], [2, 3]], [[5, 5], [6, 5], [6, 7]]]);
+
+    let output = tensor.mode_dim(1);
+    let expected = TensorData::from([[[1, 3]], [[6, 5]]]);
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn test_int_median_dim() {
+    let tensor = TestTensorInt::<2>::from([[1, 5, 3, 2], [8, 4, 6, 7]]);
+
+    let output = tensor.median_dim(1);
+    let expected = TensorData::from([[2], [6]]);
+    output.into_data().assert_eq(&expected, false);
+}
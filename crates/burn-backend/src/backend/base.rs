@@ -11,7 +11,7 @@ use enumset::{EnumSet, EnumSetType};
 #[cfg(feature = "distributed")]
 use crate::distributed::{DistributedParamId, DistributedParams};
 
-use super::DeviceOps;
+use super::{DeviceOps, MemoryQuota, MemoryQuotaError};
 
 /// The mapping of types used by Backend and traits.
 pub trait BackendTypes {
@@ -122,6 +122,23 @@ pub trait Backend:
         func(input)
     }
 
+    /// Runs the function with the tensors it allocates on the device charged to the
+    /// [quota](MemoryQuota), so that the models served in the same process can't starve each
+    /// other of memory.
+    ///
+    /// Tensor operations can't fail, so the function runs to completion even when an allocation
+    /// doesn't fit in the quota; its output is then dropped and the scope returns a
+    /// [MemoryQuotaError]. Backends whose memory manager doesn't track the allocations run the
+    /// function without a limit.
+    #[allow(unused_variables)]
+    fn memory_quota<Output, Func: FnOnce() -> Output>(
+        device: &Self::Device,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        Ok(func())
+    }
+
     /// Manually triggers a memory cleanup on the given device.
     #[allow(unused_variables)]
     fn memory_cleanup(device: &Self::Device) {}
@@ -174,6 +191,14 @@ pub trait Backend:
     /// A CUDA device will return all devices available to CUDA, a Vulkan device will return all
     /// devices available to Vulkan, etc.
     fn device_count(type_id: u16) -> usize;
+
+    /// Returns the hardware [properties](DeviceProperties) of the specified device.
+    ///
+    /// The properties that the backend can't query are left to `None`.
+    #[allow(unused_variables)]
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        DeviceProperties::default()
+    }
 }
 
 /// Trait that allows a backend to support autodiff.
@@ -397,3 +422,20 @@ impl DTypeUsage {
         DTypeUsage::Storage | DTypeUsage::Arithmetic
     }
 }
+
+/// The hardware properties of a device, used to select devices at runtime.
+///
+/// Each property is `None` when the backend can't query it on the device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceProperties {
+    /// The size in bytes of the largest buffer that can be allocated on the device.
+    pub max_allocation_size: Option<u64>,
+    /// The minimum number of threads executing in lockstep, also known as the subgroup, warp or
+    /// wavefront size.
+    pub plane_size_min: Option<u32>,
+    /// The maximum number of threads executing in lockstep, also known as the subgroup, warp or
+    /// wavefront size.
+    pub plane_size_max: Option<u32>,
+    /// The number of CPU cores available to the device, for CPU devices.
+    pub num_cpu_cores: Option<u32>,
+}
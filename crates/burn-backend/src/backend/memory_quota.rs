@@ -0,0 +1,120 @@
+use burn_std::stub::Mutex;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+/// A memory budget, in bytes, for the tensors allocated within a
+/// [memory quota scope](crate::Backend::memory_quota).
+///
+/// A quota is shared by its clones, so the same quota can be used by several scopes, such as
+/// the requests served by the same model on different threads, while the scopes of other models
+/// use their own quota. A scope is charged with the peak of the device memory allocated while it
+/// runs, as measured by the memory manager of the device, and releases its charge when it ends.
+#[derive(Clone, Debug)]
+pub struct MemoryQuota {
+    state: Arc<Mutex<QuotaState>>,
+}
+
+#[derive(Debug)]
+struct QuotaState {
+    limit: u64,
+    used: u64,
+}
+
+impl MemoryQuota {
+    /// Create a new quota of `limit` bytes.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(QuotaState { limit, used: 0 })),
+        }
+    }
+
+    /// The number of bytes of the quota.
+    pub fn limit(&self) -> u64 {
+        self.state.lock().unwrap().limit
+    }
+
+    /// The number of bytes currently charged to the quota.
+    pub fn used(&self) -> u64 {
+        self.state.lock().unwrap().used
+    }
+
+    /// Charge `bytes` to the quota, or return an error without charging anything if they don't
+    /// fit in the remaining budget.
+    pub fn try_reserve(&self, bytes: u64) -> Result<(), MemoryQuotaError> {
+        let mut state = self.state.lock().unwrap();
+        match state.used.checked_add(bytes) {
+            Some(used) if used <= state.limit => {
+                state.used = used;
+                Ok(())
+            }
+            _ => Err(MemoryQuotaError {
+                requested: bytes,
+                used: state.used,
+                limit: state.limit,
+            }),
+        }
+    }
+
+    /// Release `bytes` previously charged to the quota.
+    pub fn release(&self, bytes: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.used = state.used.saturating_sub(bytes);
+    }
+}
+
+/// The error returned by a [memory quota scope](crate::Backend::memory_quota) when an allocation
+/// doesn't fit in its [quota](MemoryQuota).
+///
+/// The error is recoverable: the output of the scope is dropped, which frees its allocations,
+/// and the charge of the scope is released.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryQuotaError {
+    /// The number of bytes of the allocation that failed.
+    pub requested: u64,
+    /// The number of bytes charged to the quota when the allocation failed.
+    pub used: u64,
+    /// The number of bytes of the quota.
+    pub limit: u64,
+}
+
+impl core::fmt::Display for MemoryQuotaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Memory quota exceeded: requested {} bytes with {} of {} bytes in use",
+            self.requested, self.used, self.limit
+        )
+    }
+}
+
+impl core::error::Error for MemoryQuotaError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reserve_within_limit() {
+        let quota = MemoryQuota::new(100);
+        let shared = quota.clone();
+
+        quota.try_reserve(60).unwrap();
+        let error = shared.try_reserve(60).unwrap_err();
+        assert_eq!(
+            error,
+            MemoryQuotaError {
+                requested: 60,
+                used: 60,
+                limit: 100
+            }
+        );
+
+        quota.release(60);
+        shared.try_reserve(100).unwrap();
+        assert_eq!(quota.used(), 100);
+    }
+}
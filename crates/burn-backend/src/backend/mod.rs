@@ -1,9 +1,11 @@
 mod base;
 mod device;
+mod memory_quota;
 mod primitive;
 
 pub use base::*;
 pub use device::*;
+pub use memory_quota::*;
 pub use primitive::*;
 
 /// Backend operations on tensors.
@@ -275,6 +275,33 @@ pub trait ActivationOps<B: Backend> {
         B::float_sub(shifted, log_sum_exp)
     }
 
+    /// Computes the logarithm of the sum of the exponentials along the given dimension.
+    ///
+    /// Shifts the values by their detached `max` before the exponentials, so that the result
+    /// neither overflows nor underflows, and only applies the shift where the `max` is finite,
+    /// so that a slice of `-inf` values reduces to `-inf` instead of `NaN`.
+    ///
+    /// The default implementation is composed of the `max`, `sub`, `exp`, `sum` and `log`
+    /// operations. The CubeCL and `tch` backends override it with a single-pass kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the values are reduced.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor, with the size of `dim` set to 1.
+    fn logsumexp(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&B::float_device(&tensor)).bool_dtype;
+        let max = B::float_max_dim(B::float_detach(tensor.clone()), dim);
+        let infinite = B::float_is_inf(max.clone(), bool_dtype);
+        let max = B::float_mask_fill(max, infinite, 0f32.into());
+        let shifted = B::float_sub(tensor, max.clone());
+        let log_sum_exp = B::float_log(B::float_sum_dim(B::float_exp(shifted), dim));
+        B::float_add(log_sum_exp, max)
+    }
+
     /// Applies the softmin function along the given dimension.
     ///
     /// Equivalent to `softmax(-tensor, dim)`.
@@ -1,5 +1,6 @@
 use super::{
     argwhere::argwhere_data, cat::cat_with_slice_assign, repeat_dim::repeat_with_slice_assign,
+    roll::roll_with_slice_cat,
 };
 use crate::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
 use crate::{Backend, TensorData, TensorMetadata, get_device_settings};
@@ -461,6 +462,24 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the elements reversed.
     fn bool_flip(tensor: BoolTensor<B>, axes: &[usize]) -> BoolTensor<B>;
 
+    /// Roll the elements of a tensor along the given dimensions, wrapping around.
+    ///
+    /// Along each rolled dimension, the element at index `i` of the output is the element at
+    /// index `(i + shift) % size` of the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift of each rolled dimension, in `1..size`.
+    /// * `dims` - The dimensions to roll, without repeats.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements rolled.
+    fn bool_roll(tensor: BoolTensor<B>, shifts: &[usize], dims: &[usize]) -> BoolTensor<B> {
+        roll_with_slice_cat(tensor, shifts, dims, B::bool_slice, B::bool_cat)
+    }
+
     /// Tests if any element in the boolean `tensor` evaluates to True.
     ///
     /// # Arguments
@@ -1,5 +1,6 @@
 use super::cat::cat_with_slice_assign;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::roll::roll_with_slice_cat;
 use super::sort::{argsort, sort, sort_with_indices};
 use crate::tensor::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::{Backend, Distribution, TensorData, TensorMetadata, element::ElementConversion};
@@ -1150,6 +1151,24 @@ pub trait IntTensorOps<B: Backend> {
     /// The tensor with the elements reversed.
     fn int_flip(tensor: IntTensor<B>, axes: &[usize]) -> IntTensor<B>;
 
+    /// Roll the elements of a tensor along the given dimensions, wrapping around.
+    ///
+    /// Along each rolled dimension, the element at index `i` of the output is the element at
+    /// index `(i + shift) % size` of the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift of each rolled dimension, in `1..size`.
+    /// * `dims` - The dimensions to roll, without repeats.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements rolled.
+    fn int_roll(tensor: IntTensor<B>, shifts: &[usize], dims: &[usize]) -> IntTensor<B> {
+        roll_with_slice_cat(tensor, shifts, dims, B::int_slice, B::int_cat)
+    }
+
     /// Creates a new int tensor with random values.
     ///
     ///  # Arguments
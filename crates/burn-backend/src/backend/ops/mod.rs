@@ -3,12 +3,14 @@ mod bool_tensor;
 mod int_tensor;
 mod modules;
 mod qtensor;
+mod random;
 mod tensor;
 mod transaction;
 
 pub(crate) mod argwhere;
 pub(crate) mod cat;
 pub(crate) mod repeat_dim;
+pub(crate) mod roll;
 pub(crate) mod sort;
 
 pub use activation::*;
@@ -16,5 +18,6 @@ pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
 pub use qtensor::*;
+pub use random::*;
 pub use tensor::*;
 pub use transaction::*;
@@ -59,6 +59,14 @@ pub fn attention_fallback<B: Backend>(
         attention_scores
     };
 
+    // Sliding window masking: mask keys at least `window` positions away from the query
+    let attention_scores = if let Some(window) = options.sliding_window {
+        let window_mask = build_sliding_window_mask::<B>(&attention_scores, window);
+        B::float_mask_fill(attention_scores, window_mask, f32::NEG_INFINITY.into())
+    } else {
+        attention_scores
+    };
+
     // Additive bias (ALiBi, relative position biases, etc.)
     let attention_scores = if let Some(bias) = attn_bias {
         B::float_add(attention_scores, bias)
@@ -114,3 +122,34 @@ fn build_causal_mask<B: Backend>(attention_scores: &FloatTensor<B>) -> BoolTenso
     let mask_4d = B::bool_reshape(mask_2d, Shape::new([1, 1, seq_q, seq_k]));
     B::bool_expand(mask_4d, Shape::new([batch_size, num_heads, seq_q, seq_k]))
 }
+
+/// Builds a sliding window bool mask where `true` means "mask this position".
+/// Shape: [batch_size, num_heads, seq_q, seq_k], masking positions where
+/// |col - (row + offset)| >= window, with the same bottom-right alignment as the causal mask.
+fn build_sliding_window_mask<B: Backend>(
+    attention_scores: &FloatTensor<B>,
+    window: usize,
+) -> BoolTensor<B> {
+    let device = B::float_device(attention_scores);
+    let scores_shape = attention_scores.shape().dims::<4>();
+    let [batch_size, num_heads, seq_q, seq_k] = scores_shape;
+    let settings = get_device_settings::<B>(&device);
+
+    let offset = seq_k as i64 - seq_q as i64;
+    let rows = B::int_reshape(
+        B::int_arange(0..seq_q as i64, &device, settings.int_dtype),
+        Shape::new([seq_q, 1]),
+    );
+    let cols = B::int_reshape(
+        B::int_arange(0..seq_k as i64, &device, settings.int_dtype),
+        Shape::new([1, seq_k]),
+    );
+
+    // Distance of each key from the query, broadcast to [seq_q, seq_k]
+    let rows_shifted = B::int_add_scalar(rows, offset.into());
+    let distance = B::int_abs(B::int_sub(cols, rows_shifted));
+    let mask_2d = B::int_greater_equal_elem(distance, (window as i64).into(), settings.bool_dtype);
+
+    let mask_4d = B::bool_reshape(mask_2d, Shape::new([1, 1, seq_q, seq_k]));
+    B::bool_expand(mask_4d, Shape::new([batch_size, num_heads, seq_q, seq_k]))
+}
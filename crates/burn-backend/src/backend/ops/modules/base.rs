@@ -1,4 +1,4 @@
-use super::{conv, ctc, linear, pool};
+use super::{conv, ctc, linear, pixel_shuffle, pool};
 use crate::ops::unfold::unfold4d_using_conv2d;
 use crate::tensor::{BoolTensor, FloatTensor, IntTensor};
 use crate::{Backend, TensorMetadata};
@@ -661,6 +661,108 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B>,
     ) -> MaxPool2dBackward<B>;
 
+    /// One dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d(x: FloatTensor<B>, output_size: usize) -> FloatTensor<B> {
+        Self::adaptive_max_pool1d_with_indices(x, output_size).output
+    }
+
+    /// One dimensional adaptive max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d_with_indices(
+        x: FloatTensor<B>,
+        output_size: usize,
+    ) -> MaxPool1dWithIndices<B> {
+        pool::adaptive_max_pool1d_with_indices_from_2d::<B>(x, output_size)
+    }
+
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B>, output_size: [usize; 2]) -> FloatTensor<B> {
+        Self::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+
+    /// Two dimensional adaptive max pooling with indices, flattened over the height and width.
+    ///
+    /// The default implementation selects the windows and reduces them with tensor operations,
+    /// which autodiff differentiates through.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<B>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<B> {
+        pool::adaptive_max_pool2d_with_indices_default::<B>(x, output_size)
+    }
+
+    /// One dimensional power-average pooling `(Σ x^p)^(1/p)`.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn lp_pool1d(
+        x: FloatTensor<B>,
+        norm_type: f64,
+        kernel_size: usize,
+        stride: usize,
+        ceil_mode: bool,
+    ) -> FloatTensor<B> {
+        pool::lp_pool1d_from_2d::<B>(x, norm_type, kernel_size, stride, ceil_mode)
+    }
+
+    /// Two dimensional power-average pooling `(Σ x^p)^(1/p)`.
+    ///
+    /// The default implementation scales an [avg pooling](ModuleOps::avg_pool2d) of `x^p`, which
+    /// autodiff differentiates through.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn lp_pool2d(
+        x: FloatTensor<B>,
+        norm_type: f64,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        ceil_mode: bool,
+    ) -> FloatTensor<B> {
+        pool::lp_pool2d_default::<B>(x, norm_type, kernel_size, stride, ceil_mode)
+    }
+
+    /// Rearranges the channels of the input into blocks of pixels (depth to space).
+    ///
+    /// The output pixel `[c, h * r + i, w * r + j]` is the input pixel
+    /// `[c * r * r + i * r + j, h, w]`, for an upscale factor `r`.
+    ///
+    /// # Shapes
+    ///
+    /// * x: `[batch_size, channels * r * r, height, width]`,
+    /// * returns: `[batch_size, channels, height * r, width * r]`,
+    fn pixel_shuffle(x: FloatTensor<B>, upscale_factor: usize) -> FloatTensor<B> {
+        pixel_shuffle::pixel_shuffle_default::<B>(x, upscale_factor)
+    }
+
+    /// Rearranges blocks of pixels of the input into channels (space to depth), the inverse of
+    /// [pixel_shuffle](ModuleOps::pixel_shuffle).
+    ///
+    /// # Shapes
+    ///
+    /// * x: `[batch_size, channels, height * r, width * r]`,
+    /// * returns: `[batch_size, channels * r * r, height, width]`,
+    fn pixel_unshuffle(x: FloatTensor<B>, downscale_factor: usize) -> FloatTensor<B> {
+        pixel_shuffle::pixel_unshuffle_default::<B>(x, downscale_factor)
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -692,7 +794,8 @@ pub trait ModuleOps<B: Backend> {
     ///   where `true` indicates positions to mask (i.e. set to -inf before softmax).
     /// - `attn_bias`: Optional float tensor of shape `[batch_size, num_heads, seq_len_q, seq_len_k]`
     ///   added to the attention scores before softmax (e.g. ALiBi, relative position biases).
-    /// - `options`: Additional attention options (custom scale, softcap, causal masking).
+    /// - `options`: Additional attention options (custom scale, softcap, causal and sliding window
+    ///   masking).
     ///
     /// # Returns
     /// A tensor of shape `[batch_size, num_heads, seq_len_q, val_dim]`
@@ -30,6 +30,12 @@ pub fn float_grid_sample_2d_ref<B: Backend>(
             options.padding_mode,
             options.align_corners,
         ),
+        InterpolateMode::Nearest => float_grid_sample_2d_nearest::<B>(
+            tensor,
+            grid,
+            options.padding_mode,
+            options.align_corners,
+        ),
         _ => todo!(
             "Default implementation for grid_sample_2d with {:?} unimplemented",
             options.mode
@@ -54,73 +60,7 @@ fn float_grid_sample_2d_bilinear<B: Backend>(
     let spatial_out = h_out * w_out;
     let device = B::float_device(&tensor);
 
-    // Separate x and y coordinates from grid
-    // shape: (N, H_out, W_out, 1)
-    let grid_x_slice = vec![
-        Slice::new(0, Some(n as isize), 1),
-        Slice::new(0, Some(h_out as isize), 1),
-        Slice::new(0, Some(w_out as isize), 1),
-        Slice::new(0, Some(1), 1),
-    ];
-    let grid_y_slice = vec![
-        Slice::new(0, Some(n as isize), 1),
-        Slice::new(0, Some(h_out as isize), 1),
-        Slice::new(0, Some(w_out as isize), 1),
-        Slice::new(1, Some(2), 1),
-    ];
-
-    let grid_x = B::float_slice(grid.clone(), &grid_x_slice);
-    let grid_x = B::float_reshape(grid_x, Shape::new([n, 1, h_out, w_out]));
-    let grid_y = B::float_slice(grid.clone(), &grid_y_slice);
-    let grid_y = B::float_reshape(grid_y, Shape::new([n, 1, h_out, w_out]));
-
-    // Convert normalized grid coordinates [-1, 1] to pixel coordinates
-    let w_in_f = w_in as f64;
-    let h_in_f = h_in as f64;
-
-    let (grid_x, grid_y) = if align_corners {
-        // align_corners=true: x_pixel = (x_norm + 1) * (width - 1) / 2
-        // Maps -1 to 0 and 1 to width - 1
-        let grid_x = B::float_add_scalar(grid_x, 1f32.into());
-        let grid_x = B::float_mul_scalar(grid_x, ((w_in_f - 1.0) / 2.0).into());
-
-        let grid_y = B::float_add_scalar(grid_y, 1f32.into());
-        let grid_y = B::float_mul_scalar(grid_y, ((h_in_f - 1.0) / 2.0).into());
-
-        (grid_x, grid_y)
-    } else {
-        // align_corners=false: x_pixel = (x_norm + 1) * width / 2 - 0.5
-        // Maps -1 to -0.5 and 1 to width - 0.5
-        let grid_x = B::float_add_scalar(grid_x, 1f32.into());
-        let grid_x = B::float_mul_scalar(grid_x, (w_in_f / 2.0).into());
-        let grid_x = B::float_sub_scalar(grid_x, 0.5f32.into());
-
-        let grid_y = B::float_add_scalar(grid_y, 1f32.into());
-        let grid_y = B::float_mul_scalar(grid_y, (h_in_f / 2.0).into());
-        let grid_y = B::float_sub_scalar(grid_y, 0.5f32.into());
-
-        (grid_x, grid_y)
-    };
-
-    // Apply padding mode to coordinates
-    let (grid_x, grid_y) = match padding_mode {
-        GridSamplePaddingMode::Border => {
-            // Clamp coordinates to valid range [0, size-1]
-            let grid_x = B::float_clamp(grid_x, 0f32.into(), ((w_in - 1) as f32).into());
-            let grid_y = B::float_clamp(grid_y, 0f32.into(), ((h_in - 1) as f32).into());
-            (grid_x, grid_y)
-        }
-        GridSamplePaddingMode::Reflection => {
-            // Reflect coordinates at boundaries
-            let grid_x = reflect_coordinates::<B>(grid_x, w_in_f, align_corners);
-            let grid_y = reflect_coordinates::<B>(grid_y, h_in_f, align_corners);
-            (grid_x, grid_y)
-        }
-        GridSamplePaddingMode::Zeros => {
-            // Keep coordinates as-is, we'll mask out-of-bounds later
-            (grid_x, grid_y)
-        }
-    };
+    let (grid_x, grid_y) = pixel_coordinates::<B>(grid, [h_in, w_in], padding_mode, align_corners);
 
     // Get floor indices for the four corners
     let grid_x_floored = B::float_floor(grid_x.clone());
@@ -277,6 +217,155 @@ fn float_grid_sample_2d_bilinear<B: Backend>(
     B::float_add(result, B::float_mul(sample_11, weight_11))
 }
 
+/// Nearest neighbor grid sampling implementation.
+fn float_grid_sample_2d_nearest<B: Backend>(
+    tensor: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> FloatTensor<B> {
+    let n = tensor.shape()[0];
+    let c = tensor.shape()[1];
+    let h_in = tensor.shape()[2];
+    let w_in = tensor.shape()[3];
+    let h_out = grid.shape()[1];
+    let w_out = grid.shape()[2];
+    let device = B::float_device(&tensor);
+
+    let (grid_x, grid_y) = pixel_coordinates::<B>(grid, [h_in, w_in], padding_mode, align_corners);
+
+    // Round to the nearest pixel
+    let settings = get_device_settings::<B>(&device);
+    let x = B::float_into_int(
+        B::float_floor(B::float_add_scalar(grid_x, 0.5f32.into())),
+        settings.int_dtype,
+    );
+    let y = B::float_into_int(
+        B::float_floor(B::float_add_scalar(grid_y, 0.5f32.into())),
+        settings.int_dtype,
+    );
+
+    // Mask for out-of-bounds coordinates (only used for zeros padding)
+    let mask = if padding_mode == GridSamplePaddingMode::Zeros {
+        let x_valid = B::bool_and(
+            B::int_greater_equal_elem(x.clone(), 0.into(), settings.bool_dtype),
+            B::int_lower_elem(x.clone(), (w_in as i32).into(), settings.bool_dtype),
+        );
+        let y_valid = B::bool_and(
+            B::int_greater_equal_elem(y.clone(), 0.into(), settings.bool_dtype),
+            B::int_lower_elem(y.clone(), (h_in as i32).into(), settings.bool_dtype),
+        );
+        Some(B::bool_and(x_valid, y_valid))
+    } else {
+        None
+    };
+
+    // Linear indices: idx = y * W_in + x
+    let x = B::int_clamp(x, 0.into(), ((w_in - 1) as i32).into());
+    let y = B::int_clamp(y, 0.into(), ((h_in - 1) as i32).into());
+    let idx = B::int_add(B::int_mul_scalar(y, (w_in as i32).into()), x);
+    let idx = B::int_reshape(idx, Shape::new([n, 1, h_out * w_out]));
+    let idx = B::int_expand(idx, Shape::new([n, c, h_out * w_out]));
+
+    let tensor_flat = B::float_reshape(tensor, Shape::new([n, c, h_in * w_in]));
+    let sample = B::float_gather(2, tensor_flat, idx);
+    let sample = B::float_reshape(sample, Shape::new([n, c, h_out, w_out]));
+
+    match mask {
+        Some(mask) => {
+            let mask_inv = B::bool_not(mask);
+            let mask_inv = B::bool_reshape(mask_inv, Shape::new([n, 1, h_out, w_out]));
+            let mask_inv = B::bool_expand(mask_inv, Shape::new([n, c, h_out, w_out]));
+            B::float_mask_fill(sample, mask_inv, 0f32.into())
+        }
+        None => sample,
+    }
+}
+
+/// Convert the normalized grid coordinates to pixel coordinates, with the padding mode applied.
+///
+/// Returns the x and y coordinates, each with shape (N, 1, H_out, W_out).
+fn pixel_coordinates<B: Backend>(
+    grid: FloatTensor<B>,
+    [h_in, w_in]: [usize; 2],
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let n = grid.shape()[0];
+    let h_out = grid.shape()[1];
+    let w_out = grid.shape()[2];
+
+    // Separate x and y coordinates from grid
+    // shape: (N, H_out, W_out, 1)
+    let grid_x_slice = vec![
+        Slice::new(0, Some(n as isize), 1),
+        Slice::new(0, Some(h_out as isize), 1),
+        Slice::new(0, Some(w_out as isize), 1),
+        Slice::new(0, Some(1), 1),
+    ];
+    let grid_y_slice = vec![
+        Slice::new(0, Some(n as isize), 1),
+        Slice::new(0, Some(h_out as isize), 1),
+        Slice::new(0, Some(w_out as isize), 1),
+        Slice::new(1, Some(2), 1),
+    ];
+
+    let grid_x = B::float_slice(grid.clone(), &grid_x_slice);
+    let grid_x = B::float_reshape(grid_x, Shape::new([n, 1, h_out, w_out]));
+    let grid_y = B::float_slice(grid.clone(), &grid_y_slice);
+    let grid_y = B::float_reshape(grid_y, Shape::new([n, 1, h_out, w_out]));
+
+    // Convert normalized grid coordinates [-1, 1] to pixel coordinates
+    let w_in_f = w_in as f64;
+    let h_in_f = h_in as f64;
+
+    let (grid_x, grid_y) = if align_corners {
+        // align_corners=true: x_pixel = (x_norm + 1) * (width - 1) / 2
+        // Maps -1 to 0 and 1 to width - 1
+        let grid_x = B::float_add_scalar(grid_x, 1f32.into());
+        let grid_x = B::float_mul_scalar(grid_x, ((w_in_f - 1.0) / 2.0).into());
+
+        let grid_y = B::float_add_scalar(grid_y, 1f32.into());
+        let grid_y = B::float_mul_scalar(grid_y, ((h_in_f - 1.0) / 2.0).into());
+
+        (grid_x, grid_y)
+    } else {
+        // align_corners=false: x_pixel = (x_norm + 1) * width / 2 - 0.5
+        // Maps -1 to -0.5 and 1 to width - 0.5
+        let grid_x = B::float_add_scalar(grid_x, 1f32.into());
+        let grid_x = B::float_mul_scalar(grid_x, (w_in_f / 2.0).into());
+        let grid_x = B::float_sub_scalar(grid_x, 0.5f32.into());
+
+        let grid_y = B::float_add_scalar(grid_y, 1f32.into());
+        let grid_y = B::float_mul_scalar(grid_y, (h_in_f / 2.0).into());
+        let grid_y = B::float_sub_scalar(grid_y, 0.5f32.into());
+
+        (grid_x, grid_y)
+    };
+
+    // Apply padding mode to coordinates
+    let (grid_x, grid_y) = match padding_mode {
+        GridSamplePaddingMode::Border => {
+            // Clamp coordinates to valid range [0, size-1]
+            let grid_x = B::float_clamp(grid_x, 0f32.into(), ((w_in - 1) as f32).into());
+            let grid_y = B::float_clamp(grid_y, 0f32.into(), ((h_in - 1) as f32).into());
+            (grid_x, grid_y)
+        }
+        GridSamplePaddingMode::Reflection => {
+            // Reflect coordinates at boundaries
+            let grid_x = reflect_coordinates::<B>(grid_x, w_in_f, align_corners);
+            let grid_y = reflect_coordinates::<B>(grid_y, h_in_f, align_corners);
+            (grid_x, grid_y)
+        }
+        GridSamplePaddingMode::Zeros => {
+            // Keep coordinates as-is, we'll mask out-of-bounds later
+            (grid_x, grid_y)
+        }
+    };
+
+    (grid_x, grid_y)
+}
+
 /// Reflect coordinates at boundaries using a triangle wave pattern.
 ///
 /// For align_corners=true: reflects within [0, size-1]
@@ -0,0 +1,134 @@
+use super::{InterpolateMode, InterpolateOptions};
+use crate::tensor::FloatTensor;
+use crate::{Backend, TensorData, TensorMetadata};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_std::Shape;
+
+/// Computes an [antialiased](InterpolateOptions::antialias) interpolation, as a matrix
+/// multiplication with the filter weights of each output sample, over the height and then the
+/// width.
+///
+/// Used by the backends without a native antialiased kernel.
+pub fn interpolate_antialias<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+    options: &InterpolateOptions,
+) -> FloatTensor<B> {
+    let x = resample_dim::<B>(x, 2, output_size[0], options, false);
+    resample_dim::<B>(x, 3, output_size[1], options, false)
+}
+
+/// Backward pass of [interpolate_antialias], which multiplies the output gradient by the
+/// transposed filter weights.
+pub fn interpolate_antialias_backward<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    options: &InterpolateOptions,
+) -> FloatTensor<B> {
+    let [_, _, height, width] = x.shape().dims();
+
+    let grad = resample_dim::<B>(grad, 3, width, options, true);
+    resample_dim::<B>(grad, 2, height, options, true)
+}
+
+/// Resamples a dimension of a 4D tensor with the antialiased filter weights.
+///
+/// With `transposed`, the dimension has the output size and is mapped back to `size` samples, as
+/// required by the backward pass.
+fn resample_dim<B: Backend>(
+    x: FloatTensor<B>,
+    dim: usize,
+    size: usize,
+    options: &InterpolateOptions,
+    transposed: bool,
+) -> FloatTensor<B> {
+    let current = x.shape()[dim];
+    if current == size {
+        return x;
+    }
+
+    // The weights have the shape `[output_size, input_size]`, and are multiplied on the right, so
+    // they are transposed for the forward pass.
+    let (input_size, output_size) = match transposed {
+        true => (size, current),
+        false => (current, size),
+    };
+    let weights = antialias_weights(input_size, output_size, options);
+    let weights = B::float_from_data(
+        TensorData::new(weights, [output_size, input_size]).convert_dtype(x.dtype()),
+        &B::float_device(&x),
+    );
+    let weights = match transposed {
+        true => weights,
+        false => B::float_swap_dims(weights, 0, 1),
+    };
+
+    let x = B::float_swap_dims(x, dim, 3);
+    let [a, b, c, _] = x.shape().dims();
+    let x = B::float_reshape(x, Shape::new([a * b * c, current]));
+    let output = B::float_matmul(x, weights);
+    let output = B::float_reshape(output, Shape::new([a, b, c, size]));
+
+    B::float_swap_dims(output, dim, 3)
+}
+
+/// The weights of shape `[output_size, input_size]` of the antialiased filters, which match
+/// PyTorch's `antialias` implementation.
+///
+/// When downscaling, the support of the filter is widened by the scale, so that all the input
+/// samples contribute to the output.
+fn antialias_weights(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Vec<f32> {
+    let (support, filter): (f64, fn(f64) -> f64) = match options.mode {
+        InterpolateMode::Bilinear => (1.0, |x| (1.0 - x.abs()).max(0.0)),
+        // Keys cubic filter with `a = -0.5`.
+        InterpolateMode::Bicubic => (2.0, |x| {
+            let a = -0.5;
+            let x = x.abs();
+            if x < 1.0 {
+                ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0
+            } else if x < 2.0 {
+                (((x - 5.0) * x + 8.0) * x - 4.0) * a
+            } else {
+                0.0
+            }
+        }),
+        ref mode => {
+            panic!("Antialiasing is only supported by the bilinear and bicubic modes, got {mode:?}")
+        }
+    };
+
+    let scale = match options.align_corners {
+        true if output_size > 1 => (input_size - 1) as f64 / (output_size - 1) as f64,
+        true => 0.0,
+        false => input_size as f64 / output_size as f64,
+    };
+    let (support, inv_scale) = match scale >= 1.0 {
+        true => (support * scale, 1.0 / scale),
+        false => (support, 1.0),
+    };
+
+    let mut weights = vec![0.0; output_size * input_size];
+    for (i, row) in weights.chunks_mut(input_size).enumerate() {
+        let center = scale * (i as f64 + 0.5);
+        let start = (center - support + 0.5).max(0.0) as usize;
+        let end = ((center + support + 0.5) as usize).min(input_size);
+
+        let mut total = 0.0;
+        for (j, weight) in row.iter_mut().enumerate().take(end).skip(start) {
+            *weight = filter((j as f64 - center + 0.5) * inv_scale);
+            total += *weight;
+        }
+        if total != 0.0 {
+            row[start..end]
+                .iter_mut()
+                .for_each(|weight| *weight /= total);
+        }
+    }
+
+    weights.into_iter().map(|weight| weight as f32).collect()
+}
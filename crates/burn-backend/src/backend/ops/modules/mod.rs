@@ -16,9 +16,15 @@ pub mod unfold;
 /// Module with pooling operations.
 pub mod pool;
 
+/// Module with pixel shuffle operations.
+pub mod pixel_shuffle;
+
 /// Module for grid_sample operations
 pub mod grid_sample;
 
+/// Module with interpolate operations.
+pub mod interpolate;
+
 mod base;
 
 pub use base::*;
@@ -0,0 +1,41 @@
+use crate::tensor::FloatTensor;
+use crate::{Backend, TensorMetadata};
+use burn_std::Shape;
+
+pub(crate) fn pixel_shuffle_default<B: Backend>(
+    x: FloatTensor<B>,
+    upscale_factor: usize,
+) -> FloatTensor<B> {
+    let [batch_size, channels_in, height, width] = x.shape().dims();
+    let r = upscale_factor;
+    assert!(
+        r > 0 && channels_in.is_multiple_of(r * r),
+        "pixel_shuffle expects the channels ({channels_in}) to be divisible by the square of the upscale factor ({r})"
+    );
+    let channels = channels_in / (r * r);
+
+    // [B, C * r * r, H, W] -> [B, C, r, r, H, W] -> [B, C, H, r, W, r] -> [B, C, H * r, W * r]
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, r, r, height, width]));
+    let x = B::float_permute(x, &[0, 1, 4, 2, 5, 3]);
+
+    B::float_reshape(x, Shape::new([batch_size, channels, height * r, width * r]))
+}
+
+pub(crate) fn pixel_unshuffle_default<B: Backend>(
+    x: FloatTensor<B>,
+    downscale_factor: usize,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height_in, width_in] = x.shape().dims();
+    let r = downscale_factor;
+    assert!(
+        r > 0 && height_in.is_multiple_of(r) && width_in.is_multiple_of(r),
+        "pixel_unshuffle expects the height ({height_in}) and width ({width_in}) to be divisible by the downscale factor ({r})"
+    );
+    let (height, width) = (height_in / r, width_in / r);
+
+    // [B, C, H * r, W * r] -> [B, C, H, r, W, r] -> [B, C, r, r, H, W] -> [B, C * r * r, H, W]
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, height, r, width, r]));
+    let x = B::float_permute(x, &[0, 1, 3, 5, 2, 4]);
+
+    B::float_reshape(x, Shape::new([batch_size, channels * r * r, height, width]))
+}
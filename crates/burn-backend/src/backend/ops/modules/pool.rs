@@ -1,8 +1,9 @@
 use crate::tensor::{FloatTensor, IntTensor};
-use crate::{Backend, TensorMetadata};
+use crate::{Backend, TensorData, TensorMetadata, get_device_settings};
+use alloc::vec::Vec;
 use burn_std::Shape;
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
@@ -174,3 +175,131 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+/// The input indices of each adaptive pooling window along a dimension, padded to the largest
+/// window by repeating the last index of the window, with the size of the largest window.
+///
+/// As in PyTorch, window `i` spans `[floor(i * size_in / size_out), ceil((i + 1) * size_in / size_out))`.
+fn adaptive_windows(size_in: usize, size_out: usize) -> (Vec<usize>, usize) {
+    let bounds = (0..size_out)
+        .map(|i| {
+            let start = i * size_in / size_out;
+            let end = ((i + 1) * size_in).div_ceil(size_out);
+            (start, end)
+        })
+        .collect::<Vec<_>>();
+    let window = bounds
+        .iter()
+        .map(|(start, end)| end - start)
+        .max()
+        .unwrap_or(0);
+
+    let indices = bounds
+        .into_iter()
+        .flat_map(|(start, end)| (0..window).map(move |offset| (start + offset).min(end - 1)))
+        .collect();
+
+    (indices, window)
+}
+
+/// Adaptive max pooling decomposed into a selection of the windows and a max reduction, so that
+/// every backend supports it, and autodiff differentiates through it.
+pub(crate) fn adaptive_max_pool2d_with_indices_default<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+) -> MaxPool2dWithIndices<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let [height_out, width_out] = output_size;
+    let device = B::float_device(&x);
+    let int_dtype = get_device_settings::<B>(&device).int_dtype;
+
+    let (rows, window_height) = adaptive_windows(height, height_out);
+    let (cols, window_width) = adaptive_windows(width, width_out);
+    let window = window_height * window_width;
+
+    // Flat `height * width` input index of each element of each window.
+    let mut positions = Vec::with_capacity(height_out * width_out * window);
+    for i in 0..height_out {
+        for j in 0..width_out {
+            for row in &rows[i * window_height..(i + 1) * window_height] {
+                for col in &cols[j * window_width..(j + 1) * window_width] {
+                    positions.push((row * width + col) as i64);
+                }
+            }
+        }
+    }
+    let positions = B::int_from_data(
+        TensorData::new(positions, Shape::new([height_out * width_out * window]))
+            .convert_dtype(int_dtype.into()),
+        &device,
+    );
+
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, height * width]));
+    let windows = B::float_select(x, 2, positions.clone());
+    let windows = B::float_reshape(
+        windows,
+        Shape::new([batch_size, channels, height_out, width_out, window]),
+    );
+    let (output, argmax) = B::float_max_dim_with_indices(windows, 4, int_dtype);
+
+    let positions = B::int_expand(
+        B::int_reshape(positions, Shape::new([1, 1, height_out, width_out, window])),
+        Shape::new([batch_size, channels, height_out, width_out, window]),
+    );
+    let indices = B::int_gather(4, positions, argmax);
+
+    let shape = Shape::new([batch_size, channels, height_out, width_out]);
+    MaxPool2dWithIndices::new(
+        B::float_reshape(output, shape.clone()),
+        B::int_reshape(indices, shape),
+    )
+}
+
+pub(crate) fn adaptive_max_pool1d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: usize,
+) -> MaxPool1dWithIndices<B> {
+    let [batch_size, channels, length] = x.shape().dims();
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, 1, length]));
+    let x = B::adaptive_max_pool2d_with_indices(x, [1, output_size]);
+
+    let shape = Shape::from([batch_size, channels, output_size]);
+    MaxPool1dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(x.indices, shape),
+    )
+}
+
+/// Power-average pooling `(Σ x^p)^(1/p)` over each window, computed from an average pooling of
+/// `x^p` scaled by the size of the kernel.
+pub(crate) fn lp_pool2d_default<B: Backend>(
+    x: FloatTensor<B>,
+    norm_type: f64,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    ceil_mode: bool,
+) -> FloatTensor<B> {
+    let x = B::float_powf_scalar(x, norm_type.into());
+    let x = B::avg_pool2d(x, kernel_size, stride, [0, 0], true, ceil_mode);
+    let x = B::float_mul_scalar(x, ((kernel_size[0] * kernel_size[1]) as f64).into());
+
+    B::float_powf_scalar(x, (1.0 / norm_type).into())
+}
+
+pub(crate) fn lp_pool1d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    norm_type: f64,
+    kernel_size: usize,
+    stride: usize,
+    ceil_mode: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, length] = x.shape().dims();
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::lp_pool2d(x, norm_type, [kernel_size, 1], [stride, 1], ceil_mode);
+
+    let [batch_size, channels, length, _] = x.shape().dims();
+
+    B::float_reshape(x, Shape::from([batch_size, channels, length]))
+}
@@ -0,0 +1,230 @@
+use crate::tensor::{Device, FloatTensor};
+use crate::{Backend, Distribution, TensorMetadata, get_device_settings};
+use burn_std::{FloatDType, Shape};
+use core::f64::consts::PI;
+
+/// The number of rejection sampling rounds: each round accepts more than 90% of the remaining
+/// samples, so the fallback value is practically never used.
+const REJECTION_ROUNDS: usize = 12;
+
+/// Samples a tensor from the distributions that backends derive from their uniform and normal
+/// samplers, namely the [gamma](Distribution::Gamma), [beta](Distribution::Beta) and
+/// [Poisson](Distribution::Poisson) distributions. Other distributions are forwarded to
+/// [float_random](crate::ops::FloatTensorOps::float_random).
+///
+/// The samples only depend on the random values of the backend, so they are reproducible after
+/// seeding the backend.
+pub fn float_random_derived<B: Backend>(
+    shape: Shape,
+    distribution: Distribution,
+    device: &Device<B>,
+    dtype: FloatDType,
+) -> FloatTensor<B> {
+    match distribution {
+        Distribution::Gamma(concentration, scale) => {
+            let concentration = B::float_full(shape, concentration.into(), device, dtype);
+            B::float_mul_scalar(float_random_gamma::<B>(concentration), scale.into())
+        }
+        Distribution::Beta(alpha, beta) => {
+            let alpha = B::float_full(shape.clone(), alpha.into(), device, dtype);
+            let beta = B::float_full(shape, beta.into(), device, dtype);
+            float_random_beta::<B>(alpha, beta)
+        }
+        Distribution::Poisson(rate) => float_random_poisson::<B>(shape, rate, device, dtype),
+        distribution => B::float_random(shape, distribution, device, dtype),
+    }
+}
+
+/// Samples each element from a gamma distribution with the given concentration and a unit scale.
+///
+/// Uses the Marsaglia and Tsang rejection sampler, boosting the concentrations below one. The
+/// samples are a differentiable function of the concentration given the random values, so the
+/// gradients with respect to the concentration flow through them (reparameterization).
+pub fn float_random_gamma<B: Backend>(concentration: FloatTensor<B>) -> FloatTensor<B> {
+    let device = B::float_device(&concentration);
+    let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+    let shape = concentration.shape();
+    let dtype = concentration.dtype().into();
+
+    // Gamma(a) = Gamma(a + 1) * U^(1 / a) for a < 1.
+    let small = B::float_lower_elem(concentration.clone(), 1f32.into(), bool_dtype);
+    let boosted = B::float_mask_where(
+        concentration.clone(),
+        small.clone(),
+        B::float_add_scalar(concentration.clone(), 1f32.into()),
+    );
+
+    let d = B::float_sub_scalar(boosted, (1.0 / 3.0).into());
+    let c = B::float_recip(B::float_sqrt(B::float_mul_scalar(d.clone(), 9f32.into())));
+
+    let mut sample = d.clone();
+    let mut accepted = B::float_lower_elem(d.clone(), f64::NEG_INFINITY.into(), bool_dtype);
+    for _ in 0..REJECTION_ROUNDS {
+        let normal = B::float_random(shape.clone(), Distribution::Normal(0., 1.), &device, dtype);
+        let uniform = B::float_random(shape.clone(), Distribution::Default, &device, dtype);
+
+        // v = (1 + c * x)^3, accepted when log(u) < x^2 / 2 + d - d * v + d * log(v).
+        let t = B::float_add_scalar(B::float_mul(c.clone(), normal.clone()), 1f32.into());
+        let v = B::float_mul(B::float_mul(t.clone(), t.clone()), t);
+        let bound = B::float_add(
+            B::float_mul_scalar(B::float_mul(normal.clone(), normal), 0.5f32.into()),
+            B::float_mul(
+                d.clone(),
+                B::float_sub(
+                    B::float_add_scalar(B::float_log(v.clone()), 1f32.into()),
+                    v.clone(),
+                ),
+            ),
+        );
+        let accept = B::bool_and(
+            B::float_greater_elem(v.clone(), 0f32.into(), bool_dtype),
+            B::float_lower(B::float_log(uniform), bound, bool_dtype),
+        );
+        let accept = B::bool_and(accept, B::bool_not(accepted.clone()));
+
+        sample = B::float_mask_where(sample, accept.clone(), B::float_mul(d.clone(), v));
+        accepted = B::bool_or(accepted, accept);
+    }
+
+    let uniform = B::float_random(shape, Distribution::Default, &device, dtype);
+    let boost = B::float_powf(uniform, B::float_recip(concentration));
+    B::float_mask_where(sample.clone(), small, B::float_mul(sample, boost))
+}
+
+/// Samples each element from a beta distribution with the given parameters, as the ratio of two
+/// [gamma samples](float_random_gamma), which keeps the samples differentiable with respect to
+/// both parameters.
+pub fn float_random_beta<B: Backend>(
+    alpha: FloatTensor<B>,
+    beta: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let x = float_random_gamma::<B>(alpha);
+    let y = float_random_gamma::<B>(beta);
+    B::float_div(x.clone(), B::float_add(x, y))
+}
+
+/// Samples a tensor from a Poisson distribution with the given rate.
+///
+/// Small rates use the inverse transform of a uniform sample, with the cumulative probabilities
+/// computed ahead of time, and large rates use the PTRS rejection sampler of Hörmann.
+fn float_random_poisson<B: Backend>(
+    shape: Shape,
+    rate: f64,
+    device: &Device<B>,
+    dtype: FloatDType,
+) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(device).bool_dtype;
+
+    if rate < 10.0 {
+        let uniform = B::float_random(shape.clone(), Distribution::Default, device, dtype);
+        let mut count = B::float_zeros(shape, device, dtype);
+
+        // count = #{k : u > P(X <= k)}
+        let mut probability = (-rate).exp();
+        let mut cumulative = probability;
+        let mut k = 0.0;
+        while 1.0 - cumulative > 1e-12 && k < rate + 100.0 {
+            let greater = B::float_greater_elem(uniform.clone(), cumulative.into(), bool_dtype);
+            count = B::float_add(count, B::bool_into_float(greater, dtype));
+            k += 1.0;
+            probability *= rate / k;
+            cumulative += probability;
+        }
+
+        return count;
+    }
+
+    let log_rate = rate.ln();
+    let b = 0.931 + 2.53 * rate.sqrt();
+    let a = -0.059 + 0.02483 * b;
+    let log_inv_alpha = (1.1239 + 1.1328 / (b - 3.4)).ln();
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    let mut sample = B::float_full(shape.clone(), rate.floor().into(), device, dtype);
+    let mut accepted = B::float_lower_elem(sample.clone(), f64::NEG_INFINITY.into(), bool_dtype);
+    for _ in 0..REJECTION_ROUNDS {
+        let u = B::float_random(
+            shape.clone(),
+            Distribution::Uniform(-0.5, 0.5),
+            device,
+            dtype,
+        );
+        let v = B::float_random(shape.clone(), Distribution::Default, device, dtype);
+        let us = B::float_neg(B::float_sub_scalar(B::float_abs(u.clone()), 0.5f32.into()));
+
+        // k = floor((2a / us + b) * u + rate + 0.43)
+        let k = B::float_floor(B::float_add_scalar(
+            B::float_mul(
+                B::float_add_scalar(
+                    B::float_mul_scalar(B::float_recip(us.clone()), (2.0 * a).into()),
+                    b.into(),
+                ),
+                u,
+            ),
+            (rate + 0.43).into(),
+        ));
+
+        let quick = B::bool_and(
+            B::float_greater_equal_elem(us.clone(), 0.07f32.into(), bool_dtype),
+            B::float_lower_equal_elem(v.clone(), v_r.into(), bool_dtype),
+        );
+        let reject = B::bool_or(
+            B::float_lower_elem(k.clone(), 0f32.into(), bool_dtype),
+            B::bool_and(
+                B::float_lower_elem(us.clone(), 0.013f32.into(), bool_dtype),
+                B::float_greater(v.clone(), us.clone(), bool_dtype),
+            ),
+        );
+
+        // log(v) + log(1 / alpha) - log(a / us^2 + b) <= -rate + k * log(rate) - log(k!)
+        let lhs = B::float_sub(
+            B::float_add_scalar(B::float_log(v), log_inv_alpha.into()),
+            B::float_log(B::float_add_scalar(
+                B::float_mul_scalar(B::float_recip(B::float_mul(us.clone(), us)), a.into()),
+                b.into(),
+            )),
+        );
+        let rhs = B::float_sub(
+            B::float_add_scalar(
+                B::float_mul_scalar(k.clone(), log_rate.into()),
+                (-rate).into(),
+            ),
+            log_factorial::<B>(k.clone()),
+        );
+        let full = B::bool_and(
+            B::bool_not(reject),
+            B::float_lower_equal(lhs, rhs, bool_dtype),
+        );
+
+        let accept = B::bool_and(B::bool_or(quick, full), B::bool_not(accepted.clone()));
+        sample = B::float_mask_where(sample, accept.clone(), k);
+        accepted = B::bool_or(accepted, accept);
+    }
+
+    sample
+}
+
+/// The logarithm of `k!`, from the Stirling series of `log(Gamma(k + 1))`, which is accurate to
+/// a few units in the last place of a single precision float for all `k >= 1`.
+fn log_factorial<B: Backend>(k: FloatTensor<B>) -> FloatTensor<B> {
+    let z = B::float_add_scalar(k, 1f32.into());
+    let recip = B::float_recip(z.clone());
+    let recip_cube = B::float_mul(B::float_mul(recip.clone(), recip.clone()), recip.clone());
+
+    // (z - 1/2) log(z) - z + log(2 pi) / 2 + 1 / (12 z) - 1 / (360 z^3)
+    let series = B::float_sub(
+        B::float_mul_scalar(recip, (1.0 / 12.0).into()),
+        B::float_mul_scalar(recip_cube, (1.0 / 360.0).into()),
+    );
+    let main = B::float_sub(
+        B::float_mul(
+            B::float_sub_scalar(z.clone(), 0.5f32.into()),
+            B::float_log(z.clone()),
+        ),
+        z,
+    );
+    B::float_add(
+        B::float_add_scalar(main, (0.5 * (2.0 * PI).ln()).into()),
+        series,
+    )
+}
@@ -0,0 +1,40 @@
+use crate::TensorMetadata;
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_std::Slice;
+
+/// Roll a tensor by slicing each rolled dimension in two parts and concatenating them in the
+/// opposite order.
+pub(crate) fn roll_with_slice_cat<T, S, C>(
+    tensor: T,
+    shifts: &[usize],
+    dims: &[usize],
+    slice: S,
+    cat: C,
+) -> T
+where
+    T: TensorMetadata,
+    S: Fn(T, &[Slice]) -> T,
+    C: Fn(Vec<T>, usize) -> T,
+{
+    shifts
+        .iter()
+        .zip(dims)
+        .fold(tensor, |tensor, (&shift, &dim)| {
+            let shape = tensor.shape();
+            let slices_for = |start: usize, end: usize| {
+                shape
+                    .iter()
+                    .enumerate()
+                    .map(|(d, &size)| match d == dim {
+                        true => Slice::new(start as isize, Some(end as isize), 1),
+                        false => Slice::new(0, Some(size as isize), 1),
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let head = slice(tensor.clone(), &slices_for(shift, shape[dim]));
+            let tail = slice(tensor, &slices_for(0, shift));
+            cat(vec![head, tail], dim)
+        })
+}
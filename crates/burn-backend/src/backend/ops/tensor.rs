@@ -1,6 +1,7 @@
 use super::cat::cat_with_slice_assign;
 use super::grid_sample::float_grid_sample_2d_ref;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::roll::roll_with_slice_cat;
 use super::sort::{argsort, sort, sort_with_indices};
 use crate::ops::GridSampleOptions;
 use crate::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
@@ -420,6 +421,24 @@ pub trait FloatTensorOps<B: Backend> {
     /// The tensor with the elements reversed.
     fn float_flip(tensor: FloatTensor<B>, axes: &[usize]) -> FloatTensor<B>;
 
+    /// Roll the elements of a tensor along the given dimensions, wrapping around.
+    ///
+    /// Along each rolled dimension, the element at index `i` of the output is the element at
+    /// index `(i + shift) % size` of the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift of each rolled dimension, in `1..size`.
+    /// * `dims` - The dimensions to roll, without repeats.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements rolled.
+    fn float_roll(tensor: FloatTensor<B>, shifts: &[usize], dims: &[usize]) -> FloatTensor<B> {
+        roll_with_slice_cat(tensor, shifts, dims, B::float_slice, B::float_cat)
+    }
+
     /// Reshapes a tensor.
     ///
     /// # Arguments
@@ -158,6 +158,16 @@ fn try_scatter_nd(
     let result = match reduction {
         IndexingUpdateOp::Assign => flat_data.scatter(&linear_idx, &flat_values, 0)?,
         IndexingUpdateOp::Add => flat_data.scatter_add(&linear_idx, &flat_values, 0)?,
+        IndexingUpdateOp::Mean => {
+            // The existing value is one of the averaged terms.
+            let counts =
+                flat_data
+                    .ones_like()?
+                    .scatter_add(&linear_idx, &flat_values.ones_like()?, 0)?;
+            flat_data
+                .scatter_add(&linear_idx, &flat_values, 0)?
+                .div(&counts)?
+        }
         _ => panic!(
             "scatter_nd with {:?} reduction is not supported by the candle backend",
             reduction
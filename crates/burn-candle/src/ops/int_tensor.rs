@@ -1,7 +1,7 @@
 use burn_backend::{
     DType, Distribution, ElementConversion, ExecutionError, IntDType, Scalar, Shape, Slice,
     TensorData, TensorMetadata,
-    ops::{FloatTensorOps, IntTensorOps},
+    ops::{FloatTensorOps, IntTensorOps, float_random_derived},
     tensor::{BoolTensor, Device, FloatTensor, IntElem, IntTensor},
 };
 use burn_std::{BoolDType, FloatDType};
@@ -463,32 +463,47 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
             return Self::int_from_data(cpu_random(shape, distribution, dtype.into()), device);
         }
 
-        let shape = shape.to_vec();
-        let device = &(device.clone()).into();
+        let dims = shape.to_vec();
+        let candle_device = &(device.clone()).into();
         match distribution {
             Distribution::Default => CandleTensor::new(
-                candle_core::Tensor::rand(0.elem::<F>(), 255.elem::<F>(), shape, device)
+                candle_core::Tensor::rand(0.elem::<F>(), 255.elem::<F>(), dims, candle_device)
                     .unwrap()
                     .to_dtype(I::DTYPE)
                     .unwrap(),
             ),
             Distribution::Bernoulli(prob) => CandleTensor::new(
-                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape.clone(), device)
-                    .unwrap()
-                    .to_dtype(I::DTYPE)
-                    .unwrap()
-                    .lt(&super::candle_utils::fill(prob, shape, I::DTYPE, device))
-                    .unwrap()
-                    .to_dtype(I::DTYPE)
-                    .unwrap(),
+                candle_core::Tensor::rand(
+                    0.elem::<F>(),
+                    1.elem::<F>(),
+                    dims.clone(),
+                    candle_device,
+                )
+                .unwrap()
+                .to_dtype(I::DTYPE)
+                .unwrap()
+                .lt(&super::candle_utils::fill(
+                    prob,
+                    dims,
+                    I::DTYPE,
+                    candle_device,
+                ))
+                .unwrap()
+                .to_dtype(I::DTYPE)
+                .unwrap(),
             ),
             Distribution::Uniform(from, to) => CandleTensor::new(
-                candle_core::Tensor::rand(from.elem::<F>(), to.elem::<F>(), shape, device).unwrap(),
+                candle_core::Tensor::rand(from.elem::<F>(), to.elem::<F>(), dims, candle_device)
+                    .unwrap(),
             ),
             Distribution::Normal(mean, std) => CandleTensor::new(
-                candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
+                candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), dims, candle_device)
                     .unwrap(),
             ),
+            distribution => Self::float_into_int(
+                float_random_derived::<Self>(shape, distribution, device, FloatDType::F32),
+                dtype,
+            ),
         }
     }
 
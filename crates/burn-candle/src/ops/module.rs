@@ -3,7 +3,9 @@ use burn_backend::{
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions,
         InterpolateMode, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
-        UnfoldOptions, attention::attention_fallback,
+        UnfoldOptions,
+        attention::attention_fallback,
+        interpolate::{interpolate_antialias, interpolate_antialias_backward},
     },
     tensor::{FloatTensor, IntTensor},
 };
@@ -289,6 +291,10 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        if options.antialias {
+            return interpolate_antialias::<Self>(x, output_size, &options);
+        }
+
         let tensor = match options.mode {
             InterpolateMode::Nearest => x
                 .tensor
@@ -306,6 +312,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
             InterpolateMode::Lanczos3 => {
                 panic!("lanczos3 interpolation is not supported by Candle")
             }
+            InterpolateMode::Area => {
+                panic!("area interpolation is not supported by Candle")
+            }
         };
 
         CandleTensor::new(tensor)
@@ -317,6 +326,10 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        if options.antialias {
+            return interpolate_antialias_backward::<Self>(x, grad, &options);
+        }
+
         panic!("interpolate_backward is not supported by Candle")
     }
 
@@ -3,7 +3,7 @@ use std::borrow::Borrow;
 use burn_backend::{
     DType, Distribution, ElementConversion, ExecutionError, FloatDType, Scalar, Shape, Slice,
     TensorData, bf16, f16,
-    ops::FloatTensorOps,
+    ops::{FloatTensorOps, float_random_derived},
     tensor::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor},
 };
 use burn_std::{BoolDType, IntDType};
@@ -38,32 +38,44 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
             return Self::float_from_data(cpu_random(shape, distribution, dtype.into()), device);
         }
 
-        let shape = shape.to_vec();
-        let device = &(device.clone()).into();
+        let dims = shape.to_vec();
+        let candle_device = &(device.clone()).into();
         match distribution {
             Distribution::Default => CandleTensor::new(
-                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape, device)
+                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), dims, candle_device)
                     .unwrap()
                     .to_dtype(F::DTYPE)
                     .unwrap(),
             ),
             Distribution::Bernoulli(prob) => CandleTensor::new(
-                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape.clone(), device)
-                    .unwrap()
-                    .to_dtype(F::DTYPE)
-                    .unwrap()
-                    .lt(&super::candle_utils::fill(prob, shape, F::DTYPE, device))
-                    .unwrap()
-                    .to_dtype(F::DTYPE)
-                    .unwrap(),
+                candle_core::Tensor::rand(
+                    0.elem::<F>(),
+                    1.elem::<F>(),
+                    dims.clone(),
+                    candle_device,
+                )
+                .unwrap()
+                .to_dtype(F::DTYPE)
+                .unwrap()
+                .lt(&super::candle_utils::fill(
+                    prob,
+                    dims,
+                    F::DTYPE,
+                    candle_device,
+                ))
+                .unwrap()
+                .to_dtype(F::DTYPE)
+                .unwrap(),
             ),
             Distribution::Uniform(from, to) => CandleTensor::new(
-                candle_core::Tensor::rand(from.elem::<F>(), to.elem::<F>(), shape, device).unwrap(),
+                candle_core::Tensor::rand(from.elem::<F>(), to.elem::<F>(), dims, candle_device)
+                    .unwrap(),
             ),
             Distribution::Normal(mean, std) => CandleTensor::new(
-                candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
+                candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), dims, candle_device)
                     .unwrap(),
             ),
+            distribution => float_random_derived::<Self>(shape, distribution, device, dtype),
         }
     }
 
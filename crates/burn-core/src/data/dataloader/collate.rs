@@ -0,0 +1,303 @@
+use super::batcher::Batcher;
+use burn_tensor::{AllocationProperty, Bytes, Device, Int, Tensor, TensorData};
+use std::sync::Arc;
+
+/// Assembles batches on the device, for data loaders whose workers decode the samples on the CPU.
+///
+/// Host samples ([TensorData]) of the same shape are packed into a single buffer of the
+/// [staging pool](StagingPool) and stacked along a new leading dimension with a single upload.
+/// They keep their data type until they reach the device, so integer samples such as `u8` images
+/// move 4 times less data than `f32` ones and are only converted to floats there.
+///
+/// Samples already uploaded to the device ([Tensor]) are concatenated along their leading
+/// dimension instead, without going through the host.
+///
+/// The batch can then be [normalized](DeviceCollator::with_normalization) on the device.
+#[derive(Clone)]
+pub struct DeviceCollator<const D: usize> {
+    pool: StagingPool,
+    normalization: Option<Arc<(Vec<f32>, Vec<f32>)>>,
+}
+
+impl<const D: usize> Default for DeviceCollator<D> {
+    fn default() -> Self {
+        Self::new(StagingPool::default())
+    }
+}
+
+impl<const D: usize> DeviceCollator<D> {
+    /// Creates a collator staging the host samples in the given pool.
+    pub fn new(pool: StagingPool) -> Self {
+        Self {
+            pool,
+            normalization: None,
+        }
+    }
+
+    /// Normalizes each channel (the dimension 1 of the batch) with its mean and standard
+    /// deviation after collation: `(batch - mean) / std`.
+    ///
+    /// # Panics
+    ///
+    /// When `mean` and `std` don't have the same number of channels.
+    pub fn with_normalization(mut self, mean: &[f32], std: &[f32]) -> Self {
+        assert_eq!(
+            mean.len(),
+            std.len(),
+            "The normalization mean and standard deviation must have the same number of channels"
+        );
+        self.normalization = Some(Arc::new((mean.to_vec(), std.to_vec())));
+        self
+    }
+
+    fn normalize(&self, batch: Tensor<D>) -> Tensor<D> {
+        let Some(normalization) = self.normalization.as_ref() else {
+            return batch;
+        };
+        let (mean, std) = normalization.as_ref();
+        assert!(
+            D >= 2,
+            "Normalization requires batches with a channel dimension"
+        );
+        assert_eq!(
+            batch.dims()[1],
+            mean.len(),
+            "The batch must have one channel per normalization statistic"
+        );
+
+        let mut shape = [1; D];
+        shape[1] = mean.len();
+        let device = batch.device();
+        let mean = Tensor::<1>::from_floats(mean.as_slice(), &device).reshape(shape);
+        let std = Tensor::<1>::from_floats(std.as_slice(), &device).reshape(shape);
+
+        (batch - mean) / std
+    }
+}
+
+impl<const D: usize> Batcher<TensorData, Tensor<D>> for DeviceCollator<D> {
+    /// Stacks the `D - 1` dimensional samples into a batch, with a single upload.
+    fn batch(&self, items: Vec<TensorData>, device: &Device) -> Tensor<D> {
+        let data = self.pool.stage(&items);
+        let dtype = data.dtype;
+
+        let batch = if dtype.is_float() {
+            Tensor::<D>::from_data(data, (device, dtype))
+        } else if dtype.is_int() || dtype.is_uint() {
+            Tensor::<D, Int>::from_data(data, (device, dtype)).float()
+        } else {
+            panic!("Device collation doesn't support {dtype:?} samples");
+        };
+
+        self.normalize(batch)
+    }
+}
+
+impl<const D: usize> Batcher<Tensor<D>, Tensor<D>> for DeviceCollator<D> {
+    /// Concatenates the samples along their leading dimension, on the given device.
+    fn batch(&self, items: Vec<Tensor<D>>, device: &Device) -> Tensor<D> {
+        let batch = Tensor::cat(items, 0).to_device(device);
+
+        self.normalize(batch)
+    }
+}
+
+/// A small pool of host buffers used to stage batches before uploading them to the device.
+///
+/// Each staged batch borrows a buffer from the pool, which is given back once the backend
+/// releases the uploaded data, so that the same allocations are reused from one batch to the
+/// next. At most `max_buffers` idle buffers are kept, the extra ones are freed. The pool is
+/// shared by its clones, and can be used from the worker threads of a
+/// [multi-threaded data loader](super::MultiThreadDataLoader).
+#[derive(Clone)]
+pub struct StagingPool {
+    buffers: Arc<spin::Mutex<Vec<Vec<u64>>>>,
+    max_buffers: usize,
+}
+
+impl Default for StagingPool {
+    /// A pool keeping up to 4 idle buffers.
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl StagingPool {
+    /// Creates a pool keeping up to `max_buffers` idle buffers.
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            buffers: Arc::new(spin::Mutex::new(Vec::with_capacity(max_buffers))),
+            max_buffers,
+        }
+    }
+
+    /// The number of idle buffers ready to be reused.
+    pub fn num_idle(&self) -> usize {
+        self.buffers.lock().len()
+    }
+
+    /// Packs samples of the same shape and data type into a staging buffer, as the data of a
+    /// tensor with a new leading dimension of size `samples.len()`.
+    ///
+    /// # Panics
+    ///
+    /// When there are no samples, or when their shapes or data types differ.
+    pub fn stage(&self, samples: &[TensorData]) -> TensorData {
+        let first = samples.first().expect("Can't stage an empty batch");
+        let sample_size = first.as_bytes().len();
+
+        let mut buffer = self.acquire(samples.len() * sample_size);
+        let mut offset = 0;
+        for sample in samples {
+            assert!(
+                sample.shape == first.shape && sample.dtype == first.dtype,
+                "Staged samples must share the same shape and data type, got {:?} {:?} and {:?} {:?}",
+                first.shape,
+                first.dtype,
+                sample.shape,
+                sample.dtype,
+            );
+            buffer.bytes_mut()[offset..offset + sample_size].copy_from_slice(sample.as_bytes());
+            offset += sample_size;
+        }
+
+        let mut shape = vec![samples.len()];
+        shape.extend_from_slice(first.shape.as_slice());
+        let bytes = Bytes::from_shared(bytes::Bytes::from_owner(buffer), AllocationProperty::Other);
+
+        TensorData::from_bytes(bytes, shape, first.dtype)
+    }
+
+    /// Borrows an idle buffer, or allocates a new one, holding `len` bytes.
+    fn acquire(&self, len: usize) -> StagingBuffer {
+        let mut words = self.buffers.lock().pop().unwrap_or_default();
+        words.clear();
+        words.resize(len.div_ceil(size_of::<u64>()), 0);
+
+        StagingBuffer {
+            words,
+            len,
+            pool: self.clone(),
+        }
+    }
+
+    fn release(&self, words: Vec<u64>) {
+        let mut buffers = self.buffers.lock();
+        if buffers.len() < self.max_buffers {
+            buffers.push(words);
+        }
+    }
+}
+
+/// A staging buffer, returned to its pool when dropped.
+///
+/// The bytes are stored in 8-byte words so that they are aligned for every data type.
+struct StagingBuffer {
+    words: Vec<u64>,
+    len: usize,
+    pool: StagingPool,
+}
+
+impl StagingBuffer {
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: the words hold at least `len` initialized bytes, and `u8` has no alignment
+        // requirement.
+        unsafe { core::slice::from_raw_parts_mut(self.words.as_mut_ptr().cast(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for StagingBuffer {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: the words hold at least `len` initialized bytes, and `u8` has no alignment
+        // requirement.
+        unsafe { core::slice::from_raw_parts(self.words.as_ptr().cast(), self.len) }
+    }
+}
+
+impl Drop for StagingBuffer {
+    fn drop(&mut self) {
+        self.pool.release(core::mem::take(&mut self.words));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tolerance;
+
+    type FT = f32;
+
+    #[test]
+    fn test_stage_packs_samples_and_recycles_buffer() {
+        let pool = StagingPool::new(1);
+        let samples = vec![
+            TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]),
+            TensorData::from([[5.0f32, 6.0], [7.0, 8.0]]),
+        ];
+
+        let staged = pool.stage(&samples);
+        assert_eq!(pool.num_idle(), 0);
+        assert_eq!(staged.shape.as_slice(), &[2, 2, 2]);
+        assert_eq!(
+            staged.to_vec::<f32>().unwrap(),
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+        );
+
+        drop(staged);
+        assert_eq!(pool.num_idle(), 1);
+
+        // The idle buffer is reused, and only `max_buffers` are kept.
+        let first = pool.stage(&samples);
+        let second = pool.stage(&samples);
+        assert_eq!(pool.num_idle(), 0);
+        drop(first);
+        drop(second);
+        assert_eq!(pool.num_idle(), 1);
+    }
+
+    #[test]
+    fn test_collate_integer_samples_and_normalize() {
+        let device = Default::default();
+        let collator = DeviceCollator::<3>::default().with_normalization(&[2.0, 4.0], &[2.0, 0.5]);
+        let samples = vec![
+            TensorData::from([[0u8, 2], [4, 6]]),
+            TensorData::from([[8u8, 10], [12, 14]]),
+        ];
+
+        let batch = collator.batch(samples, &device);
+
+        let expected = TensorData::from([[[-1.0, 0.0], [0.0, 4.0]], [[3.0, 4.0], [16.0, 20.0]]]);
+        batch
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_collate_device_samples() {
+        let device = Default::default();
+        let collator = DeviceCollator::<2>::default();
+        let samples = vec![
+            Tensor::<2>::from_floats([[1.0, 2.0]], &device),
+            Tensor::<2>::from_floats([[3.0, 4.0], [5.0, 6.0]], &device),
+        ];
+
+        let batch = collator.batch(samples, &device);
+
+        let expected = TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        batch
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic = "Staged samples must share the same shape and data type"]
+    fn test_stage_rejects_mismatched_samples() {
+        let pool = StagingPool::default();
+        let samples = vec![
+            TensorData::from([1.0f32, 2.0]),
+            TensorData::from([1.0f32, 2.0, 3.0]),
+        ];
+
+        pool.stage(&samples);
+    }
+}
@@ -0,0 +1,113 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A dataset, batcher or any other part of the data pipeline that adapts to the progress of the
+/// training, such as a difficulty schedule or a sequence length warmup.
+///
+/// The training loop calls the hooks of the registered curricula, so the implementations are
+/// usually cheap handles to a state shared with the data pipeline, like the
+/// [curriculum progress](CurriculumProgress). Both hooks do nothing by default.
+pub trait CurriculumAware: Send + Sync {
+    /// Called before each training epoch, before its data loader iterator is created, so that
+    /// the whole epoch sees the new state.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch about to start, from 1.
+    /// * `num_epochs` - The total number of epochs.
+    #[allow(unused_variables)]
+    fn on_epoch_start(&self, epoch: usize, num_epochs: usize) {}
+
+    /// Called after each training step.
+    ///
+    /// Data loaders prepare their batches ahead of the training steps, so a
+    /// [multi-threaded data loader](super::MultiThreadDataLoader) only sees the new state a few
+    /// batches later.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The current epoch, from 1.
+    /// * `iteration` - The number of steps completed in the current epoch.
+    #[allow(unused_variables)]
+    fn on_step(&self, epoch: usize, iteration: usize) {}
+}
+
+/// The progress of the training, shared between the training loop and the data pipeline.
+///
+/// Clones share the same state: register one with the learner, and read it from the clones kept
+/// by the datasets and batchers, including on the worker threads of the data loaders.
+#[derive(Clone, Debug, Default)]
+pub struct CurriculumProgress {
+    state: Arc<ProgressState>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    epoch: AtomicUsize,
+    num_epochs: AtomicUsize,
+    iteration: AtomicUsize,
+}
+
+impl CurriculumProgress {
+    /// Creates the progress, at epoch 0 before the training starts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current epoch, from 1, or 0 before the training starts.
+    pub fn epoch(&self) -> usize {
+        self.state.epoch.load(Ordering::Relaxed)
+    }
+
+    /// The total number of epochs, or 0 before the training starts.
+    pub fn num_epochs(&self) -> usize {
+        self.state.num_epochs.load(Ordering::Relaxed)
+    }
+
+    /// The number of steps completed in the current epoch.
+    pub fn iteration(&self) -> usize {
+        self.state.iteration.load(Ordering::Relaxed)
+    }
+
+    /// The fraction of the epochs completed before the current one, between 0 and 1, to
+    /// interpolate a schedule over the training.
+    pub fn fraction(&self) -> f64 {
+        match self.num_epochs() {
+            0 => 0.0,
+            num_epochs => self.epoch().saturating_sub(1) as f64 / num_epochs as f64,
+        }
+    }
+}
+
+impl CurriculumAware for CurriculumProgress {
+    fn on_epoch_start(&self, epoch: usize, num_epochs: usize) {
+        self.state.epoch.store(epoch, Ordering::Relaxed);
+        self.state.num_epochs.store(num_epochs, Ordering::Relaxed);
+        self.state.iteration.store(0, Ordering::Relaxed);
+    }
+
+    fn on_step(&self, epoch: usize, iteration: usize) {
+        self.state.epoch.store(epoch, Ordering::Relaxed);
+        self.state.iteration.store(iteration, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curriculum_progress_is_shared_by_clones() {
+        let progress = CurriculumProgress::new();
+        let batcher_view = progress.clone();
+        assert_eq!(batcher_view.fraction(), 0.0);
+
+        progress.on_epoch_start(3, 4);
+        progress.on_step(3, 12);
+
+        assert_eq!(batcher_view.epoch(), 3);
+        assert_eq!(batcher_view.num_epochs(), 4);
+        assert_eq!(batcher_view.iteration(), 12);
+        assert_eq!(batcher_view.fraction(), 0.5);
+    }
+}
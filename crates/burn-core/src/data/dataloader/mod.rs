@@ -1,6 +1,8 @@
 mod base;
 mod batch;
 mod builder;
+mod collate;
+mod curriculum;
 mod multithread;
 mod strategy;
 
@@ -12,5 +14,7 @@ pub mod split;
 pub use base::*;
 pub use batch::*;
 pub use builder::*;
+pub use collate::*;
+pub use curriculum::*;
 pub use multithread::*;
 pub use strategy::*;
@@ -0,0 +1,479 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+use burn_tensor::{DType, Element, TensorData, Tolerance, bf16, f16};
+use num_traits::{Float, ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+/// The version of the golden file format written by [GoldenFile].
+pub const GOLDEN_FORMAT_VERSION: u32 = 1;
+
+/// The environment variable that makes [GoldenFile] record the missing entries and overwrite the
+/// mismatching ones instead of failing, when set to a value other than `0`.
+pub const GOLDEN_UPDATE_ENV: &str = "BURN_GOLDEN_UPDATE";
+
+/// The tolerances used to compare the entries of a [GoldenFile], per floating point data type.
+///
+/// The data type of the recorded entry selects the tolerance, and both tensors are compared in
+/// that data type. Integer and boolean entries must be equal.
+#[derive(Debug, Clone, Copy)]
+pub struct GoldenTolerance {
+    /// Tolerance of `f64` entries.
+    pub f64: Tolerance<f64>,
+    /// Tolerance of `f32` and `flex32` entries.
+    pub f32: Tolerance<f32>,
+    /// Tolerance of `f16` entries.
+    pub f16: Tolerance<f16>,
+    /// Tolerance of `bf16` entries.
+    pub bf16: Tolerance<bf16>,
+}
+
+impl Default for GoldenTolerance {
+    fn default() -> Self {
+        Self {
+            f64: Tolerance::default(),
+            f32: Tolerance::default(),
+            f16: Tolerance::permissive(),
+            bf16: Tolerance::permissive(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct GoldenRecord {
+    format_version: u32,
+    burn_version: String,
+    entries: BTreeMap<String, GoldenEntry>,
+}
+
+/// A tensor written with its values as numbers, so that the changes to a golden file can be
+/// reviewed.
+#[derive(Serialize, Deserialize)]
+struct GoldenEntry {
+    dtype: DType,
+    shape: Vec<usize>,
+    values: GoldenValues,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum GoldenValues {
+    Float(Vec<GoldenFloat>),
+    Int(Vec<i64>),
+    UInt(Vec<u64>),
+    Bool(Vec<bool>),
+}
+
+/// A floating point value, written as a string when it isn't finite since JSON has no number for
+/// it.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(untagged)]
+enum GoldenFloat {
+    Finite(f64),
+    NonFinite(NonFinite),
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+enum NonFinite {
+    #[serde(rename = "NaN")]
+    NaN,
+    #[serde(rename = "inf")]
+    Infinity,
+    #[serde(rename = "-inf")]
+    NegInfinity,
+}
+
+impl From<f64> for GoldenFloat {
+    fn from(value: f64) -> Self {
+        match value {
+            value if value.is_finite() => Self::Finite(value),
+            value if value.is_nan() => Self::NonFinite(NonFinite::NaN),
+            value if value > 0.0 => Self::NonFinite(NonFinite::Infinity),
+            _ => Self::NonFinite(NonFinite::NegInfinity),
+        }
+    }
+}
+
+impl From<GoldenFloat> for f64 {
+    fn from(value: GoldenFloat) -> Self {
+        match value {
+            GoldenFloat::Finite(value) => value,
+            GoldenFloat::NonFinite(NonFinite::NaN) => f64::NAN,
+            GoldenFloat::NonFinite(NonFinite::Infinity) => f64::INFINITY,
+            GoldenFloat::NonFinite(NonFinite::NegInfinity) => f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl GoldenEntry {
+    /// Writes the values of the data, dequantized for quantized data. Every element type converts
+    /// to `f64`, `i64` or `u64` without loss.
+    fn new(data: &TensorData) -> Self {
+        let (dtype, values) = match data.dtype {
+            DType::QFloat(_) => (
+                DType::F32,
+                GoldenValues::Float(data.iter::<f64>().map(GoldenFloat::from).collect()),
+            ),
+            dtype if dtype.is_float() => (
+                dtype,
+                GoldenValues::Float(data.iter::<f64>().map(GoldenFloat::from).collect()),
+            ),
+            dtype if dtype.is_int() => (dtype, GoldenValues::Int(data.iter::<i64>().collect())),
+            dtype if dtype.is_uint() => (dtype, GoldenValues::UInt(data.iter::<u64>().collect())),
+            dtype => (dtype, GoldenValues::Bool(data.iter::<bool>().collect())),
+        };
+
+        Self {
+            dtype,
+            shape: data.shape.to_vec(),
+            values,
+        }
+    }
+
+    fn to_data(&self) -> TensorData {
+        let shape = self.shape.clone();
+        let data = match &self.values {
+            GoldenValues::Float(values) => TensorData::new(
+                values.iter().map(|&value| f64::from(value)).collect(),
+                shape,
+            ),
+            GoldenValues::Int(values) => TensorData::new(values.clone(), shape),
+            GoldenValues::UInt(values) => TensorData::new(values.clone(), shape),
+            GoldenValues::Bool(values) => TensorData::new(values.clone(), shape),
+        };
+
+        data.convert_dtype(self.dtype)
+    }
+}
+
+/// A golden file: a versioned snapshot of named tensors, such as the outputs of a model for fixed
+/// seeds and inputs, used as a regression test across backends and releases.
+///
+/// Each [check](GoldenFile::check) compares a tensor with the entry of the same name, and the
+/// missing and mismatching entries are reported when the golden file is
+/// [finished](GoldenFile::finish). To record new entries or accept new values, run the tests with
+/// the `BURN_GOLDEN_UPDATE=1` environment variable, which writes the missing entries and
+/// overwrites the mismatching ones.
+///
+/// The file is written as pretty JSON, with the values of the tensors as numbers, so that the
+/// changes show up in code reviews.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let device = Default::default();
+/// device.seed(42);
+/// let model = ModelConfig::new().init(&device);
+/// let input = Tensor::<2>::random([4, 8], Distribution::Default, &device);
+///
+/// let mut golden = GoldenFile::open("tests/golden/model.json");
+/// golden.check("output", model.forward(input).into_data());
+/// golden.finish();
+/// ```
+pub struct GoldenFile {
+    path: PathBuf,
+    record: GoldenRecord,
+    tolerance: GoldenTolerance,
+    update: bool,
+    changed: bool,
+    failures: Vec<String>,
+}
+
+impl GoldenFile {
+    /// Opens the golden file at the given path, or starts an empty one if it doesn't exist.
+    ///
+    /// # Panics
+    ///
+    /// If the file can't be read, or if it was written with a newer format version.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let record = match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                let record: GoldenRecord = serde_json::from_str(&content).unwrap_or_else(|err| {
+                    panic!("Failed to parse the golden file {}: {err}", path.display())
+                });
+                assert!(
+                    record.format_version <= GOLDEN_FORMAT_VERSION,
+                    "The golden file {} has the format version {}, which is newer than the \
+                     supported version {GOLDEN_FORMAT_VERSION}.",
+                    path.display(),
+                    record.format_version
+                );
+                record
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => GoldenRecord {
+                format_version: GOLDEN_FORMAT_VERSION,
+                burn_version: env!("CARGO_PKG_VERSION").to_string(),
+                entries: BTreeMap::new(),
+            },
+            Err(err) => panic!("Failed to read the golden file {}: {err}", path.display()),
+        };
+        let update = std::env::var(GOLDEN_UPDATE_ENV).is_ok_and(|value| value != "0");
+
+        Self {
+            path,
+            record,
+            tolerance: GoldenTolerance::default(),
+            update,
+            changed: false,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Sets the tolerances of the comparisons.
+    pub fn with_tolerance(mut self, tolerance: GoldenTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets if the missing and mismatching entries are written instead of reported, which
+    /// defaults to the `BURN_GOLDEN_UPDATE` environment variable.
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// The Burn version that wrote the golden file.
+    pub fn burn_version(&self) -> &str {
+        &self.record.burn_version
+    }
+
+    /// Compares the data with the entry of the given name.
+    pub fn check(&mut self, name: &str, data: TensorData) {
+        let result = match self.record.entries.get(name) {
+            Some(expected) => compare(&expected.to_data(), &data, &self.tolerance),
+            None => Err("the entry is missing".to_string()),
+        };
+
+        if let Err(message) = result {
+            if self.update {
+                self.record
+                    .entries
+                    .insert(name.to_string(), GoldenEntry::new(&data));
+                self.changed = true;
+            } else {
+                self.failures.push(format!("{name}: {message}"));
+            }
+        }
+    }
+
+    /// Writes the golden file if entries were recorded, and reports the missing and mismatching
+    /// entries.
+    ///
+    /// # Panics
+    ///
+    /// If an entry is missing or doesn't match, or if the file can't be written.
+    pub fn finish(mut self) {
+        if self.changed {
+            self.record.format_version = GOLDEN_FORMAT_VERSION;
+            self.record.burn_version = env!("CARGO_PKG_VERSION").to_string();
+            if let Some(parent) = self.path.parent() {
+                std::fs::create_dir_all(parent).unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to create the golden directory {}: {err}",
+                        parent.display()
+                    )
+                });
+            }
+            let content = serde_json::to_string_pretty(&self.record).unwrap();
+            std::fs::write(&self.path, content).unwrap_or_else(|err| {
+                panic!(
+                    "Failed to write the golden file {}: {err}",
+                    self.path.display()
+                )
+            });
+        }
+
+        assert!(
+            self.failures.is_empty(),
+            "{} entries of the golden file {} are missing or don't match, run with \
+             {GOLDEN_UPDATE_ENV}=1 to update them:\n  {}",
+            self.failures.len(),
+            self.path.display(),
+            self.failures.join("\n  ")
+        );
+    }
+}
+
+/// Compares the data with the expected data in the data type of the expected data.
+fn compare(
+    expected: &TensorData,
+    actual: &TensorData,
+    tolerance: &GoldenTolerance,
+) -> Result<(), String> {
+    if expected.shape != actual.shape {
+        return Err(format!(
+            "the shape {:?} != the expected shape {:?}",
+            actual.shape, expected.shape
+        ));
+    }
+
+    match expected.dtype {
+        DType::F64 => compare_float(expected, actual, tolerance.f64),
+        DType::F32 | DType::Flex32 | DType::QFloat(_) => {
+            compare_float(expected, actual, tolerance.f32)
+        }
+        DType::F16 => compare_float(expected, actual, tolerance.f16),
+        DType::BF16 => compare_float(expected, actual, tolerance.bf16),
+        _ => {
+            let num_diff = expected
+                .iter::<i64>()
+                .zip(actual.iter::<i64>())
+                .filter(|(expected, actual)| expected != actual)
+                .count();
+            match num_diff {
+                0 => Ok(()),
+                _ => Err(format!(
+                    "{num_diff} of {} elements differ",
+                    expected.num_elements()
+                )),
+            }
+        }
+    }
+}
+
+fn compare_float<F: Float + Element>(
+    expected: &TensorData,
+    actual: &TensorData,
+    tolerance: Tolerance<F>,
+) -> Result<(), String> {
+    let mut num_diff = 0;
+    let mut max_error = 0.0f64;
+    let mut sum_error = 0.0f64;
+
+    for (expected, actual) in expected.iter::<F>().zip(actual.iter::<F>()) {
+        let both_nan = expected.is_nan() && actual.is_nan();
+        if !both_nan && !tolerance.approx_eq(expected, actual) {
+            num_diff += 1;
+        }
+
+        let error = (expected - actual).abs().to_f64().unwrap_or(f64::NAN);
+        if error.is_finite() {
+            max_error = max_error.max(error);
+            sum_error += error;
+        }
+    }
+
+    match num_diff {
+        0 => Ok(()),
+        _ => {
+            let num_elements = expected.num_elements();
+            Err(format!(
+                "{num_diff} of {num_elements} elements differ (max abs error {max_error:.3e}, \
+                 mean abs error {:.3e})",
+                sum_error / num_elements as f64
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("burn-golden-{name}.json"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_golden_file_records_and_matches() {
+        let path = golden_path("records");
+
+        let mut golden = GoldenFile::open(&path).with_update(true);
+        golden.check("output", TensorData::from([1.0f32, 2.0, 3.0]));
+        golden.check("classes", TensorData::from([1i64, 0]));
+        golden.finish();
+
+        let mut golden = GoldenFile::open(&path).with_update(false);
+        assert_eq!(golden.burn_version(), env!("CARGO_PKG_VERSION"));
+        golden.check("output", TensorData::from([1.0f32, 2.0, 3.000_001]));
+        // Integer entries are compared in the recorded data type.
+        golden.check("classes", TensorData::from([1i32, 0]));
+        golden.finish();
+    }
+
+    #[test]
+    #[should_panic = "output: 1 of 3 elements differ"]
+    fn test_golden_file_mismatch() {
+        let path = golden_path("mismatch");
+
+        let mut golden = GoldenFile::open(&path).with_update(true);
+        golden.check("output", TensorData::from([1.0f32, 2.0, 3.0]));
+        golden.finish();
+
+        let mut golden = GoldenFile::open(&path).with_update(false);
+        golden.check("output", TensorData::from([1.0f32, 2.5, 3.0]));
+        golden.finish();
+    }
+
+    #[test]
+    fn test_golden_file_update() {
+        let path = golden_path("update");
+
+        let mut golden = GoldenFile::open(&path).with_update(true);
+        golden.check("output", TensorData::from([1.0f32, 2.0]));
+        golden.finish();
+
+        let mut golden = GoldenFile::open(&path).with_update(true);
+        golden.check("output", TensorData::from([4.0f32, 2.0]));
+        golden.finish();
+
+        let mut golden = GoldenFile::open(&path).with_update(false);
+        golden.check("output", TensorData::from([4.0f32, 2.0]));
+        golden.finish();
+    }
+
+    #[test]
+    #[should_panic = "output: the entry is missing"]
+    fn test_golden_file_missing_entry() {
+        let path = golden_path("missing");
+
+        let mut golden = GoldenFile::open(&path).with_update(false);
+        golden.check("output", TensorData::from([1.0f32, 2.0]));
+        golden.finish();
+    }
+
+    #[test]
+    fn test_golden_file_writes_numbers() {
+        let path = golden_path("numbers");
+
+        let mut golden = GoldenFile::open(&path).with_update(true);
+        golden.check(
+            "output",
+            TensorData::from([1.5f32, f32::NAN, f32::NEG_INFINITY]),
+        );
+        golden.check("mask", TensorData::from([true, false]));
+        golden.finish();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("1.5"));
+        assert!(content.contains("\"NaN\""));
+        assert!(content.contains("\"-inf\""));
+        assert!(content.contains("false"));
+
+        let golden = GoldenFile::open(&path);
+        let output = golden.record.entries["output"].to_data();
+        assert_eq!(output.dtype, DType::F32);
+        let values = output.iter::<f32>().collect::<Vec<_>>();
+        assert_eq!(values[0], 1.5);
+        assert!(values[1].is_nan());
+        assert_eq!(values[2], f32::NEG_INFINITY);
+        let mask = golden.record.entries["mask"].to_data();
+        assert_eq!(mask.iter::<bool>().collect::<Vec<_>>(), [true, false]);
+    }
+
+    #[test]
+    fn test_golden_tolerance_per_dtype() {
+        let tolerance = GoldenTolerance::default();
+        let expected = TensorData::from([1.0f32, 2.0]).convert::<f16>();
+        let actual = TensorData::from([1.005f32, 2.0]);
+
+        assert!(compare(&expected, &actual, &tolerance).is_ok());
+        assert!(compare(&expected.convert::<f32>(), &actual, &tolerance).is_err());
+        assert!(compare(&TensorData::from([1.0f32]), &actual, &tolerance).is_err());
+    }
+}
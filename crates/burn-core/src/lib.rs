@@ -18,6 +18,10 @@ pub mod config;
 #[cfg(feature = "std")]
 pub mod data;
 
+/// Golden file regression testing module.
+#[cfg(feature = "std")]
+pub mod golden;
+
 /// Module for the neural network module.
 pub mod module;
 
@@ -0,0 +1,188 @@
+use core::hash::{Hash, Hasher};
+
+use alloc::vec::Vec;
+use burn_tensor::{Tensor, TensorData, kind::Basic};
+use hashbrown::HashMap;
+
+use super::{Module, ParamId, list_param_ids};
+
+/// The key of a computation memoized in a [memoization scope](MemoScope).
+///
+/// Two keys are equal when they have the same name, the same module parameters and the same input
+/// values. The parameters are compared by [id](ParamId), so two clones of a module share the same
+/// key, while the inputs are compared by value, which requires reading them from the device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoKey {
+    name: &'static str,
+    params: Vec<ParamId>,
+    inputs: Vec<TensorData>,
+}
+
+impl MemoKey {
+    /// Create a new key for the computation with the given name.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            params: Vec::new(),
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Add the parameters of a module to the key.
+    pub fn with_module<M: Module>(mut self, module: &M) -> Self {
+        self.params.extend(list_param_ids(module));
+        self
+    }
+
+    /// Add the values of a tensor to the key.
+    pub fn with_tensor<const D: usize, K: Basic>(mut self, tensor: &Tensor<D, K>) -> Self {
+        self.inputs.push(tensor.to_data());
+        self
+    }
+}
+
+impl Hash for MemoKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.params.hash(state);
+        for input in self.inputs.iter() {
+            input.dtype.hash(state);
+            input.shape.as_slice().hash(state);
+            input.as_bytes().hash(state);
+        }
+    }
+}
+
+/// An opt-in scope that memoizes the outputs of repeated computations.
+///
+/// When the same layer is applied many times to identical inputs, such as when scoring the shared
+/// prefixes of the hypotheses in a beam search, the scope returns the output computed the first
+/// time instead of running the layer again.
+///
+/// The parameters are identified by their [id](ParamId), not their values, so a scope must not
+/// outlive an update of the weights of the memoized modules. Building a key also reads the inputs
+/// from the device, so memoization only pays off when the memoized computation is expensive
+/// compared to the size of its inputs.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let mut scope = MemoScope::new();
+///
+/// for hypothesis in hypotheses {
+///     let output = scope.forward("decoder", &decoder, hypothesis, |input| decoder.forward(input));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MemoScope<O> {
+    cache: HashMap<MemoKey, O>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<O> Default for MemoScope<O> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<O: Clone> MemoScope<O> {
+    /// Create a new empty scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run the forward pass of a module on the input, or return the memoized output if the same
+    /// module was already applied to the same input in this scope.
+    pub fn forward<M, const D: usize, F>(
+        &mut self,
+        name: &'static str,
+        module: &M,
+        input: Tensor<D>,
+        forward: F,
+    ) -> O
+    where
+        M: Module,
+        F: FnOnce(Tensor<D>) -> O,
+    {
+        let key = MemoKey::new(name).with_module(module).with_tensor(&input);
+        self.get_or_compute(key, || forward(input))
+    }
+
+    /// Return the memoized output for the key, or compute and memoize it.
+    pub fn get_or_compute<F>(&mut self, key: MemoKey, compute: F) -> O
+    where
+        F: FnOnce() -> O,
+    {
+        if let Some(output) = self.cache.get(&key) {
+            self.hits += 1;
+            return output.clone();
+        }
+
+        self.misses += 1;
+        let output = compute();
+        self.cache.insert(key, output.clone());
+        output
+    }
+
+    /// The number of computations that returned a memoized output.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of computations that were run.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Remove all the memoized outputs.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestDevice, test_utils::SimpleLinear};
+    use burn_tensor::Device;
+
+    #[test]
+    fn should_reuse_output_for_identical_inputs() {
+        let device = Device::new(TestDevice::default());
+        let module = SimpleLinear::new(4, 2, &device);
+        let forward = |input: Tensor<2>| input.matmul(module.weight.val().transpose());
+        let mut scope = MemoScope::new();
+
+        let input = Tensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0]], &device);
+        let first = scope.forward("linear", &module, input, forward);
+        let input = Tensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0]], &device);
+        let second = scope.forward("linear", &module, input, forward);
+        let input = Tensor::<2>::from_floats([[4.0, 3.0, 2.0, 1.0]], &device);
+        let _third = scope.forward("linear", &module, input, forward);
+
+        first.into_data().assert_eq(&second.into_data(), true);
+        assert_eq!(scope.hits(), 1);
+        assert_eq!(scope.misses(), 2);
+    }
+
+    #[test]
+    fn should_distinguish_modules() {
+        let device = Device::new(TestDevice::default());
+        let module_1 = SimpleLinear::new(4, 4, &device);
+        let module_2 = SimpleLinear::new(4, 4, &device);
+        let mut scope = MemoScope::new();
+
+        let input = Tensor::<2>::ones([1, 4], &device);
+        let _ = scope.forward("linear", &module_1, input.clone(), |x| x);
+        let _ = scope.forward("linear", &module_2, input.clone(), |x| x);
+        let _ = scope.forward("linear", &module_1.clone(), input, |x| x);
+
+        assert_eq!(scope.hits(), 1);
+        assert_eq!(scope.misses(), 2);
+    }
+}
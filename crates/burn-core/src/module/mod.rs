@@ -1,11 +1,13 @@
 mod base;
 mod display;
 mod initializer;
+mod memo;
 mod param;
 mod quantize;
 
 pub use base::*;
 pub use display::*;
 pub use initializer::*;
+pub use memo::*;
 pub use param::*;
 pub use quantize::*;
@@ -1,5 +1,6 @@
 use super::{Param, ParamId};
 use crate::module::{Module, ModuleVisitor};
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use burn_tensor::{Bool, Int, Tensor};
 use core::marker::PhantomData;
@@ -35,3 +36,99 @@ pub fn list_param_ids<M: Module>(module: &M) -> Vec<ParamId> {
 
     params_ids
 }
+
+struct ParamPathCollector<'a> {
+    pattern: &'a str,
+    path: Vec<String>,
+    param_ids: Vec<ParamId>,
+}
+
+impl ParamPathCollector<'_> {
+    fn collect(&mut self, id: ParamId) {
+        if path_matches(self.pattern, &self.path.join(".")) {
+            self.param_ids.push(id);
+        }
+    }
+}
+
+impl ModuleVisitor for ParamPathCollector<'_> {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        self.collect(param.id);
+    }
+    fn visit_int<const D: usize>(&mut self, param: &Param<Tensor<D, Int>>) {
+        self.collect(param.id);
+    }
+    fn visit_bool<const D: usize>(&mut self, param: &Param<Tensor<D, Bool>>) {
+        self.collect(param.id);
+    }
+}
+
+/// List the ids of the parameters of a module whose path matches a pattern.
+///
+/// The path of a parameter is the names of its fields joined by dots, such as
+/// `encoder.layers.0.linear.weight`, and the pattern may use `*` to match any sequence of
+/// characters, such as `encoder.*.weight` or `*.bias`.
+pub fn list_param_ids_matching<M: Module>(module: &M, pattern: &str) -> Vec<ParamId> {
+    let mut visitor = ParamPathCollector {
+        pattern,
+        path: Vec::new(),
+        param_ids: Vec::new(),
+    };
+    module.visit(&mut visitor);
+
+    visitor.param_ids
+}
+
+/// Whether a parameter path matches a pattern, where `*` matches any sequence of characters.
+///
+/// See [list_param_ids_matching].
+pub fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let path = path.as_bytes();
+    let (mut p, mut s) = (0, 0);
+    // The position of the last `*` in the pattern and of the path when it was reached.
+    let mut backtrack = None;
+
+    while s < path.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, s));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == path[s] {
+            p += 1;
+            s += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` match one more character.
+            p = star + 1;
+            s = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_path_patterns() {
+        assert!(path_matches(
+            "encoder.layers.0.weight",
+            "encoder.layers.0.weight"
+        ));
+        assert!(path_matches("encoder.*.weight", "encoder.layers.0.weight"));
+        assert!(path_matches("*.bias", "decoder.linear.bias"));
+        assert!(path_matches("*", "weight"));
+        assert!(!path_matches("encoder.*", "decoder.weight"));
+        assert!(!path_matches("*.bias", "decoder.linear.bias_scale"));
+    }
+}
@@ -6,10 +6,11 @@ use crate::{
     tune::{FusionInputGen, TuneInput},
 };
 use burn_fusion::stream::Context;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     AutotuneKey, CubeTuneId, Runtime,
     std::tensor::MatrixBatchLayout,
-    tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner},
+    tune::{LocalTuner, TunableSet, TuneGroup, local_tuner},
 };
 use cubek::matmul::{
     definition::MatmulKind,
@@ -121,7 +122,7 @@ pub fn fused_matmul_autotune<R: Runtime>(
 
         // First entry should always work, since it is considered the fallback.
         let mut set = TunableSet::new(create_key::<R>, FusionInputGen).with(
-            Tunable::new("fused_matmul_fallback", tune_fallback::<R>).group(&unit, |key| {
+            budgeted_tunable("fused_matmul_fallback", tune_fallback::<R>).group(&unit, |key| {
                 if matches!(key.matmul_key.analysis.kind, MatmulKind::InnerProduct) {
                     PRIORITY_MAX
                 } else if matches!(
@@ -143,7 +144,7 @@ pub fn fused_matmul_autotune<R: Runtime>(
             (FusedMatmulSelector::GemvUnitPerpendicular, false),
         ] {
             set = set.with(
-                Tunable::new(&selector.name(), move |input| {
+                budgeted_tunable(&selector.name(), move |input| {
                     tune_fused::<R>(input, selector)
                 })
                 .group(&gemv, move |key| match double_buf {
@@ -159,7 +160,7 @@ pub fn fused_matmul_autotune<R: Runtime>(
             (FusedMatmulSelector::DoubleUnit, true),
         ] {
             set = set.with(
-                Tunable::new(&selector.name(), move |input| {
+                budgeted_tunable(&selector.name(), move |input| {
                     tune_fused::<R>(input, selector)
                 })
                 .group(&unit, move |key| match double_buf {
@@ -215,7 +216,7 @@ pub fn fused_matmul_autotune<R: Runtime>(
                         false => PRIORITY_MAX,
                         true => double_buffering_priority(key, PRIORITY_MAX, PRIORITY_HIGH),
                     };
-                let mut tunable = Tunable::new(&selector.name(), move |input| {
+                let mut tunable = budgeted_tunable(&selector.name(), move |input| {
                     tune_fused::<R>(input, selector)
                 })
                 .group(&accelerated, move |key| {
@@ -233,12 +234,14 @@ pub fn fused_matmul_autotune<R: Runtime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&optimization.info.client, &optimization.info.device),
-        &optimization.info.client.clone(),
-        tunables,
-        TuneInput::new(context, optimization),
-    );
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&optimization.info.client, &optimization.info.device),
+            &optimization.info.client.clone(),
+            tunables,
+            TuneInput::new(context, optimization),
+        )
+    });
 }
 
 pub(crate) fn create_key<R: Runtime>(
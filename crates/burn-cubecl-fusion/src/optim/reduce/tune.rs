@@ -5,9 +5,10 @@ use crate::{
     tune::{FusionInputGen, TuneInput},
 };
 use burn_fusion::stream::Context;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     AutotuneKey, CubeTuneId, Runtime,
-    tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner},
+    tune::{LocalTuner, TunableSet, TuneGroup, local_tuner},
 };
 use cubek::reduce::{
     launch::{RoutineStrategy, tune_key::ReduceAutotuneKey},
@@ -48,7 +49,10 @@ pub fn fused_reduce_autotune<R: Runtime>(
         let group = TuneGroup::<FusedReduceAutotuneKey>::new("fused_reduce", |_key| PRIORITY_MAX);
 
         // Fallback implementation for robustness.
-        set = set.with(Tunable::new("fused_reduce_fallback", tune_fallback::<R>));
+        set = set.with(budgeted_tunable(
+            "fused_reduce_fallback",
+            tune_fallback::<R>,
+        ));
 
         // Define properties to categorize hardware strategies.
         enum ReduceProps {
@@ -82,7 +86,7 @@ pub fn fused_reduce_autotune<R: Runtime>(
         ];
 
         for (name, strategy, props) in strategies {
-            let tunable = Tunable::new(name, move |input| tune_reduce::<R>(input, &strategy))
+            let tunable = budgeted_tunable(name, move |input| tune_reduce::<R>(input, &strategy))
                 .group(&group, move |key| match props {
                     ReduceProps::GreatWithLowReduceCount => {
                         if key.reduce_key.vector_count < 128 {
@@ -107,12 +111,14 @@ pub fn fused_reduce_autotune<R: Runtime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&arg.info.client, &arg.info.device),
-        &arg.info.client.clone(),
-        tunables,
-        TuneInput::new(context, arg),
-    );
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&arg.info.client, &arg.info.device),
+            &arg.info.client.clone(),
+            tunables,
+            TuneInput::new(context, arg),
+        )
+    });
 }
 
 /// Creates the autotune key by extracting tensor metadata and fusion block statistics.
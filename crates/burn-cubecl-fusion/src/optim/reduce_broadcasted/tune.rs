@@ -6,9 +6,10 @@ use crate::{
     tune::{FusionInputGen, TuneInput},
 };
 use burn_fusion::stream::Context;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     AutotuneKey, CubeTuneId, Runtime,
-    tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner},
+    tune::{LocalTuner, TunableSet, TuneGroup, local_tuner},
 };
 use cubek::reduce::{
     launch::{RoutineStrategy, tune_key::ReduceAutotuneKey},
@@ -52,14 +53,14 @@ pub fn fused_broadcasted_reduce_autotune<R: Runtime>(
         );
 
         // Standard fallback implementation - guaranteed to work.
-        set = set.with(Tunable::new(
+        set = set.with(budgeted_tunable(
             "fused_reduce_broadcasted_fallback",
             tune_fallback::<R>,
         ));
 
         // Specialized unit strategy for fused reductions.
         set = set.with(
-            Tunable::new("fused_reduce_broadcasted_unit", move |input| {
+            budgeted_tunable("fused_reduce_broadcasted_unit", move |input| {
                 tune_reduce::<R>(
                     input,
                     &RoutineStrategy::Unit(BlueprintStrategy::Inferred(UnitStrategy)),
@@ -71,12 +72,14 @@ pub fn fused_broadcasted_reduce_autotune<R: Runtime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&arg.client, &arg.device),
-        &arg.client.clone(),
-        tunables,
-        TuneInput::new(context, arg),
-    );
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&arg.client, &arg.device),
+            &arg.client.clone(),
+            tunables,
+            TuneInput::new(context, arg),
+        )
+    });
 }
 
 /// Generates the autotune key based on the current optimization context and trace blocks.
@@ -1,6 +1,9 @@
-use crate::{CubeRuntime, FloatElement, IntElement, element::BoolElement, tensor::CubeTensor};
+use crate::{
+    CubeRuntime, FloatElement, IntElement, element::BoolElement, quota, tensor::CubeTensor,
+};
 use burn_backend::{
-    Backend, BackendTypes, DTypeUsage, DTypeUsageSet, DeviceOps, ExecutionError, TensorData,
+    Backend, BackendTypes, DTypeUsage, DTypeUsageSet, DeviceOps, DeviceProperties, ExecutionError,
+    MemoryQuota, MemoryQuotaError, TensorData,
 };
 use burn_std::{BoolStore, DType};
 use cubecl::{
@@ -86,6 +89,14 @@ where
         client.memory_persistent_allocation(input, func).unwrap()
     }
 
+    fn memory_quota<Output, Func: FnOnce() -> Output>(
+        device: &Self::Device,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        quota::scope::<R, Output>(device, quota, func)
+    }
+
     fn memory_cleanup(device: &Self::Device) {
         let client = R::client(device);
         client.memory_cleanup();
@@ -157,6 +168,18 @@ where
         let client = R::client(&Default::default());
         client.device_count(type_id)
     }
+
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        let client = R::client(device);
+        let props = client.properties();
+
+        DeviceProperties {
+            max_allocation_size: Some(props.memory.max_page_size),
+            plane_size_min: Some(props.hardware.plane_size_min),
+            plane_size_max: Some(props.hardware.plane_size_max),
+            num_cpu_cores: props.hardware.num_cpu_cores.map(|cores| cores as u32),
+        }
+    }
 }
 
 impl<R: CubeRuntime, F: FloatElement, I: IntElement, BT: BoolElement> core::fmt::Debug
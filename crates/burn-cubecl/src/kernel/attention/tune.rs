@@ -4,7 +4,8 @@ use crate::{
     tensor::CubeTensor,
 };
 use burn_backend::ops::AttentionModuleOptions;
-use cubecl::tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner};
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
+use cubecl::tune::{LocalTuner, TunableSet, TuneGroup, local_tuner};
 use cubek::attention::forward::{
     launch::AttentionAutotuneKey, routines::blackbox_accelerated::BlackboxAcceleratedStrategy,
 };
@@ -41,7 +42,7 @@ pub fn attention_autotune<R: CubeRuntime>(
 
         // First entry should always work, since it is considered the fallback.
         set = set.with(
-            Tunable::new(
+            budgeted_tunable(
                 "fallback",
                 |(query, key, value, mask, attn_bias, options)| {
                     attention::<R>(
@@ -64,7 +65,7 @@ pub fn attention_autotune<R: CubeRuntime>(
         for num_planes in [2, 4, 8] {
             let name = format!("blackbox_accelerated_{num_planes}_planes_p_{seq_q}-{seq_kv}");
             set = set.with(
-                Tunable::new(
+                budgeted_tunable(
                     &name,
                     move |(query, key, value, mask, attn_bias, options)| {
                         attention::<R>(
@@ -90,7 +91,7 @@ pub fn attention_autotune<R: CubeRuntime>(
         }
 
         set = set.with(
-            Tunable::new("unit", |(query, key, value, mask, attn_bias, options)| {
+            budgeted_tunable("unit", |(query, key, value, mask, attn_bias, options)| {
                 attention::<R>(
                     query,
                     key,
@@ -108,12 +109,14 @@ pub fn attention_autotune<R: CubeRuntime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&client, &query.device),
-        &client,
-        tunables,
-        (query, key, value, mask, attn_bias, options),
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&client, &query.device),
+            &client,
+            tunables,
+            (query, key, value, mask, attn_bias, options),
+        )
+    })
 }
 
 #[allow(clippy::type_complexity)]
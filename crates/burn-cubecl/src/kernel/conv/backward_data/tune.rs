@@ -1,8 +1,9 @@
 use burn_backend::ops::ConvOptions;
 use burn_std::Shape;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     ir::StorageType,
-    tune::{LocalTuner, Tunable, TunableSet, anchor, local_tuner},
+    tune::{LocalTuner, TunableSet, anchor, local_tuner},
 };
 use cubek::convolution::AcceleratedTileKind;
 
@@ -31,37 +32,37 @@ pub fn dgrad_autotune<R: CubeRuntime, const N: usize>(
     // No CMMA for TMA because swizzling will be mandatory for good performance on dgrad.
     let tunables = TUNER.init(|| {
         TunableSet::new(create_key::<R, N>, create_wgrad_input::<R, N>)
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "wgrad_fallback",
                 |(out_grad, weights, input_shape, options)| {
                     conv_data_backward_fallback::<R, N>(out_grad, weights, input_shape, options)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_cmma",
                 |(input, grad, shape, options)| {
                     dgrad_gemm_simple_sync(input, grad, shape, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_mma",
                 |(input, grad, shape, options)| {
                     dgrad_gemm_simple_sync(input, grad, shape, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_cmma",
                 |(input, grad, shape, options)| {
                     dgrad_gemm_simple_async(input, grad, shape, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_mma",
                 |(input, grad, shape, options)| {
                     dgrad_gemm_simple_async(input, grad, shape, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_tma_mma",
                 |(input, grad, shape, options)| {
                     dgrad_gemm_simple_tma(input, grad, shape, options, AcceleratedTileKind::Mma)
@@ -69,12 +70,14 @@ pub fn dgrad_autotune<R: CubeRuntime, const N: usize>(
             ))
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&out_grad.client, &out_grad.device),
-        &client,
-        tunables,
-        (out_grad, weights, input_shape, options),
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&out_grad.client, &out_grad.device),
+            &client,
+            tunables,
+            (out_grad, weights, input_shape, options),
+        )
+    })
 }
 
 pub fn create_wgrad_input<R: CubeRuntime, const N: usize>(
@@ -1,8 +1,9 @@
 use burn_backend::ops::ConvOptions;
 use burn_std::Shape;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     ir::StorageType,
-    tune::{LocalTuner, Tunable, TunableSet, anchor, local_tuner},
+    tune::{LocalTuner, TunableSet, anchor, local_tuner},
 };
 use cubek::convolution::AcceleratedTileKind;
 
@@ -28,43 +29,43 @@ pub fn wgrad_autotune<R: CubeRuntime, const N: usize>(
 
     let tunables = TUNER.init(|| {
         TunableSet::new(create_key::<R, N>, create_wgrad_input::<R, N>)
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "wgrad_fallback",
                 |(input, grad, shape, options)| {
                     conv_weight_backward_fallback::<R, N>(input, grad, shape, options)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_cmma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_sync(input, grad, shape, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_mma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_sync(input, grad, shape, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_cmma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_async(input, grad, shape, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_mma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_async(input, grad, shape, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_tma_cmma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_tma(input, grad, shape, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_tma_mma",
                 |(input, grad, shape, options)| {
                     wgrad_gemm_simple_tma(input, grad, shape, options, AcceleratedTileKind::Mma)
@@ -72,12 +73,14 @@ pub fn wgrad_autotune<R: CubeRuntime, const N: usize>(
             ))
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&input.client, &input.device),
-        &client,
-        tunables,
-        (input, out_grad, weight_shape, options),
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&input.client, &input.device),
+            &client,
+            tunables,
+            (input, out_grad, weight_shape, options),
+        )
+    })
 }
 
 pub fn create_wgrad_input<R: CubeRuntime, const N: usize>(
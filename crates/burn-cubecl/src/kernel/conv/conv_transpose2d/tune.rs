@@ -1,5 +1,6 @@
 use burn_backend::ops::ConvTransposeOptions;
-use cubecl::tune::{LocalTuner, Tunable, TunableSet, local_tuner};
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
+use cubecl::tune::{LocalTuner, TunableSet, local_tuner};
 
 use crate::{
     CubeAutotuneKey, CubeRuntime, CubeTuneId,
@@ -20,13 +21,13 @@ pub fn conv_transpose2d_autotune<R: CubeRuntime>(
 
     let tune_set = TUNER.init(|| {
         TunableSet::new(create_key::<R>, create_transpose2d_input::<R>)
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "conv_transpose2d_direct",
                 |(input, weights, bias, options)| {
                     conv_transpose2d_direct::<R>(input, weights, bias, options)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "conv_transpose2d_col2im",
                 |(input, weights, bias, options)| {
                     conv_transpose2d_col2im::<R>(input, weights, bias, options)
@@ -34,12 +35,14 @@ pub fn conv_transpose2d_autotune<R: CubeRuntime>(
             ))
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&input.client, &input.device),
-        &client,
-        tune_set,
-        (input, weights, bias, options),
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&input.client, &input.device),
+            &client,
+            tune_set,
+            (input, weights, bias, options),
+        )
+    })
 }
 
 pub fn create_transpose2d_input<R: CubeRuntime>(
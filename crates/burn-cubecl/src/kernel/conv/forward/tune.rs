@@ -1,7 +1,8 @@
 use burn_backend::ops::ConvOptions;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     ir::StorageType,
-    tune::{LocalTuner, Tunable, TunableSet, anchor, local_tuner},
+    tune::{LocalTuner, TunableSet, anchor, local_tuner},
 };
 use cubek::convolution::AcceleratedTileKind;
 
@@ -24,47 +25,47 @@ pub fn conv_autotune<R: CubeRuntime, const N: usize>(
 
     let tunables = TUNER.init(|| {
         TunableSet::new(create_key::<R, N>, create_conv_input::<R, N>)
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "conv_direct",
                 |(input, weight, bias, options)| conv_direct::<R, N>(input, weight, bias, options),
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "conv_im2col_1x1",
                 |(input, weight, bias, options)| {
                     conv_im2col_1x1::<R, N>(input, weight, bias, options)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_cmma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_sync(input, weight, bias, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_sync_mma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_sync(input, weight, bias, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_cmma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_async(input, weight, bias, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_async_mma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_async(input, weight, bias, options, AcceleratedTileKind::Mma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_tma_cmma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_tma(input, weight, bias, options, AcceleratedTileKind::Cmma)
                 },
             ))
-            .with(Tunable::new(
+            .with(budgeted_tunable(
                 "simple_tma_mma",
                 |(input, weight, bias, options)| {
                     conv_gemm_simple_tma(input, weight, bias, options, AcceleratedTileKind::Mma)
@@ -72,12 +73,14 @@ pub fn conv_autotune<R: CubeRuntime, const N: usize>(
             ))
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&input.client, &input.device),
-        &client,
-        tunables,
-        (input, weight, bias, options),
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&input.client, &input.device),
+            &client,
+            tunables,
+            (input, weight, bias, options),
+        )
+    })
 }
 
 pub fn create_conv_input<R: CubeRuntime, const N: usize>(
@@ -4,8 +4,9 @@ use crate::{CubeRuntime, tensor::CubeTensor};
 use burn_backend::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
 
 use super::bilinear::grid_sample_bilinear_launch;
+use super::nearest::grid_sample_nearest_launch;
 
-/// Grid sample operation supporting bilinear interpolation
+/// Grid sample operation supporting bilinear and nearest interpolation
 pub fn grid_sample<R: CubeRuntime>(
     input: CubeTensor<R>,
     grid: CubeTensor<R>,
@@ -13,6 +14,7 @@ pub fn grid_sample<R: CubeRuntime>(
 ) -> CubeTensor<R> {
     match options.mode {
         InterpolateMode::Bilinear => grid_sample_bilinear_launch(input, grid, options),
+        InterpolateMode::Nearest => grid_sample_nearest_launch(input, grid, options),
         _ => panic!(
             "Unsupported grid_sample interpolation mode: {:?}",
             options.mode
@@ -1,4 +1,5 @@
 mod base;
 mod bilinear;
+mod nearest;
 
 pub use base::*;
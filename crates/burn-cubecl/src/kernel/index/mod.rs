@@ -2,6 +2,7 @@ mod flip;
 mod gather;
 mod gather_nd;
 mod repeat_dim;
+mod roll;
 mod scatter;
 mod scatter_nd;
 mod select;
@@ -12,6 +13,7 @@ mod slice_assign;
 pub(crate) use flip::*;
 pub(crate) use gather_nd::*;
 pub(crate) use repeat_dim::*;
+pub(crate) use roll::*;
 pub(crate) use scatter_nd::*;
 pub(crate) use select::*;
 pub(crate) use select_assign::*;
@@ -0,0 +1,92 @@
+use crate::{
+    CubeRuntime,
+    kernel::utils::{address_type, shape_divmod},
+    ops::numeric::empty_device_dtype,
+    tensor::CubeTensor,
+};
+use burn_backend::TensorMetadata;
+use cubecl::{
+    calculate_cube_count_elemwise,
+    prelude::*,
+    std::{FastDivmod, tensor::layout::linear::LinearView},
+};
+
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn roll_kernel<E: Numeric>(
+    input: &Tensor<E>,
+    output: &mut LinearView<E, ReadWrite>,
+    in_shape: Sequence<FastDivmod<usize>>,
+    shifts: Sequence<usize>,
+    #[define(E)] _dtype: StorageType,
+) {
+    if !output.is_in_bounds(ABSOLUTE_POS) {
+        terminate!();
+    }
+
+    let rank = in_shape.len().comptime();
+
+    let mut offset = ABSOLUTE_POS;
+    let mut offset_input = 0;
+
+    #[unroll]
+    for i in 0..rank {
+        let dim = rank - i - 1;
+
+        let (rem, offset_local) = in_shape[dim].div_mod(offset);
+        offset = rem;
+
+        // Shifts are smaller than the dimension, so a single modulo wraps around.
+        let offset_local = in_shape[dim].modulo(offset_local + shifts[dim]);
+
+        offset_input += offset_local * input.stride(dim);
+    }
+
+    output.write(ABSOLUTE_POS, input[offset_input]);
+}
+
+pub(crate) fn roll<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    shifts: &[usize],
+    dims: &[usize],
+) -> CubeTensor<R> {
+    let output = empty_device_dtype(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape(),
+        tensor.dtype,
+    );
+
+    let dtype = tensor.dtype;
+    let ndims = tensor.meta.num_dims();
+    let mut shifts_sequence = SequenceArg::<R, usize>::new();
+
+    for i in 0..ndims {
+        let shift = dims
+            .iter()
+            .position(|&dim| dim == i)
+            .map(|index| shifts[index])
+            .unwrap_or(0);
+        shifts_sequence.push(shift);
+    }
+
+    let num_elements = output.meta.num_elements();
+    let cube_dim = CubeDim::new(&tensor.client, num_elements);
+    let cube_count = calculate_cube_count_elemwise(&tensor.client, num_elements, cube_dim);
+
+    let shape = shape_divmod(&tensor);
+    unsafe {
+        roll_kernel::launch_unchecked(
+            &output.client,
+            cube_count,
+            cube_dim,
+            address_type!(tensor, output),
+            tensor.into_tensor_arg(),
+            output.clone().into_linear_view(),
+            shape,
+            shifts_sequence,
+            dtype.into(),
+        )
+    }
+
+    output
+}
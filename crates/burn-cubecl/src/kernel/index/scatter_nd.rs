@@ -1,39 +1,30 @@
 use crate::{
     CubeRuntime,
     kernel::{
-        AddOp, AssignOp, BinaryMaxOp, BinaryMinOp, BinaryOp, BinaryOpFamily, MulOp,
+        AddOp, AssignOp, BinaryMaxOp, BinaryMinOp, BinaryOp, BinaryOpFamily, MulOp, cast,
         utils::{address_type, shape_divmod_range},
     },
+    ops::numeric::{div, ones_client},
     tensor::CubeTensor,
 };
-use burn_backend::tensor::IndexingUpdateOp;
+use burn_backend::{DType, tensor::IndexingUpdateOp};
 use cubecl::std::tensor::layout::linear::LinearView;
 use cubecl::{CubeDim, calculate_cube_count_elemwise};
+use cubecl::{client::ComputeClient, features::AtomicUsage, ir::Type};
 use cubecl::{prelude::*, std::FastDivmod};
 
-/// scatter_nd GPU kernel.
-///
-/// Each thread handles one element across all update slices.
-/// Work items = num_updates * slice_size.
-#[cube(launch_unchecked, address_type = "dynamic")]
-fn scatter_nd_kernel<T: Numeric, I: Int, Op: BinaryOpFamily>(
-    data: &mut Tensor<T>,
+/// Offsets in `data` and `values` of the element `slice_offset` of the update `update_idx`.
+#[cube]
+fn scatter_nd_offsets<D: CubePrimitive, T: CubePrimitive, I: Int>(
+    data: &Tensor<D>,
     indices: &LinearView<I>,
     values: &Tensor<T>,
-    data_slice_shape: Sequence<FastDivmod<usize>>,
-    values_shape: Sequence<FastDivmod<usize>>,
-    slice_size: usize,
+    data_slice_shape: &Sequence<FastDivmod<usize>>,
+    values_shape: &Sequence<FastDivmod<usize>>,
+    update_idx: usize,
+    slice_offset: usize,
     k: usize,
-    working_units: usize,
-    #[define(T, I)] _dtypes: [StorageType; 2],
-) {
-    if ABSOLUTE_POS >= working_units {
-        terminate!();
-    }
-
-    let slice_offset = ABSOLUTE_POS % slice_size;
-    let update_idx = ABSOLUTE_POS / slice_size;
-
+) -> (usize, usize) {
     let idx_base = update_idx * k;
     let mut base_offset = 0usize;
     for j in 0..k {
@@ -65,7 +56,43 @@ fn scatter_nd_kernel<T: Numeric, I: Int, Op: BinaryOpFamily>(
         val_offset += coord * values.stride(1 + dim);
     }
 
-    let data_idx = base_offset + data_slice_offset;
+    (base_offset + data_slice_offset, val_offset)
+}
+
+/// scatter_nd GPU kernel.
+///
+/// Each thread handles one element across all update slices.
+/// Work items = num_updates * slice_size.
+///
+/// The updates of duplicate indices race, so this kernel is only used for assignments, where
+/// an arbitrary one of the written values is kept.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn scatter_nd_kernel<T: Numeric, I: Int, Op: BinaryOpFamily>(
+    data: &mut Tensor<T>,
+    indices: &LinearView<I>,
+    values: &Tensor<T>,
+    data_slice_shape: Sequence<FastDivmod<usize>>,
+    values_shape: Sequence<FastDivmod<usize>>,
+    slice_size: usize,
+    k: usize,
+    working_units: usize,
+    #[define(T, I)] _dtypes: [StorageType; 2],
+) {
+    if ABSOLUTE_POS >= working_units {
+        terminate!();
+    }
+
+    let (data_idx, val_offset) = scatter_nd_offsets::<T, T, I>(
+        data,
+        indices,
+        values,
+        &data_slice_shape,
+        &values_shape,
+        ABSOLUTE_POS / slice_size,
+        ABSOLUTE_POS % slice_size,
+        k,
+    );
+
     let result = Op::BinaryOp::<T, Const<1>>::execute(
         Vector::cast_from(data[data_idx]),
         Vector::cast_from(values[val_offset]),
@@ -73,11 +100,285 @@ fn scatter_nd_kernel<T: Numeric, I: Int, Op: BinaryOpFamily>(
     data[data_idx] = result.extract(0);
 }
 
+/// scatter_nd GPU kernel reducing `f32` values with a compare-and-swap loop on their bits, so the
+/// updates of duplicate indices are all applied.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn scatter_nd_atomic_float_kernel<I: Int, Op: BinaryOpFamily>(
+    data: &mut Tensor<Atomic<u32>>,
+    indices: &LinearView<I>,
+    values: &Tensor<f32>,
+    data_slice_shape: Sequence<FastDivmod<usize>>,
+    values_shape: Sequence<FastDivmod<usize>>,
+    slice_size: usize,
+    k: usize,
+    working_units: usize,
+    #[define(I)] _dtype: StorageType,
+) {
+    if ABSOLUTE_POS >= working_units {
+        terminate!();
+    }
+
+    let (data_idx, val_offset) = scatter_nd_offsets::<Atomic<u32>, f32, I>(
+        data,
+        indices,
+        values,
+        &data_slice_shape,
+        &values_shape,
+        ABSOLUTE_POS / slice_size,
+        ABSOLUTE_POS % slice_size,
+        k,
+    );
+
+    atomic_update_float::<Op>(&mut data[data_idx], values[val_offset]);
+}
+
+#[cube]
+fn atomic_update_float<Op: BinaryOpFamily>(slot: &mut Atomic<u32>, value: f32) {
+    let mut current = slot.load();
+    loop {
+        let previous = current;
+        let updated = Op::BinaryOp::<f32, Const<1>>::execute(
+            Vector::cast_from(f32::from_bits(previous)),
+            Vector::cast_from(value),
+        );
+        current = slot.compare_exchange_weak(previous, updated.extract(0).to_bits());
+        if current == previous {
+            break;
+        }
+    }
+}
+
+/// scatter_nd GPU kernel reducing `f64` values with a compare-and-swap loop on their bits, for
+/// the devices with 64-bit atomics.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn scatter_nd_atomic_float64_kernel<I: Int, Op: BinaryOpFamily>(
+    data: &mut Tensor<Atomic<u64>>,
+    indices: &LinearView<I>,
+    values: &Tensor<f64>,
+    data_slice_shape: Sequence<FastDivmod<usize>>,
+    values_shape: Sequence<FastDivmod<usize>>,
+    slice_size: usize,
+    k: usize,
+    working_units: usize,
+    #[define(I)] _dtype: StorageType,
+) {
+    if ABSOLUTE_POS >= working_units {
+        terminate!();
+    }
+
+    let (data_idx, val_offset) = scatter_nd_offsets::<Atomic<u64>, f64, I>(
+        data,
+        indices,
+        values,
+        &data_slice_shape,
+        &values_shape,
+        ABSOLUTE_POS / slice_size,
+        ABSOLUTE_POS % slice_size,
+        k,
+    );
+
+    atomic_update_float64::<Op>(&mut data[data_idx], values[val_offset]);
+}
+
+#[cube]
+fn atomic_update_float64<Op: BinaryOpFamily>(slot: &mut Atomic<u64>, value: f64) {
+    let mut current = slot.load();
+    loop {
+        let previous = current;
+        let updated = Op::BinaryOp::<f64, Const<1>>::execute(
+            Vector::cast_from(f64::from_bits(previous)),
+            Vector::cast_from(value),
+        );
+        current = slot.compare_exchange_weak(previous, updated.extract(0).to_bits());
+        if current == previous {
+            break;
+        }
+    }
+}
+
+/// scatter_nd GPU kernel reducing 32-bit integers, or 64-bit integers on the devices with 64-bit
+/// atomics, with a compare-and-swap loop, so the updates of duplicate indices are all applied.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn scatter_nd_atomic_int_kernel<T: Int, I: Int, Op: BinaryOpFamily>(
+    data: &mut Tensor<Atomic<T>>,
+    indices: &LinearView<I>,
+    values: &Tensor<T>,
+    data_slice_shape: Sequence<FastDivmod<usize>>,
+    values_shape: Sequence<FastDivmod<usize>>,
+    slice_size: usize,
+    k: usize,
+    working_units: usize,
+    #[define(T, I)] _dtypes: [StorageType; 2],
+) {
+    if ABSOLUTE_POS >= working_units {
+        terminate!();
+    }
+
+    let (data_idx, val_offset) = scatter_nd_offsets::<Atomic<T>, T, I>(
+        data,
+        indices,
+        values,
+        &data_slice_shape,
+        &values_shape,
+        ABSOLUTE_POS / slice_size,
+        ABSOLUTE_POS % slice_size,
+        k,
+    );
+
+    atomic_update_int::<T, Op>(&mut data[data_idx], values[val_offset]);
+}
+
+#[cube]
+fn atomic_update_int<T: Int, Op: BinaryOpFamily>(slot: &mut Atomic<T>, value: T) {
+    let mut current = slot.load();
+    loop {
+        let previous = current;
+        let updated = Op::BinaryOp::<T, Const<1>>::execute(
+            Vector::cast_from(previous),
+            Vector::cast_from(value),
+        );
+        current = slot.compare_exchange_weak(previous, updated.extract(0));
+        if current == previous {
+            break;
+        }
+    }
+}
+
+/// scatter_nd GPU kernel for the types without atomics on the device.
+///
+/// Each thread handles one element of the slices and applies all the updates to it in order, so
+/// the updates of duplicate indices never race, at the cost of a parallelism limited to the slice
+/// size. Work items = slice_size.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn scatter_nd_serial_kernel<T: Numeric, I: Int, Op: BinaryOpFamily>(
+    data: &mut Tensor<T>,
+    indices: &LinearView<I>,
+    values: &Tensor<T>,
+    data_slice_shape: Sequence<FastDivmod<usize>>,
+    values_shape: Sequence<FastDivmod<usize>>,
+    num_updates: usize,
+    k: usize,
+    slice_size: usize,
+    #[define(T, I)] _dtypes: [StorageType; 2],
+) {
+    if ABSOLUTE_POS >= slice_size {
+        terminate!();
+    }
+
+    for update_idx in 0..num_updates {
+        let (data_idx, val_offset) = scatter_nd_offsets::<T, T, I>(
+            data,
+            indices,
+            values,
+            &data_slice_shape,
+            &values_shape,
+            update_idx,
+            ABSOLUTE_POS,
+            k,
+        );
+
+        let result = Op::BinaryOp::<T, Const<1>>::execute(
+            Vector::cast_from(data[data_idx]),
+            Vector::cast_from(values[val_offset]),
+        );
+        data[data_idx] = result.extract(0);
+    }
+}
+
+/// How the updates of duplicate indices are reduced.
+enum ScatterNdStrategy {
+    /// One thread per updated element, racing on duplicate indices.
+    Racing,
+    /// One thread per updated element, with a compare-and-swap loop on `f32` bits.
+    AtomicFloat,
+    /// One thread per updated element, with a compare-and-swap loop on `f64` bits.
+    AtomicFloat64,
+    /// One thread per updated element, with a compare-and-swap loop on integers.
+    AtomicInt,
+    /// One thread per slice element, applying the updates sequentially.
+    Serial,
+}
+
 pub(crate) fn scatter_nd<R: CubeRuntime>(
     tensor: CubeTensor<R>,
     indices: CubeTensor<R>,
     values: CubeTensor<R>,
     reduction: IndexingUpdateOp,
+) -> CubeTensor<R> {
+    match reduction {
+        IndexingUpdateOp::Assign => {
+            scatter_nd_with::<R, AssignOp>(tensor, indices, values, ScatterNdStrategy::Racing)
+        }
+        IndexingUpdateOp::Add => scatter_nd_reduce::<R, AddOp>(tensor, indices, values),
+        IndexingUpdateOp::Mul => scatter_nd_reduce::<R, MulOp>(tensor, indices, values),
+        IndexingUpdateOp::Min => scatter_nd_reduce::<R, BinaryMinOp>(tensor, indices, values),
+        IndexingUpdateOp::Max => scatter_nd_reduce::<R, BinaryMaxOp>(tensor, indices, values),
+        IndexingUpdateOp::Mean => {
+            // The existing value is one of the averaged terms.
+            let ones = |tensor: &CubeTensor<R>| {
+                ones_client(
+                    tensor.client.clone(),
+                    tensor.device.clone(),
+                    tensor.meta.shape.clone(),
+                    tensor.dtype,
+                )
+            };
+            let counts =
+                scatter_nd_reduce::<R, AddOp>(ones(&tensor), indices.clone(), ones(&values));
+            let sum = scatter_nd_reduce::<R, AddOp>(tensor, indices, values);
+
+            div(sum, counts)
+        }
+    }
+}
+
+/// Check if the client supports compare-and-swap on atomics of the given element type.
+fn supports_atomic_cas<R: CubeRuntime>(client: &ComputeClient<R>, dtype: DType) -> bool {
+    client
+        .properties()
+        .atomic_type_usage(Type::atomic(dtype))
+        .contains(AtomicUsage::LoadStore)
+}
+
+/// Reduces the updates of duplicate indices without races: with atomics on a 32-bit working
+/// type when the tensor fits in it, with 64-bit atomics when the device supports them, and
+/// sequentially otherwise.
+fn scatter_nd_reduce<R: CubeRuntime, Op: BinaryOpFamily>(
+    tensor: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    values: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let dtype = tensor.dtype;
+    let (working_dtype, strategy) = match dtype {
+        DType::F32 | DType::Flex32 | DType::F16 | DType::BF16 => {
+            (DType::F32, ScatterNdStrategy::AtomicFloat)
+        }
+        DType::I32 | DType::I16 | DType::I8 => (DType::I32, ScatterNdStrategy::AtomicInt),
+        DType::U32 | DType::U16 | DType::U8 => (DType::U32, ScatterNdStrategy::AtomicInt),
+        DType::F64 if supports_atomic_cas(&tensor.client, DType::U64) => {
+            (DType::F64, ScatterNdStrategy::AtomicFloat64)
+        }
+        DType::I64 | DType::U64 if supports_atomic_cas(&tensor.client, dtype) => {
+            (dtype, ScatterNdStrategy::AtomicInt)
+        }
+        _ => return scatter_nd_with::<R, Op>(tensor, indices, values, ScatterNdStrategy::Serial),
+    };
+
+    let output = scatter_nd_with::<R, Op>(
+        cast(tensor, working_dtype),
+        indices,
+        cast(values, working_dtype),
+        strategy,
+    );
+
+    cast(output, dtype)
+}
+
+fn scatter_nd_with<R: CubeRuntime, Op: BinaryOpFamily>(
+    tensor: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    values: CubeTensor<R>,
+    strategy: ScatterNdStrategy,
 ) -> CubeTensor<R> {
     // Ensure we can write in-place
     let tensor = match tensor.can_mut() && tensor.is_nonoverlapping() {
@@ -94,41 +395,107 @@ pub(crate) fn scatter_nd<R: CubeRuntime>(
     let num_updates: usize = idx_shape.as_slice()[..m - 1].iter().product();
     // slice_size = product of data.shape[K..]
     let slice_size: usize = data_shape.as_slice()[k..].iter().product();
-    let working_units = num_updates * slice_size;
+    let working_units = match strategy {
+        ScatterNdStrategy::Serial => slice_size,
+        _ => num_updates * slice_size,
+    };
 
     let cube_dim = CubeDim::new(&indices.client, working_units);
     let cube_count = calculate_cube_count_elemwise(&indices.client, working_units, cube_dim);
 
     let (tensor_dtype, indices_dtype) = (tensor.dtype, indices.dtype);
 
-    let launch = match reduction {
-        IndexingUpdateOp::Assign => scatter_nd_kernel::launch_unchecked::<AssignOp, R>,
-        IndexingUpdateOp::Add => scatter_nd_kernel::launch_unchecked::<AddOp, R>,
-        IndexingUpdateOp::Mul => scatter_nd_kernel::launch_unchecked::<MulOp, R>,
-        IndexingUpdateOp::Min => scatter_nd_kernel::launch_unchecked::<BinaryMinOp, R>,
-        IndexingUpdateOp::Max => scatter_nd_kernel::launch_unchecked::<BinaryMaxOp, R>,
-    };
-
     let data_slice_shape = shape_divmod_range(&tensor, k..data_shape.num_dims());
     // values dims 1.. (skip the num_updates leading dim)
     let values_slice_shape = shape_divmod_range(&values, 1..values.meta.shape.num_dims());
 
+    let client = tensor.client.clone();
+    let address_type = address_type!(tensor, indices, values);
+
     unsafe {
-        launch(
-            &tensor.client.clone(),
-            cube_count,
-            cube_dim,
-            address_type!(tensor, indices, values),
-            tensor.clone().into_tensor_arg(),
-            indices.into_linear_view(),
-            values.into_tensor_arg(),
-            data_slice_shape,
-            values_slice_shape,
-            slice_size,
-            k,
-            working_units,
-            [tensor_dtype.into(), indices_dtype.into()],
-        )
+        match strategy {
+            ScatterNdStrategy::Racing => scatter_nd_kernel::launch_unchecked::<Op, R>(
+                &client,
+                cube_count,
+                cube_dim,
+                address_type,
+                tensor.clone().into_tensor_arg(),
+                indices.into_linear_view(),
+                values.into_tensor_arg(),
+                data_slice_shape,
+                values_slice_shape,
+                slice_size,
+                k,
+                working_units,
+                [tensor_dtype.into(), indices_dtype.into()],
+            ),
+            ScatterNdStrategy::AtomicFloat => {
+                scatter_nd_atomic_float_kernel::launch_unchecked::<Op, R>(
+                    &client,
+                    cube_count,
+                    cube_dim,
+                    address_type,
+                    tensor.clone().into_tensor_arg(),
+                    indices.into_linear_view(),
+                    values.into_tensor_arg(),
+                    data_slice_shape,
+                    values_slice_shape,
+                    slice_size,
+                    k,
+                    working_units,
+                    indices_dtype.into(),
+                )
+            }
+            ScatterNdStrategy::AtomicFloat64 => {
+                scatter_nd_atomic_float64_kernel::launch_unchecked::<Op, R>(
+                    &client,
+                    cube_count,
+                    cube_dim,
+                    address_type,
+                    tensor.clone().into_tensor_arg(),
+                    indices.into_linear_view(),
+                    values.into_tensor_arg(),
+                    data_slice_shape,
+                    values_slice_shape,
+                    slice_size,
+                    k,
+                    working_units,
+                    indices_dtype.into(),
+                )
+            }
+            ScatterNdStrategy::AtomicInt => {
+                scatter_nd_atomic_int_kernel::launch_unchecked::<Op, R>(
+                    &client,
+                    cube_count,
+                    cube_dim,
+                    address_type,
+                    tensor.clone().into_tensor_arg(),
+                    indices.into_linear_view(),
+                    values.into_tensor_arg(),
+                    data_slice_shape,
+                    values_slice_shape,
+                    slice_size,
+                    k,
+                    working_units,
+                    [tensor_dtype.into(), indices_dtype.into()],
+                )
+            }
+            ScatterNdStrategy::Serial => scatter_nd_serial_kernel::launch_unchecked::<Op, R>(
+                &client,
+                cube_count,
+                cube_dim,
+                address_type,
+                tensor.clone().into_tensor_arg(),
+                indices.into_linear_view(),
+                values.into_tensor_arg(),
+                data_slice_shape,
+                values_slice_shape,
+                num_updates,
+                k,
+                slice_size,
+                [tensor_dtype.into(), indices_dtype.into()],
+            ),
+        }
     }
 
     tensor
@@ -1,6 +1,9 @@
 use crate::{
     CubeRuntime,
-    kernel::into_contiguous,
+    kernel::{
+        into_contiguous,
+        pool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    },
     ops::{numeric::empty_device_dtype, permute_nchw_to_nhwc, permute_nhwc_to_nchw},
     tensor::CubeTensor,
 };
@@ -14,12 +17,16 @@ use cubek::interpolate::{
 
 /// Interpolate operation
 ///
-/// Supports nearest, bilinear, bicubic and lanczos3 modes
+/// Supports nearest, bilinear, bicubic, lanczos3 and area modes
 pub fn interpolate<R: CubeRuntime>(
     input: CubeTensor<R>,
     output_size: [usize; 2],
     options: InterpolateOptions,
 ) -> CubeTensor<R> {
+    if let InterpolateMode::Area = options.mode {
+        return adaptive_avg_pool2d(input, output_size);
+    }
+
     let [batch_size, channels, _, _] = input.meta.shape().dims();
     let [out_height, out_width] = output_size;
 
@@ -59,6 +66,10 @@ pub fn interpolate_backward<R: CubeRuntime>(
     _output_size: [usize; 2],
     options: InterpolateOptions,
 ) -> CubeTensor<R> {
+    if let InterpolateMode::Area = options.mode {
+        return adaptive_avg_pool2d_backward(input, out_grad);
+    }
+
     let input = permute_nchw_to_nhwc(input);
     let out_grad = permute_nchw_to_nhwc(out_grad);
 
@@ -99,6 +110,9 @@ fn map_options(options: InterpolateOptions) -> CubekInterpolateOptions {
                 InterpolateMode::Bilinear => CubekInterpolateMode::Bilinear,
                 InterpolateMode::Bicubic => CubekInterpolateMode::Bicubic,
                 InterpolateMode::Lanczos3 => CubekInterpolateMode::Lanczos3,
+                InterpolateMode::Area => {
+                    unreachable!("Area interpolation is an adaptive average pooling")
+                }
             }
         },
         align_corners: options.align_corners,
@@ -0,0 +1,105 @@
+use crate::{
+    CubeRuntime,
+    kernel::{into_contiguous, utils::address_type},
+    ops::{
+        numeric::{empty_device_dtype, full_device_dtype},
+        swap_dims,
+    },
+    tensor::CubeTensor,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// logsumexp GPU kernel.
+///
+/// Each thread reduces one contiguous row of `row_len` values in a single pass, keeping a running
+/// maximum and the sum of the exponentials shifted by it, rescaled whenever the maximum grows.
+/// Values equal to the running maximum add exactly one, so that rows of `-inf` (or `+inf`) never
+/// compute `inf - inf`.
+#[cube(launch_unchecked, address_type = "dynamic")]
+fn logsumexp_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    row_len: usize,
+    num_rows: usize,
+    #[define(F)] _dtype: StorageType,
+) {
+    let row = ABSOLUTE_POS;
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let offset = row * row_len;
+    let mut max = input[offset];
+    let mut sum = F::new(1.0);
+
+    for i in 1..row_len {
+        let value = input[offset + i];
+        if value > max {
+            sum = sum * (max - value).exp() + F::new(1.0);
+            max = value;
+        } else if value == max {
+            sum += F::new(1.0);
+        } else {
+            sum += (value - max).exp();
+        }
+    }
+
+    output[row] = max + sum.ln();
+}
+
+pub(crate) fn logsumexp<R: CubeRuntime>(tensor: CubeTensor<R>, dim: usize) -> CubeTensor<R> {
+    let ndims = tensor.meta.num_dims();
+
+    // The kernel reads each row from contiguous memory, so the reduced dimension is moved last
+    // and made contiguous, and the result is moved back.
+    if dim != ndims - 1 {
+        let last = ndims - 1;
+        let tensor = into_contiguous(swap_dims(tensor, dim, last));
+        let result = logsumexp(tensor, last);
+        return swap_dims(result, dim, last);
+    }
+    let tensor = into_contiguous(tensor);
+
+    let mut output_shape = tensor.meta.shape.clone();
+    let row_len = output_shape[dim];
+    output_shape[dim] = 1;
+
+    // The sum of no exponentials is zero.
+    if row_len == 0 {
+        return full_device_dtype(
+            tensor.client.clone(),
+            output_shape,
+            tensor.device.clone(),
+            InputScalar::new(f32::NEG_INFINITY, tensor.dtype),
+            tensor.dtype,
+        );
+    }
+
+    let output = empty_device_dtype(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        output_shape,
+        tensor.dtype,
+    );
+
+    let num_rows = output.meta.num_elements();
+    let cube_dim = CubeDim::new(&tensor.client, num_rows);
+    let cube_count = calculate_cube_count_elemwise(&tensor.client, num_rows, cube_dim);
+    let dtype = tensor.dtype;
+
+    unsafe {
+        logsumexp_kernel::launch_unchecked(
+            &output.client,
+            cube_count,
+            cube_dim,
+            address_type!(tensor, output),
+            tensor.into_tensor_arg(),
+            output.clone().into_tensor_arg(),
+            row_len,
+            num_rows,
+            dtype.into(),
+        )
+    };
+
+    output
+}
@@ -4,9 +4,10 @@ use crate::{
     tensor::CubeTensor,
 };
 use burn_backend::DType;
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     std::tensor::MatrixBatchLayout,
-    tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner},
+    tune::{LocalTuner, TunableSet, TuneGroup, local_tuner},
 };
 use cubek::matmul::{
     components::tile::TileMatmulKind,
@@ -162,7 +163,7 @@ pub fn matmul_autotune<R: CubeRuntime>(
 
         // First entry should always work, since it is considered the fallback.
         set = set.with(
-            Tunable::new("matmul_naive", |(lhs, rhs, out)| {
+            budgeted_tunable("matmul_naive", |(lhs, rhs, out)| {
                 launch_matmul_naive::<R>(&Strategy::Naive, lhs, rhs, out)
                     .map_err(|err| std::format!("{err:?}"))
             })
@@ -197,7 +198,7 @@ pub fn matmul_autotune<R: CubeRuntime>(
             ),
         ] {
             set = set.with(
-                Tunable::new(&strategy.to_string(), move |(lhs, rhs, out)| {
+                budgeted_tunable(&strategy.to_string(), move |(lhs, rhs, out)| {
                     launch_matmul::<R>(&strategy, lhs, rhs, out)
                         .map_err(|err| std::format!("{err:?}"))
                 })
@@ -228,7 +229,7 @@ pub fn matmul_autotune<R: CubeRuntime>(
                 ),
             ] {
                 set = set.with(
-                    Tunable::new(&strategy.to_string(), move |(lhs, rhs, out)| {
+                    budgeted_tunable(&strategy.to_string(), move |(lhs, rhs, out)| {
                         launch_matmul::<R>(&strategy, lhs, rhs, out)
                             .map_err(|err| format!("{err:?}"))
                     })
@@ -246,7 +247,7 @@ pub fn matmul_autotune<R: CubeRuntime>(
             target_num_planes: None,
         }));
         set = set.with(
-            Tunable::new(
+            budgeted_tunable(
                 &gemm_no_stage_strategy.to_string(),
                 move |(lhs, rhs, out)| {
                     launch_matmul::<R>(&gemm_no_stage_strategy, lhs, rhs, out)
@@ -440,7 +441,7 @@ pub fn matmul_autotune<R: CubeRuntime>(
                 false => PRIORITY_MAX,
                 true => double_buffering_priority(key, PRIORITY_MAX, PRIORITY_HIGH),
             };
-            let mut tunable = Tunable::new(&strategy.to_string(), move |(lhs, rhs, out)| {
+            let mut tunable = budgeted_tunable(&strategy.to_string(), move |(lhs, rhs, out)| {
                 launch_matmul::<R>(&strategy, lhs, rhs, out).map_err(|err| format!("{err:?}"))
             });
 
@@ -459,12 +460,14 @@ pub fn matmul_autotune<R: CubeRuntime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&lhs.client, &lhs.device),
-        &client,
-        tunables,
-        (lhs, rhs, output.clone()),
-    );
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&lhs.client, &lhs.device),
+            &client,
+            tunables,
+            (lhs, rhs, output.clone()),
+        )
+    });
 
     output
 }
@@ -7,6 +7,7 @@ mod comparison;
 mod contiguous;
 mod cross;
 mod index;
+mod logsumexp;
 mod mask;
 mod unary_float;
 mod unary_int;
@@ -18,6 +19,7 @@ pub(crate) use binary_int::*;
 pub use cast::*;
 pub use contiguous::*;
 pub(crate) use cross::*;
+pub(crate) use logsumexp::*;
 pub use mask::*;
 pub(crate) use unary_float::*;
 pub(crate) use unary_int::*;
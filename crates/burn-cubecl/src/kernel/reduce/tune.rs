@@ -2,9 +2,10 @@
 
 use super::SumAutotuneKey;
 use crate::{CubeAutotuneKey, CubeRuntime, CubeTuneId, tensor::CubeTensor};
+use burn_std::autotune::{budgeted_tunable, tune_with_budget};
 use cubecl::{
     client::ComputeClient,
-    tune::{LocalTuner, Tunable, TunableSet, TuneGroup, local_tuner},
+    tune::{LocalTuner, TunableSet, TuneGroup, local_tuner},
 };
 use cubek::reduce::{
     ReduceDtypes, ReduceStrategy,
@@ -88,7 +89,7 @@ pub fn autotune_reduce<R: CubeRuntime>(
                 ),
             ] {
                 let name = format!("{name}{vector_size_ident}");
-                let mut tunable = Tunable::new(
+                let mut tunable = budgeted_tunable(
                     &name,
                     move |(input, output, axis, config, dtypes): (
                         CubeTensor<R>,
@@ -144,12 +145,14 @@ pub fn autotune_reduce<R: CubeRuntime>(
         set
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&input.client, &input.device),
-        client,
-        tunables,
-        (input, output, axis, config, dtypes),
-    );
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&input.client, &input.device),
+            client,
+            tunables,
+            (input, output, axis, config, dtypes),
+        )
+    });
 }
 
 pub(crate) fn create_key<Run: CubeRuntime>(
@@ -214,22 +217,24 @@ pub fn autotune_sum<R: CubeRuntime>(
 
     let tunables = TUNER.init(|| {
         TunableSet::new(create_key_sum::<R>, sum_input_gen::<R>)
-            .with(Tunable::new("sum_chained", sum_chained::<R>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 1>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 2>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 4>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 8>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 16>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 32>))
-            .with(Tunable::new("sum_one_shot", sum_one_shot::<R, 64>))
+            .with(budgeted_tunable("sum_chained", sum_chained::<R>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 1>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 2>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 4>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 8>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 16>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 32>))
+            .with(budgeted_tunable("sum_one_shot", sum_one_shot::<R, 64>))
     });
 
-    TUNER.execute(
-        &CubeTuneId::new(&input.client, &input.device),
-        client,
-        tunables,
-        input,
-    )
+    tune_with_budget(|| {
+        TUNER.execute(
+            &CubeTuneId::new(&input.client, &input.device),
+            client,
+            tunables,
+            input,
+        )
+    })
 }
 
 pub(crate) fn create_key_sum<Run: CubeRuntime>(input: &CubeTensor<Run>) -> CubeAutotuneKey {
@@ -22,12 +22,16 @@ use cubecl::{CubeTask, Runtime};
 pub use element::{BoolElement, CubeElement, FloatElement, IntElement};
 
 mod backend;
+mod quota;
 
 pub use backend::*;
 
 // Re-export cubecl.
 pub use cubecl;
 
+/// Budgets for autotuning kernels.
+pub use burn_std::autotune;
+
 mod tune_key;
 pub use tune_key::CubeAutotuneKey;
 
@@ -1,5 +1,5 @@
-use crate::{CubeBackend, CubeRuntime, FloatElement, IntElement, element::BoolElement};
-use burn_backend::ops::ActivationOps;
+use crate::{CubeBackend, CubeRuntime, FloatElement, IntElement, element::BoolElement, kernel};
+use burn_backend::{ops::ActivationOps, tensor::FloatTensor};
 
 impl<R, F, I, BT> ActivationOps<Self> for CubeBackend<R, F, I, BT>
 where
@@ -8,4 +8,7 @@ where
     I: IntElement,
     BT: BoolElement,
 {
+    fn logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        kernel::logsumexp(tensor, dim)
+    }
 }
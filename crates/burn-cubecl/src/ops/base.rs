@@ -1,4 +1,4 @@
-use crate::{CubeRuntime, kernel, ops::numeric::empty_device_dtype, tensor::CubeTensor};
+use crate::{CubeRuntime, kernel, ops::numeric::empty_device_dtype, quota, tensor::CubeTensor};
 use burn_backend::{
     DType, ExecutionError, QTensorPrimitive, Shape, TensorData,
     quantization::{QuantLevel, QuantStore, params_shape},
@@ -13,6 +13,7 @@ use cubecl::{quant::scheme::BlockSize, tensor_vector_size_parallel};
 
 pub(crate) fn from_data<R: CubeRuntime>(data: TensorData, device: &R::Device) -> CubeTensor<R> {
     let client = R::client(device);
+    quota::reserve(&client, device, data.bytes.len());
     let alloc = client.create_tensor(data.bytes, data.shape.clone(), data.dtype.size());
     let shape: Shape = (&data.shape).into();
     CubeTensor::new(
@@ -77,6 +78,7 @@ pub(crate) fn empty<R: CubeRuntime>(
     dtype: DType,
 ) -> CubeTensor<R> {
     let client = R::client(device);
+    quota::reserve(&client, device, shape.num_elements() * dtype.size());
     let alloc = client.empty_tensor(shape.clone(), dtype.size());
 
     CubeTensor::new(
@@ -158,6 +158,10 @@ where
         kernel::flip(tensor, axes, dtype)
     }
 
+    fn bool_roll(tensor: BoolTensor<Self>, shifts: &[usize], dims: &[usize]) -> BoolTensor<Self> {
+        kernel::roll(tensor, shifts, dims)
+    }
+
     fn bool_unfold(
         tensor: FloatTensor<Self>,
         dim: usize,
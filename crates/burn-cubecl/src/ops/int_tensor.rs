@@ -17,7 +17,10 @@ use crate::{
     kernel::prng::{random_bernoulli, random_normal, random_uniform},
 };
 use burn_backend::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
-use burn_backend::{DType, IntDType, Slice, ops::IntTensorOps};
+use burn_backend::{
+    DType, IntDType, Slice,
+    ops::{FloatTensorOps, IntTensorOps, float_random_derived},
+};
 use burn_backend::{Distribution, ElementConversion, Shape, TensorData, get_device_settings};
 use burn_backend::{ExecutionError, Scalar};
 use burn_std::{BoolDType, FloatDType};
@@ -529,6 +532,7 @@ where
         device: &Device<Self>,
         dtype: IntDType,
     ) -> IntTensor<Self> {
+        let int_dtype = dtype;
         let dtype = dtype.into();
         match distribution {
             Distribution::Default => random_uniform(shape, device, 0., 255., dtype),
@@ -539,6 +543,10 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem(), dtype)
             }
+            distribution => Self::float_into_int(
+                float_random_derived::<Self>(shape, distribution, device, FloatDType::F32),
+                int_dtype,
+            ),
         }
     }
 
@@ -555,6 +563,10 @@ where
         kernel::flip(tensor, axes, bool_dtype.into())
     }
 
+    fn int_roll(tensor: IntTensor<Self>, shifts: &[usize], dims: &[usize]) -> IntTensor<Self> {
+        kernel::roll(tensor, shifts, dims)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         numeric::bitwise_and(lhs, rhs)
     }
@@ -9,6 +9,7 @@ use burn_backend::{
     ops::{
         AttentionModuleOptions, ConvOptions, ConvTransposeOptions, DeformConv2dBackward,
         DeformConvOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+        interpolate::{interpolate_antialias, interpolate_antialias_backward},
     },
 };
 
@@ -302,6 +303,12 @@ where
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        // The antialiased filters have a support proportional to the downscaling factor, which
+        // the interpolate kernels don't handle.
+        if options.antialias {
+            return interpolate_antialias::<Self>(x, output_size, &options);
+        }
+
         kernel::interpolate::interpolate(x, output_size, options)
     }
 
@@ -311,6 +318,10 @@ where
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        if options.antialias {
+            return interpolate_antialias_backward::<Self>(x, grad, &options);
+        }
+
         kernel::interpolate::interpolate_backward(x, grad, output_size, options)
     }
 
@@ -323,7 +334,11 @@ where
         options: AttentionModuleOptions,
     ) -> FloatTensor<Self> {
         // Fall back to naive attention for features the flash kernel doesn't support.
-        if attn_bias.is_some() || options.softcap.is_some() || options.scale.is_some() {
+        if attn_bias.is_some()
+            || options.softcap.is_some()
+            || options.scale.is_some()
+            || options.sliding_window.is_some()
+        {
             return burn_backend::ops::attention::attention_fallback::<Self>(
                 query, key, value, mask, attn_bias, options,
             );
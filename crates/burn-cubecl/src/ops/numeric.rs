@@ -1,6 +1,7 @@
 use crate::{
     CubeRuntime,
     kernel::utils::{address_type, shape_divmod},
+    quota,
 };
 use crate::{element::CubeElement, tensor::CubeTensor};
 use crate::{
@@ -125,6 +126,7 @@ pub fn empty_device<R: CubeRuntime, E: CubeElement>(
     device: R::Device,
     shape: Shape,
 ) -> CubeTensor<R> {
+    quota::reserve(&client, &device, shape.num_elements() * size_of::<E>());
     let MemoryLayout { memory, strides } = client.empty_tensor(shape.clone(), size_of::<E>());
 
     CubeTensor::new(
@@ -143,6 +145,7 @@ pub fn empty_device_dtype<R: CubeRuntime>(
     shape: Shape,
     dtype: DType,
 ) -> CubeTensor<R> {
+    quota::reserve(&client, &device, shape.num_elements() * dtype.size());
     let MemoryLayout { memory, strides } = client.empty_tensor(shape.clone(), dtype.size());
 
     CubeTensor::new(client, memory, Metadata::new(shape, strides), device, dtype)
@@ -155,6 +158,7 @@ pub fn empty_device_contiguous_dtype<R: CubeRuntime>(
     shape: Shape,
     dtype: DType,
 ) -> CubeTensor<R> {
+    quota::reserve(&client, &device, shape.num_elements() * dtype.size());
     let descriptor = MemoryLayoutDescriptor::contiguous(shape.clone(), dtype.size());
     let MemoryLayout { memory, strides } = client.empty_tensors(vec![descriptor]).remove(0);
 
@@ -16,6 +16,7 @@ use crate::{
     CubeBackend, CubeRuntime, FloatElement, IntElement,
     element::BoolElement,
     kernel::{self, matmul::MatmulStrategy},
+    quota,
     tensor::{CubeTensor, QParams},
 };
 
@@ -115,6 +116,10 @@ fn new_quantized<R: CubeRuntime>(
     let scales_desc =
         MemoryLayoutDescriptor::new(alloc_kind, scales_shape.clone(), scales_dtype.size());
 
+    let num_bytes =
+        shape_value.num_elements() * data_size + scales_shape.num_elements() * scales_dtype.size();
+    quota::reserve(&client, device, num_bytes);
+
     let mut tensors = match data {
         Some(data) => {
             let num_bytes = shape_value.num_elements() * data_size;
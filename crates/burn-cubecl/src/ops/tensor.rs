@@ -13,7 +13,10 @@ use crate::{
 use burn_backend::ops::GridSampleOptions;
 use burn_backend::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
 use burn_backend::{DType, ElementConversion, FloatDType, Slice};
-use burn_backend::{Distribution, Shape, TensorData, ops::FloatTensorOps};
+use burn_backend::{
+    Distribution, Shape, TensorData,
+    ops::{FloatTensorOps, float_random_derived},
+};
 use burn_backend::{ExecutionError, Scalar, get_device_settings};
 use burn_std::{BoolDType, IntDType};
 use cubecl::prelude::*;
@@ -45,6 +48,7 @@ where
         device: &Device<Self>,
         dtype: FloatDType,
     ) -> FloatTensor<Self> {
+        let float_dtype = dtype;
         let dtype = dtype.into();
         match distribution {
             Distribution::Default => random_uniform(shape, device, 0., 1., dtype),
@@ -55,6 +59,7 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem(), dtype)
             }
+            distribution => float_random_derived::<Self>(shape, distribution, device, float_dtype),
         }
     }
 
@@ -684,6 +689,14 @@ where
         kernel::flip(tensor, axes, bool_dtype.into())
     }
 
+    fn float_roll(
+        tensor: FloatTensor<Self>,
+        shifts: &[usize],
+        dims: &[usize],
+    ) -> FloatTensor<Self> {
+        kernel::roll(tensor, shifts, dims)
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         kernel::cast(tensor, dtype.into())
     }
@@ -0,0 +1,119 @@
+use crate::CubeRuntime;
+use burn_backend::{DeviceId, DeviceOps, MemoryQuota, MemoryQuotaError, StreamId};
+use cubecl::client::ComputeClient;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+/// The memory quota scopes entered on each stream of each device, from the outermost to the
+/// innermost.
+///
+/// The memory managers of the cube runtimes are per stream, so a scope is charged with the growth
+/// of the memory allocated on its stream, which other streams, and therefore the scopes of other
+/// threads, don't affect.
+static SCOPES: LazyLock<Mutex<HashMap<(DeviceId, StreamId), Vec<QuotaScope>>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Debug)]
+struct QuotaScope {
+    quota: MemoryQuota,
+    /// The bytes allocated on the stream when the scope was entered.
+    baseline: u64,
+    /// The bytes charged to the quota by the scope, which is the peak of the growth of the
+    /// allocated memory.
+    charged: u64,
+    /// The first allocation that didn't fit in the quota.
+    error: Option<MemoryQuotaError>,
+}
+
+/// Runs the function with the memory it allocates on the current stream of the device charged
+/// to the quota.
+///
+/// Tensor operations can't fail, so the function runs to completion even when an allocation
+/// doesn't fit in the quota: the error of the first allocation that didn't fit is recorded, and
+/// once the function is done its output is dropped, which frees its memory, and the error is
+/// returned.
+pub(crate) fn scope<R: CubeRuntime, Output>(
+    device: &R::Device,
+    quota: &MemoryQuota,
+    func: impl FnOnce() -> Output,
+) -> Result<Output, MemoryQuotaError> {
+    let client = R::client(device);
+    let key = (device.to_id(), StreamId::current());
+
+    SCOPES
+        .lock()
+        .unwrap()
+        .entry(key)
+        .or_default()
+        .push(QuotaScope {
+            quota: quota.clone(),
+            baseline: allocated(&client),
+            charged: 0,
+            error: None,
+        });
+
+    let output = func();
+
+    // Account for the memory allocated since the last allocation of the scope, e.g. by fused
+    // kernels.
+    reserve::<R>(&client, device, 0);
+
+    let mut scopes = SCOPES.lock().unwrap();
+    let stack = scopes.get_mut(&key).expect("The scope should be entered");
+    let scope = stack.pop().expect("The scope should be entered");
+    if stack.is_empty() {
+        scopes.remove(&key);
+    }
+    core::mem::drop(scopes);
+
+    scope.quota.release(scope.charged);
+
+    match scope.error {
+        Some(error) => Err(error),
+        None => Ok(output),
+    }
+}
+
+/// Charges an allocation of `bytes` on the current stream of the device to the quota of the
+/// innermost scope, before it is made.
+///
+/// Returns an error without charging anything when the memory allocated by the scope would
+/// exceed the quota.
+pub(crate) fn try_reserve<R: CubeRuntime>(
+    client: &ComputeClient<R>,
+    device: &R::Device,
+    bytes: usize,
+) -> Result<(), MemoryQuotaError> {
+    let key = (device.to_id(), StreamId::current());
+    let mut scopes = SCOPES.lock().unwrap();
+    let Some(scope) = scopes.get_mut(&key).and_then(|stack| stack.last_mut()) else {
+        return Ok(());
+    };
+
+    let growth = allocated(client).saturating_sub(scope.baseline) + bytes as u64;
+    if growth > scope.charged {
+        scope.quota.try_reserve(growth - scope.charged)?;
+        scope.charged = growth;
+    }
+
+    Ok(())
+}
+
+/// Charges an allocation of `bytes` like [try_reserve], for the operations that can't fail: the
+/// first error is recorded, and returned by the [scope] once its function has completed.
+pub(crate) fn reserve<R: CubeRuntime>(client: &ComputeClient<R>, device: &R::Device, bytes: usize) {
+    if let Err(error) = try_reserve::<R>(client, device, bytes) {
+        let key = (device.to_id(), StreamId::current());
+        let mut scopes = SCOPES.lock().unwrap();
+        if let Some(scope) = scopes.get_mut(&key).and_then(|stack| stack.last_mut()) {
+            scope.error.get_or_insert(error);
+        }
+    }
+}
+
+/// The bytes of the memory allocated on the current stream, including the padding of the
+/// allocations.
+fn allocated<R: CubeRuntime>(client: &ComputeClient<R>) -> u64 {
+    let usage = client.memory_usage();
+    usage.bytes_in_use + usage.bytes_padding
+}
@@ -0,0 +1,211 @@
+//! [DLPack](https://dmlc.github.io/dlpack/latest/) interoperability for CUDA tensors, with the
+//! `kDLCUDA` device type.
+//!
+//! Exported tensors point to the device memory of the tensor, without a copy, which is kept alive
+//! until the consumer calls the deleter. Imported tensors are copied on the device to memory owned
+//! by burn with [`copy_from_device_ptr`], since the memory pools can't adopt external allocations,
+//! and the deleter of the producer is called once the copy is complete.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+use burn_backend::DataError;
+use burn_cubecl::tensor::CubeTensor;
+use burn_std::dlpack::{DLDataType, DLDevice, DLDeviceType, DLManagedTensor, DLTensor};
+use cubecl::cuda::{CudaDevice, CudaRuntime};
+
+use crate::interop::{DevicePtr, InteropError, copy_from_device_ptr, with_device_ptr};
+
+/// Keeps the exported tensor and its metadata alive until the consumer calls the deleter.
+struct ExportContext {
+    managed: DLManagedTensor,
+    _tensor: CubeTensor<CudaRuntime>,
+    shape: Vec<i64>,
+}
+
+unsafe extern "C" fn export_deleter(managed: *mut DLManagedTensor) {
+    if managed.is_null() {
+        return;
+    }
+
+    // SAFETY: `manager_ctx` points to the context boxed by `to_dlpack`.
+    unsafe {
+        let ctx = (*managed).manager_ctx as *mut ExportContext;
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// A DLPack tensor owned by burn until it is released through its deleter.
+struct Managed(NonNull<DLManagedTensor>);
+
+// SAFETY: DLPack producers hand over ownership of the tensor to the consumer, and the deleter is
+// called once from whichever thread releases it.
+unsafe impl Send for Managed {}
+
+impl Managed {
+    fn release(self) {
+        // SAFETY: The tensor is owned and released once.
+        unsafe {
+            if let Some(deleter) = (*self.0.as_ptr()).deleter {
+                deleter(self.0.as_ptr());
+            }
+        }
+    }
+}
+
+/// Exports the tensor as a DLPack tensor on its CUDA device, without a copy.
+///
+/// The operations producing the tensor are complete when this function returns, so the consumer
+/// can read the memory on any stream. The memory isn't shared with other tensors, so the consumer
+/// can also write to it.
+///
+/// # Errors
+///
+/// If the data type can't be represented with DLPack (e.g., quantized data), or if the pending
+/// operations failed.
+pub fn to_dlpack(
+    tensor: CubeTensor<CudaRuntime>,
+) -> Result<NonNull<DLManagedTensor>, InteropError> {
+    let dtype = DLDataType::try_from(tensor.dtype).map_err(InteropError::Data)?;
+    let (tensor, ptr) = with_device_ptr(tensor, |ptr| ptr)?;
+    let shape = tensor
+        .meta
+        .shape()
+        .iter()
+        .map(|&dim| dim as i64)
+        .collect::<Vec<_>>();
+    let device = DLDevice {
+        device_type: DLDeviceType::CUDA,
+        device_id: tensor.device.index as i32,
+    };
+
+    let ctx = Box::new(ExportContext {
+        managed: DLManagedTensor {
+            dl_tensor: DLTensor {
+                data: ptr as *mut c_void,
+                device,
+                ndim: shape.len() as i32,
+                dtype,
+                shape: core::ptr::null_mut(),
+                strides: core::ptr::null_mut(),
+                byte_offset: 0,
+            },
+            manager_ctx: core::ptr::null_mut(),
+            deleter: Some(export_deleter),
+        },
+        _tensor: tensor,
+        shape,
+    });
+
+    let ctx = Box::into_raw(ctx);
+
+    // SAFETY: `ctx` was just allocated and is only released by `export_deleter`. The shape is
+    // owned by the context, so its address is stable.
+    unsafe {
+        let managed = &mut (*ctx).managed;
+        managed.dl_tensor.shape = (*ctx).shape.as_mut_ptr();
+        managed.manager_ctx = ctx as *mut c_void;
+
+        Ok(NonNull::from(managed))
+    }
+}
+
+/// Copies a DLPack tensor allocated on a CUDA device to a new tensor.
+///
+/// The tensor is created on the device of the DLPack tensor, and its values are copied on the
+/// device with [`copy_from_device_ptr`]. The DLPack tensor is released through the deleter of the
+/// producer once the copy is complete, or immediately on error.
+///
+/// Only CUDA memory with a compact row-major layout is supported, host memory is imported with
+/// `TensorData::from_dlpack`.
+///
+/// # Safety
+///
+/// `managed` must point to a valid DLPack tensor that is not used by the caller anymore, whose
+/// producing operations are complete.
+pub unsafe fn from_dlpack(
+    managed: NonNull<DLManagedTensor>,
+) -> Result<CubeTensor<CudaRuntime>, InteropError> {
+    let managed = Managed(managed);
+    // SAFETY: Validity of the pointer is guaranteed by the caller.
+    let tensor = unsafe { &managed.0.as_ref().dl_tensor };
+
+    let layout = match tensor.device.device_type {
+        // SAFETY: DLPack guarantees the shape and strides are valid for `ndim` elements.
+        DLDeviceType::CUDA => unsafe { tensor.contiguous_layout() },
+        _ => Err(DataError::Unsupported(alloc::format!(
+            "DLPack tensor on device {:?} isn't a CUDA tensor",
+            tensor.device
+        ))),
+    };
+    let (shape, dtype) = match layout {
+        Ok(layout) => layout,
+        Err(err) => {
+            managed.release();
+            return Err(InteropError::Data(err));
+        }
+    };
+
+    if tensor.data.is_null() && shape.num_elements() > 0 {
+        managed.release();
+        return Err(InteropError::Data(DataError::Unsupported(alloc::format!(
+            "DLPack tensor of shape {shape:?} has no data"
+        ))));
+    }
+
+    let device = CudaDevice::new(tensor.device.device_id as usize);
+    let ptr = tensor.first_element() as DevicePtr;
+
+    // SAFETY: The memory holds the tensor per the caller contract, and stays valid until the
+    // producer's deleter is called.
+    unsafe { copy_from_device_ptr(ptr, shape, dtype, &device, move |_| managed.release()) }
+}
+
+#[cfg(all(test, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+    use crate::interop::import_with;
+    use burn_backend::{DType, Shape, TensorData, ops::FloatTensorOps};
+    use burn_cubecl::{CubeBackend, ops::into_data_sync};
+
+    type B = CubeBackend<CudaRuntime, f32, i32, u8>;
+
+    #[test]
+    fn should_export_without_copy() {
+        let device = CudaDevice::default();
+        let tensor = B::float_from_data(TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]), &device);
+        let (tensor, ptr) = with_device_ptr(tensor, |ptr| ptr).unwrap();
+
+        let managed = to_dlpack(tensor).unwrap();
+
+        let dl_tensor = unsafe { &managed.as_ref().dl_tensor };
+        assert_eq!(dl_tensor.data as DevicePtr, ptr);
+        assert_eq!(dl_tensor.device.device_type, DLDeviceType::CUDA);
+        assert_eq!(dl_tensor.ndim, 2);
+        Managed(managed).release();
+    }
+
+    #[test]
+    fn should_round_trip_dlpack() {
+        let device = CudaDevice::default();
+        let expected = TensorData::from([1.0f32, 2.0, 3.0]);
+        let tensor = B::float_from_data(expected.clone(), &device);
+
+        let managed = to_dlpack(tensor).unwrap();
+        let imported = unsafe { from_dlpack(managed) }.unwrap();
+
+        into_data_sync(imported).assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn should_reject_host_dlpack() {
+        let (tensor, _) =
+            import_with(Shape::new([2]), DType::F32, &CudaDevice::default(), |_| ()).unwrap();
+        let managed = to_dlpack(tensor).unwrap();
+        unsafe { (*managed.as_ptr()).dl_tensor.device = DLDevice::CPU };
+
+        assert!(unsafe { from_dlpack(managed) }.is_err());
+    }
+}
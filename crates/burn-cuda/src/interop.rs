@@ -0,0 +1,385 @@
+//! Interoperability with device memory owned by other CUDA libraries or processes.
+//!
+//! Tensors are always backed by memory owned by burn's memory pools, so data is only exchanged
+//! without a copy by lending the memory of a tensor for an explicit scope: [`import_with`] lets
+//! external code write the values of a new tensor, and [`with_device_ptr`] lets it read or update
+//! an existing one. The memory pools can't adopt memory allocated outside of them, so memory that
+//! is already owned by another library or process is copied: [`copy_from_device_ptr`] and
+//! [`import_ipc`] copy it to a new tensor, and [`export_ipc`] copies a tensor to a dedicated
+//! allocation that can be shared. The calling thread doesn't need a current CUDA context, the
+//! primary context of the device is used, which is also the one used by burn.
+
+use core::fmt;
+
+use burn_backend::{Backend, DType, DataError, ExecutionError, Shape};
+use burn_cubecl::{CubeBackend, kernel::into_contiguous, tensor::CubeTensor};
+use cubecl::Runtime;
+use cubecl::cuda::{CudaDevice, CudaRuntime};
+use cudarc::driver::sys;
+
+/// A raw CUDA device pointer (`CUdeviceptr`).
+pub type DevicePtr = u64;
+
+/// Error returned when external memory can't be copied to or from a tensor.
+#[derive(Debug)]
+pub enum InteropError {
+    /// A CUDA driver call failed.
+    Cuda(sys::CUresult),
+    /// The pending tensor operations failed.
+    Execution(ExecutionError),
+    /// The external tensor can't be represented, e.g. because of its data type or layout.
+    Data(DataError),
+    /// The external memory is too small for the tensor.
+    SizeMismatch {
+        /// The number of bytes needed by the tensor.
+        expected: usize,
+        /// The number of bytes available in the external memory.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for InteropError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cuda(result) => write!(f, "CUDA driver call failed with {result:?}"),
+            Self::Execution(err) => write!(f, "Tensor execution failed: {err:?}"),
+            Self::Data(err) => write!(f, "Unsupported external tensor: {err}"),
+            Self::SizeMismatch { expected, actual } => write!(
+                f,
+                "External memory of {actual} bytes is too small for a tensor of {expected} bytes"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InteropError {}
+
+/// An inter-process handle to the memory of a tensor, see [`export_ipc`].
+///
+/// The handle can be sent to another process on the same machine as plain bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CudaIpcHandle {
+    /// The `CUipcMemHandle` of the allocation holding the tensor.
+    pub handle: [u8; 64],
+    /// The offset of the tensor data in the allocation, in bytes.
+    pub offset: u64,
+    /// The size of the tensor data, in bytes.
+    pub size: u64,
+}
+
+/// The values of a tensor shared with other processes.
+///
+/// The values are copied to a dedicated allocation, since IPC handles refer to whole allocations
+/// while tensors are sub-allocated from memory pools, so the other processes can only access the
+/// values of the tensor. The allocation stays valid as long as this value is alive, it must
+/// outlive the use of the [handle](Self::handle) by the other processes.
+pub struct CudaIpcExport {
+    device: CudaDevice,
+    handle: CudaIpcHandle,
+    ptr: DevicePtr,
+}
+
+impl CudaIpcExport {
+    /// The handle to send to the other processes.
+    pub fn handle(&self) -> CudaIpcHandle {
+        self.handle
+    }
+}
+
+impl Drop for CudaIpcExport {
+    fn drop(&mut self) {
+        if bind_primary_context(&self.device).is_ok() {
+            // SAFETY: The allocation was made by `export_ipc` and is only freed here.
+            unsafe {
+                sys::cuMemFree_v2(self.ptr);
+            }
+        }
+    }
+}
+
+/// Create a new tensor whose values are written by external code, without a copy.
+///
+/// The closure receives the device pointer to the uninitialized memory of the tensor, in
+/// row-major order, and must write all its values before returning.
+///
+/// * `shape` - The shape of the tensor.
+/// * `dtype` - The data type of the values.
+/// * `device` - The device of the tensor.
+/// * `func` - The external code writing the values.
+pub fn import_with<T>(
+    shape: Shape,
+    dtype: DType,
+    device: &CudaDevice,
+    func: impl FnOnce(DevicePtr) -> T,
+) -> Result<(CubeTensor<CudaRuntime>, T), InteropError> {
+    let client = CudaRuntime::client(device);
+    let handle = client.empty(shape.num_elements() * dtype.size());
+    // The new allocation may reuse memory still used by pending operations.
+    sync(device)?;
+
+    let resource = client.get_resource(handle.clone().binding());
+    bind_primary_context(device)?;
+    let output = func(resource.resource().ptr);
+
+    let tensor = CubeTensor::new_contiguous(client, device.clone(), shape, handle, dtype);
+    Ok((tensor, output))
+}
+
+/// Copy external device memory to a new tensor.
+///
+/// The values are copied on the device to memory owned by burn, since the memory pools of CubeCL
+/// can't adopt allocations made outside of them. The external memory is handed back to its owner
+/// by calling `release` with `ptr` once the copy is complete, or has failed, before this function
+/// returns.
+///
+/// Use [`import_with`] instead when the external code can write its output to memory provided by
+/// burn, which doesn't copy.
+///
+/// * `ptr` - The device pointer to the values, in row-major order.
+/// * `shape` - The shape of the tensor.
+/// * `dtype` - The data type of the values.
+/// * `device` - The device of the memory and of the tensor.
+/// * `release` - Hands the memory back to its owner, e.g. to free it.
+///
+/// # Safety
+///
+/// `ptr` must point to at least `shape.num_elements() * dtype.size()` bytes allocated on
+/// `device`, which must not be freed or written to until `release` is called.
+pub unsafe fn copy_from_device_ptr(
+    ptr: DevicePtr,
+    shape: Shape,
+    dtype: DType,
+    device: &CudaDevice,
+    release: impl FnOnce(DevicePtr) + Send + 'static,
+) -> Result<CubeTensor<CudaRuntime>, InteropError> {
+    let size = shape.num_elements() * dtype.size();
+    let imported = import_with(shape, dtype, device, |dst| {
+        // SAFETY: The source is valid for `size` bytes per the caller contract, and the
+        // destination was allocated with `size` bytes.
+        unsafe {
+            check(sys::cuMemcpyDtoD_v2(dst, ptr, size))?;
+            check(sys::cuCtxSynchronize())
+        }
+    });
+    // The copy is complete, or failed, so the tensor doesn't need the external memory anymore.
+    release(ptr);
+
+    let (tensor, copied) = imported?;
+    copied?;
+    Ok(tensor)
+}
+
+/// Lend the device memory of a tensor to external code.
+///
+/// The closure receives the device pointer to the values of the tensor, in row-major order.
+/// All the operations producing the tensor are complete when the closure is called, and the
+/// pointer is valid until the closure returns. Writes made through the pointer must be complete
+/// before the closure returns, and are visible in the returned tensor.
+///
+/// The memory is only lent without a copy when the tensor doesn't share it with other tensors,
+/// so that the writes can't affect them.
+pub fn with_device_ptr<T>(
+    tensor: CubeTensor<CudaRuntime>,
+    func: impl FnOnce(DevicePtr) -> T,
+) -> Result<(CubeTensor<CudaRuntime>, T), InteropError> {
+    let tensor = into_contiguous(tensor);
+    let tensor = match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    };
+    sync(&tensor.device)?;
+    bind_primary_context(&tensor.device)?;
+
+    let resource = tensor.client.get_resource(tensor.handle.clone().binding());
+    let output = func(resource.resource().ptr);
+
+    Ok((tensor, output))
+}
+
+/// Share the values of a tensor with other processes.
+///
+/// Other processes can read the values with [`import_ipc`] while the returned export is alive.
+/// The values are copied to the export, so the tensor can be used and dropped freely.
+pub fn export_ipc(tensor: &CubeTensor<CudaRuntime>) -> Result<CudaIpcExport, InteropError> {
+    let tensor = into_contiguous(tensor.clone());
+    let size = tensor.meta.shape().num_elements() * tensor.dtype.size();
+    sync(&tensor.device)?;
+
+    let resource = tensor.client.get_resource(tensor.handle.clone().binding());
+    bind_primary_context(&tensor.device)?;
+
+    let mut ptr = 0;
+    // SAFETY: The out pointer is valid.
+    unsafe { check(sys::cuMemAlloc_v2(&mut ptr, size.max(1)))? };
+
+    let mut ipc_handle = sys::CUipcMemHandle { reserved: [0; 64] };
+    // SAFETY: `ptr` was allocated above with `size` bytes, and the source is a live allocation of
+    // `size` bytes owned by the tensor.
+    let exported = unsafe {
+        check(sys::cuMemcpyDtoD_v2(ptr, resource.resource().ptr, size))
+            .and_then(|_| check(sys::cuCtxSynchronize()))
+            .and_then(|_| check(sys::cuIpcGetMemHandle(&mut ipc_handle, ptr)))
+    };
+    if let Err(err) = exported {
+        // SAFETY: `ptr` was allocated above and isn't shared.
+        unsafe { sys::cuMemFree_v2(ptr) };
+        return Err(err);
+    }
+
+    let handle = CudaIpcHandle {
+        handle: ipc_handle.reserved.map(|byte| byte as u8),
+        offset: 0,
+        size: size as u64,
+    };
+
+    Ok(CudaIpcExport {
+        device: tensor.device.clone(),
+        handle,
+        ptr,
+    })
+}
+
+/// Copy the values shared by another process to a new tensor.
+///
+/// The shared memory is opened, copied with [`copy_from_device_ptr`], and closed once the copy is
+/// complete.
+///
+/// * `handle` - The handle received from the exporting process.
+/// * `shape` - The shape of the tensor.
+/// * `dtype` - The data type of the values.
+/// * `device` - The device of the shared memory and of the tensor.
+///
+/// # Safety
+///
+/// The [export](CudaIpcExport) of the handle must be kept alive by the other process until this
+/// function returns.
+pub unsafe fn import_ipc(
+    handle: &CudaIpcHandle,
+    shape: Shape,
+    dtype: DType,
+    device: &CudaDevice,
+) -> Result<CubeTensor<CudaRuntime>, InteropError> {
+    let expected = shape.num_elements() * dtype.size();
+    if expected > handle.size as usize {
+        return Err(InteropError::SizeMismatch {
+            expected,
+            actual: handle.size as usize,
+        });
+    }
+
+    bind_primary_context(device)?;
+    let ipc_handle = sys::CUipcMemHandle {
+        reserved: handle.handle.map(|byte| byte as _),
+    };
+    let mut base = 0;
+    // SAFETY: The handle refers to a live allocation per the caller contract.
+    unsafe {
+        check(sys::cuIpcOpenMemHandle_v2(
+            &mut base,
+            ipc_handle,
+            sys::CUipcMem_flags::CU_IPC_MEM_LAZY_ENABLE_PEER_ACCESS as u32,
+        ))?;
+    }
+
+    let offset = handle.offset;
+    let close = move |ptr: DevicePtr| {
+        // SAFETY: The allocation was opened above at `ptr - offset`, and isn't used anymore.
+        // Failing to close it only keeps the mapping alive until the process exits.
+        unsafe { sys::cuIpcCloseMemHandle(ptr - offset) };
+    };
+
+    // SAFETY: The opened allocation holds `handle.size` bytes at `handle.offset`, and stays open
+    // until it is released.
+    unsafe { copy_from_device_ptr(base + offset, shape, dtype, device, close) }
+}
+
+fn sync(device: &CudaDevice) -> Result<(), InteropError> {
+    <CubeBackend<CudaRuntime, f32, i32, u8> as Backend>::sync(device)
+        .map_err(InteropError::Execution)
+}
+
+fn bind_primary_context(device: &CudaDevice) -> Result<(), InteropError> {
+    let mut cu_device = 0;
+    let mut context = core::ptr::null_mut();
+    // SAFETY: The out pointers are valid. The primary context is already retained by burn, so
+    // retaining it again doesn't create a new context.
+    unsafe {
+        check(sys::cuInit(0))?;
+        check(sys::cuDeviceGet(&mut cu_device, device.index as i32))?;
+        check(sys::cuDevicePrimaryCtxRetain(&mut context, cu_device))?;
+        check(sys::cuCtxSetCurrent(context))?;
+        check(sys::cuDevicePrimaryCtxRelease_v2(cu_device))?;
+    }
+
+    Ok(())
+}
+
+fn check(result: sys::CUresult) -> Result<(), InteropError> {
+    match result {
+        sys::CUresult::CUDA_SUCCESS => Ok(()),
+        err => Err(InteropError::Cuda(err)),
+    }
+}
+
+#[cfg(all(test, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+    use burn_backend::{TensorData, ops::FloatTensorOps};
+    use burn_cubecl::ops::into_data_sync;
+
+    type B = CubeBackend<CudaRuntime, f32, i32, u8>;
+
+    #[test]
+    fn should_copy_imported_memory_and_lend_without_copy() {
+        let device = CudaDevice::default();
+        let source = B::float_from_data(TensorData::from([1.0f32, 2.0, 3.0, 4.0]), &device);
+
+        let (source, source_ptr) = with_device_ptr(source, |ptr| ptr).unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        // SAFETY: The source tensor holds 4 f32 values and outlives the import.
+        let imported = unsafe {
+            copy_from_device_ptr(
+                source_ptr,
+                Shape::new([4]),
+                DType::F32,
+                &device,
+                move |ptr| sender.send(ptr).unwrap(),
+            )
+            .unwrap()
+        };
+        // The external memory is released once it is copied.
+        assert_eq!(receiver.try_recv(), Ok(source_ptr));
+        let (imported, imported_ptr) = with_device_ptr(imported, |ptr| ptr).unwrap();
+        assert_ne!(imported_ptr, source_ptr);
+
+        let (written, written_ptr) = import_with(Shape::new([4]), DType::F32, &device, |dst| {
+            // SAFETY: Both allocations hold 4 f32 values.
+            unsafe { check(sys::cuMemcpyDtoD_v2(dst, source_ptr, 16)).unwrap() };
+            dst
+        })
+        .unwrap();
+        let (written, lent_ptr) = with_device_ptr(written, |ptr| ptr).unwrap();
+
+        assert_eq!(written_ptr, lent_ptr);
+        let expected = TensorData::from([1.0f32, 2.0, 3.0, 4.0]);
+        into_data_sync(imported).assert_eq(&expected, true);
+        into_data_sync(written).assert_eq(&expected, true);
+        into_data_sync(source).assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn should_not_lend_shared_memory() {
+        let device = CudaDevice::default();
+        let tensor = B::float_from_data(TensorData::from([1.0f32, 2.0]), &device);
+        let shared = tensor.clone();
+
+        let (tensor, _) = with_device_ptr(tensor, |ptr| {
+            // SAFETY: The lent memory holds 2 f32 values.
+            unsafe { check(sys::cuMemsetD32_v2(ptr, 0, 2)).unwrap() };
+        })
+        .unwrap();
+
+        into_data_sync(tensor).assert_eq(&TensorData::from([0.0f32, 0.0]), true);
+        into_data_sync(shared).assert_eq(&TensorData::from([1.0f32, 2.0]), true);
+    }
+}
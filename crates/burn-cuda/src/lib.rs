@@ -2,9 +2,15 @@
 
 extern crate alloc;
 
+pub mod interop;
+
+/// DLPack interoperability for CUDA tensors.
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
+
 use burn_cubecl::CubeBackend;
-pub use cubecl::cuda::CudaDevice;
-use cubecl::cuda::CudaRuntime;
+pub use burn_cubecl::tensor::CubeTensor;
+pub use cubecl::cuda::{CudaDevice, CudaRuntime};
 
 #[cfg(not(feature = "fusion"))]
 pub type Cuda<F = f32, I = i32> = CubeBackend<CudaRuntime, F, I, u8>;
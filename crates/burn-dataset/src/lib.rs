@@ -24,6 +24,10 @@ pub mod audio;
 #[cfg(feature = "vision")]
 pub mod vision;
 
+/// Video datasets.
+#[cfg(feature = "video")]
+pub mod video;
+
 /// Natural language processing datasets.
 #[cfg(feature = "nlp")]
 pub mod nlp;
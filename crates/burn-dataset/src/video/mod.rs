@@ -0,0 +1,3 @@
+mod video_folder;
+
+pub use video_folder::*;
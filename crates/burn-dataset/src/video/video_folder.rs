@@ -0,0 +1,711 @@
+use crate::Dataset;
+
+use ffmpeg_next as ffmpeg;
+use ffmpeg_next::format::Pixel;
+use ffmpeg_next::media::Type;
+use ffmpeg_next::software::scaling;
+use ffmpeg_next::util::frame::video::Video;
+use globwalk::{self, DirEntry};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const SUPPORTED_FILES: [&str; 5] = ["avi", "mkv", "mov", "mp4", "webm"];
+
+/// How clips are sampled from each video.
+///
+/// A clip is made of `clip_len` frames, taken every `frame_stride` frames. Consecutive clips of a
+/// video start `clip_stride` frames apart, and only clips fully contained in the video are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipSampling {
+    /// Number of frames in a clip.
+    pub clip_len: usize,
+    /// Step between the frames of a clip.
+    pub frame_stride: usize,
+    /// Step between the first frames of consecutive clips.
+    pub clip_stride: usize,
+}
+
+impl ClipSampling {
+    /// Sample non-overlapping clips of consecutive frames.
+    pub fn new(clip_len: usize) -> Self {
+        Self {
+            clip_len,
+            frame_stride: 1,
+            clip_stride: clip_len,
+        }
+    }
+
+    /// Set the step between the frames of a clip.
+    pub fn with_frame_stride(mut self, frame_stride: usize) -> Self {
+        self.frame_stride = frame_stride;
+        self
+    }
+
+    /// Set the step between the first frames of consecutive clips.
+    pub fn with_clip_stride(mut self, clip_stride: usize) -> Self {
+        self.clip_stride = clip_stride;
+        self
+    }
+
+    /// Number of frames spanned by a clip, from its first frame to its last frame.
+    fn span(&self) -> usize {
+        (self.clip_len - 1) * self.frame_stride + 1
+    }
+
+    /// First frames of the clips of a video with `num_frames` frames.
+    fn clip_starts(&self, num_frames: usize) -> Vec<usize> {
+        if num_frames < self.span() {
+            return Vec::new();
+        }
+
+        (0..=num_frames - self.span())
+            .step_by(self.clip_stride)
+            .collect()
+    }
+
+    /// Indices of the frames of the clip starting at `start`.
+    fn frame_indices(&self, start: usize) -> Vec<usize> {
+        (0..self.clip_len)
+            .map(|i| start + i * self.frame_stride)
+            .collect()
+    }
+}
+
+/// Video clip dataset item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoClipItem {
+    /// RGB frames of the clip, with shape `[num_frames, height, width, 3]` in row-major order.
+    pub frames: Vec<u8>,
+
+    /// Number of frames in the clip.
+    pub num_frames: usize,
+
+    /// Frame height.
+    pub height: usize,
+
+    /// Frame width.
+    pub width: usize,
+
+    /// Class label of the video.
+    pub label: usize,
+
+    /// Index of the first frame of the clip in the video.
+    pub start_frame: usize,
+
+    /// Original video source.
+    pub video_path: String,
+}
+
+/// Error type for [VideoFolderDataset](VideoFolderDataset).
+#[derive(Error, Debug)]
+pub enum VideoLoaderError {
+    /// Unknown error.
+    #[error("unknown: `{0}`")]
+    Unknown(String),
+
+    /// I/O operation error.
+    #[error("I/O error: `{0}`")]
+    IOError(String),
+
+    /// Invalid file error.
+    #[error("Invalid file extension: `{0}`")]
+    InvalidFileExtensionError(String),
+
+    /// Decoding error.
+    #[error("Decoding error: `{0}`")]
+    DecodingError(String),
+
+    /// Invalid clip sampling.
+    #[error("Invalid clip sampling: `{0}`")]
+    InvalidSampling(String),
+}
+
+#[derive(Debug, Clone)]
+struct VideoEntry {
+    path: PathBuf,
+    label: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClipEntry {
+    video: usize,
+    start: usize,
+}
+
+/// A dataset of video clips decoded from disk with ffmpeg.
+///
+/// Videos are only probed when the dataset is created, and clips are decoded when they are
+/// requested, so decoding runs on the data loader worker threads.
+pub struct VideoFolderDataset {
+    videos: Vec<VideoEntry>,
+    clips: Vec<ClipEntry>,
+    sampling: ClipSampling,
+    frame_size: Option<(u32, u32)>,
+}
+
+impl Dataset<VideoClipItem> for VideoFolderDataset {
+    fn get(&self, index: usize) -> Option<VideoClipItem> {
+        let clip = self.clips.get(index)?;
+        let video = &self.videos[clip.video];
+        let indices = self.sampling.frame_indices(clip.start);
+
+        let (frames, width, height) = decode_frames(&video.path, &indices, self.frame_size)
+            .unwrap_or_else(|err| panic!("Failed to decode video {}: {err}", video.path.display()));
+
+        Some(VideoClipItem {
+            frames,
+            num_frames: indices.len(),
+            height,
+            width,
+            label: video.label,
+            start_frame: clip.start,
+            video_path: video.path.to_string_lossy().into_owned(),
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.clips.len()
+    }
+}
+
+impl VideoFolderDataset {
+    /// Create a video classification dataset from the root folder.
+    ///
+    /// The label of each video is the name of its parent folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Dataset root folder.
+    /// * `sampling` - How clips are sampled from each video.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_classification<P: AsRef<Path>>(
+        root: P,
+        sampling: ClipSampling,
+    ) -> Result<Self, VideoLoaderError> {
+        let walker = globwalk::GlobWalkerBuilder::from_patterns(
+            root.as_ref(),
+            &[format!("*.{{{}}}", SUPPORTED_FILES.join(","))],
+        )
+        .follow_links(true)
+        .sort_by(|p1: &DirEntry, p2: &DirEntry| p1.path().cmp(p2.path())) // order by path
+        .build()
+        .map_err(|err| VideoLoaderError::Unknown(format!("{err:?}")))?
+        .filter_map(Result::ok);
+
+        let mut items = Vec::new();
+        let mut classes = HashSet::new();
+        for video in walker {
+            let video_path = video.path();
+
+            // Label name is represented by the parent folder name
+            let label = video_path
+                .parent()
+                .and_then(|parent| parent.file_name())
+                .ok_or_else(|| {
+                    VideoLoaderError::IOError(
+                        "Could not resolve video parent folder name".to_string(),
+                    )
+                })?
+                .to_string_lossy()
+                .into_owned();
+
+            classes.insert(label.clone());
+            items.push((video_path.to_path_buf(), label));
+        }
+
+        // Sort class names
+        let mut classes = classes.into_iter().collect::<Vec<_>>();
+        classes.sort();
+
+        Self::new_classification_with_items(items, &classes, sampling)
+    }
+
+    /// Create a video classification dataset with the specified items.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - List of dataset items, each item represented by a tuple `(video path, label)`.
+    /// * `classes` - Dataset class names.
+    /// * `sampling` - How clips are sampled from each video.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_classification_with_items<P: AsRef<Path>, S: AsRef<str>>(
+        items: Vec<(P, String)>,
+        classes: &[S],
+        sampling: ClipSampling,
+    ) -> Result<Self, VideoLoaderError> {
+        if sampling.clip_len == 0 || sampling.frame_stride == 0 || sampling.clip_stride == 0 {
+            return Err(VideoLoaderError::InvalidSampling(format!("{sampling:?}")));
+        }
+        ffmpeg::init().map_err(|err| VideoLoaderError::DecodingError(err.to_string()))?;
+
+        // Class names to index map
+        let classes_map: HashMap<_, _> = classes
+            .iter()
+            .enumerate()
+            .map(|(idx, cls)| (cls.as_ref().to_string(), idx))
+            .collect();
+
+        let mut videos = Vec::with_capacity(items.len());
+        let mut clips = Vec::new();
+        for (path, label) in items {
+            let path = path.as_ref();
+            Self::check_extension(path)?;
+
+            let label = *classes_map
+                .get(&label)
+                .ok_or_else(|| VideoLoaderError::Unknown(format!("Unknown class `{label}`")))?;
+            let num_frames = count_frames(path).map_err(|err| {
+                VideoLoaderError::DecodingError(format!("{}: {err}", path.display()))
+            })?;
+
+            let video = videos.len();
+            clips.extend(
+                sampling
+                    .clip_starts(num_frames)
+                    .into_iter()
+                    .map(|start| ClipEntry { video, start }),
+            );
+            videos.push(VideoEntry {
+                path: path.to_path_buf(),
+                label,
+            });
+        }
+
+        Ok(Self {
+            videos,
+            clips,
+            sampling,
+            frame_size: None,
+        })
+    }
+
+    /// Resize the decoded frames to the given size.
+    pub fn with_frame_size(mut self, width: u32, height: u32) -> Self {
+        self.frame_size = Some((width, height));
+        self
+    }
+
+    /// Check if extension is supported.
+    fn check_extension(path: &Path) -> Result<(), VideoLoaderError> {
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        if SUPPORTED_FILES.contains(&extension.as_str()) {
+            Ok(())
+        } else {
+            Err(VideoLoaderError::InvalidFileExtensionError(extension))
+        }
+    }
+}
+
+/// Number of frames in the best video stream of the file.
+fn count_frames(path: &Path) -> Result<usize, ffmpeg::Error> {
+    let mut input = ffmpeg::format::input(path)?;
+    let (stream_index, frames) = {
+        let stream = input
+            .streams()
+            .best(Type::Video)
+            .ok_or(ffmpeg::Error::StreamNotFound)?;
+        (stream.index(), stream.frames())
+    };
+
+    if frames > 0 {
+        return Ok(frames as usize);
+    }
+
+    // The container doesn't store the frame count, each packet holds one frame.
+    Ok(input
+        .packets()
+        .filter(|(stream, _)| stream.index() == stream_index)
+        .count())
+}
+
+/// Decode the frames with the given sorted indices as RGB.
+///
+/// The decoding starts from the keyframe preceding the first frame, unless the frames can't be
+/// located after seeking, in which case the video is decoded from the start.
+///
+/// When the video has fewer frames than expected, the last decoded frame is repeated.
+fn decode_frames(
+    path: &Path,
+    indices: &[usize],
+    frame_size: Option<(u32, u32)>,
+) -> Result<(Vec<u8>, usize, usize), ffmpeg::Error> {
+    match decode_clip(path, indices, frame_size, true)? {
+        Some(clip) => Ok(clip),
+        None => decode_clip(path, indices, frame_size, false)
+            .map(|clip| clip.expect("The frames are located from the start of the video")),
+    }
+}
+
+/// Decode the frames with the given sorted indices as RGB, seeking to the keyframe preceding the
+/// first frame if `seek` is enabled.
+///
+/// Returns `None` if the frames can't be located after seeking.
+fn decode_clip(
+    path: &Path,
+    indices: &[usize],
+    frame_size: Option<(u32, u32)>,
+    seek: bool,
+) -> Result<Option<(Vec<u8>, usize, usize)>, ffmpeg::Error> {
+    let mut input = ffmpeg::format::input(path)?;
+    let (stream_index, parameters, clock) = {
+        let stream = input
+            .streams()
+            .best(Type::Video)
+            .ok_or(ffmpeg::Error::StreamNotFound)?;
+        (
+            stream.index(),
+            stream.parameters(),
+            FrameClock::new(&stream),
+        )
+    };
+
+    // Seeking requires locating the decoded frames from their timestamps.
+    let clock = clock.filter(|_| seek);
+    let seeked = match (clock, indices.first()) {
+        (Some(clock), Some(&first)) if first > 0 => {
+            let timestamp = clock.seek_timestamp(first);
+            input.seek(timestamp, ..timestamp).is_ok()
+        }
+        _ => false,
+    };
+
+    let decoder = ffmpeg::codec::context::Context::from_parameters(parameters)?
+        .decoder()
+        .video()?;
+    let (width, height) = frame_size.unwrap_or((decoder.width(), decoder.height()));
+    let scaler = scaling::Context::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        Pixel::RGB24,
+        width,
+        height,
+        scaling::Flags::BILINEAR,
+    )?;
+
+    let mut clip = ClipDecoder {
+        decoder,
+        scaler,
+        clock,
+        seeked,
+        lost: false,
+        indices,
+        next: 0,
+        frame_index: 0,
+        frame_len: width as usize * height as usize * 3,
+        row_len: width as usize * 3,
+        frames: Vec::new(),
+    };
+
+    for (stream, packet) in input.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        clip.decoder.send_packet(&packet)?;
+        clip.receive_frames()?;
+        if clip.is_done() || clip.lost {
+            break;
+        }
+    }
+
+    if !clip.is_done() && !clip.lost {
+        clip.decoder.send_eof()?;
+        clip.receive_frames()?;
+    }
+
+    if clip.lost {
+        return Ok(None);
+    }
+
+    let mut frames = clip.frames;
+    let frame_len = clip.frame_len;
+    if frames.is_empty() {
+        return Err(ffmpeg::Error::InvalidData);
+    }
+    while frames.len() < indices.len() * frame_len {
+        frames.extend_from_within(frames.len() - frame_len..);
+    }
+
+    Ok(Some((frames, width as usize, height as usize)))
+}
+
+/// Converts the timestamps of a video stream to frame indices, assuming a constant frame rate.
+#[derive(Debug, Clone, Copy)]
+struct FrameClock {
+    /// Duration of a timestamp unit, in seconds.
+    time_base: f64,
+    /// Timestamp of the first frame.
+    start: i64,
+    /// Number of frames per second.
+    frame_rate: f64,
+}
+
+impl FrameClock {
+    fn new(stream: &ffmpeg::format::stream::Stream) -> Option<Self> {
+        let time_base = f64::from(stream.time_base());
+        let frame_rate = f64::from(stream.avg_frame_rate());
+        // Unknown rates are `0/0`, which converts to NaN.
+        if !(time_base > 0.0 && frame_rate > 0.0) {
+            return None;
+        }
+
+        let start = match stream.start_time() {
+            ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+            start => start,
+        };
+
+        Some(Self {
+            time_base,
+            start,
+            frame_rate,
+        })
+    }
+
+    /// Index of the frame with the given timestamp.
+    fn frame_index(&self, timestamp: i64) -> usize {
+        let seconds = timestamp.saturating_sub(self.start) as f64 * self.time_base;
+        (seconds * self.frame_rate).round().max(0.0) as usize
+    }
+
+    /// Timestamp of the frame with the given index, in the `AV_TIME_BASE` units used to seek.
+    fn seek_timestamp(&self, frame_index: usize) -> i64 {
+        let seconds = self.start as f64 * self.time_base + frame_index as f64 / self.frame_rate;
+        (seconds * ffmpeg::ffi::AV_TIME_BASE as f64) as i64
+    }
+}
+
+struct ClipDecoder<'a> {
+    decoder: ffmpeg::decoder::Video,
+    scaler: scaling::Context,
+    clock: Option<FrameClock>,
+    /// Whether the decoding started from a keyframe instead of the start of the video.
+    seeked: bool,
+    /// Whether a frame couldn't be located after seeking.
+    lost: bool,
+    indices: &'a [usize],
+    next: usize,
+    frame_index: usize,
+    frame_len: usize,
+    row_len: usize,
+    frames: Vec<u8>,
+}
+
+impl ClipDecoder<'_> {
+    fn is_done(&self) -> bool {
+        self.next == self.indices.len()
+    }
+
+    fn receive_frames(&mut self) -> Result<(), ffmpeg::Error> {
+        let mut decoded = Video::empty();
+
+        while !self.is_done() && self.decoder.receive_frame(&mut decoded).is_ok() {
+            let index = match (self.clock, decoded.timestamp()) {
+                (Some(clock), Some(timestamp)) => clock.frame_index(timestamp),
+                _ if self.seeked => {
+                    self.lost = true;
+                    return Ok(());
+                }
+                _ => self.frame_index,
+            };
+
+            // Frames missing from the video are replaced by the next decoded frame.
+            if self.indices[self.next] <= index {
+                let mut rgb = Video::empty();
+                self.scaler.run(&decoded, &mut rgb)?;
+                while !self.is_done() && self.indices[self.next] <= index {
+                    self.push_frame(&rgb);
+                    self.next += 1;
+                }
+            }
+            self.frame_index = index + 1;
+        }
+
+        Ok(())
+    }
+
+    fn push_frame(&mut self, rgb: &Video) {
+        // Rows may be padded, copy them without the padding.
+        let stride = rgb.stride(0);
+        let data = rgb.data(0);
+        let height = self.frame_len / self.row_len;
+
+        for row in 0..height {
+            let start = row * stride;
+            self.frames
+                .extend_from_slice(&data[start..start + self.row_len]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn clip_sampling_non_overlapping() {
+        let sampling = ClipSampling::new(4);
+
+        assert_eq!(sampling.clip_starts(10), vec![0, 4]);
+        assert_eq!(sampling.frame_indices(4), vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    pub fn clip_sampling_with_strides() {
+        let sampling = ClipSampling::new(3)
+            .with_frame_stride(2)
+            .with_clip_stride(1);
+
+        // Each clip spans 5 frames
+        assert_eq!(sampling.clip_starts(7), vec![0, 1, 2]);
+        assert_eq!(sampling.frame_indices(2), vec![2, 4, 6]);
+    }
+
+    #[test]
+    pub fn clip_sampling_short_video() {
+        let sampling = ClipSampling::new(8);
+
+        assert!(sampling.clip_starts(5).is_empty());
+    }
+
+    /// Encodes a gray video with `num_frames` frames, whose brightness increases with the frame
+    /// index, and a keyframe every 5 frames.
+    fn encode_video(name: &str, num_frames: usize) -> PathBuf {
+        ffmpeg::init().unwrap();
+        let path =
+            std::env::temp_dir().join(format!("burn-video-{}-{name}.mkv", std::process::id()));
+
+        let mut output = ffmpeg::format::output(&path).unwrap();
+        let codec = ffmpeg::encoder::find(ffmpeg::codec::Id::MPEG4).unwrap();
+        let mut encoder = ffmpeg::codec::context::Context::new_with_codec(codec)
+            .encoder()
+            .video()
+            .unwrap();
+        encoder.set_width(16);
+        encoder.set_height(16);
+        encoder.set_format(Pixel::YUV420P);
+        encoder.set_time_base((1, 25));
+        encoder.set_frame_rate(Some((25, 1)));
+        encoder.set_gop(5);
+        encoder.set_max_b_frames(0);
+        let mut encoder = encoder.open_as(codec).unwrap();
+
+        let mut stream = output.add_stream(codec).unwrap();
+        stream.set_parameters(&encoder);
+        stream.set_time_base((1, 25));
+        output.write_header().unwrap();
+        let stream_time_base = output.stream(0).unwrap().time_base();
+
+        let write_packets =
+            |encoder: &mut ffmpeg::encoder::video::Encoder,
+             output: &mut ffmpeg::format::context::Output| {
+                let mut packet = ffmpeg::Packet::empty();
+                while encoder.receive_packet(&mut packet).is_ok() {
+                    packet.set_stream(0);
+                    packet.rescale_ts((1, 25), stream_time_base);
+                    packet.write_interleaved(output).unwrap();
+                }
+            };
+
+        for index in 0..num_frames {
+            let mut frame = Video::new(Pixel::YUV420P, 16, 16);
+            frame.data_mut(0).fill(20 + 10 * index as u8);
+            frame.data_mut(1).fill(128);
+            frame.data_mut(2).fill(128);
+            frame.set_pts(Some(index as i64));
+            encoder.send_frame(&frame).unwrap();
+            write_packets(&mut encoder, &mut output);
+        }
+        encoder.send_eof().unwrap();
+        write_packets(&mut encoder, &mut output);
+        output.write_trailer().unwrap();
+
+        path
+    }
+
+    /// Mean brightness of each frame of the clip.
+    fn brightness(frames: &[u8], num_frames: usize) -> Vec<f64> {
+        frames
+            .chunks(frames.len() / num_frames)
+            .map(|frame| frame.iter().map(|&v| v as f64).sum::<f64>() / frame.len() as f64)
+            .collect()
+    }
+
+    #[test]
+    pub fn video_folder_dataset_decode_clips() {
+        let path = encode_video("decode", 20);
+        let sampling = ClipSampling::new(3)
+            .with_frame_stride(2)
+            .with_clip_stride(4);
+        let dataset = VideoFolderDataset::new_classification_with_items(
+            vec![(&path, "class".to_string())],
+            &["class"],
+            sampling,
+        )
+        .unwrap();
+
+        // Clips start at frames 0, 4, 8 and 12.
+        assert_eq!(dataset.len(), 4);
+
+        // The whole video, decoded from the start.
+        let indices = (0..20).collect::<Vec<_>>();
+        let (all, ..) = decode_clip(&path, &indices, None, false).unwrap().unwrap();
+        let all = brightness(&all, 20);
+        assert!(all.windows(2).all(|pair| pair[1] > pair[0] + 5.0));
+
+        for clip in 0..dataset.len() {
+            let item = dataset.get(clip).unwrap();
+            assert_eq!((item.num_frames, item.height, item.width), (3, 16, 16));
+
+            // Clips that don't start on a keyframe are decoded after seeking.
+            let expected = sampling
+                .frame_indices(item.start_frame)
+                .into_iter()
+                .map(|index| all[index])
+                .collect::<Vec<_>>();
+            assert_eq!(brightness(&item.frames, 3), expected);
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    pub fn video_folder_dataset_resize_frames() {
+        let path = encode_video("resize", 4);
+        let dataset = VideoFolderDataset::new_classification_with_items(
+            vec![(&path, "class".to_string())],
+            &["class"],
+            ClipSampling::new(2),
+        )
+        .unwrap()
+        .with_frame_size(8, 4);
+
+        let item = dataset.get(1).unwrap();
+        assert_eq!((item.num_frames, item.height, item.width), (2, 4, 8));
+        assert_eq!(item.frames.len(), 2 * 4 * 8 * 3);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    pub fn video_folder_dataset_invalid_extension() {
+        let result = VideoFolderDataset::new_classification_with_items(
+            vec![("video.gif", "class".to_string())],
+            &["class"],
+            ClipSampling::new(2),
+        );
+
+        assert!(matches!(
+            result,
+            Err(VideoLoaderError::InvalidFileExtensionError(_))
+        ));
+    }
+}
@@ -13,7 +13,8 @@ use alloc::vec;
 use burn_backend::quantization::QuantScheme;
 use burn_backend::tensor::{Device, QuantizedTensor};
 use burn_backend::{
-    AutodiffBackend, Backend, BackendTypes, DType, ExecutionError, QTensorPrimitive,
+    AutodiffBackend, Backend, BackendTypes, DType, ExecutionError, MemoryQuota, MemoryQuotaError,
+    QTensorPrimitive,
 };
 
 #[cfg(feature = "autodiff")]
@@ -91,6 +92,10 @@ impl Backend for Dispatch {
         dispatch_device!(device, |device| B::dtype_usage(device, dtype))
     }
 
+    fn device_properties(device: &Self::Device) -> burn_backend::DeviceProperties {
+        dispatch_device!(device, |device| B::device_properties(device))
+    }
+
     fn ad_enabled(device: &Self::Device) -> bool {
         match device {
             #[cfg(feature = "autodiff")]
@@ -138,6 +143,14 @@ impl Backend for Dispatch {
         ))
     }
 
+    fn memory_quota<Output, Func: FnOnce() -> Output>(
+        device: &Self::Device,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        dispatch_device!(device, |device| B::memory_quota(device, quota, func))
+    }
+
     fn memory_cleanup(device: &Self::Device) {
         dispatch_device!(device, |device| B::memory_cleanup(device))
     }
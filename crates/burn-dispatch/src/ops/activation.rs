@@ -48,6 +48,10 @@ impl ActivationOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::log_softmax(tensor, dim) => Float)
     }
 
+    fn logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::logsumexp(tensor, dim) => Float)
+    }
+
     fn softmin(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
         unary_float!(tensor, float, |tensor| B::softmin(tensor, dim) => Float)
     }
@@ -143,6 +143,10 @@ impl BoolTensorOps<Self> for Dispatch {
         unary_op!(tensor, bool, |tensor| B::bool_flip(tensor, axes) => Bool)
     }
 
+    fn bool_roll(tensor: BoolTensor<Self>, shifts: &[usize], dims: &[usize]) -> BoolTensor<Self> {
+        unary_op!(tensor, bool, |tensor| B::bool_roll(tensor, shifts, dims) => Bool)
+    }
+
     fn bool_expand(tensor: BoolTensor<Self>, shape: Shape) -> BoolTensor<Self> {
         unary_op!(tensor, bool, |tensor| B::bool_expand(tensor, shape) => Bool)
     }
@@ -311,6 +311,10 @@ impl IntTensorOps<Self> for Dispatch {
         unary_op!(tensor, int, |tensor| B::int_flip(tensor, axes) => Int)
     }
 
+    fn int_roll(tensor: IntTensor<Self>, shifts: &[usize], dims: &[usize]) -> IntTensor<Self> {
+        unary_op!(tensor, int, |tensor| B::int_roll(tensor, shifts, dims) => Int)
+    }
+
     fn int_random(
         shape: Shape,
         distribution: burn_backend::Distribution,
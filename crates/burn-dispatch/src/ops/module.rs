@@ -208,6 +208,59 @@ impl ModuleOps<Self> for Dispatch {
         MaxPool2dBackward::new(x_grad)
     }
 
+    fn adaptive_max_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::adaptive_max_pool2d(x, output_size)
+        )
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let (out, indices) = multi_op!(
+            inputs[(x, float)],
+            outputs[(out, Float), (indices, Int)],
+            {
+                let res = B::adaptive_max_pool2d_with_indices(x, output_size);
+                (res.output, res.indices)
+            }
+        );
+        MaxPool2dWithIndices::new(out, indices)
+    }
+
+    fn lp_pool2d(
+        x: FloatTensor<Self>,
+        norm_type: f64,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::lp_pool2d(x, norm_type, kernel_size, stride, ceil_mode)
+        )
+    }
+
+    fn pixel_shuffle(x: FloatTensor<Self>, upscale_factor: usize) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::pixel_shuffle(x, upscale_factor)
+        )
+    }
+
+    fn pixel_unshuffle(x: FloatTensor<Self>, downscale_factor: usize) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::pixel_unshuffle(x, downscale_factor)
+        )
+    }
+
     fn interpolate(
         x: FloatTensor<Self>,
         output_size: [usize; 2],
@@ -130,6 +130,14 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_flip(tensor, axes) => Float)
     }
 
+    fn float_roll(
+        tensor: FloatTensor<Self>,
+        shifts: &[usize],
+        dims: &[usize],
+    ) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_roll(tensor, shifts, dims) => Float)
+    }
+
     fn float_reshape(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
         unary_float!(tensor, float, |tensor| B::float_reshape(tensor, shape) => Float)
     }
@@ -216,17 +216,15 @@ impl Layout {
         }
     }
 
-    /// Apply slices to create a new layout.
+    /// Apply slices to create a new layout (zero-copy, metadata only).
     ///
-    /// Returns `(new_layout, needs_copy)`:
-    /// - `needs_copy = false`: Can use zero-copy view with new layout
-    /// - `needs_copy = true`: Has negative steps requiring data reordering
-    pub fn slice(&self, slices: &[Slice]) -> (Self, bool) {
+    /// Negative steps iterate backward from the last element of the range, with a negative
+    /// stride.
+    pub fn slice(&self, slices: &[Slice]) -> Self {
         let ndims = self.num_dims();
         let mut new_dims = self.shape.to_vec();
         let mut new_strides = self.strides.clone();
         let mut new_offset = self.start_offset as isize;
-        let mut needs_copy = false;
 
         for (dim, slice) in slices.iter().enumerate() {
             if dim >= ndims {
@@ -253,42 +251,30 @@ impl Layout {
             };
 
             let step = slice.step;
-            let abs_step = step.unsigned_abs();
+            let len = if end > start {
+                (end - start).div_ceil(step.unsigned_abs())
+            } else {
+                0
+            };
+            new_dims[dim] = len;
+            new_strides[dim] = stride * step;
 
             if step > 0 {
-                // Positive step: forward iteration
-                let len = if end > start {
-                    (end - start).div_ceil(abs_step)
-                } else {
-                    0
-                };
-                new_dims[dim] = len;
-                new_strides[dim] = stride * step;
+                // Positive step: forward iteration from the start of the range
                 new_offset += stride * start as isize;
-            } else {
-                // Negative step: select range then iterate in reverse
-                // Requires copy to reorder elements
-                needs_copy = true;
-                let len = if end > start {
-                    (end - start).div_ceil(abs_step)
-                } else {
-                    0
-                };
-                new_dims[dim] = len;
-                new_strides[dim] = stride; // Will be handled during copy
+            } else if len > 0 {
+                // Negative step: backward iteration from the last element of the range
+                new_offset += stride * (end - 1) as isize;
             }
         }
 
         debug_assert!(new_offset >= 0, "slice: negative offset");
 
-        (
-            Self {
-                shape: Shape::from(new_dims),
-                strides: new_strides,
-                start_offset: new_offset as usize,
-            },
-            needs_copy,
-        )
+        Self {
+            shape: Shape::from(new_dims),
+            strides: new_strides,
+            start_offset: new_offset as usize,
+        }
     }
 
     /// Reshape to a new shape. Only works if contiguous with zero offset.
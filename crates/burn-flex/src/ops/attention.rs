@@ -298,6 +298,9 @@ where
     } else {
         None
     };
+    let sliding_window = options
+        .sliding_window
+        .map(|window| (seq_kv as isize - seq_q as isize, window as isize));
 
     let q_data: &[T] = query.storage();
     let k_data: &[T] = key.storage();
@@ -330,6 +333,7 @@ where
         scale,
         softcap,
         causal_offset,
+        sliding_window,
         seq_q,
         seq_kv,
         head_dim,
@@ -451,6 +455,8 @@ struct AttentionParams<T> {
     scale: T,
     softcap: Option<T>,
     causal_offset: Option<isize>,
+    /// Offset of the diagonal and size of the sliding window, if any.
+    sliding_window: Option<(isize, isize)>,
     seq_q: usize,
     seq_kv: usize,
     head_dim: usize,
@@ -486,6 +492,7 @@ fn flash_attention_head<T: FlashGemm>(
         scale,
         softcap,
         causal_offset,
+        sliding_window,
         seq_q,
         seq_kv,
         head_dim,
@@ -506,6 +513,15 @@ fn flash_attention_head<T: FlashGemm>(
         let kv_end = (kv_start + TILE_KV).min(seq_kv);
         let tile_kv = kv_end - kv_start;
 
+        // Tiles outside the sliding window of every query contribute nothing, so the cost is
+        // proportional to the window instead of the sequence length.
+        if let Some((offset, window)) = sliding_window
+            && ((kv_end as isize) <= offset - window + 1
+                || (kv_start as isize) >= seq_q as isize - 1 + offset + window)
+        {
+            continue;
+        }
+
         // Step 1: Score matmul via gemm
         // scores[seq_q, tile_kv] = Q[seq_q, head_dim] @ K_tile[tile_kv, head_dim]^T
         //
@@ -559,6 +575,12 @@ fn flash_attention_head<T: FlashGemm>(
                     val = neg_inf;
                 }
 
+                if let Some((offset, window)) = sliding_window
+                    && (kv_idx as isize - (qi as isize + offset)).abs() >= window
+                {
+                    val = neg_inf;
+                }
+
                 if let Some(b) = bias {
                     val += b[qi * seq_kv + kv_idx];
                 }
@@ -735,6 +757,9 @@ where
     } else {
         None
     };
+    let sliding_window = options
+        .sliding_window
+        .map(|window| (seq_kv as isize - seq_q as isize, window as isize));
 
     let q_data: &[T] = query.storage();
     let k_data: &[T] = key.storage();
@@ -767,6 +792,7 @@ where
         scale,
         softcap,
         causal_offset,
+        sliding_window,
         seq_q,
         seq_kv,
         head_dim,
@@ -825,6 +851,7 @@ fn naive_attention_head<T: FlashGemm>(
         scale,
         softcap,
         causal_offset,
+        sliding_window,
         seq_q,
         seq_kv,
         head_dim,
@@ -875,6 +902,12 @@ fn naive_attention_head<T: FlashGemm>(
                 val = neg_inf;
             }
 
+            if let Some((offset, window)) = sliding_window
+                && (ki as isize - (qi as isize + offset)).abs() >= window
+            {
+                val = neg_inf;
+            }
+
             if let Some(b) = bias {
                 val += b[qi * seq_kv + ki];
             }
@@ -1389,6 +1422,16 @@ mod tests {
             scale: Some(0.05),
             softcap: Some(30.0),
             is_causal: true,
+            sliding_window: None,
+        };
+        let sliding_window = AttentionModuleOptions {
+            is_causal: true,
+            sliding_window: Some(24),
+            ..Default::default()
+        };
+        let band = AttentionModuleOptions {
+            sliding_window: Some(40),
+            ..Default::default()
         };
 
         run_both(1, 1, 4, 4, 8, 8, false, false, default, "basic_4x4");
@@ -1436,6 +1479,58 @@ mod tests {
             causal,
             "partial_tile_causal",
         );
+        run_both(
+            1,
+            2,
+            16,
+            256,
+            16,
+            16,
+            false,
+            false,
+            sliding_window,
+            "sliding_window",
+        );
+        run_both(1, 2, 64, 256, 16, 16, true, false, band, "band_with_mask");
+    }
+
+    #[test]
+    fn test_sliding_window_skips_tiles() {
+        // Long enough for the flash path, where only the last tile is inside the window.
+        let seq_q = 4;
+        let seq_kv = 1024;
+        let head_dim = 2;
+        let window = 16;
+
+        // All queries/keys [1, 0] so all visible scores equal.
+        let mut q_data = vec![0.0f32; seq_q * head_dim];
+        for i in 0..seq_q {
+            q_data[i * head_dim] = 1.0;
+        }
+        let mut k_data = vec![0.0f32; seq_kv * head_dim];
+        for i in 0..seq_kv {
+            k_data[i * head_dim] = 1.0;
+        }
+        let v_data: Vec<f32> = (0..seq_kv).map(|i| i as f32).collect();
+
+        let q = flex_f32(q_data, &[1, 1, seq_q, head_dim]);
+        let k = flex_f32(k_data, &[1, 1, seq_kv, head_dim]);
+        let v = flex_f32(v_data, &[1, 1, seq_kv, 1]);
+
+        let opts = AttentionModuleOptions {
+            is_causal: true,
+            sliding_window: Some(window),
+            ..Default::default()
+        };
+        let result = super::attention(q, k, v, None, None, opts);
+        let data: &[f32] = result.storage();
+
+        // q[i] sees the 16 keys ending at 1020 + i -> mean 1012.5 + i.
+        assert_eq!(data.len(), seq_q);
+        for (i, &out) in data.iter().enumerate() {
+            let expected = 1012.5 + i as f32;
+            assert!((out - expected).abs() < 0.1, "q{i}: got {out}");
+        }
     }
 
     #[test]
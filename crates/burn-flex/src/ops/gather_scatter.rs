@@ -881,7 +881,14 @@ fn compute_strides(dims: &[usize]) -> Vec<usize> {
 
 /// Multi-dimensional scatter: update `data` at locations specified by N-dimensional index tuples.
 pub fn scatter_nd<
-    E: Element + Pod + Default + Copy + core::ops::AddAssign + core::ops::Mul<Output = E> + PartialOrd,
+    E: Element
+        + Pod
+        + Default
+        + Copy
+        + core::ops::AddAssign
+        + core::ops::Mul<Output = E>
+        + core::ops::Div<Output = E>
+        + PartialOrd,
 >(
     data: FlexTensor,
     indices: FlexTensor,
@@ -910,6 +917,9 @@ pub fn scatter_nd<
 
     let strides = compute_strides(&data_shape);
 
+    // The number of averaged terms of each position, including the existing value.
+    let mut counts = (reduction == IndexingUpdateOp::Mean).then(|| vec![1usize; result.len()]);
+
     for n in 0..num_indices {
         let mut base_offset = 0usize;
         for j in 0..k {
@@ -949,6 +959,21 @@ pub fn scatter_nd<
                     }
                 }
             }
+            IndexingUpdateOp::Mean => {
+                let counts = counts.as_mut().unwrap();
+                for s in 0..slice_size {
+                    result[base_offset + s] += val_data[val_offset + s];
+                    counts[base_offset + s] += 1;
+                }
+            }
+        }
+    }
+
+    if let Some(counts) = counts {
+        for (value, count) in result.iter_mut().zip(counts) {
+            if count > 1 {
+                *value = *value / E::from_elem(count as i64);
+            }
         }
     }
 
@@ -10,6 +10,7 @@ use burn_backend::{
         AttentionModuleOptions, ConvOptions, ConvTransposeOptions, DeformConv2dBackward,
         DeformConvOptions, FloatTensorOps, IntTensorOps, InterpolateMode, InterpolateOptions,
         MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+        interpolate::{interpolate_antialias, interpolate_antialias_backward},
     },
     tensor::{BoolTensor, FloatTensor, IntTensor},
 };
@@ -519,7 +520,12 @@ impl ModuleOps<Flex> for Flex {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Flex> {
+        if options.antialias {
+            return interpolate_antialias::<Flex>(x, output_size, &options);
+        }
+
         match (options.mode, x.dtype()) {
+            (InterpolateMode::Area, _) => Self::adaptive_avg_pool2d(x, output_size),
             (InterpolateMode::Nearest, DType::F32) => {
                 interpolate::interpolate_nearest_f32(x, output_size, options.align_corners)
             }
@@ -581,7 +587,12 @@ impl ModuleOps<Flex> for Flex {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Flex> {
+        if options.antialias {
+            return interpolate_antialias_backward::<Flex>(x, grad, &options);
+        }
+
         match (options.mode, x.dtype()) {
+            (InterpolateMode::Area, _) => Self::adaptive_avg_pool2d_backward(x, grad),
             (InterpolateMode::Nearest, DType::F32) => {
                 interpolate::interpolate_nearest_backward_f32(
                     x,
@@ -3,140 +3,17 @@
 use alloc::vec;
 use alloc::vec::Vec;
 use burn_backend::{DType, Element};
-use burn_std::{Bytes, Shape, Slice, bf16, f16};
+use burn_std::{Slice, bf16, f16};
 
-use crate::{FlexTensor, Layout};
+use crate::FlexTensor;
 
 /// Slice a tensor according to the given slice parameters.
 ///
-/// For positive steps, this is zero-copy (metadata only).
-/// For negative steps, data is copied to handle the reversal.
+/// This is zero-copy (metadata only) for any step: negative steps are strided views with
+/// negative strides, like [flip](crate::Layout::flip).
 pub fn slice(tensor: FlexTensor, slices: &[Slice]) -> FlexTensor {
-    let (new_layout, needs_copy) = tensor.layout().slice(slices);
-
-    if !needs_copy {
-        // Zero-copy: share data with new layout
-        FlexTensor::from_arc(tensor.data_arc(), new_layout, tensor.dtype())
-    } else {
-        // Needs copy due to negative steps
-        slice_with_copy(&tensor, slices)
-    }
-}
-
-/// Slice with data copy (handles negative steps).
-fn slice_with_copy(tensor: &FlexTensor, slices: &[Slice]) -> FlexTensor {
-    match tensor.dtype() {
-        DType::F32 => slice_copy_impl::<f32>(tensor, slices),
-        DType::F64 => slice_copy_impl::<f64>(tensor, slices),
-        DType::F16 => slice_copy_impl::<f16>(tensor, slices),
-        DType::BF16 => slice_copy_impl::<bf16>(tensor, slices),
-        DType::I32 => slice_copy_impl::<i32>(tensor, slices),
-        DType::I64 => slice_copy_impl::<i64>(tensor, slices),
-        DType::I16 => slice_copy_impl::<i16>(tensor, slices),
-        DType::I8 => slice_copy_impl::<i8>(tensor, slices),
-        DType::U32 => slice_copy_impl::<u32>(tensor, slices),
-        DType::U64 => slice_copy_impl::<u64>(tensor, slices),
-        DType::U16 => slice_copy_impl::<u16>(tensor, slices),
-        DType::U8 => slice_copy_impl::<u8>(tensor, slices),
-        DType::Bool(_) => slice_copy_impl::<u8>(tensor, slices),
-        _ => panic!("slice: unsupported dtype {:?}", tensor.dtype()),
-    }
-}
-
-/// Generic slice implementation with copy.
-fn slice_copy_impl<E: Element + bytemuck::Pod + Default>(
-    tensor: &FlexTensor,
-    slices: &[Slice],
-) -> FlexTensor {
-    let src = tensor.storage::<E>();
-    let src_layout = tensor.layout();
-    let ndims = src_layout.num_dims();
-
-    // Calculate output shape and collect normalized slice info
-    let mut out_shape = Vec::with_capacity(ndims);
-    let mut slice_info: Vec<(usize, usize, isize)> = Vec::with_capacity(ndims); // (start, len, step)
-
-    for dim in 0..ndims {
-        let dim_size = src_layout.shape()[dim] as isize;
-
-        let slice = if dim < slices.len() {
-            &slices[dim]
-        } else {
-            // Default: full range
-            &Slice::new(0, None, 1)
-        };
-
-        let (start, len, step) = compute_slice_info(slice, dim_size);
-        out_shape.push(len);
-        slice_info.push((start, len, step));
-    }
-
-    let out_layout = Layout::contiguous(Shape::from(out_shape.clone()));
-    let num_elements = out_layout.num_elements();
-
-    if num_elements == 0 {
-        let bytes = Bytes::from_elems::<E>(Vec::new());
-        return FlexTensor::new(bytes, out_layout, tensor.dtype());
-    }
-
-    // Allocate output
-    let mut out_data: Vec<E> = Vec::with_capacity(num_elements);
-
-    // Use recursive iteration for arbitrary dimensions
-    let mut indices = vec![0usize; ndims];
-    copy_slice_recursive(src, src_layout, &slice_info, &mut out_data, &mut indices, 0);
-
-    let bytes = Bytes::from_elems(out_data);
-    FlexTensor::new(bytes, out_layout, tensor.dtype())
-}
-
-/// Recursively copy sliced elements.
-fn copy_slice_recursive<E: Copy>(
-    src: &[E],
-    src_layout: &Layout,
-    slice_info: &[(usize, usize, isize)],
-    out: &mut Vec<E>,
-    indices: &mut [usize],
-    dim: usize,
-) {
-    let ndims = src_layout.num_dims();
-
-    if dim == ndims {
-        // Base case: copy single element
-        let src_idx = compute_src_index(src_layout, slice_info, indices);
-        out.push(src[src_idx]);
-        return;
-    }
-
-    let (_, len, _) = slice_info[dim];
-
-    for i in 0..len {
-        indices[dim] = i;
-        copy_slice_recursive(src, src_layout, slice_info, out, indices, dim + 1);
-    }
-}
-
-/// Compute source index from output indices and slice info.
-fn compute_src_index(
-    layout: &Layout,
-    slice_info: &[(usize, usize, isize)],
-    out_indices: &[usize],
-) -> usize {
-    let mut idx = layout.start_offset() as isize;
-    for (dim, &out_i) in out_indices.iter().enumerate() {
-        let (start, _, step) = slice_info[dim];
-        let src_i = if step > 0 {
-            start + out_i * step as usize
-        } else {
-            // Negative step: start from high index, go down
-            let result = start as isize - (out_i as isize) * (-step);
-            debug_assert!(result >= 0, "slice: negative source index at dim {dim}");
-            result as usize
-        };
-        idx += src_i as isize * layout.strides()[dim];
-    }
-    debug_assert!(idx >= 0, "slice: negative final index");
-    idx as usize
+    let new_layout = tensor.layout().slice(slices);
+    FlexTensor::from_arc(tensor.data_arc(), new_layout, tensor.dtype())
 }
 
 /// Normalize a potentially negative index to a positive one.
@@ -471,6 +348,23 @@ mod tests {
         assert_eq!(values, vec![4.0, 3.0, 2.0, 1.0, 0.0]);
     }
 
+    #[test]
+    fn test_slice_negative_step_is_view() {
+        // Create a 2x5 tensor: [[0, 1, 2, 3, 4], [5, 6, 7, 8, 9]]
+        let data: Vec<f32> = (0..10).map(|i| i as f32).collect();
+        let tensor = FlexTensor::from_data(TensorData::new(data, [2, 5]));
+
+        // Slice [.., 0..4;-2] -> [[3, 1], [8, 6]] without copying
+        let slices = vec![Slice::new(0, None, 1), Slice::new(0, Some(4), -2)];
+        let result = slice(tensor.clone(), &slices);
+
+        assert!(!tensor.is_unique(), "the slice should share the data");
+        assert_eq!(result.layout().strides(), &[5, -2]);
+        let result_data = result.into_data();
+        let values: Vec<f32> = bytemuck::cast_slice(&result_data.bytes).to_vec();
+        assert_eq!(values, vec![3.0, 1.0, 8.0, 6.0]);
+    }
+
     #[test]
     fn test_slice_assign_1d() {
         // Create a 1D tensor: [0, 1, 2, 3, 4]
@@ -4,7 +4,7 @@ use crate::{
     stream::{Context, OrderedExecution},
 };
 use burn_backend::{
-    Backend, BackendTypes, DType, DeviceOps, ExecutionError,
+    Backend, BackendTypes, DType, DeviceOps, ExecutionError, MemoryQuota, MemoryQuotaError,
     tensor::{BoolTensor, Device, FloatTensor, IntTensor, QuantizedTensor},
 };
 use burn_ir::{BackendIr, OperationIr, TensorHandle};
@@ -73,6 +73,19 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::memory_persistent_allocations(device, input, func)
     }
 
+    fn memory_quota<Output, Func: FnOnce() -> Output>(
+        device: &Self::Device,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        B::memory_quota(device, quota, || {
+            let output = func();
+            // Execute the queued operations, so that their memory is allocated in the scope.
+            GlobalFusionClient::<B::FusionRuntime>::load(device).sync(|| ());
+            output
+        })
+    }
+
     fn memory_cleanup(device: &Self::Device) {
         B::memory_cleanup(device)
     }
@@ -95,6 +108,10 @@ impl<B: FusionBackend> Backend for Fusion<B> {
     fn device_count(type_id: u16) -> usize {
         B::device_count(type_id)
     }
+
+    fn device_properties(device: &Self::Device) -> burn_backend::DeviceProperties {
+        B::device_properties(device)
+    }
 }
 
 /// The status of a [fuser](OperationFuser).
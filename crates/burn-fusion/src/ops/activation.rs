@@ -1,4 +1,26 @@
-use crate::{Fusion, FusionBackend};
-use burn_backend::ops::ActivationOps;
+use crate::{
+    Fusion, FusionBackend, reduce_float_ops,
+    stream::{StreamId, execution::Operation},
+};
+use burn_backend::{ops::ActivationOps, tensor::FloatTensor};
+use burn_ir::*;
+use std::marker::PhantomData;
 
-impl<B: FusionBackend> ActivationOps<Self> for Fusion<B> {}
+impl<B: FusionBackend> ActivationOps<Self> for Fusion<B> {
+    fn logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(LogSumExpOps, |tensor, axis, _| B::logsumexp(tensor, axis));
+
+        let streams = StreamId::current();
+
+        let client = tensor.client.clone();
+        let desc = ReduceDimOpIr::create(tensor.into_ir(), dim, 1, || client.create_empty_handle());
+
+        client
+            .register(
+                streams,
+                OperationIr::Float(desc.out.dtype, FloatOperationIr::LogSumExp(desc.clone())),
+                LogSumExpOps::<B>::new(desc),
+            )
+            .output()
+    }
+}
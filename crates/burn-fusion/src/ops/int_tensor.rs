@@ -1816,6 +1816,37 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
             .output()
     }
 
+    fn int_roll(tensor: IntTensor<Self>, shifts: &[usize], dims: &[usize]) -> IntTensor<Self> {
+        #[derive(new, Debug)]
+        struct RollOps<B: FusionBackend> {
+            desc: RollOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for RollOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_int_tensor::<B>(&self.desc.input);
+                let output = B::int_roll(input, &self.desc.shifts, &self.desc.dims);
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = StreamId::current();
+
+        let client = tensor.client.clone();
+        let desc = RollOpIr::create(tensor.into_ir(), shifts.into(), dims.into(), || {
+            client.create_empty_handle()
+        });
+
+        client
+            .register(
+                streams,
+                OperationIr::BaseInt(BaseOperationIr::Roll(desc.clone())),
+                RollOps::<B>::new(desc),
+            )
+            .output()
+    }
+
     fn int_repeat_dim(tensor: IntTensor<Self>, dim: usize, times: usize) -> IntTensor<Self> {
         #[derive(new, Debug)]
         struct RepeatDimOps<B: FusionBackend> {
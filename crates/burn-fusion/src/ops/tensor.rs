@@ -2340,6 +2340,41 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
             .output()
     }
 
+    fn float_roll(
+        tensor: FloatTensor<Self>,
+        shifts: &[usize],
+        dims: &[usize],
+    ) -> FloatTensor<Self> {
+        #[derive(new, Debug)]
+        struct RollOps<B: FusionBackend> {
+            desc: RollOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for RollOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = B::float_roll(input, &self.desc.shifts, &self.desc.dims);
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = StreamId::current();
+
+        let client = tensor.client.clone();
+        let desc = RollOpIr::create(tensor.into_ir(), shifts.into(), dims.into(), || {
+            client.create_empty_handle()
+        });
+
+        client
+            .register(
+                streams,
+                OperationIr::BaseFloat(BaseOperationIr::Roll(desc.clone())),
+                RollOps::<B>::new(desc),
+            )
+            .output()
+    }
+
     fn float_round(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         unary_float_ops!(RoundOps, B::float_round);
 
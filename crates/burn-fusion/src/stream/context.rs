@@ -728,6 +728,12 @@ impl RelativeOps for FloatOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationIr::LogSumExp(desc) => FloatOperationIr::LogSumExp(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+                axis: desc.axis,
+                accumulator_len: desc.accumulator_len,
+            }),
         }
     }
 }
@@ -1150,6 +1156,12 @@ impl RelativeOps for BaseOperationIr {
                 out: desc.out.to_relative(converter),
                 axes: desc.axes.clone(),
             }),
+            BaseOperationIr::Roll(desc) => BaseOperationIr::Roll(RollOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+                shifts: desc.shifts.clone(),
+                dims: desc.dims.clone(),
+            }),
             BaseOperationIr::Slice(desc) => BaseOperationIr::Slice(SliceOpIr {
                 tensor: desc.tensor.to_relative(converter),
                 ranges: desc.ranges.iter().map(|_info| Slice::from(0..1)).collect(),
@@ -268,6 +268,16 @@ impl_ir_create!(
     dtype = input.dtype
 );
 
+impl_ir_create!(
+    RollOpIr {
+        input: TensorIr,
+        shifts: Vec<usize>,
+        dims: Vec<usize>
+    },
+    shape = input.shape.clone(),
+    dtype = input.dtype
+);
+
 impl_ir_create!(
     CatOpIr { tensors: Vec<TensorIr>, dim: usize },
     shape = Shape::cat(tensors.iter().map(|t| &t.shape), dim).unwrap(),
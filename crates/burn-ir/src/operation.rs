@@ -166,6 +166,8 @@ pub enum FloatOperationIr {
     GridSample2d(GridSample2dOpIr),
     /// Operation corresponding to [powf](burn_backend::ops::FloatTensorOps::float_powi).
     Powf(BinaryOpIr),
+    /// Operation corresponding to [logsumexp](burn_backend::ops::ActivationOps::logsumexp).
+    LogSumExp(ReduceDimOpIr),
 }
 
 /// Operation intermediate representation specific to module.
@@ -304,6 +306,12 @@ pub enum BaseOperationIr {
     /// Bool => [flip](burn_backend::ops::BoolTensorOps::bool_flip).
     Flip(FlipOpIr),
 
+    /// Operation corresponding to:
+    /// Float => [roll](burn_backend::ops::FloatTensorOps::float_roll).
+    /// Int => [roll](burn_backend::ops::IntTensorOps::int_roll).
+    /// Bool => [roll](burn_backend::ops::BoolTensorOps::bool_roll).
+    Roll(RollOpIr),
+
     /// Operation corresponding to:
     ///
     /// Float => [expand](burn_backend::ops::FloatTensorOps::float_expand).
@@ -784,6 +792,19 @@ pub struct FlipOpIr {
     pub axes: Vec<usize>,
 }
 
+/// Roll operation intermediate representation.
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+pub struct RollOpIr {
+    /// Input tensor intermediate representation.
+    pub input: TensorIr,
+    /// Output tensor intermediate representation.
+    pub out: TensorIr,
+    /// The shift of each rolled dimension.
+    pub shifts: Vec<usize>,
+    /// The dimensions to roll.
+    pub dims: Vec<usize>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct RandomOpIr {
@@ -1673,6 +1694,7 @@ pub enum InterpolateModeIr {
     Bilinear,
     Bicubic,
     Lanczos3,
+    Area,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -1680,6 +1702,7 @@ pub enum InterpolateModeIr {
 pub struct InterpolateOptionsIr {
     pub mode: InterpolateModeIr,
     pub align_corners: bool,
+    pub antialias: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -1774,6 +1797,7 @@ pub struct AttentionOptionsIr {
     pub scale: Option<ScalarIr>,
     pub softcap: Option<ScalarIr>,
     pub is_causal: bool,
+    pub sliding_window: Option<usize>,
 }
 
 impl From<AttentionOptionsIr> for AttentionModuleOptions {
@@ -1782,6 +1806,7 @@ impl From<AttentionOptionsIr> for AttentionModuleOptions {
             scale: ir.scale.map(|s| s.elem()),
             softcap: ir.softcap.map(|s| s.elem()),
             is_causal: ir.is_causal,
+            sliding_window: ir.sliding_window,
         }
     }
 }
@@ -1792,6 +1817,7 @@ impl From<AttentionModuleOptions> for AttentionOptionsIr {
             scale: ir.scale.map(ScalarIr::Float),
             softcap: ir.softcap.map(ScalarIr::Float),
             is_causal: ir.is_causal,
+            sliding_window: ir.sliding_window,
         }
     }
 }
@@ -1839,13 +1865,16 @@ impl From<InterpolateModeIr> for InterpolateMode {
             InterpolateModeIr::Bilinear => Self::Bilinear,
             InterpolateModeIr::Bicubic => Self::Bicubic,
             InterpolateModeIr::Lanczos3 => Self::Lanczos3,
+            InterpolateModeIr::Area => Self::Area,
         }
     }
 }
 
 impl From<InterpolateOptionsIr> for InterpolateOptions {
     fn from(val: InterpolateOptionsIr) -> Self {
-        Self::new(val.mode.into()).with_align_corners(val.align_corners)
+        Self::new(val.mode.into())
+            .with_align_corners(val.align_corners)
+            .with_antialias(val.antialias)
     }
 }
 
@@ -1857,6 +1886,7 @@ impl From<InterpolateMode> for InterpolateModeIr {
             InterpolateMode::Bilinear => Self::Bilinear,
             InterpolateMode::Bicubic => Self::Bicubic,
             InterpolateMode::Lanczos3 => Self::Lanczos3,
+            InterpolateMode::Area => Self::Area,
         }
     }
 }
@@ -1866,6 +1896,7 @@ impl From<InterpolateOptions> for InterpolateOptionsIr {
         Self {
             mode: val.mode.into(),
             align_corners: val.align_corners,
+            antialias: val.antialias,
         }
     }
 }
@@ -2035,6 +2066,7 @@ impl BaseOperationIr {
             BaseOperationIr::Permute(repr) => Box::new([&repr.input].into_iter()),
             BaseOperationIr::Expand(repr) => Box::new([&repr.input].into_iter()),
             BaseOperationIr::Flip(repr) => Box::new([&repr.input].into_iter()),
+            BaseOperationIr::Roll(repr) => Box::new([&repr.input].into_iter()),
             BaseOperationIr::Slice(repr) => Box::new([&repr.tensor].into_iter()),
             BaseOperationIr::SliceAssign(repr) => Box::new([&repr.tensor, &repr.value].into_iter()),
             BaseOperationIr::Gather(repr) => Box::new([&repr.tensor, &repr.indices].into_iter()),
@@ -2072,6 +2104,7 @@ impl BaseOperationIr {
             BaseOperationIr::Permute(repr) => Box::new([&repr.out].into_iter()),
             BaseOperationIr::Expand(repr) => Box::new([&repr.out].into_iter()),
             BaseOperationIr::Flip(repr) => Box::new([&repr.out].into_iter()),
+            BaseOperationIr::Roll(repr) => Box::new([&repr.out].into_iter()),
             BaseOperationIr::Slice(repr) => Box::new([&repr.out].into_iter()),
             BaseOperationIr::SliceAssign(repr) => Box::new([&repr.out].into_iter()),
             BaseOperationIr::Gather(repr) => Box::new([&repr.out].into_iter()),
@@ -2115,6 +2148,9 @@ impl BaseOperationIr {
             BaseOperationIr::Flip(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
             }
+            BaseOperationIr::Roll(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
             BaseOperationIr::Slice(repr) => {
                 repr.tensor.mark_read_only(nodes, &mut output);
             }
@@ -2478,6 +2514,7 @@ impl FloatOperationIr {
             FloatOperationIr::GridSample2d(repr) => {
                 Box::new([&repr.tensor, &repr.grid].into_iter())
             }
+            FloatOperationIr::LogSumExp(repr) => Box::new([&repr.input].into_iter()),
             FloatOperationIr::Tan(repr) => Box::new([&repr.input].into_iter()),
             FloatOperationIr::Cosh(repr) => Box::new([&repr.input].into_iter()),
             FloatOperationIr::Sinh(repr) => Box::new([&repr.input].into_iter()),
@@ -2516,6 +2553,7 @@ impl FloatOperationIr {
             FloatOperationIr::IsNan(repr) => Box::new([&repr.out].into_iter()),
             FloatOperationIr::IsInf(repr) => Box::new([&repr.out].into_iter()),
             FloatOperationIr::GridSample2d(repr) => Box::new([&repr.out].into_iter()),
+            FloatOperationIr::LogSumExp(repr) => Box::new([&repr.out].into_iter()),
             FloatOperationIr::Tan(repr) => Box::new([&repr.out].into_iter()),
             FloatOperationIr::Cosh(repr) => Box::new([&repr.out].into_iter()),
             FloatOperationIr::Sinh(repr) => Box::new([&repr.out].into_iter()),
@@ -2605,6 +2643,9 @@ impl FloatOperationIr {
                 repr.tensor.mark_read_only(nodes, &mut output);
                 repr.grid.mark_read_only(nodes, &mut output);
             }
+            FloatOperationIr::LogSumExp(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
             FloatOperationIr::Tan(repr) => repr.input.mark_read_only(nodes, &mut output),
             FloatOperationIr::Cosh(repr) => repr.input.mark_read_only(nodes, &mut output),
             FloatOperationIr::Sinh(repr) => repr.input.mark_read_only(nodes, &mut output),
@@ -3356,6 +3397,9 @@ impl core::hash::Hash for RandomOpIr {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::Gamma(_, _) => 5u8.hash(state),
+            Distribution::Beta(_, _) => 6u8.hash(state),
+            Distribution::Poisson(_) => 7u8.hash(state),
         }
     }
 }
@@ -193,7 +193,7 @@ where
         reduction: burn_backend::tensor::IndexingUpdateOp,
     ) -> SharedArray<E>
     where
-        E: core::ops::Mul<Output = E> + PartialOrd,
+        E: core::ops::Mul<Output = E> + core::ops::Div<Output = E> + PartialOrd,
     {
         use burn_backend::tensor::IndexingUpdateOp;
 
@@ -233,6 +233,10 @@ where
             s
         };
 
+        // The number of averaged terms of each position, including the existing value.
+        let mut counts =
+            (reduction == IndexingUpdateOp::Mean).then(|| vec![1usize; output_flat.len()]);
+
         for n in 0..num_indices {
             // Compute flat base offset from the K-dimensional index
             let mut base_offset = 0usize;
@@ -275,6 +279,21 @@ where
                         }
                     }
                 }
+                IndexingUpdateOp::Mean => {
+                    let counts = counts.as_mut().unwrap();
+                    for s in 0..slice_size {
+                        output_flat[base_offset + s].add_assign(val_flat[val_offset + s]);
+                        counts[base_offset + s] += 1;
+                    }
+                }
+            }
+        }
+
+        if let Some(counts) = counts {
+            for (value, count) in output_flat.iter_mut().zip(counts) {
+                if count > 1 {
+                    *value = *value / (count as i64).elem::<E>();
+                }
             }
         }
 
@@ -27,7 +27,7 @@ pub(crate) fn grid_sample_2d<E: FloatNdArrayElement>(
     options: GridSampleOptions,
 ) -> SharedArray<E> {
     match options.mode {
-        InterpolateMode::Bilinear => (),
+        InterpolateMode::Bilinear | InterpolateMode::Nearest => (),
         _ => todo!(
             "grid_sample_2d with {:?} mode is not implemented",
             options.mode
@@ -82,9 +82,15 @@ pub(crate) fn grid_sample_2d<E: FloatNdArrayElement>(
                 (px, py)
             };
 
-            // Bilinear interpolation with the specified padding mode
-            let val =
-                bilinear_interpolate(&tensor, b, c, px, py, width_in, height_in, pad_mode, align);
+            // Interpolation with the specified padding mode
+            let val = match options.mode {
+                InterpolateMode::Nearest => {
+                    nearest_interpolate(&tensor, b, c, px, py, width_in, height_in, pad_mode, align)
+                }
+                _ => bilinear_interpolate(
+                    &tensor, b, c, px, py, width_in, height_in, pad_mode, align,
+                ),
+            };
 
             unsafe {
                 let output = unsafe_shared_out.get();
@@ -113,35 +119,8 @@ where
     E: FloatNdArrayElement,
     S: ndarray::Data<Elem = E>,
 {
-    // Handle inf/nan coordinates
-    if !x.is_finite() || !y.is_finite() {
-        return match padding_mode {
-            GridSamplePaddingMode::Zeros => 0.0,
-            GridSamplePaddingMode::Border => {
-                // Clamp to center of image for inf/nan
-                let cx = ((width - 1) as f64 / 2.0).clamp(0.0, (width - 1) as f64);
-                let cy = ((height - 1) as f64 / 2.0).clamp(0.0, (height - 1) as f64);
-                source[(b, c, cy as usize, cx as usize)].elem::<f64>()
-            }
-            GridSamplePaddingMode::Reflection => 0.0, // Simplified: treat as zeros for inf/nan
-        };
-    }
-
-    // Apply padding mode to get actual sampling coordinates
-    let (x, y) = match padding_mode {
-        GridSamplePaddingMode::Border => {
-            // Clamp coordinates to valid range [0, size-1]
-            let x = x.clamp(0.0, (width - 1) as f64);
-            let y = y.clamp(0.0, (height - 1) as f64);
-            (x, y)
-        }
-        GridSamplePaddingMode::Reflection => {
-            // Reflect coordinates at boundaries
-            let x = reflect_coordinate(x, width, align_corners);
-            let y = reflect_coordinate(y, height, align_corners);
-            (x, y)
-        }
-        GridSamplePaddingMode::Zeros => (x, y), // Keep as-is, handle out-of-bounds in read
+    let Some((x, y)) = pad_coordinates(x, y, width, height, padding_mode, align_corners) else {
+        return non_finite_value(source, b, c, width, height, padding_mode);
     };
 
     // Get the four corner indices
@@ -154,25 +133,8 @@ where
     let x_frac = x - x.floor();
     let y_frac = y - y.floor();
 
-    // Helper to read a value based on padding mode
-    let read_value = |xi: i64, yi: i64| -> f64 {
-        match padding_mode {
-            GridSamplePaddingMode::Zeros => {
-                // Return 0 for out-of-bounds
-                if xi >= 0 && xi < width as i64 && yi >= 0 && yi < height as i64 {
-                    source[(b, c, yi as usize, xi as usize)].elem::<f64>()
-                } else {
-                    0.0
-                }
-            }
-            GridSamplePaddingMode::Border | GridSamplePaddingMode::Reflection => {
-                // Coordinates should already be in valid range after clamping/reflection
-                let xi = xi.clamp(0, (width - 1) as i64) as usize;
-                let yi = yi.clamp(0, (height - 1) as i64) as usize;
-                source[(b, c, yi, xi)].elem::<f64>()
-            }
-        }
-    };
+    let read_value =
+        |xi: i64, yi: i64| read_padded(source, b, c, xi, yi, width, height, padding_mode);
 
     // Read the four corners
     let v00 = read_value(x0, y0);
@@ -189,6 +151,125 @@ where
     v00 * w00 + v01 * w01 + v10 * w10 + v11 * w11
 }
 
+/// Nearest neighbor interpolation at a point with configurable padding mode.
+#[allow(clippy::too_many_arguments)]
+fn nearest_interpolate<E, S>(
+    source: &ndarray::ArrayBase<S, ndarray::Dim<[usize; 4]>>,
+    b: usize,
+    c: usize,
+    x: f64,
+    y: f64,
+    width: usize,
+    height: usize,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> f64
+where
+    E: FloatNdArrayElement,
+    S: ndarray::Data<Elem = E>,
+{
+    let Some((x, y)) = pad_coordinates(x, y, width, height, padding_mode, align_corners) else {
+        return non_finite_value(source, b, c, width, height, padding_mode);
+    };
+
+    // Round to the nearest pixel
+    let xi = (x + 0.5).floor() as i64;
+    let yi = (y + 0.5).floor() as i64;
+
+    read_padded(source, b, c, xi, yi, width, height, padding_mode)
+}
+
+/// Apply the padding mode to the sampling coordinates, or `None` if they aren't finite.
+fn pad_coordinates(
+    x: f64,
+    y: f64,
+    width: usize,
+    height: usize,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> Option<(f64, f64)> {
+    if !x.is_finite() || !y.is_finite() {
+        return None;
+    }
+
+    let coordinates = match padding_mode {
+        GridSamplePaddingMode::Border => {
+            // Clamp coordinates to valid range [0, size-1]
+            let x = x.clamp(0.0, (width - 1) as f64);
+            let y = y.clamp(0.0, (height - 1) as f64);
+            (x, y)
+        }
+        GridSamplePaddingMode::Reflection => {
+            // Reflect coordinates at boundaries
+            let x = reflect_coordinate(x, width, align_corners);
+            let y = reflect_coordinate(y, height, align_corners);
+            (x, y)
+        }
+        GridSamplePaddingMode::Zeros => (x, y), // Keep as-is, handle out-of-bounds in read
+    };
+
+    Some(coordinates)
+}
+
+/// The value sampled at inf/nan coordinates.
+fn non_finite_value<E, S>(
+    source: &ndarray::ArrayBase<S, ndarray::Dim<[usize; 4]>>,
+    b: usize,
+    c: usize,
+    width: usize,
+    height: usize,
+    padding_mode: GridSamplePaddingMode,
+) -> f64
+where
+    E: FloatNdArrayElement,
+    S: ndarray::Data<Elem = E>,
+{
+    match padding_mode {
+        GridSamplePaddingMode::Zeros => 0.0,
+        GridSamplePaddingMode::Border => {
+            // Clamp to center of image for inf/nan
+            let cx = ((width - 1) as f64 / 2.0).clamp(0.0, (width - 1) as f64);
+            let cy = ((height - 1) as f64 / 2.0).clamp(0.0, (height - 1) as f64);
+            source[(b, c, cy as usize, cx as usize)].elem::<f64>()
+        }
+        GridSamplePaddingMode::Reflection => 0.0, // Simplified: treat as zeros for inf/nan
+    }
+}
+
+/// Read a value at integer indices based on padding mode.
+#[allow(clippy::too_many_arguments)]
+fn read_padded<E, S>(
+    source: &ndarray::ArrayBase<S, ndarray::Dim<[usize; 4]>>,
+    b: usize,
+    c: usize,
+    xi: i64,
+    yi: i64,
+    width: usize,
+    height: usize,
+    padding_mode: GridSamplePaddingMode,
+) -> f64
+where
+    E: FloatNdArrayElement,
+    S: ndarray::Data<Elem = E>,
+{
+    match padding_mode {
+        GridSamplePaddingMode::Zeros => {
+            // Return 0 for out-of-bounds
+            if xi >= 0 && xi < width as i64 && yi >= 0 && yi < height as i64 {
+                source[(b, c, yi as usize, xi as usize)].elem::<f64>()
+            } else {
+                0.0
+            }
+        }
+        GridSamplePaddingMode::Border | GridSamplePaddingMode::Reflection => {
+            // Coordinates should already be in valid range after clamping/reflection
+            let xi = xi.clamp(0, (width - 1) as i64) as usize;
+            let yi = yi.clamp(0, (height - 1) as i64) as usize;
+            source[(b, c, yi, xi)].elem::<f64>()
+        }
+    }
+}
+
 /// Reflect a coordinate at the boundaries using a triangle wave pattern.
 ///
 /// For align_corners=true: reflects within [0, size-1]
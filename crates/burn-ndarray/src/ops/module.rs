@@ -22,7 +22,11 @@ use crate::{
 };
 use burn_backend::{
     ElementConversion, TensorMetadata,
-    ops::{attention::attention_fallback, *},
+    ops::{
+        attention::attention_fallback,
+        interpolate::{interpolate_antialias, interpolate_antialias_backward},
+        *,
+    },
     tensor::FloatTensor,
 };
 
@@ -285,6 +289,10 @@ where
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        if options.antialias {
+            return interpolate_antialias::<Self>(x, output_size, &options);
+        }
+
         match options.mode {
             InterpolateMode::Nearest => {
                 module_op!(inp(x), opt(), E, |x| nearest_interpolate::<E>(
@@ -323,6 +331,7 @@ where
                 )
                 .into())
             }
+            InterpolateMode::Area => Self::adaptive_avg_pool2d(x, output_size),
         }
     }
 
@@ -332,6 +341,10 @@ where
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<Self> {
+        if options.antialias {
+            return interpolate_antialias_backward::<Self>(x, grad, &options);
+        }
+
         match options.mode {
             InterpolateMode::Nearest => module_op!(inp(x, grad), opt(), E, |x, grad| {
                 nearest_interpolate_backward::<E>(x, grad, output_size).into()
@@ -348,6 +361,7 @@ where
             InterpolateMode::Lanczos3 => {
                 panic!("lanczos3 interpolation backward is not supported for ndarray backend")
             }
+            InterpolateMode::Area => Self::adaptive_avg_pool2d_backward(x, grad),
         }
     }
 
@@ -0,0 +1,166 @@
+use burn_core as burn;
+
+use alloc::vec;
+use burn::config::Config;
+use burn::tensor::activation::softmax;
+use burn::tensor::{Bool, Int, Tensor};
+
+use super::{apply_repetition_penalty, apply_temperature, apply_top_k, apply_top_p, beam_search};
+
+/// An autoregressive model that predicts the next token of a batch of sequences, which can be
+/// decoded with [generate].
+///
+/// The model is given all the tokens of the sequences at every step, so a model without a cache
+/// can simply run its forward pass on them, while a model with a key-value cache keeps it in its
+/// [state](Autoregressive::State) and only processes the last token.
+pub trait Autoregressive {
+    /// The state carried between the decoding steps, such as a key-value cache.
+    ///
+    /// Stateless models can use `()`.
+    type State;
+
+    /// Creates the state before the first step, for a batch of `batch_size` sequences.
+    fn init_state(&self, batch_size: usize) -> Self::State;
+
+    /// Returns the next-token logits of each sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`
+    /// - output: `[batch_size, vocab_size]`
+    fn forward_step(&self, tokens: Tensor<2, Int>, state: &mut Self::State) -> Tensor<2>;
+
+    /// Keeps the state of the sequences at `indices`, in this order.
+    ///
+    /// This is used by beam search, which duplicates the best sequences and drops the others
+    /// after each step.
+    fn select_state(&self, state: Self::State, indices: Tensor<1, Int>) -> Self::State;
+}
+
+/// How the next token is chosen at each step of [generate].
+#[derive(Config, Debug, PartialEq)]
+pub enum DecodingStrategy {
+    /// The most likely token.
+    Greedy,
+    /// A token sampled from the next-token distribution.
+    ///
+    /// The logits are divided by the temperature, then filtered with top-k and top-p (nucleus)
+    /// sampling when enabled.
+    Sample {
+        /// The temperature of the distribution.
+        temperature: f64,
+        /// Only sample from the `k` most likely tokens.
+        top_k: Option<usize>,
+        /// Only sample from the most likely tokens whose probabilities add up to `p`.
+        top_p: Option<f64>,
+    },
+    /// Beam search, which keeps the `num_beams` most likely sequences at each step and returns
+    /// the best one.
+    ///
+    /// The finished sequences are ranked by their log-probability divided by
+    /// `length ^ length_penalty`, so a positive length penalty favors longer sequences.
+    BeamSearch {
+        /// The number of sequences kept at each step.
+        num_beams: usize,
+        /// The exponent of the sequence length in the final scores.
+        length_penalty: f64,
+    },
+}
+
+/// Configuration of the [generate] function.
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of tokens generated after the prompt.
+    pub max_new_tokens: usize,
+    /// How the next token is chosen.
+    #[config(default = "DecodingStrategy::Greedy")]
+    pub strategy: DecodingStrategy,
+    /// The token ending a sequence.
+    ///
+    /// The finished sequences are padded with it until all sequences are finished, at which
+    /// point the generation stops.
+    #[config(default = "None")]
+    pub eos_token: Option<usize>,
+    /// Penalty of the tokens that already appear in a sequence, see
+    /// [apply_repetition_penalty](super::apply_repetition_penalty). `1.0` disables it.
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+}
+
+/// Generates the continuation of a batch of prompts with an [autoregressive](Autoregressive)
+/// model.
+///
+/// Returns the prompts followed by the generated tokens.
+///
+/// # Shapes
+///
+/// - prompt: `[batch_size, prompt_length]`
+/// - output: `[batch_size, prompt_length + new_tokens]`, with `new_tokens <= max_new_tokens`
+pub fn generate<M: Autoregressive>(
+    model: &M,
+    prompt: Tensor<2, Int>,
+    config: &GenerationConfig,
+) -> Tensor<2, Int> {
+    if let DecodingStrategy::BeamSearch {
+        num_beams,
+        length_penalty,
+    } = config.strategy
+    {
+        return beam_search(model, prompt, config, num_beams, length_penalty);
+    }
+
+    let [batch_size, _] = prompt.dims();
+    let mut state = model.init_state(batch_size);
+    let mut tokens = prompt;
+    let mut finished = Tensor::<1, Bool>::zeros([batch_size], &tokens.device());
+
+    for _ in 0..config.max_new_tokens {
+        let logits = model.forward_step(tokens.clone(), &mut state);
+        let logits = config.penalize_repetitions(logits, &tokens);
+
+        let mut next = match &config.strategy {
+            DecodingStrategy::Sample {
+                temperature,
+                top_k,
+                top_p,
+            } => {
+                let mut logits = apply_temperature(logits, *temperature);
+                if let Some(k) = top_k {
+                    logits = apply_top_k(logits, *k);
+                }
+                if let Some(p) = top_p {
+                    logits = apply_top_p(logits, *p);
+                }
+                softmax(logits, 1).categorical(1)
+            }
+            _ => logits.argmax(1),
+        };
+
+        if let Some(eos_token) = config.eos_token {
+            next = next.mask_fill(finished.clone().unsqueeze_dim(1), eos_token as i64);
+            finished = finished.bool_or(next.clone().equal_elem(eos_token as i64).squeeze_dim(1));
+        }
+
+        tokens = Tensor::cat(vec![tokens, next], 1);
+
+        if config.eos_token.is_some() && finished.clone().all().into_scalar::<bool>() {
+            break;
+        }
+    }
+
+    tokens
+}
+
+impl GenerationConfig {
+    pub(crate) fn penalize_repetitions(
+        &self,
+        logits: Tensor<2>,
+        tokens: &Tensor<2, Int>,
+    ) -> Tensor<2> {
+        if self.repetition_penalty == 1.0 {
+            return logits;
+        }
+
+        apply_repetition_penalty(logits, tokens.clone(), self.repetition_penalty)
+    }
+}
@@ -0,0 +1,103 @@
+use burn_core as burn;
+
+use alloc::vec;
+use burn::tensor::activation::log_softmax;
+use burn::tensor::{Bool, Int, Tensor};
+
+use super::{Autoregressive, GenerationConfig};
+
+/// Beam search decoding, see [DecodingStrategy::BeamSearch](super::DecodingStrategy::BeamSearch).
+///
+/// The beams of each prompt are stored contiguously, as a batch of `batch_size * num_beams`
+/// sequences.
+pub(crate) fn beam_search<M: Autoregressive>(
+    model: &M,
+    prompt: Tensor<2, Int>,
+    config: &GenerationConfig,
+    num_beams: usize,
+    length_penalty: f64,
+) -> Tensor<2, Int> {
+    assert!(num_beams > 0, "Beam search requires at least one beam");
+    let [batch_size, prompt_length] = prompt.dims();
+    let num_sequences = batch_size * num_beams;
+    let device = prompt.device();
+
+    let mut tokens = prompt
+        .unsqueeze_dim::<3>(1)
+        .repeat_dim(1, num_beams)
+        .reshape([num_sequences, prompt_length]);
+    let mut state = model.init_state(num_sequences);
+
+    // Only the first beam of each prompt is live at the start, so that the first step doesn't
+    // select the same token in every beam.
+    let first_beam = Tensor::<1, Int>::arange(0..num_beams as i64, &device)
+        .equal_elem(0)
+        .unsqueeze_dim::<2>(0)
+        .repeat_dim(0, batch_size);
+    let mut scores = Tensor::<2>::zeros([batch_size, num_beams], &device)
+        .mask_fill(first_beam.bool_not(), f32::NEG_INFINITY);
+    let mut finished = Tensor::<1, Bool>::zeros([num_sequences], &device);
+    let mut lengths = Tensor::<1, Int>::zeros([num_sequences], &device);
+
+    // Offset of the first beam of each prompt in the batch of sequences.
+    let offsets = Tensor::<1, Int>::arange(0..batch_size as i64, &device)
+        .mul_scalar(num_beams as i64)
+        .unsqueeze_dim::<2>(1);
+
+    for _ in 0..config.max_new_tokens {
+        let logits = model.forward_step(tokens.clone(), &mut state);
+        let logits = config.penalize_repetitions(logits, &tokens);
+        let [_, vocab_size] = logits.dims();
+        let mut log_probs = log_softmax(logits, 1);
+
+        // Finished sequences keep their score by producing the end-of-sequence token.
+        if let Some(eos_token) = config.eos_token {
+            let not_eos = Tensor::<1, Int>::arange(0..vocab_size as i64, &device)
+                .not_equal_elem(eos_token as i64)
+                .unsqueeze_dim::<2>(0)
+                .expand([num_sequences, vocab_size]);
+            let padding = Tensor::<2>::zeros([num_sequences, vocab_size], &device)
+                .mask_fill(not_eos, f32::NEG_INFINITY);
+            let mask = finished
+                .clone()
+                .unsqueeze_dim::<2>(1)
+                .expand([num_sequences, vocab_size]);
+            log_probs = log_probs.mask_where(mask, padding);
+        }
+
+        let candidates = (scores.reshape([num_sequences, 1]) + log_probs)
+            .reshape([batch_size, num_beams * vocab_size]);
+        let (best_scores, best_candidates) = candidates.topk_with_indices(num_beams, 1);
+        let next = best_candidates.clone().remainder_scalar(vocab_size as i64);
+        let beams = (best_candidates.div_scalar(vocab_size as i64) + offsets.clone())
+            .reshape([num_sequences]);
+        let next = next.reshape([num_sequences, 1]);
+        scores = best_scores;
+
+        tokens = Tensor::cat(vec![tokens.select(0, beams.clone()), next.clone()], 1);
+        state = model.select_state(state, beams.clone());
+
+        let was_finished = finished.select(0, beams.clone());
+        lengths = lengths.select(0, beams) + was_finished.clone().bool_not().int();
+
+        finished = match config.eos_token {
+            Some(eos_token) => {
+                was_finished.bool_or(next.equal_elem(eos_token as i64).reshape([num_sequences]))
+            }
+            None => was_finished,
+        };
+
+        if config.eos_token.is_some() && finished.clone().all().into_scalar::<bool>() {
+            break;
+        }
+    }
+
+    let lengths = lengths
+        .float()
+        .clamp_min(1.0)
+        .powf_scalar(length_penalty)
+        .reshape([batch_size, num_beams]);
+    let best = (scores / lengths).argmax(1) + offsets;
+
+    tokens.select(0, best.reshape([batch_size]))
+}
@@ -0,0 +1,162 @@
+use burn_core as burn;
+
+use burn::tensor::activation::softmax;
+use burn::tensor::{IndexingUpdateOp, Int, Tensor};
+
+/// Divides the next-token logits by the temperature.
+///
+/// Temperatures below 1 sharpen the distribution, temperatures above 1 flatten it.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+pub fn apply_temperature(logits: Tensor<2>, temperature: f64) -> Tensor<2> {
+    assert!(
+        temperature > 0.0,
+        "The temperature should be positive, got {temperature}"
+    );
+
+    logits.div_scalar(temperature)
+}
+
+/// Keeps the `k` largest next-token logits of each sequence, the others are set to `-inf`.
+///
+/// Tokens tied with the `k`-th largest logit are kept as well.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+pub fn apply_top_k(logits: Tensor<2>, k: usize) -> Tensor<2> {
+    assert!(k > 0, "Top-k sampling should keep at least one token");
+    let [_, vocab_size] = logits.dims();
+    if k >= vocab_size {
+        return logits;
+    }
+
+    let threshold = logits.clone().topk(k, 1).min_dim(1);
+    let mask = logits.clone().lower(threshold);
+
+    logits.mask_fill(mask, f32::NEG_INFINITY)
+}
+
+/// Nucleus filtering: keeps the smallest set of most likely tokens of each sequence whose
+/// probabilities add up to at least `p`, the others are set to `-inf`.
+///
+/// The most likely token is always kept.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+pub fn apply_top_p(logits: Tensor<2>, p: f64) -> Tensor<2> {
+    assert!(
+        p > 0.0 && p <= 1.0,
+        "Top-p sampling expects a probability in (0, 1], got {p}"
+    );
+
+    let (sorted, indices) = logits.clone().sort_descending_with_indices(1);
+    let probs = softmax(sorted.clone(), 1);
+    // Probability mass of the more likely tokens, which is below `p` for the kept tokens.
+    let mass_before = probs.clone().cumsum(1) - probs;
+    let sorted = sorted.mask_fill(mass_before.greater_equal_elem(p), f32::NEG_INFINITY);
+
+    logits
+        .zeros_like()
+        .scatter(1, indices, sorted, IndexingUpdateOp::Add)
+}
+
+/// Penalizes the tokens that already appear in the sequences, as in
+/// [CTRL](https://arxiv.org/abs/1909.05858).
+///
+/// The positive logits of the repeated tokens are divided by the penalty and the negative ones
+/// are multiplied by it, so a penalty above 1 makes repetitions less likely.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+/// - tokens: `[batch_size, seq_length]`
+pub fn apply_repetition_penalty(
+    logits: Tensor<2>,
+    tokens: Tensor<2, Int>,
+    penalty: f64,
+) -> Tensor<2> {
+    assert!(
+        penalty > 0.0,
+        "The repetition penalty should be positive, got {penalty}"
+    );
+
+    let seen = logits
+        .zeros_like()
+        .scatter(
+            1,
+            tokens.clone(),
+            tokens.ones_like().float(),
+            IndexingUpdateOp::Add,
+        )
+        .greater_elem(0.0);
+    let penalized = logits.clone().div_scalar(penalty).mask_where(
+        logits.clone().lower_elem(0.0),
+        logits.clone().mul_scalar(penalty),
+    );
+
+    logits.mask_where(seen, penalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Device, Tolerance};
+
+    #[test]
+    fn top_k_should_mask_smaller_logits() {
+        let device = Device::default();
+        let logits = Tensor::<2>::from_data([[1.0, 4.0, 3.0, 2.0], [0.5, 0.0, 2.0, 1.0]], &device);
+
+        let output = apply_top_k(logits, 2);
+
+        let inf = f32::NEG_INFINITY;
+        let expected =
+            Tensor::<2>::from_data([[inf, 4.0, 3.0, inf], [inf, inf, 2.0, 1.0]], &device);
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn top_p_should_keep_the_nucleus() {
+        let device = Device::default();
+        // Probabilities [0.1, 0.6, 0.3] and [0.25, 0.25, 0.5].
+        let logits = Tensor::<2>::from_data([[0.1f32, 0.6, 0.3], [0.25, 0.25, 0.5]], &device).log();
+
+        let output = apply_top_p(logits.clone(), 0.8);
+
+        let inf = f32::NEG_INFINITY;
+        let mask = Tensor::<2>::from_data([[inf, 0.0, 0.0], [0.0, 0.0, 0.0]], &device);
+        output
+            .into_data()
+            .assert_eq(&(logits + mask).into_data(), false);
+    }
+
+    #[test]
+    fn top_p_should_keep_the_most_likely_token() {
+        let device = Device::default();
+        let logits = Tensor::<2>::from_data([[1.0, 5.0, 2.0]], &device);
+
+        let output = apply_top_p(logits, 0.01);
+
+        let inf = f32::NEG_INFINITY;
+        let expected = Tensor::<2>::from_data([[inf, 5.0, inf]], &device);
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn repetition_penalty_should_penalize_seen_tokens() {
+        let device = Device::default();
+        let logits = Tensor::<2>::from_data([[2.0, -2.0, 2.0, -2.0]], &device);
+        let tokens = Tensor::<2, Int>::from_data([[0, 1, 1]], &device);
+
+        let output = apply_repetition_penalty(logits, tokens, 2.0);
+
+        let expected = Tensor::<2>::from_data([[1.0, -4.0, 2.0, -2.0]], &device);
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+}
@@ -0,0 +1,133 @@
+mod base;
+mod beam_search;
+mod logits;
+
+pub use base::*;
+pub use logits::*;
+
+pub(crate) use beam_search::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::{Device, Int, Tensor, TensorData};
+
+    /// Predicts the next token from the last one with a table of probabilities.
+    struct Bigram {
+        table: Tensor<2>,
+    }
+
+    impl Bigram {
+        fn new<const N: usize>(probs: [[f32; N]; N], device: &Device) -> Self {
+            let table = Tensor::<2>::from_data(probs, device).clamp_min(1e-6).log();
+            Self { table }
+        }
+    }
+
+    impl Autoregressive for Bigram {
+        type State = ();
+
+        fn init_state(&self, _batch_size: usize) -> Self::State {}
+
+        fn forward_step(&self, tokens: Tensor<2, Int>, _state: &mut Self::State) -> Tensor<2> {
+            let [batch_size, seq_length] = tokens.dims();
+            let last = tokens.narrow(1, seq_length - 1, 1).reshape([batch_size]);
+
+            self.table.clone().select(0, last)
+        }
+
+        fn select_state(&self, state: Self::State, _indices: Tensor<1, Int>) -> Self::State {
+            state
+        }
+    }
+
+    fn cycle(device: &Device) -> Bigram {
+        Bigram::new(
+            [
+                [0.1, 0.7, 0.1, 0.1],
+                [0.1, 0.1, 0.7, 0.1],
+                [0.1, 0.1, 0.1, 0.7],
+                [0.7, 0.1, 0.1, 0.1],
+            ],
+            device,
+        )
+    }
+
+    #[test]
+    fn greedy_should_generate_max_new_tokens() {
+        let device = Device::default();
+        let model = cycle(&device);
+        let prompt = Tensor::<2, Int>::from_data([[0], [2]], &device);
+
+        let output = generate(&model, prompt, &GenerationConfig::new(5));
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 1, 2, 3, 0, 1], [2, 3, 0, 1, 2, 3]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn greedy_should_pad_finished_sequences_and_stop() {
+        let device = Device::default();
+        let model = cycle(&device);
+        let prompt = Tensor::<2, Int>::from_data([[0], [2]], &device);
+        let config = GenerationConfig::new(8).with_eos_token(Some(3));
+
+        let output = generate(&model, prompt, &config);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2, 3], [2, 3, 3, 3]]), false);
+    }
+
+    #[test]
+    fn sampling_from_the_top_token_should_be_greedy() {
+        let device = Device::default();
+        let model = cycle(&device);
+        let prompt = Tensor::<2, Int>::from_data([[1], [3]], &device);
+        let config = GenerationConfig::new(4).with_strategy(DecodingStrategy::Sample {
+            temperature: 0.5,
+            top_k: Some(1),
+            top_p: Some(0.9),
+        });
+
+        let output = generate(&model, prompt, &config);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3, 0, 1], [3, 0, 1, 2, 3]]), false);
+    }
+
+    #[test]
+    fn beam_search_should_find_a_more_likely_sequence_than_greedy() {
+        let device = Device::default();
+        // Greedy decoding gives 0, 1, 3 with probability 0.24, against 0.36 for 0, 2, 3.
+        let model = Bigram::new(
+            [
+                [0.0, 0.6, 0.4, 0.0, 0.0],
+                [0.0, 0.0, 0.3, 0.4, 0.3],
+                [0.0, 0.0, 0.0, 0.9, 0.1],
+                [0.2, 0.2, 0.2, 0.2, 0.2],
+                [0.2, 0.2, 0.2, 0.2, 0.2],
+            ],
+            &device,
+        );
+        let prompt = Tensor::<2, Int>::from_data([[0]], &device);
+        let greedy = GenerationConfig::new(4).with_eos_token(Some(3));
+        let beam_search = greedy.clone().with_strategy(DecodingStrategy::BeamSearch {
+            num_beams: 2,
+            length_penalty: 1.0,
+        });
+
+        let greedy = generate(&model, prompt.clone(), &greedy);
+        let beam_search = generate(&model, prompt, &beam_search);
+
+        greedy
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 3]]), false);
+        beam_search
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 2, 3]]), false);
+    }
+}
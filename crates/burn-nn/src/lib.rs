@@ -12,6 +12,9 @@ pub mod loss;
 pub mod modules;
 pub use modules::*;
 
+/// Text generation utilities for autoregressive models.
+pub mod generate;
+
 pub mod activation;
 pub use activation::{
     celu::*, elu::*, gelu::*, glu::*, hard_shrink::*, hard_sigmoid::*, leaky_relu::*, prelu::*,
@@ -6,6 +6,8 @@ use burn::tensor::activation::log_sigmoid;
 use burn::tensor::{Device, Int, Tensor};
 use burn::{config::Config, module::Module};
 
+use super::mean_per_sample;
+
 /// Configuration to create a [Binary Cross-entropy loss](BinaryCrossEntropyLoss) using the [init function](BinaryCrossEntropyLossConfig::init).
 #[derive(Config, Debug)]
 pub struct BinaryCrossEntropyLossConfig {
@@ -98,6 +100,21 @@ impl BinaryCrossEntropyLoss {
     /// - logits: `[batch_size, num_classes]`
     /// - targets: `[batch_size, num_classes]`
     pub fn forward<const D: usize>(&self, logits: Tensor<D>, targets: Tensor<D, Int>) -> Tensor<1> {
+        self.forward_no_reduction(logits, targets).mean()
+    }
+
+    /// Compute the criterion of each sample, averaged over the classes for multi-label targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size]` or `[batch_size, num_classes]`
+    /// - targets: same as the logits
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<D>,
+        targets: Tensor<D, Int>,
+    ) -> Tensor<1> {
         self.assertions(&logits, &targets);
 
         let mut targets_float = targets.clone().float();
@@ -132,7 +149,7 @@ impl BinaryCrossEntropyLoss {
             loss = loss * weights;
         }
 
-        loss.mean()
+        mean_per_sample(loss)
     }
 
     fn assertions<const D: usize>(&self, logits: &Tensor<D>, targets: &Tensor<D, Int>) {
@@ -95,6 +95,7 @@ impl CosineEmbeddingLoss {
         match &self.reduction {
             Reduction::Mean | Reduction::Auto => tensor.mean(),
             Reduction::Sum => tensor.sum(),
+            Reduction::None => tensor,
             other => panic!("{other:?} reduction is not supported"),
         }
     }
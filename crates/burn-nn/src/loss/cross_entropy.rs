@@ -1,5 +1,4 @@
 use burn_core as burn;
-use burn_core::tensor::IndexingUpdateOp;
 
 use alloc::string::ToString;
 use alloc::vec;
@@ -21,6 +20,13 @@ pub struct CrossEntropyLossConfig {
     /// Prevents pad tokens from impacting loss calculation.
     pub pad_tokens: Option<Vec<usize>>,
 
+    /// Create cross-entropy ignoring a target index.
+    ///
+    /// Samples whose target equals this index don't contribute to the loss nor to the mean,
+    /// which is averaged over the remaining samples only. Unlike the pad tokens, the index doesn't
+    /// have to be a valid class, so the PyTorch convention of `-100` can be used.
+    pub ignore_index: Option<i64>,
+
     /// Create weighted cross-entropy.
     ///
     /// The loss of a specific sample will simply be given by: weight * log(p(x)) * 1,
@@ -48,6 +54,7 @@ impl CrossEntropyLossConfig {
         self.assertions();
         CrossEntropyLoss {
             pad_tokens: self.pad_tokens.clone(),
+            ignore_index: self.ignore_index,
             weights: self
                 .weights
                 .as_ref()
@@ -81,6 +88,8 @@ impl CrossEntropyLossConfig {
 pub struct CrossEntropyLoss {
     /// Pad tokens to ignore in the loss calculation.
     pub pad_tokens: Option<Vec<usize>>,
+    /// Target index to ignore in the loss calculation.
+    pub ignore_index: Option<i64>,
     /// Weights for cross-entropy.
     pub weights: Option<Tensor<1>>,
     /// Label smoothing factor.
@@ -105,6 +114,7 @@ impl ModuleDisplay for CrossEntropyLoss {
 
         content
             .add("pad_tokens", &pad_tokens)
+            .add("ignore_index", &self.ignore_index)
             .add("weights", &self.weights)
             .add("smoothing", &self.smoothing)
             .add("logits", &self.logits)
@@ -127,51 +137,83 @@ impl CrossEntropyLoss {
     /// - logits: `[batch_size, num_targets]`
     /// - targets: `[batch_size]`
     pub fn forward(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+        let (tensor, targets, ignored) = self.log_likelihoods(logits, targets);
+
+        self.negative_mean(tensor, targets, ignored)
+    }
+
+    /// Compute the criterion of each sample, without reducing.
+    ///
+    /// The losses are weighted by the class weights, and are zero for the padded and ignored
+    /// targets. Unlike [forward](Self::forward), they aren't normalized by the weights.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+        let (tensor, _, _) = self.log_likelihoods(logits, targets);
+
+        tensor.neg()
+    }
+
+    /// The (weighted) log-likelihood of each sample, zero for the padded and ignored targets, with
+    /// the valid targets and the ignored mask.
+    fn log_likelihoods(
+        &self,
+        logits: Tensor<2>,
+        targets: Tensor<1, Int>,
+    ) -> (Tensor<1>, Tensor<1, Int>, Option<Tensor<1, Bool>>) {
         Self::assertions(logits.clone(), targets.clone());
         match self.smoothing {
-            Some(alpha) => self.forward_smoothed(logits, targets, alpha),
-            _ => self.forward_default(logits, targets),
+            Some(alpha) => self.log_likelihoods_smoothed(logits, targets, alpha),
+            _ => self.log_likelihoods_default(logits, targets),
         }
     }
 
-    fn forward_smoothed(
+    fn log_likelihoods_smoothed(
         &self,
         logits: Tensor<2>,
         targets: Tensor<1, Int>,
         alpha: f32,
-    ) -> Tensor<1> {
+    ) -> (Tensor<1>, Tensor<1, Int>, Option<Tensor<1, Bool>>) {
         let mask = self.padding_mask(&targets);
+        let ignored = self.ignored_mask(&targets);
+        let targets = Self::valid_targets(targets, ignored.clone());
         let tensor = if self.logits {
             log_softmax(logits, 1)
         } else {
             logits.log()
         };
         let [batch_size, nr_classes] = tensor.dims();
-        let tensor = tensor
-            * Self::compute_smoothed_targets([batch_size, nr_classes], targets.clone(), alpha);
+        let tensor = match &self.weights {
+            Some(weights) => tensor * weights.clone().reshape([1, nr_classes]),
+            None => tensor,
+        };
 
-        match &self.weights {
-            Some(weights) => {
-                let tensor = tensor
-                    * weights
-                        .clone()
-                        .reshape([1, nr_classes])
-                        .repeat_dim(0, batch_size);
-                let weights = weights.clone().gather(0, targets);
-                let tensor = Self::apply_mask_2d(tensor, mask);
-                tensor.sum().neg() / weights.sum()
-            }
-            None => {
-                let tensor = Self::apply_mask_2d(tensor, mask);
-                tensor.sum_dim(1).mean().neg()
-            }
-        }
+        // The smoothed targets `y(1 - a) + a / nr_classes` are applied without materializing them:
+        // the target term is gathered, and the uniform term is a sum over the classes.
+        let target_term = tensor
+            .clone()
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .reshape([batch_size]);
+        let uniform_term = tensor.sum_dim(1).reshape([batch_size]);
+        let tensor = target_term * (1. - alpha) + uniform_term * (alpha / nr_classes as f32);
+
+        (Self::apply_mask_1d(tensor, mask), targets, ignored)
     }
 
-    fn forward_default(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+    fn log_likelihoods_default(
+        &self,
+        logits: Tensor<2>,
+        targets: Tensor<1, Int>,
+    ) -> (Tensor<1>, Tensor<1, Int>, Option<Tensor<1, Bool>>) {
         let [batch_size] = targets.dims();
 
         let mask = self.padding_mask(&targets);
+        let ignored = self.ignored_mask(&targets);
+        let targets = Self::valid_targets(targets, ignored.clone());
         let target_indices = targets.clone().reshape([batch_size, 1]);
         let tensor = if self.logits {
             log_softmax(logits, 1).gather(1, target_indices)
@@ -181,35 +223,46 @@ impl CrossEntropyLoss {
             let eps = finfo.min_positive.sqrt();
             logits.clamp_min(eps).gather(1, target_indices).log()
         };
-
-        match &self.weights {
+        let tensor = match &self.weights {
             Some(weights) => {
+                tensor.reshape([batch_size]) * weights.clone().gather(0, targets.clone())
+            }
+            None => tensor.reshape([batch_size]),
+        };
+
+        (Self::apply_mask_1d(tensor, mask), targets, ignored)
+    }
+
+    /// Negated mean of the (weighted) log-likelihoods, normalized by the sum of the weights of the
+    /// targets when weighted. Ignored targets are excluded from the normalization.
+    fn negative_mean(
+        &self,
+        tensor: Tensor<1>,
+        targets: Tensor<1, Int>,
+        ignored: Option<Tensor<1, Bool>>,
+    ) -> Tensor<1> {
+        match (&self.weights, ignored) {
+            (Some(weights), ignored) => {
                 let weights = weights.clone().gather(0, targets);
-                let tensor = tensor.reshape([batch_size]) * weights.clone();
-                let tensor = Self::apply_mask_1d(tensor, mask);
+                let weights = Self::apply_mask_1d(weights, ignored);
                 tensor.sum().neg() / weights.sum()
             }
-            None => {
-                let tensor = Self::apply_mask_1d(tensor.reshape([batch_size]), mask);
-                tensor.mean().neg()
-            }
+            (None, Some(ignored)) => tensor.sum().neg() / ignored.bool_not().float().sum(),
+            (None, None) => tensor.mean().neg(),
         }
     }
 
-    fn compute_smoothed_targets(
-        shape: [usize; 2],
-        targets: Tensor<1, Int>,
-        alpha: f32,
-    ) -> Tensor<2> {
-        let [batch_size, nr_classes] = shape;
-        let device = &targets.device();
-        let targets_matrix = Tensor::<2>::zeros(shape, device).scatter(
-            1,
-            targets.reshape([batch_size, 1]),
-            Tensor::ones([batch_size, 1], device),
-            IndexingUpdateOp::Add,
-        );
-        targets_matrix * (1. - alpha) + alpha / nr_classes as f32
+    fn ignored_mask(&self, targets: &Tensor<1, Int>) -> Option<Tensor<1, Bool>> {
+        self.ignore_index
+            .map(|index| targets.clone().equal_elem(index))
+    }
+
+    /// Replaces the ignored targets, which may not be valid classes, so that they can be gathered.
+    fn valid_targets(targets: Tensor<1, Int>, ignored: Option<Tensor<1, Bool>>) -> Tensor<1, Int> {
+        match ignored {
+            Some(ignored) => targets.mask_fill(ignored, 0),
+            None => targets,
+        }
     }
 
     fn padding_mask(&self, targets: &Tensor<1, Int>) -> Option<Tensor<1, Bool>> {
@@ -221,6 +274,12 @@ impl CrossEntropyLoss {
             }
             mask = Some(res.greater_elem(0));
         }
+        if let Some(ignored) = self.ignored_mask(targets) {
+            mask = Some(match mask {
+                Some(mask) => mask.bool_or(ignored),
+                None => ignored,
+            });
+        }
 
         mask
     }
@@ -233,15 +292,6 @@ impl CrossEntropyLoss {
         tensor
     }
 
-    fn apply_mask_2d(mut tensor: Tensor<2>, mask: Option<Tensor<1, Bool>>) -> Tensor<2> {
-        if let Some(mask) = mask {
-            let [batch_size, nr_classes] = tensor.dims();
-            tensor = tensor.mask_fill(mask.reshape([batch_size, 1]).repeat_dim(1, nr_classes), 0);
-        }
-
-        tensor
-    }
-
     fn assertions(logits: Tensor<2>, targets: Tensor<1, Int>) {
         let [logits_height, _] = logits.dims();
         let [targets_height] = targets.dims();
@@ -393,6 +443,62 @@ mod tests {
             .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
     }
 
+    #[test]
+    fn test_cross_entropy_loss_with_ignore_index() {
+        let (logits, _, _) = setup!();
+        let device = logits.device();
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([2, -100, 4, 1]), &device);
+        let valid_logits = logits
+            .clone()
+            .select(0, Tensor::from_ints([0, 2, 3], &device));
+        let valid_targets = Tensor::<1, Int>::from_data(TensorData::from([2, 4, 1]), &device);
+
+        for config in [
+            CrossEntropyLossConfig::new(),
+            CrossEntropyLossConfig::new().with_smoothing(Some(0.1)),
+            CrossEntropyLossConfig::new().with_weights(Some(vec![1.0, 2., 3., 4., 5.])),
+            CrossEntropyLossConfig::new()
+                .with_weights(Some(vec![1.0, 2., 3., 4., 5.]))
+                .with_smoothing(Some(0.1)),
+        ] {
+            let loss_1 = config
+                .clone()
+                .with_ignore_index(Some(-100))
+                .init(&device)
+                .forward(logits.clone(), targets.clone());
+            let loss_2 = config
+                .init(&device)
+                .forward(valid_logits.clone(), valid_targets.clone());
+
+            loss_1
+                .into_data()
+                .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
+        }
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_no_reduction() {
+        let (logits, _, _) = setup!();
+        let device = logits.device();
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([2, -100, 4, 1]), &device);
+        let loss = CrossEntropyLossConfig::new()
+            .with_ignore_index(Some(-100))
+            .init(&device);
+
+        let per_sample = loss
+            .forward_no_reduction(logits.clone(), targets.clone())
+            .into_data()
+            .to_vec::<FT>()
+            .unwrap();
+        assert_eq!(per_sample[1], 0.0);
+
+        let mean = per_sample.iter().sum::<FT>() / 3.0;
+        TensorData::from([mean]).assert_approx_eq::<FT>(
+            &loss.forward(logits, targets).into_data(),
+            Tolerance::default(),
+        );
+    }
+
     #[test]
     fn test_label_smoothing_with_zero_alpha_and_pad_token() {
         let (logits, targets, _) = setup_padded!();
@@ -413,25 +519,6 @@ mod tests {
             .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
     }
 
-    #[test]
-    fn test_label_smoothing_target_conversion() {
-        let (logits, targets, _) = setup!();
-        let smoothed_targets =
-            CrossEntropyLoss::compute_smoothed_targets(logits.dims(), targets, 0.05);
-        let targets_logits = Tensor::<2>::from_data(
-            TensorData::from([
-                [0.01, 0.01, 0.96, 0.01, 0.01],
-                [0.96, 0.01, 0.01, 0.01, 0.01],
-                [0.01, 0.01, 0.01, 0.01, 0.96],
-                [0.01, 0.96, 0.01, 0.01, 0.01],
-            ]),
-            &Default::default(),
-        );
-        smoothed_targets
-            .into_data()
-            .assert_approx_eq::<FT>(&targets_logits.into_data(), Tolerance::default());
-    }
-
     #[test]
     fn test_label_smoothing() {
         let (logits, targets, _) = setup!();
@@ -506,7 +593,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{loss}"),
-            "CrossEntropyLoss {pad_tokens: None, weights: Tensor {rank: 1, shape: [3]}, smoothing: 0.5, logits: true}"
+            "CrossEntropyLoss {pad_tokens: None, ignore_index: None, weights: Tensor {rank: 1, shape: [3]}, smoothing: 0.5, logits: true}"
         );
     }
 
@@ -160,10 +160,12 @@ impl CTCLoss {
     /// - `reduction`: The reduction stratey to apply to the loss tensor containing the CTC loss values for
     ///   each sample (e.g., mean, sum). For the mean reduction strategy, the output losses will be divided
     ///   by the target lengths and then the mean over the batch is taken. This follows PyTorch's behavior.
+    ///   The none reduction strategy returns the loss of each sample, without dividing it.
     ///
     /// # Returns
     ///
-    /// - A 1D tensor of shape `[1]` containing the reduced loss value.
+    /// - A 1D tensor of shape `[1]` containing the reduced loss value, or of shape `[batch_size]`
+    ///   with `Reduction::None`.
     ///
     /// # Shapes
     ///
@@ -173,7 +175,7 @@ impl CTCLoss {
     /// - `target_lengths`: `[batch_size]`
     ///
     /// # Panics
-    /// - If `reduction` is not one of `Reduction::Auto`, `Reduction::Mean`, and `Reduction::Sum`.
+    /// - If `reduction` is `Reduction::BatchMean`.
     /// - If `blank` index is greater than or equal to `num_classes`.
     /// - If the batch dimension of `log_probs`, `targets`, `input_lengths`, and `target_lengths` do not match.
     pub fn forward_with_reduction(
@@ -195,6 +197,7 @@ impl CTCLoss {
                 ctc_loss_tensor.div(target_lengths_float).mean()
             }
             Reduction::Sum => ctc_loss_tensor.sum(),
+            Reduction::None => ctc_loss_tensor,
             other => panic!("{other:?} reduction is not supported"),
         }
     }
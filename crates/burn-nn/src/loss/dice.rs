@@ -0,0 +1,320 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::activation::softmax;
+use burn::tensor::{Device, Int, Tensor};
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Dice loss](DiceLoss) using the [init function](DiceLossConfig::init).
+#[derive(Config, Debug)]
+pub struct DiceLossConfig {
+    /// The weight of each class in the average of the per-class losses.
+    pub weights: Option<Vec<f32>>,
+
+    /// The smoothing term added to the numerator and the denominator of the Dice coefficient,
+    /// which keeps the loss defined for classes absent from both the predictions and the targets.
+    #[config(default = 1.0)]
+    pub smooth: f32,
+
+    /// Treat the predictions as logits, applying a softmax over the class dimension.
+    #[config(default = true)]
+    pub logits: bool,
+}
+
+impl DiceLossConfig {
+    /// Initialize [Dice loss](DiceLoss).
+    pub fn init(&self, device: &Device) -> DiceLoss {
+        assertions(self.smooth, self.weights.as_deref());
+        DiceLoss {
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<1>::from_floats(e.as_slice(), device)),
+            smooth: self.smooth,
+            logits: self.logits,
+        }
+    }
+}
+
+/// Calculate the Dice loss `1 - (2|P ∩ T| + smooth) / (|P| + |T| + smooth)` of segmentation
+/// predictions, for each class of each sample.
+///
+/// The overlap is measured on the soft predictions over all the positions of a sample, which
+/// makes the loss insensitive to the imbalance between the foreground and the background.
+///
+/// Should be created using [DiceLossConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct DiceLoss {
+    /// The weight of each class.
+    pub weights: Option<Tensor<1>>,
+    /// The smoothing term.
+    pub smooth: f32,
+    /// Treat the predictions as logits.
+    pub logits: bool,
+}
+
+impl ModuleDisplay for DiceLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("weights", &self.weights)
+            .add("smooth", &self.smooth)
+            .add("logits", &self.logits)
+            .optional()
+    }
+}
+
+impl DiceLoss {
+    /// Compute the loss with one-hot (or soft) targets, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, num_classes, ...]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<D>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        reduce(self.forward_no_reduction(predictions, targets), reduction)
+    }
+
+    /// Compute the loss with class index targets, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, ...]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward_indices<const D: usize, const DT: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<DT, Int>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let targets = one_hot_targets(targets, predictions.dims()[1]);
+        self.forward(predictions, targets, reduction)
+    }
+
+    /// Compute the loss of each sample, averaged over the classes.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, num_classes, ...]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<D>,
+    ) -> Tensor<1> {
+        let overlap = Overlap::new(predictions, targets, self.logits);
+
+        let dice = overlap
+            .intersection
+            .mul_scalar(2.0)
+            .add_scalar(self.smooth)
+            .div(
+                (overlap.predicted + overlap.target)
+                    .add_scalar(self.smooth)
+                    .clamp_min(f32::MIN_POSITIVE),
+            );
+
+        class_average(dice.neg().add_scalar(1.0), self.weights.as_ref())
+    }
+}
+
+/// The soft overlap between the predictions and the targets of each class of each sample.
+pub(super) struct Overlap {
+    /// `Σ p * t`, with shape `[batch_size, num_classes]`.
+    pub intersection: Tensor<2>,
+    /// `Σ p`, with shape `[batch_size, num_classes]`.
+    pub predicted: Tensor<2>,
+    /// `Σ t`, with shape `[batch_size, num_classes]`.
+    pub target: Tensor<2>,
+}
+
+impl Overlap {
+    pub fn new<const D: usize>(predictions: Tensor<D>, targets: Tensor<D>, logits: bool) -> Self {
+        assert!(
+            D >= 2,
+            "Segmentation losses expect predictions of shape [batch_size, num_classes, ...]"
+        );
+        let predictions_dims = predictions.dims();
+        let targets_dims = targets.dims();
+        assert!(
+            predictions_dims == targets_dims,
+            "Shape of targets ({targets_dims:?}) should correspond to the shape of predictions ({predictions_dims:?})."
+        );
+
+        let [batch_size, num_classes] = [predictions_dims[0], predictions_dims[1]];
+        let predictions = if logits {
+            softmax(predictions, 1)
+        } else {
+            predictions
+        };
+        let predictions = predictions.reshape([batch_size as i32, num_classes as i32, -1]);
+        let targets = targets.reshape([batch_size as i32, num_classes as i32, -1]);
+        let sum = |tensor: Tensor<3>| tensor.sum_dim(2).reshape([batch_size, num_classes]);
+
+        Self {
+            intersection: sum(predictions.clone() * targets.clone()),
+            predicted: sum(predictions),
+            target: sum(targets),
+        }
+    }
+}
+
+/// One-hot encodes class index targets along the class dimension.
+pub(super) fn one_hot_targets<const D: usize, const DT: usize>(
+    targets: Tensor<DT, Int>,
+    num_classes: usize,
+) -> Tensor<D> {
+    assert!(
+        DT + 1 == D,
+        "Index targets of rank {DT} don't match predictions of rank {D}, expected rank {}",
+        D - 1
+    );
+    targets.one_hot::<D>(num_classes).float().movedim(D - 1, 1)
+}
+
+/// The weighted average over the classes of per-class losses, with shape `[batch_size]`.
+pub(super) fn class_average(loss: Tensor<2>, weights: Option<&Tensor<1>>) -> Tensor<1> {
+    let [batch_size, num_classes] = loss.dims();
+    match weights {
+        Some(weights) => {
+            let weights_classes = weights.dims()[0];
+            assert!(
+                weights_classes == num_classes,
+                "The number of classes ({num_classes}) does not match the weights provided ({weights_classes})."
+            );
+            let weights = weights.clone().reshape([1, num_classes]);
+            (loss * weights.clone())
+                .sum_dim(1)
+                .div(weights.sum_dim(1))
+                .reshape([batch_size])
+        }
+        None => loss.mean_dim(1).reshape([batch_size]),
+    }
+}
+
+pub(super) fn reduce(loss: Tensor<1>, reduction: Reduction) -> Tensor<1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+        Reduction::None => loss,
+        other => panic!("{other:?} reduction is not supported"),
+    }
+}
+
+pub(super) fn assertions(smooth: f32, weights: Option<&[f32]>) {
+    assert!(
+        smooth >= 0.0,
+        "The smoothing term must be non-negative. Got {smooth}"
+    );
+    if let Some(weights) = weights {
+        assert!(
+            weights.iter().all(|e| e > &0.),
+            "Weights of the segmentation loss have to be positive."
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn predictions() -> Tensor<3> {
+        Tensor::<3>::from_floats(
+            [[[0.9, 0.2, 0.7, 0.1], [0.1, 0.8, 0.3, 0.9]]],
+            &Default::default(),
+        )
+    }
+
+    fn targets() -> Tensor<2, Int> {
+        Tensor::<2, Int>::from_data(TensorData::from([[0, 1, 0, 1]]), &Default::default())
+    }
+
+    #[test]
+    fn test_dice_loss() {
+        let device = Default::default();
+        let loss = DiceLossConfig::new().with_logits(false).init(&device);
+
+        let per_class = loss.forward_indices(predictions(), targets(), Reduction::Mean);
+        let weighted = DiceLossConfig::new()
+            .with_logits(false)
+            .with_weights(Some(alloc::vec![1.0, 3.0]))
+            .init(&device)
+            .forward_indices(predictions(), targets(), Reduction::Sum);
+
+        per_class
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1400560]), Tolerance::default());
+        weighted
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1386555]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_dice_loss_one_hot_targets() {
+        let device = Default::default();
+        let loss = DiceLossConfig::new()
+            .with_logits(false)
+            .with_smooth(0.0)
+            .init(&device);
+        let targets =
+            Tensor::<3>::from_floats([[[1.0, 0.0, 1.0, 0.0], [0.0, 1.0, 0.0, 1.0]]], &device);
+
+        let one_hot = loss.forward(predictions(), targets, Reduction::Mean);
+        let indices = loss.forward_indices(predictions(), targets(), Reduction::Mean);
+
+        one_hot
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1751094]), Tolerance::default());
+        one_hot
+            .into_data()
+            .assert_approx_eq::<FT>(&indices.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_dice_loss_perfect_predictions() {
+        let device = Default::default();
+        let loss = DiceLossConfig::new().init(&device);
+        let logits =
+            Tensor::<3>::from_floats([[[50.0, -50.0, 50.0], [-50.0, 50.0, -50.0]]], &device);
+        let targets = Tensor::<2, Int>::from_data(TensorData::from([[0, 1, 0]]), &device);
+
+        let loss = loss.forward_indices(logits, targets, Reduction::Mean);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let loss = DiceLossConfig::new().init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "DiceLoss {weights: None, smooth: 1, logits: true}"
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::activation::{log_sigmoid, log_softmax, logsumexp};
+use burn::tensor::{Device, Int, Tensor};
+use burn::{config::Config, module::Module};
+
+use super::{Reduction, mean_per_sample};
+
+/// Configuration to create a [focal loss](FocalLoss) using the [init function](FocalLossConfig::init).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter. The loss of each sample is scaled by `(1 - p_t)^gamma`, where `p_t`
+    /// is the predicted probability of its target, which down-weights the well-classified samples.
+    /// A gamma of 0 gives the cross-entropy.
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// The weight of the positive targets of the [binary](FocalLoss::forward_binary) loss, in
+    /// `(0, 1)`. The negative targets are weighted by `1 - alpha`.
+    pub alpha: Option<f32>,
+
+    /// The weight of each class of the [multi-class](FocalLoss::forward) loss.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [focal loss](FocalLoss).
+    pub fn init(&self, device: &Device) -> FocalLoss {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self.alpha,
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<1>::from_floats(e.as_slice(), device)),
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.0,
+            "The gamma of the focal loss must be non-negative. Got {}",
+            self.gamma
+        );
+        if let Some(alpha) = self.alpha {
+            assert!(
+                alpha > 0.0 && alpha < 1.0,
+                "The alpha of the focal loss must be in the interval (0, 1). Got {alpha}"
+            );
+        }
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of the focal loss have to be positive."
+            );
+        }
+    }
+}
+
+/// Calculate the focal loss from the input logits and the targets.
+///
+/// The focal loss is the cross-entropy scaled by `(1 - p_t)^gamma`, so that the training focuses
+/// on the hard, misclassified samples instead of the many easy ones, such as the background of
+/// dense object detection.
+///
+/// Both the log-probabilities and the modulating factor are computed from the logits in the log
+/// domain, so the loss and its gradient stay finite for saturated predictions.
+///
+/// Reference: "Focal Loss for Dense Object Detection" <https://arxiv.org/abs/1708.02002>
+///
+/// Should be created using [FocalLossConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct FocalLoss {
+    /// The focusing parameter.
+    pub gamma: f32,
+    /// The weight of the positive targets of the binary loss.
+    pub alpha: Option<f32>,
+    /// The weight of each class of the multi-class loss.
+    pub weights: Option<Tensor<1>>,
+}
+
+impl ModuleDisplay for FocalLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("gamma", &self.gamma)
+            .add("alpha", &self.alpha)
+            .add("weights", &self.weights)
+            .optional()
+    }
+}
+
+impl FocalLoss {
+    /// Compute the multi-class focal loss, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(
+        &self,
+        logits: Tensor<2>,
+        targets: Tensor<1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let loss = self.forward_no_reduction(logits, targets);
+        Self::reduce(loss, reduction)
+    }
+
+    /// Compute the multi-class focal loss of each sample.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+        let [batch_size, num_classes] = logits.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({targets_size}) should correspond to outer shape of logits ({batch_size})."
+        );
+        if let Some(weights) = &self.weights {
+            let weights_classes = weights.dims()[0];
+            assert!(
+                weights_classes == num_classes,
+                "The number of classes ({num_classes}) does not match the weights provided ({weights_classes})."
+            );
+        }
+
+        let log_probs = log_softmax(logits, 1);
+        let log_p = log_probs
+            .clone()
+            .gather(1, targets.clone().reshape([batch_size, 1]));
+
+        let mut loss = log_p.neg();
+        if self.gamma > 0.0 {
+            // log(1 - p_t) is the log-sum-exp of the log-probabilities of the other classes.
+            let mask = targets.clone().one_hot::<2>(num_classes).bool();
+            let log_not_p = logsumexp(log_probs.mask_fill(mask, f32::NEG_INFINITY), &[1]);
+            loss = loss * log_not_p.mul_scalar(self.gamma).exp();
+        }
+
+        let loss = loss.reshape([batch_size]);
+        match &self.weights {
+            Some(weights) => loss * weights.clone().gather(0, targets),
+            None => loss,
+        }
+    }
+
+    /// Compute the binary focal loss, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// Binary:
+    /// - logits: `[batch_size]`
+    /// - targets: `[batch_size]`
+    ///
+    /// Multi-label:
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size, num_classes]`
+    pub fn forward_binary<const D: usize>(
+        &self,
+        logits: Tensor<D>,
+        targets: Tensor<D, Int>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let loss = self.forward_binary_no_reduction(logits, targets);
+        Self::reduce(loss, reduction)
+    }
+
+    /// Compute the binary focal loss of each element, with targets in `{0, 1}`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[...dims]`
+    pub fn forward_binary_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<D>,
+        targets: Tensor<D, Int>,
+    ) -> Tensor<D> {
+        let logits_dims = logits.dims();
+        let targets_dims = targets.dims();
+        assert!(
+            logits_dims == targets_dims,
+            "Shape of targets ({targets_dims:?}) should correspond to the shape of logits ({logits_dims:?})."
+        );
+
+        // The logit of the target class: p_t = sigmoid(z) and 1 - p_t = sigmoid(-z).
+        let targets = targets.float();
+        let z = logits * targets.clone().mul_scalar(2.0).sub_scalar(1.0);
+
+        let mut loss = log_sigmoid(z.clone()).neg();
+        if self.gamma > 0.0 {
+            loss = loss * log_sigmoid(z.neg()).mul_scalar(self.gamma).exp();
+        }
+
+        match self.alpha {
+            // alpha * t + (1 - alpha) * (1 - t)
+            Some(alpha) => {
+                loss * targets
+                    .mul_scalar(2.0 * alpha - 1.0)
+                    .add_scalar(1.0 - alpha)
+            }
+            None => loss,
+        }
+    }
+
+    fn reduce<const D: usize>(loss: Tensor<D>, reduction: Reduction) -> Tensor<1> {
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => mean_per_sample(loss),
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loss::{BinaryCrossEntropyLossConfig, CrossEntropyLossConfig};
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn binary_inputs() -> (Tensor<1>, Tensor<1, Int>) {
+        let device = Default::default();
+        let logits = Tensor::<1>::from_floats([0.0, 2.0, -1.5, 3.0], &device);
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([1, 0, 0, 1]), &device);
+        (logits, targets)
+    }
+
+    fn multi_class_inputs() -> (Tensor<2>, Tensor<1, Int>) {
+        let device = Default::default();
+        let logits = Tensor::<2>::from_floats([[1.0, 2.0, 3.0], [0.5, -1.0, 2.0]], &device);
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([0, 2]), &device);
+        (logits, targets)
+    }
+
+    #[test]
+    fn test_binary_focal_loss() {
+        let (logits, targets) = binary_inputs();
+        let loss = FocalLossConfig::new().init(&Default::default());
+
+        let loss_elements = loss.forward_binary_no_reduction(logits.clone(), targets.clone());
+        let loss_mean = loss.forward_binary(logits.clone(), targets.clone(), Reduction::Mean);
+        let loss_alpha = FocalLossConfig::new()
+            .with_alpha(Some(0.25))
+            .init(&Default::default())
+            .forward_binary(logits, targets, Reduction::Mean);
+
+        loss_elements.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.1732868, 1.6500782, 0.0067028, 0.0001093]),
+            Tolerance::default(),
+        );
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.4575443]), Tolerance::default());
+        loss_alpha
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.3214837]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_binary_focal_loss_without_focusing_is_bce() {
+        let device = Default::default();
+        let (logits, targets) = binary_inputs();
+
+        let focal = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .init(&device)
+            .forward_binary(logits.clone(), targets.clone(), Reduction::Mean);
+        let bce = BinaryCrossEntropyLossConfig::new()
+            .with_logits(true)
+            .init(&device)
+            .forward(logits, targets);
+
+        focal
+            .into_data()
+            .assert_approx_eq::<FT>(&bce.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_multi_class_focal_loss() {
+        let (logits, targets) = multi_class_inputs();
+        let loss = FocalLossConfig::new().init(&Default::default());
+
+        let loss_elements = loss.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_weighted = FocalLossConfig::new()
+            .with_weights(Some(alloc::vec![0.5, 1.0, 2.0]))
+            .init(&Default::default())
+            .forward(logits, targets, Reduction::Sum);
+
+        loss_elements.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([1.9936045, 0.0110928]),
+            Tolerance::default(),
+        );
+        loss_weighted
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0189878]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_multi_class_focal_loss_without_focusing_is_cross_entropy() {
+        let device = Default::default();
+        let (logits, targets) = multi_class_inputs();
+
+        let focal = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .init(&device)
+            .forward(logits.clone(), targets.clone(), Reduction::Mean);
+        let cross_entropy = CrossEntropyLossConfig::new()
+            .init(&device)
+            .forward(logits, targets);
+
+        focal
+            .into_data()
+            .assert_approx_eq::<FT>(&cross_entropy.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_focal_loss_saturated_logits_are_finite() {
+        let device = Default::default();
+        let loss = FocalLossConfig::new().init(&device);
+        let logits = Tensor::<1>::from_floats([100.0, -100.0, 100.0], &device);
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([1, 0, 0]), &device);
+
+        let binary = loss.forward_binary_no_reduction(logits, targets);
+        let multi_class = loss.forward_no_reduction(
+            Tensor::<2>::from_floats([[100.0, -100.0], [-100.0, 100.0]], &device),
+            Tensor::<1, Int>::from_data(TensorData::from([0, 0]), &device),
+        );
+
+        binary
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 0.0, 100.0]), Tolerance::default());
+        multi_class
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 200.0]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_focal_loss_saturated_logits_gradients_are_finite() {
+        let device = Device::default().autodiff();
+        let logits =
+            Tensor::<2>::from_floats([[100.0, -100.0], [-100.0, 100.0]], &device).require_grad();
+        let targets = Tensor::<1, Int>::from_data(TensorData::from([0, 0]), &device);
+
+        let loss = FocalLossConfig::new().init(&device);
+        let grads = loss
+            .forward(logits.clone(), targets.clone(), Reduction::Sum)
+            .backward();
+        let grad = logits.grad(&grads).unwrap().into_data();
+
+        assert!(grad.iter::<f32>().all(f32::is_finite), "gradient: {grad}");
+    }
+
+    #[test]
+    fn display() {
+        let loss = FocalLossConfig::new()
+            .with_alpha(Some(0.25))
+            .init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "FocalLoss {gamma: 2, alpha: 0.25, weights: None}"
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::{Distribution, Tensor};
+use burn::{config::Config, module::Module};
+
+/// The form of a [gradient penalty](GradientPenalty).
+#[derive(Config, Debug, PartialEq)]
+pub enum GradientPenaltyKind {
+    /// The R1 penalty `E[||∇D(x)||²]`, evaluated on real samples. It keeps the discriminator
+    /// flat on the data distribution, which stabilizes the training of non-saturating GANs.
+    ///
+    /// Reference: "Which Training Methods for GANs do actually Converge?"
+    /// <https://arxiv.org/abs/1801.04406>
+    R1,
+    /// The WGAN-GP penalty `E[(||∇D(x̂)|| - 1)²]`, evaluated on random
+    /// [interpolations](GradientPenalty::interpolate) between real and generated samples. It
+    /// keeps the critic of a Wasserstein GAN close to 1-Lipschitz.
+    ///
+    /// Reference: "Improved Training of Wasserstein GANs" <https://arxiv.org/abs/1704.00028>
+    Wasserstein,
+}
+
+/// Configuration to create a [gradient penalty](GradientPenalty).
+#[derive(Config, Debug)]
+pub struct GradientPenaltyConfig {
+    /// The form of the penalty.
+    pub kind: GradientPenaltyKind,
+    /// The weight of the penalty. For the R1 penalty written `γ/2 * E[||∇D(x)||²]`, use `γ/2`.
+    #[config(default = 10.0)]
+    pub weight: f32,
+    /// The number of random directions used to estimate the squared gradient norm of each
+    /// sample.
+    #[config(default = 4)]
+    pub num_directions: usize,
+    /// The step of the finite differences.
+    #[config(default = 1e-2)]
+    pub epsilon: f32,
+}
+
+impl GradientPenaltyConfig {
+    /// Initialize a [gradient penalty](GradientPenalty).
+    pub fn init(&self) -> GradientPenalty {
+        assert!(
+            self.weight >= 0.0,
+            "The weight of the gradient penalty must be non-negative."
+        );
+        assert!(
+            self.num_directions > 0,
+            "The gradient penalty requires at least one direction."
+        );
+        assert!(
+            self.epsilon > 0.0,
+            "The finite difference step of the gradient penalty must be positive."
+        );
+
+        GradientPenalty {
+            kind: self.kind.clone(),
+            weight: self.weight,
+            num_directions: self.num_directions,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+/// Penalizes the gradient of a critic (or discriminator) with respect to its input.
+///
+/// The gradient norms are estimated from central finite differences of the critic along random
+/// Gaussian directions `v`, since `E[(v · ∇D(x))²] = ||∇D(x)||²`. The penalty is thus a
+/// first-order function of the critic parameters, and its gradient is computed by the regular
+/// backward pass, without differentiating through a backward pass. All the perturbed samples are
+/// evaluated in a single call of the critic.
+///
+/// A discriminator usually holds an optional penalty and implements
+/// [Regularizer](super::Regularizer) by evaluating it with its own forward pass:
+///
+/// ```rust,ignore
+/// impl Regularizer<Tensor<4>> for Discriminator {
+///     fn regularization(&self, real: &Tensor<4>) -> Option<Tensor<1>> {
+///         let penalty = self.gradient_penalty.as_ref()?;
+///         Some(penalty.forward(real.clone(), |x| self.forward(x)))
+///     }
+/// }
+/// ```
+///
+/// Should be created with [GradientPenaltyConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct GradientPenalty {
+    /// The form of the penalty.
+    #[module(skip)]
+    pub kind: GradientPenaltyKind,
+    /// The weight of the penalty.
+    pub weight: f32,
+    /// The number of random directions per sample.
+    pub num_directions: usize,
+    /// The step of the finite differences.
+    pub epsilon: f32,
+}
+
+impl ModuleDisplay for GradientPenalty {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add_debug_attribute("kind", &self.kind)
+            .add("weight", &self.weight)
+            .add("num_directions", &self.num_directions)
+            .optional()
+    }
+}
+
+impl GradientPenalty {
+    /// Compute the penalty of the critic at the given samples, averaged over the batch.
+    ///
+    /// The samples are detached: the penalty only depends on the critic parameters.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[batch_size, ...]`
+    /// - critic output: `[batch_size, 1]`
+    /// - output: `[1]`
+    pub fn forward<const D: usize, F>(&self, inputs: Tensor<D>, critic: F) -> Tensor<1>
+    where
+        F: Fn(Tensor<D>) -> Tensor<2>,
+    {
+        let squared_norm = self.squared_gradient_norm(inputs, critic);
+
+        let penalty = match self.kind {
+            GradientPenaltyKind::R1 => squared_norm,
+            GradientPenaltyKind::Wasserstein => {
+                // The offset keeps the gradient of the square root finite at zero.
+                squared_norm
+                    .add_scalar(1e-12)
+                    .sqrt()
+                    .sub_scalar(1.0)
+                    .square()
+            }
+        };
+
+        penalty.mean().mul_scalar(self.weight)
+    }
+
+    /// Estimate the squared norm of the gradient of the critic for each sample.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[batch_size, ...]`
+    /// - critic output: `[batch_size, 1]`
+    /// - output: `[batch_size]`
+    pub fn squared_gradient_norm<const D: usize, F>(
+        &self,
+        inputs: Tensor<D>,
+        critic: F,
+    ) -> Tensor<1>
+    where
+        F: Fn(Tensor<D>) -> Tensor<2>,
+    {
+        let batch_size = inputs.dims()[0];
+        let num_perturbed = batch_size * self.num_directions;
+
+        let inputs = inputs.detach().repeat_dim(0, self.num_directions);
+        let steps = inputs
+            .random_like(Distribution::Normal(0.0, 1.0))
+            .mul_scalar(self.epsilon);
+        let perturbed = Tensor::cat(
+            Vec::from([inputs.clone() + steps.clone(), inputs - steps]),
+            0,
+        );
+
+        let outputs = critic(perturbed).sum_dim(1).reshape([2 * num_perturbed]);
+        let forward = outputs.clone().slice(0..num_perturbed);
+        let backward = outputs.slice(num_perturbed..2 * num_perturbed);
+
+        // Directional derivatives v · ∇D(x), one row per direction.
+        let derivatives = (forward - backward)
+            .div_scalar(2.0 * self.epsilon)
+            .reshape([self.num_directions, batch_size]);
+
+        derivatives.square().mean_dim(0).reshape([batch_size])
+    }
+
+    /// Sample random interpolations `α * real + (1 - α) * fake`, with `α ~ U(0, 1)` for each
+    /// sample, where the [Wasserstein](GradientPenaltyKind::Wasserstein) penalty is evaluated.
+    ///
+    /// # Shapes
+    ///
+    /// - real: `[batch_size, ...]`
+    /// - fake: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn interpolate<const D: usize>(real: Tensor<D>, fake: Tensor<D>) -> Tensor<D> {
+        assert_eq!(
+            real.dims(),
+            fake.dims(),
+            "Shape of real and generated samples must match"
+        );
+        let mut shape = [1; D];
+        shape[0] = real.dims()[0];
+        let alpha = Tensor::<D>::random(shape, Distribution::Default, &real.device());
+
+        let real = real.detach();
+        let fake = fake.detach();
+        fake.clone() + (real - fake) * alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, LinearConfig};
+    use burn::module::Param;
+    use burn::tensor::{TensorData, Tolerance};
+
+    fn critic() -> Linear {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(4, 1).with_bias(false).init(&device);
+        linear.weight =
+            Param::from_tensor(Tensor::from_data([[1.0], [-2.0], [0.5], [2.0]], &device));
+        linear
+    }
+
+    #[test]
+    fn test_r1_penalty_of_linear_critic() {
+        // The gradient of a linear critic is its weight: ||w||² = 1 + 4 + 0.25 + 4.
+        let critic = critic();
+        let penalty = GradientPenaltyConfig::new(GradientPenaltyKind::R1)
+            .with_weight(1.0)
+            .with_num_directions(4096)
+            .init();
+        let inputs = Tensor::<2>::random([2, 4], Distribution::Default, &Default::default());
+
+        let penalty = penalty
+            .forward(inputs, |x| critic.forward(x))
+            .into_scalar::<f32>();
+
+        assert!((penalty - 9.25).abs() < 0.9, "penalty: {penalty}");
+    }
+
+    #[test]
+    fn test_wasserstein_penalty_of_unit_critic() {
+        // A linear critic with a unit gradient has no penalty.
+        let device = Default::default();
+        let mut critic = LinearConfig::new(1, 1).with_bias(false).init(&device);
+        critic.weight = Param::from_tensor(Tensor::from_data([[1.0]], &device));
+        let penalty = GradientPenaltyConfig::new(GradientPenaltyKind::Wasserstein)
+            .with_num_directions(4096)
+            .init();
+
+        let real = Tensor::<2>::random([3, 1], Distribution::Default, &device);
+        let fake = Tensor::<2>::random([3, 1], Distribution::Default, &device);
+        let inputs = GradientPenalty::interpolate(real, fake);
+        let penalty = penalty.forward(inputs, |x| critic.forward(x));
+
+        penalty
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([0.0]), Tolerance::absolute(5e-2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_gradient_penalty_backward() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let critic = LinearConfig::new(4, 1).init(&device);
+        let penalty = GradientPenaltyConfig::new(GradientPenaltyKind::R1).init();
+        let inputs = Tensor::<2>::random([2, 4], Distribution::Default, &device);
+
+        let grads = penalty.forward(inputs, |x| critic.forward(x)).backward();
+
+        // d(w · v)² / dw = 2 (w · v) v
+        assert!(critic.weight.val().grad(&grads).is_some());
+    }
+
+    #[test]
+    fn display() {
+        let penalty = GradientPenaltyConfig::new(GradientPenaltyKind::R1).init();
+
+        assert_eq!(
+            alloc::format!("{penalty}"),
+            "GradientPenalty {kind: R1, weight: 10, num_directions: 4}"
+        );
+    }
+}
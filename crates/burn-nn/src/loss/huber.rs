@@ -4,7 +4,7 @@ use burn::module::{Content, DisplaySettings, ModuleDisplay};
 use burn::tensor::Tensor;
 use burn::{config::Config, module::Module};
 
-use super::Reduction;
+use super::{Reduction, mean_per_sample};
 
 /// Configuration to create a [Huber loss](HuberLoss).
 #[derive(Config, Debug)]
@@ -81,7 +81,7 @@ impl HuberLoss {
     ///
     /// - predictions: \[...dims\]
     /// - targets: \[...dims\]
-    /// - output: \[1\]
+    /// - output: \[1\], or \[batch_size\] with `Reduction::None`
     pub fn forward<const D: usize>(
         &self,
         predictions: Tensor<D>,
@@ -92,6 +92,7 @@ impl HuberLoss {
         match reduction {
             Reduction::Mean | Reduction::Auto => loss.mean(),
             Reduction::Sum => loss.sum(),
+            Reduction::None => mean_per_sample(loss),
             other => panic!("{other:?} reduction is not supported"),
         }
     }
@@ -0,0 +1,293 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::activation::logsumexp;
+use burn::tensor::linalg::{Norm, vector_normalize};
+use burn::tensor::{Bool, Int, Tensor};
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create an [InfoNCE loss](InfoNceLoss) using the [init function](InfoNceLossConfig::init).
+#[derive(Config, Debug)]
+pub struct InfoNceLossConfig {
+    /// The temperature dividing the similarities. Lower temperatures focus the loss on the
+    /// hardest negatives.
+    #[config(default = 0.1)]
+    pub temperature: f32,
+
+    /// Normalize the embeddings, so that the similarities are cosine similarities instead of dot
+    /// products.
+    #[config(default = true)]
+    pub normalize: bool,
+
+    /// Average the loss of the queries against the keys with the loss of the keys against the
+    /// queries, as in CLIP.
+    #[config(default = false)]
+    pub symmetric: bool,
+}
+
+impl InfoNceLossConfig {
+    /// Initialize [InfoNCE loss](InfoNceLoss).
+    pub fn init(&self) -> InfoNceLoss {
+        assert!(
+            self.temperature > 0.0,
+            "The temperature of the InfoNCE loss must be positive. Got {}",
+            self.temperature
+        );
+        InfoNceLoss {
+            temperature: self.temperature,
+            normalize: self.normalize,
+            symmetric: self.symmetric,
+        }
+    }
+}
+
+/// Calculate the InfoNCE contrastive loss with in-batch negatives: each query must identify its
+/// own key among all the keys of the batch, through a cross-entropy over the similarities.
+///
+/// [forward_nt_xent](InfoNceLoss::forward_nt_xent) computes the NT-Xent variant of SimCLR,
+/// where each of the two views of a sample must identify the other view among all the other
+/// embeddings of both views.
+///
+/// References:
+/// - "Representation Learning with Contrastive Predictive Coding" <https://arxiv.org/abs/1807.03748>
+/// - "A Simple Framework for Contrastive Learning of Visual Representations" <https://arxiv.org/abs/2002.05709>
+///
+/// Should be created using [InfoNceLossConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct InfoNceLoss {
+    /// The temperature dividing the similarities.
+    pub temperature: f32,
+    /// Normalize the embeddings.
+    pub normalize: bool,
+    /// Average the loss of both directions.
+    pub symmetric: bool,
+}
+
+impl ModuleDisplay for InfoNceLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("temperature", &self.temperature)
+            .add("normalize", &self.normalize)
+            .add("symmetric", &self.symmetric)
+            .optional()
+    }
+}
+
+impl InfoNceLoss {
+    /// Compute the loss of each query, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, d_model]`
+    /// - keys: `[batch_size, d_model]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(&self, queries: Tensor<2>, keys: Tensor<2>, reduction: Reduction) -> Tensor<1> {
+        reduce(self.forward_no_reduction(queries, keys), reduction)
+    }
+
+    /// Compute the loss of each query, whose positive is the key of the same index.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, d_model]`
+    /// - keys: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, queries: Tensor<2>, keys: Tensor<2>) -> Tensor<1> {
+        let [batch_size, _] = queries.dims();
+        assert!(
+            queries.dims() == keys.dims(),
+            "Shapes of queries ({:?}) and keys ({:?}) must match.",
+            queries.dims(),
+            keys.dims()
+        );
+
+        let logits = self.logits(queries, keys);
+        let targets = Tensor::<1, Int>::arange(0..batch_size as i64, &logits.device());
+
+        let loss = cross_entropy(logits.clone(), targets.clone());
+        if self.symmetric {
+            (loss + cross_entropy(logits.transpose(), targets)).div_scalar(2.0)
+        } else {
+            loss
+        }
+    }
+
+    /// Compute the NT-Xent loss of each view, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - first: `[batch_size, d_model]`
+    /// - second: `[batch_size, d_model]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward_nt_xent(
+        &self,
+        first: Tensor<2>,
+        second: Tensor<2>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        reduce(self.forward_nt_xent_no_reduction(first, second), reduction)
+    }
+
+    /// Compute the NT-Xent loss of each view: the embeddings of the first views followed by those
+    /// of the second views. The positive of each embedding is the other view of its sample, and
+    /// the negatives are all the other embeddings of both views.
+    ///
+    /// The loss is always symmetric, so [symmetric](InfoNceLoss::symmetric) is ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - first: `[batch_size, d_model]`
+    /// - second: `[batch_size, d_model]`
+    /// - output: `[2 * batch_size]`
+    pub fn forward_nt_xent_no_reduction(&self, first: Tensor<2>, second: Tensor<2>) -> Tensor<1> {
+        let [batch_size, _] = first.dims();
+        assert!(
+            first.dims() == second.dims(),
+            "Shapes of the first ({:?}) and second ({:?}) views must match.",
+            first.dims(),
+            second.dims()
+        );
+        let num_views = 2 * batch_size;
+
+        let embeddings = Tensor::cat(Vec::from([first, second]), 0);
+        let device = embeddings.device();
+        let self_mask = Tensor::<2, Bool>::diag_mask([num_views, num_views], 0, &device).bool_not();
+        let logits = self
+            .logits(embeddings.clone(), embeddings)
+            .mask_fill(self_mask, f32::NEG_INFINITY);
+
+        let targets = Tensor::<1, Int>::arange(0..num_views as i64, &device)
+            .add_scalar(batch_size as i64)
+            .remainder_scalar(num_views as i64);
+
+        cross_entropy(logits, targets)
+    }
+
+    fn logits(&self, queries: Tensor<2>, keys: Tensor<2>) -> Tensor<2> {
+        let (queries, keys) = if self.normalize {
+            (
+                vector_normalize(queries, Norm::L2, 1, 1e-12),
+                vector_normalize(keys, Norm::L2, 1, 1e-12),
+            )
+        } else {
+            (queries, keys)
+        };
+
+        queries
+            .matmul(keys.transpose())
+            .div_scalar(self.temperature)
+    }
+}
+
+/// The cross-entropy of each row of logits with its target column.
+fn cross_entropy(logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+    let [num_rows, _] = logits.dims();
+    let positives = logits.clone().gather(1, targets.reshape([num_rows, 1]));
+
+    (logsumexp(logits, &[1]) - positives).reshape([num_rows])
+}
+
+fn reduce(loss: Tensor<1>, reduction: Reduction) -> Tensor<1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+        Reduction::None => loss,
+        other => panic!("{other:?} reduction is not supported"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn embeddings() -> (Tensor<2>, Tensor<2>) {
+        let device = Default::default();
+        (
+            Tensor::<2>::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]], &device),
+            Tensor::<2>::from_floats([[1.0, 0.0], [1.0, 1.0], [0.0, 1.0]], &device),
+        )
+    }
+
+    #[test]
+    fn test_info_nce_loss() {
+        let (queries, keys) = embeddings();
+        let loss = InfoNceLossConfig::new().with_temperature(0.5).init();
+
+        let output = loss.forward_no_reduction(queries.clone(), keys.clone());
+        let symmetric = InfoNceLossConfig::new()
+            .with_temperature(0.5)
+            .with_symmetric(true)
+            .init()
+            .forward_no_reduction(queries, keys);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.5259131, 1.1116996, 1.3340541]),
+            Tolerance::default(),
+        );
+        symmetric.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.5259131, 1.2228768, 1.2228768]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_nt_xent_loss() {
+        let (first, second) = embeddings();
+        let loss = InfoNceLossConfig::new().with_temperature(0.5).init();
+
+        let output = loss.forward_nt_xent_no_reduction(first.clone(), second.clone());
+        let mean = loss.forward_nt_xent(first, second, Reduction::Mean);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                0.8687824, 1.4545689, 1.7572376, 0.8687824, 1.7572376, 1.4545689,
+            ]),
+            Tolerance::default(),
+        );
+        mean.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.3601963]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_nt_xent_loss_gradients_are_finite() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let first = Tensor::<2>::from_floats([[1.0, 0.0], [0.0, 1.0]], &device).require_grad();
+        let second = Tensor::<2>::from_floats([[1.0, 1.0], [0.0, 2.0]], &device);
+
+        let grads = InfoNceLossConfig::new()
+            .init()
+            .forward_nt_xent(first.clone(), second, Reduction::Mean)
+            .backward();
+        let grad = first.grad(&grads).unwrap().into_data();
+
+        assert!(grad.iter::<f32>().all(f32::is_finite), "gradient: {grad}");
+    }
+
+    #[test]
+    fn display() {
+        let loss = InfoNceLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "InfoNceLoss {temperature: 0.1, normalize: true, symmetric: false}"
+        );
+    }
+}
@@ -58,12 +58,13 @@ impl KLDivLoss {
     /// Compute the criterion on the input tensor.
     ///
     /// `Reduction::Auto` behaves as `Reduction::BatchMean`,`Reduction::Mean` dose not align with the math definition.
+    /// `Reduction::None` returns the divergence of each sample, summed over the other dimensions.
     ///
     /// # Shapes
     ///
     /// - predictions: \[batch_size,num_targets\]
     /// - targets: \[batch_size,num_targets\]
-    /// - output: \[1\]
+    /// - output: \[1\], or \[batch_size\] with `Reduction::None`
     pub fn forward<const D: usize>(
         &self,
         predictions: Tensor<D>,
@@ -78,6 +79,12 @@ impl KLDivLoss {
             }
             Reduction::Mean => loss.mean(),
             Reduction::Sum => loss.sum(),
+            Reduction::None => {
+                let batch_size = loss.dims()[0];
+                loss.reshape([batch_size as i32, -1])
+                    .sum_dim(1)
+                    .reshape([batch_size])
+            }
         }
     }
     /// Compute the criterion on the input tensor without reducing.
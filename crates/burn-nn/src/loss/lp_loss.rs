@@ -1,4 +1,4 @@
-use super::Reduction;
+use super::{Reduction, mean_per_sample};
 use burn::config::Config;
 use burn::module::Module;
 use burn::tensor::Tensor;
@@ -127,6 +127,7 @@ impl LpLoss {
     /// * `reduction` - Specifies how to reduce the element-wise losses:
     ///   - `Reduction::Mean` or `Reduction::Auto`: Returns the mean of all element-wise losses.
     ///   - `Reduction::Sum`: Returns the sum of all element-wise losses.
+    ///   - `Reduction::None`: Returns the mean of the element-wise losses of each sample.
     ///
     /// # Returns
     ///
@@ -136,7 +137,7 @@ impl LpLoss {
     ///
     /// - predictions: `[...dims]` - Any shape
     /// - targets: `[...dims]` - Must match predictions shape
-    /// - output: `[1]` - Scalar loss value
+    /// - output: `[1]` - Scalar loss value, or `[batch_size]` with `Reduction::None`
     pub fn forward<const D: usize>(
         &self,
         predictions: Tensor<D>,
@@ -148,6 +149,7 @@ impl LpLoss {
         match reduction {
             Reduction::Mean | Reduction::Auto => unreduced_loss.mean(),
             Reduction::Sum => unreduced_loss.sum(),
+            Reduction::None => mean_per_sample(unreduced_loss),
             other => panic!("{other:?} reduction is not supported"),
         }
     }
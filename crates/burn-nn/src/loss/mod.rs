@@ -2,24 +2,46 @@ mod binary_cross_entropy;
 mod cosine_embedding;
 mod cross_entropy;
 mod ctc;
+mod dice;
+mod focal;
+mod gradient_penalty;
 mod huber;
+mod info_nce;
 mod kldiv;
 mod lp_loss;
 mod mse;
+mod param_penalty;
+mod perceptual;
 mod poisson;
 mod reduction;
+mod regularizer;
 mod rnnt;
 mod smooth_l1;
+mod ssim;
+mod total_variation;
+mod triplet;
+mod tversky;
 
 pub use binary_cross_entropy::*;
 pub use cosine_embedding::*;
 pub use cross_entropy::*;
 pub use ctc::*;
+pub use dice::*;
+pub use focal::*;
+pub use gradient_penalty::*;
 pub use huber::*;
+pub use info_nce::*;
 pub use kldiv::*;
 pub use lp_loss::*;
 pub use mse::*;
+pub use param_penalty::*;
+pub use perceptual::*;
 pub use poisson::*;
 pub use reduction::*;
+pub use regularizer::*;
 pub use rnnt::*;
 pub use smooth_l1::*;
+pub use ssim::*;
+pub use total_variation::*;
+pub use triplet::*;
+pub use tversky::*;
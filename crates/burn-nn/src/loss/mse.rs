@@ -1,6 +1,6 @@
 use burn_core as burn;
 
-use crate::loss::reduction::Reduction;
+use crate::loss::reduction::{Reduction, mean_per_sample};
 
 use burn::module::Module;
 use burn::tensor::Tensor;
@@ -37,6 +37,7 @@ impl MseLoss {
         match reduction {
             Reduction::Mean | Reduction::Auto => tensor.mean(),
             Reduction::Sum => tensor.sum(),
+            Reduction::None => mean_per_sample(tensor),
             other => panic!("{other:?} reduction is not supported"),
         }
     }
@@ -66,7 +67,8 @@ mod tests {
         let mse = MseLoss::new();
         let loss_no_reduction = mse.forward_no_reduction(logits.clone(), targets.clone());
         let loss = mse.forward(logits.clone(), targets.clone(), Reduction::Auto);
-        let loss_sum = mse.forward(logits, targets, Reduction::Sum);
+        let loss_sum = mse.forward(logits.clone(), targets.clone(), Reduction::Sum);
+        let loss_per_sample = mse.forward(logits, targets, Reduction::None);
 
         let expected = TensorData::from([[1.0, 1.0], [0.0, 4.0]]);
         loss_no_reduction.into_data().assert_eq(&expected, false);
@@ -76,6 +78,9 @@ mod tests {
 
         let expected = TensorData::from([6.0]);
         loss_sum.into_data().assert_eq(&expected, false);
+
+        let expected = TensorData::from([1.0, 2.0]);
+        loss_per_sample.into_data().assert_eq(&expected, false);
     }
 
     #[test]
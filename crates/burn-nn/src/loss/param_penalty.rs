@@ -0,0 +1,251 @@
+use burn_core as burn;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use burn::config::Config;
+use burn::module::{Module, ModuleVisitor, Param, path_matches};
+use burn::tensor::Tensor;
+
+use super::{Regularizer, regularization_sum};
+
+/// A penalty on the values of a parameter.
+#[derive(Config, Debug, PartialEq)]
+pub enum ParamPenaltyKind {
+    /// The sum of the absolute values, which drives parameters to exactly zero.
+    L1,
+    /// The sum of the squared values. For adaptive optimizers, prefer the decoupled weight decay
+    /// of the optimizer, which isn't rescaled by the gradient statistics.
+    L2,
+    /// The sum of the L2 norms of the slices along `dim`, each scaled by the square root of its
+    /// size, which drives whole groups, such as the output units of a layer, to zero.
+    GroupLasso {
+        /// The dimension indexing the groups.
+        dim: usize,
+    },
+    /// The squared Frobenius distance between the Gram matrix of the parameter and the identity,
+    /// `||W Wᵗ - I||²`, where `W` has one row per index of the first dimension, or `||Wᵗ W - I||²`
+    /// when it has more rows than columns. Parameters with a single dimension are skipped.
+    Orthogonality,
+}
+
+/// A penalty applied to the parameters whose path matches a pattern.
+#[derive(Config, Debug)]
+pub struct ParamPenaltyConfig {
+    /// The pattern of the parameter paths, such as `encoder.*.weight`, where `*` matches any
+    /// sequence of characters. See [list_param_ids_matching](burn::module::list_param_ids_matching).
+    pub pattern: String,
+    /// The penalty.
+    pub kind: ParamPenaltyKind,
+    /// The weight of the penalty.
+    #[config(default = 1e-4)]
+    pub weight: f32,
+}
+
+/// Configuration to create a [parameter regularizer](ParamRegularizer).
+#[derive(Config, Debug)]
+pub struct ParamRegularizerConfig {
+    /// The penalties. A parameter matching several patterns gets all of their penalties.
+    pub penalties: Vec<ParamPenaltyConfig>,
+}
+
+impl ParamRegularizerConfig {
+    /// Initialize a [parameter regularizer](ParamRegularizer).
+    pub fn init(&self) -> ParamRegularizer {
+        for penalty in &self.penalties {
+            assert!(
+                penalty.weight >= 0.0,
+                "The weight of the penalty of `{}` must be non-negative.",
+                penalty.pattern
+            );
+        }
+
+        ParamRegularizer {
+            penalties: self.penalties.clone(),
+        }
+    }
+}
+
+/// Penalizes the parameters of a module, selected by their path in the module tree.
+///
+/// As a [regularizer](Regularizer) of a module, it traverses the module and sums the weighted
+/// penalties of the matching float parameters, which the training step adds to its loss:
+///
+/// ```rust,ignore
+/// let regularizer = ParamRegularizerConfig::new(vec![
+///     ParamPenaltyConfig::new("*.weight".into(), ParamPenaltyKind::L1),
+///     ParamPenaltyConfig::new("encoder.*.weight".into(), ParamPenaltyKind::Orthogonality),
+/// ])
+/// .init();
+///
+/// let loss = loss + regularizer.regularization(&model).unwrap();
+/// ```
+///
+/// Should be created with [ParamRegularizerConfig].
+#[derive(Clone, Debug)]
+pub struct ParamRegularizer {
+    penalties: Vec<ParamPenaltyConfig>,
+}
+
+impl<M: Module> Regularizer<M> for ParamRegularizer {
+    fn regularization(&self, module: &M) -> Option<Tensor<1>> {
+        let mut visitor = ParamPenaltyVisitor {
+            penalties: &self.penalties,
+            path: Vec::new(),
+            terms: Vec::new(),
+        };
+        module.visit(&mut visitor);
+
+        regularization_sum(visitor.terms.into_iter().map(Some))
+    }
+}
+
+struct ParamPenaltyVisitor<'a> {
+    penalties: &'a [ParamPenaltyConfig],
+    path: Vec<String>,
+    terms: Vec<Tensor<1>>,
+}
+
+impl ModuleVisitor for ParamPenaltyVisitor<'_> {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let path = self.path.join(".");
+        for penalty in self.penalties {
+            if !path_matches(&penalty.pattern, &path) {
+                continue;
+            }
+            if let Some(term) = param_penalty(&penalty.kind, param.val()) {
+                self.terms.push(term.mul_scalar(penalty.weight));
+            }
+        }
+    }
+}
+
+fn param_penalty<const D: usize>(kind: &ParamPenaltyKind, tensor: Tensor<D>) -> Option<Tensor<1>> {
+    match kind {
+        ParamPenaltyKind::L1 => Some(tensor.abs().sum()),
+        ParamPenaltyKind::L2 => Some(tensor.square().sum()),
+        ParamPenaltyKind::GroupLasso { dim } => {
+            assert!(
+                *dim < D,
+                "Group lasso dimension {dim} is out of range for a parameter with {D} dimensions"
+            );
+            let num_groups = tensor.dims()[*dim];
+            let group_size = tensor.shape().num_elements() / num_groups;
+            let groups = tensor.swap_dims(0, *dim).reshape([num_groups as i32, -1]);
+
+            Some(
+                groups
+                    .square()
+                    .sum_dim(1)
+                    .sqrt()
+                    .sum()
+                    .mul_scalar((group_size as f32).sqrt()),
+            )
+        }
+        ParamPenaltyKind::Orthogonality => {
+            if D < 2 {
+                return None;
+            }
+            let rows = tensor.dims()[0];
+            let matrix = tensor.reshape([rows as i32, -1]);
+            let cols = matrix.dims()[1];
+
+            let gram = if rows <= cols {
+                matrix.clone().matmul(matrix.transpose())
+            } else {
+                matrix.clone().transpose().matmul(matrix)
+            };
+            let size = rows.min(cols);
+            let identity = Tensor::<2>::eye(size, &gram.device());
+
+            Some((gram - identity).square().sum())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, LinearConfig};
+    use burn::tensor::{TensorData, Tolerance};
+
+    #[derive(Module, Debug)]
+    struct Model {
+        encoder: Linear,
+        decoder: Linear,
+    }
+
+    fn model() -> Model {
+        let device = Default::default();
+        let linear = |weight: [[f32; 2]; 2]| {
+            let mut linear = LinearConfig::new(2, 2).init(&device);
+            linear.weight = Param::from_tensor(Tensor::from_data(weight, &device));
+            linear.bias = Some(Param::from_tensor(Tensor::from_data([1.0, -1.0], &device)));
+            linear
+        };
+
+        Model {
+            encoder: linear([[1.0, -2.0], [0.0, 3.0]]),
+            decoder: linear([[1.0, 0.0], [0.0, 1.0]]),
+        }
+    }
+
+    fn regularization(penalties: Vec<ParamPenaltyConfig>) -> f32 {
+        ParamRegularizerConfig::new(penalties)
+            .init()
+            .regularization(&model())
+            .unwrap()
+            .into_scalar::<f32>()
+    }
+
+    #[test]
+    fn test_param_penalties_by_path() {
+        let l1 = |pattern: &str| {
+            ParamPenaltyConfig::new(pattern.to_string(), ParamPenaltyKind::L1).with_weight(1.0)
+        };
+
+        // Encoder weight: 6, decoder weight: 2, each bias: 2.
+        assert_eq!(regularization(Vec::from([l1("encoder.weight")])), 6.0);
+        assert_eq!(regularization(Vec::from([l1("*.weight")])), 8.0);
+        assert_eq!(
+            regularization(Vec::from([l1("*.bias"), l1("encoder.*")])),
+            12.0
+        );
+    }
+
+    #[test]
+    fn test_group_lasso_and_orthogonality_penalties() {
+        let penalty = |kind| ParamPenaltyConfig::new("*.weight".to_string(), kind).with_weight(1.0);
+
+        // Encoder rows: sqrt(5) + 3, decoder rows: 1 + 1, scaled by sqrt(2).
+        let group_lasso = regularization(Vec::from([penalty(ParamPenaltyKind::GroupLasso {
+            dim: 0,
+        })]));
+        let expected = (5f32.sqrt() + 5.0) * 2f32.sqrt();
+        TensorData::from([group_lasso])
+            .assert_approx_eq::<f32>(&TensorData::from([expected]), Tolerance::default());
+
+        // The decoder weight is orthogonal, the encoder Gram matrix is [[5, -6], [-6, 9]].
+        let orthogonality = regularization(Vec::from([penalty(ParamPenaltyKind::Orthogonality)]));
+        assert_eq!(orthogonality, 16.0 + 36.0 + 36.0 + 64.0);
+    }
+
+    #[test]
+    fn test_no_matching_params() {
+        let regularizer = ParamRegularizerConfig::new(Vec::from([ParamPenaltyConfig::new(
+            "missing.*".to_string(),
+            ParamPenaltyKind::L2,
+        )]))
+        .init();
+
+        assert!(regularizer.regularization(&model()).is_none());
+    }
+}
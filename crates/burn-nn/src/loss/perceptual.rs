@@ -0,0 +1,248 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+
+use burn::tensor::Tensor;
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [perceptual loss](PerceptualLoss) using the
+/// [init function](PerceptualLossConfig::init).
+#[derive(Config, Debug)]
+pub struct PerceptualLossConfig {
+    /// The weight of each feature map returned by the extractor. When `None`, every feature map
+    /// has a weight of 1.
+    #[config(default = "None")]
+    pub layer_weights: Option<Vec<f32>>,
+    /// Whether the features of each pixel are normalized to unit length over the channels before
+    /// being compared, as done by LPIPS.
+    #[config(default = true)]
+    pub normalize: bool,
+}
+
+impl PerceptualLossConfig {
+    /// Initialize a [perceptual loss](PerceptualLoss) around a feature extractor, such as a
+    /// pretrained classification network. The parameters of the extractor are frozen.
+    pub fn init<M: Module>(&self, extractor: M) -> PerceptualLoss<M> {
+        if let Some(weights) = &self.layer_weights {
+            assert!(
+                weights.iter().all(|weight| *weight >= 0.0),
+                "The layer weights of the perceptual loss must be non-negative."
+            );
+        }
+
+        PerceptualLoss {
+            extractor: extractor.no_grad(),
+            layer_weights: self.layer_weights.clone(),
+            normalize: self.normalize,
+        }
+    }
+}
+
+/// Calculate the perceptual loss between the predictions and the targets: the weighted mean
+/// squared distance between the feature maps that a frozen feature extractor computes for both
+/// images.
+///
+/// Comparing deep features instead of pixels penalizes the differences that are visible to a
+/// human, such as blurry textures, rather than small misalignments, which makes it a common loss
+/// for super-resolution and image reconstruction. With normalized features, the loss matches
+/// LPIPS with uniform channel weights.
+///
+/// The extractor is frozen when the loss is created, so the gradients only flow to the
+/// predictions.
+///
+/// Reference: "The Unreasonable Effectiveness of Deep Features as a Perceptual Metric"
+/// <https://arxiv.org/abs/1801.03924>
+///
+/// Should be created with [PerceptualLossConfig].
+#[derive(Module, Debug)]
+pub struct PerceptualLoss<M> {
+    /// The frozen feature extractor.
+    pub extractor: M,
+    /// The weight of each feature map.
+    pub layer_weights: Option<Vec<f32>>,
+    /// Whether the features are normalized over the channels.
+    pub normalize: bool,
+}
+
+impl<M: Module> PerceptualLoss<M> {
+    /// Compute the loss of each image, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - Returns the feature maps of the extractor for a batch of images, usually
+    ///   the activations of a few of its layers.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward<F>(
+        &self,
+        predictions: Tensor<4>,
+        targets: Tensor<4>,
+        reduction: Reduction,
+        features: F,
+    ) -> Tensor<1>
+    where
+        F: Fn(&M, Tensor<4>) -> Vec<Tensor<4>>,
+    {
+        let loss = self.forward_no_reduction(predictions, targets, features);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+
+    /// Compute the loss of each image, summing the weighted distances of the feature maps, each
+    /// averaged over the pixels.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<F>(
+        &self,
+        predictions: Tensor<4>,
+        targets: Tensor<4>,
+        features: F,
+    ) -> Tensor<1>
+    where
+        F: Fn(&M, Tensor<4>) -> Vec<Tensor<4>>,
+    {
+        assert_eq!(
+            predictions.dims(),
+            targets.dims(),
+            "Shape of predictions and targets must match"
+        );
+        let [batch_size, ..] = predictions.dims();
+
+        let predicted = features(&self.extractor, predictions);
+        let expected = features(&self.extractor, targets.detach());
+        assert_eq!(
+            predicted.len(),
+            expected.len(),
+            "The extractor must return the same number of feature maps for every input"
+        );
+        if let Some(weights) = &self.layer_weights {
+            assert_eq!(
+                weights.len(),
+                predicted.len(),
+                "Expected {} layer weights, got {} feature maps",
+                weights.len(),
+                predicted.len()
+            );
+        }
+
+        let mut loss: Option<Tensor<1>> = None;
+        for (layer, (predicted, expected)) in predicted.into_iter().zip(expected).enumerate() {
+            let (predicted, expected) = if self.normalize {
+                (normalize(predicted), normalize(expected.detach()))
+            } else {
+                (predicted, expected.detach())
+            };
+
+            // Squared distance over the channels, averaged over the pixels.
+            let distance = (predicted - expected)
+                .square()
+                .sum_dim(1)
+                .mean_dims(&[2, 3])
+                .reshape([batch_size]);
+            let distance = match &self.layer_weights {
+                Some(weights) => distance.mul_scalar(weights[layer]),
+                None => distance,
+            };
+
+            loss = Some(match loss {
+                Some(loss) => loss + distance,
+                None => distance,
+            });
+        }
+
+        loss.expect("The extractor must return at least one feature map")
+    }
+}
+
+/// Normalizes the features of each pixel to unit length over the channels.
+fn normalize(features: Tensor<4>) -> Tensor<4> {
+    let norm = features
+        .clone()
+        .square()
+        .sum_dim(1)
+        .sqrt()
+        .add_scalar(1e-10);
+    features / norm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, LinearConfig};
+    use burn::tensor::{Distribution, TensorData, Tolerance};
+    type FT = f32;
+
+    /// Two feature maps: the raw image and a 1x1 projection of its channels.
+    fn features(extractor: &Linear, input: Tensor<4>) -> Vec<Tensor<4>> {
+        let projected = extractor
+            .forward(input.clone().swap_dims(1, 3))
+            .swap_dims(1, 3);
+        Vec::from([input, projected])
+    }
+
+    #[test]
+    fn test_perceptual_loss_identical_images() {
+        let device = Default::default();
+        let extractor = LinearConfig::new(3, 4).init(&device);
+        let loss = PerceptualLossConfig::new().init(extractor);
+        let images = Tensor::<4>::random([2, 3, 4, 4], Distribution::Default, &device);
+
+        let loss = loss.forward_no_reduction(images.clone(), images, features);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_perceptual_loss_unnormalized_weighted() {
+        let device = Default::default();
+        let extractor = LinearConfig::new(1, 1).with_bias(false).init(&device);
+        let loss = PerceptualLossConfig::new()
+            .with_normalize(false)
+            .with_layer_weights(Some(Vec::from([1.0, 0.0])))
+            .init(extractor);
+        let predictions = Tensor::<4>::full([1, 1, 2, 2], 0.5, &device);
+        let targets = Tensor::<4>::zeros([1, 1, 2, 2], &device);
+
+        // Only the raw image is compared: (0.5 - 0)^2
+        let loss = loss.forward(predictions, targets, Reduction::Sum, features);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.25]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_perceptual_loss_frozen_extractor() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let extractor = LinearConfig::new(3, 4).init(&device);
+        let loss = PerceptualLossConfig::new().init(extractor);
+        let targets = Tensor::<4>::random([1, 3, 4, 4], Distribution::Default, &device);
+        let predictions =
+            Tensor::<4>::random([1, 3, 4, 4], Distribution::Default, &device).require_grad();
+
+        let grads = loss
+            .forward(predictions.clone(), targets, Reduction::Mean, features)
+            .backward();
+
+        assert!(predictions.grad(&grads).is_some());
+        assert!(loss.extractor.weight.val().grad(&grads).is_none());
+    }
+}
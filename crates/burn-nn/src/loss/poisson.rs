@@ -5,7 +5,7 @@ use burn::module::{Content, DisplaySettings, ModuleDisplay};
 use burn::tensor::Tensor;
 use burn::{config::Config, module::Module};
 
-use super::Reduction;
+use super::{Reduction, mean_per_sample};
 
 /// Configuration for creating a [PoissonNllLoss](PoissonNllLoss) instance.
 ///
@@ -136,6 +136,7 @@ impl PoissonNllLoss {
         match reduction {
             Reduction::Mean | Reduction::Auto => loss.mean(),
             Reduction::Sum => loss.sum(),
+            Reduction::None => mean_per_sample(loss),
             other => panic!("{other:?} reduction is not supported"),
         }
     }
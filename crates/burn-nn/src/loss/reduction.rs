@@ -1,6 +1,7 @@
 use burn_core as burn;
 
 use burn::config::Config;
+use burn::tensor::Tensor;
 
 /// The reduction type for the loss.
 #[derive(Config, Debug)]
@@ -16,4 +17,20 @@ pub enum Reduction {
 
     /// The mean of the losses will be returned.
     Auto,
+
+    /// The loss of each sample will be returned, with shape `[batch_size]`.
+    ///
+    /// Unless stated otherwise by the loss, the element-wise losses of a sample are averaged over
+    /// the dimensions after the batch dimension, so that the mean of the per-sample losses is the
+    /// [mean](Reduction::Mean) loss.
+    None,
+}
+
+/// The mean of the element-wise losses of each sample, over the dimensions after the first one.
+pub(crate) fn mean_per_sample<const D: usize>(loss: Tensor<D>) -> Tensor<1> {
+    let batch_size = loss.dims()[0];
+
+    loss.reshape([batch_size as i32, -1])
+        .mean_dim(1)
+        .reshape([batch_size])
 }
@@ -0,0 +1,38 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::tensor::Tensor;
+
+/// Modules that add a regularization penalty to the loss, computed from the input of a training
+/// step, such as the [total variation](super::TotalVariationLoss) of generated images or the
+/// [gradient penalty](super::GradientPenalty) of a critic.
+///
+/// Models implement this trait by collecting the penalties of their submodules with
+/// [regularization_sum], and the training step adds the penalty of the model to its loss.
+/// Regularizers are usually stored as `Option` fields, so that they can be disabled from the
+/// configuration of the model.
+pub trait Regularizer<I> {
+    /// The penalty for the given input, or `None` when the module has no regularizer.
+    fn regularization(&self, input: &I) -> Option<Tensor<1>>;
+}
+
+/// Sums regularization penalties, skipping the modules without regularizer.
+pub fn regularization_sum<T>(terms: T) -> Option<Tensor<1>>
+where
+    T: IntoIterator<Item = Option<Tensor<1>>>,
+{
+    terms.into_iter().flatten().reduce(|acc, term| acc + term)
+}
+
+impl<I, M: Regularizer<I>> Regularizer<I> for Option<M> {
+    fn regularization(&self, input: &I) -> Option<Tensor<1>> {
+        self.as_ref()
+            .and_then(|module| module.regularization(input))
+    }
+}
+
+impl<I, M: Regularizer<I>> Regularizer<I> for Vec<M> {
+    fn regularization(&self, input: &I) -> Option<Tensor<1>> {
+        regularization_sum(self.iter().map(|module| module.regularization(input)))
+    }
+}
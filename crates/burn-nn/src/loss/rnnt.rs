@@ -97,7 +97,8 @@ impl RNNTLoss {
         self.gather_loss(alpha, &lpb, logit_lengths, target_lengths, b)
     }
 
-    /// Computes RNNT loss with the given reduction. Returns shape `[1]`.
+    /// Computes RNNT loss with the given reduction. Returns shape `[1]`, or `[B]` with
+    /// `Reduction::None`.
     pub fn forward_with_reduction(
         &self,
         logits: Tensor<4>,
@@ -110,6 +111,7 @@ impl RNNTLoss {
         match reduction {
             Reduction::Auto | Reduction::Mean => loss.mean(),
             Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
             other => panic!("{other:?} reduction is not supported"),
         }
     }
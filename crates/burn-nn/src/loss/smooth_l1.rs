@@ -1,4 +1,4 @@
-use super::Reduction;
+use super::{Reduction, mean_per_sample};
 use burn::config::Config;
 use burn::module::Module;
 use burn::tensor::Tensor;
@@ -141,6 +141,7 @@ impl SmoothL1Loss {
     /// - `reduction` - Specifies how to reduce the element-wise losses:
     ///   - `Reduction::Mean` or `Reduction::Auto`: Returns the mean of all element-wise losses.
     ///   - `Reduction::Sum`: Returns the sum of all element-wise losses.
+    ///   - `Reduction::None`: Returns the mean of the element-wise losses of each sample.
     ///
     /// # Returns
     ///
@@ -150,7 +151,7 @@ impl SmoothL1Loss {
     ///
     /// - predictions: `[...dims]` - Any shape
     /// - targets: `[...dims]` - Must match predictions shape
-    /// - output: `[1]` - Scalar loss value
+    /// - output: `[1]` - Scalar loss value, or `[batch_size]` with `Reduction::None`
     pub fn forward_with_reduction<const D: usize>(
         &self,
         predictions: Tensor<D>,
@@ -162,6 +163,7 @@ impl SmoothL1Loss {
         match reduction {
             Reduction::Mean | Reduction::Auto => unreduced_loss.mean(),
             Reduction::Sum => unreduced_loss.sum(),
+            Reduction::None => mean_per_sample(unreduced_loss),
             other => panic!("{other:?} reduction is not supported"),
         }
     }
@@ -0,0 +1,480 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::module::{avg_pool2d, conv2d};
+use burn::tensor::ops::ConvOptions;
+use burn::tensor::{Device, Int, Tensor};
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [SSIM loss](SsimLoss).
+#[derive(Config, Debug)]
+pub struct SsimLossConfig {
+    /// The dynamic range of the pixel values, e.g. `1.0` for images in `[0, 1]`.
+    #[config(default = 1.0)]
+    pub pixel_range: f32,
+    /// The constant stabilizing the luminance term.
+    #[config(default = 0.01)]
+    pub k1: f32,
+    /// The constant stabilizing the contrast and structure terms.
+    #[config(default = 0.03)]
+    pub k2: f32,
+    /// The size of the Gaussian window, which must be odd.
+    #[config(default = 11)]
+    pub kernel_size: usize,
+    /// The standard deviation of the Gaussian window.
+    #[config(default = 1.5)]
+    pub sigma: f32,
+}
+
+impl SsimLossConfig {
+    /// Initialize [SSIM loss](SsimLoss).
+    pub fn init(&self) -> SsimLoss {
+        self.assertions();
+        SsimLoss {
+            c1: (self.k1 * self.pixel_range).powi(2),
+            c2: (self.k2 * self.pixel_range).powi(2),
+            kernel_size: self.kernel_size,
+            sigma: self.sigma,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.pixel_range > 0.0,
+            "The pixel range of the SSIM loss must be positive."
+        );
+        assert!(
+            self.k1 > 0.0 && self.k2 > 0.0,
+            "The constants of the SSIM loss must be positive."
+        );
+        assert!(
+            self.kernel_size % 2 == 1,
+            "The kernel size of the SSIM loss must be odd."
+        );
+        assert!(
+            self.sigma > 0.0,
+            "The standard deviation of the SSIM loss window must be positive."
+        );
+    }
+}
+
+/// Configuration to create a [MS-SSIM loss](MsSsimLoss).
+#[derive(Config, Debug)]
+pub struct MsSsimLossConfig {
+    /// The configuration of the SSIM computed at each scale.
+    #[config(default = "SsimLossConfig::new()")]
+    pub ssim: SsimLossConfig,
+    /// The weight of each scale, from the finest to the coarsest. The images are downsampled by
+    /// a factor of 2 between consecutive scales.
+    #[config(default = "Vec::from([0.0448, 0.2856, 0.3001, 0.2363, 0.1333])")]
+    pub betas: Vec<f32>,
+}
+
+impl MsSsimLossConfig {
+    /// Initialize [MS-SSIM loss](MsSsimLoss).
+    pub fn init(&self) -> MsSsimLoss {
+        self.assertions();
+        MsSsimLoss {
+            ssim: self.ssim.init(),
+            betas: self.betas.clone(),
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            !self.betas.is_empty(),
+            "MS-SSIM loss requires at least one scale."
+        );
+        assert!(
+            self.betas.iter().all(|beta| *beta >= 0.0),
+            "The weights of the MS-SSIM loss scales must be non-negative."
+        );
+    }
+}
+
+/// Calculate the structural similarity (SSIM) loss between the predictions and the targets,
+/// `1 - SSIM(predictions, targets)`.
+///
+/// SSIM compares the local means, variances and covariance of the images within a Gaussian
+/// window, so the loss rewards reconstructions that preserve the local structure of the targets
+/// rather than their exact pixel values. It is differentiable and is commonly combined with an
+/// L1 loss for image reconstruction and super-resolution.
+///
+/// Reference: "Image Quality Assessment: From Error Visibility to Structural Similarity"
+/// <https://ece.uwaterloo.ca/~z70wang/publications/ssim.pdf>
+///
+/// Should be created with [SsimLossConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct SsimLoss {
+    /// The constant stabilizing the luminance term, `(k1 * pixel_range)^2`.
+    pub c1: f32,
+    /// The constant stabilizing the contrast and structure terms, `(k2 * pixel_range)^2`.
+    pub c2: f32,
+    /// The size of the Gaussian window.
+    pub kernel_size: usize,
+    /// The standard deviation of the Gaussian window.
+    pub sigma: f32,
+}
+
+impl ModuleDisplay for SsimLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("kernel_size", &self.kernel_size)
+            .add("sigma", &self.sigma)
+            .optional()
+    }
+}
+
+impl SsimLoss {
+    /// Compute the loss of each image, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(
+        &self,
+        predictions: Tensor<4>,
+        targets: Tensor<4>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+
+    /// Compute the loss of each image, averaging the SSIM over the channels and the pixels.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, predictions: Tensor<4>, targets: Tensor<4>) -> Tensor<1> {
+        assert_eq!(
+            predictions.dims(),
+            targets.dims(),
+            "Shape of predictions and targets must match"
+        );
+        let [batch_size, ..] = predictions.dims();
+
+        let (contrast_structure, luminance) = self.maps(predictions, targets);
+        let ssim = (contrast_structure * luminance)
+            .reshape([batch_size as i32, -1])
+            .mean_dim(1)
+            .reshape([batch_size]);
+
+        ssim.neg().add_scalar(1.0)
+    }
+
+    /// The normalized 1D Gaussian kernel.
+    fn kernel(&self, device: &Device) -> Tensor<1> {
+        let center = (self.kernel_size / 2) as f32;
+        let x = Tensor::<1, Int>::arange(0..self.kernel_size as i64, device)
+            .float()
+            .sub_scalar(center);
+        let kernel = x.square().div_scalar(-2.0 * self.sigma * self.sigma).exp();
+        kernel.clone().div(kernel.sum())
+    }
+
+    /// Computes the contrast-structure and the luminance maps of each channel.
+    ///
+    /// The five local statistics are blurred together: the inputs, their squares and their
+    /// product are stacked along the channels and filtered by a single pair of depthwise
+    /// convolutions. The window is only applied where it fits in the images, so the maps are
+    /// smaller than the inputs by `kernel_size - 1` pixels.
+    fn maps(&self, x: Tensor<4>, y: Tensor<4>) -> (Tensor<4>, Tensor<4>) {
+        let [_, channels, height, width] = x.dims();
+        assert!(
+            height >= self.kernel_size && width >= self.kernel_size,
+            "Image dimensions (H={height}, W={width}) must be >= kernel_size ({})",
+            self.kernel_size
+        );
+
+        let groups = 5 * channels;
+        let size = self.kernel_size;
+        let kernel = self.kernel(&x.device());
+        let horizontal = kernel
+            .clone()
+            .reshape([1, 1, 1, size])
+            .repeat_dim(0, groups);
+        let vertical = kernel.reshape([1, 1, size, 1]).repeat_dim(0, groups);
+
+        let stacked = Tensor::cat(
+            Vec::from([
+                x.clone(),
+                y.clone(),
+                x.clone().square(),
+                y.clone().square(),
+                x * y,
+            ]),
+            1,
+        );
+        let options = ConvOptions::new([1, 1], [0, 0], [1, 1], groups);
+        let blurred = conv2d(stacked, horizontal, None, options.clone());
+        let blurred = conv2d(blurred, vertical, None, options);
+
+        let mut stats = blurred.chunk(5, 1).into_iter();
+        let mut next = || stats.next().unwrap();
+        let (mu_x, mu_y, mu_xx, mu_yy, mu_xy) = (next(), next(), next(), next(), next());
+
+        let mu_x_mu_y = mu_x.clone() * mu_y.clone();
+        let mu_x_sq = mu_x.square();
+        let mu_y_sq = mu_y.square();
+
+        // Var(X) = E[X^2] - E[X]^2 and Cov(X, Y) = E[XY] - E[X]E[Y]
+        let var_x = mu_xx - mu_x_sq.clone();
+        let var_y = mu_yy - mu_y_sq.clone();
+        let cov_xy = mu_xy - mu_x_mu_y.clone();
+
+        // cs(x, y) = (2σxy + C2) / (σx² + σy² + C2)
+        // l(x, y) = (2μxμy + C1) / (μx² + μy² + C1)
+        let contrast_structure = (cov_xy.mul_scalar(2.0) + self.c2) / (var_x + var_y + self.c2);
+        let luminance = (mu_x_mu_y.mul_scalar(2.0) + self.c1) / (mu_x_sq + mu_y_sq + self.c1);
+
+        (contrast_structure, luminance)
+    }
+}
+
+/// Calculate the multi-scale structural similarity (MS-SSIM) loss between the predictions and
+/// the targets, `1 - MS-SSIM(predictions, targets)`.
+///
+/// The contrast and structure terms of [SSIM](SsimLoss) are computed at several scales, halving
+/// the resolution of the images between consecutive scales, and the luminance term at the
+/// coarsest one. The terms are combined as a weighted geometric mean, so the loss accounts for
+/// structures of different sizes.
+///
+/// The images must be at least `kernel_size * 2^(scales - 1)` pixels high and wide.
+///
+/// Reference: "Multi-Scale Structural Similarity for Image Quality Assessment"
+/// <https://www.cns.nyu.edu/pub/eero/wang03b.pdf>
+///
+/// Should be created with [MsSsimLossConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct MsSsimLoss {
+    /// The SSIM computed at each scale.
+    pub ssim: SsimLoss,
+    /// The weight of each scale, from the finest to the coarsest.
+    pub betas: Vec<f32>,
+}
+
+impl ModuleDisplay for MsSsimLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("kernel_size", &self.ssim.kernel_size)
+            .add("sigma", &self.ssim.sigma)
+            .add("scales", &self.betas.len())
+            .optional()
+    }
+}
+
+impl MsSsimLoss {
+    /// Compute the loss of each image, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(
+        &self,
+        predictions: Tensor<4>,
+        targets: Tensor<4>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+
+    /// Compute the loss of each image, averaging the MS-SSIM over the channels.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, predictions: Tensor<4>, targets: Tensor<4>) -> Tensor<1> {
+        assert_eq!(
+            predictions.dims(),
+            targets.dims(),
+            "Shape of predictions and targets must match"
+        );
+        let [batch_size, channels, ..] = predictions.dims();
+
+        let mut x = predictions;
+        let mut y = targets;
+        let mut ms_ssim = Tensor::<2>::ones([batch_size, channels], (&x.device(), x.dtype()));
+        let last = self.betas.len() - 1;
+
+        for (scale, beta) in self.betas.iter().enumerate() {
+            let (contrast_structure, luminance) = self.ssim.maps(x.clone(), y.clone());
+            let term = if scale == last {
+                contrast_structure * luminance
+            } else {
+                contrast_structure
+            };
+            // Clamp to avoid negative values before raising to a power (prevents NaNs).
+            let term = term
+                .mean_dims(&[2, 3])
+                .reshape([batch_size, channels])
+                .clamp_min(1e-6);
+            ms_ssim = ms_ssim * term.powf_scalar(*beta);
+
+            if scale != last {
+                x = avg_pool2d(x, [2, 2], [2, 2], [0, 0], false, false);
+                y = avg_pool2d(y, [2, 2], [2, 2], [0, 0], false, false);
+            }
+        }
+
+        ms_ssim
+            .mean_dim(1)
+            .reshape([batch_size])
+            .neg()
+            .add_scalar(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Distribution, TensorData, Tolerance};
+    type FT = f32;
+
+    fn image(shape: [usize; 4]) -> Tensor<4> {
+        Tensor::<4>::random(shape, Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn test_ssim_loss_identical_images() {
+        let predictions = image([2, 3, 16, 16]);
+        let loss = SsimLossConfig::new()
+            .with_kernel_size(7)
+            .init()
+            .forward_no_reduction(predictions.clone(), predictions);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ssim_loss_constant_images() {
+        // For constant images, only the luminance term remains:
+        // 1 - (2 * 0.2 * 0.6 + C1) / (0.2^2 + 0.6^2 + C1)
+        let device = Default::default();
+        let predictions = Tensor::<4>::full([1, 1, 8, 8], 0.2, &device);
+        let targets = Tensor::<4>::full([1, 1, 8, 8], 0.6, &device);
+
+        let loss = SsimLossConfig::new().with_kernel_size(3).init().forward(
+            predictions,
+            targets,
+            Reduction::Mean,
+        );
+
+        let c1 = 0.0001;
+        let expected = 1.0 - (2.0 * 0.2 * 0.6 + c1) / (0.04 + 0.36 + c1);
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([expected]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ms_ssim_loss_ranks_distortions() {
+        let device = Default::default();
+        let targets = image([1, 1, 32, 32]);
+        let slightly_noisy = targets.clone()
+            + Tensor::<4>::random([1, 1, 32, 32], Distribution::Normal(0.0, 0.01), &device);
+        let very_noisy = targets.clone()
+            + Tensor::<4>::random([1, 1, 32, 32], Distribution::Normal(0.0, 0.5), &device);
+
+        let loss = MsSsimLossConfig::new()
+            .with_ssim(SsimLossConfig::new().with_kernel_size(3))
+            .with_betas(Vec::from([0.3, 0.3, 0.4]))
+            .init();
+
+        let identical = loss
+            .forward(targets.clone(), targets.clone(), Reduction::Mean)
+            .into_scalar::<f32>();
+        let slightly_noisy = loss
+            .forward(slightly_noisy, targets.clone(), Reduction::Mean)
+            .into_scalar::<f32>();
+        let very_noisy = loss
+            .forward(very_noisy, targets, Reduction::Mean)
+            .into_scalar::<f32>();
+
+        assert!(identical.abs() < 1e-4, "identical: {identical}");
+        assert!(
+            slightly_noisy < very_noisy,
+            "slightly noisy: {slightly_noisy}, very noisy: {very_noisy}"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ssim_ad_loss() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let targets = Tensor::<4>::random([1, 2, 8, 8], Distribution::Default, &device);
+        let predictions =
+            Tensor::<4>::random([1, 2, 8, 8], Distribution::Default, &device).require_grad();
+
+        let loss = SsimLossConfig::new().with_kernel_size(3).init().forward(
+            predictions.clone(),
+            targets,
+            Reduction::Mean,
+        );
+
+        let grads = loss.backward();
+        let grads_predictions = predictions.grad(&grads).unwrap();
+
+        assert_eq!(grads_predictions.dims(), [1, 2, 8, 8]);
+        let norm = grads_predictions.abs().sum().into_scalar::<f32>();
+        assert!(norm.is_finite() && norm > 0.0, "gradient norm: {norm}");
+    }
+
+    #[test]
+    #[should_panic = "The kernel size of the SSIM loss must be odd."]
+    fn test_ssim_loss_even_kernel_size() {
+        let _loss = SsimLossConfig::new().with_kernel_size(4).init();
+    }
+
+    #[test]
+    fn display() {
+        let loss = SsimLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "SsimLoss {kernel_size: 11, sigma: 1.5}"
+        );
+    }
+}
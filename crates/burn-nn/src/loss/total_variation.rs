@@ -0,0 +1,187 @@
+use burn_core as burn;
+
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+use burn::{config::Config, module::Module};
+
+use super::{Reduction, Regularizer};
+
+/// Configuration to create a [total variation loss](TotalVariationLoss).
+#[derive(Config, Debug)]
+pub struct TotalVariationLossConfig {
+    /// The weight of the penalty.
+    #[config(default = 1.0)]
+    pub weight: f32,
+    /// Whether the differences between neighbouring pixels are squared instead of taken in
+    /// absolute value. The absolute (anisotropic) variation preserves sharp edges, while the
+    /// squared variation penalizes them more.
+    #[config(default = false)]
+    pub squared: bool,
+}
+
+impl TotalVariationLossConfig {
+    /// Initialize [total variation loss](TotalVariationLoss).
+    pub fn init(&self) -> TotalVariationLoss {
+        assert!(
+            self.weight >= 0.0,
+            "The weight of the total variation loss must be non-negative."
+        );
+        TotalVariationLoss {
+            weight: self.weight,
+            squared: self.squared,
+        }
+    }
+}
+
+/// Calculate the total variation of images: the differences between horizontally and vertically
+/// neighbouring pixels, averaged over the pixels.
+///
+/// Penalizing the total variation favors piecewise smooth images, which reduces the noise and the
+/// checkerboard artifacts of generated or reconstructed images.
+///
+/// As a [regularizer](Regularizer) of images, it adds the mean total variation of the batch.
+///
+/// Should be created with [TotalVariationLossConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct TotalVariationLoss {
+    /// The weight of the penalty.
+    pub weight: f32,
+    /// Whether the differences are squared.
+    pub squared: bool,
+}
+
+impl ModuleDisplay for TotalVariationLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("weight", &self.weight)
+            .add("squared", &self.squared)
+            .optional()
+    }
+}
+
+impl TotalVariationLoss {
+    /// Compute the total variation of each image, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(&self, images: Tensor<4>, reduction: Reduction) -> Tensor<1> {
+        let loss = self.forward_no_reduction(images);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+
+    /// Compute the weighted total variation of each image.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, images: Tensor<4>) -> Tensor<1> {
+        let [batch_size, channels, height, width] = images.dims();
+        assert!(
+            height > 1 && width > 1,
+            "Total variation requires images of at least 2x2 pixels, got {height}x{width}"
+        );
+
+        let vertical = images
+            .clone()
+            .slice([0..batch_size, 0..channels, 1..height])
+            - images
+                .clone()
+                .slice([0..batch_size, 0..channels, 0..height - 1]);
+        let horizontal = images
+            .clone()
+            .slice([0..batch_size, 0..channels, 0..height, 1..width])
+            - images.slice([0..batch_size, 0..channels, 0..height, 0..width - 1]);
+
+        let (vertical, horizontal) = if self.squared {
+            (vertical.square(), horizontal.square())
+        } else {
+            (vertical.abs(), horizontal.abs())
+        };
+
+        let variation = vertical.mean_dims(&[1, 2, 3]) + horizontal.mean_dims(&[1, 2, 3]);
+        variation.reshape([batch_size]).mul_scalar(self.weight)
+    }
+}
+
+impl Regularizer<Tensor<4>> for TotalVariationLoss {
+    fn regularization(&self, input: &Tensor<4>) -> Option<Tensor<1>> {
+        Some(self.forward(input.clone(), Reduction::Mean))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn images() -> Tensor<4> {
+        Tensor::<4>::from_data(
+            TensorData::from([[[[0.0, 1.0, 3.0], [0.0, 1.0, 3.0]]]]),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn test_total_variation_loss() {
+        // Horizontal differences: [1, 2, 1, 2], vertical differences: [0, 0, 0].
+        let loss = TotalVariationLossConfig::new().init();
+
+        let absolute = loss.forward(images(), Reduction::Mean);
+        let squared = TotalVariationLossConfig::new()
+            .with_squared(true)
+            .with_weight(2.0)
+            .init()
+            .forward_no_reduction(images());
+
+        absolute
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.5]), Tolerance::default());
+        squared
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_total_variation_regularizer_collection() {
+        let regularizers = Vec::from([
+            Some(TotalVariationLossConfig::new().init()),
+            None,
+            Some(TotalVariationLossConfig::new().with_weight(3.0).init()),
+        ]);
+
+        let penalty = regularizers.regularization(&images()).unwrap();
+
+        penalty
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([6.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let loss = TotalVariationLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "TotalVariationLoss {weight: 1, squared: false}"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use burn_core as burn;
+
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+use burn::tensor::activation::relu;
+use burn::tensor::linalg::cosine_similarity;
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// The distance between embeddings used by the [triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug, PartialEq)]
+pub enum TripletDistance {
+    /// The Euclidean distance `||a - b||`.
+    Euclidean,
+    /// The squared Euclidean distance `||a - b||²`.
+    SquaredEuclidean,
+    /// The cosine distance `1 - cos(a, b)`, which ignores the norm of the embeddings.
+    Cosine,
+}
+
+/// Configuration to create a [triplet margin loss](TripletMarginLoss) using the
+/// [init function](TripletMarginLossConfig::init).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin by which the negative must be farther from the anchor than the positive.
+    #[config(default = 1.0)]
+    pub margin: f32,
+
+    /// The distance between embeddings.
+    #[config(default = "TripletDistance::Euclidean")]
+    pub distance: TripletDistance,
+
+    /// Use the distance between the positive and the negative when it is smaller than the distance
+    /// between the anchor and the negative, which makes the negative harder.
+    ///
+    /// Reference: "Learning local feature descriptors with triplets and shallow convolutional
+    /// neural networks" <https://bmva-archive.org.uk/bmvc/2016/papers/paper119/paper119.pdf>
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [triplet margin loss](TripletMarginLoss).
+    pub fn init(&self) -> TripletMarginLoss {
+        assert!(
+            self.margin >= 0.0,
+            "The margin of the triplet loss must be non-negative. Got {}",
+            self.margin
+        );
+        TripletMarginLoss {
+            margin: self.margin,
+            distance: self.distance.clone(),
+            swap: self.swap,
+        }
+    }
+}
+
+/// Calculate the triplet margin loss `max(d(a, p) - d(a, n) + margin, 0)`, which pulls each anchor
+/// towards its positive and pushes it away from its negative.
+///
+/// Should be created using [TripletMarginLossConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct TripletMarginLoss {
+    /// The margin.
+    pub margin: f32,
+    /// The distance between embeddings.
+    #[module(skip)]
+    pub distance: TripletDistance,
+    /// Use the distance swap.
+    pub swap: bool,
+}
+
+impl ModuleDisplay for TripletMarginLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("margin", &self.margin)
+            .add_debug_attribute("distance", &self.distance)
+            .add("swap", &self.swap)
+            .optional()
+    }
+}
+
+impl TripletMarginLoss {
+    /// Compute the loss of each triplet, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, d_model]`
+    /// - positives: `[batch_size, d_model]`
+    /// - negatives: `[batch_size, d_model]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward(
+        &self,
+        anchors: Tensor<2>,
+        positives: Tensor<2>,
+        negatives: Tensor<2>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let loss = self.forward_no_reduction(anchors, positives, negatives);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+            Reduction::None => loss,
+            other => panic!("{other:?} reduction is not supported"),
+        }
+    }
+
+    /// Compute the loss of each triplet.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: `[batch_size, d_model]`
+    /// - positives: `[batch_size, d_model]`
+    /// - negatives: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        anchors: Tensor<2>,
+        positives: Tensor<2>,
+        negatives: Tensor<2>,
+    ) -> Tensor<1> {
+        let dims = anchors.dims();
+        assert!(
+            positives.dims() == dims && negatives.dims() == dims,
+            "Shapes of anchors ({dims:?}), positives ({:?}) and negatives ({:?}) must match.",
+            positives.dims(),
+            negatives.dims()
+        );
+
+        let positive_distance = self.distance(anchors.clone(), positives.clone());
+        let mut negative_distance = self.distance(anchors, negatives.clone());
+        if self.swap {
+            negative_distance = negative_distance.min_pair(self.distance(positives, negatives));
+        }
+
+        relu(positive_distance - negative_distance + self.margin).reshape([dims[0]])
+    }
+
+    fn distance(&self, lhs: Tensor<2>, rhs: Tensor<2>) -> Tensor<2> {
+        match self.distance {
+            // The offset keeps the gradient of the square root finite at zero.
+            TripletDistance::Euclidean => (lhs - rhs).square().sum_dim(1).add_scalar(1e-12).sqrt(),
+            TripletDistance::SquaredEuclidean => (lhs - rhs).square().sum_dim(1),
+            TripletDistance::Cosine => cosine_similarity(lhs, rhs, 1, None).neg().add_scalar(1.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn triplets() -> (Tensor<2>, Tensor<2>, Tensor<2>) {
+        let device = Default::default();
+        (
+            Tensor::<2>::from_floats([[0.0, 0.0], [1.0, 1.0]], &device),
+            Tensor::<2>::from_floats([[0.0, 1.0], [1.0, 2.0]], &device),
+            Tensor::<2>::from_floats([[0.0, 1.5], [4.0, 5.0]], &device),
+        )
+    }
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let (anchors, positives, negatives) = triplets();
+
+        let loss = TripletMarginLossConfig::new().init().forward_no_reduction(
+            anchors.clone(),
+            positives.clone(),
+            negatives.clone(),
+        );
+        let swapped = TripletMarginLossConfig::new()
+            .with_swap(true)
+            .init()
+            .forward(anchors, positives, negatives, Reduction::Mean);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.5, 0.0]), Tolerance::default());
+        swapped
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.75]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_cosine_distance() {
+        let device = Default::default();
+        let anchors = Tensor::<2>::from_floats([[1.0, 0.0], [1.0, 1.0]], &device);
+        let positives = Tensor::<2>::from_floats([[1.0, 1.0], [1.0, 2.0]], &device);
+        let negatives = Tensor::<2>::from_floats([[0.0, 1.0], [4.0, 5.0]], &device);
+
+        let loss = TripletMarginLossConfig::new()
+            .with_margin(0.5)
+            .with_distance(TripletDistance::Cosine)
+            .init()
+            .forward_no_reduction(anchors, positives, negatives);
+
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 0.5452004]), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_triplet_margin_loss_gradients_are_finite() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let anchors = Tensor::<2>::from_floats([[1.0, 2.0]], &device).require_grad();
+        let positives = anchors.clone();
+        let negatives = Tensor::<2>::from_floats([[1.0, 2.5]], &device);
+
+        let grads = TripletMarginLossConfig::new()
+            .init()
+            .forward(anchors.clone(), positives, negatives, Reduction::Sum)
+            .backward();
+        let grad = anchors.grad(&grads).unwrap().into_data();
+
+        assert!(grad.iter::<f32>().all(f32::is_finite), "gradient: {grad}");
+    }
+
+    #[test]
+    fn display() {
+        let loss = TripletMarginLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "TripletMarginLoss {margin: 1, distance: Euclidean, swap: false}"
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::{Device, Int, Tensor};
+use burn::{config::Config, module::Module};
+
+use super::Reduction;
+use super::dice::{Overlap, assertions, class_average, one_hot_targets, reduce};
+
+/// Configuration to create a [Tversky loss](TverskyLoss) using the [init function](TverskyLossConfig::init).
+#[derive(Config, Debug)]
+pub struct TverskyLossConfig {
+    /// The weight of the false positives.
+    #[config(default = 0.3)]
+    pub alpha: f32,
+
+    /// The weight of the false negatives. A beta larger than alpha favors the recall, which suits
+    /// small structures.
+    #[config(default = 0.7)]
+    pub beta: f32,
+
+    /// The weight of each class in the average of the per-class losses.
+    pub weights: Option<Vec<f32>>,
+
+    /// The smoothing term added to the numerator and the denominator of the Tversky index.
+    #[config(default = 1.0)]
+    pub smooth: f32,
+
+    /// Treat the predictions as logits, applying a softmax over the class dimension.
+    #[config(default = true)]
+    pub logits: bool,
+}
+
+impl TverskyLossConfig {
+    /// Initialize [Tversky loss](TverskyLoss).
+    pub fn init(&self, device: &Device) -> TverskyLoss {
+        assertions(self.smooth, self.weights.as_deref());
+        assert!(
+            self.alpha >= 0.0 && self.beta >= 0.0,
+            "The alpha and beta of the Tversky loss must be non-negative. Got {} and {}",
+            self.alpha,
+            self.beta
+        );
+        TverskyLoss {
+            alpha: self.alpha,
+            beta: self.beta,
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<1>::from_floats(e.as_slice(), device)),
+            smooth: self.smooth,
+            logits: self.logits,
+        }
+    }
+}
+
+/// Calculate the Tversky loss `1 - (TP + smooth) / (TP + alpha FP + beta FN + smooth)` of
+/// segmentation predictions, for each class of each sample.
+///
+/// The Tversky index generalizes the Dice coefficient, which it matches without smoothing for
+/// `alpha = beta = 0.5`, by weighting the false positives and the false negatives separately.
+///
+/// Reference: "Tversky loss function for image segmentation using 3D fully convolutional deep
+/// networks" <https://arxiv.org/abs/1706.05721>
+///
+/// Should be created using [TverskyLossConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct TverskyLoss {
+    /// The weight of the false positives.
+    pub alpha: f32,
+    /// The weight of the false negatives.
+    pub beta: f32,
+    /// The weight of each class.
+    pub weights: Option<Tensor<1>>,
+    /// The smoothing term.
+    pub smooth: f32,
+    /// Treat the predictions as logits.
+    pub logits: bool,
+}
+
+impl ModuleDisplay for TverskyLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("alpha", &self.alpha)
+            .add("beta", &self.beta)
+            .add("weights", &self.weights)
+            .add("smooth", &self.smooth)
+            .add("logits", &self.logits)
+            .optional()
+    }
+}
+
+impl TverskyLoss {
+    /// Compute the loss with one-hot (or soft) targets, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, num_classes, ...]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<D>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        reduce(self.forward_no_reduction(predictions, targets), reduction)
+    }
+
+    /// Compute the loss with class index targets, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, ...]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
+    pub fn forward_indices<const D: usize, const DT: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<DT, Int>,
+        reduction: Reduction,
+    ) -> Tensor<1> {
+        let targets = one_hot_targets(targets, predictions.dims()[1]);
+        self.forward(predictions, targets, reduction)
+    }
+
+    /// Compute the loss of each sample, averaged over the classes.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_classes, ...]`
+    /// - targets: `[batch_size, num_classes, ...]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<D>,
+    ) -> Tensor<1> {
+        let overlap = Overlap::new(predictions, targets, self.logits);
+        let true_positives = overlap.intersection;
+        let false_positives = overlap.predicted - true_positives.clone();
+        let false_negatives = overlap.target - true_positives.clone();
+
+        let denominator = true_positives.clone()
+            + false_positives.mul_scalar(self.alpha)
+            + false_negatives.mul_scalar(self.beta);
+        let tversky = true_positives.add_scalar(self.smooth).div(
+            denominator
+                .add_scalar(self.smooth)
+                .clamp_min(f32::MIN_POSITIVE),
+        );
+
+        class_average(tversky.neg().add_scalar(1.0), self.weights.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loss::DiceLossConfig;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn predictions() -> Tensor<3> {
+        Tensor::<3>::from_floats(
+            [[[0.9, 0.2, 0.7, 0.1], [0.1, 0.8, 0.3, 0.9]]],
+            &Default::default(),
+        )
+    }
+
+    fn targets() -> Tensor<2, Int> {
+        Tensor::<2, Int>::from_data(TensorData::from([[0, 1, 0, 1]]), &Default::default())
+    }
+
+    #[test]
+    fn test_tversky_loss() {
+        let loss = TverskyLossConfig::new()
+            .with_logits(false)
+            .init(&Default::default());
+
+        let output = loss.forward_indices(predictions(), targets(), Reduction::Mean);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1167450]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_tversky_loss_matches_dice() {
+        let device = Default::default();
+        let tversky = TverskyLossConfig::new()
+            .with_alpha(0.5)
+            .with_beta(0.5)
+            .with_smooth(0.0)
+            .init(&device)
+            .forward_indices(predictions(), targets(), Reduction::Mean);
+        let dice = DiceLossConfig::new()
+            .with_smooth(0.0)
+            .init(&device)
+            .forward_indices(predictions(), targets(), Reduction::Mean);
+
+        tversky
+            .into_data()
+            .assert_approx_eq::<FT>(&dice.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let loss = TverskyLossConfig::new().init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{loss}"),
+            "TverskyLoss {alpha: 0.3, beta: 0.7, weights: None, smooth: 1, logits: true}"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use burn_core as burn;
+
+use burn::tensor::Tensor;
+
+/// Key/value cache for the [Multi Head Attention](super::MultiHeadAttention) layer, used for
+/// incremental decoding with [forward_kv_cache](super::MultiHeadAttention::forward_kv_cache).
+///
+/// The keys and values of the decoded tokens are written in buffers of `max_seq_length`
+/// positions, allocated on the first step, so that each step only projects its new tokens and
+/// no memory is reallocated while decoding.
+pub struct MhaKvCache {
+    state: Option<(Tensor<4>, Tensor<4>)>,
+    len: usize,
+    max_seq_length: usize,
+}
+
+impl MhaKvCache {
+    /// Create an empty cache holding up to `max_seq_length` tokens.
+    pub fn new(max_seq_length: usize) -> Self {
+        Self {
+            state: None,
+            len: 0,
+            max_seq_length,
+        }
+    }
+
+    /// The number of tokens in the cache.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the cache holds no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum number of tokens the cache can hold.
+    pub fn max_seq_length(&self) -> usize {
+        self.max_seq_length
+    }
+
+    /// Remove the tokens from the cache, keeping its buffers to decode a new sequence.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Write the keys and values of the new tokens, with shape
+    /// `[batch_size, n_heads_kv, seq_length, d_k]`, after the cached ones, and return the keys and
+    /// values of all the tokens.
+    pub(crate) fn append(&mut self, key: Tensor<4>, value: Tensor<4>) -> (Tensor<4>, Tensor<4>) {
+        let [batch_size, n_heads, seq_length, d_k] = key.dims();
+        let start = self.len;
+        let end = start + seq_length;
+        assert!(
+            end <= self.max_seq_length,
+            "Key/value cache overflow: {end} tokens exceed the maximum sequence length of {}",
+            self.max_seq_length
+        );
+
+        let (key_cache, value_cache) = self.state.take().unwrap_or_else(|| {
+            let shape = [batch_size, n_heads, self.max_seq_length, d_k];
+            (
+                Tensor::zeros(shape, (&key.device(), key.dtype())),
+                Tensor::zeros(shape, (&value.device(), value.dtype())),
+            )
+        });
+        assert_eq!(
+            key_cache.dims()[0],
+            batch_size,
+            "The batch size can't change while decoding with a key/value cache"
+        );
+
+        let key_cache =
+            key_cache.slice_assign([0..batch_size, 0..n_heads, start..end, 0..d_k], key);
+        let value_cache =
+            value_cache.slice_assign([0..batch_size, 0..n_heads, start..end, 0..d_k], value);
+        self.state = Some((key_cache.clone(), value_cache.clone()));
+        self.len = end;
+
+        (
+            key_cache.slice([0..batch_size, 0..n_heads, 0..end, 0..d_k]),
+            value_cache.slice([0..batch_size, 0..n_heads, 0..end, 0..d_k]),
+        )
+    }
+}
@@ -17,6 +17,27 @@ pub fn generate_autoregressive_mask(
     mask.expand([batch_size, seq_length, seq_length])
 }
 
+/// Generate a sliding window attention mask.
+///
+/// Like the [autoregressive mask](generate_autoregressive_mask), but each position can only
+/// attend to the `window_size` most recent positions, itself included.
+///
+/// To avoid materializing the mask, the backend attention can apply the same masking with the
+/// `is_causal` and `sliding_window` options.
+pub fn generate_sliding_window_mask(
+    batch_size: usize,
+    seq_length: usize,
+    window_size: usize,
+    device: &Device,
+) -> Tensor<3, Bool> {
+    assert!(window_size > 0, "The window size must be positive");
+    let shape = [seq_length, seq_length];
+    let mask = Tensor::<2, Bool>::tril_mask(shape, 0, device).bool_or(
+        Tensor::<2, Bool>::triu_mask(shape, 1 - window_size as i64, device),
+    );
+    mask.expand([batch_size, seq_length, seq_length])
+}
+
 /// Generate a padding attention mask.
 pub struct GeneratePaddingMask {
     /// The generated tensor.
@@ -130,6 +151,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_sliding_window_mask() {
+        let device = Default::default();
+
+        let mask = generate_sliding_window_mask(1, 4, 2, &device);
+
+        mask.into_data().assert_eq(
+            &TensorData::from([[
+                [false, true, true, true],
+                [false, false, true, true],
+                [true, false, false, true],
+                [true, true, false, false],
+            ]]),
+            false,
+        );
+    }
+
     #[test]
     fn test_generate_padding_mask() {
         let device = Default::default();
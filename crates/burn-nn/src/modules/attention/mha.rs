@@ -1,5 +1,6 @@
 use burn_core as burn;
 
+use super::MhaKvCache;
 use crate::activation::Gelu;
 use crate::cache::TensorCache;
 use crate::{Dropout, DropoutConfig, Linear, LinearConfig};
@@ -19,6 +20,11 @@ pub struct MultiHeadAttentionConfig {
     pub d_model: usize,
     /// The number of heads.
     pub n_heads: usize,
+    /// The number of key and value heads, shared by groups of query heads (grouped-query
+    /// attention). It must divide `n_heads`, and `1` gives multi-query attention.
+    /// Default: `n_heads`
+    #[config(default = "None")]
+    pub n_heads_kv: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
@@ -47,10 +53,14 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - `query`: [`Linear`] layer with `d_model` input and output features.
-/// - `key`: [`Linear`] layer with `d_model` input and output features.
-/// - `value`: [`Linear`] layer with `d_model` input and output features.
+/// - `key`: [`Linear`] layer with `d_model` input and `n_heads_kv * d_k` output features.
+/// - `value`: [`Linear`] layer with `d_model` input and `n_heads_kv * d_k` output features.
 /// - `output`: [`Linear`] layer with `d_model` input and output features.
 ///
+/// With fewer key/value heads than query heads (grouped-query attention), each key/value head is
+/// shared by a group of consecutive query heads. The groups are broadcast in the matrix
+/// multiplications, so the keys and values, and their [cache](MhaKvCache), are never repeated.
+///
 /// Should be created with [MultiHeadAttentionConfig].
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -71,6 +81,8 @@ pub struct MultiHeadAttention {
     pub d_model: usize,
     /// The number of heads.
     pub n_heads: usize,
+    /// The number of key and value heads.
+    pub n_heads_kv: usize,
     /// Size of the key and query vectors.
     pub d_k: usize,
     /// Minimum value a float can take.
@@ -90,6 +102,7 @@ impl ModuleDisplay for MultiHeadAttention {
         content
             .add("d_model", &self.d_model)
             .add("n_heads", &self.n_heads)
+            .add("n_heads_kv", &self.n_heads_kv)
             .add("d_k", &self.d_k)
             .add("dropout", &self.dropout.prob)
             .add("min_float", &self.min_float)
@@ -114,20 +127,30 @@ pub struct MhaInput {
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init(&self, device: &Device) -> MultiHeadAttention {
-        let linear = |config: &Self| {
-            LinearConfig::new(config.d_model, config.d_model)
+        let n_heads_kv = self.n_heads_kv.unwrap_or(self.n_heads);
+        assert_eq!(
+            self.n_heads % n_heads_kv,
+            0,
+            "Query heads must be divisible by key/value heads"
+        );
+
+        // The key and value projections are smaller by the number of query heads per group.
+        let d_kv = self.d_model / (self.n_heads / n_heads_kv);
+        let linear = |d_output| {
+            LinearConfig::new(self.d_model, d_output)
                 .with_initializer(self.initializer.clone())
                 .init(device)
         };
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self.d_model),
+            key: linear(d_kv),
+            value: linear(d_kv),
+            output: linear(self.d_model),
             dropout: DropoutConfig::new(self.dropout).init(),
             activation: Gelu::new(),
             n_heads: self.n_heads,
+            n_heads_kv,
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
@@ -197,22 +220,53 @@ impl MultiHeadAttention {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput) -> MhaOutput {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
-
         let query = self.attention_linear(input.query, &self.query);
         let key = self.attention_linear(input.key, &self.key);
         let value = self.attention_linear(input.value, &self.value);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        self.forward_projected(query, key, value, input.mask_pad, input.mask_attn)
+    }
 
-        let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
-        let context = self.output.forward(context);
+    /// Applies the forward pass on the new tokens of an incremental decoding, using and updating
+    /// a [key/value cache](MhaKvCache).
+    ///
+    /// Only the new tokens are projected, and their queries attend to the keys and values of all
+    /// the tokens in the cache, so generating a sequence takes linear time in its length instead
+    /// of recomputing the previous tokens at every step. When several new tokens are given without
+    /// an attention mask, such as when processing a prompt, a causal mask is applied.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_1, d_model]`
+    /// - value: `[batch_size, seq_length_1, d_model]`
+    /// - mask_pad: `[batch_size, seq_length_2]`
+    /// - mask_attn: `[batch_size, seq_length_1, seq_length_2]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    ///
+    /// where `seq_length_2` is the number of tokens in the cache, including the new ones.
+    pub fn forward_kv_cache(&self, input: MhaInput, cache: &mut MhaKvCache) -> MhaOutput {
+        let [batch_size, seq_length_1, _d_model] = input.query.dims();
+        let start = cache.len();
 
-        MhaOutput { weights, context }
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+        let (key, value) = cache.append(key, value);
+
+        let mask_attn = input.mask_attn.or_else(|| {
+            let seq_length_2 = cache.len();
+            (seq_length_1 > 1).then(|| {
+                Tensor::<2, Bool>::tril_mask(
+                    [seq_length_1, seq_length_2],
+                    start as i64,
+                    &query.device(),
+                )
+                .expand([batch_size, seq_length_1, seq_length_2])
+            })
+        });
+
+        self.forward_projected(query, key, value, input.mask_pad, mask_attn)
     }
 
     /// Applies the forward pass using a cache.
@@ -239,7 +293,7 @@ impl MultiHeadAttention {
         let attn_scores = self.attn_scores(query, key);
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
-        let context = weights.clone().matmul(value);
+        let context = self.grouped_matmul(weights.clone(), value);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -249,14 +303,60 @@ impl MultiHeadAttention {
         MhaOutput { weights, context }
     }
 
+    /// Applies the attention on queries, keys and values already projected by
+    /// [attention_linear](Self::attention_linear).
+    pub(crate) fn forward_projected(
+        &self,
+        query: Tensor<4>,
+        key: Tensor<4>,
+        value: Tensor<4>,
+        mask_pad: Option<Tensor<2, Bool>>,
+        mask_attn: Option<Tensor<3, Bool>>,
+    ) -> MhaOutput {
+        let [batch_size, _n_heads, seq_length_1, _d_k] = query.dims();
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+
+        let context = self.grouped_matmul(weights.clone(), value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, self.d_model]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
     fn attn_scores(&self, query: Tensor<4>, key: Tensor<4>) -> Tensor<4> {
-        let attn_scores = query
-            .matmul(key.transpose())
+        let attn_scores = self
+            .grouped_matmul(query, key.transpose())
             .div_scalar((self.d_k as f32).sqrt());
 
         self.dropout.forward(attn_scores)
     }
 
+    /// Multiplies each query head by its key/value head, by merging the query heads of each group
+    /// into the rows of a single matrix.
+    ///
+    /// # Shapes
+    ///
+    /// - lhs: `[batch_size, n_heads, seq_length, d_lhs]`
+    /// - rhs: `[batch_size, n_heads_kv, d_lhs, d_rhs]`
+    /// - output: `[batch_size, n_heads, seq_length, d_rhs]`
+    fn grouped_matmul(&self, lhs: Tensor<4>, rhs: Tensor<4>) -> Tensor<4> {
+        if self.n_heads == self.n_heads_kv {
+            return lhs.matmul(rhs);
+        }
+
+        let [batch_size, n_heads, seq_length, d_lhs] = lhs.dims();
+        let group_size = n_heads / self.n_heads_kv;
+        let [_, _, _, d_rhs] = rhs.dims();
+
+        lhs.reshape([batch_size, self.n_heads_kv, group_size * seq_length, d_lhs])
+            .matmul(rhs)
+            .reshape([batch_size, n_heads, seq_length, d_rhs])
+    }
+
     fn attn_weights(
         &self,
         mut attn_scores: Tensor<4>,
@@ -288,11 +388,13 @@ impl MultiHeadAttention {
         }
     }
 
-    fn attention_linear(&self, x: Tensor<3>, linear: &Linear) -> Tensor<4> {
+    /// Projects the input with a linear layer and splits the heads, of which there are
+    /// `n_heads` for the queries and `n_heads_kv` for the keys and values.
+    pub(crate) fn attention_linear(&self, x: Tensor<3>, linear: &Linear) -> Tensor<4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size as i32, seq_length as i32, -1, self.d_k as i32])
             .swap_dims(1, 2)
     }
 }
@@ -511,6 +613,139 @@ mod tests {
             .assert_approx_eq::<f32>(&output_2.into_data(), Tolerance::default());
     }
 
+    #[test]
+    fn test_autoregressive_mask_should_have_same_output_as_kv_cache_decoding() {
+        let [batch_size, seq_length, prompt_length, d_model, n_heads] = [3, 5, 2, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init(&device);
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut cache = MhaKvCache::new(seq_length);
+
+        // The prompt is processed at once, then the tokens are decoded one at a time.
+        let prompt = tensor
+            .clone()
+            .slice([0..batch_size, 0..prompt_length, 0..d_model]);
+        let output = mha.forward_kv_cache(MhaInput::self_attn(prompt), &mut cache);
+        let mut output_2 = Vec::from([output.context]);
+        for i in prompt_length..seq_length {
+            let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            let output = mha.forward_kv_cache(MhaInput::self_attn(token), &mut cache);
+            output_2.push(output.context);
+        }
+
+        assert_eq!(cache.len(), seq_length);
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&output_2.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_share_kv_heads() {
+        let [batch_size, seq_length, d_model, n_heads, n_heads_kv] = [2, 5, 16, 4, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_n_heads_kv(Some(n_heads_kv))
+            .init(&device);
+        let d_k = d_model / n_heads;
+        assert_eq!(mha.key.weight.shape().dims(), [d_model, n_heads_kv * d_k]);
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output = mha.forward(MhaInput::self_attn(tensor.clone()));
+
+        // Reference with the key/value heads repeated for each query head of their group.
+        let repeat = |x: Tensor<4>| {
+            x.reshape([batch_size, n_heads_kv, 1, seq_length, d_k])
+                .expand([
+                    batch_size,
+                    n_heads_kv,
+                    n_heads / n_heads_kv,
+                    seq_length,
+                    d_k,
+                ])
+                .reshape([batch_size, n_heads, seq_length, d_k])
+        };
+        let query = mha.attention_linear(tensor.clone(), &mha.query);
+        let key = repeat(mha.attention_linear(tensor.clone(), &mha.key));
+        let value = repeat(mha.attention_linear(tensor, &mha.value));
+        let weights = softmax(
+            query
+                .matmul(key.transpose())
+                .div_scalar((d_k as f32).sqrt()),
+            3,
+        );
+        let context = weights
+            .clone()
+            .matmul(value)
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+        let context = mha.output.forward(context);
+
+        output
+            .weights
+            .into_data()
+            .assert_approx_eq::<f32>(&weights.into_data(), Tolerance::default());
+        output
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_multi_query_attention_kv_cache_decoding() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 4, 12, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_n_heads_kv(Some(1))
+            .init(&device);
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let output_1 = mha.forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn));
+
+        let mut cache = MhaKvCache::new(seq_length);
+        let output_2: Vec<_> = (0..seq_length)
+            .map(|i| {
+                let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+                mha.forward_kv_cache(MhaInput::self_attn(token), &mut cache)
+                    .context
+            })
+            .collect();
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&output_2.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "Query heads must be divisible by key/value heads")]
+    fn test_grouped_query_attention_panics_if_heads_not_divisible() {
+        MultiHeadAttentionConfig::new(12, 4)
+            .with_n_heads_kv(Some(3))
+            .init(&Default::default());
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
@@ -518,7 +753,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{mha}"),
-            "MultiHeadAttention {d_model: 2, n_heads: 4, d_k: 0, \
+            "MultiHeadAttention {d_model: 2, n_heads: 4, n_heads_kv: 4, d_k: 0, \
             dropout: 0.1, min_float: -10000, quiet_softmax: false, params: 24}"
         );
     }
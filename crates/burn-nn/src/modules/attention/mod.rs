@@ -1,7 +1,11 @@
 mod cross_attention;
+mod kv_cache;
 mod mask;
 mod mha;
+mod sdpa;
 
 pub use cross_attention::*;
+pub use kv_cache::*;
 pub use mask::*;
 pub use mha::*;
+pub use sdpa::*;
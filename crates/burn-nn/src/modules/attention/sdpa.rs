@@ -0,0 +1,176 @@
+use burn_core as burn;
+
+use burn::tensor::module::attention;
+use burn::tensor::ops::AttentionModuleOptions;
+use burn::tensor::{Bool, Tensor};
+
+use crate::DropoutConfig;
+
+/// Computes the scaled dot-product attention `softmax(QKᵗ * scale) · V`, with an optional
+/// boolean mask, causal masking and dropout on the attention weights.
+///
+/// Without active dropout, this calls the backend [attention](burn::tensor::module::attention)
+/// operation, which uses a fused (flash attention) kernel when the backend provides one and never
+/// materializes the `[seq_length_q, seq_length_k]` weights of every head. Dropout is only active
+/// on autodiff devices, following [Dropout](crate::Dropout); since the fused kernels don't support
+/// it, the attention is then composed from separate operations.
+///
+/// Rows where every key is masked attend to nothing and produce zeros.
+///
+/// # Arguments
+///
+/// - `mask`: Positions to mask, where `true` means the query can't attend to the key.
+/// - `dropout`: The probability of dropping an attention weight during training.
+/// - `options`: The scale, softcap, causal and sliding window masking. With `is_causal`, the causal
+///   boundary is aligned on the last query and key, so the cached keys of incremental decoding are
+///   visible. The sliding window is aligned the same way.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, n_heads, seq_length_q, d_k]`
+/// - key: `[batch_size, n_heads, seq_length_k, d_k]`
+/// - value: `[batch_size, n_heads, seq_length_k, d_v]`
+/// - mask: `[batch_size, n_heads, seq_length_q, seq_length_k]`
+/// - output: `[batch_size, n_heads, seq_length_q, d_v]`
+pub fn scaled_dot_product_attention(
+    query: Tensor<4>,
+    key: Tensor<4>,
+    value: Tensor<4>,
+    mask: Option<Tensor<4, Bool>>,
+    dropout: f64,
+    options: AttentionModuleOptions,
+) -> Tensor<4> {
+    if dropout == 0.0 || !query.device().is_autodiff() {
+        return attention(query, key, value, mask, None, options);
+    }
+
+    let [batch_size, n_heads, seq_length_q, d_k] = query.dims();
+    let seq_length_k = key.dims()[2];
+    let device = query.device();
+    let finfo = query
+        .dtype()
+        .finfo()
+        .expect("Attention requires float tensors");
+
+    let scale = options.scale.unwrap_or_else(|| 1.0 / (d_k as f64).sqrt());
+    let mut scores = query.matmul(key.transpose()).mul_scalar(scale);
+
+    if let Some(softcap) = options.softcap {
+        assert!(softcap > 0.0, "softcap must be positive, got {softcap}");
+        scores = scores.div_scalar(softcap).tanh().mul_scalar(softcap);
+    }
+    if let Some(mask) = mask {
+        scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+    }
+    if options.is_causal {
+        let offset = seq_length_k as i64 - seq_length_q as i64;
+        let causal = Tensor::<2, Bool>::tril_mask([seq_length_q, seq_length_k], offset, &device)
+            .reshape([1, 1, seq_length_q, seq_length_k])
+            .expand([batch_size, n_heads, seq_length_q, seq_length_k]);
+        scores = scores.mask_fill(causal, f32::NEG_INFINITY);
+    }
+    if let Some(window) = options.sliding_window {
+        let offset = seq_length_k as i64 - seq_length_q as i64;
+        let window = window as i64;
+        let outside = Tensor::<2, Bool>::triu_mask(
+            [seq_length_q, seq_length_k],
+            offset - window + 1,
+            &device,
+        )
+        .bool_or(Tensor::<2, Bool>::tril_mask(
+            [seq_length_q, seq_length_k],
+            offset + window - 1,
+            &device,
+        ))
+        .reshape([1, 1, seq_length_q, seq_length_k])
+        .expand([batch_size, n_heads, seq_length_q, seq_length_k]);
+        scores = scores.mask_fill(outside, f32::NEG_INFINITY);
+    }
+
+    // Softmax that yields zeros instead of NaNs for fully masked rows, by clamping the maximum
+    // to the smallest finite value and the sum to the smallest positive value.
+    let max = scores.clone().max_dim(3).clamp_min(finfo.min);
+    let numerator = (scores - max).exp();
+    let denominator = numerator.clone().sum_dim(3).clamp_min(finfo.min_positive);
+    let weights = numerator / denominator;
+
+    let weights = DropoutConfig::new(dropout).init().forward(weights);
+    weights.matmul(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::activation::softmax;
+    use burn::tensor::{Distribution, Tolerance};
+
+    fn qkv(device: &burn::tensor::Device) -> (Tensor<4>, Tensor<4>, Tensor<4>) {
+        let query = Tensor::<4>::random([2, 3, 4, 8], Distribution::Default, device);
+        let key = Tensor::<4>::random([2, 3, 6, 8], Distribution::Default, device);
+        let value = Tensor::<4>::random([2, 3, 6, 5], Distribution::Default, device);
+        (query, key, value)
+    }
+
+    #[test]
+    fn test_sdpa_matches_naive_attention() {
+        let device = Default::default();
+        let (query, key, value) = qkv(&device);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            0.1,
+            AttentionModuleOptions::default(),
+        );
+
+        let scores = query.matmul(key.transpose()).div_scalar(8f32.sqrt());
+        let expected = softmax(scores, 3).matmul(value);
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sdpa_causal_composition_matches_fused() {
+        let device = burn::tensor::Device::default().autodiff();
+        let (query, key, value) = qkv(&device);
+        let options = AttentionModuleOptions {
+            is_causal: true,
+            ..Default::default()
+        };
+
+        let fused = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            0.0,
+            options,
+        );
+        // A dropout that keeps almost every weight takes the composed path.
+        let composed = scaled_dot_product_attention(query, key, value, None, 1e-9, options);
+
+        composed
+            .into_data()
+            .assert_approx_eq::<f32>(&fused.into_data(), Tolerance::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sdpa_fully_masked_rows_are_zeros() {
+        let device = burn::tensor::Device::default().autodiff();
+        let (query, key, value) = qkv(&device);
+        let mask = Tensor::<4>::ones([2, 3, 4, 6], &device).greater_elem(0.0);
+
+        let output =
+            scaled_dot_product_attention(query, key, value, Some(mask), 0.5, Default::default());
+
+        output.into_data().assert_approx_eq::<f32>(
+            &Tensor::<4>::zeros([2, 3, 4, 5], &Default::default()).into_data(),
+            Tolerance::default(),
+        );
+    }
+}
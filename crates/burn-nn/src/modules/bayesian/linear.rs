@@ -0,0 +1,301 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay, Param};
+use burn::tensor::activation::softplus;
+use burn::tensor::module::linear;
+use burn::tensor::{Device, Distribution, Tensor};
+
+/// Modules with variational parameters, whose KL divergence to their prior is added to the loss.
+///
+/// The loss of variational inference (the negative ELBO) is the negative log-likelihood of the
+/// data plus the KL divergence of all the variational parameters, usually scaled by the inverse of
+/// the number of training items. Models implement this trait by collecting the terms of their
+/// submodules with [kl_divergence_sum].
+pub trait KlDivergence {
+    /// The KL divergence between the posterior and the prior of the parameters, or `None` when
+    /// the module has no variational parameters.
+    fn kl_divergence(&self) -> Option<Tensor<1>>;
+}
+
+/// Sums KL divergence terms, skipping the modules without variational parameters.
+pub fn kl_divergence_sum<I>(terms: I) -> Option<Tensor<1>>
+where
+    I: IntoIterator<Item = Option<Tensor<1>>>,
+{
+    terms.into_iter().flatten().reduce(|acc, term| acc + term)
+}
+
+impl<M: KlDivergence> KlDivergence for Option<M> {
+    fn kl_divergence(&self) -> Option<Tensor<1>> {
+        self.as_ref().and_then(KlDivergence::kl_divergence)
+    }
+}
+
+impl<M: KlDivergence> KlDivergence for Vec<M> {
+    fn kl_divergence(&self) -> Option<Tensor<1>> {
+        kl_divergence_sum(self.iter().map(KlDivergence::kl_divergence))
+    }
+}
+
+/// Configuration to create a [BayesLinear] layer using the
+/// [init function](BayesLinearConfig::init).
+#[derive(Config, Debug)]
+pub struct BayesLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The standard deviation of the zero-mean Gaussian prior of the parameters.
+    #[config(default = 1.0)]
+    pub prior_sigma: f64,
+    /// The initial value of `rho`, where the standard deviation of the posterior is
+    /// `softplus(rho)`. The default gives a standard deviation of about 0.0067.
+    #[config(default = -5.0)]
+    pub init_rho: f64,
+    /// The type of function used to initialize the mean of the parameters.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A linear layer with a factorized Gaussian posterior over its parameters (Bayes by Backprop).
+///
+/// Each forward pass samples the parameters with the reparameterization trick, so repeated passes
+/// on the same input give different outputs whose spread measures the uncertainty of the layer.
+/// [forward_mean](BayesLinear::forward_mean) uses the mean of the parameters instead.
+///
+/// Reference: "Weight Uncertainty in Neural Networks" <https://arxiv.org/abs/1505.05424>
+///
+/// Should be created with [BayesLinearConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct BayesLinear {
+    /// Mean of the weight, of shape `[d_input, d_output]`.
+    pub weight_mu: Param<Tensor<2>>,
+    /// Standard deviation parameter of the weight, where the standard deviation is
+    /// `softplus(weight_rho)`.
+    pub weight_rho: Param<Tensor<2>>,
+    /// Mean of the bias, of size `d_output`.
+    pub bias_mu: Option<Param<Tensor<1>>>,
+    /// Standard deviation parameter of the bias.
+    pub bias_rho: Option<Param<Tensor<1>>>,
+    /// The standard deviation of the prior.
+    pub prior_sigma: f64,
+}
+
+impl BayesLinearConfig {
+    /// Initialize a new [`BayesLinear`] module.
+    pub fn init(&self, device: &Device) -> BayesLinear {
+        assert!(self.prior_sigma > 0.0, "The prior standard deviation must be positive.");
+
+        let fan = (Some(self.d_input), Some(self.d_output));
+        let rho = Initializer::Constant {
+            value: self.init_rho,
+        };
+        let shape = [self.d_input, self.d_output];
+        let weight_mu = self.initializer.init_with(shape, fan.0, fan.1, device);
+        let weight_rho = rho.init_with(shape, fan.0, fan.1, device);
+        let (bias_mu, bias_rho) = if self.bias {
+            let shape = [self.d_output];
+            (
+                Some(self.initializer.init_with(shape, fan.0, fan.1, device)),
+                Some(rho.init_with(shape, fan.0, fan.1, device)),
+            )
+        } else {
+            (None, None)
+        };
+
+        BayesLinear {
+            weight_mu,
+            weight_rho,
+            bias_mu,
+            bias_rho,
+            prior_sigma: self.prior_sigma,
+        }
+    }
+}
+
+impl BayesLinear {
+    /// Applies the forward pass with parameters sampled from the posterior.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<D>) -> Tensor<D> {
+        let weight = sample(&self.weight_mu, &self.weight_rho);
+        let bias = self
+            .bias_mu
+            .as_ref()
+            .zip(self.bias_rho.as_ref())
+            .map(|(mu, rho)| sample(mu, rho));
+
+        linear(input, weight, bias)
+    }
+
+    /// Applies the forward pass with the mean of the parameters.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward_mean<const D: usize>(&self, input: Tensor<D>) -> Tensor<D> {
+        linear(
+            input,
+            self.weight_mu.val(),
+            self.bias_mu.as_ref().map(|b| b.val()),
+        )
+    }
+}
+
+impl KlDivergence for BayesLinear {
+    fn kl_divergence(&self) -> Option<Tensor<1>> {
+        let weight = gaussian_kl(&self.weight_mu, &self.weight_rho, self.prior_sigma);
+        let bias = self
+            .bias_mu
+            .as_ref()
+            .zip(self.bias_rho.as_ref())
+            .map(|(mu, rho)| gaussian_kl(mu, rho, self.prior_sigma));
+
+        kl_divergence_sum([Some(weight), bias])
+    }
+}
+
+/// Samples `mu + softplus(rho) * eps`, with `eps ~ N(0, 1)`.
+fn sample<const D: usize>(mu: &Param<Tensor<D>>, rho: &Param<Tensor<D>>) -> Tensor<D> {
+    let mu = mu.val();
+    let eps = mu.random_like(Distribution::Normal(0.0, 1.0));
+
+    mu + softplus(rho.val(), 1.0) * eps
+}
+
+/// The KL divergence between `N(mu, softplus(rho)^2)` and `N(0, prior_sigma^2)`, summed over the
+/// elements:
+///
+/// `ln(prior_sigma / sigma) + (sigma^2 + mu^2) / (2 * prior_sigma^2) - 1 / 2`
+fn gaussian_kl<const D: usize>(
+    mu: &Param<Tensor<D>>,
+    rho: &Param<Tensor<D>>,
+    prior_sigma: f64,
+) -> Tensor<1> {
+    let sigma = softplus(rho.val(), 1.0);
+    let prior_variance = prior_sigma * prior_sigma;
+
+    (sigma.clone().square() + mu.val().square())
+        .div_scalar(2.0 * prior_variance)
+        .sub(sigma.log())
+        .add_scalar(prior_sigma.ln() - 0.5)
+        .sum()
+}
+
+impl ModuleDisplay for BayesLinear {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_input, d_output] = self.weight_mu.shape().dims();
+        content
+            .add("d_input", &d_input)
+            .add("d_output", &d_output)
+            .add("bias", &self.bias_mu.is_some())
+            .add("prior_sigma", &self.prior_sigma)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use burn::tensor::{TensorData, Tolerance};
+
+    #[test]
+    fn test_bayes_linear_forward_mean() {
+        let device = Device::default();
+        let config = BayesLinearConfig::new(2, 3)
+            .with_initializer(Initializer::Constant { value: 1.0 });
+        let layer = config.init(&device);
+        let input = Tensor::<2>::from_data([[1.0, 2.0]], &device);
+
+        let output = layer.forward_mean(input);
+
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[4.0, 4.0, 4.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_bayes_linear_forward_is_stochastic() {
+        let device = Device::default();
+        device.seed(0);
+        let layer = BayesLinearConfig::new(4, 4)
+            .with_init_rho(0.0)
+            .init(&device);
+        let input = Tensor::<2>::ones([2, 4], &device);
+
+        let first = layer.forward(input.clone());
+        let second = layer.forward(input);
+
+        assert_eq!(first.dims(), [2, 4]);
+        assert_ne!(first.into_data(), second.into_data());
+    }
+
+    #[test]
+    fn test_bayes_linear_kl_divergence() {
+        let device = Device::default();
+        // softplus(ln(e - 1)) = 1, so the posterior matches the prior when the mean is zero.
+        let config = BayesLinearConfig::new(2, 2)
+            .with_initializer(Initializer::Zeros)
+            .with_init_rho((core::f64::consts::E - 1.0).ln());
+        let layer = config.init(&device);
+
+        let kl = layer.kl_divergence().unwrap();
+        kl.into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([0.0]), Tolerance::default());
+
+        // With a prior of standard deviation 2, each of the 6 parameters has a KL of
+        // ln(2) + 1 / 8 - 1 / 2.
+        let layer = config.with_prior_sigma(2.0).init(&device);
+        let expected = 6.0 * (2f32.ln() + 0.125 - 0.5);
+        let kl = kl_divergence_sum([layer.kl_divergence(), None]).unwrap();
+        kl.into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([expected]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_kl_divergence_collection() {
+        let device = Device::default();
+        let layers = vec![
+            BayesLinearConfig::new(2, 2).init(&device),
+            BayesLinearConfig::new(2, 2).with_bias(false).init(&device),
+        ];
+
+        let total = layers.kl_divergence().unwrap().into_scalar::<f32>();
+        let sum = layers
+            .iter()
+            .map(|layer| layer.kl_divergence().unwrap().into_scalar::<f32>())
+            .sum::<f32>();
+
+        assert!((total - sum).abs() < 1e-3);
+        assert!(None::<BayesLinear>.kl_divergence().is_none());
+    }
+
+    #[test]
+    fn display() {
+        let layer = BayesLinearConfig::new(3, 5).init(&Device::default());
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "BayesLinear {d_input: 3, d_output: 5, bias: true, prior_sigma: 1, params: 40}"
+        );
+    }
+}
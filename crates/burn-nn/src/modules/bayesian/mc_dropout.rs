@@ -0,0 +1,126 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::Module;
+use burn::tensor::Tensor;
+
+/// Configuration to create a [MonteCarloDropout] wrapper using the
+/// [init function](MonteCarloDropoutConfig::init).
+#[derive(Config, Debug)]
+pub struct MonteCarloDropoutConfig {
+    /// The number of stochastic forward passes.
+    #[config(default = 20)]
+    pub num_samples: usize,
+}
+
+/// The mean and the variance of the stochastic forward passes of a [MonteCarloDropout] wrapper.
+#[derive(Debug, Clone)]
+pub struct MonteCarloOutput<const D: usize> {
+    /// The mean of the outputs, used as the prediction.
+    pub mean: Tensor<D>,
+    /// The variance of the outputs, used as the uncertainty of the prediction.
+    pub variance: Tensor<D>,
+}
+
+/// Monte Carlo dropout: runs a model several times with its dropout active, and returns the mean
+/// and the variance of the outputs.
+///
+/// The dropout layers of the model must be created with
+/// [stochastic inference](crate::DropoutConfig::stochastic_inference), so that they stay active
+/// outside of training.
+///
+/// Reference: "Dropout as a Bayesian Approximation" <https://arxiv.org/abs/1506.02142>
+///
+/// Should be created with [MonteCarloDropoutConfig].
+#[derive(Module, Debug)]
+pub struct MonteCarloDropout<M> {
+    /// The wrapped model.
+    pub model: M,
+    /// The number of stochastic forward passes.
+    pub num_samples: usize,
+}
+
+impl MonteCarloDropoutConfig {
+    /// Initialize a new [Monte Carlo dropout](MonteCarloDropout) wrapper around the model.
+    pub fn init<M: Module>(&self, model: M) -> MonteCarloDropout<M> {
+        assert!(self.num_samples > 0, "Monte Carlo dropout requires at least 1 sample.");
+
+        MonteCarloDropout {
+            model,
+            num_samples: self.num_samples,
+        }
+    }
+}
+
+impl<M: Module> MonteCarloDropout<M> {
+    /// Applies the stochastic forward passes, and returns the mean and the variance of the
+    /// outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward pass of the model, usually `|model| model.forward(input.clone())`.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[..., any]` for both the mean and the variance.
+    pub fn forward<const D: usize, F>(&self, mut forward: F) -> MonteCarloOutput<D>
+    where
+        F: FnMut(&M) -> Tensor<D>,
+    {
+        let first = forward(&self.model);
+        let mut sum = first.clone();
+        let mut sum_squares = first.square();
+        for _ in 1..self.num_samples {
+            let output = forward(&self.model);
+            sum = sum + output.clone();
+            sum_squares = sum_squares + output.square();
+        }
+
+        let num_samples = self.num_samples as f64;
+        let mean = sum.div_scalar(num_samples);
+        let variance = (sum_squares.div_scalar(num_samples) - mean.clone().square()).clamp_min(0.0);
+
+        MonteCarloOutput { mean, variance }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dropout, DropoutConfig};
+    use burn::tensor::{Shape, TensorData, Tolerance};
+
+    #[test]
+    fn test_mc_dropout_deterministic_model() {
+        let dropout = DropoutConfig::new(0.5).init();
+        let mc = MonteCarloDropoutConfig::new()
+            .with_num_samples(4)
+            .init(dropout);
+        let input = Tensor::<2>::from_data([[1.0, 2.0]], &Default::default());
+
+        let output = mc.forward(|model: &Dropout| model.forward(input.clone()));
+
+        output.mean.into_data().assert_eq(&input.into_data(), false);
+        output
+            .variance
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[0.0, 0.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_mc_dropout_stochastic_model() {
+        let dropout = DropoutConfig::new(0.5).with_stochastic_inference(true).init();
+        let mc = MonteCarloDropoutConfig::new()
+            .with_num_samples(200)
+            .init(dropout);
+        let input = Tensor::<2>::ones(Shape::new([1, 100]), &Default::default());
+
+        let output = mc.forward(|model: &Dropout| model.forward(input.clone()));
+
+        // Each output is 0 or 2 with the same probability: the mean is 1 and the variance is 1.
+        let mean = output.mean.mean().into_scalar::<f32>();
+        let variance = output.variance.mean().into_scalar::<f32>();
+        assert!((mean - 1.0).abs() < 0.1, "mean: {mean}");
+        assert!((variance - 1.0).abs() < 0.1, "variance: {variance}");
+    }
+}
@@ -0,0 +1,5 @@
+mod linear;
+mod mc_dropout;
+
+pub use linear::*;
+pub use mc_dropout::*;
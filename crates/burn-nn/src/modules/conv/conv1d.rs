@@ -2,8 +2,12 @@ use alloc::format;
 
 use burn_core as burn;
 
-use crate::{PaddingConfig1d, conv::checks};
-use burn::tensor::{Device, Tensor, module::conv1d, ops::PaddedConvOptions};
+use crate::{PaddingConfig1d, conv::checks, padding::is_zero_padding};
+use burn::tensor::{
+    Device, Tensor,
+    module::conv1d,
+    ops::{PadMode, PaddedConvOptions},
+};
 use burn::{
     config::Config,
     module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay, Param},
@@ -33,6 +37,12 @@ pub struct Conv1dConfig {
     /// will automatically use asymmetric padding to preserve input dimensions.
     #[config(default = "PaddingConfig1d::Valid")]
     pub padding: PaddingConfig1d,
+    /// The padding mode, used to fill the padded regions.
+    ///
+    /// Zero padding is applied by the convolution, while other modes (e.g. `Reflect`, `Edge`
+    /// or `Circular`) pad the input before the convolution.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -64,6 +74,9 @@ pub struct Conv1d {
     /// Padding configuration.
     #[module(skip)]
     pub padding: PaddingConfig1d,
+    /// The padding mode.
+    #[module(skip)]
+    pub padding_mode: PadMode,
 }
 
 impl ModuleDisplay for Conv1d {
@@ -129,6 +142,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
+            padding_mode: self.padding_mode,
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -152,6 +166,12 @@ impl Conv1d {
             self.padding
                 .calculate_padding_1d_pair(length, self.kernel_size, self.stride);
 
+        let (input, left, right) = if is_zero_padding(&self.padding_mode) {
+            (input, left, right)
+        } else {
+            (input.pad([(left, right)], self.padding_mode), 0, 0)
+        };
+
         let options = PaddedConvOptions::asymmetric(
             [self.stride],
             [left],
@@ -280,6 +300,31 @@ mod tests {
         assert_eq!(output.dims(), [1, 3, 6]);
     }
 
+    #[test]
+    fn padding_modes_forward() {
+        let device = Default::default();
+        let config = Conv1dConfig::new(1, 1, 3)
+            .with_padding(PaddingConfig1d::Explicit(1, 1))
+            .with_initializer(Initializer::Constant { value: 1.0 })
+            .with_bias(false);
+        let input = Tensor::<3>::from_data([[[1.0, 2.0, 3.0]]], &device);
+
+        // Reflect: [2, 1, 2, 3, 2]
+        let conv = config
+            .clone()
+            .with_padding_mode(PadMode::Reflect)
+            .init(&device);
+        conv.forward(input.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[[5.0, 6.0, 7.0]]]), false);
+
+        // Circular: [3, 1, 2, 3, 1]
+        let conv = config.with_padding_mode(PadMode::Circular).init(&device);
+        conv.forward(input)
+            .into_data()
+            .assert_eq(&TensorData::from([[[6.0, 6.0, 6.0]]]), false);
+    }
+
     #[test]
     fn initializer_fan_out() {
         let device = Device::default();
@@ -3,13 +3,14 @@ use alloc::format;
 use burn_core as burn;
 
 use crate::PaddingConfig2d;
+use crate::padding::is_zero_padding;
 use burn::config::Config;
 use burn::module::Initializer;
 use burn::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
 use burn::tensor::Device;
 use burn::tensor::Tensor;
 use burn::tensor::module::conv2d;
-use burn::tensor::ops::PaddedConvOptions;
+use burn::tensor::ops::{PadMode, PaddedConvOptions};
 
 use crate::conv::checks;
 
@@ -35,6 +36,12 @@ pub struct Conv2dConfig {
     /// will automatically use asymmetric padding to preserve input dimensions.
     #[config(default = "PaddingConfig2d::Valid")]
     pub padding: PaddingConfig2d,
+    /// The padding mode, used to fill the padded regions.
+    ///
+    /// Zero padding is applied by the convolution, while other modes (e.g. `Reflect`, `Edge`
+    /// or `Circular`) pad the input before the convolution.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -66,6 +73,9 @@ pub struct Conv2d {
     /// The padding configuration.
     #[module(skip)]
     pub padding: PaddingConfig2d,
+    /// The padding mode.
+    #[module(skip)]
+    pub padding_mode: PadMode,
 }
 
 impl Conv2dConfig {
@@ -105,6 +115,7 @@ impl Conv2dConfig {
             kernel_size: self.kernel_size,
             dilation: self.dilation,
             padding: self.padding.clone(),
+            padding_mode: self.padding_mode,
             groups: self.groups,
         }
     }
@@ -172,6 +183,13 @@ impl Conv2d {
             &self.stride,
         );
 
+        let (input, [top, left], [bottom, right]) = if is_zero_padding(&self.padding_mode) {
+            (input, [top, left], [bottom, right])
+        } else {
+            let input = input.pad([(top, bottom), (left, right)], self.padding_mode);
+            (input, [0, 0], [0, 0])
+        };
+
         let options = PaddedConvOptions::asymmetric(
             self.stride,
             [top, left],
@@ -325,6 +343,25 @@ mod tests {
         assert_eq!(output.dims(), [1, 3, 6, 9]);
     }
 
+    #[test]
+    fn edge_padding_mode_forward() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([1, 1], [3, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .with_padding_mode(PadMode::Edge)
+            .with_initializer(Initializer::Constant { value: 1.0 })
+            .with_bias(false);
+        let conv = config.init(&device);
+
+        let input = Tensor::<4>::ones([1, 1, 3, 3], &device);
+        let output = conv.forward(input);
+
+        // Replicated borders see the full kernel of ones, unlike zero padding
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[[9.0; 3]; 3]]]), false);
+    }
+
     #[test]
     fn symmetric_explicit_padding_forward() {
         let device = Default::default();
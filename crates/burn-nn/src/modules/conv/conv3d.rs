@@ -3,13 +3,14 @@ use alloc::format;
 use burn_core as burn;
 
 use crate::PaddingConfig3d;
+use crate::padding::is_zero_padding;
 use burn::config::Config;
 use burn::module::Initializer;
 use burn::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
 use burn::tensor::Device;
 use burn::tensor::Tensor;
 use burn::tensor::module::conv3d;
-use burn::tensor::ops::ConvOptions;
+use burn::tensor::ops::{ConvOptions, PadMode};
 
 use crate::conv::checks;
 
@@ -32,6 +33,12 @@ pub struct Conv3dConfig {
     /// The padding configuration.
     #[config(default = "PaddingConfig3d::Valid")]
     pub padding: PaddingConfig3d,
+    /// The padding mode, used to fill the padded regions.
+    ///
+    /// Zero padding is applied by the convolution, while other modes (e.g. `Reflect`, `Edge`
+    /// or `Circular`) pad the input before the convolution.
+    #[config(default = "PadMode::Constant(0.0)")]
+    pub padding_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -63,6 +70,9 @@ pub struct Conv3d {
     /// The padding configuration.
     #[module(skip)]
     pub padding: PaddingConfig3d,
+    /// The padding mode.
+    #[module(skip)]
+    pub padding_mode: PadMode,
 }
 
 impl Conv3dConfig {
@@ -106,6 +116,7 @@ impl Conv3dConfig {
             kernel_size: self.kernel_size,
             dilation: self.dilation,
             padding: self.padding.clone(),
+            padding_mode: self.padding_mode,
             groups: self.groups,
         }
     }
@@ -160,6 +171,18 @@ impl Conv3d {
             &self.kernel_size,
             &self.stride,
         );
+
+        let (input, padding) = if is_zero_padding(&self.padding_mode) {
+            (input, padding)
+        } else {
+            let [depth, height, width] = padding;
+            let input = input.pad(
+                [(depth, depth), (height, height), (width, width)],
+                self.padding_mode,
+            );
+            (input, [0, 0, 0])
+        };
+
         conv3d(
             input,
             self.weight.val(),
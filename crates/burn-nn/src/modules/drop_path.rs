@@ -0,0 +1,155 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use burn::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [DropPath](DropPath) layer using the [init function](DropPathConfig::init).
+#[derive(Config, Debug)]
+pub struct DropPathConfig {
+    /// The probability of dropping the residual branch of each sample during training.
+    pub prob: f64,
+    /// Scales the kept samples by `1 / (1 - prob)` during training, so that the expected output
+    /// matches the inference output.
+    #[config(default = true)]
+    pub scale_by_keep: bool,
+}
+
+/// Stochastic depth: randomly drops the residual branch of whole samples during training.
+///
+/// As described in the paper [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382),
+/// the output of a residual branch is set to zero for each sample of the batch with probability
+/// `prob`, so that the block reduces to its skip connection. It should be applied to the branch
+/// before the residual addition, e.g. `x + drop_path.forward(block.forward(x))`.
+///
+/// The kept samples are scaled by `1 / (1 - prob)` during training, and the module is the identity
+/// during inference.
+///
+/// Should be created with [DropPathConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct DropPath {
+    /// The probability of dropping the residual branch of each sample during training.
+    pub prob: f64,
+    /// Scales the kept samples by `1 / (1 - prob)` during training.
+    pub scale_by_keep: bool,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        if self.prob < 0.0 || self.prob > 1.0 {
+            panic!(
+                "DropPath probability should be between 0 and 1, but got {}",
+                self.prob
+            );
+        }
+        DropPath {
+            prob: self.prob,
+            scale_by_keep: self.scale_by_keep,
+        }
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [DropPath](DropPath) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<D>) -> Tensor<D> {
+        if !input.device().is_autodiff() || self.prob == 0.0 {
+            return input;
+        }
+
+        // One Bernoulli draw per sample, broadcast over the other dimensions.
+        let prob_keep = 1.0 - self.prob;
+        let mut shape = [1; D];
+        shape[0] = input.dims()[0];
+        let keep = Tensor::<D>::random(
+            shape,
+            Distribution::Bernoulli(prob_keep),
+            (&input.device(), input.dtype()),
+        );
+        let x = input * keep;
+
+        if self.scale_by_keep && prob_keep > 0.0 {
+            x * (1.0 / prob_keep)
+        } else {
+            x
+        }
+    }
+}
+
+impl ModuleDisplay for DropPath {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("prob", &self.prob)
+            .add("scale_by_keep", &self.scale_by_keep)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        use burn::tensor::Device;
+        let device = Device::default().autodiff();
+        let tensor = Tensor::<3>::ones(Shape::new([100, 4, 8]), &device);
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor);
+
+        // Every sample is either dropped or scaled as a whole.
+        let per_sample: Tensor<2> = output.reshape([100, 32]);
+        let min = per_sample.clone().min_dim(1);
+        let max = per_sample.max_dim(1);
+        assert_eq!(min.to_data(), max.to_data());
+
+        let values = max.into_data().to_vec::<f32>().unwrap();
+        assert!(values.iter().all(|&v| v == 0.0 || v == 2.0));
+        assert!(values.contains(&0.0) && values.contains(&2.0));
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<2>::ones(Shape::new([100, 100]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+
+    #[test]
+    fn display() {
+        let config = DropPathConfig::new(0.1);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "DropPath {prob: 0.1, scale_by_keep: true}"
+        );
+    }
+
+    #[test]
+    #[should_panic = "DropPath probability should be between 0 and 1,"]
+    fn drop_path_prob_invalid() {
+        let config = DropPathConfig::new(1.5);
+        let _layer = config.init();
+    }
+}
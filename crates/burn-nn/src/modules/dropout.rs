@@ -9,6 +9,9 @@ use burn::tensor::{Distribution, Tensor};
 pub struct DropoutConfig {
     /// The probability of randomly zeroes some elements of the input tensor during training.
     pub prob: f64,
+    /// Keeps the dropout active during inference, for Monte Carlo dropout.
+    #[config(default = false)]
+    pub stochastic_inference: bool,
 }
 
 /// Set at random some elements of the input tensor to zero during training.
@@ -18,12 +21,17 @@ pub struct DropoutConfig {
 ///
 /// The input is also scaled during training to `1 / (1 - prob_keep)`.
 ///
+/// With [stochastic inference](DropoutConfig::stochastic_inference), the dropout is also applied
+/// during inference, which is used by [MonteCarloDropout](crate::bayesian::MonteCarloDropout).
+///
 /// Should be created with [DropoutConfig].
 #[derive(Module, Debug)]
 #[module(custom_display)]
 pub struct Dropout {
     /// The probability of randomly zeroes some elements of the input tensor during training.
     pub prob: f64,
+    /// Keeps the dropout active during inference.
+    pub stochastic_inference: bool,
 }
 
 impl DropoutConfig {
@@ -35,7 +43,10 @@ impl DropoutConfig {
                 self.prob
             );
         }
-        Dropout { prob: self.prob }
+        Dropout {
+            prob: self.prob,
+            stochastic_inference: self.stochastic_inference,
+        }
     }
 }
 
@@ -49,7 +60,8 @@ impl Dropout {
     /// - input: `[..., any]`
     /// - output: `[..., any]`
     pub fn forward<const D: usize>(&self, input: Tensor<D>) -> Tensor<D> {
-        if !input.device().is_autodiff() || self.prob == 0.0 {
+        let active = input.device().is_autodiff() || self.stochastic_inference;
+        if !active || self.prob == 0.0 {
             return input;
         }
 
@@ -101,6 +113,16 @@ mod tests {
         assert_eq!(tensor.to_data(), output.to_data());
     }
 
+    #[test]
+    fn with_stochastic_inference_should_change_input() {
+        let tensor = Tensor::<2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = DropoutConfig::new(0.5).with_stochastic_inference(true).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+    }
+
     #[test]
     fn display() {
         let config = DropoutConfig::new(0.5);
@@ -0,0 +1,230 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::Initializer;
+use burn::module::Module;
+use burn::module::Param;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::{Device, IndexingUpdateOp, Int, Tensor};
+
+/// The reduction applied to the embeddings of each bag of an [EmbeddingBag](EmbeddingBag).
+#[derive(Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// The sum of the embeddings, weighted by the per-index weights when provided.
+    Sum,
+    /// The mean of the embeddings.
+    Mean,
+    /// The elementwise maximum of the embeddings.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer using the [init function](EmbeddingBagConfig::init).
+#[derive(Config, Debug)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The reduction applied to the embeddings of each bag.
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Lookup table that pools the vectors of bags of indices of variable lengths.
+///
+/// The bags are given as a flat sequence of indices with the offset at which each bag starts,
+/// so no padding is needed. The vectors of each bag are reduced with a scatter over the bags,
+/// without materializing a `[batch_size, max_bag_length, d_model]` tensor.
+///
+/// Should be created with [EmbeddingBagConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct EmbeddingBag {
+    /// The learnable weights of the module of shape `[n_embedding, d_model]` initialized
+    /// from a normal distribution `N(0, 1)`.
+    pub weight: Param<Tensor<2>>,
+    /// The reduction applied to the embeddings of each bag.
+    #[module(skip)]
+    pub mode: EmbeddingBagMode,
+}
+
+impl ModuleDisplay for EmbeddingBag {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [n_embedding, d_model] = self.weight.shape().dims();
+        content
+            .add("n_embedding", &n_embedding)
+            .add("d_model", &d_model)
+            .add_debug_attribute("mode", &self.mode)
+            .optional()
+    }
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init(&self, device: &Device) -> EmbeddingBag {
+        let weight = self
+            .initializer
+            .init([self.n_embedding, self.d_model], device);
+
+        EmbeddingBag {
+            weight,
+            mode: self.mode.clone(),
+        }
+    }
+}
+
+impl EmbeddingBag {
+    /// Applies the forward pass on the bags of indices.
+    ///
+    /// Bag `b` holds the indices `input[offsets[b]..offsets[b + 1]]`, the last bag ending at the
+    /// end of the input. The offsets must start at zero and be non-decreasing; equal offsets
+    /// delimit empty bags, whose output is zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `input`: The flat indices of all the bags.
+    /// - `offsets`: The start of each bag in `input`.
+    /// - `per_sample_weights`: The weight of each index, only supported with the
+    ///   [sum](EmbeddingBagMode::Sum) reduction.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[num_indices]`
+    /// - offsets: `[batch_size]`
+    /// - per_sample_weights: `[num_indices]`
+    /// - output: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        input: Tensor<1, Int>,
+        offsets: Tensor<1, Int>,
+        per_sample_weights: Option<Tensor<1>>,
+    ) -> Tensor<2> {
+        let [num_indices] = input.dims();
+        let [batch_size] = offsets.dims();
+        let d_model = self.weight.dims()[1];
+
+        let bag_ids = Self::bag_ids(offsets, num_indices);
+        let mut embeddings = self.weight.val().select(0, input);
+
+        if let Some(weights) = per_sample_weights {
+            assert_eq!(
+                self.mode,
+                EmbeddingBagMode::Sum,
+                "Per-sample weights are only supported with the sum reduction"
+            );
+            embeddings = embeddings
+                * weights
+                    .reshape([num_indices, 1])
+                    .expand([num_indices, d_model]);
+        }
+
+        match self.mode {
+            EmbeddingBagMode::Sum => embeddings.segment_sum(bag_ids, batch_size),
+            EmbeddingBagMode::Mean => embeddings.segment_mean(bag_ids, batch_size),
+            EmbeddingBagMode::Max => embeddings.segment_max(bag_ids, batch_size),
+        }
+    }
+
+    /// The bag of each index: the number of bags, after the first one, that start at or before it.
+    fn bag_ids(offsets: Tensor<1, Int>, num_indices: usize) -> Tensor<1, Int> {
+        let [batch_size] = offsets.dims();
+        let device = offsets.device();
+        // One extra slot for the empty bags that start at the end of the input.
+        let starts = Tensor::<1, Int>::zeros([num_indices + 1], &device);
+        if batch_size < 2 {
+            return starts.slice(0..num_indices);
+        }
+
+        let offsets = offsets.slice(1..batch_size);
+        starts
+            .select_assign(
+                0,
+                offsets.clone(),
+                offsets.ones_like(),
+                IndexingUpdateOp::Add,
+            )
+            .slice(0..num_indices)
+            .cumsum(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance};
+    type FT = f32;
+
+    fn embedding_bag(mode: EmbeddingBagMode) -> EmbeddingBag {
+        let device = Default::default();
+        let mut embedding_bag = EmbeddingBagConfig::new(4, 2).with_mode(mode).init(&device);
+        embedding_bag.weight = Param::from_tensor(Tensor::from_data(
+            [[1.0, -1.0], [2.0, 0.0], [3.0, 5.0], [-4.0, 1.0]],
+            &device,
+        ));
+        embedding_bag
+    }
+
+    fn forward(mode: EmbeddingBagMode, weights: Option<[f32; 5]>) -> TensorData {
+        let device = Default::default();
+        // Bags: [0, 2], [], [1, 3, 2]
+        let input = Tensor::<1, Int>::from_data([0, 2, 1, 3, 2], &device);
+        let offsets = Tensor::<1, Int>::from_data([0, 2, 2], &device);
+        let weights = weights.map(|weights| Tensor::<1>::from_data(weights, &device));
+
+        embedding_bag(mode)
+            .forward(input, offsets, weights)
+            .into_data()
+    }
+
+    #[test]
+    fn test_embedding_bag_reductions() {
+        forward(EmbeddingBagMode::Sum, None).assert_approx_eq::<FT>(
+            &TensorData::from([[4.0, 4.0], [0.0, 0.0], [1.0, 6.0]]),
+            Tolerance::default(),
+        );
+        forward(EmbeddingBagMode::Mean, None).assert_approx_eq::<FT>(
+            &TensorData::from([[2.0, 2.0], [0.0, 0.0], [1.0 / 3.0, 2.0]]),
+            Tolerance::default(),
+        );
+        forward(EmbeddingBagMode::Max, None).assert_approx_eq::<FT>(
+            &TensorData::from([[3.0, 5.0], [0.0, 0.0], [3.0, 5.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_embedding_bag_per_sample_weights() {
+        let output = forward(EmbeddingBagMode::Sum, Some([1.0, 0.5, 2.0, 1.0, 0.0]));
+
+        output.assert_approx_eq::<FT>(
+            &TensorData::from([[2.5, 1.5], [0.0, 0.0], [0.0, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "only supported with the sum reduction"]
+    fn test_embedding_bag_weights_require_sum() {
+        forward(EmbeddingBagMode::Mean, Some([1.0; 5]));
+    }
+
+    #[test]
+    fn display() {
+        let config = EmbeddingBagConfig::new(100, 10);
+        let embed = config.init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{embed}"),
+            "EmbeddingBag {n_embedding: 100, d_model: 10, mode: Mean, params: 1000}"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::config::Config;
+use burn::module::Module;
+use burn::tensor::Tensor;
+
+/// How the outputs of the models of an [Ensemble] are combined.
+#[derive(Config, Debug, PartialEq)]
+pub enum EnsembleFusion {
+    /// The mean of the outputs.
+    Mean,
+    /// The weighted mean of the outputs, with one weight per model.
+    ///
+    /// The weights are normalized by their sum.
+    Weighted(Vec<f64>),
+    /// Majority voting for classification.
+    ///
+    /// Each model votes for the class with the highest score along the last dimension, and the
+    /// output is the fraction of the models that voted for each class. Ties give a vote to each
+    /// of the tied classes.
+    Voting,
+}
+
+/// Configuration to create an [Ensemble] using the [init function](EnsembleConfig::init).
+#[derive(Config, Debug)]
+pub struct EnsembleConfig {
+    /// How the outputs of the models are combined.
+    #[config(default = "EnsembleFusion::Mean")]
+    pub fusion: EnsembleFusion,
+}
+
+/// Holds several models and combines their outputs.
+///
+/// The models don't need to share an architecture, only the shape of their outputs. Since
+/// modules don't share a forward signature, the forward pass of each model is given as a
+/// closure to [forward](Ensemble::forward).
+///
+/// Should be created with [EnsembleConfig].
+#[derive(Module, Debug)]
+pub struct Ensemble<M> {
+    /// The models of the ensemble.
+    pub models: Vec<M>,
+    /// How the outputs of the models are combined.
+    #[module(skip)]
+    pub fusion: EnsembleFusion,
+}
+
+impl EnsembleConfig {
+    /// Initialize a new [ensemble](Ensemble) of the given models.
+    pub fn init<M: Module>(&self, models: Vec<M>) -> Ensemble<M> {
+        assert!(!models.is_empty(), "An ensemble requires at least 1 model.");
+        if let EnsembleFusion::Weighted(weights) = &self.fusion {
+            assert_eq!(
+                weights.len(),
+                models.len(),
+                "An ensemble requires one weight per model."
+            );
+            assert!(
+                weights.iter().sum::<f64>() > 0.0,
+                "The ensemble weights should have a positive sum."
+            );
+        }
+
+        Ensemble {
+            models,
+            fusion: self.fusion.clone(),
+        }
+    }
+}
+
+impl<M: Module> Ensemble<M> {
+    /// Applies the forward pass of each model, and combines the outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward pass of a model, usually `|model| model.forward(input.clone())`.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[..., any]`, the same for all the models.
+    pub fn forward<const D: usize, F>(&self, forward: F) -> Tensor<D>
+    where
+        F: FnMut(&M) -> Tensor<D>,
+    {
+        let outputs = self.models.iter().map(forward);
+        let num_models = self.models.len() as f64;
+
+        match &self.fusion {
+            EnsembleFusion::Mean => Self::sum(outputs).div_scalar(num_models),
+            EnsembleFusion::Weighted(weights) => {
+                let total = weights.iter().sum::<f64>();
+                Self::sum(
+                    outputs
+                        .zip(weights)
+                        .map(|(output, &weight)| output.mul_scalar(weight / total)),
+                )
+            }
+            EnsembleFusion::Voting => Self::sum(outputs.map(|output| {
+                let max = output.clone().max_dim(D - 1).expand(output.shape());
+                output.greater_equal(max).float()
+            }))
+            .div_scalar(num_models),
+        }
+    }
+
+    /// Applies the forward pass of each model, without combining the outputs.
+    pub fn forward_all<const D: usize, F>(&self, forward: F) -> Vec<Tensor<D>>
+    where
+        F: FnMut(&M) -> Tensor<D>,
+    {
+        self.models.iter().map(forward).collect()
+    }
+
+    /// The number of models in the ensemble.
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Returns true if the ensemble has no models.
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    fn sum<const D: usize>(outputs: impl Iterator<Item = Tensor<D>>) -> Tensor<D> {
+        outputs
+            .reduce(|acc, output| acc + output)
+            .expect("An ensemble requires at least 1 model.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use alloc::vec;
+    use burn::module::Param;
+    use burn::tensor::{Device, TensorData, Tolerance};
+
+    fn models(device: &Device) -> Vec<Linear> {
+        [1.0, 2.0, 6.0]
+            .into_iter()
+            .map(|scale| Linear {
+                weight: Param::from_tensor(Tensor::from_data([[scale, 0.0], [0.0, 1.0]], device)),
+                bias: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ensemble_mean() {
+        let device = Default::default();
+        let ensemble = EnsembleConfig::new().init(models(&device));
+        let input = Tensor::<2>::from_data([[1.0, 2.0]], &device);
+
+        let output = ensemble.forward(|model| model.forward(input.clone()));
+
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[3.0, 2.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ensemble_weighted() {
+        let device = Default::default();
+        let config =
+            EnsembleConfig::new().with_fusion(EnsembleFusion::Weighted(vec![2.0, 1.0, 1.0]));
+        let ensemble = config.init(models(&device));
+        let input = Tensor::<2>::from_data([[1.0, 2.0]], &device);
+
+        let output = ensemble.forward(|model| model.forward(input.clone()));
+
+        // (2 * 1 + 2 + 6) / 4
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[2.5, 2.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ensemble_voting() {
+        let device = Default::default();
+        let config = EnsembleConfig::new().with_fusion(EnsembleFusion::Voting);
+        let ensemble = config.init(models(&device));
+        let input = Tensor::<2>::from_data([[1.0, 3.0]], &device);
+
+        let output = ensemble.forward(|model| model.forward(input.clone()));
+
+        // The outputs are [1, 3], [2, 3] and [6, 3].
+        output.into_data().assert_approx_eq::<f32>(
+            &TensorData::from([[1.0 / 3.0, 2.0 / 3.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "An ensemble requires one weight per model."]
+    fn test_ensemble_weighted_invalid() {
+        let device = Default::default();
+        let config = EnsembleConfig::new().with_fusion(EnsembleFusion::Weighted(vec![1.0]));
+
+        let _ensemble = config.init(models(&device));
+    }
+
+    #[test]
+    fn test_ensemble_num_params() {
+        let device = Default::default();
+        let ensemble = EnsembleConfig::new().init(models(&device));
+
+        assert_eq!(ensemble.len(), 3);
+        assert_eq!(ensemble.num_params(), 12);
+    }
+}
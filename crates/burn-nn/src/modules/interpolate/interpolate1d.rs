@@ -36,6 +36,11 @@ pub struct Interpolate1dConfig {
     /// If `false`, half-pixel coordinate mapping is used instead.
     #[config(default = true)]
     pub align_corners: bool,
+
+    /// If `true`, the linear and cubic modes widen their filter when downsampling, so that all
+    /// the input values contribute to the output.
+    #[config(default = false)]
+    pub antialias: bool,
 }
 
 /// Interpolate module for resizing 1D tensors with shape [N, C, L].
@@ -65,6 +70,10 @@ pub struct Interpolate1d {
 
     /// Whether to align corner pixels
     pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    #[module(skip)]
+    pub antialias: bool,
 }
 
 impl Interpolate1dConfig {
@@ -75,6 +84,7 @@ impl Interpolate1dConfig {
             scale_factor: self.scale_factor,
             mode: self.mode,
             align_corners: self.align_corners,
+            antialias: self.antialias,
         }
     }
 }
@@ -112,7 +122,8 @@ impl Interpolate1d {
             input,
             [1, output_size],
             InterpolateOptions::new(self.mode.clone().into())
-                .with_align_corners(self.align_corners),
+                .with_align_corners(self.align_corners)
+                .with_antialias(self.antialias),
         );
 
         result.squeeze_dims(&[2])
@@ -36,6 +36,11 @@ pub struct Interpolate2dConfig {
     /// If `false`, half-pixel coordinate mapping is used instead.
     #[config(default = true)]
     pub align_corners: bool,
+
+    /// If `true`, the linear and cubic modes widen their filter when downsampling, so that all
+    /// the input values contribute to the output.
+    #[config(default = false)]
+    pub antialias: bool,
 }
 
 /// Interpolate module for resizing tensors with shape [N, C, H, W].
@@ -66,6 +71,10 @@ pub struct Interpolate2d {
 
     /// Whether to align corner pixels
     pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    #[module(skip)]
+    pub antialias: bool,
 }
 
 impl Interpolate2dConfig {
@@ -76,6 +85,7 @@ impl Interpolate2dConfig {
             scale_factor: self.scale_factor,
             mode: self.mode,
             align_corners: self.align_corners,
+            antialias: self.antialias,
         }
     }
 }
@@ -107,7 +117,8 @@ impl Interpolate2d {
             input,
             output_size,
             InterpolateOptions::new(self.mode.clone().into())
-                .with_align_corners(self.align_corners),
+                .with_align_corners(self.align_corners)
+                .with_antialias(self.antialias),
         )
     }
 }
@@ -0,0 +1,241 @@
+use alloc::format;
+
+use burn::tensor::module::interpolate3d;
+
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use burn::tensor::Tensor;
+use burn::tensor::ops::InterpolateOptions;
+
+use super::InterpolateMode;
+
+/// Configuration for the 3D interpolation module.
+///
+/// This struct defines the configuration options for the 3D interpolation operation.
+/// It allows specifying the output size, scale factor, and interpolation mode.
+#[derive(Config, Debug)]
+pub struct Interpolate3dConfig {
+    /// Output size of the interpolated tensor.
+    /// If specified, this takes precedence over `scale_factor`.
+    #[config(default = "None")]
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor.
+    /// This is used when `output_size` is not specified.
+    #[config(default = "None")]
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode to use for resizing.
+    /// Determines how the output values are calculated.
+    #[config(default = "InterpolateMode::Nearest")]
+    pub mode: InterpolateMode,
+
+    /// If `true`, the input and output tensors are aligned by their corner pixels.
+    /// If `false`, half-pixel coordinate mapping is used instead.
+    #[config(default = true)]
+    pub align_corners: bool,
+
+    /// If `true`, the linear and cubic modes widen their filter when downsampling, so that all
+    /// the input values contribute to the output.
+    #[config(default = false)]
+    pub antialias: bool,
+}
+
+/// Interpolate module for resizing volumetric tensors with shape [N, C, D, H, W].
+///
+/// This struct represents a 3D interpolation module that can resize volumes or videos
+/// using various interpolation methods, such as trilinear interpolation with the
+/// [linear](InterpolateMode::Linear) mode. It provides flexibility in specifying
+/// either an output size or a scale factor for resizing, along with options
+/// for the interpolation mode.
+///
+/// The module can be created using the [Interpolate3dConfig] struct and the
+/// `init` method, which returns an instance of the [Interpolate3d] struct.
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Interpolate3d {
+    /// Output size of the interpolated tensor
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode used for resizing
+    #[module(skip)]
+    pub mode: InterpolateMode,
+
+    /// Whether to align corner pixels
+    pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    #[module(skip)]
+    pub antialias: bool,
+}
+
+impl Interpolate3dConfig {
+    /// Initialize the interpolation module
+    pub fn init(self) -> Interpolate3d {
+        Interpolate3d {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: self.mode,
+            align_corners: self.align_corners,
+            antialias: self.antialias,
+        }
+    }
+}
+
+impl Interpolate3d {
+    /// Performs the forward pass of the 3D interpolation module
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor with shape [N, C, D, H, W]
+    ///
+    /// # Returns
+    ///
+    /// Resized tensor with shape [N, C, D', H', W'], where D', H' and W' are determined by
+    /// the output_size or scale_factor specified in the module configuration
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let input = Tensor::<5>::random([1, 3, 8, 64, 64], Distribution::Uniform(0.0, 1.0), &device);
+    /// let interpolate = Interpolate3dConfig::new()
+    ///     .with_output_size(Some([16, 128, 128]))
+    ///     .with_mode(InterpolateMode::Linear)
+    ///     .init();
+    /// let output = interpolate.forward(input);
+    /// assert_eq!(output.dims(), [1, 3, 16, 128, 128]);
+    /// ```
+    pub fn forward(&self, input: Tensor<5>) -> Tensor<5> {
+        let output_size = calculate_output_size(input.dims(), self.output_size, self.scale_factor);
+        interpolate3d(
+            input,
+            output_size,
+            InterpolateOptions::new(self.mode.clone().into())
+                .with_align_corners(self.align_corners)
+                .with_antialias(self.antialias),
+        )
+    }
+}
+
+/// Calculates the output size for tensor interpolation.
+///
+/// # Arguments
+///
+/// * `input_dims` - The dimensions of the input tensor [N, C, D, H, W].
+/// * `output_size` - Optional desired output size [D', H', W'].
+/// * `scale_factor` - Optional scale factor for depth, height and width.
+///
+/// # Returns
+///
+/// The calculated output size [D', H', W'].
+///
+/// # Panics
+///
+/// Panics if neither `output_size` nor `scale_factor` is provided,
+/// or if the scale factor results in dimensions exceeding usize::MAX.
+fn calculate_output_size(
+    input_dims: [usize; 5],
+    output_size: Option<[usize; 3]>,
+    scale_factor: Option<[f32; 3]>,
+) -> [usize; 3] {
+    match (output_size, scale_factor) {
+        (Some(output_size), None) => {
+            // Use provided
+            output_size
+        }
+        (None, Some(scale_factor)) => {
+            // Calculate output size based on scale factor
+            let [_, _, d, h, w] = input_dims;
+            let dims = [d, h, w];
+
+            [0, 1, 2].map(|i| {
+                let new_dim = (dims[i] as f64) * (scale_factor[i] as f64);
+
+                if new_dim > usize::MAX as f64 {
+                    panic!("Scale factor is too large");
+                }
+
+                new_dim as usize
+            })
+        }
+        _ => panic!("Either output_size or scale_factor must be provided"),
+    }
+}
+
+impl ModuleDisplay for Interpolate3d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add_debug_attribute("mode", &self.mode)
+            .add("output_size", &format!("{:?}", self.output_size))
+            .add("scale_factor", &self.scale_factor)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::tensor::Distribution;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate_output_size() {
+        let input_dims = [1, 1, 2, 4, 4];
+
+        let output_size = calculate_output_size(input_dims, Some([3, 2, 2]), None);
+        assert_eq!(output_size, [3, 2, 2]);
+
+        let output_size = calculate_output_size(input_dims, None, Some([2.0, 0.5, 1.5]));
+        assert_eq!(output_size, [4, 2, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Either output_size or scale_factor must be provided")]
+    fn test_missing_params() {
+        calculate_output_size([1, 1, 2, 4, 4], None, None);
+    }
+
+    #[test]
+    fn test_module() {
+        let input = Tensor::<5>::random(
+            [2, 3, 2, 4, 4],
+            Distribution::Uniform(0.0, 1.0),
+            &Default::default(),
+        );
+
+        let config = Interpolate3dConfig::new()
+            .with_output_size(Some([4, 8, 8]))
+            .with_mode(InterpolateMode::Linear);
+        let output = config.init().forward(input.clone());
+        assert_eq!(output.dims(), [2, 3, 4, 8, 8]);
+
+        let config = Interpolate3dConfig::new()
+            .with_scale_factor(Some([1.0, 0.5, 0.5]))
+            .with_mode(InterpolateMode::Area);
+        let output = config.init().forward(input);
+        assert_eq!(output.dims(), [2, 3, 2, 2, 2]);
+    }
+
+    #[test]
+    fn display() {
+        let config = Interpolate3dConfig::new().with_output_size(Some([4, 20, 20]));
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "Interpolate3d {mode: Nearest, output_size: Some([4, 20, 20]), \
+            scale_factor: None}"
+        );
+    }
+}
@@ -1,8 +1,10 @@
 mod interpolate1d;
 mod interpolate2d;
+mod interpolate3d;
 
 pub use interpolate1d::*;
 pub use interpolate2d::*;
+pub use interpolate3d::*;
 
 use burn_core as burn;
 
@@ -49,6 +51,14 @@ pub enum InterpolateMode {
     /// the output value. It generally provides high-quality results,
     /// especially for downsampling.
     Lanczos,
+
+    /// Area interpolation
+    ///
+    /// This mode averages the input values covered by each output value,
+    /// like an adaptive average pooling.
+    ///
+    /// It is suited for downsampling, and ignores `align_corners`.
+    Area,
 }
 
 impl From<InterpolateMode> for OpsInterpolateMode {
@@ -59,6 +69,7 @@ impl From<InterpolateMode> for OpsInterpolateMode {
             InterpolateMode::Linear => OpsInterpolateMode::Bilinear,
             InterpolateMode::Cubic => OpsInterpolateMode::Bicubic,
             InterpolateMode::Lanczos => OpsInterpolateMode::Lanczos3,
+            InterpolateMode::Area => OpsInterpolateMode::Area,
         }
     }
 }
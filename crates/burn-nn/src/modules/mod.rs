@@ -1,6 +1,9 @@
 /// Attention module
 pub mod attention;
 
+/// Bayesian module
+pub mod bayesian;
+
 /// Cache module
 pub mod cache;
 
@@ -16,10 +19,14 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+mod drop_path;
 mod dropout;
 mod embedding;
+mod embedding_bag;
+mod ensemble;
 mod linear;
 mod noise;
+mod pixel_shuffle;
 mod pos_encoding;
 mod rnn;
 mod rope_encoding;
@@ -28,10 +35,14 @@ mod unfold;
 pub mod norm;
 pub use norm::{batch::*, group::*, instance::*, layer::*, local_response::*, rms::*};
 
+pub use drop_path::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use embedding_bag::*;
+pub use ensemble::*;
 pub use linear::*;
 pub use noise::*;
+pub use pixel_shuffle::*;
 pub use pos_encoding::*;
 pub use rnn::*;
 pub use rope_encoding::*;
@@ -0,0 +1,137 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Module, ModuleDisplay};
+
+use burn::tensor::Tensor;
+use burn::tensor::module::{pixel_shuffle, pixel_unshuffle};
+
+/// Configuration to create a [pixel shuffle](PixelShuffle) layer using the [init function](PixelShuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelShuffleConfig {
+    /// The factor by which the height and width are increased.
+    pub upscale_factor: usize,
+}
+
+/// Rearranges channels into blocks of pixels (depth to space), as in the sub-pixel convolution
+/// heads of super-resolution networks.
+///
+/// Should be created with [PixelShuffleConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct PixelShuffle {
+    /// The factor by which the height and width are increased.
+    pub upscale_factor: usize,
+}
+
+impl ModuleDisplay for PixelShuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("upscale_factor", &self.upscale_factor)
+            .optional()
+    }
+}
+
+impl PixelShuffleConfig {
+    /// Initializes a new [PixelShuffle] module.
+    pub fn init(&self) -> PixelShuffle {
+        PixelShuffle {
+            upscale_factor: self.upscale_factor,
+        }
+    }
+}
+
+impl PixelShuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_shuffle](burn::tensor::module::pixel_shuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels * upscale_factor^2, height, width]`
+    /// returns: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`
+    pub fn forward(&self, input: Tensor<4>) -> Tensor<4> {
+        pixel_shuffle(input, self.upscale_factor)
+    }
+}
+
+/// Configuration to create a [pixel unshuffle](PixelUnshuffle) layer using the [init function](PixelUnshuffleConfig::init).
+#[derive(Config, Debug)]
+pub struct PixelUnshuffleConfig {
+    /// The factor by which the height and width are reduced.
+    pub downscale_factor: usize,
+}
+
+/// Rearranges blocks of pixels into channels (space to depth), the inverse of [PixelShuffle],
+/// as in the focus layers of YOLO-style detectors.
+///
+/// Should be created with [PixelUnshuffleConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct PixelUnshuffle {
+    /// The factor by which the height and width are reduced.
+    pub downscale_factor: usize,
+}
+
+impl ModuleDisplay for PixelUnshuffle {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("downscale_factor", &self.downscale_factor)
+            .optional()
+    }
+}
+
+impl PixelUnshuffleConfig {
+    /// Initializes a new [PixelUnshuffle] module.
+    pub fn init(&self) -> PixelUnshuffle {
+        PixelUnshuffle {
+            downscale_factor: self.downscale_factor,
+        }
+    }
+}
+
+impl PixelUnshuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [pixel_unshuffle](burn::tensor::module::pixel_unshuffle) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels, height * downscale_factor, width * downscale_factor]`
+    /// returns: `[batch_size, channels * downscale_factor^2, height, width]`
+    pub fn forward(&self, input: Tensor<4>) -> Tensor<4> {
+        pixel_unshuffle(input, self.downscale_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let shuffle = PixelShuffleConfig::new(2).init();
+        let unshuffle = PixelUnshuffleConfig::new(2).init();
+
+        assert_eq!(
+            alloc::format!("{shuffle}"),
+            "PixelShuffle {upscale_factor: 2}"
+        );
+        assert_eq!(
+            alloc::format!("{unshuffle}"),
+            "PixelUnshuffle {downscale_factor: 2}"
+        );
+    }
+}
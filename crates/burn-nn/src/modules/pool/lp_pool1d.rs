@@ -0,0 +1,107 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::Module;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+
+use burn::tensor::module::lp_pool1d;
+
+/// Configuration to create a [1D power-average pooling](LpPool1d) layer using the [init function](LpPool1dConfig::init).
+#[derive(Config, Debug)]
+pub struct LpPool1dConfig {
+    /// The power `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride.
+    #[config(default = "kernel_size")]
+    pub stride: usize,
+    /// If true, use ceiling instead of floor for output size calculation.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
+}
+
+/// Applies a 1D power-average pooling over input tensors.
+///
+/// Each output is `(sum x^p)^(1/p)` over its window, which is a sum pooling for `p = 1`
+/// and approaches max pooling as `p` grows.
+///
+/// Should be created with [LpPool1dConfig](LpPool1dConfig).
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct LpPool1d {
+    /// The power `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The stride.
+    pub stride: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// If true, use ceiling instead of floor for output size calculation.
+    pub ceil_mode: bool,
+}
+
+impl ModuleDisplay for LpPool1d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("norm_type", &self.norm_type)
+            .add("kernel_size", &self.kernel_size)
+            .add("stride", &self.stride)
+            .add("ceil_mode", &self.ceil_mode)
+            .optional()
+    }
+}
+
+impl LpPool1dConfig {
+    /// Initialize a new [lp pool 1d](LpPool1d) module.
+    pub fn init(&self) -> LpPool1d {
+        LpPool1d {
+            norm_type: self.norm_type,
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            ceil_mode: self.ceil_mode,
+        }
+    }
+}
+
+impl LpPool1d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [lp_pool1d](burn::tensor::module::lp_pool1d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, length_in]`
+    /// - output: `[batch_size, channels, length_out]`
+    pub fn forward(&self, input: Tensor<3>) -> Tensor<3> {
+        lp_pool1d(
+            input,
+            self.norm_type,
+            self.kernel_size,
+            self.stride,
+            self.ceil_mode,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = LpPool1dConfig::new(2.0, 3).with_stride(2);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "LpPool1d {norm_type: 2, kernel_size: 3, stride: 2, ceil_mode: false}"
+        );
+    }
+}
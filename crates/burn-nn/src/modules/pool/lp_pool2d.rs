@@ -0,0 +1,107 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::Module;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+
+use burn::tensor::module::lp_pool2d;
+
+/// Configuration to create a [2D power-average pooling](LpPool2d) layer using the [init function](LpPool2dConfig::init).
+#[derive(Config, Debug)]
+pub struct LpPool2dConfig {
+    /// The power `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The strides.
+    #[config(default = "kernel_size")]
+    pub strides: [usize; 2],
+    /// If true, use ceiling instead of floor for output size calculation.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
+}
+
+/// Applies a 2D power-average pooling over input tensors.
+///
+/// Each output is `(sum x^p)^(1/p)` over its window, which is a sum pooling for `p = 1`
+/// and approaches max pooling as `p` grows.
+///
+/// Should be created with [LpPool2dConfig](LpPool2dConfig).
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct LpPool2d {
+    /// The power `p` of the norm computed over each window.
+    pub norm_type: f64,
+    /// The strides.
+    pub stride: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// If true, use ceiling instead of floor for output size calculation.
+    pub ceil_mode: bool,
+}
+
+impl ModuleDisplay for LpPool2d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("norm_type", &self.norm_type)
+            .add("kernel_size", &alloc::format!("{:?}", &self.kernel_size))
+            .add("stride", &alloc::format!("{:?}", &self.stride))
+            .add("ceil_mode", &self.ceil_mode)
+            .optional()
+    }
+}
+
+impl LpPool2dConfig {
+    /// Initialize a new [lp pool 2d](LpPool2d) module.
+    pub fn init(&self) -> LpPool2d {
+        LpPool2d {
+            norm_type: self.norm_type,
+            stride: self.strides,
+            kernel_size: self.kernel_size,
+            ceil_mode: self.ceil_mode,
+        }
+    }
+}
+
+impl LpPool2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [lp_pool2d](burn::tensor::module::lp_pool2d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height_in, width_in]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<4>) -> Tensor<4> {
+        lp_pool2d(
+            input,
+            self.norm_type,
+            self.kernel_size,
+            self.stride,
+            self.ceil_mode,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = LpPool2dConfig::new(2.0, [3, 3]);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "LpPool2d {norm_type: 2, kernel_size: [3, 3], stride: [3, 3], ceil_mode: false}"
+        );
+    }
+}
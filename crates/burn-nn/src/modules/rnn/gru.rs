@@ -2,6 +2,8 @@ use burn_core as burn;
 
 use super::gate_controller::GateController;
 use crate::activation::{Activation, ActivationConfig};
+use crate::{Dropout, DropoutConfig};
+use alloc::vec::Vec;
 use burn::config::Config;
 use burn::module::Initializer;
 use burn::module::Module;
@@ -447,6 +449,224 @@ impl BiGru {
     }
 }
 
+/// Configuration to create a [StackedGru](StackedGru) module using the [init function](StackedGruConfig::init).
+#[derive(Config, Debug)]
+pub struct StackedGruConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+    /// If a bias should be applied during the Gru transformations.
+    pub bias: bool,
+    /// The number of stacked layers, each layer processing the output sequence of the previous one.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If true, each layer processes the sequence in both directions and outputs the
+    /// concatenation of the forward and reverse hidden states.
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout probability applied to the outputs of every layer except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// If reset gate should be applied after weight multiplication.
+    #[config(default = "true")]
+    pub reset_after: bool,
+    /// Gru initializer
+    #[config(default = "Initializer::XavierNormal{gain:1.0}")]
+    pub initializer: Initializer,
+    /// If true, the input tensor is expected to be `[batch_size, seq_length, input_size]`.
+    /// If false, the input tensor is expected to be `[seq_length, batch_size, input_size]`.
+    #[config(default = true)]
+    pub batch_first: bool,
+    /// Activation function for the update and reset gates.
+    #[config(default = "ActivationConfig::Sigmoid")]
+    pub gate_activation: ActivationConfig,
+    /// Activation function for the new/candidate gate.
+    #[config(default = "ActivationConfig::Tanh")]
+    pub hidden_activation: ActivationConfig,
+    /// Optional hidden state clip threshold.
+    pub clip: Option<f64>,
+}
+
+/// The StackedGru module: a multi-layer, optionally bidirectional GRU.
+///
+/// Follows the semantics of the [PyTorch GRU](https://pytorch.org/docs/stable/generated/torch.nn.GRU.html):
+/// the states of the layers and directions are stacked along the first dimension, at index
+/// `layer * num_directions + direction`, and the weights of each layer and direction map to one
+/// [Gru].
+///
+/// Should be created with [StackedGruConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct StackedGru {
+    /// The GRUs processing the sequence in the forward direction, one per layer.
+    pub layers: Vec<Gru>,
+    /// The GRUs processing the sequence in the reverse direction, one per layer when
+    /// bidirectional, empty otherwise.
+    pub reverse_layers: Vec<Gru>,
+    /// The dropout applied between layers.
+    pub dropout: Dropout,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+    /// If true, input is `[batch_size, seq_length, input_size]`.
+    /// If false, input is `[seq_length, batch_size, input_size]`.
+    pub batch_first: bool,
+}
+
+impl ModuleDisplay for StackedGru {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_input, _] = self.layers[0]
+            .update_gate
+            .input_transform
+            .weight
+            .shape()
+            .dims();
+        let bias = self.layers[0].update_gate.input_transform.bias.is_some();
+
+        content
+            .add("d_input", &d_input)
+            .add("d_hidden", &self.d_hidden)
+            .add("bias", &bias)
+            .add("num_layers", &self.layers.len())
+            .add("bidirectional", &!self.reverse_layers.is_empty())
+            .optional()
+    }
+}
+
+impl StackedGruConfig {
+    /// Initialize a new [stacked GRU](StackedGru) module.
+    pub fn init(&self, device: &Device) -> StackedGru {
+        assert!(
+            self.num_layers > 0,
+            "A stacked GRU requires at least one layer"
+        );
+        let num_directions = if self.bidirectional { 2 } else { 1 };
+
+        let layer_config = |layer: usize| {
+            let d_input = if layer == 0 {
+                self.d_input
+            } else {
+                self.d_hidden * num_directions
+            };
+            GruConfig::new(d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .with_reset_after(self.reset_after)
+                .with_gate_activation(self.gate_activation.clone())
+                .with_hidden_activation(self.hidden_activation.clone())
+                .with_clip(self.clip)
+        };
+
+        let layers = (0..self.num_layers)
+            .map(|layer| layer_config(layer).init(device))
+            .collect();
+        let reverse_layers = if self.bidirectional {
+            (0..self.num_layers)
+                .map(|layer| layer_config(layer).init(device))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        StackedGru {
+            layers,
+            reverse_layers,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            d_hidden: self.d_hidden,
+            batch_first: self.batch_first,
+        }
+    }
+}
+
+impl StackedGru {
+    /// Applies the forward pass on the input tensor. This stacked GRU implementation returns the
+    /// hidden states of the last layer for each element in a sequence and the final hidden states
+    /// of every layer and direction.
+    ///
+    /// ## Parameters:
+    /// - batched_input: The input tensor of shape:
+    ///   - `[batch_size, sequence_length, input_size]` if `batch_first` is true (default)
+    ///   - `[sequence_length, batch_size, input_size]` if `batch_first` is false
+    /// - state: An optional tensor representing the initial hidden state with shape
+    ///   `[num_layers * num_directions, batch_size, hidden_size]`. If no initial state is
+    ///   provided, it is initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: A tensor representing the output features of the last layer. Shape:
+    ///   - `[batch_size, sequence_length, hidden_size * num_directions]` if `batch_first` is true
+    ///   - `[sequence_length, batch_size, hidden_size * num_directions]` if `batch_first` is false
+    /// - state: The final hidden states with shape
+    ///   `[num_layers * num_directions, batch_size, hidden_size]`.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<3>,
+        state: Option<Tensor<3>>,
+    ) -> (Tensor<3>, Tensor<3>) {
+        // Convert to batch-first layout internally if needed
+        let mut output = if self.batch_first {
+            batched_input
+        } else {
+            batched_input.swap_dims(0, 1)
+        };
+
+        let device = output.device();
+        let [batch_size, seq_length, _] = output.dims();
+        let num_directions = if self.reverse_layers.is_empty() { 1 } else { 2 };
+        let mut hiddens = Vec::with_capacity(self.layers.len() * num_directions);
+
+        for (layer, gru) in self.layers.iter().enumerate() {
+            let directions = core::iter::once(gru).chain(self.reverse_layers.get(layer));
+            let mut outputs = Vec::with_capacity(num_directions);
+
+            for (direction, gru) in directions.enumerate() {
+                let index = layer * num_directions + direction;
+                let init_state = state
+                    .as_ref()
+                    .map(|state| state.clone().slice([index..index + 1]).squeeze_dim(0));
+
+                let (direction_output, final_state) = if direction == 0 {
+                    gru.forward_iter(
+                        output.clone().iter_dim(1).zip(0..seq_length),
+                        init_state,
+                        batch_size,
+                        seq_length,
+                        &device,
+                    )
+                } else {
+                    gru.forward_iter(
+                        output.clone().iter_dim(1).rev().zip((0..seq_length).rev()),
+                        init_state,
+                        batch_size,
+                        seq_length,
+                        &device,
+                    )
+                };
+                outputs.push(direction_output);
+                hiddens.push(final_state);
+            }
+
+            output = Tensor::cat(outputs, 2);
+            if layer + 1 < self.layers.len() {
+                output = self.dropout.forward(output);
+            }
+        }
+
+        // Convert output back to seq-first layout if needed
+        let output = if self.batch_first {
+            output
+        } else {
+            output.swap_dims(0, 1)
+        };
+
+        (output, Tensor::stack(hiddens, 0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1054,4 +1274,67 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FT>(&expected_output_no_h0, tolerance);
     }
+
+    #[test]
+    fn test_stacked_gru_matches_bigru() {
+        let device = Default::default();
+        let stacked = StackedGruConfig::new(2, 3, true)
+            .with_bidirectional(true)
+            .init(&device);
+        let bigru = BiGru {
+            forward: stacked.layers[0].clone(),
+            reverse: stacked.reverse_layers[0].clone(),
+            d_hidden: 3,
+            batch_first: true,
+        };
+
+        let input = Tensor::<3>::random([2, 4, 2], Distribution::Default, &device);
+        let hidden = Tensor::<3>::random([2, 2, 3], Distribution::Default, &device);
+
+        let (output, state) = stacked.forward(input.clone(), Some(hidden.clone()));
+        let (expected_output, expected_state) = bigru.forward(input, Some(hidden));
+
+        let tolerance = Tolerance::default();
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_output.into_data(), tolerance);
+        state
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_state.into_data(), tolerance);
+    }
+
+    #[test]
+    fn test_stacked_gru_chains_layers() {
+        let device = Default::default();
+        // Dropout is only active on autodiff devices.
+        let stacked = StackedGruConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_dropout(0.5)
+            .init(&device);
+
+        let input = Tensor::<3>::random([2, 4, 2], Distribution::Default, &device);
+        let (output, state) = stacked.forward(input.clone(), None);
+
+        let first_output = stacked.layers[0].forward(input, None);
+        let expected_output = stacked.layers[1].forward(first_output, None);
+
+        assert_eq!(state.dims(), [2, 2, 3]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_output.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn stacked_gru_display() {
+        let config = StackedGruConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true);
+
+        let layer = config.init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "StackedGru {d_input: 2, d_hidden: 3, bias: true, num_layers: 2, bidirectional: true, params: 324}"
+        );
+    }
 }
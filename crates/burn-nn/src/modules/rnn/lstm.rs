@@ -1,7 +1,8 @@
 use burn_core as burn;
 
-use crate::GateController;
 use crate::activation::{Activation, ActivationConfig};
+use crate::{Dropout, DropoutConfig, GateController};
+use alloc::vec::Vec;
 use burn::config::Config;
 use burn::module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay};
 use burn::tensor::Device;
@@ -490,6 +491,225 @@ impl BiLstm {
     }
 }
 
+/// Configuration to create a [StackedLstm](StackedLstm) module using the [init function](StackedLstmConfig::init).
+#[derive(Config, Debug)]
+pub struct StackedLstmConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+    /// If a bias should be applied during the Lstm transformations.
+    pub bias: bool,
+    /// The number of stacked layers, each layer processing the output sequence of the previous one.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If true, each layer processes the sequence in both directions and outputs the
+    /// concatenation of the forward and reverse hidden states.
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout probability applied to the outputs of every layer except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// Lstm initializer
+    #[config(default = "Initializer::XavierNormal{gain:1.0}")]
+    pub initializer: Initializer,
+    /// If true, the input tensor is expected to be `[batch_size, seq_length, input_size]`.
+    /// If false, the input tensor is expected to be `[seq_length, batch_size, input_size]`.
+    #[config(default = true)]
+    pub batch_first: bool,
+    /// Optional cell state clip threshold.
+    pub clip: Option<f64>,
+    /// If true, couples the input and forget gates.
+    #[config(default = false)]
+    pub input_forget: bool,
+    /// Activation function for the input, forget, and output gates.
+    #[config(default = "ActivationConfig::Sigmoid")]
+    pub gate_activation: ActivationConfig,
+    /// Activation function for the cell gate (candidate cell state).
+    #[config(default = "ActivationConfig::Tanh")]
+    pub cell_activation: ActivationConfig,
+    /// Activation function applied to the cell state before computing hidden output.
+    #[config(default = "ActivationConfig::Tanh")]
+    pub hidden_activation: ActivationConfig,
+}
+
+/// The StackedLstm module: a multi-layer, optionally bidirectional LSTM.
+///
+/// Follows the semantics of the [PyTorch LSTM](https://pytorch.org/docs/stable/generated/torch.nn.LSTM.html):
+/// the states of the layers and directions are stacked along the first dimension, at index
+/// `layer * num_directions + direction`, and the weights of each layer and direction map to one
+/// [Lstm].
+///
+/// Should be created with [StackedLstmConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct StackedLstm {
+    /// The LSTMs processing the sequence in the forward direction, one per layer.
+    pub layers: Vec<Lstm>,
+    /// The LSTMs processing the sequence in the reverse direction, one per layer when
+    /// bidirectional, empty otherwise.
+    pub reverse_layers: Vec<Lstm>,
+    /// The dropout applied between layers.
+    pub dropout: Dropout,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+    /// If true, input is `[batch_size, seq_length, input_size]`.
+    /// If false, input is `[seq_length, batch_size, input_size]`.
+    pub batch_first: bool,
+}
+
+impl ModuleDisplay for StackedLstm {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_input, _] = self.layers[0]
+            .input_gate
+            .input_transform
+            .weight
+            .shape()
+            .dims();
+        let bias = self.layers[0].input_gate.input_transform.bias.is_some();
+
+        content
+            .add("d_input", &d_input)
+            .add("d_hidden", &self.d_hidden)
+            .add("bias", &bias)
+            .add("num_layers", &self.layers.len())
+            .add("bidirectional", &!self.reverse_layers.is_empty())
+            .optional()
+    }
+}
+
+impl StackedLstmConfig {
+    /// Initialize a new [stacked LSTM](StackedLstm) module.
+    pub fn init(&self, device: &Device) -> StackedLstm {
+        assert!(
+            self.num_layers > 0,
+            "A stacked LSTM requires at least one layer"
+        );
+        let num_directions = if self.bidirectional { 2 } else { 1 };
+
+        // Internal LSTMs always use batch_first=true; StackedLstm handles layout conversion
+        let layer_config = |layer: usize, reverse: bool| {
+            let d_input = if layer == 0 {
+                self.d_input
+            } else {
+                self.d_hidden * num_directions
+            };
+            LstmConfig::new(d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .with_batch_first(true)
+                .with_reverse(reverse)
+                .with_clip(self.clip)
+                .with_input_forget(self.input_forget)
+                .with_gate_activation(self.gate_activation.clone())
+                .with_cell_activation(self.cell_activation.clone())
+                .with_hidden_activation(self.hidden_activation.clone())
+        };
+
+        let layers = (0..self.num_layers)
+            .map(|layer| layer_config(layer, false).init(device))
+            .collect();
+        let reverse_layers = if self.bidirectional {
+            (0..self.num_layers)
+                .map(|layer| layer_config(layer, true).init(device))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        StackedLstm {
+            layers,
+            reverse_layers,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            d_hidden: self.d_hidden,
+            batch_first: self.batch_first,
+        }
+    }
+}
+
+impl StackedLstm {
+    /// Applies the forward pass on the input tensor. This stacked LSTM implementation returns the
+    /// hidden states of the last layer for each element in a sequence and the final states of
+    /// every layer and direction.
+    ///
+    /// ## Parameters:
+    /// - batched_input: The input tensor of shape:
+    ///   - `[batch_size, sequence_length, input_size]` if `batch_first` is true (default)
+    ///   - `[sequence_length, batch_size, input_size]` if `batch_first` is false
+    /// - state: An optional `LstmState` representing the initial cell state and hidden state.
+    ///   Each state tensor has shape `[num_layers * num_directions, batch_size, hidden_size]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - output: A tensor represents the output features of the last layer. Shape:
+    ///   - `[batch_size, sequence_length, hidden_size * num_directions]` if `batch_first` is true
+    ///   - `[sequence_length, batch_size, hidden_size * num_directions]` if `batch_first` is false
+    /// - state: A `LstmState` represents the final states. Both `state.cell` and `state.hidden`
+    ///   have the shape `[num_layers * num_directions, batch_size, hidden_size]`.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<3>,
+        state: Option<LstmState<3>>,
+    ) -> (Tensor<3>, LstmState<3>) {
+        // Convert to batch-first layout internally if needed
+        let mut output = if self.batch_first {
+            batched_input
+        } else {
+            batched_input.swap_dims(0, 1)
+        };
+
+        let num_directions = if self.reverse_layers.is_empty() { 1 } else { 2 };
+        let num_states = self.layers.len() * num_directions;
+        let mut cells = Vec::with_capacity(num_states);
+        let mut hiddens = Vec::with_capacity(num_states);
+
+        for (layer, lstm) in self.layers.iter().enumerate() {
+            let directions = core::iter::once(lstm).chain(self.reverse_layers.get(layer));
+            let mut outputs = Vec::with_capacity(num_directions);
+
+            for (direction, lstm) in directions.enumerate() {
+                let index = layer * num_directions + direction;
+                let init_state = state.as_ref().map(|state| {
+                    LstmState::new(
+                        state.cell.clone().slice([index..index + 1]).squeeze_dim(0),
+                        state
+                            .hidden
+                            .clone()
+                            .slice([index..index + 1])
+                            .squeeze_dim(0),
+                    )
+                });
+
+                let (direction_output, final_state) = lstm.forward(output.clone(), init_state);
+                outputs.push(direction_output);
+                cells.push(final_state.cell);
+                hiddens.push(final_state.hidden);
+            }
+
+            output = Tensor::cat(outputs, 2);
+            if layer + 1 < self.layers.len() {
+                output = self.dropout.forward(output);
+            }
+        }
+
+        // Convert output back to seq-first layout if needed
+        let output = if self.batch_first {
+            output
+        } else {
+            output.swap_dims(0, 1)
+        };
+
+        let state = LstmState::new(Tensor::stack(cells, 0), Tensor::stack(hiddens, 0));
+
+        (output, state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -910,4 +1130,85 @@ mod tests {
             "BiLstm {d_input: 2, d_hidden: 3, bias: true, params: 168}"
         );
     }
+
+    #[test]
+    fn test_stacked_lstm_matches_bilstm() {
+        let device = Default::default();
+        let stacked = StackedLstmConfig::new(2, 3, true)
+            .with_bidirectional(true)
+            .init(&device);
+        let bilstm = BiLstm {
+            forward: stacked.layers[0].clone(),
+            reverse: stacked.reverse_layers[0].clone(),
+            d_hidden: 3,
+            batch_first: true,
+        };
+
+        let input = Tensor::<3>::random([2, 4, 2], Distribution::Default, &device);
+        let cell = Tensor::<3>::random([2, 2, 3], Distribution::Default, &device);
+        let hidden = Tensor::<3>::random([2, 2, 3], Distribution::Default, &device);
+
+        let (output, state) = stacked.forward(
+            input.clone(),
+            Some(LstmState::new(cell.clone(), hidden.clone())),
+        );
+        let (expected_output, expected_state) =
+            bilstm.forward(input, Some(LstmState::new(cell, hidden)));
+
+        let tolerance = Tolerance::default();
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_output.into_data(), tolerance);
+        state
+            .cell
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_state.cell.into_data(), tolerance);
+        state
+            .hidden
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_state.hidden.into_data(), tolerance);
+    }
+
+    #[test]
+    fn test_stacked_lstm_chains_layers() {
+        let device = Default::default();
+        // Dropout is only active on autodiff devices.
+        let stacked = StackedLstmConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_dropout(0.5)
+            .with_batch_first(false)
+            .init(&device);
+
+        let input = Tensor::<3>::random([4, 2, 2], Distribution::Default, &device);
+        let (output, state) = stacked.forward(input.clone(), None);
+
+        let (first_output, first_state) = stacked.layers[0].forward(input.swap_dims(0, 1), None);
+        let (expected_output, second_state) = stacked.layers[1].forward(first_output, None);
+
+        assert_eq!(output.dims(), [4, 2, 3]);
+        assert_eq!(state.hidden.dims(), [2, 2, 3]);
+        let tolerance = Tolerance::default();
+        output
+            .swap_dims(0, 1)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_output.into_data(), tolerance);
+        state.cell.into_data().assert_approx_eq::<FT>(
+            &Tensor::stack::<3>(Vec::from([first_state.cell, second_state.cell]), 0).into_data(),
+            tolerance,
+        );
+    }
+
+    #[test]
+    fn display_stacked_lstm() {
+        let config = StackedLstmConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true);
+
+        let layer = config.init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "StackedLstm {d_input: 2, d_hidden: 3, bias: true, num_layers: 2, bidirectional: true, params: 432}"
+        );
+    }
 }
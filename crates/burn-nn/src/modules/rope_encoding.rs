@@ -23,6 +23,66 @@ pub struct RotaryEncodingConfig {
     /// Scaling factor for frequency computation. Defaults to 10000.0
     #[config(default = "10000.0")]
     pub theta: f32,
+
+    /// Context-extension scaling of the rotation frequencies, used to run a model on sequences
+    /// longer than the ones it was trained on. Defaults to no scaling.
+    #[config(default = "None")]
+    pub scaling: Option<RotaryScaling>,
+
+    /// Number of leading features of each position that are rotated, the remaining features being
+    /// passed through unchanged (partial rotary embedding). Defaults to `d_model`.
+    #[config(default = "None")]
+    pub rotary_dims: Option<usize>,
+}
+
+/// Context-extension scaling strategy of the [rotary encoding](RotaryEncoding) frequencies.
+#[derive(Config, Debug, PartialEq)]
+pub enum RotaryScaling {
+    /// Position interpolation: all the frequencies are divided by `factor`.
+    ///
+    /// Reference: "Extending Context Window of Large Language Models via Positional
+    /// Interpolation" <https://arxiv.org/abs/2306.15595>
+    Linear {
+        /// The ratio between the extended and the original context length.
+        factor: f32,
+    },
+    /// NTK-aware scaling: the base `theta` is multiplied by `factor ^ (d / (d - 2))`, which
+    /// interpolates the low frequencies while keeping the high frequencies almost unchanged.
+    Ntk {
+        /// The ratio between the extended and the original context length.
+        factor: f32,
+    },
+    /// YaRN scaling: the dimensions whose wavelength is short compared to the original context
+    /// keep their frequency, the ones whose wavelength is longer are interpolated by `factor`, with
+    /// a linear ramp in between. The rotations are also scaled by `0.1 * ln(factor) + 1`, which
+    /// scales the attention logits by the square of this factor.
+    ///
+    /// Reference: "YaRN: Efficient Context Window Extension of Large Language Models"
+    /// <https://arxiv.org/abs/2309.00071>
+    Yarn {
+        /// The ratio between the extended and the original context length.
+        factor: f32,
+        /// The context length the model was trained on.
+        original_max_sequence_length: usize,
+        /// The number of rotations over the original context above which the frequencies are
+        /// kept. Usually 32.
+        beta_fast: f32,
+        /// The number of rotations over the original context below which the frequencies are
+        /// interpolated. Usually 1.
+        beta_slow: f32,
+    },
+}
+
+impl RotaryScaling {
+    /// YaRN scaling with the usual `beta_fast = 32` and `beta_slow = 1`.
+    pub fn yarn(factor: f32, original_max_sequence_length: usize) -> Self {
+        Self::Yarn {
+            factor,
+            original_max_sequence_length,
+            beta_fast: 32.0,
+            beta_slow: 1.0,
+        }
+    }
 }
 
 impl RotaryEncodingConfig {
@@ -30,18 +90,19 @@ impl RotaryEncodingConfig {
     ///
     /// # Panics
     ///
-    /// Panics if the size of input embedding dimension is not even.
+    /// Panics if the number of rotary dimensions is not even or is larger than `d_model`.
     /// Panics if the theta parameter is not positive.
     pub fn init(&self, device: &Device) -> RotaryEncoding {
         self.initialize(|x| x, device)
     }
 
     /// Initialize a new [RotaryEncoding](RotaryEncoding) module with a custom frequency scaling function.
-    /// This is useful to apply different RoPE extensions.
+    /// This is useful to apply different RoPE extensions. The function receives the frequencies
+    /// after the configured [scaling](RotaryScaling), if any.
     ///
     /// # Panics
     ///
-    /// Panics if the size of input embedding dimension is not even.
+    /// Panics if the number of rotary dimensions is not even or is larger than `d_model`.
     /// Panics if the theta parameter is not positive.
     pub fn init_with_frequency_scaling(
         &self,
@@ -55,46 +116,130 @@ impl RotaryEncodingConfig {
     ///
     /// # Panics
     ///
-    /// Panics if the size of input embedding dimension is not even.
+    /// Panics if the number of rotary dimensions is not even or is larger than `d_model`.
     /// Panics if the theta parameter is not positive.
     fn initialize(
         &self,
         scaling: impl Fn(Tensor<1>) -> Tensor<1>,
         device: &Device,
     ) -> RotaryEncoding {
-        assert_eq!(
-            self.d_model % 2,
-            0,
-            "The input embedding dimension must be even"
+        let rotary_dims = self.rotary_dims.unwrap_or(self.d_model);
+        assert_eq!(rotary_dims % 2, 0, "The rotary dimension must be even");
+        assert!(
+            rotary_dims > 0 && rotary_dims <= self.d_model,
+            "The rotary dimension must be in (0, d_model], got {rotary_dims} for d_model {}",
+            self.d_model
         );
         assert!(
             self.theta > 0.0,
             "Theta parameter must be positive (default: 10000)."
         );
 
+        let mut base = self.theta;
+        if let Some(RotaryScaling::Ntk { factor }) = self.scaling {
+            assert!(
+                rotary_dims > 2,
+                "NTK scaling requires a rotary dimension larger than 2"
+            );
+            base *= factor.powf(rotary_dims as f32 / (rotary_dims - 2) as f32);
+        }
+
         // Calculate the rotation frequencies for positional embeddings based on the formula
         // `theta = 1 / (theta ^ (2i / d_model)) for i in [0..d_model/2]`
-        let exponent = Tensor::<1, Int>::arange_step(0..self.d_model as i64, 2, device)
+        let exponent = Tensor::<1, Int>::arange_step(0..rotary_dims as i64, 2, device)
             .float()
-            .div_scalar(self.d_model as f32);
+            .div_scalar(rotary_dims as f32);
 
         // Calculate (10000 ^ (2i / d_model)) by using the log base property `exp(log(10000) * (2i / d_model))`
         // This is done since burn doesn't support exponentiation of scalar to tensor
-        let theta = exponent.mul_scalar(self.theta.ln()).exp().recip();
+        let theta = exponent.mul_scalar(base.ln()).exp().recip();
+
+        let (theta, attention_scaling) = match self.scaling {
+            None | Some(RotaryScaling::Ntk { .. }) => (theta, 1.0),
+            Some(RotaryScaling::Linear { factor }) => {
+                assert!(factor > 0.0, "The scaling factor must be positive");
+                (theta.div_scalar(factor), 1.0)
+            }
+            Some(RotaryScaling::Yarn {
+                factor,
+                original_max_sequence_length,
+                beta_fast,
+                beta_slow,
+            }) => {
+                assert!(factor > 0.0, "The scaling factor must be positive");
+                let theta = yarn_frequencies(
+                    theta,
+                    base,
+                    factor,
+                    original_max_sequence_length,
+                    beta_fast,
+                    beta_slow,
+                );
+                let attention_scaling = if factor > 1.0 {
+                    0.1 * factor.ln() + 1.0
+                } else {
+                    1.0
+                };
+                (theta, attention_scaling)
+            }
+        };
 
         let theta = scaling(theta);
 
-        let freq_complex =
-            RotaryEncoding::compute_rotary_frequencies(0..self.max_sequence_length, theta.clone());
+        let freq_complex = RotaryEncoding::compute_rotary_frequencies(
+            0..self.max_sequence_length,
+            theta.clone(),
+            attention_scaling,
+        );
 
         RotaryEncoding {
             freq_complex,
             theta,
+            d_model: self.d_model,
+            attention_scaling,
             start_offset: 0,
         }
     }
 }
 
+/// Blends the original frequencies with the interpolated ones, `theta / factor`, according to the
+/// number of rotations of each dimension over the original context.
+fn yarn_frequencies(
+    theta: Tensor<1>,
+    base: f32,
+    factor: f32,
+    original_max_sequence_length: usize,
+    beta_fast: f32,
+    beta_slow: f32,
+) -> Tensor<1> {
+    let num_freqs = theta.dims()[0];
+    let rotary_dims = num_freqs * 2;
+
+    // The dimension at which the wavelength fits `num_rotations` times in the original context.
+    let correction_dim = |num_rotations: f32| {
+        rotary_dims as f32
+            * (original_max_sequence_length as f32 / (num_rotations * 2.0 * core::f32::consts::PI))
+                .ln()
+            / (2.0 * base.ln())
+    };
+    let max_dim = (rotary_dims - 1) as f32;
+    let low = correction_dim(beta_fast).floor().clamp(0.0, max_dim);
+    let mut high = correction_dim(beta_slow).ceil().clamp(0.0, max_dim);
+    if low == high {
+        high += 0.001;
+    }
+
+    // 0 for the dimensions that keep their frequency, 1 for the interpolated ones.
+    let ramp = Tensor::<1, Int>::arange(0..num_freqs as i64, &theta.device())
+        .float()
+        .sub_scalar(low)
+        .div_scalar(high - low)
+        .clamp(0.0, 1.0);
+
+    let interpolated = theta.clone().div_scalar(factor);
+    interpolated * ramp.clone() + theta * ramp.neg().add_scalar(1.0)
+}
+
 /// A module that applies rotary positional encoding to a tensor.
 /// Rotary Position Encoding or Embedding (RoPE), is a type of position embedding which encodes
 /// absolute positional information with rotation matrix and naturally incorporates
@@ -106,11 +251,13 @@ impl RotaryEncodingConfig {
 #[derive(Module, Debug)]
 #[module(custom_display)]
 pub struct RotaryEncoding {
-    /// Complex frequency tensor of shape (max_sequence_length, d_model, 2) with real and imaginary components
+    /// Complex frequency tensor of shape (max_sequence_length, rotary_dims, 2) with real and imaginary components
     // Essentially a cache of pre-computed RoPE values.
     pub freq_complex: Tensor<3>,
     /// Frequency vector used to compute/apply the complex rotations.
     pub theta: Tensor<1>,
+    d_model: usize,
+    attention_scaling: f32,
     start_offset: usize,
 }
 
@@ -122,9 +269,14 @@ impl ModuleDisplay for RotaryEncoding {
     }
 
     fn custom_content(&self, content: Content) -> Option<Content> {
-        let [max_sequence_length, d_model, _] = self.freq_complex.shape().dims();
+        let [max_sequence_length, rotary_dims, _] = self.freq_complex.shape().dims();
+        let content = content.add("d_model", &self.d_model);
+        let content = if rotary_dims < self.d_model {
+            content.add("rotary_dims", &rotary_dims)
+        } else {
+            content
+        };
         content
-            .add("d_model", &d_model)
             .add("max_sequence_length", &max_sequence_length)
             .optional()
     }
@@ -157,7 +309,8 @@ impl RotaryEncoding {
     /// * `start` - Sequence start position index.
     ///
     /// # Returns:
-    /// Output tensor with the same shape as input tensor after applying rotary encoding.
+    /// Output tensor with the same shape as input tensor after applying rotary encoding. With
+    /// partial rotary dimensions, only the leading features are rotated.
     ///
     /// # Panics
     /// If the input tensor does not have at least 2 dimensions for sequence length and hidden dimension.
@@ -167,6 +320,18 @@ impl RotaryEncoding {
             "Input tensor must have at least 2 dimensions for sequence length and hidden dimension"
         );
 
+        let rotary_dims = self.freq_complex.dims()[1];
+        let d_model = x.dims()[D - 1];
+        if rotary_dims < d_model {
+            let rotated = self.rotate(x.clone().narrow(D - 1, 0, rotary_dims), start);
+            let passed = x.narrow(D - 1, rotary_dims, d_model - rotary_dims);
+            return Tensor::cat(vec![rotated, passed], D - 1);
+        }
+
+        self.rotate(x, start)
+    }
+
+    fn rotate<const D: usize>(&self, x: Tensor<D>, start: usize) -> Tensor<D> {
         let device = x.device();
         let input_shape = x.shape();
 
@@ -210,8 +375,11 @@ impl RotaryEncoding {
 
         if start >= current_end {
             // Overwrite the whole buffer
-            let new_freqs =
-                Self::compute_rotary_frequencies(start..start + max_seq_len, self.theta.clone());
+            let new_freqs = Self::compute_rotary_frequencies(
+                start..start + max_seq_len,
+                self.theta.clone(),
+                self.attention_scaling,
+            );
             self.freq_complex
                 .inplace(|freqs| freqs.slice_assign([0..max_seq_len], new_freqs));
         } else {
@@ -225,6 +393,7 @@ impl RotaryEncoding {
             let new_freqs = Self::compute_rotary_frequencies(
                 current_end..start + max_seq_len,
                 self.theta.clone(),
+                self.attention_scaling,
             );
             self.freq_complex
                 .inplace(|freqs| freqs.slice_assign([num_keep..max_seq_len], new_freqs));
@@ -237,10 +406,15 @@ impl RotaryEncoding {
     /// # Arguments
     /// - `range`: Range of position indices `[start, end)`.
     /// - `theta`: 1D tensor of shape `(d_model / 2)` containing base angular frequencies.
+    /// - `attention_scaling`: Magnitude of the rotations.
     ///
     /// # Returns
     /// Tensor of shape `(range.len(), d_model, 2)` containing `[cos, sin]` pairs for each position and frequency.
-    fn compute_rotary_frequencies(range: Range<usize>, theta: Tensor<1>) -> Tensor<3> {
+    fn compute_rotary_frequencies(
+        range: Range<usize>,
+        theta: Tensor<1>,
+        attention_scaling: f32,
+    ) -> Tensor<3> {
         let d_model = theta.dims()[0] * 2;
         let num_positions = range.end - range.start;
 
@@ -254,8 +428,12 @@ impl RotaryEncoding {
                 * theta.unsqueeze();
 
         // Convert frequency values to complex numbers (polar form)
-        let p_cos = frequencies.clone().cos();
-        let p_sin = frequencies.sin();
+        let mut p_cos = frequencies.clone().cos();
+        let mut p_sin = frequencies.sin();
+        if attention_scaling != 1.0 {
+            p_cos = p_cos.mul_scalar(attention_scaling);
+            p_sin = p_sin.mul_scalar(attention_scaling);
+        }
 
         Tensor::cat(vec![p_cos, p_sin], 1)
             .reshape([num_positions, 2, d_model / 2])
@@ -269,7 +447,7 @@ impl RotaryEncoding {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use burn::tensor::Tolerance;
+    use burn::tensor::{Distribution, TensorData, Tolerance};
     type FT = f32;
 
     #[test]
@@ -566,6 +744,95 @@ mod tests {
         rotary_encoding.shift(4); // should be monotonically increasing
     }
 
+    #[test]
+    fn test_rotary_encoding_linear_scaling() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(4, 8).init(&device);
+        let scaled = RotaryEncodingConfig::new(4, 8)
+            .with_scaling(Some(RotaryScaling::Linear { factor: 2.0 }))
+            .init(&device);
+
+        // Position 2 of the scaled encoding is position 1 of the original one.
+        scaled
+            .freq_complex
+            .slice([2..3])
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &rotary_encoding.freq_complex.slice([1..2]).into_data(),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn test_rotary_encoding_ntk_scaling() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(2, 8)
+            .with_scaling(Some(RotaryScaling::Ntk { factor: 4.0 }))
+            .init(&device);
+
+        // The highest frequency is kept and the lowest one is interpolated by the factor.
+        let theta = rotary_encoding.theta.into_data().to_vec::<FT>().unwrap();
+        assert!((theta[0] - 1.0).abs() < 1e-6);
+        assert!((theta[3] - 1e-3 / 4.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_rotary_encoding_yarn_scaling() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(2, 8)
+            .with_scaling(Some(RotaryScaling::yarn(4.0, 4096)))
+            .init(&device);
+
+        // The correction range is [1, 3]: the ramp is [0, 0, 0.5, 1].
+        rotary_encoding.theta.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([1.0, 0.1, 0.00625, 0.00025]),
+            Tolerance::default(),
+        );
+
+        // The rotations are scaled by 0.1 * ln(4) + 1.
+        let mscale = 0.1 * 4f32.ln() + 1.0;
+        rotary_encoding
+            .freq_complex
+            .slice([0..1, 0..1])
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[[mscale, 0.0]]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_rotary_encoding_partial_rotary_dims() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4).init(&device);
+        let partial = RotaryEncodingConfig::new(10, 6)
+            .with_rotary_dims(Some(4))
+            .init(&device);
+
+        let input = Tensor::<3>::random([2, 3, 6], Distribution::Default, &device);
+        let output = partial.forward(input.clone());
+
+        let rotated = rotary_encoding.forward(input.clone().narrow(2, 0, 4));
+        output
+            .clone()
+            .narrow(2, 0, 4)
+            .into_data()
+            .assert_approx_eq::<FT>(&rotated.into_data(), Tolerance::default());
+        output
+            .narrow(2, 4, 2)
+            .into_data()
+            .assert_eq(&input.narrow(2, 4, 2).into_data(), true);
+        assert_eq!(
+            alloc::format!("{partial}"),
+            "RotaryEncoding {d_model: 6, rotary_dims: 4, max_sequence_length: 10}"
+        );
+    }
+
+    #[test]
+    #[should_panic = "The rotary dimension must be in (0, d_model]"]
+    fn test_rotary_dims_larger_than_d_model() {
+        RotaryEncodingConfig::new(10, 4)
+            .with_rotary_dims(Some(6))
+            .init(&Default::default());
+    }
+
     #[test]
     fn display() {
         let config = RotaryEncodingConfig::new(10, 4);
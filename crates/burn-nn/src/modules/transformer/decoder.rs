@@ -10,7 +10,7 @@ use crate::activation::ActivationConfig;
 use crate::cache::TensorCache;
 use crate::{
     Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
-    attention::{MhaCache, MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+    attention::{MhaCache, MhaInput, MhaKvCache, MultiHeadAttention, MultiHeadAttentionConfig},
 };
 
 use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
@@ -245,6 +245,60 @@ impl TransformerDecoderAutoregressiveCache {
     }
 }
 
+/// Key/value cache for a single [Transformer Decoder Layer](TransformerDecoderLayer), used for
+/// incremental decoding.
+pub struct TransformerDecoderLayerKvCache {
+    /// Self-attention cache.
+    pub self_attn: MhaKvCache,
+    /// The projected keys and values of the memory, computed on the first step.
+    memory: Option<(Tensor<4>, Tensor<4>)>,
+}
+
+impl TransformerDecoderLayerKvCache {
+    /// Create an empty cache holding up to `max_seq_length` target tokens.
+    pub fn new(max_seq_length: usize) -> Self {
+        Self {
+            self_attn: MhaKvCache::new(max_seq_length),
+            memory: None,
+        }
+    }
+}
+
+/// Key/value cache for the [Transformer Decoder](TransformerDecoder), used for incremental
+/// decoding with [forward_kv_cache](TransformerDecoder::forward_kv_cache).
+pub struct TransformerDecoderKvCache {
+    layers: Vec<TransformerDecoderLayerKvCache>,
+}
+
+impl TransformerDecoderKvCache {
+    fn new(num_layers: usize, max_seq_length: usize) -> Self {
+        Self {
+            layers: (0..num_layers)
+                .map(|_| TransformerDecoderLayerKvCache::new(max_seq_length))
+                .collect(),
+        }
+    }
+
+    /// The number of target tokens in the cache.
+    pub fn len(&self) -> usize {
+        self.layers.first().map_or(0, |layer| layer.self_attn.len())
+    }
+
+    /// Whether the cache holds no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove the tokens and the memory from the cache, keeping its buffers to decode a new
+    /// sequence.
+    pub fn reset(&mut self) {
+        for layer in self.layers.iter_mut() {
+            layer.self_attn.reset();
+            layer.memory = None;
+        }
+    }
+}
+
 impl TransformerDecoderLayer {
     /// Create a new [TransformerDecoderLayer](TransformerDecoderLayer).
     pub fn new(config: &TransformerDecoderConfig, device: &Device) -> Self {
@@ -450,6 +504,100 @@ impl TransformerDecoderLayer {
         input.target = x;
         input
     }
+
+    /// Applies the forward pass on the new target tokens using a key/value cache.
+    ///
+    /// The target only contains the new tokens, and its padding and attention masks cover all the
+    /// tokens in the cache, including the new ones.
+    pub fn forward_kv_cache(
+        &self,
+        mut input: TransformerDecoderInput,
+        cache: &mut TransformerDecoderLayerKvCache,
+    ) -> TransformerDecoderInput {
+        // Self attention residual path.
+        let x = input.target;
+        let mut residual_path = x.clone();
+
+        // Normalize.
+        if self.norm_first {
+            residual_path = self.norm_3.forward(residual_path);
+        }
+
+        // Self attention.
+        let mut self_attn_input = MhaInput::self_attn(residual_path);
+        if let Some(mask_pad) = &input.target_mask_pad {
+            self_attn_input = self_attn_input.mask_pad(mask_pad.clone());
+        }
+        if let Some(mask_attn) = &input.target_mask_attn {
+            self_attn_input = self_attn_input.mask_attn(mask_attn.clone());
+        }
+        let residual_path = self
+            .self_attn
+            .forward_kv_cache(self_attn_input, &mut cache.self_attn)
+            .context;
+
+        let residual_path = self.dropout.forward(residual_path);
+        let mut x = x + residual_path;
+
+        // Cross attention residual path.
+        // Normalize.
+        let residual_path = if self.norm_first {
+            self.norm_1.forward(x.clone())
+        } else {
+            x = self.norm_1.forward(x);
+            x.clone()
+        };
+
+        // Cross attention, with the keys and values of the memory projected once.
+        let (key, value) = cache
+            .memory
+            .get_or_insert_with(|| {
+                let attn = &self.cross_attn;
+                (
+                    attn.attention_linear(input.memory.clone(), &attn.key),
+                    attn.attention_linear(input.memory.clone(), &attn.value),
+                )
+            })
+            .clone();
+        let query = self
+            .cross_attn
+            .attention_linear(residual_path, &self.cross_attn.query);
+        let residual_path = self
+            .cross_attn
+            .forward_projected(
+                query,
+                key,
+                value,
+                input.memory_mask_pad.clone(),
+                input.memory_mask_attn.clone(),
+            )
+            .context;
+
+        let residual_path = self.dropout.forward(residual_path);
+        let mut x = x + residual_path;
+
+        // Feed forward residual path.
+        // Normalize.
+        let residual_path = if self.norm_first {
+            self.norm_2.forward(x.clone())
+        } else {
+            x = self.norm_2.forward(x);
+            x.clone()
+        };
+
+        let residual_path = self.pwff.forward(residual_path);
+        let residual_path = self.dropout.forward(residual_path);
+        let mut x = x + residual_path;
+
+        // Main path.
+        // Normalize.
+        if !self.norm_first {
+            x = self.norm_3.forward(x)
+        }
+
+        input.target = x;
+        input
+    }
 }
 
 impl TransformerDecoder {
@@ -481,6 +629,38 @@ impl TransformerDecoder {
     pub fn new_autoregressive_cache(&self) -> TransformerDecoderAutoregressiveCache {
         TransformerDecoderAutoregressiveCache::empty(self.layers.len())
     }
+
+    /// Applies the forward pass on the new target tokens using a key/value cache.
+    ///
+    /// Unlike [forward_autoregressive_inference](Self::forward_autoregressive_inference), the
+    /// target only contains the new tokens, so each step only computes the new tokens and
+    /// generating a sequence takes linear time in its length. When the target has several tokens
+    /// and no attention mask, such as when processing a prompt, a causal mask is applied.
+    ///
+    /// # Shapes
+    ///
+    /// - target: `[batch_size, seq_length_new, d_model]`
+    /// - memory: `[batch_size, seq_length_memory, d_model]`
+    /// - output: `[batch_size, seq_length_new, d_model]`
+    pub fn forward_kv_cache(
+        &self,
+        mut input: TransformerDecoderInput,
+        cache: &mut TransformerDecoderKvCache,
+    ) -> Tensor<3> {
+        for (layer, cache) in self.layers.iter().zip(cache.layers.iter_mut()) {
+            input = layer.forward_kv_cache(input, cache);
+        }
+
+        input.target
+    }
+
+    /// Create an empty key/value cache holding up to `max_seq_length` target tokens.
+    ///
+    /// The buffers of the cache are allocated on the first step, and reused until the cache is
+    /// dropped, even after a [reset](TransformerDecoderKvCache::reset).
+    pub fn new_kv_cache(&self, max_seq_length: usize) -> TransformerDecoderKvCache {
+        TransformerDecoderKvCache::new(self.layers.len(), max_seq_length)
+    }
 }
 
 #[cfg(test)]
@@ -557,6 +737,67 @@ mod tests {
             .assert_approx_eq::<FT>(&output_2.into_data(), tolerance);
     }
 
+    #[test]
+    fn test_kv_cache_norm_last() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        let device = Device::default();
+        device.seed(0);
+
+        test_kv_cache(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_norm_first(false),
+        )
+    }
+
+    #[test]
+    fn test_kv_cache_norm_first() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        let device = Device::default();
+        device.seed(0);
+
+        test_kv_cache(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers).with_norm_first(true),
+        )
+    }
+
+    fn test_kv_cache(config: TransformerDecoderConfig) {
+        let device = Default::default();
+        let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
+        let transformer = config.init(&device);
+
+        let memory = Tensor::arange(0..(batch_size * seq_length * d_model) as i64, &device)
+            .float()
+            .reshape([batch_size, seq_length, d_model]);
+        let target = Tensor::arange(0..(batch_size * seq_length * d_model) as i64, &device)
+            .float()
+            .reshape([batch_size, seq_length, d_model]);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &target.device());
+        let input = TransformerDecoderInput::new(target.clone(), memory.clone())
+            .target_mask_attn(mask_attn);
+
+        // Normal forward using masking.
+        let output_1 = transformer.forward(input);
+
+        // Forward one token at a time using the key/value cache.
+        let mut output_2 = Vec::new();
+        let mut cache = transformer.new_kv_cache(seq_length);
+
+        for i in 0..seq_length {
+            let target = target.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            let input = TransformerDecoderInput::new(target, memory.clone());
+            output_2.push(transformer.forward_kv_cache(input, &mut cache));
+        }
+
+        assert_eq!(cache.len(), seq_length);
+        let output_2 = Tensor::cat(output_2, 1);
+
+        // Should produce the same tokens.
+        let tolerance = Tolerance::rel_abs(5e-3, 1e-4);
+        output_1
+            .into_data()
+            .assert_approx_eq::<FT>(&output_2.into_data(), tolerance);
+    }
+
     #[test]
     fn display() {
         let config = TransformerDecoderConfig::new(2, 4, 2, 3);
@@ -1,6 +1,14 @@
 use burn_core as burn;
 
 use burn::config::Config;
+use burn::tensor::ops::PadMode;
+
+/// Whether the padding mode can be handled by the convolution itself, i.e. zero padding.
+///
+/// Other modes are applied by padding the input before the convolution.
+pub(crate) fn is_zero_padding(mode: &PadMode) -> bool {
+    matches!(mode, PadMode::Constant(value) if *value == 0.0)
+}
 
 /// Calculate asymmetric padding for "same" convolution.
 /// Returns (start_padding, end_padding) where start is applied first (top/left).
@@ -19,6 +19,9 @@ pub mod cosine;
 /// Step learning rate scheduler
 pub mod step;
 
+/// One cycle learning rate scheduler
+pub mod one_cycle;
+
 mod base;
 
 pub use base::*;
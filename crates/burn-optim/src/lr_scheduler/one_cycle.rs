@@ -0,0 +1,305 @@
+use burn_core as burn;
+
+use super::{LrScheduler, String};
+use crate::LearningRate;
+use burn::config::Config;
+
+/// The function used to change the learning rate and the momentum within a phase of the
+/// [one cycle schedule](OneCycleLrScheduler).
+#[derive(Config, Debug, Copy)]
+pub enum AnnealStrategy {
+    /// Follow half a cosine period between the two values.
+    Cos,
+    /// Interpolate linearly between the two values.
+    Linear,
+}
+
+/// The configuration for creating a [one cycle learning rate scheduler](OneCycleLrScheduler).
+///
+/// The learning rate warms up from `max_lr / div_factor` to `max_lr` during the first
+/// `pct_start` fraction of the steps, then anneals down to
+/// `max_lr / (div_factor * final_div_factor)` at the last step, and stays there afterwards.
+#[derive(Config, Debug)]
+pub struct OneCycleLrSchedulerConfig {
+    // The peak learning rate.
+    max_lr: LearningRate,
+    // The total number of steps of the cycle.
+    total_steps: usize,
+    // The fraction of the steps spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    // The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    // The final learning rate is the initial learning rate divided by `final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+    // The function used to change the learning rate and the momentum.
+    #[config(default = "AnnealStrategy::Cos")]
+    anneal_strategy: AnnealStrategy,
+    // Whether the momentum is cycled inversely to the learning rate, between `base_momentum` and
+    // `max_momentum`.
+    #[config(default = false)]
+    cycle_momentum: bool,
+    // The momentum at the peak learning rate.
+    #[config(default = 0.85)]
+    base_momentum: f64,
+    // The momentum at the start and the end of the cycle.
+    #[config(default = 0.95)]
+    max_momentum: f64,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Creates the configuration of a cycle spanning `num_epochs` epochs of `steps_per_epoch`
+    /// steps.
+    pub fn from_epochs(max_lr: LearningRate, num_epochs: usize, steps_per_epoch: usize) -> Self {
+        Self::new(max_lr, num_epochs * steps_per_epoch)
+    }
+
+    /// Initializes a [one cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `max_lr` is not greater than 0
+    /// * `total_steps` is less than 2
+    /// * `pct_start` is out of range (0.0, 1.0)
+    /// * `div_factor` or `final_div_factor` is not greater than 0
+    /// * `base_momentum` is greater than `max_momentum`, when cycling the momentum
+    pub fn init(&self) -> Result<OneCycleLrScheduler, String> {
+        if self.max_lr <= 0. {
+            return Err("Maximum learning rate must be greater than 0".into());
+        }
+        if self.total_steps < 2 {
+            return Err("Total number of steps must be at least 2".into());
+        }
+        if self.pct_start <= 0. || self.pct_start >= 1. {
+            return Err("Warmup fraction must be greater than 0 and less than 1".into());
+        }
+        if self.div_factor <= 0. || self.final_div_factor <= 0. {
+            return Err("Division factors must be greater than 0".into());
+        }
+        if self.cycle_momentum && self.base_momentum > self.max_momentum {
+            return Err("Base momentum must be at most equal to the maximum momentum".into());
+        }
+
+        let warmup_steps = ((self.pct_start * self.total_steps as f64).round() as usize)
+            .clamp(1, self.total_steps - 1);
+        let initial_lr = self.max_lr / self.div_factor;
+
+        Ok(OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            min_lr: initial_lr / self.final_div_factor,
+            warmup_steps,
+            total_steps: self.total_steps,
+            anneal_strategy: self.anneal_strategy,
+            momentum: self
+                .cycle_momentum
+                .then_some((self.base_momentum, self.max_momentum)),
+            current_iter: usize::MAX,
+        })
+    }
+}
+
+/// A one cycle learning rate scheduler.
+///
+/// This scheduler is described in [Super-Convergence: Very Fast Training of Neural Networks Using
+/// Large Learning Rates](https://arxiv.org/abs/1708.07120). See [OneCycleLrSchedulerConfig] for
+/// more information.
+///
+/// The scheduler only returns the learning rate: when the momentum is cycled, its value for the
+/// current step is available with [momentum](OneCycleLrScheduler::momentum), to be applied by a
+/// custom training loop.
+#[derive(Clone, Copy, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    min_lr: LearningRate,
+    warmup_steps: usize,
+    total_steps: usize,
+    anneal_strategy: AnnealStrategy,
+    // The base and maximum momentum, when cycled.
+    momentum: Option<(f64, f64)>,
+    current_iter: usize,
+}
+
+impl OneCycleLrScheduler {
+    /// The momentum of the current step, between the base momentum at the peak learning rate and
+    /// the maximum momentum at both ends of the cycle.
+    ///
+    /// Returns `None` when the momentum isn't cycled.
+    pub fn momentum(&self) -> Option<f64> {
+        let (base, max) = self.momentum?;
+        // Before the first step, the momentum is the one of the first step.
+        let iter = match self.current_iter {
+            usize::MAX => 0,
+            iter => iter,
+        };
+        let (phase, pct) = self.phase(iter);
+
+        Some(match phase {
+            Phase::Warmup => self.anneal(max, base, pct),
+            Phase::Anneal => self.anneal(base, max, pct),
+        })
+    }
+
+    // The phase of the cycle of an iteration, and the progress within the phase.
+    fn phase(&self, iter: usize) -> (Phase, f64) {
+        if iter < self.warmup_steps {
+            return (Phase::Warmup, iter as f64 / self.warmup_steps as f64);
+        }
+
+        let anneal_steps = self.total_steps - 1 - self.warmup_steps;
+        let pct = match anneal_steps {
+            0 => 1.0,
+            _ => ((iter - self.warmup_steps) as f64 / anneal_steps as f64).min(1.0),
+        };
+
+        (Phase::Anneal, pct)
+    }
+
+    fn anneal(&self, start: f64, end: f64, pct: f64) -> f64 {
+        match self.anneal_strategy {
+            AnnealStrategy::Cos => {
+                end + 0.5 * (start - end) * (1.0 + (pct * core::f64::consts::PI).cos())
+            }
+            AnnealStrategy::Linear => start + (end - start) * pct,
+        }
+    }
+}
+
+enum Phase {
+    Warmup,
+    Anneal,
+}
+
+impl LrScheduler for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        // Make current_iter overflow from usize::MAX to 0 on the first call, and saturate after
+        // the end of the cycle to keep the final learning rate.
+        self.current_iter = self.current_iter.wrapping_add(1).min(self.total_steps);
+
+        match self.phase(self.current_iter) {
+            (Phase::Warmup, pct) => self.anneal(self.initial_lr, self.max_lr, pct),
+            (Phase::Anneal, pct) => self.anneal(self.max_lr, self.min_lr, pct),
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_max_lr_too_low() {
+        let r = OneCycleLrSchedulerConfig::new(0., 10).init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Maximum learning rate must be greater than 0",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_total_steps_too_low() {
+        let r = OneCycleLrSchedulerConfig::new(0.1, 1).init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Total number of steps must be at least 2",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_pct_start_out_of_range() {
+        let r = OneCycleLrSchedulerConfig::new(0.1, 10)
+            .with_pct_start(1.0)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Warmup fraction must be greater than 0 and less than 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_change_cos() {
+        let scheduler = OneCycleLrSchedulerConfig::new(1.0, 5)
+            .with_pct_start(0.4)
+            .with_div_factor(4.0)
+            .with_final_div_factor(5.0)
+            .init()
+            .unwrap();
+        let expected_lrs = [
+            0.25,  // initial
+            0.625, // cos(PI/2) of the warmup
+            1.0,   // peak
+            0.525, // cos(PI/2) of the annealing
+            0.05,  // final
+            0.05,  // stays at the final learning rate
+        ];
+        test_utils::check_lr_sequence(scheduler, expected_lrs);
+    }
+
+    #[test]
+    fn test_lr_change_linear_in_epochs() {
+        let scheduler = OneCycleLrSchedulerConfig::from_epochs(1.0, 3, 2)
+            .with_pct_start(0.5)
+            .with_div_factor(4.0)
+            .with_final_div_factor(5.0)
+            .with_anneal_strategy(AnnealStrategy::Linear)
+            .init()
+            .unwrap();
+        let expected_lrs = [0.25, 0.5, 0.75, 1.0, 0.525, 0.05, 0.05];
+        test_utils::check_lr_sequence(scheduler, expected_lrs);
+    }
+
+    #[test]
+    fn test_momentum_cycles_inversely() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(1.0, 5)
+            .with_pct_start(0.4)
+            .with_cycle_momentum(true)
+            .init()
+            .unwrap();
+        let expected_momentums = [0.95, 0.9, 0.85, 0.9, 0.95];
+
+        for expected in expected_momentums {
+            scheduler.step();
+            let momentum = scheduler.momentum().unwrap();
+            assert!(
+                (momentum - expected).abs() < 1e-10,
+                "Momentum {momentum} should be {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_momentum_not_cycled_by_default() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(1.0, 5).init().unwrap();
+        scheduler.step();
+        assert_eq!(scheduler.momentum(), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let scheduler = OneCycleLrSchedulerConfig::new(0.5, 12).init().unwrap();
+        test_utils::check_save_load(scheduler, 5);
+    }
+}
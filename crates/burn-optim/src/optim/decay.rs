@@ -1,9 +1,14 @@
 use burn_core as burn;
 
 use burn::config::Config;
+use burn::module::ParamId;
 use burn::record::Record;
 use burn::tensor::Device;
 use burn::tensor::Tensor;
+use hashbrown::HashSet;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// Configuration to create [weight decay](WeightDecay).
 #[derive(Config, Debug)]
@@ -62,3 +67,71 @@ impl<const D: usize> WeightDecayState<D> {
         self
     }
 }
+
+/// Configuration to create [decoupled weight decay](DecoupledWeightDecay).
+#[derive(Config, Debug)]
+pub struct DecoupledWeightDecayConfig {
+    /// Decay rate, multiplied by the learning rate at each step.
+    pub penalty: f32,
+}
+
+/// Decoupled weight decay, which shrinks the parameters towards zero before the update of any
+/// optimizer, independently of the gradients.
+///
+/// Unlike [weight decay](WeightDecay), which adds an L2 penalty to the gradients, the decay isn't
+/// rescaled by the gradient statistics of adaptive optimizers, as in AdamW. It is applied by an
+/// [optimizer adaptor](crate::optim::adaptor::OptimizerAdaptor::with_decoupled_weight_decay),
+/// optionally to a subset of the parameters, usually selected by their path with
+/// [list_param_ids_matching](burn::module::list_param_ids_matching).
+///
+/// The decay of a [parameter group](crate::optim::ParamGroup::with_weight_decay) takes precedence
+/// over the one of the optimizer for the parameters of the group, whether or not they are
+/// selected by [with_params](DecoupledWeightDecay::with_params).
+#[derive(Clone, Debug)]
+pub struct DecoupledWeightDecay {
+    penalty: f32,
+    params: Option<HashSet<ParamId>>,
+}
+
+impl DecoupledWeightDecay {
+    /// Creates a new [decoupled weight decay](DecoupledWeightDecay) from a
+    /// [config](DecoupledWeightDecayConfig), applied to all the parameters.
+    pub fn new(config: &DecoupledWeightDecayConfig) -> Self {
+        Self {
+            penalty: config.penalty,
+            params: None,
+        }
+    }
+
+    /// Only decays the given parameters.
+    pub fn with_params(mut self, params: Vec<ParamId>) -> Self {
+        self.params = Some(params.into_iter().collect());
+        self
+    }
+
+    /// Decays a parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the parameter.
+    /// * `lr` - The learning rate of the step.
+    /// * `tensor` - The value of the parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `tensor` - The decayed value, `tensor * (1 - lr * penalty)` when the parameter is
+    ///   selected.
+    pub fn decay<const D: usize>(&self, id: ParamId, lr: f64, tensor: Tensor<D>) -> Tensor<D> {
+        let selected = self
+            .params
+            .as_ref()
+            .is_none_or(|params| params.contains(&id));
+        let rate = lr * self.penalty as f64;
+
+        if selected && rate != 0.0 {
+            tensor.mul_scalar(1.0 - rate)
+        } else {
+            tensor
+        }
+    }
+}
@@ -13,6 +13,7 @@ mod grad_accum;
 mod grads;
 mod lbfgs;
 mod muon;
+mod param_group;
 mod rmsprop;
 mod sgd;
 mod simple;
@@ -27,6 +28,7 @@ pub use grad_accum::*;
 pub use grads::*;
 pub use lbfgs::*;
 pub use muon::*;
+pub use param_group::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
@@ -0,0 +1,131 @@
+use burn_core as burn;
+
+use super::decay::{DecoupledWeightDecay, DecoupledWeightDecayConfig};
+use burn::module::{Module, ParamId, list_param_ids_matching};
+use hashbrown::HashSet;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A group of parameters updated with their own optimizer settings.
+///
+/// Parameter groups are attached to an
+/// [optimizer adaptor](crate::optim::adaptor::OptimizerAdaptor::with_param_group), and are
+/// usually selected by their path with [ParamGroup::matching], to train module subtrees with
+/// different learning rates or to exclude biases and normalization parameters from the weight
+/// decay.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let optim = AdamConfig::new()
+///     .init()
+///     .with_decoupled_weight_decay(DecoupledWeightDecay::new(&DecoupledWeightDecayConfig::new(0.01)))
+///     .with_param_group(ParamGroup::matching(&model, "*.bias").without_weight_decay())
+///     .with_param_group(ParamGroup::matching(&model, "encoder.*").with_lr_scale(0.1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParamGroup {
+    params: HashSet<ParamId>,
+    lr_scale: f64,
+    weight_decay: Option<DecoupledWeightDecay>,
+}
+
+impl ParamGroup {
+    /// Creates a group of the given parameters, with the settings of the optimizer.
+    pub fn new(params: Vec<ParamId>) -> Self {
+        Self {
+            params: params.into_iter().collect(),
+            lr_scale: 1.0,
+            weight_decay: None,
+        }
+    }
+
+    /// Creates a group of the parameters of a module whose path matches a pattern, as with
+    /// [list_param_ids_matching].
+    pub fn matching<M: Module>(module: &M, pattern: &str) -> Self {
+        Self::new(list_param_ids_matching(module, pattern))
+    }
+
+    /// Creates groups for layer-wise learning rate decay: the parameters matching the last
+    /// pattern, usually the head of the model, keep the learning rate of the optimizer, and the
+    /// learning rate is multiplied by `decay` for each previous pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module.
+    /// * `patterns` - The path patterns of the layers, from the input to the output.
+    /// * `decay` - The factor between the learning rates of consecutive layers.
+    pub fn layer_wise_lr_decay<M: Module>(module: &M, patterns: &[&str], decay: f64) -> Vec<Self> {
+        let num_layers = patterns.len();
+
+        patterns
+            .iter()
+            .enumerate()
+            .map(|(layer, pattern)| {
+                let depth = (num_layers - 1 - layer) as i32;
+                Self::matching(module, pattern).with_lr_scale(decay.powi(depth))
+            })
+            .collect()
+    }
+
+    /// Multiplies the learning rate of the optimizer by `lr_scale` for the parameters of the
+    /// group, so that the group follows the learning rate scheduler.
+    pub fn with_lr_scale(mut self, lr_scale: f64) -> Self {
+        self.lr_scale = lr_scale;
+        self
+    }
+
+    /// Applies a [decoupled weight decay](DecoupledWeightDecay) with the given penalty to the
+    /// parameters of the group, instead of the one of the optimizer, even when the latter only
+    /// [selects some parameters](DecoupledWeightDecay::with_params).
+    pub fn with_weight_decay(mut self, penalty: f32) -> Self {
+        let config = DecoupledWeightDecayConfig { penalty };
+        self.weight_decay = Some(DecoupledWeightDecay::new(&config));
+        self
+    }
+
+    /// Excludes the parameters of the group from the decoupled weight decay of the optimizer.
+    pub fn without_weight_decay(self) -> Self {
+        self.with_weight_decay(0.0)
+    }
+
+    /// Whether the group contains a parameter.
+    pub fn contains(&self, id: ParamId) -> bool {
+        self.params.contains(&id)
+    }
+
+    /// The ids of the parameters of the group.
+    pub fn params(&self) -> &HashSet<ParamId> {
+        &self.params
+    }
+
+    /// The factor applied to the learning rate of the optimizer.
+    pub fn lr_scale(&self) -> f64 {
+        self.lr_scale
+    }
+
+    /// The decoupled weight decay of the group, if it overrides the one of the optimizer.
+    pub fn weight_decay(&self) -> Option<&DecoupledWeightDecay> {
+        self.weight_decay.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::Device;
+    use burn_nn::LinearConfig;
+
+    #[test]
+    fn layer_wise_lr_decay_should_scale_earlier_layers() {
+        let layer = LinearConfig::new(4, 4).init(&Device::default());
+        let groups = ParamGroup::layer_wise_lr_decay(&layer, &["bias", "weight"], 0.5);
+
+        assert_eq!(groups.len(), 2);
+        assert!(groups[0].contains(layer.bias.as_ref().unwrap().id));
+        assert_eq!(groups[0].lr_scale(), 0.5);
+        assert!(groups[1].contains(layer.weight.id));
+        assert_eq!(groups[1].lr_scale(), 1.0);
+    }
+}
@@ -101,8 +101,12 @@ mod tests {
     use super::*;
     use crate::{
         grad_clipping::GradientClipping,
-        optim::{GradientsParams, Optimizer},
+        optim::{
+            GradientsParams, Optimizer, ParamGroup,
+            decay::{DecoupledWeightDecay, DecoupledWeightDecayConfig},
+        },
     };
+    use burn::module::list_param_ids_matching;
     use burn::tensor::{Distribution, Shape};
     use burn_nn::{Linear, LinearConfig};
 
@@ -156,6 +160,104 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[test]
+    fn should_apply_decoupled_weight_decay_to_selected_params() {
+        let device = Device::default().autodiff();
+        let layer = layer(&device);
+        let weight = layer.weight.val().inner();
+        let bias = layer.bias.as_ref().unwrap().val().inner();
+        let decay = DecoupledWeightDecay::new(&DecoupledWeightDecayConfig { penalty: 0.5 })
+            .with_params(list_param_ids_matching(&layer, "weight"));
+        let mut optim = SgdConfig::new()
+            .init::<Linear>()
+            .with_decoupled_weight_decay(decay);
+
+        // A zero loss only leaves the decay.
+        let loss = layer.forward(random_tensor(&device)).mul_scalar(0.0);
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .weight
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&weight.mul_scalar(0.99).into_data(), Default::default());
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&bias.into_data(), Default::default());
+    }
+
+    #[test]
+    fn should_apply_param_group_settings() {
+        let device = Device::default().autodiff();
+        let layer = layer(&device);
+        let weight = layer.weight.val().inner();
+        let bias = layer.bias.as_ref().unwrap().val().inner();
+        let decay = DecoupledWeightDecay::new(&DecoupledWeightDecayConfig { penalty: 0.5 });
+        let mut optim = SgdConfig::new()
+            .init::<Linear>()
+            .with_decoupled_weight_decay(decay)
+            .with_param_group(ParamGroup::matching(&layer, "weight").with_lr_scale(2.0))
+            .with_param_group(ParamGroup::matching(&layer, "bias").without_weight_decay());
+
+        // A zero loss only leaves the decay, with twice the learning rate for the weight.
+        let loss = layer.forward(random_tensor(&device)).mul_scalar(0.0);
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .weight
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&weight.mul_scalar(0.98).into_data(), Default::default());
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&bias.into_data(), Default::default());
+    }
+
+    #[test]
+    fn should_prefer_param_group_weight_decay_over_selected_params() {
+        let device = Device::default().autodiff();
+        let layer = layer(&device);
+        let weight = layer.weight.val().inner();
+        let bias = layer.bias.as_ref().unwrap().val().inner();
+        let decay = DecoupledWeightDecay::new(&DecoupledWeightDecayConfig { penalty: 0.5 })
+            .with_params(list_param_ids_matching(&layer, "weight"));
+        let mut optim = SgdConfig::new()
+            .init::<Linear>()
+            .with_decoupled_weight_decay(decay)
+            .with_param_group(ParamGroup::matching(&layer, "bias").with_weight_decay(1.0));
+
+        // The bias isn't selected by the decay of the optimizer, but its group has its own.
+        let loss = layer.forward(random_tensor(&device)).mul_scalar(0.0);
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .weight
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&weight.mul_scalar(0.99).into_data(), Default::default());
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .inner()
+            .into_data()
+            .assert_approx_eq::<f32>(&bias.mul_scalar(0.98).into_data(), Default::default());
+    }
+
     fn random_tensor(device: &Device) -> Tensor<2> {
         Tensor::<2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
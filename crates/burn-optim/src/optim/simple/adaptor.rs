@@ -6,7 +6,7 @@ use super::{SimpleOptimizer, record::AdaptorRecord};
 use crate::{
     LearningRate, MultiGradientsParams,
     grad_clipping::GradientClipping,
-    optim::{GradientsParams, Optimizer},
+    optim::{GradientsParams, Optimizer, ParamGroup, decay::DecoupledWeightDecay},
 };
 
 use burn::module::{AutodiffModule, ModuleMapper, Param, ParamId};
@@ -14,6 +14,9 @@ use burn::tensor::{Device, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// Wrapper struct that adapts any [simple optimizer](SimpleOptimizer) into
 /// an [optimizer](Optimizer).
 #[derive(Clone)]
@@ -26,6 +29,10 @@ where
     records: HashMap<ParamId, AdaptorRecord<O>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    weight_decay: Option<DecoupledWeightDecay>,
+    param_groups: Vec<ParamGroup>,
+    /// The index of the group of each parameter that belongs to one.
+    param_group_index: HashMap<ParamId, usize>,
 }
 
 impl<O, M> From<O> for OptimizerAdaptor<O, M>
@@ -39,6 +46,9 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            weight_decay: None,
+            param_groups: Vec::new(),
+            param_group_index: HashMap::new(),
         }
     }
 }
@@ -77,6 +87,49 @@ where
         self
     }
 
+    /// Sets the [decoupled weight decay](DecoupledWeightDecay), applied to the parameters before
+    /// the update of the optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight_decay` - The decoupled weight decay.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_decoupled_weight_decay(mut self, weight_decay: DecoupledWeightDecay) -> Self {
+        self.weight_decay = Some(weight_decay);
+        self
+    }
+
+    /// Adds a [parameter group](ParamGroup), updated with its own learning rate scale and
+    /// decoupled weight decay.
+    ///
+    /// A parameter belongs to the first added group that contains it, and the parameters outside
+    /// of any group use the settings of the optimizer. The decoupled weight decay of a group
+    /// replaces the one of the optimizer for its parameters, and a group without one keeps it.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_param_group(mut self, group: ParamGroup) -> Self {
+        let index = self.param_groups.len();
+        for &id in group.params() {
+            self.param_group_index.entry(id).or_insert(index);
+        }
+        self.param_groups.push(group);
+        self
+    }
+
+    /// Access the parameter groups.
+    pub fn param_groups(&self) -> &[ParamGroup] {
+        &self.param_groups
+    }
+
     fn step_common(&mut self, lr: LearningRate, module: M, mut grads: GradAdaptor) -> M {
         module.map(&mut SimpleOptimizerMapper::<O>::new(
             &self.optim,
@@ -84,6 +137,9 @@ where
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            self.weight_decay.as_ref(),
+            &self.param_groups,
+            &self.param_group_index,
         ))
     }
 }
@@ -160,6 +216,9 @@ where
     grads: &'a mut GradAdaptor,
     lr: LearningRate,
     grad_clipping: Option<&'a GradientClipping>,
+    weight_decay: Option<&'a DecoupledWeightDecay>,
+    param_groups: &'a [ParamGroup],
+    param_group_index: &'a HashMap<ParamId, usize>,
 }
 
 impl<O> ModuleMapper for SimpleOptimizerMapper<'_, O>
@@ -199,9 +258,22 @@ where
                 "Tensor and gradients are on the same device."
             );
 
+            let group = self
+                .param_group_index
+                .get(&id)
+                .map(|&index| &self.param_groups[index]);
+            let lr = group.map_or(self.lr, |group| self.lr * group.lr_scale());
+            let weight_decay = group
+                .and_then(|group| group.weight_decay())
+                .or(self.weight_decay);
+
+            let tensor = match weight_decay {
+                Some(weight_decay) => weight_decay.decay(id, lr, tensor.inner()),
+                None => tensor.inner(),
+            };
             let (tensor, state) = self.optimizer.step(
-                self.lr,
-                tensor.inner(),
+                lr,
+                tensor,
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
             );
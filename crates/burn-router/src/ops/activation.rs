@@ -1,4 +1,17 @@
-use crate::{BackendRouter, RunnerChannel};
-use burn_backend::ops::ActivationOps;
+use crate::{BackendRouter, RunnerChannel, RunnerClient};
+use burn_backend::{ops::ActivationOps, tensor::FloatTensor};
+use burn_ir::{FloatOperationIr, OperationIr, ReduceDimOpIr};
 
-impl<R: RunnerChannel> ActivationOps<Self> for BackendRouter<R> {}
+impl<R: RunnerChannel> ActivationOps<Self> for BackendRouter<R> {
+    fn logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        let client = tensor.client.clone();
+        let desc = ReduceDimOpIr::create(tensor.into_ir(), dim, 1, || client.create_empty_handle());
+
+        client
+            .register(OperationIr::Float(
+                desc.out.dtype,
+                FloatOperationIr::LogSumExp(desc),
+            ))
+            .output()
+    }
+}
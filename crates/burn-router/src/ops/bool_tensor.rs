@@ -9,8 +9,8 @@ use burn_backend::{Scalar, Shape, Slice, TensorData};
 use burn_ir::{
     BaseOperationIr, BinaryOpIr, BoolOperationIr, CastOpIr, CatOpIr, CreationOpIr, FlipOpIr,
     GatherOpIr, InitOperationIr, MaskFillOpIr, MaskWhereOpIr, OperationIr, OperationOutput,
-    PermuteOpIr, RepeatDimOpIr, ScalarOpIr, ScatterOpIr, SelectAssignOpIr, SelectOpIr, ShapeOpIr,
-    SliceAssignOpIr, SliceOpIr, SwapDimsOpIr, UnaryOpIr, UnfoldOpIr,
+    PermuteOpIr, RepeatDimOpIr, RollOpIr, ScalarOpIr, ScatterOpIr, SelectAssignOpIr, SelectOpIr,
+    ShapeOpIr, SliceAssignOpIr, SliceOpIr, SwapDimsOpIr, UnaryOpIr, UnfoldOpIr,
 };
 
 impl<R: RunnerChannel> BoolTensorOps<Self> for BackendRouter<R> {
@@ -202,6 +202,17 @@ impl<R: RunnerChannel> BoolTensorOps<Self> for BackendRouter<R> {
             .output()
     }
 
+    fn bool_roll(tensor: BoolTensor<Self>, shifts: &[usize], dims: &[usize]) -> BoolTensor<Self> {
+        let client = tensor.client.clone();
+        let desc = RollOpIr::create(tensor.into_ir(), shifts.into(), dims.into(), || {
+            client.create_empty_handle()
+        });
+
+        client
+            .register(OperationIr::BaseBool(BaseOperationIr::Roll(desc)))
+            .output()
+    }
+
     fn bool_expand(tensor: BoolTensor<Self>, shape: Shape) -> BoolTensor<Self> {
         let client = tensor.client.clone();
         let desc = ShapeOpIr::expand(tensor.into_ir(), shape, || client.create_empty_handle());
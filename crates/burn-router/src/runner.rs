@@ -171,6 +171,12 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::float_flip(tensor, &desc.axes);
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                BaseOperationIr::Roll(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_roll(tensor, &desc.shifts, &desc.dims);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 BaseOperationIr::Expand(desc) => {
                     let tensor = handles.get_float_tensor::<B>(&desc.input);
 
@@ -334,6 +340,12 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::int_flip(tensor, &desc.axes);
                     handles.register_int_tensor::<B>(&desc.out.id, output);
                 }
+                BaseOperationIr::Roll(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.input);
+
+                    let output = B::int_roll(tensor, &desc.shifts, &desc.dims);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
                 BaseOperationIr::Expand(desc) => {
                     let tensor = handles.get_int_tensor::<B>(&desc.input);
 
@@ -493,6 +505,12 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::bool_flip(tensor, &desc.axes);
                     handles.register_bool_tensor::<B>(&desc.out.id, output);
                 }
+                BaseOperationIr::Roll(desc) => {
+                    let tensor = handles.get_bool_tensor::<B>(&desc.input);
+
+                    let output = B::bool_roll(tensor, &desc.shifts, &desc.dims);
+                    handles.register_bool_tensor::<B>(&desc.out.id, output);
+                }
                 BaseOperationIr::Expand(desc) => {
                     let tensor = handles.get_bool_tensor::<B>(&desc.input);
 
@@ -1183,6 +1201,12 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::float_grid_sample_2d(tensor, grid, desc.options.clone().into());
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                FloatOperationIr::LogSumExp(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::logsumexp(tensor, desc.axis);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
             },
             OperationIr::Module(op) => match op {
                 ModuleOperationIr::Embedding(desc) => {
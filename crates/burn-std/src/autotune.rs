@@ -0,0 +1,307 @@
+//! Every autotuned operation benchmarks a set of candidate kernels the first time a new
+//! [autotune key](cubecl::tune::AutotuneKey) is seen. The [budget](AutotuneBudget) caps how many
+//! candidates are benchmarked for a key and for how long, which keeps tuning cheap in CI while
+//! deployment builds can still tune exhaustively.
+//!
+//! The budget is set with [set_autotune_budget], or read once from the environment:
+//!
+//! - `BURN_AUTOTUNE_PROFILE`: `fast` or `exhaustive`.
+//! - `BURN_AUTOTUNE_MAX_CANDIDATES`: maximum number of candidates benchmarked per key.
+//! - `BURN_AUTOTUNE_MAX_TIME_MS`: time after which no new candidate is benchmarked for a key.
+//!
+//! The explicit limits override the ones of the profile. Invalid values are ignored.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use cubecl::tune::{AutotuneError, IntoTuneFn, Tunable, TuneFn};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+/// Preset [budgets](AutotuneBudget) for autotuning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutotuneProfile {
+    /// Benchmark a single candidate per key, the one with the highest priority.
+    ///
+    /// Suited for CI and tests, where tuning every shape would dominate the run time.
+    Fast,
+    /// Benchmark every candidate selected by the autotune level without limits.
+    ///
+    /// Suited for deployment builds, ideally combined with a bundled autotune cache.
+    Exhaustive,
+}
+
+/// Limits on the work done when autotuning a single key.
+///
+/// Candidates are benchmarked in priority order, so the limits drop the candidates with the
+/// lowest priorities. The first candidate is always benchmarked, so a kernel can always be
+/// selected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AutotuneBudget {
+    /// The maximum number of candidates benchmarked per key.
+    pub max_candidates: Option<usize>,
+    /// The time after which no new candidate is benchmarked for a key.
+    pub max_time_per_key: Option<Duration>,
+}
+
+impl AutotuneBudget {
+    /// A budget without limits.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// The budget of the given profile.
+    pub fn from_profile(profile: AutotuneProfile) -> Self {
+        match profile {
+            AutotuneProfile::Fast => Self::unlimited().with_max_candidates(1),
+            AutotuneProfile::Exhaustive => Self::unlimited(),
+        }
+    }
+
+    /// Set the maximum number of candidates benchmarked per key.
+    pub fn with_max_candidates(mut self, max_candidates: usize) -> Self {
+        self.max_candidates = Some(max_candidates);
+        self
+    }
+
+    /// Set the time after which no new candidate is benchmarked for a key.
+    pub fn with_max_time_per_key(mut self, max_time_per_key: Duration) -> Self {
+        self.max_time_per_key = Some(max_time_per_key);
+        self
+    }
+
+    fn from_env() -> Self {
+        let mut budget = match std::env::var("BURN_AUTOTUNE_PROFILE") {
+            Ok(val) => match val.to_ascii_lowercase().as_str() {
+                "fast" => Self::from_profile(AutotuneProfile::Fast),
+                _ => Self::unlimited(),
+            },
+            Err(_) => Self::unlimited(),
+        };
+
+        if let Some(max_candidates) = env_usize("BURN_AUTOTUNE_MAX_CANDIDATES") {
+            budget = budget.with_max_candidates(max_candidates);
+        }
+        if let Some(millis) = env_usize("BURN_AUTOTUNE_MAX_TIME_MS") {
+            budget = budget.with_max_time_per_key(Duration::from_millis(millis as u64));
+        }
+
+        budget
+    }
+}
+
+fn env_usize(name: &str) -> Option<usize> {
+    std::env::var(name).ok()?.parse().ok()
+}
+
+static BUDGET: RwLock<Option<AutotuneBudget>> = RwLock::new(None);
+
+/// Set the [budget](AutotuneBudget) used for keys that are not tuned yet.
+///
+/// This overrides the budget read from the environment. Keys that are already tuned, including
+/// the ones loaded from the autotune cache, are not tuned again.
+pub fn set_autotune_budget(budget: AutotuneBudget) {
+    *BUDGET.write().unwrap() = Some(budget);
+}
+
+/// The current autotune [budget](AutotuneBudget).
+pub fn autotune_budget() -> AutotuneBudget {
+    if let Some(budget) = *BUDGET.read().unwrap() {
+        return budget;
+    }
+
+    *BUDGET
+        .write()
+        .unwrap()
+        .get_or_insert_with(AutotuneBudget::from_env)
+}
+
+/// The candidates started while tuning a single key.
+struct TuneSession {
+    budget: AutotuneBudget,
+    start: Option<Instant>,
+    started: HashSet<usize>,
+}
+
+impl TuneSession {
+    fn admit(&mut self, candidate: usize) -> bool {
+        if self.started.contains(&candidate) {
+            return true;
+        }
+
+        let start = *self.start.get_or_insert_with(Instant::now);
+
+        if !self.started.is_empty() {
+            if let Some(max) = self.budget.max_candidates
+                && self.started.len() >= max
+            {
+                return false;
+            }
+            if let Some(max) = self.budget.max_time_per_key
+                && start.elapsed() >= max
+            {
+                return false;
+            }
+        }
+
+        self.started.insert(candidate);
+        true
+    }
+}
+
+std::thread_local! {
+    static SESSION: RefCell<Option<TuneSession>> = const { RefCell::new(None) };
+}
+
+/// Run an autotuned operation, limiting the [budgeted](budgeted_tunable) candidates benchmarked
+/// by the current [budget](autotune_budget).
+///
+/// Calls nest, so an operation autotuned while benchmarking another one gets its own budget.
+pub fn tune_with_budget<O>(func: impl FnOnce() -> O) -> O {
+    with_session(autotune_budget(), func)
+}
+
+fn with_session<O>(budget: AutotuneBudget, func: impl FnOnce() -> O) -> O {
+    // On wasm, tuning is spawned as a detached task and isn't covered by the session.
+    if cfg!(target_family = "wasm") {
+        return func();
+    }
+
+    struct Restore(Option<TuneSession>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SESSION.with(|session| *session.borrow_mut() = previous);
+        }
+    }
+
+    let session = TuneSession {
+        budget,
+        start: None,
+        started: HashSet::new(),
+    };
+    let _restore = Restore(SESSION.with(|current| current.borrow_mut().replace(session)));
+
+    func()
+}
+
+/// Create a [tunable](Tunable) that is skipped once the budget of the key being tuned by
+/// [tune_with_budget] is exhausted.
+pub fn budgeted_tunable<K, In, Out, Marker>(
+    name: impl Into<String>,
+    function: impl IntoTuneFn<In, Out, Marker>,
+) -> Tunable<K, In, Out> {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let name = name.into();
+    let function = Budgeted {
+        id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        function: function.into_tunable(name.clone()),
+    };
+
+    Tunable::new(name, function)
+}
+
+struct Budgeted<F> {
+    id: usize,
+    function: F,
+}
+
+impl<F: TuneFn> TuneFn for Budgeted<F> {
+    type Inputs = F::Inputs;
+    type Output = F::Output;
+
+    fn execute(&self, inputs: Self::Inputs) -> Result<Self::Output, AutotuneError> {
+        let admitted = SESSION.with(|session| match session.borrow_mut().as_mut() {
+            Some(session) => session.admit(self.id),
+            None => true,
+        });
+
+        if !admitted {
+            return Err(AutotuneError::Skip {
+                name: self.function.name().to_string(),
+            });
+        }
+
+        self.function.execute(inputs)
+    }
+
+    fn name(&self) -> &str {
+        self.function.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(budget: AutotuneBudget) -> TuneSession {
+        TuneSession {
+            budget,
+            start: None,
+            started: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn should_limit_candidates() {
+        let mut session = session(AutotuneBudget::unlimited().with_max_candidates(2));
+
+        assert!(session.admit(3));
+        assert!(session.admit(1));
+        assert!(!session.admit(2));
+        // Candidates already benchmarked keep running, so the fastest one can be executed.
+        assert!(session.admit(3));
+        assert!(session.admit(1));
+    }
+
+    #[test]
+    fn should_limit_time_per_key() {
+        let mut session =
+            session(AutotuneBudget::unlimited().with_max_time_per_key(Duration::ZERO));
+
+        assert!(session.admit(0));
+        assert!(!session.admit(1));
+        assert!(session.admit(0));
+    }
+
+    #[test]
+    fn should_always_admit_first_candidate() {
+        let mut session = session(AutotuneBudget::unlimited().with_max_candidates(0));
+
+        assert!(session.admit(0));
+        assert!(!session.admit(1));
+    }
+
+    #[test]
+    fn should_admit_without_session() {
+        let tunable = Budgeted {
+            id: 0,
+            function: (|x: u32| Ok::<_, String>(x + 1)).into_tunable("add".to_string()),
+        };
+
+        assert_eq!(tunable.execute(1).unwrap(), 2);
+    }
+
+    #[test]
+    fn should_skip_over_budget_in_session() {
+        let first = Budgeted {
+            id: usize::MAX - 1,
+            function: (|x: u32| Ok::<_, String>(x)).into_tunable("first".to_string()),
+        };
+        let second = Budgeted {
+            id: usize::MAX,
+            function: (|x: u32| Ok::<_, String>(x)).into_tunable("second".to_string()),
+        };
+        let budget = AutotuneBudget::from_profile(AutotuneProfile::Fast);
+
+        with_session(budget, || {
+            assert!(first.execute(0).is_ok());
+            assert!(matches!(second.execute(0), Err(AutotuneError::Skip { .. })));
+        });
+        assert!(second.execute(0).is_ok());
+    }
+}
@@ -0,0 +1,362 @@
+//! [Apache Arrow](https://arrow.apache.org/) interoperability for [`TensorData`].
+//!
+//! Primitive arrays map to 1D tensor data and (nested) fixed-size-list arrays map to tensor data
+//! of higher rank. Those conversions share the underlying buffer, so dataframe libraries such as
+//! polars can hand batches over to Burn without copying them.
+
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ArrowPrimitiveType, Float16Type, Float32Type, Float64Type, Int8Type, Int16Type, Int32Type,
+    Int64Type, UInt8Type, UInt16Type, UInt32Type, UInt64Type,
+};
+use arrow_array::{Array, ArrayRef, BooleanArray, FixedSizeListArray, PrimitiveArray, RecordBatch};
+use arrow_buffer::{Buffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::{AllocationProperty, BoolStore, Bytes, DType, DataError, Shape, TensorData};
+
+/// Shares an Arrow buffer with [`Bytes`].
+struct ArrowBuffer(Buffer);
+
+impl AsRef<[u8]> for ArrowBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Shares [`Bytes`] with an Arrow buffer.
+struct DataBuffer(Bytes);
+
+impl AsRef<[u8]> for DataBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TensorData {
+    /// Creates tensor data from an Arrow array without copying its values.
+    ///
+    /// - A primitive array of length `N` becomes tensor data of shape `[N]`.
+    /// - A fixed-size-list array of length `N` and list size `K` becomes tensor data of shape
+    ///   `[N, K, ...]`, where the trailing dimensions come from the list values.
+    ///
+    /// Boolean arrays are bit-packed by Arrow, so their values are copied.
+    ///
+    /// # Errors
+    ///
+    /// If the array contains nulls or if its data type has no tensor equivalent.
+    pub fn from_arrow(array: &dyn Array) -> Result<Self, DataError> {
+        if array.null_count() > 0 {
+            return Err(DataError::Unsupported(format!(
+                "Arrow array with {} null values can't be converted to tensor data",
+                array.null_count()
+            )));
+        }
+
+        let (buffer, dtype) = match array.data_type() {
+            DataType::Float64 => (primitive_buffer::<Float64Type>(array), DType::F64),
+            DataType::Float32 => (primitive_buffer::<Float32Type>(array), DType::F32),
+            DataType::Float16 => (primitive_buffer::<Float16Type>(array), DType::F16),
+            DataType::Int64 => (primitive_buffer::<Int64Type>(array), DType::I64),
+            DataType::Int32 => (primitive_buffer::<Int32Type>(array), DType::I32),
+            DataType::Int16 => (primitive_buffer::<Int16Type>(array), DType::I16),
+            DataType::Int8 => (primitive_buffer::<Int8Type>(array), DType::I8),
+            DataType::UInt64 => (primitive_buffer::<UInt64Type>(array), DType::U64),
+            DataType::UInt32 => (primitive_buffer::<UInt32Type>(array), DType::U32),
+            DataType::UInt16 => (primitive_buffer::<UInt16Type>(array), DType::U16),
+            DataType::UInt8 => (primitive_buffer::<UInt8Type>(array), DType::U8),
+            DataType::Boolean => {
+                let values = array.as_boolean().values().iter().collect::<Vec<_>>();
+                return Ok(TensorData::new(values, [array.len()]));
+            }
+            DataType::FixedSizeList(_, size) => {
+                let list = array.as_fixed_size_list();
+                // Only the values of the lists in the (sliced) array are converted.
+                let values = list.values().slice(0, list.len() * *size as usize);
+                let values = Self::from_arrow(values.as_ref())?;
+
+                let mut dims = vec![list.len(), *size as usize];
+                dims.extend_from_slice(&values.shape.as_slice()[1..]);
+
+                return Ok(Self::from_bytes(
+                    values.bytes,
+                    Shape::from(dims),
+                    values.dtype,
+                ));
+            }
+            other => {
+                return Err(DataError::TypeMismatch(format!(
+                    "Arrow data type {other} can't be converted to tensor data"
+                )));
+            }
+        };
+
+        let bytes = bytes::Bytes::from_owner(ArrowBuffer(buffer));
+        let bytes = Bytes::from_shared(bytes, AllocationProperty::Native);
+
+        Ok(Self::from_bytes(bytes, [array.len()], dtype))
+    }
+
+    /// Creates tensor data from an Arrow record batch.
+    ///
+    /// A batch with a single column is converted with [`TensorData::from_arrow`] without copying
+    /// its values. A batch with `C` primitive columns of the same data type becomes tensor data
+    /// of shape `[num_rows, C]`; since Arrow stores columns separately, the values are
+    /// interleaved into a new buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the batch has no columns, if the columns have different data types or if a column
+    /// can't be converted with [`TensorData::from_arrow`].
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self, DataError> {
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| Self::from_arrow(column.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match columns.len() {
+            0 => Err(DataError::Unsupported(
+                "Arrow record batch without columns can't be converted to tensor data".into(),
+            )),
+            1 => Ok(columns.into_iter().next().unwrap()),
+            num_columns => {
+                let dtype = columns[0].dtype;
+                if let Some(other) = columns.iter().find(|column| column.dtype != dtype) {
+                    return Err(DataError::TypeMismatch(format!(
+                        "Arrow record batch columns must share the same data type, got {dtype:?} and {:?}",
+                        other.dtype
+                    )));
+                }
+                if columns.iter().any(|column| column.rank() != 1) {
+                    return Err(DataError::Unsupported(
+                        "Arrow record batch with more than one column must only contain primitive columns".into(),
+                    ));
+                }
+
+                let num_rows = batch.num_rows();
+                let elem_size = dtype.size();
+                let mut bytes = vec![0u8; num_rows * num_columns * elem_size];
+
+                for (col, column) in columns.iter().enumerate() {
+                    for (row, value) in column.as_bytes().chunks_exact(elem_size).enumerate() {
+                        let start = (row * num_columns + col) * elem_size;
+                        bytes[start..start + elem_size].copy_from_slice(value);
+                    }
+                }
+
+                Ok(Self::from_bytes_vec(bytes, [num_rows, num_columns], dtype))
+            }
+        }
+    }
+
+    /// Converts the tensor data into an Arrow array without copying its values.
+    ///
+    /// Tensor data of shape `[N]` becomes a primitive array of length `N`, and tensor data of
+    /// shape `[N, K, ...]` becomes a (nested) fixed-size-list array of length `N`.
+    ///
+    /// Boolean values are bit-packed by Arrow, so they are copied.
+    ///
+    /// # Errors
+    ///
+    /// If the data type has no Arrow equivalent (e.g., `bf16` or quantized data).
+    pub fn into_arrow(self) -> Result<ArrayRef, DataError> {
+        let dims = match self.shape.as_slice() {
+            [] => vec![1],
+            dims => dims.to_vec(),
+        };
+        let num_elements = dims.iter().product::<usize>();
+
+        let mut array = match self.dtype {
+            DType::Bool(BoolStore::Native) => {
+                let values = self.into_vec::<bool>()?;
+                Arc::new(BooleanArray::from(values)) as ArrayRef
+            }
+            dtype => {
+                let buffer = Buffer::from(bytes::Bytes::from_owner(DataBuffer(self.bytes)));
+                match dtype {
+                    DType::F64 => primitive_array::<Float64Type>(buffer, num_elements),
+                    DType::F32 | DType::Flex32 => {
+                        primitive_array::<Float32Type>(buffer, num_elements)
+                    }
+                    DType::F16 => primitive_array::<Float16Type>(buffer, num_elements),
+                    DType::I64 => primitive_array::<Int64Type>(buffer, num_elements),
+                    DType::I32 => primitive_array::<Int32Type>(buffer, num_elements),
+                    DType::I16 => primitive_array::<Int16Type>(buffer, num_elements),
+                    DType::I8 => primitive_array::<Int8Type>(buffer, num_elements),
+                    DType::U64 => primitive_array::<UInt64Type>(buffer, num_elements),
+                    DType::U32 | DType::Bool(BoolStore::U32) => {
+                        primitive_array::<UInt32Type>(buffer, num_elements)
+                    }
+                    DType::U16 => primitive_array::<UInt16Type>(buffer, num_elements),
+                    DType::U8 | DType::Bool(BoolStore::U8) => {
+                        primitive_array::<UInt8Type>(buffer, num_elements)
+                    }
+                    DType::BF16 | DType::QFloat(_) | DType::Bool(BoolStore::Native) => {
+                        return Err(DataError::TypeMismatch(format!(
+                            "Data type {dtype:?} can't be converted to an Arrow array"
+                        )));
+                    }
+                }
+            }
+        };
+
+        // Wrap the flat values from the innermost dimension outward.
+        for &size in dims[1..].iter().rev() {
+            let field = Field::new("item", array.data_type().clone(), false);
+            array = Arc::new(FixedSizeListArray::new(
+                Arc::new(field),
+                size as i32,
+                array,
+                None,
+            ));
+        }
+
+        Ok(array)
+    }
+
+    /// Converts the tensor data into an Arrow record batch with a single column named `name`.
+    ///
+    /// The column is created with [`TensorData::into_arrow`], so no values are copied.
+    ///
+    /// # Errors
+    ///
+    /// If the data can't be converted with [`TensorData::into_arrow`].
+    pub fn into_record_batch(self, name: &str) -> Result<RecordBatch, DataError> {
+        let array = self.into_arrow()?;
+        let schema = Schema::new(vec![Field::new(name, array.data_type().clone(), false)]);
+
+        RecordBatch::try_new(Arc::new(schema), vec![array])
+            .map_err(|err| DataError::Unsupported(format!("{err}")))
+    }
+}
+
+fn primitive_buffer<T: ArrowPrimitiveType>(array: &dyn Array) -> Buffer {
+    // The scalar buffer is already sliced to the array offset and length.
+    array.as_primitive::<T>().values().inner().clone()
+}
+
+fn primitive_array<T: ArrowPrimitiveType>(buffer: Buffer, len: usize) -> ArrayRef {
+    // Arrow requires the values to be aligned to their type, which the tensor data bytes might
+    // not be, e.g. when they were created from a byte buffer. Those are copied instead.
+    let buffer = match buffer
+        .as_ptr()
+        .align_offset(core::mem::align_of::<T::Native>())
+    {
+        0 => buffer,
+        _ => Buffer::from_slice_ref(buffer.as_slice()),
+    };
+
+    Arc::new(PrimitiveArray::<T>::new(
+        ScalarBuffer::new(buffer, 0, len),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float32Array, Int64Array};
+
+    #[test]
+    fn should_convert_primitive_array() {
+        let array = Float32Array::from(vec![1.0, 2.0, 3.0, 4.0]);
+        let data = TensorData::from_arrow(&array).unwrap();
+
+        data.assert_eq(&TensorData::from([1.0f32, 2.0, 3.0, 4.0]), true);
+    }
+
+    #[test]
+    fn should_convert_sliced_primitive_array() {
+        let array = Int64Array::from(vec![1, 2, 3, 4, 5]).slice(1, 3);
+        let data = TensorData::from_arrow(&array).unwrap();
+
+        data.assert_eq(&TensorData::from([2i64, 3, 4]), true);
+    }
+
+    #[test]
+    fn should_share_primitive_array_buffer() {
+        let array = Float32Array::from(vec![1.0, 2.0, 3.0]);
+        let ptr = array.values().inner().as_ptr();
+        let data = TensorData::from_arrow(&array).unwrap();
+
+        assert_eq!(data.as_bytes().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn should_round_trip_fixed_size_list() {
+        let data = TensorData::from([[[1.0f32, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+        let array = data.clone().into_arrow().unwrap();
+
+        assert_eq!(array.len(), 2);
+        assert!(matches!(array.data_type(), DataType::FixedSizeList(_, 2)));
+
+        let output = TensorData::from_arrow(array.as_ref()).unwrap();
+        output.assert_eq(&data, true);
+    }
+
+    #[test]
+    fn should_convert_sliced_fixed_size_list() {
+        let data = TensorData::from([[1i32, 2], [3, 4], [5, 6], [7, 8]]);
+        let array = data.into_arrow().unwrap().slice(1, 2);
+
+        let output = TensorData::from_arrow(array.as_ref()).unwrap();
+        output.assert_eq(&TensorData::from([[3i32, 4], [5, 6]]), true);
+    }
+
+    #[test]
+    fn should_copy_misaligned_bytes() {
+        // The values start one byte into the allocation, so they aren't aligned for `f32`.
+        let values = [1.0f32, 2.0, 3.0].map(f32::to_le_bytes).concat();
+        let bytes = bytes::Bytes::from([&[0u8][..], &values[..]].concat()).slice(1..);
+        let bytes = Bytes::from_shared(bytes, AllocationProperty::Native);
+        let data = TensorData::from_bytes(bytes, [3], DType::F32);
+
+        let array = data.into_arrow().unwrap();
+        let array = array.as_primitive::<Float32Type>();
+        assert_eq!(array.values().as_ref(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn should_convert_record_batch_columns() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(Int64Array::from(vec![4, 5, 6])),
+            ],
+        )
+        .unwrap();
+
+        let data = TensorData::from_record_batch(&batch).unwrap();
+        data.assert_eq(&TensorData::from([[1i64, 4], [2, 5], [3, 6]]), true);
+    }
+
+    #[test]
+    fn should_round_trip_record_batch() {
+        let data = TensorData::from([[1i32, 2, 3], [4, 5, 6]]);
+        let batch = data.clone().into_record_batch("features").unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).name(), "features");
+
+        let output = TensorData::from_record_batch(&batch).unwrap();
+        output.assert_eq(&data, true);
+    }
+
+    #[test]
+    fn should_reject_nulls() {
+        let array = Float32Array::from(vec![Some(1.0), None]);
+
+        assert!(TensorData::from_arrow(&array).is_err());
+    }
+}
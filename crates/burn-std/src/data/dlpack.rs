@@ -0,0 +1,479 @@
+//! [DLPack](https://dmlc.github.io/dlpack/latest/) interoperability for [`TensorData`].
+//!
+//! DLPack is the in-process tensor exchange protocol used by PyTorch, CuPy, JAX and NumPy.
+//! Exported tensors share the [`TensorData`] buffer with the consumer, and imported tensors
+//! wrap the producer buffer without copying it.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+use crate::{AllocationProperty, BoolStore, Bytes, DType, DataError, Shape, TensorData};
+
+/// The device type of a [`DLTensor`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDeviceType(pub i32);
+
+impl DLDeviceType {
+    /// CPU device.
+    pub const CPU: Self = Self(1);
+    /// CUDA GPU device.
+    pub const CUDA: Self = Self(2);
+    /// Pinned CUDA CPU memory allocated by `cudaMallocHost`.
+    pub const CUDA_HOST: Self = Self(3);
+    /// OpenCL device.
+    pub const OPENCL: Self = Self(4);
+    /// Vulkan buffer.
+    pub const VULKAN: Self = Self(7);
+    /// Metal buffer.
+    pub const METAL: Self = Self(8);
+    /// ROCm GPU device.
+    pub const ROCM: Self = Self(10);
+    /// Pinned ROCm CPU memory allocated by `hipMallocHost`.
+    pub const ROCM_HOST: Self = Self(11);
+    /// CUDA managed/unified memory allocated by `cudaMallocManaged`.
+    pub const CUDA_MANAGED: Self = Self(13);
+    /// WebGPU buffer.
+    pub const WEBGPU: Self = Self(15);
+
+    /// Returns true if the memory of this device type can be read directly from the host.
+    pub fn is_host_accessible(&self) -> bool {
+        matches!(
+            *self,
+            Self::CPU | Self::CUDA_HOST | Self::ROCM_HOST | Self::CUDA_MANAGED
+        )
+    }
+}
+
+/// The device on which a [`DLTensor`] is allocated.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDevice {
+    /// The device type.
+    pub device_type: DLDeviceType,
+    /// The device index, or `0` for the CPU.
+    pub device_id: i32,
+}
+
+impl DLDevice {
+    /// The CPU device.
+    pub const CPU: Self = Self {
+        device_type: DLDeviceType::CPU,
+        device_id: 0,
+    };
+}
+
+/// The type code of a [`DLDataType`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDataTypeCode(pub u8);
+
+impl DLDataTypeCode {
+    /// Signed integer.
+    pub const INT: Self = Self(0);
+    /// Unsigned integer.
+    pub const UINT: Self = Self(1);
+    /// IEEE floating point.
+    pub const FLOAT: Self = Self(2);
+    /// Brain floating point.
+    pub const BFLOAT: Self = Self(4);
+    /// Boolean.
+    pub const BOOL: Self = Self(6);
+}
+
+/// The element type of a [`DLTensor`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDataType {
+    /// The type code.
+    pub code: DLDataTypeCode,
+    /// The number of bits of a single lane.
+    pub bits: u8,
+    /// The number of lanes, `1` for scalar types.
+    pub lanes: u16,
+}
+
+impl DLDataType {
+    const fn scalar(code: DLDataTypeCode, bits: u8) -> Self {
+        Self {
+            code,
+            bits,
+            lanes: 1,
+        }
+    }
+}
+
+impl TryFrom<DType> for DLDataType {
+    type Error = DataError;
+
+    fn try_from(dtype: DType) -> Result<Self, Self::Error> {
+        let (code, bits) = match dtype {
+            DType::F64 => (DLDataTypeCode::FLOAT, 64),
+            DType::F32 | DType::Flex32 => (DLDataTypeCode::FLOAT, 32),
+            DType::F16 => (DLDataTypeCode::FLOAT, 16),
+            DType::BF16 => (DLDataTypeCode::BFLOAT, 16),
+            DType::I64 => (DLDataTypeCode::INT, 64),
+            DType::I32 => (DLDataTypeCode::INT, 32),
+            DType::I16 => (DLDataTypeCode::INT, 16),
+            DType::I8 => (DLDataTypeCode::INT, 8),
+            DType::U64 => (DLDataTypeCode::UINT, 64),
+            DType::U32 => (DLDataTypeCode::UINT, 32),
+            DType::U16 => (DLDataTypeCode::UINT, 16),
+            DType::U8 | DType::Bool(BoolStore::U8) => (DLDataTypeCode::UINT, 8),
+            DType::Bool(BoolStore::U32) => (DLDataTypeCode::UINT, 32),
+            DType::Bool(BoolStore::Native) => (DLDataTypeCode::BOOL, 8),
+            DType::QFloat(_) => {
+                return Err(DataError::TypeMismatch(format!(
+                    "DLPack does not support quantized data type {dtype:?}"
+                )));
+            }
+        };
+
+        Ok(Self::scalar(code, bits))
+    }
+}
+
+impl TryFrom<DLDataType> for DType {
+    type Error = DataError;
+
+    fn try_from(dtype: DLDataType) -> Result<Self, Self::Error> {
+        let unsupported =
+            || DataError::TypeMismatch(format!("Unsupported DLPack data type {dtype:?}"));
+
+        if dtype.lanes != 1 {
+            return Err(unsupported());
+        }
+
+        match (dtype.code, dtype.bits) {
+            (DLDataTypeCode::FLOAT, 64) => Ok(DType::F64),
+            (DLDataTypeCode::FLOAT, 32) => Ok(DType::F32),
+            (DLDataTypeCode::FLOAT, 16) => Ok(DType::F16),
+            (DLDataTypeCode::BFLOAT, 16) => Ok(DType::BF16),
+            (DLDataTypeCode::INT, 64) => Ok(DType::I64),
+            (DLDataTypeCode::INT, 32) => Ok(DType::I32),
+            (DLDataTypeCode::INT, 16) => Ok(DType::I16),
+            (DLDataTypeCode::INT, 8) => Ok(DType::I8),
+            (DLDataTypeCode::UINT, 64) => Ok(DType::U64),
+            (DLDataTypeCode::UINT, 32) => Ok(DType::U32),
+            (DLDataTypeCode::UINT, 16) => Ok(DType::U16),
+            (DLDataTypeCode::UINT, 8) => Ok(DType::U8),
+            (DLDataTypeCode::BOOL, 8) => Ok(DType::Bool(BoolStore::Native)),
+            _ => Err(unsupported()),
+        }
+    }
+}
+
+/// A tensor view following the DLPack `DLTensor` layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    /// Pointer to the (possibly device) memory of the tensor.
+    pub data: *mut c_void,
+    /// The device on which the memory is allocated.
+    pub device: DLDevice,
+    /// The number of dimensions.
+    pub ndim: i32,
+    /// The element type.
+    pub dtype: DLDataType,
+    /// The shape of the tensor, with `ndim` elements.
+    pub shape: *mut i64,
+    /// The strides of the tensor in number of elements, or null for a compact row-major layout.
+    pub strides: *mut i64,
+    /// The offset in bytes from `data` to the first element.
+    pub byte_offset: u64,
+}
+
+impl DLTensor {
+    /// The shape and data type of the tensor, which must have a compact row-major layout.
+    ///
+    /// # Safety
+    ///
+    /// `shape` must point to `ndim` elements, as well as `strides` when it isn't null.
+    pub unsafe fn contiguous_layout(&self) -> Result<(Shape, DType), DataError> {
+        let dtype = DType::try_from(self.dtype)?;
+        let rank = usize::try_from(self.ndim).map_err(|_| {
+            DataError::Unsupported(format!("DLPack tensor with {} dimensions", self.ndim))
+        })?;
+        let dims: &[i64] = match rank {
+            0 => &[],
+            // SAFETY: DLPack guarantees `ndim` elements in `shape`.
+            _ => unsafe { core::slice::from_raw_parts(self.shape, rank) },
+        };
+        let shape = dims
+            .iter()
+            .map(|&dim| usize::try_from(dim))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                DataError::Unsupported(format!("DLPack tensor with negative shape {dims:?}"))
+            })?;
+
+        if !self.strides.is_null() && rank > 0 {
+            // SAFETY: DLPack guarantees `ndim` elements in `strides` when it's not null.
+            let strides = unsafe { core::slice::from_raw_parts(self.strides, rank) };
+            let mut expected = 1;
+            for (&dim, &stride) in dims.iter().zip(strides).rev() {
+                // Strides of size-1 dimensions are irrelevant to the layout.
+                if dim != 1 && stride != expected {
+                    return Err(DataError::Unsupported(format!(
+                        "DLPack tensor with strides {strides:?} isn't contiguous"
+                    )));
+                }
+                expected *= dim;
+            }
+        }
+
+        Ok((Shape::from(shape), dtype))
+    }
+
+    /// The address of the first element, i.e. `data` advanced by `byte_offset`.
+    pub fn first_element(&self) -> *mut u8 {
+        (self.data as *mut u8).wrapping_add(self.byte_offset as usize)
+    }
+}
+
+/// A [`DLTensor`] bundled with the context needed to release it, following the DLPack
+/// `DLManagedTensor` layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    /// The tensor view.
+    pub dl_tensor: DLTensor,
+    /// Opaque context of the producer.
+    pub manager_ctx: *mut c_void,
+    /// Called by the consumer once it no longer needs the tensor.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// Keeps the exported data and its metadata alive until the consumer calls the deleter.
+struct ExportContext {
+    managed: DLManagedTensor,
+    data: TensorData,
+    shape: Vec<i64>,
+}
+
+unsafe extern "C" fn export_deleter(managed: *mut DLManagedTensor) {
+    if managed.is_null() {
+        return;
+    }
+
+    // SAFETY: `manager_ctx` points to the context boxed by `TensorData::into_dlpack`.
+    unsafe {
+        let ctx = (*managed).manager_ctx as *mut ExportContext;
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// Owns an imported DLPack tensor and releases it through its deleter when dropped.
+struct ImportOwner {
+    managed: NonNull<DLManagedTensor>,
+    data: NonNull<u8>,
+    len: usize,
+}
+
+// SAFETY: DLPack producers hand over ownership of the tensor to the consumer, the memory is
+// only read through the shared `bytes::Bytes` buffer and released once.
+unsafe impl Send for ImportOwner {}
+
+impl AsRef<[u8]> for ImportOwner {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: the pointer and length were validated on import and the memory stays alive
+        // until the deleter is called on drop.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for ImportOwner {
+    fn drop(&mut self) {
+        // SAFETY: the producer's deleter is called exactly once.
+        unsafe { release(self.managed) }
+    }
+}
+
+unsafe fn release(managed: NonNull<DLManagedTensor>) {
+    unsafe {
+        if let Some(deleter) = (*managed.as_ptr()).deleter {
+            deleter(managed.as_ptr());
+        }
+    }
+}
+
+impl TensorData {
+    /// Exports the tensor data as a DLPack tensor allocated on the CPU.
+    ///
+    /// No data is copied: the returned tensor points to the bytes of this [`TensorData`], which
+    /// are kept alive until the consumer calls the deleter.
+    ///
+    /// # Errors
+    ///
+    /// If the data type can't be represented with DLPack (e.g., quantized data).
+    pub fn into_dlpack(self) -> Result<NonNull<DLManagedTensor>, DataError> {
+        let dtype = DLDataType::try_from(self.dtype)?;
+        let shape = self.shape.iter().map(|&dim| dim as i64).collect::<Vec<_>>();
+
+        let ctx = Box::new(ExportContext {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data: core::ptr::null_mut(),
+                    device: DLDevice::CPU,
+                    ndim: shape.len() as i32,
+                    dtype,
+                    shape: core::ptr::null_mut(),
+                    strides: core::ptr::null_mut(),
+                    byte_offset: 0,
+                },
+                manager_ctx: core::ptr::null_mut(),
+                deleter: Some(export_deleter),
+            },
+            data: self,
+            shape,
+        });
+
+        let ctx = Box::into_raw(ctx);
+
+        // SAFETY: `ctx` was just allocated and is only released by `export_deleter`. The data
+        // and shape buffers are owned by the context, so their addresses are stable.
+        unsafe {
+            let managed = &mut (*ctx).managed;
+            managed.dl_tensor.data = (*ctx).data.bytes.as_mut_ptr() as *mut c_void;
+            managed.dl_tensor.shape = (*ctx).shape.as_mut_ptr();
+            managed.manager_ctx = ctx as *mut c_void;
+
+            Ok(NonNull::from(managed))
+        }
+    }
+
+    /// Imports a DLPack tensor without copying its memory.
+    ///
+    /// The returned [`TensorData`] takes ownership of the DLPack tensor and calls its deleter
+    /// once the bytes are dropped. On error, the tensor is released immediately.
+    ///
+    /// Only host accessible memory with a compact row-major layout is supported; device
+    /// tensors are imported by their backend, e.g. `burn_cuda::dlpack` for CUDA tensors.
+    ///
+    /// # Safety
+    ///
+    /// `managed` must point to a valid DLPack tensor that is not used by the caller anymore.
+    pub unsafe fn from_dlpack(managed: NonNull<DLManagedTensor>) -> Result<Self, DataError> {
+        // SAFETY: validity of the pointer is guaranteed by the caller.
+        let result = unsafe { Self::validate_dlpack(&managed.as_ref().dl_tensor) };
+
+        let (data, shape, dtype) = match result {
+            Ok(values) => values,
+            Err(err) => {
+                // SAFETY: we own the tensor and it's not used afterward.
+                unsafe { release(managed) };
+                return Err(err);
+            }
+        };
+
+        let len = shape.num_elements() * dtype.size();
+        let owner = ImportOwner { managed, data, len };
+        let bytes = Bytes::from_shared(bytes::Bytes::from_owner(owner), AllocationProperty::Other);
+
+        Ok(Self::from_bytes(bytes, shape, dtype))
+    }
+
+    unsafe fn validate_dlpack(tensor: &DLTensor) -> Result<(NonNull<u8>, Shape, DType), DataError> {
+        if !tensor.device.device_type.is_host_accessible() {
+            return Err(DataError::Unsupported(format!(
+                "DLPack tensor on device {:?} isn't accessible from the host",
+                tensor.device
+            )));
+        }
+
+        // SAFETY: DLPack guarantees the shape and strides are valid for `ndim` elements.
+        let (shape, dtype) = unsafe { tensor.contiguous_layout()? };
+        let data = match NonNull::new(tensor.first_element()) {
+            Some(data) => data,
+            // Empty tensors may not have an allocation.
+            None if shape.num_elements() == 0 => NonNull::dangling(),
+            None => {
+                return Err(DataError::Unsupported(format!(
+                    "DLPack tensor of shape {shape:?} has no data"
+                )));
+            }
+        };
+
+        Ok((data, shape, dtype))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_round_trip_dlpack() {
+        let data = TensorData::new(vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+        let managed = data.clone().into_dlpack().unwrap();
+
+        let tensor = unsafe { &managed.as_ref().dl_tensor };
+        assert_eq!(tensor.ndim, 2);
+        assert_eq!(tensor.dtype, DLDataType::scalar(DLDataTypeCode::FLOAT, 32));
+        assert_eq!(tensor.device, DLDevice::CPU);
+
+        let imported = unsafe { TensorData::from_dlpack(managed) }.unwrap();
+        imported.assert_eq(&data, true);
+    }
+
+    #[test]
+    fn should_share_memory_on_export() {
+        let data = TensorData::new(vec![1i32, 2, 3], [3]);
+        let ptr = data.as_bytes().as_ptr();
+        let managed = data.into_dlpack().unwrap();
+
+        let exported = unsafe { managed.as_ref().dl_tensor.data } as *const u8;
+        assert_eq!(exported, ptr);
+
+        unsafe { release(managed) };
+    }
+
+    #[test]
+    fn should_reject_non_contiguous_dlpack() {
+        let data = TensorData::new(vec![1.0f32, 2.0, 3.0, 4.0], [2, 2]);
+        let managed = data.into_dlpack().unwrap();
+
+        let mut strides = [1i64, 2];
+        unsafe { (*managed.as_ptr()).dl_tensor.strides = strides.as_mut_ptr() };
+
+        let result = unsafe { TensorData::from_dlpack(managed) };
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_negative_dims() {
+        let data = TensorData::new(vec![1.0f32, 2.0], [2]);
+        let managed = data.into_dlpack().unwrap();
+
+        unsafe { *(*managed.as_ptr()).dl_tensor.shape = -2 };
+
+        let result = unsafe { TensorData::from_dlpack(managed) };
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_null_data() {
+        let data = TensorData::new(vec![1.0f32, 2.0], [2]);
+        let managed = data.into_dlpack().unwrap();
+
+        unsafe { (*managed.as_ptr()).dl_tensor.data = core::ptr::null_mut() };
+
+        let result = unsafe { TensorData::from_dlpack(managed) };
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_quantized_dtype() {
+        let data = TensorData::from_bytes_vec(
+            vec![1, 2, 3],
+            [3],
+            DType::QFloat(crate::QuantScheme::default()),
+        );
+
+        assert!(data.into_dlpack().is_err());
+    }
+}
@@ -3,3 +3,15 @@ mod tensor;
 
 pub use compare::*;
 pub use tensor::*;
+
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+
+#[cfg(feature = "ndarray-interop")]
+pub mod ndarray;
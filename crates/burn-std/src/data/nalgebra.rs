@@ -0,0 +1,115 @@
+//! [`nalgebra`](https://docs.rs/nalgebra) interoperability for [`TensorData`].
+//!
+//! Tensor data is stored in row-major order while `nalgebra` matrices are column-major, so
+//! views use explicit strides to borrow the values without copying them.
+
+use alloc::format;
+
+use nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, Dyn, Scalar};
+
+use crate::{DataError, Element, TensorData};
+
+impl TensorData {
+    /// Borrows 2D tensor data as a `nalgebra` matrix view, without copying the values.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor data isn't 2D or if `E` doesn't match its data type.
+    pub fn as_matrix_view<E: Element + Scalar>(
+        &self,
+    ) -> Result<DMatrixView<'_, E, Dyn, Dyn>, DataError> {
+        let [rows, cols] = self.matrix_dims()?;
+        let values = self.as_slice::<E>()?;
+
+        Ok(DMatrixView::from_slice_with_strides(
+            values, rows, cols, cols, 1,
+        ))
+    }
+
+    /// Mutably borrows 2D tensor data as a `nalgebra` matrix view, without copying the values.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor data isn't 2D or if `E` doesn't match its data type.
+    pub fn as_matrix_view_mut<E: Element + Scalar>(
+        &mut self,
+    ) -> Result<DMatrixViewMut<'_, E, Dyn, Dyn>, DataError> {
+        let [rows, cols] = self.matrix_dims()?;
+        let values = self.as_mut_slice::<E>()?;
+
+        Ok(DMatrixViewMut::from_slice_with_strides_mut(
+            values, rows, cols, cols, 1,
+        ))
+    }
+
+    fn matrix_dims(&self) -> Result<[usize; 2], DataError> {
+        match self.shape.as_slice() {
+            &[rows, cols] => Ok([rows, cols]),
+            dims => Err(DataError::Unsupported(format!(
+                "Only 2D tensor data can be viewed as a matrix, got shape {dims:?}"
+            ))),
+        }
+    }
+}
+
+impl<E: Element + Scalar> From<DMatrix<E>> for TensorData {
+    /// Converts a `nalgebra` matrix into 2D tensor data.
+    ///
+    /// The values are copied to convert the column-major matrix into row-major tensor data.
+    fn from(matrix: DMatrix<E>) -> Self {
+        let (rows, cols) = matrix.shape();
+        let values = matrix.transpose().as_slice().to_vec();
+
+        TensorData::new(values, [rows, cols])
+    }
+}
+
+impl<E: Element + Scalar> TryFrom<TensorData> for DMatrix<E> {
+    type Error = DataError;
+
+    /// Converts 2D tensor data into a `nalgebra` matrix.
+    fn try_from(data: TensorData) -> Result<Self, Self::Error> {
+        let [rows, cols] = data.matrix_dims()?;
+
+        Ok(DMatrix::from_row_slice(rows, cols, data.as_slice::<E>()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_borrow_as_matrix_view() {
+        let data = TensorData::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let view = data.as_matrix_view::<f32>().unwrap();
+
+        assert_eq!(view.shape(), (2, 3));
+        assert_eq!(view[(0, 2)], 3.0);
+        assert_eq!(view[(1, 0)], 4.0);
+    }
+
+    #[test]
+    fn should_mutate_through_matrix_view() {
+        let mut data = TensorData::from([[1.0f64, 2.0], [3.0, 4.0]]);
+        data.as_matrix_view_mut::<f64>().unwrap()[(1, 0)] = 10.0;
+
+        data.assert_eq(&TensorData::from([[1.0f64, 2.0], [10.0, 4.0]]), true);
+    }
+
+    #[test]
+    fn should_reject_non_matrix_view() {
+        let data = TensorData::from([1.0f32, 2.0]);
+
+        assert!(data.as_matrix_view::<f32>().is_err());
+    }
+
+    #[test]
+    fn should_round_trip_matrix() {
+        let matrix = DMatrix::from_row_slice(2, 3, &[1i32, 2, 3, 4, 5, 6]);
+        let data = TensorData::from(matrix.clone());
+
+        data.assert_eq(&TensorData::from([[1i32, 2, 3], [4, 5, 6]]), true);
+        assert_eq!(DMatrix::<i32>::try_from(data).unwrap(), matrix);
+    }
+}
@@ -0,0 +1,147 @@
+//! [`ndarray`](https://docs.rs/ndarray) interoperability for [`TensorData`].
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use ndarray::{Array, ArrayD, ArrayView, ArrayViewD, ArrayViewMutD, Dimension, IxDyn};
+
+use crate::{DataError, Element, TensorData};
+
+impl TensorData {
+    /// Borrows the tensor data as an `ndarray` view, without copying the values.
+    ///
+    /// # Errors
+    ///
+    /// If `E` doesn't match the data type of the tensor data.
+    pub fn as_array_view<E: Element>(&self) -> Result<ArrayViewD<'_, E>, DataError> {
+        let values = self.as_slice::<E>()?;
+
+        ArrayViewD::from_shape(IxDyn(self.shape.as_slice()), values)
+            .map_err(|err| DataError::Unsupported(format!("{err}")))
+    }
+
+    /// Mutably borrows the tensor data as an `ndarray` view, without copying the values.
+    ///
+    /// # Errors
+    ///
+    /// If `E` doesn't match the data type of the tensor data.
+    pub fn as_array_view_mut<E: Element>(&mut self) -> Result<ArrayViewMutD<'_, E>, DataError> {
+        let shape = IxDyn(self.shape.as_slice());
+        let values = self.as_mut_slice::<E>()?;
+
+        ArrayViewMutD::from_shape(shape, values)
+            .map_err(|err| DataError::Unsupported(format!("{err}")))
+    }
+}
+
+impl<E: Element, D: Dimension> From<Array<E, D>> for TensorData {
+    /// Converts an owned `ndarray` array into tensor data.
+    ///
+    /// Arrays in standard (row-major) layout reuse their allocation, other arrays are copied.
+    fn from(array: Array<E, D>) -> Self {
+        let shape = array.shape().to_vec();
+
+        if !array.is_standard_layout() {
+            let values = array.iter().copied().collect::<Vec<_>>();
+            return TensorData::new(values, shape);
+        }
+
+        let num_elements = array.len();
+        let (mut values, offset) = array.into_raw_vec_and_offset();
+        let offset = offset.unwrap_or(0);
+
+        if offset != 0 || values.len() != num_elements {
+            values = values[offset..offset + num_elements].to_vec();
+        }
+
+        TensorData::new(values, shape)
+    }
+}
+
+impl<E: Element, D: Dimension> From<ArrayView<'_, E, D>> for TensorData {
+    /// Copies the values of an `ndarray` view into tensor data.
+    fn from(view: ArrayView<'_, E, D>) -> Self {
+        let shape = view.shape().to_vec();
+        let values = view.iter().copied().collect::<Vec<_>>();
+
+        TensorData::new(values, shape)
+    }
+}
+
+impl<E: Element> TryFrom<TensorData> for ArrayD<E> {
+    type Error = DataError;
+
+    /// Converts tensor data into an owned `ndarray` array, reusing the allocation when possible.
+    fn try_from(data: TensorData) -> Result<Self, Self::Error> {
+        let shape = data.shape.as_slice().to_vec();
+        let values = data.into_vec::<E>()?;
+
+        ArrayD::from_shape_vec(IxDyn(&shape), values)
+            .map_err(|err| DataError::Unsupported(format!("{err}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{Array2, s};
+
+    #[test]
+    fn should_borrow_as_array_view() {
+        let data = TensorData::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let view = data.as_array_view::<f32>().unwrap();
+
+        assert_eq!(view.shape(), &[2, 3]);
+        assert_eq!(view[[1, 2]], 6.0);
+        assert_eq!(view.as_ptr(), data.as_slice::<f32>().unwrap().as_ptr());
+    }
+
+    #[test]
+    fn should_mutate_through_array_view() {
+        let mut data = TensorData::from([[1i32, 2], [3, 4]]);
+        data.as_array_view_mut::<i32>().unwrap()[[0, 1]] = 10;
+
+        data.assert_eq(&TensorData::from([[1i32, 10], [3, 4]]), true);
+    }
+
+    #[test]
+    fn should_fail_view_with_wrong_element_type() {
+        let data = TensorData::from([1.0f32, 2.0]);
+
+        assert!(data.as_array_view::<i64>().is_err());
+    }
+
+    #[test]
+    fn should_convert_from_array() {
+        let array = Array2::from_shape_vec((2, 2), vec![1.0f64, 2.0, 3.0, 4.0]).unwrap();
+        let data = TensorData::from(array);
+
+        data.assert_eq(&TensorData::from([[1.0f64, 2.0], [3.0, 4.0]]), true);
+    }
+
+    #[test]
+    fn should_convert_from_transposed_array() {
+        let array = Array2::from_shape_vec((2, 2), vec![1i64, 2, 3, 4]).unwrap();
+        let data = TensorData::from(array.reversed_axes());
+
+        data.assert_eq(&TensorData::from([[1i64, 3], [2, 4]]), true);
+    }
+
+    #[test]
+    fn should_convert_from_sliced_view() {
+        let array = Array2::from_shape_vec((2, 3), vec![1i32, 2, 3, 4, 5, 6]).unwrap();
+        let data = TensorData::from(array.slice(s![.., 1..]));
+
+        data.assert_eq(&TensorData::from([[2i32, 3], [5, 6]]), true);
+    }
+
+    #[test]
+    fn should_convert_into_array() {
+        let data = TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        let array = ArrayD::<f32>::try_from(data).unwrap();
+
+        assert_eq!(array.shape(), &[2, 2]);
+        assert_eq!(array[[1, 0]], 3.0);
+    }
+}
@@ -749,6 +749,9 @@ pub enum DataError {
     /// Invalid target element type.
     #[error("{0}")]
     TypeMismatch(String),
+    /// The memory layout or location of the data is not supported.
+    #[error("{0}")]
+    Unsupported(String),
 }
 
 #[cfg(test)]
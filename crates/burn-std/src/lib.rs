@@ -41,6 +41,10 @@ pub use ops::*;
 /// Burn runtime configurations.
 pub mod config;
 
+/// Budgets for autotuning kernels.
+#[cfg(all(feature = "cubecl", feature = "std"))]
+pub mod autotune;
+
 /// Common Errors.
 pub use cubecl_zspace::errors::{self, *};
 
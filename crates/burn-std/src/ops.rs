@@ -226,6 +226,11 @@ pub enum InterpolateMode {
     /// Lanczos3 interpolation (6-tap sinc-based filter).
     /// <https://en.wikipedia.org/wiki/Lanczos_resampling>
     Lanczos3,
+
+    /// Area interpolation, which averages the input pixels covered by each output pixel.
+    /// Matches PyTorch's `area` mode, which is an adaptive average pooling, and ignores
+    /// `align_corners`.
+    Area,
 }
 
 /// Interpolation options.
@@ -236,15 +241,19 @@ pub struct InterpolateOptions {
     /// If `true`, the input and output tensors are aligned by their corner pixels.
     /// If `false`, half-pixel coordinate mapping is used instead.
     pub align_corners: bool,
+    /// If `true`, the filter of the bilinear and bicubic modes is widened by the downscaling
+    /// factor, so that all the input pixels contribute to the output like PyTorch's `antialias`.
+    pub antialias: bool,
 }
 
 impl InterpolateOptions {
     /// Create new interpolate options with the given mode.
-    /// Defaults to `align_corners = true`.
+    /// Defaults to `align_corners = true` and `antialias = false`.
     pub fn new(mode: InterpolateMode) -> Self {
         Self {
             mode,
             align_corners: true,
+            antialias: false,
         }
     }
 
@@ -253,6 +262,12 @@ impl InterpolateOptions {
         self.align_corners = align_corners;
         self
     }
+
+    /// Set antialias.
+    pub fn with_antialias(mut self, antialias: bool) -> Self {
+        self.antialias = antialias;
+        self
+    }
 }
 
 /// Padding mode for grid sampling when coordinates are out of bounds.
@@ -332,6 +347,7 @@ impl GridSampleOptions {
 /// - [`Constant`](PadMode::Constant): Fill with a specified value (default: 0.0)
 /// - [`Reflect`](PadMode::Reflect): Mirror values at boundary, excluding edge (requires padding < dim_size)
 /// - [`Edge`](PadMode::Edge): Replicate boundary values
+/// - [`Circular`](PadMode::Circular): Wrap values around from the opposite boundary (requires padding <= dim_size)
 #[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum PadMode {
     /// Fill padded regions with a constant value.
@@ -352,10 +368,21 @@ pub enum PadMode {
 
     /// Replicate the edge values.
     ///
+    /// Also known as "replicate" padding.
+    ///
     /// # Example
     /// For tensor `[1, 2, 3, 4]` with padding 2 on the left:
     /// Result: `[1, 1, 1, 2, 3, 4]`
     Edge,
+
+    /// Wrap values around, as if the tensor was periodic.
+    ///
+    /// Padding must not exceed the dimension size (i.e., `padding <= dim_size`).
+    ///
+    /// # Example
+    /// For tensor `[1, 2, 3, 4]` with padding 2 on the left:
+    /// Result: `[3, 4, 1, 2, 3, 4]`
+    Circular,
 }
 
 impl Default for PadMode {
@@ -370,6 +397,32 @@ impl<E: ElementConversion> From<E> for PadMode {
     }
 }
 
+/// Interpolation method used when the requested quantile lies between two data points.
+///
+/// With `i` and `j` the indices of the two closest sorted values surrounding the quantile
+/// position, and `fraction` the fractional part of that position:
+///
+/// - [`Linear`](QuantileInterpolation::Linear): `values[i] + (values[j] - values[i]) * fraction`
+/// - [`Lower`](QuantileInterpolation::Lower): `values[i]`
+/// - [`Higher`](QuantileInterpolation::Higher): `values[j]`
+/// - [`Nearest`](QuantileInterpolation::Nearest): `values[i]` or `values[j]`, whichever is
+///   closest (ties are rounded to the even index)
+/// - [`Midpoint`](QuantileInterpolation::Midpoint): `(values[i] + values[j]) / 2`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum QuantileInterpolation {
+    /// Linear interpolation between the two closest values.
+    #[default]
+    Linear,
+    /// The lower of the two closest values.
+    Lower,
+    /// The higher of the two closest values.
+    Higher,
+    /// The closest value.
+    Nearest,
+    /// The average of the two closest values.
+    Midpoint,
+}
+
 /// Options for the attention module.
 #[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct AttentionModuleOptions {
@@ -385,6 +438,13 @@ pub struct AttentionModuleOptions {
     /// passing an explicit lower-triangular bool mask because backends can use optimized
     /// kernel paths (e.g. flash attention with causal mode).
     pub is_causal: bool,
+
+    /// When set, each query position only attends to the key positions less than
+    /// `sliding_window` positions away from it (local attention). The positions are aligned on
+    /// the last query and key like the causal mask, so combined with `is_causal` each query
+    /// attends to the `sliding_window` most recent keys, as in Mistral. Backends apply it while
+    /// computing the scores when they can, instead of materializing a full bool mask.
+    pub sliding_window: Option<usize>,
 }
 
 /// Computation to be used to update the existing values in indexed assignment operations (scatter/select).
@@ -400,6 +460,10 @@ pub enum IndexingUpdateOp {
     Min,
     /// Take element-wise maximum.
     Max,
+    /// Take the mean of the existing value and of the values written to the same position.
+    ///
+    /// The mean of integers is rounded like an integer division.
+    Mean,
 }
 
 #[cfg(test)]
@@ -457,6 +457,82 @@ impl Slice {
         }
     }
 
+    /// Creates a slice from Python-style `start:stop:step` bounds.
+    ///
+    /// Unlike the [`s!`] macro, where the range selects the elements and a negative step only
+    /// reverses their order, a negative Python step iterates from `start` (inclusive) down to
+    /// `stop` (exclusive), and missing bounds default to the ends of the axis in the direction
+    /// of the step.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // x[5:1:-1] selects [5, 4, 3, 2], like s![2..6;-1].
+    /// assert_eq!(Slice::python(Some(5), Some(1), -1), Slice::new(2, Some(6), -1));
+    /// // x[::-1] reverses the axis, like s![..;-1].
+    /// assert_eq!(Slice::python(None, None, -1), Slice::new(0, None, -1));
+    /// ```
+    pub fn python(start: Option<isize>, stop: Option<isize>, step: isize) -> Self {
+        assert!(step != 0, "Step cannot be zero");
+        if step > 0 {
+            return Self::new(start.unwrap_or(0), stop, step);
+        }
+
+        // Iterating down from `start` to `stop` selects the range `stop + 1..=start`.
+        let end = start.and_then(handle_signed_inclusive_end);
+        let start = match stop {
+            None => 0,
+            // Stopping before the last element selects nothing.
+            Some(-1) => return Self::new(0, Some(0), step),
+            Some(stop) => stop + 1,
+        };
+        Self::new(start, end, step)
+    }
+
+    /// Parses a Python-style slice, such as `1:-1`, `::2` or `::-1`, or a single index.
+    ///
+    /// See [python](Slice::python) for the semantics of negative steps.
+    pub fn parse_python(source: &str) -> Result<Self, crate::ExpressionError> {
+        let parse_bound = |v: &str| -> Result<Option<isize>, crate::ExpressionError> {
+            let v = v.trim();
+            if v.is_empty() {
+                return Ok(None);
+            }
+            v.parse::<isize>().map(Some).map_err(|e| {
+                crate::ExpressionError::parse_error(
+                    format!("Invalid integer: '{v}': {}", e),
+                    source,
+                )
+            })
+        };
+
+        let parts: Vec<&str> = source.split(':').collect();
+        match parts.as_slice() {
+            [index] => match parse_bound(index)? {
+                Some(index) => Ok(Slice::index(index)),
+                None => Err(crate::ExpressionError::parse_error(
+                    "Empty expression",
+                    source,
+                )),
+            },
+            [start, stop] => Ok(Slice::python(parse_bound(start)?, parse_bound(stop)?, 1)),
+            [start, stop, step] => {
+                let step = parse_bound(step)?.unwrap_or(1);
+                if step == 0 {
+                    return Err(crate::ExpressionError::invalid_expression(
+                        "Step cannot be zero",
+                        source,
+                    ));
+                }
+                Ok(Slice::python(parse_bound(start)?, parse_bound(stop)?, step))
+            }
+            _ => Err(crate::ExpressionError::parse_error(
+                "Too many ':' separators",
+                source,
+            )),
+        }
+    }
+
     /// Creates a slice with a custom step
     pub fn with_step(start: isize, end: Option<isize>, step: isize) -> Self {
         assert!(step != 0, "Step cannot be zero");
@@ -773,6 +849,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slice_python() {
+        let select = |slice: Slice, size: usize| -> Vec<isize> {
+            let (range, step) = slice.to_range_and_step(size);
+            let mut indices: Vec<isize> = (range.start as isize..range.end as isize).collect();
+            if step < 0 {
+                indices.reverse();
+            }
+            indices.into_iter().step_by(step.unsigned_abs()).collect()
+        };
+
+        // Expected values from Python: `list(range(6))[start:stop:step]`.
+        assert_eq!(select(Slice::python(None, None, 2), 6), vec![0, 2, 4]);
+        assert_eq!(
+            select(Slice::python(Some(-4), Some(-1), 1), 6),
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            select(Slice::python(None, None, -1), 6),
+            vec![5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(select(Slice::python(Some(4), Some(1), -2), 6), vec![4, 2]);
+        assert_eq!(select(Slice::python(None, Some(0), -2), 6), vec![5, 3, 1]);
+        assert_eq!(select(Slice::python(Some(-2), None, -3), 6), vec![4, 1]);
+        assert_eq!(select(Slice::python(Some(-1), Some(-3), -1), 6), vec![5, 4]);
+        assert_eq!(select(Slice::python(Some(3), Some(-1), -1), 6), vec![]);
+        assert_eq!(
+            select(Slice::python(Some(10), Some(-10), -4), 6),
+            vec![5, 1]
+        );
+    }
+
+    #[test]
+    fn test_slice_parse_python() {
+        assert_eq!(Slice::parse_python("1"), Ok(Slice::new(1, Some(2), 1)));
+        assert_eq!(Slice::parse_python(":"), Ok(Slice::new(0, None, 1)));
+        assert_eq!(Slice::parse_python("1:-1"), Ok(Slice::new(1, Some(-1), 1)));
+        assert_eq!(Slice::parse_python("::2"), Ok(Slice::new(0, None, 2)));
+        assert_eq!(Slice::parse_python("::-1"), Ok(Slice::new(0, None, -1)));
+        assert_eq!(
+            Slice::parse_python("5:1:-2"),
+            Ok(Slice::new(2, Some(6), -2))
+        );
+
+        assert_eq!(
+            Slice::parse_python("::0"),
+            Err(crate::ExpressionError::invalid_expression(
+                "Step cannot be zero",
+                "::0"
+            ))
+        );
+        assert_eq!(
+            Slice::parse_python("1:2:3:4"),
+            Err(crate::ExpressionError::parse_error(
+                "Too many ':' separators",
+                "1:2:3:4"
+            ))
+        );
+    }
+
     #[test]
     fn test_slice_output_size() {
         // Test the output_size method directly
@@ -47,6 +47,11 @@ impl<E: TchElement> ActivationOps<Self> for LibTorch<E> {
         TchTensor::from_existing(tensor, storage)
     }
 
+    fn logsumexp(tensor: TchTensor, dim: usize) -> TchTensor {
+        let tensor = tensor.tensor.logsumexp([dim as i64].as_slice(), true);
+        TchTensor::new(tensor)
+    }
+
     fn softmin(tensor: TchTensor, dim: usize) -> TchTensor {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.neg().softmax(dim as i64, None);
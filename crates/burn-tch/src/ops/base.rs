@@ -293,6 +293,9 @@ impl TchOps {
             IndexingUpdateOp::Mul => flat_data.scatter_reduce(0, &linear_idx, &flat_values, "prod"),
             IndexingUpdateOp::Min => flat_data.scatter_reduce(0, &linear_idx, &flat_values, "amin"),
             IndexingUpdateOp::Max => flat_data.scatter_reduce(0, &linear_idx, &flat_values, "amax"),
+            IndexingUpdateOp::Mean => {
+                flat_data.scatter_reduce(0, &linear_idx, &flat_values, "mean")
+            }
         };
 
         let storage = tensor.storage.clone();
@@ -667,6 +670,14 @@ impl TchOps {
         TchTensor::new(tensor)
     }
 
+    pub fn roll(tensor: TchTensor, shifts: &[usize], dims: &[usize]) -> TchTensor {
+        // Torch rolls towards higher indices, while the shifts move elements towards lower ones.
+        let shifts = shifts.iter().map(|x| -(*x as i64)).collect::<Vec<_>>();
+        let dims = dims.iter().map(|x| *x as i64).collect::<Vec<_>>();
+        let tensor = tensor.tensor.roll(shifts, dims);
+        TchTensor::new(tensor)
+    }
+
     pub fn pow(tensor: TchTensor, exponent: TchTensor) -> TchTensor {
         TchTensor::binary_ops_tensor(
             tensor,
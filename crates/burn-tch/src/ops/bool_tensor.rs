@@ -139,6 +139,10 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         TchOps::flip(tensor, axes)
     }
 
+    fn bool_roll(tensor: TchTensor, shifts: &[usize], dims: &[usize]) -> TchTensor {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     async fn bool_argwhere(tensor: TchTensor, out_dtype: IntDType) -> TchTensor {
         TchTensor::new(tensor.tensor.argwhere().to_kind(out_dtype.into_kind()))
     }
@@ -3,7 +3,7 @@ use std::ops::Range;
 use burn_backend::{
     BoolDType, Distribution, ExecutionError, FloatDType, IntDType, Scalar, Shape, TensorData,
     TensorMetadata,
-    ops::{FloatTensorOps, IntTensorOps},
+    ops::{FloatTensorOps, IntTensorOps, float_random_derived},
     tensor::IntTensor,
 };
 
@@ -426,6 +426,10 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
                 let mut tensor = TchTensor::empty(shape, *device, dtype.into());
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => Self::float_into_int(
+                float_random_derived::<Self>(shape, distribution, device, FloatDType::F32),
+                dtype,
+            ),
         }
     }
 
@@ -448,6 +452,10 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::flip(tensor, axes)
     }
 
+    fn int_roll(tensor: IntTensor<Self>, shifts: &[usize], dims: &[usize]) -> IntTensor<Self> {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn int_sign(tensor: IntTensor<Self>) -> IntTensor<Self> {
         TchOps::sign(tensor)
     }
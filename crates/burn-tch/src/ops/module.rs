@@ -379,6 +379,14 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn pixel_shuffle(x: TchTensor, upscale_factor: usize) -> TchTensor {
+        TchTensor::new(x.tensor.pixel_shuffle(upscale_factor as i64))
+    }
+
+    fn pixel_unshuffle(x: TchTensor, downscale_factor: usize) -> TchTensor {
+        TchTensor::new(x.tensor.pixel_unshuffle(downscale_factor as i64))
+    }
+
     fn adaptive_avg_pool1d(x: TchTensor, output_size: usize) -> TchTensor {
         let tensor = tch::Tensor::adaptive_avg_pool1d(&x.tensor, output_size as i64);
 
@@ -394,6 +402,27 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
 
         let align_corners = options.align_corners;
         let tensor = match options.mode {
+            InterpolateMode::Bilinear if options.antialias => {
+                tch::Tensor::internal_upsample_bilinear2d_aa(
+                    &x.tensor,
+                    output_size,
+                    align_corners,
+                    None,
+                    None,
+                )
+            }
+            InterpolateMode::Bicubic if options.antialias => {
+                tch::Tensor::internal_upsample_bicubic2d_aa(
+                    &x.tensor,
+                    output_size,
+                    align_corners,
+                    None,
+                    None,
+                )
+            }
+            _ if options.antialias => {
+                panic!("Antialiasing is only supported by the bilinear and bicubic modes")
+            }
             InterpolateMode::Nearest => {
                 tch::Tensor::upsample_nearest2d(&x.tensor, output_size, None, None)
             }
@@ -409,6 +438,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             InterpolateMode::Lanczos3 => {
                 panic!("lanczos3 interpolation is not supported by PyTorch/tch backend")
             }
+            InterpolateMode::Area => tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size),
         };
 
         TchTensor::new(tensor)
@@ -426,6 +456,29 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         let align_corners = options.align_corners;
 
         let tensor = match options.mode {
+            InterpolateMode::Bilinear if options.antialias => {
+                tch::Tensor::internal_upsample_bilinear2d_aa_backward(
+                    &grad.tensor,
+                    output_size,
+                    input_size,
+                    align_corners,
+                    None,
+                    None,
+                )
+            }
+            InterpolateMode::Bicubic if options.antialias => {
+                tch::Tensor::internal_upsample_bicubic2d_aa_backward(
+                    &grad.tensor,
+                    output_size,
+                    input_size,
+                    align_corners,
+                    None,
+                    None,
+                )
+            }
+            _ if options.antialias => {
+                panic!("Antialiasing is only supported by the bilinear and bicubic modes")
+            }
             InterpolateMode::Nearest => tch::Tensor::upsample_nearest2d_backward(
                 &grad.tensor,
                 output_size,
@@ -457,6 +510,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             InterpolateMode::Lanczos3 => {
                 panic!("lanczos3 interpolation backward is not supported by PyTorch/tch backend")
             }
+            InterpolateMode::Area => Self::adaptive_avg_pool2d_backward(x, grad).tensor,
         };
 
         TchTensor::new(tensor)
@@ -470,7 +524,8 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         attn_bias: Option<TchTensor>,
         options: AttentionModuleOptions,
     ) -> TchTensor {
-        if attn_bias.is_some() {
+        // Torch's fused attention doesn't support additive biases nor sliding windows.
+        if attn_bias.is_some() || options.sliding_window.is_some() {
             return attention_fallback::<Self>(query, key, value, mask, attn_bias, options);
         }
 
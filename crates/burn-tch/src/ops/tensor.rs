@@ -4,7 +4,8 @@ use burn_backend::backend::ExecutionError;
 use burn_backend::tensor::{BoolTensor, FloatTensor, IntTensor};
 use burn_backend::{BoolDType, IntDType, Scalar, bf16, f16};
 use burn_backend::{
-    DType, Distribution, FloatDType, Shape, TensorData, TensorMetadata, ops::FloatTensorOps,
+    DType, Distribution, FloatDType, Shape, TensorData, TensorMetadata,
+    ops::{FloatTensorOps, float_random_derived},
 };
 
 impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
@@ -45,6 +46,7 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
                 let mut tensor = TchTensor::empty(shape, *device, dtype.into());
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => float_random_derived::<Self>(shape, distribution, device, dtype),
         }
     }
 
@@ -517,6 +519,10 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         TchOps::flip(tensor, axes)
     }
 
+    fn float_roll(tensor: TchTensor, shifts: &[usize], dims: &[usize]) -> TchTensor {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn float_sign(tensor: TchTensor) -> TchTensor {
         TchOps::sign(tensor)
     }
@@ -188,6 +188,20 @@ pub(crate) trait BasicOps: TensorKind {
     /// The tensor with the axes flipped.
     fn flip(tensor: BridgeTensor, axes: &[usize]) -> BridgeTensor;
 
+    /// Rolls the elements of the tensor along the given dimensions, wrapping around.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift of each rolled dimension, in `1..size`.
+    /// * `dims` - The dimensions to roll, without repeats.
+    ///
+    /// # Returns
+    ///
+    /// The tensor where the element at index `i` of each rolled dimension is the element at
+    /// index `(i + shift) % size` of the input.
+    fn roll(tensor: BridgeTensor, shifts: &[usize], dims: &[usize]) -> BridgeTensor;
+
     ///  Select tensor elements corresponding to the given slices.
     ///
     /// # Arguments
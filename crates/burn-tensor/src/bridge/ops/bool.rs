@@ -217,6 +217,10 @@ impl BasicOps for Bool {
         BridgeTensor::Bool(Dispatch::bool_flip(tensor.into(), axes))
     }
 
+    fn roll(tensor: BridgeTensor, shifts: &[usize], dims: &[usize]) -> BridgeTensor {
+        BridgeTensor::Bool(Dispatch::bool_roll(tensor.into(), shifts, dims))
+    }
+
     fn unfold(tensor: BridgeTensor, dim: usize, size: usize, step: usize) -> BridgeTensor {
         BridgeTensor::Bool(Dispatch::bool_unfold(tensor.into(), dim, size, step))
     }
@@ -349,6 +349,10 @@ impl BasicOps for Float {
         }
     }
 
+    fn roll(tensor: BridgeTensor, shifts: &[usize], dims: &[usize]) -> BridgeTensor {
+        BridgeTensor::Float(Dispatch::float_roll(tensor.into_float(), shifts, dims))
+    }
+
     fn unfold(tensor: BridgeTensor, dim: usize, size: usize, step: usize) -> BridgeTensor {
         BridgeTensor::Float(Dispatch::float_unfold(tensor.into_float(), dim, size, step))
     }
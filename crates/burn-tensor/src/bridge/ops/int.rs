@@ -234,6 +234,10 @@ impl BasicOps for Int {
         BridgeTensor::Int(Dispatch::int_flip(tensor.into(), axes))
     }
 
+    fn roll(tensor: BridgeTensor, shifts: &[usize], dims: &[usize]) -> BridgeTensor {
+        BridgeTensor::Int(Dispatch::int_roll(tensor.into(), shifts, dims))
+    }
+
     fn unfold(tensor: BridgeTensor, dim: usize, size: usize, step: usize) -> BridgeTensor {
         BridgeTensor::Int(Dispatch::int_unfold(tensor.into(), dim, size, step))
     }
@@ -1,16 +1,21 @@
+pub use burn_backend::{
+    DTypeUsage, DTypeUsageSet, DeviceProperties, MemoryQuota, MemoryQuotaError,
+};
 pub use burn_dispatch::devices::*;
 pub use burn_std::{
     DeviceError, DeviceSettings, ExecutionError, backtrace::BackTrace, device::DeviceId,
 };
 
-use burn_backend::Backend;
+use burn_backend::{Backend, TensorData};
 #[allow(unused)]
 use burn_dispatch::DispatchDeviceId;
 use burn_dispatch::{Dispatch, DispatchDevice};
+use burn_std::DType;
 use burn_std::FloatDType;
 use burn_std::IntDType;
 use burn_std::QuantScheme;
 
+use alloc::string::String;
 use alloc::vec::Vec;
 use enumset::EnumSet;
 use enumset::EnumSetType;
@@ -201,6 +206,15 @@ impl Device {
         Dispatch::sync(&self.dispatch)
     }
 
+    /// Marks the given data as staging buffers for transfers to this device.
+    ///
+    /// The data may be moved to pinned host memory, which speeds up the transfers and allows
+    /// them to overlap with compute on accelerators. Data loaders can stage their batches
+    /// before creating tensors from them.
+    pub fn staging<'a>(&self, data: impl Iterator<Item = &'a mut TensorData>) {
+        Dispatch::staging(data, &self.dispatch)
+    }
+
     /// Seeds the random number generator for this device.
     ///
     /// Seeding before tensor operations that involve randomness (e.g. [`Tensor::random`](crate::Tensor::random))
@@ -256,6 +270,71 @@ impl Device {
         Dispatch::memory_persistent_allocations(&self.dispatch, input, func)
     }
 
+    /// Runs the function with the tensors it allocates on this device charged to the
+    /// [quota](MemoryQuota).
+    ///
+    /// Tensor operations can't fail, so the function runs to completion even when an allocation
+    /// doesn't fit in the quota; its output is then dropped and a [MemoryQuotaError] is returned,
+    /// so that a request can't hold more memory than the quota of its model. Only the CubeCL
+    /// backends, with or without fusion, track their allocations; the others run the function
+    /// without a limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let quota = MemoryQuota::new(512 * 1024 * 1024);
+    ///
+    /// match device.memory_quota(&quota, || model.forward(input).into_data()) {
+    ///     Ok(output) => respond(output),
+    ///     Err(error) => reject(error),
+    /// }
+    /// ```
+    pub fn memory_quota<Output, Func: FnOnce() -> Output>(
+        &self,
+        quota: &MemoryQuota,
+        func: Func,
+    ) -> Result<Output, MemoryQuotaError> {
+        Dispatch::memory_quota(&self.dispatch, quota, func)
+    }
+
+    /// Returns the name of the backend running this device.
+    pub fn backend_name(&self) -> String {
+        Dispatch::name(&self.dispatch)
+    }
+
+    /// Returns the hardware [properties](DeviceProperties) of this device, such as its maximum
+    /// allocation size and its plane (subgroup) size.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let device = Device::enumerate(DeviceType::Cuda)
+    ///     .into_iter()
+    ///     .max_by_key(|device| device.properties().max_allocation_size)
+    ///     .unwrap_or_default();
+    /// ```
+    pub fn properties(&self) -> DeviceProperties {
+        Dispatch::device_properties(&self.dispatch)
+    }
+
+    /// Returns `true` if the data type is supported for general tensor operations on this device.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let device = Default::default();
+    /// let dtype = if device.supports_dtype(DType::BF16) { DType::BF16 } else { DType::F32 };
+    /// ```
+    pub fn supports_dtype(&self, dtype: impl Into<DType>) -> bool {
+        Dispatch::supports_dtype(&self.dispatch, dtype.into())
+    }
+
+    /// Returns how the data type can be used on this device, including whether it is supported by
+    /// hardware-accelerated paths such as tensor cores.
+    pub fn dtype_usage(&self, dtype: impl Into<DType>) -> DTypeUsageSet {
+        Dispatch::dtype_usage(&self.dispatch, dtype.into())
+    }
+
     /// Returns the [`DeviceSettings`] for this device.
     ///
     /// Settings include the default float and integer data types used when creating
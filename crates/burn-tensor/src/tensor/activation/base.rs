@@ -3,7 +3,7 @@ use burn_dispatch::Dispatch;
 
 use crate::check::TensorCheck;
 use crate::ops::BridgeTensor;
-use crate::{Tensor, check, s};
+use crate::{Distribution, IndexingUpdateOp, Tensor, check, s};
 
 /// Applies the rectified linear unit function element-wise
 /// as described in the paper [Deep Learning using Rectified Linear Units (ReLU)](https://arxiv.org/pdf/1803.08375).
@@ -286,6 +286,176 @@ pub fn log_softmax<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
     )))
 }
 
+/// Computes the logarithm of the sum of the exponentials of the input tensor along the given
+/// dimensions.
+///
+#[cfg_attr(
+    doc,
+    doc = r#"
+$$
+\text{logsumexp}\(x\) = \log\left(\sum_i \exp\(x_i\)\right)
+$$
+"#
+)]
+#[cfg_attr(not(doc), doc = "`logsumexp(x) = log(sum_i(exp(x_i)))`")]
+///
+/// The reduction is numerically stable: it doesn't overflow for large inputs, and a slice of
+/// `-inf` values reduces to `-inf`. The dimensions are flattened into a single one before the
+/// reduction, so that the backend reduces them all in one pass.
+///
+/// # Arguments
+/// - `dims`: the dimensions to reduce, which are kept with a size of 1.
+///
+/// # Panics
+/// - If any of `dims` is outside [0, D)
+pub fn logsumexp<const D: usize>(tensor: Tensor<D>, dims: &[usize]) -> Tensor<D> {
+    let mut reduced = [false; D];
+    for &dim in dims {
+        check!(TensorCheck::dim_ops::<D>("logsumexp", dim));
+        reduced[dim] = true;
+    }
+    let num_kept = reduced.iter().filter(|&&reduced| !reduced).count();
+    if num_kept == D {
+        return tensor;
+    }
+    if num_kept == D - 1 {
+        let dim = reduced.iter().position(|&reduced| reduced).unwrap();
+        return Tensor::new(BridgeTensor::Float(Dispatch::logsumexp(
+            tensor.primitive.into_float(),
+            dim,
+        )));
+    }
+
+    // Moves the reduced dimensions after the kept ones and flattens them into the first of them,
+    // the remaining ones being left with a size of 1.
+    let shape = tensor.shape();
+    let kept = (0..D).filter(|&dim| !reduced[dim]);
+    let mut axes = [0; D];
+    for (axis, dim) in axes
+        .iter_mut()
+        .zip(kept.clone().chain((0..D).filter(|&dim| reduced[dim])))
+    {
+        *axis = dim;
+    }
+    let mut flattened = [1; D];
+    for (size, dim) in flattened.iter_mut().zip(kept) {
+        *size = shape[dim];
+    }
+    flattened[num_kept] = (0..D)
+        .filter(|&dim| reduced[dim])
+        .map(|dim| shape[dim])
+        .product();
+    let output_shape: [usize; D] =
+        core::array::from_fn(|dim| if reduced[dim] { 1 } else { shape[dim] });
+
+    let tensor = tensor.permute(axes).reshape(flattened);
+    let output = Dispatch::logsumexp(tensor.primitive.into_float(), num_kept);
+    Tensor::<D>::new(BridgeTensor::Float(output)).reshape(output_shape)
+}
+
+/// Applies the softmax function on the input tensor over the given dimensions.
+///
+/// The values are normalized jointly over all the `dims`, as if they were flattened into a single
+/// dimension, so the outputs sum to one over each slice spanned by `dims`.
+///
+/// # Arguments
+/// - `dims`: the dimensions along which Softmax will be computed.
+///
+/// # Panics
+/// - If any of `dims` is outside [0, D)
+pub fn softmax_dims<const D: usize>(tensor: Tensor<D>, dims: &[usize]) -> Tensor<D> {
+    log_softmax_dims(tensor, dims).exp()
+}
+
+/// Applies the log softmax function on the input tensor over the given dimensions.
+///
+/// The values are normalized jointly over all the `dims`, as with [softmax_dims].
+///
+/// # Arguments
+/// - `dims`: the dimensions along which Softmax will be computed.
+///
+/// # Panics
+/// - If any of `dims` is outside [0, D)
+pub fn log_softmax_dims<const D: usize>(tensor: Tensor<D>, dims: &[usize]) -> Tensor<D> {
+    let log_sum_exp = logsumexp(tensor.clone(), dims);
+    tensor - log_sum_exp
+}
+
+/// Samples from the Gumbel-softmax (Concrete) distribution along the given dimension, a
+/// differentiable relaxation of sampling from the categorical distribution with the given
+/// unnormalized log-probabilities.
+///
+#[cfg_attr(
+    doc,
+    doc = r#"
+$$
+\text{gumbel\\_softmax}\(x_i\) = \text{softmax}\left(\frac{x_i + g_i}{\tau}\right),
+\quad g_i = -\log\(-\log\(u_i\)\), \quad u_i \sim \mathcal{U}\(0, 1\)
+$$
+"#
+)]
+#[cfg_attr(
+    not(doc),
+    doc = "`gumbel_softmax(x_i) = softmax((x_i + g_i) / tau)` with `g_i = -log(-log(u_i))`, `u_i ~ U(0, 1)`"
+)]
+///
+/// The samples approach one-hot vectors as the temperature decreases. With `hard`, the samples are
+/// the one-hot vectors of their largest category, while the gradients are the ones of the soft
+/// samples (see [straight_through](Tensor::straight_through)).
+///
+/// Reference: "Categorical Reparameterization with Gumbel-Softmax"
+/// <https://arxiv.org/abs/1611.01144>
+///
+/// # Arguments
+/// - `tau`: the temperature, which must be positive.
+/// - `hard`: whether to return one-hot samples.
+/// - `dim`: the dimension of the categories.
+///
+/// # Panics
+/// - If `dim` is outside [0, D)
+/// - If `tau` is not positive
+pub fn gumbel_softmax<const D: usize>(
+    logits: Tensor<D>,
+    tau: f64,
+    hard: bool,
+    dim: usize,
+) -> Tensor<D> {
+    check!(TensorCheck::dim_ops::<D>("gumbel softmax", dim));
+    assert!(
+        tau > 0.0,
+        "The Gumbel-softmax temperature must be positive, got {tau}"
+    );
+    let min_positive = logits
+        .dtype()
+        .finfo()
+        .expect("Gumbel-softmax requires float tensors")
+        .min_positive;
+
+    // `-log(1 - u)` follows the exponential distribution, clamped away from zero so that the
+    // Gumbel noise `-log(e)` stays finite.
+    let exponential = logits
+        .random_like(Distribution::Default)
+        .neg()
+        .add_scalar(1.0)
+        .log()
+        .neg()
+        .clamp_min(min_positive);
+    let gumbels = exponential.log().neg();
+
+    let soft = softmax((logits + gumbels).div_scalar(tau), dim);
+    if !hard {
+        return soft;
+    }
+
+    let index = soft.clone().argmax(dim);
+    let ones = Tensor::ones(index.shape(), (&soft.device(), soft.dtype()));
+    let one_hot = soft
+        .zeros_like()
+        .scatter(dim, index, ones, IndexingUpdateOp::Assign);
+
+    soft.straight_through(one_hot)
+}
+
 /// Applies the sigmoid function element-wise.
 ///
 #[cfg_attr(
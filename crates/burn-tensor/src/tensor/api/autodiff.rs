@@ -24,10 +24,54 @@ impl Gradients {
     }
 }
 
+#[cfg(all(feature = "autodiff", feature = "std"))]
+std::thread_local! {
+    static BACKWARD_OBSERVER: core::cell::RefCell<Option<alloc::rc::Rc<dyn Fn()>>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Runs `func`, calling `on_backward` at the start of each [backward](Tensor::backward) pass
+/// executed by `func` on the current thread.
+///
+/// This is used to time the forward and backward passes of a training step separately.
+#[cfg(all(feature = "autodiff", feature = "std"))]
+pub fn observe_backward<R>(on_backward: impl Fn() + 'static, func: impl FnOnce() -> R) -> R {
+    /// Restores the previous observer, even when `func` panics.
+    struct Restore(Option<alloc::rc::Rc<dyn Fn()>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            BACKWARD_OBSERVER.with(|observer| *observer.borrow_mut() = previous);
+        }
+    }
+
+    let previous = BACKWARD_OBSERVER.with(|observer| {
+        observer
+            .borrow_mut()
+            .replace(alloc::rc::Rc::new(on_backward))
+    });
+    let _restore = Restore(previous);
+
+    func()
+}
+
+#[cfg(all(feature = "autodiff", feature = "std"))]
+fn notify_backward() {
+    // The observer is cloned out of the cell, so it can run backward passes itself.
+    let observer = BACKWARD_OBSERVER.with(|observer| observer.borrow().clone());
+    if let Some(observer) = observer {
+        observer();
+    }
+}
+
 #[cfg(feature = "autodiff")]
 impl<const D: usize> Tensor<D> {
     /// Backward pass of the tensor.
     pub fn backward(&self) -> Gradients {
+        #[cfg(feature = "std")]
+        notify_backward();
+
         Gradients::new(Dispatch::backward(self.primitive.clone().into_float()))
     }
 
@@ -60,6 +104,29 @@ impl<const D: usize> Tensor<D> {
     }
 }
 
+impl<const D: usize> Tensor<D> {
+    /// Straight-through estimator: returns the values of `forward`, while the gradients flow to
+    /// `self` as if it was returned.
+    ///
+    /// This is used to backpropagate through non-differentiable operations, such as rounding,
+    /// quantization or sampling, with `self` as a differentiable surrogate of `forward`, by computing
+    /// `self + (forward - self).detach()`. Without autodiff, `forward` is returned as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Rounds in the forward pass, identity in the backward pass.
+    /// let rounded = tensor.clone().straight_through(tensor.round());
+    /// ```
+    pub fn straight_through(self, forward: Tensor<D>) -> Tensor<D> {
+        if !self.device().is_autodiff() {
+            return forward;
+        }
+        let offset = (forward - self.clone()).detach();
+        self + offset
+    }
+}
+
 impl<const D: usize, K: Autodiff> Tensor<D, K> {
     /// Returns the inner tensor without the autodiff information.
     pub fn inner(self) -> Tensor<D, K> {
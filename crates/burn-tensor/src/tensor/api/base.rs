@@ -1097,13 +1097,7 @@ where
             );
         }
 
-        Tensor::cat(
-            vec![
-                self.clone().slice_dim(dim, shift..),
-                self.slice_dim(dim, ..shift),
-            ],
-            dim,
-        )
+        Tensor::new(K::roll(self.primitive, &[shift], &[dim]))
     }
 
     /// Roll operation.
@@ -1222,13 +1216,7 @@ where
             )
         }
 
-        let x = self.unchecked_roll_dim(shifts[0], dims[0]);
-
-        if dims.len() == 1 {
-            x
-        } else {
-            x.unchecked_roll(&shifts[1..], &dims[1..])
-        }
+        Tensor::new(K::roll(self.primitive, shifts, dims))
     }
 
     /// Returns a tensor containing the elements selected from the given slices.
@@ -1574,6 +1562,39 @@ where
         Self::new(K::to_device(self.primitive, device))
     }
 
+    /// Move the tensor to the given device without blocking the current thread.
+    ///
+    /// The data is read asynchronously from the current device, staged in pinned host memory
+    /// when the target device supports it, and uploaded to the target device. Awaiting the
+    /// transfers of the next batches while the current batch is being processed lets data
+    /// loading overlap with compute.
+    ///
+    /// # Notes
+    ///
+    /// The tensor is returned as is when it is already on the given device. Tensors tracked by
+    /// autodiff are moved with [to_device](Tensor::to_device) to keep their gradient graph.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let batch = batch.to_device_async(&device).await?;
+    /// ```
+    pub async fn to_device_async(self, device: &Device) -> Result<Self, ExecutionError> {
+        let current = self.device();
+        if &current == device {
+            return Ok(self);
+        }
+        if current.is_autodiff() || device.is_autodiff() {
+            return Ok(self.to_device(device));
+        }
+
+        let dtype = self.dtype();
+        let mut data = self.into_data_async().await?;
+        device.staging(core::iter::once(&mut data));
+
+        Ok(Self::new(K::from_data(data, device, dtype)))
+    }
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -1602,10 +1623,13 @@ where
     }
 
     /// Assign the selected elements along the given dimension corresponding to the given indices
-    /// from the value tensor to the original tensor using sum reduction.
+    /// from the value tensor to the original tensor using the `update` reduction.
     ///
     /// # Note
-    /// For booleans, the sum operator is logical or.
+    /// For booleans, the sum operator is logical or, and it is the only supported operation.
+    ///
+    /// See [scatter](Tensor::scatter) for the supported operations and their behavior with
+    /// duplicate indices.
     ///
     /// # Arguments
     ///
@@ -1641,6 +1665,15 @@ where
             &values.shape()
         ));
 
+        if update != IndexingUpdateOp::Add {
+            // The other operations are lowered to a scatter with the indices broadcast along `dim`.
+            let mut shape = [1; D];
+            shape[dim] = indices.dims()[0];
+            let indices = indices.reshape(shape).expand(values.shape());
+
+            return self.scatter(dim, indices, values, update);
+        }
+
         Self::new(K::select_assign(
             self.primitive,
             dim,
@@ -1729,9 +1762,9 @@ where
     }
 
     /// Assign the gathered elements corresponding to the given indices along the specified dimension
-    /// from the value tensor to the original tensor using sum reduction.
+    /// from the value tensor to the original tensor using the `update` reduction.
     ///
-    /// Example using a 3D tensor:
+    /// Example using a 3D tensor with [add](IndexingUpdateOp::Add):
     ///
     /// `input[indices[i, j, k], j, k] += values[i, j, k]; // dim = 0`
     /// `input[i, indices[i, j, k], k] += values[i, j, k]; // dim = 1`
@@ -1750,12 +1783,28 @@ where
     ///
     /// Other references to the input tensor will not be modified by this operation.
     ///
+    /// Like PyTorch's `scatter_reduce` with `include_self=True`, the existing value takes part in
+    /// the reduction: [Mul](IndexingUpdateOp::Mul) computes a product, [Min](IndexingUpdateOp::Min)
+    /// and [Max](IndexingUpdateOp::Max) the smallest and largest values, and
+    /// [Mean](IndexingUpdateOp::Mean) the mean of the existing value and of all the values written
+    /// to the position. The mean of integer tensors is rounded like an integer division.
+    ///
+    /// # Determinism
+    ///
+    /// When `indices` contains duplicate entries:
+    /// - `Add`, `Mul`, `Min`, `Max` and `Mean` apply all the updates on every backend. The CPU
+    ///   backends (`ndarray`, `flex` and `tch` on CPU) apply them sequentially. The GPU backends
+    ///   (`cubecl`) apply them with atomic operations, so the order of the floating point
+    ///   additions and multiplications, and therefore the rounding, may vary between runs. The
+    ///   `f16` and `bf16` values are reduced in `f32` on GPU, and the 64-bit values are reduced
+    ///   with 64-bit atomics when the device supports them, sequentially otherwise.
+    /// - `Assign` keeps an arbitrary one of the written values.
+    ///
+    /// The candle backend doesn't support `Mul`, `Min` and `Max`. Bool tensors only support `Add`.
+    ///
     /// # Warning
     /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
     /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
-    ///
-    /// # Panics
-    /// If the `update` is not `IndexingUpdateOp::Add`. Other operations are currently not implemented.
     pub fn scatter(
         self,
         dim: usize,
@@ -1770,6 +1819,15 @@ where
             &values.shape()
         ));
 
+        if update != IndexingUpdateOp::Add {
+            // Backends only implement the other operations for multi-dimensional scatter.
+            let num_elements = values.shape().num_elements();
+            let indices = Self::scatter_coordinates(dim, indices);
+            let values = values.reshape([num_elements]);
+
+            return self.scatter_nd::<2, 1>(indices, values, update);
+        }
+
         Self::new(K::scatter(
             dim,
             self.primitive,
@@ -1779,6 +1837,32 @@ where
         ))
     }
 
+    /// The full coordinates of the positions updated by [scatter](Tensor::scatter), with shape
+    /// `[num_elements, D]`.
+    fn scatter_coordinates(dim: usize, indices: Tensor<D, Int>) -> Tensor<2, Int> {
+        let shape = indices.shape();
+        let num_elements = shape.num_elements();
+        let device = indices.device();
+        let dtype = indices.dtype();
+
+        let coordinates = (0..D)
+            .map(|d| {
+                let coordinate = if d == dim {
+                    indices.clone()
+                } else {
+                    let mut range_shape = [1; D];
+                    range_shape[d] = shape[d];
+                    Tensor::<1, Int>::arange(0..shape[d] as i64, (&device, dtype))
+                        .reshape(range_shape)
+                        .expand(shape.clone())
+                };
+                coordinate.reshape([num_elements, 1])
+            })
+            .collect();
+
+        Tensor::cat(coordinates, 1)
+    }
+
     /// Multi-dimensional scatter: update the tensor at locations given by `indices` using the specified `update` operation.
     ///
     /// The size of `indices`'s last axis (call it `K`) indexes the leading `K` dims of `self`;
@@ -1792,14 +1876,14 @@ where
     ///
     /// # Note
     ///
-    /// When `indices` contains duplicate entries, behavior varies by operation:
-    /// - For `Add`, accumulation is supported, though results may be non-deterministic on GPU
-    ///   backends.
-    /// - For other operations (`Assign`, `Mul`, `Min`, `Max`), duplicate indices result in
-    ///   undefined behavior for both the forward result and the backward gradients.
+    /// When `indices` contains duplicate entries, the forward result follows
+    /// [scatter](Tensor::scatter): `Add`, `Mul`, `Min`, `Max` and `Mean` apply all the updates,
+    /// possibly in a different order between runs on GPU backends, and `Assign` keeps an
+    /// arbitrary one of the written values.
     ///
-    /// For deterministic results and correct gradient calculation across all operations,
-    /// `indices` should contain unique entries.
+    /// The gradients account for duplicate entries: with `Mul`, each value receives the product of
+    /// the other factors of its position, and with `Min` and `Max`, every input equal to the result
+    /// receives the gradient, including ties.
     ///
     /// # Warning
     ///
@@ -1437,6 +1437,63 @@ impl TensorCheck {
         check
     }
 
+    /// Checks if fold operation is possible for the given shapes.
+    pub(crate) fn fold<const D1: usize, const D2: usize>(
+        ops: &str,
+        shape: &Shape,
+        dim: usize,
+        length: usize,
+        step: usize,
+    ) -> Self {
+        let mut check = TensorCheck::Ok;
+
+        if D2 + 1 != D1 {
+            check = check.register(
+                ops,
+                TensorError::new("The fold rank is incompatible with the input tensor rank.")
+                    .details(format!(
+                        "The output rank '{D2}' + 1 != the input rank '{D1}'.",
+                    )),
+            );
+            return check;
+        }
+
+        if dim + 1 >= D1 {
+            check = check.register(
+                ops,
+                TensorError::new("The windows can't be folded along the window size dimension.")
+                    .details(format!(
+                        "The dimension '{dim}' should be lower than the last dimension '{}'.",
+                        D1 - 1
+                    )),
+            );
+            return check;
+        }
+
+        if step == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The step between each window must be positive."),
+            );
+        }
+
+        let (num_windows, size) = (shape[dim], shape[D1 - 1]);
+        if num_windows > 0 && (num_windows - 1) * step + size > length {
+            check = check.register(
+                ops,
+                TensorError::new("The folded length is too small for the windows.").details(
+                    format!(
+                        "The {num_windows} windows of size {size} with a step of {step} need a \
+                         length of at least {}, got {length}.",
+                        (num_windows - 1) * step + size
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
     /// Checks if input is compatible with convolution weights.
     pub(crate) fn conv<const D1: usize, const D2: usize>(
         ops: &str,
@@ -0,0 +1,518 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::kind::{Basic, Ordered};
+use crate::ops::BridgeTensor;
+use crate::{Shape, Tensor};
+
+/// The reduction applied by [reduce_pattern](Tensor::reduce_pattern) to the axes that are missing
+/// from the output of the pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternReduction {
+    /// Sum of the elements.
+    Sum,
+    /// Mean of the elements.
+    Mean,
+    /// Product of the elements.
+    Prod,
+    /// Maximum of the elements.
+    Max,
+    /// Minimum of the elements.
+    Min,
+}
+
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Basic,
+{
+    /// Rearranges the dimensions of the tensor with an [einops](https://einops.rocks) pattern,
+    /// which combines a reshape and a permutation.
+    ///
+    /// The pattern is made of the input and output dimensions, separated by `->`:
+    ///
+    /// - A name, such as `h`, is an axis.
+    /// - Parentheses, such as `(h w)`, group several axes into a single dimension, where the first
+    ///   axis is the outermost one.
+    /// - `1` is a dimension of size 1.
+    /// - `...` stands for all the dimensions that aren't named, and must appear on both sides.
+    ///
+    /// Both sides must have the same axes, with as many dimensions as the rank of the input and
+    /// output tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The rearrangement pattern, such as `"b c h w -> b (h w) c"`.
+    /// * `axes` - The sizes of the axes that can't be inferred from the input shape, such as
+    ///   `&[("h", 2)]` to split the dimension `(h w)` in `h` and `w`.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is invalid or doesn't match the input shape and the output rank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let images = Tensor::<4>::zeros([8, 3, 32, 32], &device);
+    ///
+    ///     let sequence = images.clone().rearrange::<3>("b c h w -> b (h w) c", &[]);
+    ///     println!("{:?}", sequence.dims());
+    ///     // [8, 1024, 3]
+    ///
+    ///     let patches = images.rearrange::<4>(
+    ///         "b c (h p) (w q) -> b (h w) (p q) c",
+    ///         &[("p", 4), ("q", 4)],
+    ///     );
+    ///     println!("{:?}", patches.dims());
+    ///     // [8, 64, 16, 3]
+    /// }
+    /// ```
+    pub fn rearrange<const D2: usize>(
+        self,
+        pattern: &str,
+        axes: &[(&str, usize)],
+    ) -> Tensor<D2, K> {
+        let plan = PatternPlan::new::<D, D2>(PatternOp::Rearrange, pattern, &self.dims(), axes);
+        let tensor = K::reshape(self.primitive, Shape::from(plan.input_shape.clone()));
+
+        Tensor::new(plan.arrange::<K>(tensor))
+    }
+
+    /// Rearranges the dimensions of the tensor with an [einops](https://einops.rocks) pattern,
+    /// and repeats the tensor along the new axes of the output.
+    ///
+    /// The pattern follows the syntax of [rearrange](Tensor::rearrange), except that the output
+    /// can have axes that aren't in the input, whose sizes must be given. The new axes can be
+    /// grouped with the other axes, such as `(h a)` to repeat each row `a` times.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is invalid or doesn't match the input shape and the output rank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///
+    ///     let upsampled = tensor.repeat_pattern::<2>("h w -> (h a) (w b)", &[("a", 2), ("b", 2)]);
+    ///     println!("{upsampled}");
+    ///     // [[1.0, 1.0, 2.0, 2.0],
+    ///     //  [1.0, 1.0, 2.0, 2.0],
+    ///     //  [3.0, 3.0, 4.0, 4.0],
+    ///     //  [3.0, 3.0, 4.0, 4.0]]
+    /// }
+    /// ```
+    pub fn repeat_pattern<const D2: usize>(
+        self,
+        pattern: &str,
+        axes: &[(&str, usize)],
+    ) -> Tensor<D2, K> {
+        let plan = PatternPlan::new::<D, D2>(PatternOp::Repeat, pattern, &self.dims(), axes);
+        let tensor = K::reshape(self.primitive, Shape::from(plan.input_shape.clone()));
+
+        Tensor::new(plan.arrange::<K>(tensor))
+    }
+}
+
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Ordered,
+{
+    /// Rearranges the dimensions of the tensor with an [einops](https://einops.rocks) pattern,
+    /// and reduces the axes of the input that aren't in the output.
+    ///
+    /// The pattern follows the syntax of [rearrange](Tensor::rearrange), except that the input
+    /// can have axes that aren't in the output, which are reduced with the given reduction.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is invalid or doesn't match the input shape and the output rank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{PatternReduction, Tensor};
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let images = Tensor::<4>::ones([8, 3, 32, 32], &device);
+    ///
+    ///     let pooled = images
+    ///         .clone()
+    ///         .reduce_pattern::<2>("b c h w -> b c", PatternReduction::Mean, &[]);
+    ///     println!("{:?}", pooled.dims());
+    ///     // [8, 3]
+    ///
+    ///     let pooled = images.reduce_pattern::<4>(
+    ///         "b c (h p) (w q) -> b c h w",
+    ///         PatternReduction::Max,
+    ///         &[("p", 2), ("q", 2)],
+    ///     );
+    ///     println!("{:?}", pooled.dims());
+    ///     // [8, 3, 16, 16]
+    /// }
+    /// ```
+    pub fn reduce_pattern<const D2: usize>(
+        self,
+        pattern: &str,
+        reduction: PatternReduction,
+        axes: &[(&str, usize)],
+    ) -> Tensor<D2, K> {
+        let plan = PatternPlan::new::<D, D2>(PatternOp::Reduce, pattern, &self.dims(), axes);
+        let mut tensor = K::reshape(self.primitive, Shape::from(plan.input_shape.clone()));
+        for &dim in plan.reduced.iter() {
+            tensor = match reduction {
+                PatternReduction::Sum => K::sum_dim(tensor, dim),
+                PatternReduction::Mean => K::mean_dim(tensor, dim),
+                PatternReduction::Prod => K::prod_dim(tensor, dim),
+                PatternReduction::Max => K::max_dim(tensor, dim),
+                PatternReduction::Min => K::min_dim(tensor, dim),
+            };
+        }
+
+        Tensor::new(plan.arrange::<K>(tensor))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PatternOp {
+    Rearrange,
+    Repeat,
+    Reduce,
+}
+
+/// One side of a pattern: the axes of each dimension, where `...` is expanded to one axis per
+/// dimension.
+struct PatternSide {
+    dims: Vec<Vec<String>>,
+    ellipsis: Option<usize>,
+}
+
+impl PatternSide {
+    fn parse(expr: &str) -> Result<Self, String> {
+        let mut dims = Vec::new();
+        let mut group: Option<Vec<String>> = None;
+        let mut ellipsis = None;
+        let mut chars = expr.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            let token = match c {
+                c if c.is_whitespace() => continue,
+                '(' => {
+                    if group.is_some() {
+                        return Err("Nested parentheses aren't supported.".to_string());
+                    }
+                    group = Some(Vec::new());
+                    continue;
+                }
+                ')' => {
+                    let axes = group.take().ok_or("Unmatched closing parenthesis.")?;
+                    dims.push(axes);
+                    continue;
+                }
+                '.' => {
+                    if !expr[start..].starts_with("...") {
+                        return Err("Expected an ellipsis '...'.".to_string());
+                    }
+                    chars.next();
+                    chars.next();
+                    "..."
+                }
+                c if c.is_alphanumeric() || c == '_' => {
+                    let mut end = start + c.len_utf8();
+                    while let Some(&(i, c)) = chars.peek() {
+                        if !(c.is_alphanumeric() || c == '_') {
+                            break;
+                        }
+                        end = i + c.len_utf8();
+                        chars.next();
+                    }
+                    &expr[start..end]
+                }
+                c => return Err(format!("Unexpected character '{c}'.")),
+            };
+
+            match token {
+                "..." => {
+                    if group.is_some() {
+                        return Err("The ellipsis can't be in parentheses.".to_string());
+                    }
+                    if ellipsis.is_some() {
+                        return Err("The ellipsis can appear only once per side.".to_string());
+                    }
+                    ellipsis = Some(dims.len());
+                    dims.push(Vec::new());
+                }
+                // Dimensions of size 1.
+                "1" => {
+                    if group.is_none() {
+                        dims.push(Vec::new());
+                    }
+                }
+                token if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                    return Err(format!(
+                        "Anonymous axis '{token}' isn't supported, name it and give its size."
+                    ));
+                }
+                name => {
+                    let name = name.to_string();
+                    if dims
+                        .iter()
+                        .chain(group.iter())
+                        .any(|axes| axes.contains(&name))
+                    {
+                        return Err(format!("The axis '{name}' appears more than once."));
+                    }
+                    match &mut group {
+                        Some(axes) => axes.push(name),
+                        None => dims.push(alloc::vec![name]),
+                    }
+                }
+            }
+        }
+
+        if group.is_some() {
+            return Err("Unmatched opening parenthesis.".to_string());
+        }
+
+        Ok(Self { dims, ellipsis })
+    }
+
+    /// Replaces the ellipsis with one axis per dimension.
+    fn expand_ellipsis(&mut self, num_dims: usize) {
+        if let Some(position) = self.ellipsis {
+            // The axes names can't contain dots, so they can't collide.
+            self.dims.remove(position);
+            for i in (0..num_dims).rev() {
+                self.dims.insert(position, alloc::vec![format!("...{i}")]);
+            }
+        }
+    }
+
+    fn axes(&self) -> impl Iterator<Item = &String> {
+        self.dims.iter().flatten()
+    }
+}
+
+/// The lowering of a pattern to elementary operations on the primitive tensor.
+///
+/// The input is reshaped so that each axis is a dimension, the reduced axes are reduced, and the
+/// remaining axes are permuted in the order of the output. The new axes are then broadcast, and
+/// the axes are merged in the dimensions of the output.
+struct PatternPlan {
+    /// The shape of the input with one dimension per axis.
+    input_shape: Vec<usize>,
+    /// The dimensions of the input axes to reduce, which are kept with a size of 1.
+    reduced: Vec<usize>,
+    /// The shape of the input axes after the reduction, without the reduced axes.
+    kept_shape: Vec<usize>,
+    /// The permutation of the kept axes to the order of the output.
+    permutation: Vec<usize>,
+    /// The shape of the output axes, where the new axes have a size of 1.
+    repeated_shape: Vec<usize>,
+    /// The shape of the output axes.
+    expanded_shape: Vec<usize>,
+    /// The shape of the output.
+    output_shape: Vec<usize>,
+}
+
+impl PatternPlan {
+    fn new<const D: usize, const D2: usize>(
+        op: PatternOp,
+        pattern: &str,
+        dims: &[usize],
+        axes: &[(&str, usize)],
+    ) -> Self {
+        Self::try_new::<D, D2>(op, pattern, dims, axes)
+            .unwrap_or_else(|err| panic!("Invalid pattern '{pattern}' for shape {dims:?}: {err}"))
+    }
+
+    fn try_new<const D: usize, const D2: usize>(
+        op: PatternOp,
+        pattern: &str,
+        dims: &[usize],
+        axes: &[(&str, usize)],
+    ) -> Result<Self, String> {
+        let (lhs, rhs) = pattern
+            .split_once("->")
+            .ok_or("The pattern must have the form 'input -> output'.")?;
+        let mut lhs = PatternSide::parse(lhs)?;
+        let mut rhs = PatternSide::parse(rhs)?;
+
+        // Validate the ranks.
+        let num_ellipsis_dims = match lhs.ellipsis {
+            Some(_) if lhs.dims.len() - 1 > D => {
+                return Err(format!(
+                    "The input has {} named dimensions, but the tensor rank is {D}.",
+                    lhs.dims.len() - 1
+                ));
+            }
+            Some(_) => D + 1 - lhs.dims.len(),
+            None if lhs.dims.len() != D => {
+                return Err(format!(
+                    "The input has {} dimensions, but the tensor rank is {D}.",
+                    lhs.dims.len()
+                ));
+            }
+            None => 0,
+        };
+        if rhs.ellipsis.is_some() && lhs.ellipsis.is_none() {
+            return Err("The ellipsis of the output must be in the input.".to_string());
+        }
+        lhs.expand_ellipsis(num_ellipsis_dims);
+        rhs.expand_ellipsis(num_ellipsis_dims);
+        if rhs.dims.len() != D2 {
+            return Err(format!(
+                "The output has {} dimensions, but the output rank is {D2}.",
+                rhs.dims.len()
+            ));
+        }
+
+        // Validate the axes of each side.
+        for axis in lhs.axes() {
+            if op != PatternOp::Reduce && !rhs.axes().any(|other| other == axis) {
+                return Err(format!("The axis '{axis}' is missing from the output."));
+            }
+        }
+        for axis in rhs.axes() {
+            if op != PatternOp::Repeat && !lhs.axes().any(|other| other == axis) {
+                return Err(format!("The axis '{axis}' is missing from the input."));
+            }
+        }
+
+        // Resolve the sizes of the axes.
+        let mut sizes = Vec::<(String, usize)>::new();
+        for &(name, size) in axes {
+            if !lhs.axes().chain(rhs.axes()).any(|axis| axis == name) {
+                return Err(format!("The axis '{name}' isn't in the pattern."));
+            }
+            sizes.push((name.to_string(), size));
+        }
+        let size_of = |sizes: &[(String, usize)], axis: &String| {
+            sizes
+                .iter()
+                .find(|(name, _)| name == axis)
+                .map(|(_, size)| *size)
+        };
+
+        for (axes, &dim) in lhs.dims.iter().zip(dims) {
+            let unknown = axes
+                .iter()
+                .filter(|axis| size_of(&sizes, axis).is_none())
+                .collect::<Vec<_>>();
+            let known = axes
+                .iter()
+                .filter_map(|axis| size_of(&sizes, axis))
+                .product::<usize>();
+
+            match unknown.as_slice() {
+                [] if known != dim => {
+                    return Err(format!(
+                        "The axes ({}) have a size of {known}, but the dimension has a size of \
+                         {dim}.",
+                        axes.join(" ")
+                    ));
+                }
+                [] => {}
+                [axis] if known != 0 && dim % known == 0 => {
+                    sizes.push((axis.to_string(), dim / known));
+                }
+                [axis] => {
+                    return Err(format!(
+                        "The size of the axis '{axis}' can't be inferred from the dimension of \
+                         size {dim}."
+                    ));
+                }
+                _ => {
+                    return Err(format!(
+                        "The sizes of the axes ({}) can't be inferred, give all but one of them.",
+                        axes.join(" ")
+                    ));
+                }
+            }
+        }
+
+        let mut repeated_shape = Vec::new();
+        let mut expanded_shape = Vec::new();
+        for axis in rhs.axes() {
+            let size = size_of(&sizes, axis)
+                .ok_or_else(|| format!("The size of the new axis '{axis}' must be given."))?;
+            let is_new = !lhs.axes().any(|other| other == axis);
+            repeated_shape.push(if is_new { 1 } else { size });
+            expanded_shape.push(size);
+        }
+
+        let input_axes = lhs.axes().collect::<Vec<_>>();
+        let input_shape = input_axes
+            .iter()
+            .map(|axis| size_of(&sizes, axis).unwrap())
+            .collect::<Vec<_>>();
+        let is_kept = |axis: &String| rhs.axes().any(|other| other == axis);
+        let reduced = (0..input_axes.len())
+            .filter(|&dim| !is_kept(input_axes[dim]))
+            .collect::<Vec<_>>();
+        let kept_axes = input_axes
+            .iter()
+            .copied()
+            .filter(|axis| is_kept(axis))
+            .collect::<Vec<_>>();
+        let kept_shape = kept_axes
+            .iter()
+            .map(|axis| size_of(&sizes, axis).unwrap())
+            .collect();
+        let permutation = rhs
+            .axes()
+            .filter_map(|axis| kept_axes.iter().position(|other| *other == axis))
+            .collect();
+        let output_shape = rhs
+            .dims
+            .iter()
+            .map(|axes| {
+                axes.iter()
+                    .map(|axis| size_of(&sizes, axis).unwrap())
+                    .product()
+            })
+            .collect();
+
+        Ok(Self {
+            input_shape,
+            reduced,
+            kept_shape,
+            permutation,
+            repeated_shape,
+            expanded_shape,
+            output_shape,
+        })
+    }
+
+    /// Permutes, broadcasts and merges the axes of the input, once reshaped to the input shape
+    /// and reduced.
+    fn arrange<K: Basic>(self, tensor: BridgeTensor) -> BridgeTensor {
+        let mut tensor = tensor;
+        if !self.reduced.is_empty() {
+            tensor = K::reshape(tensor, Shape::from(self.kept_shape));
+        }
+        if self
+            .permutation
+            .iter()
+            .enumerate()
+            .any(|(i, &axis)| i != axis)
+        {
+            tensor = K::permute(tensor, &self.permutation);
+        }
+        if self.repeated_shape != self.expanded_shape {
+            tensor = K::reshape(tensor, Shape::from(self.repeated_shape));
+            tensor = K::expand(tensor, Shape::from(self.expanded_shape));
+        }
+
+        K::reshape(tensor, Shape::from(self.output_shape))
+    }
+}
@@ -2,11 +2,13 @@ use crate::AsIndex;
 use crate::Cast;
 use crate::Device;
 use crate::Tensor;
+use crate::TensorCreationOptions;
 use crate::cast::ToElement;
 use crate::check;
 use crate::check::TensorCheck;
 use crate::kind::FloatMath;
 use crate::ops::BridgeTensor;
+use crate::ops::QuantileInterpolation;
 use crate::quantization::{QuantScheme, QuantizationParameters};
 use crate::tensor::stats;
 use crate::tensor::{Distribution, TensorData};
@@ -14,6 +16,7 @@ use crate::{Bool, Float, Int, TensorPrimitive};
 #[cfg(feature = "distributed")]
 use burn_backend::AutodiffBackend;
 use burn_backend::ElementConversion;
+use burn_backend::FloatDType;
 use burn_backend::Scalar;
 use burn_backend::TensorMetadata;
 #[cfg(feature = "distributed")]
@@ -22,9 +25,10 @@ use burn_backend::ops::ActivationOps;
 use burn_backend::ops::FloatTensorOps;
 use burn_backend::ops::GridSampleOptions;
 use burn_backend::ops::QTensorOps;
+use burn_backend::ops::{float_random_beta, float_random_gamma};
 use burn_backend::quantization::QuantizationParametersPrimitive;
 use burn_dispatch::Dispatch;
-use core::f32;
+use core::ops::Range;
 
 /// Default RTOL value for `is_close` and `all_close`.
 pub const DEFAULT_RTOL: f64 = 1e-5;
@@ -32,6 +36,140 @@ pub const DEFAULT_RTOL: f64 = 1e-5;
 /// Default ATOL value for `is_close` and `all_close`.
 pub const DEFAULT_ATOL: f64 = 1e-8;
 
+impl Tensor<1> {
+    /// Returns a new float tensor with the values `start, start + step, ...` up to `end` (exclusive).
+    ///
+    /// The values are computed on the device, in the data type of the creation options.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values to generate.
+    /// * `step` - The step between each value, which can be negative to generate decreasing values.
+    /// * `options` - The device and optional data type of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::arange_float(0.0..1.0, 0.25, &device);
+    ///     println!("{tensor}");
+    ///     // [0.0, 0.25, 0.5, 0.75]
+    /// }
+    /// ```
+    pub fn arange_float(
+        range: Range<f64>,
+        step: f64,
+        options: impl Into<TensorCreationOptions>,
+    ) -> Self {
+        assert!(
+            step != 0.0 && step.is_finite(),
+            "The step of arange_float must be finite and non-zero. Got {step}"
+        );
+        let size = ((range.end - range.start) / step).ceil().max(0.0) as usize;
+
+        Self::indices(size, options)
+            .mul_scalar(step)
+            .add_scalar(range.start)
+    }
+
+    /// Returns a new float tensor with `steps` values evenly spaced from `start` to `end`
+    /// (inclusive).
+    ///
+    /// The values are computed on the device, in the data type of the creation options. The first
+    /// half is computed from `start` and the second half from `end`, so both endpoints are exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The last value.
+    /// * `steps` - The number of values.
+    /// * `options` - The device and optional data type of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::linspace(0.0, 1.0, 5, &device);
+    ///     println!("{tensor}");
+    ///     // [0.0, 0.25, 0.5, 0.75, 1.0]
+    /// }
+    /// ```
+    pub fn linspace(
+        start: f64,
+        end: f64,
+        steps: usize,
+        options: impl Into<TensorCreationOptions>,
+    ) -> Self {
+        let indices = Self::indices(steps, options);
+        if steps <= 1 {
+            return indices.add_scalar(start);
+        }
+
+        let step = (end - start) / (steps - 1) as f64;
+        let second_half = indices.clone().greater_equal_elem((steps / 2) as f64);
+        let from_end = indices
+            .clone()
+            .sub_scalar((steps - 1) as f64)
+            .mul_scalar(step)
+            .add_scalar(end);
+
+        indices
+            .mul_scalar(step)
+            .add_scalar(start)
+            .mask_where(second_half, from_end)
+    }
+
+    /// Returns a new float tensor with `steps` values `base^x`, where the exponents `x` are evenly
+    /// spaced from `start` to `end` (inclusive), as in [linspace](Tensor::linspace).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first exponent.
+    /// * `end` - The last exponent.
+    /// * `steps` - The number of values.
+    /// * `base` - The base of the exponentiation.
+    /// * `options` - The device and optional data type of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::logspace(0.0, 3.0, 4, 10.0, &device);
+    ///     println!("{tensor}");
+    ///     // [1.0, 10.0, 100.0, 1000.0]
+    /// }
+    /// ```
+    pub fn logspace(
+        start: f64,
+        end: f64,
+        steps: usize,
+        base: f64,
+        options: impl Into<TensorCreationOptions>,
+    ) -> Self {
+        let exponents = Self::linspace(start, end, steps, options);
+
+        exponents.full_like(base).powf(exponents)
+    }
+
+    /// The float tensor `[0, 1, ..., size - 1]`, in the data type of the creation options.
+    fn indices(size: usize, options: impl Into<TensorCreationOptions>) -> Self {
+        let opt = options.into();
+        let dtype: FloatDType = opt.resolve_dtype::<Float>().into();
+        let size = i64::try_from(size).expect("The number of values doesn't fit in i64 range.");
+
+        Tensor::<1, Int>::arange(0..size, &opt.device).cast(dtype)
+    }
+}
+
 impl<const D: usize> Tensor<D> {
     /// Applies the [error function](https://en.wikipedia.org/wiki/Error_function) element wise.
     ///
@@ -66,21 +204,37 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
     /// Converts each of the elements of the input tensor from angles in degrees to radians.
     ///
     /// # Example
-    /// ```ignore
-    /// let tensor_in_radians = tensor.deg2rad();
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.0, 90.0, -180.0], &device);
+    ///     println!("{}", tensor.deg2rad());
+    ///     // [0.0, 1.5707964, -3.1415927]
+    /// }
     /// ```
     pub fn deg2rad(self) -> Self {
-        self.mul_scalar(f32::consts::PI / 180.0)
+        self.mul_scalar(core::f64::consts::PI / 180.0)
     }
 
     /// Converts each of the elements of the input tensor from angles in radians to degrees.
     ///
     /// # Example
-    /// ```ignore
-    /// let tensor_in_degrees = tensor.rad2deg();
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.0, 1.5707964, -3.1415927], &device);
+    ///     println!("{}", tensor.rad2deg());
+    ///     // [0.0, 90.0, -180.0]
+    /// }
     /// ```
     pub fn rad2deg(self) -> Self {
-        self.mul_scalar(180.0 / f32::consts::PI)
+        self.mul_scalar(180.0 / core::f64::consts::PI)
     }
 
     /// Applies element wise round operation.
@@ -157,6 +311,56 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         )))
     }
 
+    /// Samples each element from a gamma distribution with the concentration given by the
+    /// corresponding element of `concentration`, and a unit scale.
+    ///
+    /// The samples are reparameterized: gradients flow back to `concentration`, which makes the
+    /// sampler usable in variational objectives. Multiply the samples by a scale to sample from
+    /// `Gamma(concentration, scale)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let concentration = Tensor::<1>::from_floats([0.5, 1.0, 4.0], &device);
+    ///     let samples = Tensor::random_gamma(concentration);
+    ///     println!("{samples}");
+    /// }
+    /// ```
+    pub fn random_gamma(concentration: Self) -> Self {
+        Self::new(BridgeTensor::Float(float_random_gamma::<Dispatch>(
+            concentration.primitive.into_float(),
+        )))
+    }
+
+    /// Samples each element from a beta distribution with the parameters given by the
+    /// corresponding elements of `alpha` and `beta`.
+    ///
+    /// The samples are reparameterized with respect to both parameters, like
+    /// [random_gamma](Tensor::random_gamma).
+    pub fn random_beta(alpha: Self, beta: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("random_beta", &alpha, &beta));
+
+        Self::new(BridgeTensor::Float(float_random_beta::<Dispatch>(
+            alpha.primitive.into_float(),
+            beta.primitive.into_float(),
+        )))
+    }
+
+    /// Samples from Dirichlet distributions whose concentrations are given along the last
+    /// dimension of `concentration`, so that each sample sums to one along that dimension.
+    ///
+    /// The samples are normalized [gamma samples](Tensor::random_gamma), so they are
+    /// reparameterized with respect to the concentrations.
+    pub fn random_dirichlet(concentration: Self) -> Self {
+        let samples = Self::random_gamma(concentration);
+        let sum = samples.clone().sum_dim(D - 1);
+        samples / sum
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -283,6 +487,52 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         stats::median_with_indices(self, dim)
     }
 
+    /// Returns the `q`-th quantile of the values along the specified dimension.
+    ///
+    /// The quantile is computed on the sorted values, and the `interpolation` method
+    /// determines the result when the quantile position falls between two values.
+    ///
+    /// # Note
+    ///
+    /// The current implementation performs a full sort along the specified dimension,
+    /// which has O(nlog(n)) complexity.
+    ///
+    /// # Arguments
+    ///
+    /// - `q` - The quantile to compute, in the range `[0, 1]`.
+    /// - `dim` - The dimension along which to compute the quantile.
+    /// - `interpolation` - The interpolation method between two values.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same rank, where the reduced dimension has size 1.
+    ///
+    /// # Panics
+    ///
+    /// If `q` is not in the range `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    /// use burn_tensor::ops::QuantileInterpolation;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<2>::from_data([[1.0, 4.0, 2.0, 3.0]], &device);
+    ///
+    ///     let quantile = tensor.clone().quantile(0.5, 1, QuantileInterpolation::Linear);
+    ///     // Result: [[2.5]]
+    ///
+    ///     let quantile = tensor.quantile(0.5, 1, QuantileInterpolation::Lower);
+    ///     // Result: [[2.0]]
+    /// }
+    /// ```
+    pub fn quantile(self, q: f64, dim: usize, interpolation: QuantileInterpolation) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Quantile", dim));
+        stats::quantile(self, q, dim, interpolation)
+    }
+
     /// Converts a tensor to the specified data type.
     ///
     /// Supports both within-kind casting (e.g., `FloatDType::F64`) and cross-kind casting
@@ -792,6 +1042,116 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
 
         Tensor::new(primitive)
     }
+
+    /// Computes element wise the length of the hypotenuse of a right triangle with legs `self`
+    /// and `other`.
+    ///
+    #[cfg_attr(doc, doc = r#"$z_i = \sqrt\{x_i^2 + y_i^2\}$"#)]
+    #[cfg_attr(not(doc), doc = "`z_i = sqrt(x_i^2 + y_i^2)`")]
+    ///
+    /// The squares are computed relative to the larger leg, so they don't overflow, and an infinite
+    /// leg gives an infinite result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///
+    ///     let lhs = Tensor::<1>::from_data([3.0, -5.0, 0.0], &device);
+    ///     let rhs = Tensor::<1>::from_data([4.0, 12.0, 0.0], &device);
+    ///     println!("{}", lhs.hypot(rhs)); // [5.0, 13.0, 0.0]
+    /// }
+    /// ```
+    pub fn hypot(self, other: Self) -> Self {
+        let lhs = self.abs();
+        let rhs = other.abs();
+        let larger = lhs.clone().max_pair(rhs.clone());
+        let smaller = lhs.min_pair(rhs);
+
+        // Dividing by one when both legs are zero keeps the ratio, and its gradient, finite.
+        let is_zero = larger.clone().equal_elem(0.0);
+        let ratio = smaller.div(larger.clone().mask_fill(is_zero, 1.0));
+        let is_inf = larger.clone().is_inf();
+
+        (larger * ratio.square().add_scalar(1.0).sqrt()).mask_fill(is_inf, f64::INFINITY)
+    }
+
+    /// Returns element wise the magnitude of `self` with the sign of `other`.
+    ///
+    /// As in `math.copysign`, the sign of a zero is taken into account, so `-0.0` gives a
+    /// negative result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///
+    ///     let lhs = Tensor::<1>::from_data([1.0, -2.0, 3.0, 4.0], &device);
+    ///     let rhs = Tensor::<1>::from_data([-1.0, 1.0, 0.0, -0.0], &device);
+    ///     println!("{}", lhs.copysign(rhs)); // [-1.0, 2.0, 3.0, -4.0]
+    /// }
+    /// ```
+    pub fn copysign(self, other: Self) -> Self {
+        // The reciprocal of a negative zero is negative infinity.
+        let is_negative_zero = other
+            .clone()
+            .equal_elem(0.0)
+            .bool_and(other.clone().recip().lower_elem(0.0));
+        let is_negative = other.lower_elem(0.0).bool_or(is_negative_zero);
+
+        let magnitude = self.abs();
+        magnitude.clone().mask_where(is_negative, magnitude.neg())
+    }
+
+    /// Linearly interpolates element wise from `self` to `end` with the given weights.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = x_i + w_i \(e_i - x_i\)$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = x_i + w_i * (e_i - x_i)`")]
+    ///
+    /// As in `torch.lerp`, weights of `0.5` or more are applied from `end`, so a weight of one
+    /// returns `end` exactly. The tensors are broadcast together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///
+    ///     let start = Tensor::<1>::from_data([0.0, 1.0, 2.0], &device);
+    ///     let end = Tensor::<1>::from_data([10.0, 1.0, -2.0], &device);
+    ///     let weight = Tensor::<1>::from_data([0.5, 0.3, 1.0], &device);
+    ///     println!("{}", start.lerp(end, weight)); // [5.0, 1.0, -2.0]
+    /// }
+    /// ```
+    pub fn lerp(self, end: Self, weight: Self) -> Self {
+        let diff = end.clone() - self.clone();
+        let from_end = weight.clone().greater_equal_elem(0.5);
+        let from_start = self + diff.clone() * weight.clone();
+
+        from_start.mask_where(from_end, end - diff * weight.neg().add_scalar(1.0))
+    }
+
+    /// Linearly interpolates element wise from `self` to `end` with a scalar weight, e.g. to
+    /// update an exponential moving average with `average.lerp_scalar(value, 1.0 - decay)`.
+    ///
+    /// See [lerp](Tensor::lerp) for more information.
+    pub fn lerp_scalar(self, end: Self, weight: f64) -> Self {
+        let diff = end.clone() - self.clone();
+
+        if weight < 0.5 {
+            self + diff.mul_scalar(weight)
+        } else {
+            end - diff.mul_scalar(1.0 - weight)
+        }
+    }
 }
 
 impl<const D: usize> Tensor<D> {
@@ -0,0 +1,141 @@
+use crate::{Bool, Float, IndexingUpdateOp, Int, Tensor};
+
+impl<const D: usize> Tensor<D, Float> {
+    /// Computes the histogram of the tensor.
+    ///
+    /// The elements are sorted into `bins` equal width bins between `min` and `max`.
+    /// Elements lower than `min` or greater than `max` are ignored, and elements equal to `max`
+    /// are counted in the last bin.
+    ///
+    /// The histogram is computed on the device with a scatter-add, so no data is transferred
+    /// back to the host.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of histogram bins.
+    /// * `min` - The lower end of the range (inclusive).
+    /// * `max` - The upper end of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// A 1D int tensor of shape `[bins]` containing the number of elements in each bin.
+    ///
+    /// # Panics
+    ///
+    /// If `bins` is zero or if `min` is not lower than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([1.0, 2.0, 1.0, 4.0, 5.0], &device);
+    ///     let hist = tensor.histc(4, 0.0, 4.0);
+    ///     println!("{hist}");
+    ///     // [0, 2, 1, 1]
+    /// }
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<1, Int> {
+        let samples: Tensor<2> = self.reshape([-1, 1]);
+
+        samples.histogramdd([bins], [(min, max)])
+    }
+}
+
+impl Tensor<2, Float> {
+    /// Computes the multi-dimensional histogram of a set of samples.
+    ///
+    /// The tensor is interpreted as `N` samples of dimension `K`, with shape `[N, K]`.
+    /// Along each dimension `k`, the range `ranges[k]` is split into `bins[k]` equal width bins.
+    /// Samples with any coordinate outside of its range are ignored, and coordinates equal to
+    /// the upper end of the range are counted in the last bin.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins for each dimension.
+    /// * `ranges` - The `(min, max)` range (inclusive) for each dimension.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor of shape `bins` where each element is the number of samples that
+    /// fall in the corresponding bin.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns is not `K`, if a bin count is zero or if a range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let samples = Tensor::<2>::from_data([[0.5, 0.5], [1.5, 0.5], [1.5, 1.5]], &device);
+    ///     let hist = samples.histogramdd([2, 2], [(0.0, 2.0), (0.0, 2.0)]);
+    ///     println!("{hist}");
+    ///     // [[1, 0], [1, 1]]
+    /// }
+    /// ```
+    pub fn histogramdd<const K: usize>(
+        self,
+        bins: [usize; K],
+        ranges: [(f64, f64); K],
+    ) -> Tensor<K, Int> {
+        let [num_samples, num_dims] = self.dims();
+        assert_eq!(
+            num_dims, K,
+            "histogramdd expects samples of dimension {K}, got {num_dims}"
+        );
+        for (k, (&num_bins, &(min, max))) in bins.iter().zip(ranges.iter()).enumerate() {
+            assert!(num_bins > 0, "histogramdd expects at least one bin on dim {k}");
+            assert!(
+                min < max,
+                "histogramdd expects min < max on dim {k}, got ({min}, {max})"
+            );
+        }
+
+        let device = self.device();
+        let num_bins_total = bins.iter().product::<usize>();
+        let mut flat_index = Tensor::<1, Int>::zeros([num_samples], &device);
+        let mut in_range: Option<Tensor<1, Bool>> = None;
+        let mut stride = 1;
+
+        // Row-major linear index over the bins, last dimension varying fastest.
+        for k in (0..K).rev() {
+            let (min, max) = ranges[k];
+            let num_bins = bins[k];
+            let values: Tensor<1> = self.clone().slice_dim(1, k..k + 1).reshape([num_samples]);
+
+            let valid = values
+                .clone()
+                .greater_equal_elem(min)
+                .bool_and(values.clone().lower_equal_elem(max));
+            in_range = Some(match in_range {
+                Some(in_range) => in_range.bool_and(valid),
+                None => valid,
+            });
+
+            let scale = num_bins as f64 / (max - min);
+            let index = values
+                .sub_scalar(min)
+                .mul_scalar(scale)
+                .floor()
+                .int()
+                .clamp(0, num_bins as i64 - 1);
+
+            flat_index = flat_index.add(index.mul_scalar(stride as i64));
+            stride *= num_bins;
+        }
+
+        let in_range = in_range.expect("histogramdd expects at least one dimension");
+        let flat_index = flat_index.mask_fill(in_range.clone().bool_not(), 0);
+        let counts = in_range.int();
+
+        Tensor::<1, Int>::zeros([num_bins_total], &device)
+            .scatter(0, flat_index, counts, IndexingUpdateOp::Add)
+            .reshape(bins)
+    }
+}
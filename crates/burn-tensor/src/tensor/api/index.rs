@@ -0,0 +1,279 @@
+use crate::{Bool, IndexingUpdateOp, Int, Shape, Tensor, kind::Basic};
+use alloc::vec::Vec;
+
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Basic,
+{
+    /// Advanced indexing: selects elements using one index tensor for each of the leading `N`
+    /// dimensions, like `tensor[i0, i1, ...]` in NumPy or PyTorch.
+    ///
+    /// The index tensors are broadcast together, and the element at position `p` of the result is
+    /// `self[indices[0][p], ..., indices[N-1][p], ...]`. The output shape is the broadcast shape of
+    /// the indices followed by the remaining dimensions of the tensor: `DO = DI + D - N`.
+    ///
+    /// Negative indices count from the end of their dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The index tensors for the leading `N` dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<3>::from_data(
+    ///       [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+    ///       &device,
+    ///   );
+    ///   let rows = Tensor::<1, Int>::from_data([0, 1, -1], &device);
+    ///   let cols = Tensor::<1, Int>::from_data([1, 0, 1], &device);
+    ///   let result: Tensor<2> = tensor.index([rows, cols]);
+    ///   println!("{result}");
+    ///   // [[3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]
+    /// }
+    /// ```
+    ///
+    /// # Warning
+    ///
+    /// Not all backends have runtime bound checks for the indices, so make sure they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    pub fn index<const N: usize, const DI: usize, const DO: usize>(
+        self,
+        indices: [Tensor<DI, Int>; N],
+    ) -> Tensor<DO, K> {
+        let shape = self.shape();
+        let (flat_indices, indices_shape) = flatten_indices(&shape, indices);
+        let [num_rows, row_size] = leading_dims_split(&shape, N);
+
+        let output_shape = indexed_shape::<DO>("index", &shape, N, &indices_shape);
+
+        self.reshape([num_rows, row_size])
+            .select(0, flat_indices)
+            .reshape(output_shape)
+    }
+
+    /// Advanced index assignment: updates the elements selected by one index tensor for each of
+    /// the leading `N` dimensions, like `tensor[i0, i1, ...] = values` in NumPy or PyTorch.
+    ///
+    /// The index tensors are broadcast together, and `values` is broadcast to the shape of
+    /// [index](Tensor::index)'s output: the broadcast shape of the indices followed by the
+    /// remaining dimensions of the tensor.
+    ///
+    /// Negative indices count from the end of their dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The index tensors for the leading `N` dimensions.
+    /// * `values` - The values to write at the indexed positions.
+    /// * `update` - The operation used to update the existing values at the indexed positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{IndexingUpdateOp, Int, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2>::zeros([2, 3], &device);
+    ///   let rows = Tensor::<1, Int>::from_data([0, 1], &device);
+    ///   let cols = Tensor::<1, Int>::from_data([2, 0], &device);
+    ///   let values = Tensor::<1>::from_data([1.0, 2.0], &device);
+    ///   let result = tensor.index_put([rows, cols], values, IndexingUpdateOp::Assign);
+    ///   println!("{result}");
+    ///   // [[0.0, 0.0, 1.0], [2.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Duplicate indices have the same behavior as in [scatter_nd](Tensor::scatter_nd).
+    pub fn index_put<const N: usize, const DI: usize, const DV: usize>(
+        self,
+        indices: [Tensor<DI, Int>; N],
+        values: Tensor<DV, K>,
+        update: IndexingUpdateOp,
+    ) -> Self {
+        let shape = self.shape();
+        let (flat_indices, indices_shape) = flatten_indices(&shape, indices);
+        let [num_rows, row_size] = leading_dims_split(&shape, N);
+        let num_indices = flat_indices.dims()[0];
+
+        let values_shape = indexed_shape::<DV>("index_put", &shape, N, &indices_shape);
+        let values = values.expand(values_shape).reshape([num_indices, row_size]);
+
+        self.reshape([num_rows, row_size])
+            .scatter_nd(flat_indices.reshape([num_indices, 1]), values, update)
+            .reshape(shape)
+    }
+
+    /// Boolean mask indexing: selects the positions of the leading `M` dimensions where the mask
+    /// is `true`, like `tensor[mask]` in NumPy or PyTorch.
+    ///
+    /// The positions are taken in row-major order, and the output shape is the number of `true`
+    /// elements followed by the remaining dimensions of the tensor: `DO = 1 + D - M`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask of the leading `M` dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///   let mask = Tensor::<1, Bool>::from_data([true, false, true], &device);
+    ///   let result: Tensor<2> = tensor.index_mask(mask);
+    ///   println!("{result}");
+    ///   // [[1.0, 2.0], [5.0, 6.0]]
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// The output shape depends on the values of the mask, which are read synchronously from the
+    /// device. Prefer [mask_where](Tensor::mask_where) or [mask_fill](Tensor::mask_fill) when
+    /// the output may keep the shape of the tensor.
+    pub fn index_mask<const M: usize, const DO: usize>(
+        self,
+        mask: Tensor<M, Bool>,
+    ) -> Tensor<DO, K> {
+        let indices = mask_indices("index_mask", &self.shape(), mask);
+        self.index(indices)
+    }
+
+    /// Boolean mask index assignment: updates the positions of the leading `M` dimensions where
+    /// the mask is `true`, like `tensor[mask] = values` in NumPy or PyTorch.
+    ///
+    /// `values` is broadcast to the shape of [index_mask](Tensor::index_mask)'s output: the number
+    /// of `true` elements followed by the remaining dimensions of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask of the leading `M` dimensions of the tensor.
+    /// * `values` - The values to write at the masked positions, in row-major order.
+    /// * `update` - The operation used to update the existing values at the masked positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Bool, IndexingUpdateOp, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2>::zeros([3, 2], &device);
+    ///   let mask = Tensor::<1, Bool>::from_data([true, false, true], &device);
+    ///   let values = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///   let result = tensor.index_put_mask(mask, values, IndexingUpdateOp::Add);
+    ///   println!("{result}");
+    ///   // [[1.0, 2.0], [0.0, 0.0], [3.0, 4.0]]
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// The number of `true` elements is read synchronously from the device. To write a single
+    /// value, prefer [mask_fill](Tensor::mask_fill).
+    pub fn index_put_mask<const M: usize, const DV: usize>(
+        self,
+        mask: Tensor<M, Bool>,
+        values: Tensor<DV, K>,
+        update: IndexingUpdateOp,
+    ) -> Self {
+        let indices = mask_indices("index_put_mask", &self.shape(), mask);
+        self.index_put(indices, values, update)
+    }
+}
+
+/// The indices of the `true` elements of a mask over the leading dimensions of `shape`, one index
+/// tensor for each dimension of the mask.
+fn mask_indices<const M: usize>(
+    op: &str,
+    shape: &Shape,
+    mask: Tensor<M, Bool>,
+) -> [Tensor<1, Int>; M] {
+    let mask_shape = mask.shape();
+    assert!(
+        M <= shape.num_dims() && mask_shape.as_slice() == &shape.as_slice()[..M],
+        "{op} expects a mask with the leading dimensions of the tensor {shape:?}, got {mask_shape:?}"
+    );
+
+    let positions = mask.argwhere();
+    let num_positions = positions.dims()[0];
+
+    core::array::from_fn(|dim| positions.clone().narrow(1, dim, 1).reshape([num_positions]))
+}
+
+/// Combines the per-dimension indices into row-major indices over the leading dimensions.
+///
+/// Returns the flattened indices and their broadcast shape.
+fn flatten_indices<const N: usize, const DI: usize>(
+    shape: &Shape,
+    indices: [Tensor<DI, Int>; N],
+) -> (Tensor<1, Int>, [usize; DI]) {
+    assert!(
+        N > 0 && N <= shape.num_dims(),
+        "Advanced indexing expects between 1 and {} index tensors, got {N}",
+        shape.num_dims()
+    );
+
+    let mut flat: Option<Tensor<DI, Int>> = None;
+    let mut stride = 1;
+    for (dim, index) in indices.into_iter().enumerate().rev() {
+        let size = shape[dim] as i64;
+        let index = index
+            .add_scalar(size)
+            .remainder_scalar(size)
+            .mul_scalar(stride);
+        flat = Some(match flat {
+            Some(flat) => flat.add(index),
+            None => index,
+        });
+        stride *= size;
+    }
+
+    let flat = flat.unwrap();
+    let dims = flat.dims();
+    let num_indices = dims.iter().product::<usize>();
+
+    (flat.reshape([num_indices]), dims)
+}
+
+/// The number of positions in the `num_indexed` leading dimensions, and the size of the rest.
+fn leading_dims_split(shape: &Shape, num_indexed: usize) -> [usize; 2] {
+    let dims = shape.as_slice();
+
+    [
+        dims[..num_indexed].iter().product(),
+        dims[num_indexed..].iter().product(),
+    ]
+}
+
+/// The indices shape followed by the dimensions that aren't indexed.
+fn indexed_shape<const DO: usize>(
+    op: &str,
+    shape: &Shape,
+    num_indexed: usize,
+    indices_shape: &[usize],
+) -> [usize; DO] {
+    let dims = indices_shape
+        .iter()
+        .chain(&shape.as_slice()[num_indexed..])
+        .copied()
+        .collect::<Vec<_>>();
+
+    dims.try_into().unwrap_or_else(|dims: Vec<usize>| {
+        panic!(
+            "{op} with {num_indexed} index tensors of rank {} on a tensor of rank {} produces rank {}, got {DO}",
+            indices_shape.len(),
+            shape.num_dims(),
+            dims.len()
+        )
+    })
+}
@@ -209,6 +209,59 @@ impl<const D: usize> Tensor<D, Int> {
         )))
     }
 
+    /// Counts the number of bits set to one in each integer of the tensor.
+    ///
+    /// Negative integers are counted in their two's complement representation, so `-1` has all
+    /// the bits of the data type set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1, Int>::from_data([0, 1, 7, 255], &device);
+    ///     let counts = tensor.popcount();
+    ///     println!("{counts}");
+    ///     // [0, 1, 3, 8]
+    /// }
+    /// ```
+    pub fn popcount(self) -> Self {
+        let bits = self.dtype().size() * 8;
+        let repeat = |byte: i64| (0..bits / 8).fold(0i64, |mask, _| (mask << 8) | byte);
+
+        let sign = self
+            .clone()
+            .bitwise_right_shift_scalar(bits as i64 - 1)
+            .bitwise_and_scalar(1);
+        // Without the sign bit, the partial counts below can't overflow signed integers.
+        let x = self.bitwise_and_scalar((u64::MAX >> (65 - bits)) as i64);
+
+        // Count the bits of each pair, then of each nibble, then of each byte.
+        let x = x.clone().bitwise_and_scalar(repeat(0x55)).add(
+            x.bitwise_right_shift_scalar(1)
+                .bitwise_and_scalar(repeat(0x55)),
+        );
+        let x = x.clone().bitwise_and_scalar(repeat(0x33)).add(
+            x.bitwise_right_shift_scalar(2)
+                .bitwise_and_scalar(repeat(0x33)),
+        );
+        let mut x = x
+            .clone()
+            .add(x.bitwise_right_shift_scalar(4))
+            .bitwise_and_scalar(repeat(0x0f));
+
+        // Sum the counts of the bytes into the lowest byte.
+        let mut shift = 8;
+        while shift < bits {
+            x = x.clone().add(x.bitwise_right_shift_scalar(shift as i64));
+            shift *= 2;
+        }
+
+        x.bitwise_and_scalar(0x7f).add(sign)
+    }
+
     /// Converts a tensor to the specified data type.
     ///
     /// Supports both within-kind casting (e.g., `IntDType::I64`) and cross-kind casting
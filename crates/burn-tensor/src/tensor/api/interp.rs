@@ -0,0 +1,127 @@
+use crate::{Float, Tensor};
+
+impl<const D: usize> Tensor<D, Float> {
+    /// Evaluates a polynomial at each element of the tensor.
+    ///
+    /// The coefficients are ordered from the highest degree to the constant term, as in
+    /// `numpy.polyval`, and the polynomial is evaluated with Horner's method. Since the
+    /// coefficients are a tensor, they can be learned, e.g. for polynomial activations.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - The coefficients `[c_0, ..., c_n]` of `c_0 x^n + ... + c_n`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of the same shape as the input, filled with zeros if there are no coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.0, 1.0, 2.0], &device);
+    ///     let coefficients = Tensor::<1>::from_data([3.0, 0.0, 1.0], &device);
+    ///     println!("{}", tensor.polyval(coefficients));
+    ///     // [1.0, 4.0, 13.0]
+    /// }
+    /// ```
+    pub fn polyval(self, coefficients: Tensor<1>) -> Self {
+        let [num_coefficients] = coefficients.dims();
+        let coefficient = |i: usize| coefficients.clone().slice(i..i + 1).reshape([1; D]);
+
+        let mut output = self.zeros_like();
+        for i in 0..num_coefficients {
+            let term = coefficient(i);
+            output = match i {
+                0 => output.add(term),
+                _ => output.mul(self.clone()).add(term),
+            };
+        }
+
+        output
+    }
+
+    /// Piecewise-linear interpolation along the last dimension, as in `numpy.interp`.
+    ///
+    /// Each row of the tensor is interpolated on the sample points `xp` with values `fp` of the
+    /// matching row, which makes it suitable for lookup tables such as color curves. The leading
+    /// dimensions of `xp` and `fp` are broadcast to the ones of the tensor, so a single table of
+    /// shape `[1, ..., 1, M]` is shared by all rows. Inputs below `xp[0]` or above `xp[M - 1]`
+    /// take the value of the first or last point.
+    ///
+    /// Each input is located by comparing it with every sample point of its row, which uses
+    /// memory proportional to `N * M` for rows of `N` inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `xp` - The increasing sample points, of shape `[..., M]`.
+    /// * `fp` - The values at the sample points, of the same shape as `xp`.
+    ///
+    /// # Panics
+    ///
+    /// If `xp` and `fp` have different shapes, if there are less than two sample points, or if
+    /// their leading dimensions can't be broadcast to the ones of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([-1.0, 0.5, 1.5, 3.0], &device);
+    ///     let xp = Tensor::<1>::from_data([0.0, 1.0, 2.0], &device);
+    ///     let fp = Tensor::<1>::from_data([0.0, 10.0, 0.0], &device);
+    ///     println!("{}", tensor.interp(xp, fp));
+    ///     // [0.0, 5.0, 5.0, 0.0]
+    /// }
+    /// ```
+    pub fn interp(self, xp: Self, fp: Self) -> Self {
+        let dims = self.dims();
+        let num_points = xp.dims()[D - 1];
+        assert_eq!(
+            xp.dims(),
+            fp.dims(),
+            "interp expects sample points and values of the same shape"
+        );
+        assert!(
+            num_points >= 2,
+            "interp expects at least two sample points, got {num_points}"
+        );
+
+        let num_inputs = dims[D - 1];
+        let batch_size = dims[..D - 1].iter().product::<usize>();
+        let mut table_shape = dims;
+        table_shape[D - 1] = num_points;
+
+        let x: Tensor<2> = self.reshape([batch_size, num_inputs]);
+        let xp: Tensor<2> = xp.expand(table_shape).reshape([batch_size, num_points]);
+        let fp: Tensor<2> = fp.expand(table_shape).reshape([batch_size, num_points]);
+
+        // Index of the first sample point greater than each input, clamped to an inner segment
+        // so that the inputs out of range are extrapolated from the first or last segment.
+        let upper = x
+            .clone()
+            .unsqueeze_dim::<3>(2)
+            .greater_equal(xp.clone().unsqueeze_dim(1))
+            .int()
+            .sum_dim(2)
+            .reshape([batch_size, num_inputs])
+            .clamp(1, num_points as i64 - 1);
+        let lower = upper.clone().sub_scalar(1);
+
+        let x0 = xp.clone().gather(1, lower.clone());
+        let x1 = xp.gather(1, upper.clone());
+        let f0 = fp.clone().gather(1, lower);
+        let f1 = fp.gather(1, upper);
+
+        // Clamping the weights turns the extrapolation into the value of the nearest end point.
+        let weight = (x - x0.clone()).div(x1 - x0).clamp(0.0, 1.0);
+        let output = f0.clone() + (f1 - f0) * weight;
+
+        output.reshape(dims)
+    }
+}
@@ -6,14 +6,20 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod cast;
+mod einops;
 mod float;
 mod fmod;
+mod histogram;
+mod index;
 mod int;
+mod interp;
 mod numeric;
 mod options;
 mod orderable;
 mod pad;
 pub use pad::IntoPadding;
+mod segment;
+mod special;
 mod take;
 mod transaction;
 
@@ -24,6 +30,7 @@ pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use cast::*;
+pub use einops::PatternReduction;
 pub use float::{DEFAULT_ATOL, DEFAULT_RTOL};
 pub use options::*;
 pub use transaction::*;
@@ -901,6 +901,78 @@ where
 
         Tensor::new(K::matmul(self.primitive, other.primitive))
     }
+
+    /// Fold windows back along a dimension, the inverse of [unfold](Tensor::unfold).
+    ///
+    /// The input has the shape `[pre=..., windows, post=..., size]` returned by `unfold`, and
+    /// window `w` is written at `w * step` in dimension `dim` of the output. The values of
+    /// overlapping windows are summed, like PyTorch's `Fold`; folding a tensor of ones gives the
+    /// number of windows covering each position, to average them instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - the dimension of the windows.
+    /// * `length` - the size of the folded dimension, at least `(windows - 1) * step + size`.
+    /// * `step` - the step between each window.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the shape `[pre=..., length, post=...]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<1>::from_data([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+    ///    let windows = tensor.unfold::<2, _>(0, 3, 2);
+    ///    // [[1.0, 2.0, 3.0], [3.0, 4.0, 5.0]]
+    ///    let folded = windows.fold::<1, _>(0, 5, 2);
+    ///    println!("{folded}");
+    ///    // [1.0, 2.0, 6.0, 4.0, 5.0]
+    /// }
+    /// ```
+    pub fn fold<const D2: usize, I: AsIndex>(
+        self,
+        dim: I,
+        length: usize,
+        step: usize,
+    ) -> Tensor<D2, K> {
+        let dim = dim.expect_dim_index(D);
+        check!(TensorCheck::fold::<D, D2>(
+            "fold",
+            &self.shape(),
+            dim,
+            length,
+            step,
+        ));
+
+        let shape = self.shape();
+        let (num_windows, size) = (shape[dim], shape[D - 1]);
+        let device = self.device();
+        let mut output_shape = [0; D2];
+        output_shape.copy_from_slice(&shape[..D2]);
+        output_shape[dim] = length;
+        let output = Tensor::zeros(output_shape, (&device, self.dtype()));
+        if num_windows == 0 || size == 0 {
+            return output;
+        }
+
+        // The position of each window element in the folded dimension: `window * step + offset`.
+        let starts = Tensor::<1, Int>::arange_step(0..(num_windows * step) as i64, step, &device);
+        let offsets = Tensor::<1, Int>::arange(0..size as i64, &device);
+        let indices = (starts.unsqueeze_dim::<2>(1) + offsets.unsqueeze::<2>())
+            .reshape([num_windows * size]);
+
+        // Move the window elements next to their window, and merge the two dimensions.
+        let mut values_shape = output_shape;
+        values_shape[dim] = num_windows * size;
+        let values = self.movedim(D - 1, dim + 1).reshape(values_shape);
+
+        output.select_assign(dim, indices, values, IndexingUpdateOp::Add)
+    }
 }
 
 impl<K> Tensor<1, K>
@@ -956,6 +1028,49 @@ where
     }
 }
 
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Numeric,
+{
+    /// Creates a batch of matrices with ones on the main diagonal and zeros elsewhere.
+    ///
+    /// The last two dimensions of `shape` are the (possibly rectangular) matrices, and the leading
+    /// dimensions are the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor, `[..., rows, cols]`.
+    /// * `options` - The device and optional data type of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<3>::eye_batched([2, 2, 3], &device);
+    ///    println!("{tensor}");
+    ///    // [[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    ///    //  [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]
+    /// }
+    /// ```
+    pub fn eye_batched<S: Into<Shape>>(
+        shape: S,
+        options: impl Into<TensorCreationOptions>,
+    ) -> Self {
+        let shape: Shape = shape.into();
+        assert!(D >= 2, "eye_batched requires a tensor of rank 2 or more");
+        let opt = options.into();
+
+        let diagonal = Tensor::<D, Bool>::diag_mask(shape.clone(), 0, &opt.device)
+            .bool_not()
+            .expand(shape.clone());
+
+        Self::zeros(shape, opt).mask_fill(diagonal, 1)
+    }
+}
+
 // Tensor + tensor
 impl<const D: usize, K: Numeric> core::ops::Add<Self> for Tensor<D, K> {
     type Output = Self;
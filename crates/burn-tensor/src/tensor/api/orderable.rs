@@ -1,8 +1,10 @@
+use alloc::vec;
 use burn_backend::{ElementConversion, Scalar};
 use burn_std::{AsIndex, IndexingUpdateOp};
 
 use crate::kind::Ordered;
-use crate::{Bool, Int, check};
+use crate::tensor::stats;
+use crate::{Bool, Int, IntDType, check};
 use crate::{Tensor, check::TensorCheck};
 
 impl<const D: usize, K> Tensor<D, K>
@@ -195,6 +197,68 @@ where
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
     }
 
+    /// Sort the elements by value along a given dimension, with a stable order.
+    /// Also returns the indices.
+    ///
+    /// Unlike [sort_with_indices](Tensor::sort_with_indices), this sort is stable: equal elements
+    /// keep their original order, in both ascending and descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to sort along.
+    /// * `descending` - If `true`, sort in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<1>::from_data([2.0, 5.0, 2.0, 5.0], &device);
+    ///    let (tensor, indices) = tensor.sort_stable_with_indices(0, true);
+    ///    println!("{tensor}");
+    ///    // [5.0, 5.0, 2.0, 2.0]
+    ///    println!("{indices}");
+    ///    // [1, 3, 0, 2]
+    /// }
+    /// ```
+    pub fn sort_stable_with_indices(self, dim: usize, descending: bool) -> (Self, Tensor<D, Int>) {
+        check!(TensorCheck::sort_dim::<D>("Sort_stable_with_indices", dim));
+        let (values, indices) = K::sort_with_indices(self.primitive, dim, descending);
+        let (values, indices) = (Tensor::new(values), Tensor::new(indices));
+        let indices = stable_ties(values.clone(), indices, dim);
+        (values, indices)
+    }
+
+    /// Returns the indices that sort the elements by value along a given dimension, with a stable
+    /// order.
+    ///
+    /// Unlike [argsort](Tensor::argsort), this sort is stable: equal elements keep their original
+    /// order, in both ascending and descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to sort along.
+    /// * `descending` - If `true`, sort in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<2>::from_data([[3.0, 1.0, 3.0], [1.0, 1.0, 2.0]], &device);
+    ///    let tensor = tensor.argsort_stable(1, false);
+    ///    println!("{tensor}");
+    ///    // [[1, 0, 2], [0, 1, 2]]
+    /// }
+    /// ```
+    pub fn argsort_stable(self, dim: usize, descending: bool) -> Tensor<D, Int> {
+        self.sort_stable_with_indices(dim, descending).1
+    }
+
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     ///
     /// # Arguments
@@ -222,7 +286,7 @@ where
     /// }
     /// ```
     pub fn topk(self, k: usize, dim: usize) -> Self {
-        assert!(self.shape()[dim] > k);
+        assert!(self.shape()[dim] >= k);
         Tensor::new(K::topk(self.primitive, dim, k))
     }
 
@@ -263,6 +327,62 @@ where
         )
     }
 
+    /// Returns the `k` largest elements of the given input tensor along a given dimension.
+    /// Also returns the indices.
+    ///
+    /// Equal elements are ranked by their original order, so that the selection is deterministic
+    /// on all backends. When `sorted` is `true`, the elements are returned in descending order,
+    /// otherwise they are returned in their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of elements to return.
+    /// * `dim` - The dimension to select along.
+    /// * `sorted` - If `true`, sort the returned elements in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<1>::from_data([1.0, 3.0, 2.0, 3.0], &device);
+    ///    let (values, indices) = tensor.clone().topk_with_indices_sorted(3, 0, true);
+    ///    println!("{values} {indices}");
+    ///    // [3.0, 3.0, 2.0] [1, 3, 2]
+    ///    let (values, indices) = tensor.topk_with_indices_sorted(3, 0, false);
+    ///    println!("{values} {indices}");
+    ///    // [3.0, 2.0, 3.0] [1, 2, 3]
+    /// }
+    /// ```
+    pub fn topk_with_indices_sorted(
+        self,
+        k: usize,
+        dim: usize,
+        sorted: bool,
+    ) -> (Self, Tensor<D, Int>) {
+        check!(TensorCheck::sort_dim::<D>("Topk", dim));
+        assert!(
+            k <= self.shape()[dim],
+            "Topk: k ({k}) must not exceed the size of dimension {dim} ({})",
+            self.shape()[dim]
+        );
+        let (values, indices) = self.sort_stable_with_indices(dim, true);
+        let values = values.narrow(dim, 0, k);
+        let indices = indices.narrow(dim, 0, k);
+
+        if sorted {
+            return (values, indices);
+        }
+
+        let order = indices.clone().argsort(dim);
+        (
+            values.gather(dim, order.clone()),
+            indices.gather(dim, order),
+        )
+    }
+
     /// Create a one hot tensor.
     ///
     /// # Example
@@ -1017,6 +1137,43 @@ where
         Self::new(K::clamp_max(self.primitive, max))
     }
 
+    /// Clamp element wise between the given min and max tensors.
+    ///
+    /// The bounds are broadcast to the shape of the tensor, so a bound of shape `[1, C]` clamps
+    /// each column of a `[N, C]` tensor to its own range. For a single bound, use
+    /// [max_pair](Tensor::max_pair) or [min_pair](Tensor::min_pair).
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum values.
+    /// * `max` - The maximum values.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the values clamped between the given min and max values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<2>::from_data([[1.0, 5.0, 9.0], [-1.0, 4.0, 7.0]], &device);
+    ///    let min = Tensor::<2>::from_data([[0.0, 0.0, 8.0]], &device);
+    ///    let max = Tensor::<2>::from_data([[2.0, 4.0, 10.0]], &device);
+    ///    let tensor = tensor.clamp_tensor(min, max);
+    ///    println!("{tensor}");
+    ///    // [[1.0, 4.0, 9.0], [0.0, 4.0, 8.0]]
+    /// }
+    /// ```
+    pub fn clamp_tensor(self, min: Self, max: Self) -> Self {
+        let shape = self.shape();
+
+        self.max_pair(min.expand(shape.clone()))
+            .min_pair(max.expand(shape))
+    }
+
     /// Computes the cumulative minimum of elements along the given *dimension* or *axis*.
     ///
     /// # Arguments
@@ -1129,4 +1286,139 @@ where
     pub fn max_dims<I: AsIndex>(self, dims: &[I]) -> Self {
         dims.iter().fold(self, |tensor, &dim| tensor.max_dim(dim))
     }
+
+    /// Find the median value along the given dimension.
+    ///
+    /// For an even number of elements, the lower of the two medians is returned,
+    /// following PyTorch's behavior.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to aggregate the elements;
+    ///   supports negative indexing.
+    ///
+    /// # Returns
+    ///
+    /// The returned tensor will have the same rank,
+    /// but the aggregated dimension will have size 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2, Int>::from_data([[1, 5, 3, 2], [8, 4, 6, 7]], &device);
+    ///   let tensor = tensor.median_dim(1);
+    ///   println!("{tensor}");
+    ///   // [[2], [6]]
+    /// }
+    /// ```
+    pub fn median_dim<I: AsIndex>(self, dim: I) -> Self {
+        let dim = dim.expect_dim_index(D);
+        check!(TensorCheck::aggregate_dim::<D>("Median", dim));
+        stats::median(self, dim)
+    }
+
+    /// Find the most frequent value along the given dimension.
+    ///
+    /// When several values are equally frequent, the smallest one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to aggregate the elements;
+    ///   supports negative indexing.
+    ///
+    /// # Returns
+    ///
+    /// The returned tensor will have the same rank,
+    /// but the aggregated dimension will have size 1.
+    ///
+    /// # Note
+    ///
+    /// The elements are sorted along the dimension, and the occurrences are counted in a single
+    /// pass over the runs of equal sorted values. The cost is dominated by the sort, and the memory
+    /// usage grows linearly with the size of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2, Int>::from_data([[1, 3, 3, 2], [4, 4, 1, 1]], &device);
+    ///   let tensor = tensor.mode_dim(1);
+    ///   println!("{tensor}");
+    ///   // [[3], [1]]
+    /// }
+    /// ```
+    pub fn mode_dim<I: AsIndex>(self, dim: I) -> Self {
+        self.mode_dim_with_indices(dim).0
+    }
+
+    /// Find the most frequent value along the given dimension, and one of its indices.
+    ///
+    /// When several values are equally frequent, the smallest one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to aggregate the elements;
+    ///   supports negative indexing.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the mode values and the index of one of their occurrences in the original
+    /// tensor. The returned tensors will have the same rank, but the aggregated dimension will
+    /// have size 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///   let device = Default::default();
+    ///   let tensor = Tensor::<2, Int>::from_data([[1, 3, 2, 3], [4, 4, 1, 1]], &device);
+    ///   let (values, indices) = tensor.mode_dim_with_indices(1);
+    ///   println!("{values}");
+    ///   // [[3], [1]]
+    /// }
+    /// ```
+    pub fn mode_dim_with_indices<I: AsIndex>(self, dim: I) -> (Self, Tensor<D, Int>) {
+        let dim = dim.expect_dim_index(D);
+        check!(TensorCheck::aggregate_dim::<D>("Mode", dim));
+        stats::mode_with_indices(self, dim)
+    }
+}
+
+/// Reorder the indices of a sorted tensor so that the indices of equal values are ascending.
+///
+/// Equal values are contiguous after sorting, so each run of equal values is given an id, and the
+/// indices are sorted by run first and original position second.
+fn stable_ties<const D: usize, K: Ordered>(
+    values: Tensor<D, K>,
+    indices: Tensor<D, Int>,
+    dim: usize,
+) -> Tensor<D, Int> {
+    let size = values.shape()[dim];
+    if size < 2 {
+        return indices;
+    }
+
+    let changed = values
+        .clone()
+        .narrow(dim, 1, size - 1)
+        .not_equal(values.narrow(dim, 0, size - 1))
+        .int();
+    let first = changed.clone().narrow(dim, 0, 1).zeros_like();
+    let runs = Tensor::cat(vec![first, changed], dim).cumsum(dim);
+
+    // The keys are unique, so an unstable sort is enough.
+    let keys = runs
+        .cast(IntDType::I64)
+        .mul_scalar(size as i64)
+        .add(indices.clone().cast(IntDType::I64));
+    indices.gather(dim, keys.argsort(dim))
 }
@@ -118,7 +118,7 @@ where
     ///   - `&[(before, after)]` slice of pairs per dimension
     ///   - `Vec<(before, after)>` vector of pairs
     ///   - `(left, right, top, bottom)` tuple for last-2-dim backward compatibility
-    /// * `mode` - The padding mode: `Constant(value)`, `Reflect`, or `Edge`.
+    /// * `mode` - The padding mode: `Constant(value)`, `Reflect`, `Edge` or `Circular`.
     ///
     /// # Returns
     ///
@@ -129,6 +129,7 @@ where
     /// - Panics if more padding pairs are provided than tensor dimensions.
     /// - `Reflect` mode panics if padding exceeds `dimension_size - 1`.
     /// - `Edge` mode panics if padding is applied to a zero-sized dimension.
+    /// - `Circular` mode panics if padding exceeds `dimension_size`.
     ///
     /// # Example
     ///
@@ -156,6 +157,7 @@ where
             PadMode::Constant(value) => pad_constant(self, &pairs, value),
             PadMode::Reflect => pad_reflect(self, &pairs),
             PadMode::Edge => pad_edge(self, &pairs),
+            PadMode::Circular => pad_circular(self, &pairs),
         }
     }
 }
@@ -343,3 +345,71 @@ where
 
     output
 }
+
+/// Pad by wrapping values around from the opposite boundary.
+///
+/// Example: `[1, 2, 3, 4]` with left padding 2 becomes `[3, 4, 1, 2, 3, 4]`
+fn pad_circular<const D: usize, K>(
+    tensor: Tensor<D, K>,
+    padding: &[(usize, usize); D],
+) -> Tensor<D, K>
+where
+    K: Numeric,
+{
+    let dims = tensor.dims();
+
+    for (i, &(before, after)) in padding.iter().enumerate() {
+        if before > 0 || after > 0 {
+            assert!(
+                before <= dims[i] && after <= dims[i],
+                "Circular padding ({}, {}) must not exceed dimension {} size ({})",
+                before,
+                after,
+                i,
+                dims[i]
+            );
+        }
+    }
+
+    let mut result = tensor;
+
+    for (i, &(before, after)) in padding.iter().enumerate() {
+        if before > 0 || after > 0 {
+            result = pad_circular_dim(result, i, before, after);
+        }
+    }
+
+    result
+}
+
+/// Helper to pad a single dimension by wrapping values around.
+fn pad_circular_dim<const D: usize, K>(
+    tensor: Tensor<D, K>,
+    dim: usize,
+    pad_before: usize,
+    pad_after: usize,
+) -> Tensor<D, K>
+where
+    K: Numeric,
+{
+    let dim_size = tensor.dims()[dim];
+    let mut parts = Vec::with_capacity(3);
+
+    // The "before" padding is the end of the tensor
+    if pad_before > 0 {
+        parts.push(
+            tensor
+                .clone()
+                .narrow(dim, dim_size - pad_before, pad_before),
+        );
+    }
+
+    parts.push(tensor.clone());
+
+    // The "after" padding is the start of the tensor
+    if pad_after > 0 {
+        parts.push(tensor.narrow(dim, 0, pad_after));
+    }
+
+    Tensor::cat(parts, dim)
+}
@@ -0,0 +1,200 @@
+use alloc::vec;
+
+use crate::kind::{Numeric, Ordered};
+use crate::{Float, IndexingUpdateOp, Int, Tensor};
+
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Numeric,
+{
+    /// Sums the slices of the tensor along the first dimension that share the same segment id.
+    ///
+    /// `output[s, ...] = sum(input[i, ...] for i where segment_ids[i] == s)`
+    ///
+    /// The segment ids don't need to be sorted, and the segments without any slice are zero.
+    /// The sums are computed on the device with a scatter-add, like the message aggregation of
+    /// graph neural networks.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_ids` - The segment of each slice, of shape `[input.dims()[0]]`, with values in
+    ///   `[0, num_segments)`.
+    /// * `num_segments` - The number of segments.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[num_segments, ...]`, with the same trailing dimensions as the input.
+    ///
+    /// # Warning
+    ///
+    /// Not all backends have runtime bound checks for the indices, so make sure the segment ids
+    /// are valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///     let segment_ids = Tensor::<1, Int>::from_data([1, 0, 1], &device);
+    ///     let output = tensor.segment_sum(segment_ids, 3);
+    ///     println!("{output}");
+    ///     // [[3.0, 4.0], [6.0, 8.0], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn segment_sum(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        let shape = self.segment_output_shape(&segment_ids, num_segments);
+        let output = Self::zeros(shape, (&self.device(), self.dtype()));
+
+        output.select_assign(0, segment_ids, self, IndexingUpdateOp::Add)
+    }
+
+    /// The output shape of the segment reductions, `[num_segments, ...]`.
+    fn segment_output_shape(
+        &self,
+        segment_ids: &Tensor<1, Int>,
+        num_segments: usize,
+    ) -> [usize; D] {
+        let mut dims = self.dims();
+        let [num_ids] = segment_ids.dims();
+        assert_eq!(
+            dims[0], num_ids,
+            "Segment reductions expect one segment id per slice, got {num_ids} ids for {} slices",
+            dims[0]
+        );
+        dims[0] = num_segments;
+
+        dims
+    }
+}
+
+impl<const D: usize> Tensor<D, Float> {
+    /// Averages the slices of the tensor along the first dimension that share the same segment
+    /// id.
+    ///
+    /// `output[s, ...] = mean(input[i, ...] for i where segment_ids[i] == s)`
+    ///
+    /// The segment ids don't need to be sorted, and the segments without any slice are zero.
+    /// See [segment_sum](Tensor::segment_sum) for the arguments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///     let segment_ids = Tensor::<1, Int>::from_data([1, 0, 1], &device);
+    ///     let output = tensor.segment_mean(segment_ids, 3);
+    ///     println!("{output}");
+    ///     // [[3.0, 4.0], [3.0, 4.0], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn segment_mean(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        let [num_ids] = segment_ids.dims();
+        let options = (&self.device(), self.dtype());
+        let counts = Tensor::<1>::zeros([num_segments], options).select_assign(
+            0,
+            segment_ids.clone(),
+            Tensor::ones([num_ids], options),
+            IndexingUpdateOp::Add,
+        );
+        let mut counts_shape = [1; D];
+        counts_shape[0] = num_segments;
+
+        self.segment_sum(segment_ids, num_segments) / counts.clamp_min(1.0).reshape(counts_shape)
+    }
+}
+
+impl<const D: usize, K> Tensor<D, K>
+where
+    K: Ordered,
+{
+    /// Takes the maximum of the slices of the tensor along the first dimension that share the
+    /// same segment id.
+    ///
+    /// `output[s, ...] = max(input[i, ...] for i where segment_ids[i] == s)`
+    ///
+    /// The segment ids don't need to be sorted, and the segments without any slice are zero.
+    /// See [segment_sum](Tensor::segment_sum) for the arguments.
+    ///
+    /// # Notes
+    ///
+    /// Scattering with a max reduction isn't deterministic on all backends when several slices
+    /// share a segment. Instead, the slices are sorted by segment id and a segmented prefix max
+    /// is computed in `log2(n)` steps, after which the last slice of each segment holds the
+    /// maximum of its segment. The result is exact on every backend, at the cost of `log2(n)`
+    /// elementwise passes over the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<2>::from_data([[1.0, 6.0], [3.0, 4.0], [5.0, 2.0]], &device);
+    ///     let segment_ids = Tensor::<1, Int>::from_data([1, 0, 1], &device);
+    ///     let output = tensor.segment_max(segment_ids, 3);
+    ///     println!("{output}");
+    ///     // [[3.0, 4.0], [5.0, 6.0], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn segment_max(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        let output_shape = self.segment_output_shape(&segment_ids, num_segments);
+        let [num_ids] = segment_ids.dims();
+        if num_ids == 0 {
+            return Self::zeros(output_shape, (&self.device(), self.dtype()));
+        }
+
+        let (sorted_ids, order) = segment_ids.clone().sort_with_indices(0);
+        let mut scanned = self.select(0, order);
+
+        // Segmented prefix max (Hillis-Steele): after the step of size `step`, each slice holds
+        // the maximum of the last `2 * step` slices of its segment.
+        let mut step = 1;
+        while step < num_ids {
+            let current = scanned.clone().slice_dim(0, step..num_ids);
+            let previous = scanned.clone().slice_dim(0, 0..num_ids - step);
+            let same_segment = sorted_ids
+                .clone()
+                .slice(step..num_ids)
+                .equal(sorted_ids.clone().slice(0..num_ids - step));
+            let mut mask_shape = [1; D];
+            mask_shape[0] = num_ids - step;
+            let same_segment = same_segment
+                .reshape(mask_shape)
+                .expand(current.shape());
+
+            let updated = current.clone().mask_where(same_segment, current.max_pair(previous));
+            scanned = Tensor::cat(vec![scanned.slice_dim(0, 0..step), updated], 0);
+            step *= 2;
+        }
+
+        // The segments are contiguous once sorted, so the last slice of segment `s` is at the
+        // number of slices of the segments up to `s`, minus one.
+        let options = (&sorted_ids.device(), sorted_ids.dtype());
+        let counts = Tensor::<1, Int>::zeros([num_segments], options).select_assign(
+            0,
+            segment_ids,
+            sorted_ids.ones_like(),
+            IndexingUpdateOp::Add,
+        );
+        let last = counts
+            .clone()
+            .cumsum(0)
+            .sub_scalar(1)
+            .clamp(0, num_ids as i64 - 1);
+        let mut empty_shape = [1; D];
+        empty_shape[0] = num_segments;
+        let empty = counts
+            .equal_elem(0)
+            .reshape(empty_shape)
+            .expand(output_shape);
+
+        scanned.select(0, last).mask_fill(empty, 0)
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{Bool, Float, Tensor};
+
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+/// `ln(π)`.
+const LN_PI: f64 = 1.1447298858494002;
+
+/// `sqrt(2π)`.
+const SQRT_2PI: f64 = 2.5066282746310005;
+
+/// Coefficients of the Lanczos approximation of the gamma function (Numerical Recipes, `gammln`).
+const LANCZOS_COEFFICIENTS: [f64; 6] = [
+    76.18009172947146,
+    -86.50532032941677,
+    24.01409824083091,
+    -1.231739572450155,
+    0.1208650973866179e-2,
+    -0.5395239384953e-5,
+];
+
+/// Number of terms of the series and of the continued fraction of the incomplete gamma function,
+/// which converge for shape parameters up to about `100`.
+const GAMMAINC_ITERATIONS: usize = 64;
+
+/// Number of terms of the continued fraction of the incomplete beta function.
+const BETAINC_ITERATIONS: usize = 32;
+
+/// Smallest magnitude of the terms of the modified Lentz's continued fractions.
+const LENTZ_TINY: f64 = 1e-30;
+
+impl<const D: usize> Tensor<D, Float> {
+    /// Applies the inverse of the [error function](Tensor::erf) element wise.
+    ///
+    /// The inputs are expected in `[-1, 1]`: `erfinv(±1)` returns `±∞`, and inputs outside of the
+    /// interval return NaN.
+    ///
+    /// The initial approximation of M. Giles, "Approximating the erfinv function", is refined with
+    /// a Newton step.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.0, 0.5, -0.9], &device);
+    ///     println!("{}", tensor.erfinv());
+    ///     // [0.0, 0.4769363, -1.1630872]
+    /// }
+    /// ```
+    pub fn erfinv(self) -> Self {
+        let w = (self.clone().neg().add_scalar(1.0) * self.clone().add_scalar(1.0))
+            .log()
+            .neg();
+
+        let central = polynomial(
+            w.clone().sub_scalar(2.5),
+            &[
+                2.81022636e-08,
+                3.43273939e-07,
+                -3.5233877e-06,
+                -4.39150654e-06,
+                0.00021858087,
+                -0.00125372503,
+                -0.00417768164,
+                0.246640727,
+                1.50140941,
+            ],
+        );
+        let tail = polynomial(
+            w.clone().sqrt().sub_scalar(3.0),
+            &[
+                -0.000200214257,
+                0.000100950558,
+                0.00134934322,
+                -0.00367342844,
+                0.00573950773,
+                -0.0076224613,
+                0.00943887047,
+                1.00167406,
+                2.83297682,
+            ],
+        );
+        let estimate = central.mask_where(w.greater_equal_elem(5.0), tail) * self.clone();
+
+        // Newton step on `erf(y) - x`.
+        let derivative = estimate
+            .clone()
+            .square()
+            .neg()
+            .exp()
+            .mul_scalar(FRAC_2_SQRT_PI);
+        let refined = estimate.clone() - (estimate.erf() - self.clone()) / derivative;
+
+        let bound = self.clone().abs().equal_elem(1.0);
+        refined.mask_where(bound, self.mul_scalar(f64::INFINITY))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the gamma function element wise.
+    ///
+    /// At the poles of the gamma function, the non-positive integers, the result is `+∞` or a large
+    /// value due to the rounding of `sin(πx)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.5, 1.0, 10.0, -0.5], &device);
+    ///     println!("{}", tensor.lgamma());
+    ///     // [0.5723649, 0.0, 12.8018275, 1.2655121]
+    /// }
+    /// ```
+    pub fn lgamma(self) -> Self {
+        let reflected = self.clone().lower_elem(0.5);
+        let z = self
+            .clone()
+            .mask_where(reflected.clone(), self.clone().neg().add_scalar(1.0));
+        let lgamma = lanczos_lgamma(z);
+
+        // Reflection formula: lgamma(x) = ln(π / |sin(πx)|) - lgamma(1 - x).
+        let reflection = self
+            .mul_scalar(PI)
+            .sin()
+            .abs()
+            .log()
+            .neg()
+            .add_scalar(LN_PI)
+            - lgamma.clone();
+
+        lgamma.mask_where(reflected, reflection)
+    }
+
+    /// Applies the digamma function, the logarithmic derivative of the gamma function, element
+    /// wise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([1.0, 0.5, 10.0], &device);
+    ///     println!("{}", tensor.digamma());
+    ///     // [-0.5772157, -1.9635100, 2.2517526]
+    /// }
+    /// ```
+    pub fn digamma(self) -> Self {
+        let reflected = self.clone().lower_elem(0.5);
+        let z = self
+            .clone()
+            .mask_where(reflected.clone(), self.clone().neg().add_scalar(1.0));
+
+        // Recurrence ψ(z) = ψ(z + 6) - Σ 1 / (z + k), then the asymptotic expansion at z + 6.
+        let mut shift = z.zeros_like();
+        for k in 0..6 {
+            shift = shift + z.clone().add_scalar(k as f64).recip();
+        }
+        let y = z.add_scalar(6.0);
+        let y2 = y.clone().square().recip();
+        let expansion = polynomial(
+            y2.clone(),
+            &[
+                -1.0 / 132.0,
+                1.0 / 240.0,
+                -1.0 / 252.0,
+                1.0 / 120.0,
+                -1.0 / 12.0,
+            ],
+        ) * y2;
+        let digamma = y.clone().log() - y.recip().mul_scalar(0.5) + expansion - shift;
+
+        // Reflection formula: ψ(x) = ψ(1 - x) - π / tan(πx).
+        let reflection = digamma.clone() - self.mul_scalar(PI).tan().recip().mul_scalar(PI);
+
+        digamma.mask_where(reflected, reflection)
+    }
+
+    /// Applies the modified Bessel function of the first kind of order zero element wise.
+    ///
+    /// Uses the polynomial approximations of Abramowitz and Stegun (9.8.1 and 9.8.2), with a
+    /// relative error below `2e-7`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<1>::from_data([0.0, 1.0, 5.0], &device);
+    ///     println!("{}", tensor.i0());
+    ///     // [1.0, 1.266066, 27.239872]
+    /// }
+    /// ```
+    pub fn i0(self) -> Self {
+        let x = self.abs();
+        let large = x.clone().greater_elem(3.75);
+
+        let small_x = x.clone().clamp_max(3.75);
+        let small = polynomial(
+            small_x.div_scalar(3.75).square(),
+            &[
+                0.45813e-2,
+                0.360768e-1,
+                0.2659732,
+                1.2067492,
+                3.0899424,
+                3.5156229,
+                1.0,
+            ],
+        );
+
+        let large_x = x.clamp_min(3.75);
+        let large_value = polynomial(
+            large_x.clone().recip().mul_scalar(3.75),
+            &[
+                0.392377e-2,
+                -0.1647633e-1,
+                0.2635537e-1,
+                -0.2057706e-1,
+                0.916281e-2,
+                -0.157565e-2,
+                0.225319e-2,
+                0.1328592e-1,
+                0.39894228,
+            ],
+        ) * large_x.clone().exp()
+            / large_x.sqrt();
+
+        small.mask_where(large, large_value)
+    }
+
+    /// Applies the regularized lower incomplete gamma function `P(a, x)` element wise, where
+    /// `self` is the shape `a` and `x` the upper limit of integration.
+    ///
+    /// The function is evaluated with its series for `x < a + 1`, and with its continued fraction
+    /// otherwise, each with a fixed number of terms that converges for shapes up to about `100`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let a = Tensor::<1>::from_data([1.0, 2.0, 5.0], &device);
+    ///     let x = Tensor::<1>::from_data([1.0, 1.0, 10.0], &device);
+    ///     println!("{}", a.gammainc(x));
+    ///     // [0.6321206, 0.2642411, 0.9707473]
+    /// }
+    /// ```
+    pub fn gammainc(self, x: Self) -> Self {
+        let (lower_series, upper_fraction, use_series) = incomplete_gamma(self, x);
+
+        upper_fraction
+            .neg()
+            .add_scalar(1.0)
+            .mask_where(use_series, lower_series)
+    }
+
+    /// Applies the regularized upper incomplete gamma function `Q(a, x) = 1 - P(a, x)` element
+    /// wise, where `self` is the shape `a` and `x` the lower limit of integration.
+    ///
+    /// See [gammainc](Tensor::gammainc) for the evaluation.
+    pub fn gammaincc(self, x: Self) -> Self {
+        let (lower_series, upper_fraction, use_series) = incomplete_gamma(self, x);
+
+        upper_fraction.mask_where(use_series, lower_series.neg().add_scalar(1.0))
+    }
+
+    /// Applies the regularized incomplete beta function `I_x(a, b)` element wise, where `self` is
+    /// the parameter `a`.
+    ///
+    /// The function is evaluated with its continued fraction, on `1 - I_{1-x}(b, a)` when
+    /// `x > (a + 1) / (a + b + 2)` so that the fraction converges quickly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example() {
+    ///     let device = Default::default();
+    ///     let a = Tensor::<1>::from_data([2.0, 5.0], &device);
+    ///     let b = Tensor::<1>::from_data([3.0, 2.0], &device);
+    ///     let x = Tensor::<1>::from_data([0.4, 0.9], &device);
+    ///     println!("{}", a.betainc(b, x));
+    ///     // [0.5248, 0.885735]
+    /// }
+    /// ```
+    pub fn betainc(self, b: Self, x: Self) -> Self {
+        let swap = x
+            .clone()
+            .greater(self.clone().add_scalar(1.0) / (self.clone() + b.clone()).add_scalar(2.0));
+        let a_swapped = self.clone().mask_where(swap.clone(), b.clone());
+        let b_swapped = b.mask_where(swap.clone(), self);
+        let x_swapped = x.clone().mask_where(swap.clone(), x.neg().add_scalar(1.0));
+
+        let log_prefactor = (a_swapped.clone() + b_swapped.clone()).lgamma()
+            - a_swapped.clone().lgamma()
+            - b_swapped.clone().lgamma()
+            + a_swapped.clone() * x_swapped.clone().log()
+            + b_swapped.clone() * x_swapped.clone().neg().add_scalar(1.0).log();
+        let fraction = beta_continued_fraction(a_swapped.clone(), b_swapped, x_swapped);
+        let value = log_prefactor.exp() * fraction / a_swapped;
+
+        value.clone().mask_where(swap, value.neg().add_scalar(1.0))
+    }
+}
+
+/// Evaluates the polynomial with the given coefficients, from the highest degree to the constant,
+/// with Horner's method.
+fn polynomial<const D: usize>(x: Tensor<D>, coefficients: &[f64]) -> Tensor<D> {
+    let (first, rest) = coefficients
+        .split_first()
+        .expect("The polynomial should have coefficients");
+
+    rest.iter().fold(x.full_like(*first), |value, coefficient| {
+        (value * x.clone()).add_scalar(*coefficient)
+    })
+}
+
+/// The Lanczos approximation of `lgamma(z)` for `z > 0`.
+fn lanczos_lgamma<const D: usize>(z: Tensor<D>) -> Tensor<D> {
+    let t = z.clone().add_scalar(5.5);
+    let mut series = z.full_like(1.000000000190015);
+    for (i, coefficient) in LANCZOS_COEFFICIENTS.iter().enumerate() {
+        series = series
+            + z.clone()
+                .add_scalar((i + 1) as f64)
+                .recip()
+                .mul_scalar(*coefficient);
+    }
+
+    (z.clone().add_scalar(0.5) * t.clone().log()) - t + (series.mul_scalar(SQRT_2PI) / z).log()
+}
+
+/// Replaces the values smaller than [LENTZ_TINY] in magnitude, which would make the modified
+/// Lentz's algorithm divide by zero.
+fn lentz_guard<const D: usize>(value: Tensor<D>) -> Tensor<D> {
+    let tiny = value.clone().abs().lower_elem(LENTZ_TINY);
+
+    value.mask_fill(tiny, LENTZ_TINY)
+}
+
+/// The series of `P(a, x)`, the continued fraction of `Q(a, x)`, and where to use the series.
+///
+/// Each branch is evaluated with `x` clamped to its domain, which keeps the unused branch finite.
+fn incomplete_gamma<const D: usize>(
+    a: Tensor<D>,
+    x: Tensor<D>,
+) -> (Tensor<D>, Tensor<D>, Tensor<D, Bool>) {
+    let boundary = a.clone().add_scalar(1.0);
+    let use_series = x.clone().lower(boundary.clone());
+    let log_prefactor = |x: Tensor<D>| x.clone().log() * a.clone() - x - a.clone().lgamma();
+
+    let series_x = x.clone().min_pair(boundary.clone());
+    let mut denominator = a.clone();
+    let mut term = a.clone().recip();
+    let mut sum = term.clone();
+    for _ in 0..GAMMAINC_ITERATIONS {
+        denominator = denominator.add_scalar(1.0);
+        term = term * series_x.clone() / denominator.clone();
+        sum = sum + term.clone();
+    }
+    let series = sum * log_prefactor(series_x).exp();
+
+    let fraction_x = x.max_pair(boundary);
+    let mut b = fraction_x.clone() - a.clone() + 1.0;
+    let mut c = b.full_like(1.0 / LENTZ_TINY);
+    let mut d = b.clone().recip();
+    let mut fraction = d.clone();
+    for i in 1..=GAMMAINC_ITERATIONS {
+        let i = i as f64;
+        let an = a.clone().sub_scalar(i).mul_scalar(i);
+        b = b.add_scalar(2.0);
+        d = lentz_guard(an.clone() * d + b.clone()).recip();
+        c = lentz_guard(b.clone() + an / c);
+        fraction = fraction * d.clone() * c.clone();
+    }
+    let fraction = fraction * log_prefactor(fraction_x).exp();
+
+    (series, fraction, use_series)
+}
+
+/// The continued fraction of the incomplete beta function.
+fn beta_continued_fraction<const D: usize>(a: Tensor<D>, b: Tensor<D>, x: Tensor<D>) -> Tensor<D> {
+    let sum = a.clone() + b.clone();
+    let mut c = x.ones_like();
+    let mut d =
+        lentz_guard((sum.clone() * x.clone() / a.clone().add_scalar(1.0)).neg() + 1.0).recip();
+    let mut fraction = d.clone();
+    for m in 1..=BETAINC_ITERATIONS {
+        let m = m as f64;
+        let even = (b.clone().sub_scalar(m) * x.clone()).mul_scalar(m)
+            / (a.clone().add_scalar(2.0 * m - 1.0) * a.clone().add_scalar(2.0 * m));
+        let odd = (a.clone().add_scalar(m) * sum.clone().add_scalar(m) * x.clone()).neg()
+            / (a.clone().add_scalar(2.0 * m) * a.clone().add_scalar(2.0 * m + 1.0));
+
+        for coefficient in [even, odd] {
+            d = lentz_guard((coefficient.clone() * d).add_scalar(1.0)).recip();
+            c = lentz_guard((coefficient / c).add_scalar(1.0));
+            fraction = fraction * d.clone() * c.clone();
+        }
+    }
+
+    fraction
+}
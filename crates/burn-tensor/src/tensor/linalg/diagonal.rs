@@ -0,0 +1,166 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::kind::{Basic, Numeric};
+use crate::tensor::{Int, Shape, Tensor};
+use crate::{AsIndex, IndexingUpdateOp};
+use alloc::vec::Vec;
+
+/// Returns the diagonal with the given `offset` of the matrices formed by `dim1` and `dim2`.
+///
+/// See also: [`diag`](super::diag), which extracts the main diagonal of the last two dimensions.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor with at least 2 dimensions.
+/// * `offset` - The diagonal to extract: `0` is the main diagonal, positive offsets are above it
+///   and negative offsets are below it.
+/// * `dim1` - The row dimension of the matrices (supports negative indexing).
+/// * `dim2` - The column dimension of the matrices (supports negative indexing).
+///
+/// # Returns
+///
+/// A tensor of rank `D - 1`, where `dim1` and `dim2` are removed and the diagonal is appended as
+/// the last dimension:
+///
+/// ``
+/// result[..., i] = tensor[..., i, i + offset, ...]
+/// ``
+///
+/// The diagonal is empty when `offset` is outside of the matrices.
+///
+/// # Panics
+///
+/// * If `dim1` and `dim2` are the same dimension.
+pub fn diagonal<const D: usize, const DO: usize, K>(
+    tensor: Tensor<D, K>,
+    offset: i64,
+    dim1: impl AsIndex,
+    dim2: impl AsIndex,
+) -> Tensor<DO, K>
+where
+    K: Basic,
+{
+    check!(TensorCheck::diag::<D, DO>());
+    let dim1 = dim1.expect_dim_index(D);
+    let dim2 = dim2.expect_dim_index(D);
+    assert_ne!(
+        dim1, dim2,
+        "diagonal expects 2 different dimensions (got {dim1} twice)"
+    );
+
+    // Move the matrix dimensions last.
+    let mut axes = (0..D)
+        .filter(|&dim| dim != dim1 && dim != dim2)
+        .collect::<Vec<_>>();
+    axes.extend([dim1, dim2]);
+    let tensor = tensor.permute::<usize>(axes.try_into().unwrap());
+
+    let shape = tensor.shape();
+    let rows = shape[D - 2] as i64;
+    let cols = shape[D - 1] as i64;
+    let (start, len) = diagonal_range(rows, cols, offset);
+    let device = tensor.device();
+
+    let mut flat_shape = shape.as_slice()[..D - 2].to_vec();
+    flat_shape.push((rows * cols) as usize);
+    let flat: Tensor<DO, K> = tensor.reshape(Shape::from(flat_shape));
+
+    let indices = Tensor::<1, Int>::arange(0..len, &device)
+        .mul_scalar(cols + 1)
+        .add_scalar(start);
+    flat.select(DO - 1, indices)
+}
+
+/// Creates tensors whose diagonals with the given `offset` are filled by the last dimension of
+/// the input, in the matrices formed by `dim1` and `dim2` of the output.
+///
+/// This is the inverse of [`diagonal`]: the other elements of the matrices are zero.
+///
+/// # Arguments
+///
+/// * `tensor` - The diagonals, with shape `[..., n]`.
+/// * `offset` - The diagonal to fill: `0` is the main diagonal, positive offsets are above it
+///   and negative offsets are below it.
+/// * `dim1` - The row dimension of the output matrices (supports negative indexing).
+/// * `dim2` - The column dimension of the output matrices (supports negative indexing).
+///
+/// # Returns
+///
+/// A tensor of rank `DO = D + 1`, with square matrices of size `n + |offset|` at `dim1` and
+/// `dim2`, and the leading dimensions of the input in the other dimensions, where:
+///
+/// ``
+/// result[..., i, i + offset, ...] = tensor[..., i]
+/// ``
+///
+/// # Panics
+///
+/// * If `DO` isn't `D + 1`.
+/// * If `dim1` and `dim2` are the same dimension.
+pub fn diag_embed<const D: usize, const DO: usize, K>(
+    tensor: Tensor<D, K>,
+    offset: i64,
+    dim1: impl AsIndex,
+    dim2: impl AsIndex,
+) -> Tensor<DO, K>
+where
+    K: Numeric,
+{
+    assert_eq!(
+        DO,
+        D + 1,
+        "`diag_embed` with D={D} expects DO={} (got DO={DO})",
+        D + 1
+    );
+    let dim1 = dim1.expect_dim_index(DO);
+    let dim2 = dim2.expect_dim_index(DO);
+    assert_ne!(
+        dim1, dim2,
+        "diag_embed expects 2 different dimensions (got {dim1} twice)"
+    );
+
+    let shape = tensor.shape();
+    let len = shape[D - 1] as i64;
+    let size = len + offset.abs();
+    let (start, _) = diagonal_range(size, size, offset);
+    let device = tensor.device();
+
+    let mut flat_shape = shape.as_slice().to_vec();
+    flat_shape[D - 1] = (size * size) as usize;
+    let indices = Tensor::<1, Int>::arange(0..len, &device)
+        .mul_scalar(size + 1)
+        .add_scalar(start);
+    let flat = Tensor::<D, K>::zeros(Shape::from(flat_shape), &device).select_assign(
+        D - 1,
+        indices,
+        tensor,
+        IndexingUpdateOp::Add,
+    );
+
+    let mut matrix_shape = shape.as_slice()[..D - 1].to_vec();
+    matrix_shape.extend([size as usize, size as usize]);
+    let matrices: Tensor<DO, K> = flat.reshape(Shape::from(matrix_shape));
+
+    // Move the matrix dimensions from the end to `dim1` and `dim2`.
+    let mut batch_dims = 0..DO - 2;
+    let axes = core::array::from_fn(|dim| {
+        if dim == dim1 {
+            DO - 2
+        } else if dim == dim2 {
+            DO - 1
+        } else {
+            batch_dims.next().unwrap()
+        }
+    });
+    matrices.permute::<usize>(axes)
+}
+
+/// The flat index of the first element and the length of the diagonal with the given `offset`,
+/// in a row-major `rows x cols` matrix.
+fn diagonal_range(rows: i64, cols: i64, offset: i64) -> (i64, i64) {
+    if offset >= 0 {
+        (offset, rows.min(cols - offset).max(0))
+    } else {
+        (-offset * cols, (rows + offset).min(cols).max(0))
+    }
+}
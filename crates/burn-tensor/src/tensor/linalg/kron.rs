@@ -0,0 +1,110 @@
+use crate::kind::Numeric;
+use crate::tensor::{Shape, Tensor};
+
+/// Computes the Kronecker product of 2 tensors.
+///
+/// Both tensors must have the same rank, the product is computed over every dimension.
+///
+/// See also: [`kron_batched`].
+///
+/// # Arguments
+/// - `lhs`: the left operand, with shape ``[a_0, ..., a_n]``.
+/// - `rhs`: the right operand, with shape ``[b_0, ..., b_n]``.
+///
+/// # Returns
+///
+/// A tensor with shape ``[a_0 * b_0, ..., a_n * b_n]``, where:
+///
+/// ``
+/// result[i_0 * b_0 + j_0, ..., i_n * b_n + j_n] = lhs[i_0, ..., i_n] * rhs[j_0, ..., j_n]
+/// ``
+pub fn kron<const D: usize, K>(lhs: Tensor<D, K>, rhs: Tensor<D, K>) -> Tensor<D, K>
+where
+    K: Numeric,
+{
+    kron_batched(lhs, rhs, 0)
+}
+
+/// Computes the Kronecker product of 2 tensors, broadcasting over leading batch dimensions.
+///
+/// The first `batch_dims` dimensions are batch dimensions, which must be broadcast-compatible
+/// between the operands. The product is computed over the remaining dimensions, e.g. with
+/// `batch_dims = D - 2`, each pair of matrices is multiplied independently.
+///
+/// # Arguments
+/// - `lhs`: the left operand, with shape ``[..., a_k, ..., a_n]``.
+/// - `rhs`: the right operand, with shape ``[..., b_k, ..., b_n]``.
+/// - `batch_dims`: the number `k` of leading batch dimensions.
+///
+/// # Returns
+///
+/// A tensor with shape ``[..., a_k * b_k, ..., a_n * b_n]``, where:
+///
+/// ``
+/// result[..., i_k * b_k + j_k, ..., i_n * b_n + j_n]
+///     = lhs[..., i_k, ..., i_n] * rhs[..., j_k, ..., j_n]
+/// ``
+///
+/// # Panics
+///
+/// * If `batch_dims` is greater than the rank of the tensors.
+/// * If the batch dimensions are not broadcast-compatible.
+pub fn kron_batched<const D: usize, K>(
+    lhs: Tensor<D, K>,
+    rhs: Tensor<D, K>,
+    batch_dims: usize,
+) -> Tensor<D, K>
+where
+    K: Numeric,
+{
+    assert!(
+        batch_dims <= D,
+        "kron expects at most {D} batch dimensions (got {batch_dims})"
+    );
+
+    let lhs_dims = lhs.shape().dims::<D>();
+    let rhs_dims = rhs.shape().dims::<D>();
+
+    if batch_dims > 0 {
+        let lhs_batch = Shape::from(&lhs_dims[..batch_dims]);
+        let rhs_batch = Shape::from(&rhs_dims[..batch_dims]);
+
+        assert!(
+            lhs_batch.broadcast(&rhs_batch).is_ok(),
+            "Batch dimensions are not broadcast-compatible: lhs {:?} vs rhs {:?}",
+            &lhs_dims[..batch_dims],
+            &rhs_dims[..batch_dims]
+        );
+    }
+
+    let mut lhs = lhs;
+    let mut rhs = rhs;
+    for dim in batch_dims..D {
+        // Each element of `lhs` is repeated `b` times, while `rhs` is tiled `a` times.
+        lhs = repeat_elements(lhs, dim, rhs_dims[dim]);
+        rhs = rhs.repeat_dim(dim, lhs_dims[dim]);
+    }
+
+    lhs * rhs
+}
+
+/// Repeats each element `times` times consecutively along `dim`.
+fn repeat_elements<const D: usize, K>(
+    tensor: Tensor<D, K>,
+    dim: usize,
+    times: usize,
+) -> Tensor<D, K>
+where
+    K: Numeric,
+{
+    let mut dims = tensor.shape().dims::<D>();
+    let outer = dims[..dim].iter().product::<usize>();
+    let inner = dims[dim + 1..].iter().product::<usize>();
+    let size = dims[dim];
+    dims[dim] = size * times;
+
+    tensor
+        .reshape([outer, size, 1, inner])
+        .expand([outer, size, times, inner])
+        .reshape(dims)
+}
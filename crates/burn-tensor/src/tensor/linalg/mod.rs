@@ -1,6 +1,8 @@
 mod cosine_similarity;
 mod det;
 mod diag;
+mod diagonal;
+mod kron;
 mod lu;
 mod matvec;
 mod outer;
@@ -10,6 +12,8 @@ mod vector_norm;
 pub use cosine_similarity::*;
 pub use det::*;
 pub use diag::*;
+pub use diagonal::*;
+pub use kron::*;
 pub use lu::*;
 pub use matvec::*;
 pub use outer::*;
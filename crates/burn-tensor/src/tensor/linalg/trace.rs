@@ -1,4 +1,5 @@
-use super::diag;
+use super::{diag, diagonal};
+use crate::AsIndex;
 use crate::tensor::Tensor;
 
 /// Computes the trace of a matrix.
@@ -8,6 +9,8 @@ use crate::tensor::Tensor;
 /// The trace operation sums the diagonal elements of the last two dimensions,
 /// treating them as the matrix dimensions, while preserving all leading batch dimensions.
 ///
+/// See also: [`trace_dims`].
+///
 /// # Arguments
 ///
 /// * `tensor` - The input tensor with at least 2 dimensions.
@@ -21,3 +24,28 @@ pub fn trace<const D: usize, const DO: usize>(tensor: Tensor<D>) -> Tensor<DO> {
 
     diag_tensor.sum_dim(DO - 1)
 }
+
+/// Computes the sum of the diagonal with the given `offset` of the matrices formed by `dim1`
+/// and `dim2`, preserving all the other dimensions as batch dimensions.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor with at least 2 dimensions.
+/// * `offset` - The diagonal to sum, as in [`diagonal`].
+/// * `dim1` - The row dimension of the matrices (supports negative indexing).
+/// * `dim2` - The column dimension of the matrices (supports negative indexing).
+///
+/// # Returns
+///
+/// A tensor of rank `D - 1`, where `dim1` and `dim2` are removed and the last dimension of size
+/// 1 contains the sum along the diagonals of the input.
+pub fn trace_dims<const D: usize, const DO: usize>(
+    tensor: Tensor<D>,
+    offset: i64,
+    dim1: impl AsIndex,
+    dim2: impl AsIndex,
+) -> Tensor<DO> {
+    let diag_tensor = diagonal::<D, DO, _>(tensor, offset, dim1, dim2);
+
+    diag_tensor.sum_dim(DO - 1)
+}
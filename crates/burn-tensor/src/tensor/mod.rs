@@ -46,6 +46,12 @@ pub mod ops {
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The differentiable sorting and ranking module.
+pub mod ranking;
+
+/// The optimal transport and assignment module.
+pub mod transport;
+
 #[cfg(feature = "std")]
 pub use report::*;
 
@@ -405,6 +405,102 @@ pub fn adaptive_avg_pool1d(x: Tensor<3>, output_size: usize) -> Tensor<3> {
     )))
 }
 
+/// Applies a [2D adaptive max pooling](burn_backend::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d(x: Tensor<4>, output_size: [usize; 2]) -> Tensor<4> {
+    Tensor::new(BridgeTensor::Float(Dispatch::adaptive_max_pool2d(
+        x.primitive.into_float(),
+        output_size,
+    )))
+}
+
+/// Applies a [2D adaptive max pooling with indices](burn_backend::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+///
+/// The indices are flattened over the height and width of the input.
+pub fn adaptive_max_pool2d_with_indices(
+    x: Tensor<4>,
+    output_size: [usize; 2],
+) -> (Tensor<4>, Tensor<4, Int>) {
+    let output = Dispatch::adaptive_max_pool2d_with_indices(x.primitive.into_float(), output_size);
+
+    (
+        Tensor::new(BridgeTensor::Float(output.output)),
+        Tensor::new(BridgeTensor::Int(output.indices)),
+    )
+}
+
+/// Applies a [1D adaptive max pooling](burn_backend::ops::ModuleOps::adaptive_max_pool1d).
+pub fn adaptive_max_pool1d(x: Tensor<3>, output_size: usize) -> Tensor<3> {
+    Tensor::new(BridgeTensor::Float(Dispatch::adaptive_max_pool1d(
+        x.primitive.into_float(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling with indices](burn_backend::ops::ModuleOps::adaptive_max_pool1d_with_indices).
+pub fn adaptive_max_pool1d_with_indices(
+    x: Tensor<3>,
+    output_size: usize,
+) -> (Tensor<3>, Tensor<3, Int>) {
+    let output = Dispatch::adaptive_max_pool1d_with_indices(x.primitive.into_float(), output_size);
+
+    (
+        Tensor::new(BridgeTensor::Float(output.output)),
+        Tensor::new(BridgeTensor::Int(output.indices)),
+    )
+}
+
+/// Applies a [2D power-average pooling](burn_backend::ops::ModuleOps::lp_pool2d).
+pub fn lp_pool2d(
+    x: Tensor<4>,
+    norm_type: f64,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    ceil_mode: bool,
+) -> Tensor<4> {
+    Tensor::new(BridgeTensor::Float(Dispatch::lp_pool2d(
+        x.primitive.into_float(),
+        norm_type,
+        kernel_size,
+        stride,
+        ceil_mode,
+    )))
+}
+
+/// Applies a [1D power-average pooling](burn_backend::ops::ModuleOps::lp_pool1d).
+pub fn lp_pool1d(
+    x: Tensor<3>,
+    norm_type: f64,
+    kernel_size: usize,
+    stride: usize,
+    ceil_mode: bool,
+) -> Tensor<3> {
+    Tensor::new(BridgeTensor::Float(Dispatch::lp_pool1d(
+        x.primitive.into_float(),
+        norm_type,
+        kernel_size,
+        stride,
+        ceil_mode,
+    )))
+}
+
+/// Applies a [pixel shuffle](burn_backend::ops::ModuleOps::pixel_shuffle), rearranging
+/// `[batch_size, channels * r * r, height, width]` into `[batch_size, channels, height * r, width * r]`.
+pub fn pixel_shuffle(x: Tensor<4>, upscale_factor: usize) -> Tensor<4> {
+    Tensor::new(BridgeTensor::Float(Dispatch::pixel_shuffle(
+        x.primitive.into_float(),
+        upscale_factor,
+    )))
+}
+
+/// Applies a [pixel unshuffle](burn_backend::ops::ModuleOps::pixel_unshuffle), rearranging
+/// `[batch_size, channels, height * r, width * r]` into `[batch_size, channels * r * r, height, width]`.
+pub fn pixel_unshuffle(x: Tensor<4>, downscale_factor: usize) -> Tensor<4> {
+    Tensor::new(BridgeTensor::Float(Dispatch::pixel_unshuffle(
+        x.primitive.into_float(),
+        downscale_factor,
+    )))
+}
+
 /// Applies a [2D interpolation](burn_backend::ops::ModuleOps::interpolate).
 pub fn interpolate(
     x: Tensor<4>,
@@ -418,6 +514,30 @@ pub fn interpolate(
     )))
 }
 
+/// Applies a 3D interpolation to a tensor of shape `[N, C, D, H, W]`, such as a volume or a
+/// video, which is trilinear with the [bilinear](crate::ops::InterpolateMode::Bilinear) mode.
+///
+/// All the modes are separable and leave the samples unchanged when the size of a dimension
+/// doesn't change, so the height and width of each depth slice are interpolated first, followed
+/// by the depth of each pixel, with [2D interpolations](interpolate).
+pub fn interpolate3d(
+    x: Tensor<5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<5> {
+    let [n, c, d, h, w] = x.dims();
+    let [d_out, h_out, w_out] = output_size;
+
+    let x = interpolate(x.reshape([n, c * d, h, w]), [h_out, w_out], options.clone());
+    let x = interpolate(
+        x.reshape([n, c, d, h_out * w_out]),
+        [d_out, h_out * w_out],
+        options,
+    );
+
+    x.reshape([n, c, d_out, h_out, w_out])
+}
+
 /// Applies a linear transformation to the input tensor using the given weight and bias.
 ///
 /// ```math
@@ -0,0 +1,3 @@
+mod neural_sort;
+
+pub use neural_sort::*;
@@ -0,0 +1,138 @@
+use crate::activation::softmax;
+use crate::tensor::Tensor;
+use crate::{Int, s};
+
+/// Computes the NeuralSort relaxation of the permutation matrix sorting the scores of each row.
+///
+/// Row `i` of the output is a soft one-hot vector of the element at rank `i`, which becomes the
+/// exact permutation matrix as the temperature goes to zero. The rows sum to one, while the
+/// columns only approximately do.
+///
+/// Reference: "Stochastic Optimization of Sorting Networks via Continuous Relaxations"
+/// <https://arxiv.org/abs/1903.08850>
+///
+/// # Arguments
+/// - `scores`: the scores to sort, with shape `[batch_size, n]`.
+/// - `tau`: the temperature, which must be positive.
+/// - `descending`: whether rank `0` is the largest score instead of the smallest.
+///
+/// # Returns
+///
+/// A tensor of shape `[batch_size, n, n]`, where `result[b, i, j]` is the weight of the element
+/// `j` at rank `i`.
+///
+/// # Panics
+/// - If `tau` is not positive.
+pub fn neural_sort(scores: Tensor<2>, tau: f64, descending: bool) -> Tensor<3> {
+    assert!(
+        tau > 0.0,
+        "The NeuralSort temperature must be positive, got {tau}"
+    );
+    // The relaxation sorts in descending order.
+    let scores = if descending { scores } else { scores.neg() };
+    let [batch_size, n] = scores.dims();
+    let device = scores.device();
+
+    // (A_s 1)_j = sum_k |s_j - s_k|
+    let rows = scores.clone().reshape([batch_size, 1, n]);
+    let columns = scores.reshape([batch_size, n, 1]);
+    let abs_sums = (columns.expand([batch_size, n, n]) - rows.clone().expand([batch_size, n, n]))
+        .abs()
+        .sum_dim(2)
+        .reshape([batch_size, 1, n]);
+
+    // (n + 1 - 2i) for the ranks i in [1, n]
+    let scaling = Tensor::<1, Int>::arange(1..n as i64 + 1, &device)
+        .float()
+        .mul_scalar(-2.0)
+        .add_scalar(n as f64 + 1.0)
+        .reshape([1, n, 1]);
+
+    let logits = scaling * rows - abs_sums;
+    softmax(logits.div_scalar(tau), 2)
+}
+
+/// Sorts the elements of the last dimension with the [NeuralSort](neural_sort) relaxation.
+///
+/// Each output element is a weighted average of the inputs, which tends to the sorted values as
+/// the temperature goes to zero, and is differentiable with respect to every input.
+///
+/// # Arguments
+/// - `tensor`: the tensor to sort along its last dimension.
+/// - `tau`: the temperature, which must be positive.
+/// - `descending`: whether to sort in descending order.
+///
+/// # Panics
+/// - If `tau` is not positive.
+pub fn soft_sort<const D: usize>(tensor: Tensor<D>, tau: f64, descending: bool) -> Tensor<D> {
+    let shape = tensor.shape();
+    let (scores, [batch_size, n]) = flatten_last_dim(tensor);
+
+    let permutation = neural_sort(scores.clone(), tau, descending);
+    permutation
+        .matmul(scores.reshape([batch_size, n, 1]))
+        .reshape(shape)
+}
+
+/// Computes the soft ranks of the elements of the last dimension with the
+/// [NeuralSort](neural_sort) relaxation.
+///
+/// The rank of an element is the expected position given by the relaxed permutation matrix, in
+/// `[0, n - 1]`, which tends to its position in the sorted sequence as the temperature goes to
+/// zero.
+///
+/// # Arguments
+/// - `tensor`: the tensor to rank along its last dimension.
+/// - `tau`: the temperature, which must be positive.
+/// - `descending`: whether rank `0` is the largest element instead of the smallest.
+///
+/// # Panics
+/// - If `tau` is not positive.
+pub fn soft_rank<const D: usize>(tensor: Tensor<D>, tau: f64, descending: bool) -> Tensor<D> {
+    let shape = tensor.shape();
+    let (scores, [batch_size, n]) = flatten_last_dim(tensor);
+    let device = scores.device();
+
+    let permutation = neural_sort(scores, tau, descending);
+    let positions = Tensor::<1, Int>::arange(0..n as i64, &device)
+        .float()
+        .reshape([1, 1, n])
+        .expand([batch_size, 1, n]);
+
+    positions.matmul(permutation).reshape(shape)
+}
+
+/// Computes the soft membership of the elements of the last dimension in its `k` largest
+/// elements, with the [NeuralSort](neural_sort) relaxation.
+///
+/// The memberships are in `[0, 1]` and sum to `k` over the last dimension. They tend to the
+/// indicator of the top-k elements as the temperature goes to zero, which makes the top-k
+/// selection differentiable, for instance to weight the selected elements.
+///
+/// # Arguments
+/// - `tensor`: the scores of the elements along its last dimension.
+/// - `k`: the number of elements to select.
+/// - `tau`: the temperature, which must be positive.
+///
+/// # Panics
+/// - If `k` is larger than the size of the last dimension.
+/// - If `tau` is not positive.
+pub fn soft_top_k<const D: usize>(tensor: Tensor<D>, k: usize, tau: f64) -> Tensor<D> {
+    let shape = tensor.shape();
+    let (scores, [_, n]) = flatten_last_dim(tensor);
+    assert!(
+        k <= n,
+        "Cannot select the top {k} elements out of {n} elements"
+    );
+
+    let permutation = neural_sort(scores, tau, true);
+    permutation.slice(s![.., 0..k]).sum_dim(1).reshape(shape)
+}
+
+fn flatten_last_dim<const D: usize>(tensor: Tensor<D>) -> (Tensor<2>, [usize; 2]) {
+    assert!(D > 0, "Sorting requires at least one dimension");
+    let n = tensor.dims()[D - 1];
+    let batch_size = tensor.shape().num_elements() / n.max(1);
+
+    (tensor.reshape([batch_size, n]), [batch_size, n])
+}
@@ -0,0 +1,88 @@
+use crate::{Float, Int, Tensor, TensorCreationOptions, check, check::TensorCheck};
+
+/// Creates a 1D Kaiser window tensor.
+///
+#[cfg_attr(
+    doc,
+    doc = r#"
+$$w_n = \frac{I_0\left(\beta \sqrt{1 - \left(\frac{2n}{N} - 1\right)^2}\right)}{I_0(\beta)}$$
+
+where $I_0$ is the [modified Bessel function](Tensor::i0) of order zero, and $N$ = `size` when
+`periodic` is `true`, or $N$ = `size - 1` when `periodic` is `false`.
+"#
+)]
+#[cfg_attr(
+    not(doc),
+    doc = "`w_n = I0(β * sqrt(1 - (2n / N - 1)²)) / I0(β)` where N = size (periodic) or N = size-1 (symmetric)"
+)]
+///
+/// # Arguments
+/// - `size`: Size of the returned 1D window tensor.
+/// - `beta`: Shape parameter of the window, trading the main lobe width for the side lobe level.
+/// - `periodic`: If `true`, the window is treated as periodic (i.e., `N = size`).
+///   If `false`, the window is symmetric (i.e., `N = size - 1`).
+/// - `options`: Controls the output device and optional dtype. Accepts:
+///     - `&device` - uses the device's default float dtype
+///     - `(&device, DType::F32)` - uses an explicit dtype
+///     - `TensorCreationOptions` directly for full control.
+///
+/// # Returns
+/// - A 1D tensor of shape `[size]` containing the window.
+///
+/// # Notes
+/// - If `size == 0`, the function returns an empty tensor.
+/// - If `size == 1`, the returned window contains a single value 1.0 which overrides the formula.
+///
+/// # Panics
+/// Panics if `size` exceeds `i64::MAX`.
+///
+/// # Example
+/// ```rust
+/// use burn_tensor::{Device, signal::kaiser_window};
+///
+/// fn example() {
+///     let device = Device::default();
+///     let window_tensor = kaiser_window(6, 5.0, false, &device);
+///     // Output: [0.036711, 0.414904, 0.913812, 0.913812, 0.414904, 0.036711]
+/// }
+/// ```
+pub fn kaiser_window(
+    size: usize,
+    beta: f64,
+    periodic: bool,
+    options: impl Into<TensorCreationOptions>,
+) -> Tensor<1> {
+    let opt = options.into();
+    let dtype = opt.resolve_dtype::<Float>();
+    let shape = [size];
+    check!(TensorCheck::creation_ops::<1>("KaiserWindow", &shape));
+
+    if size == 0 {
+        return Tensor::<1>::empty(shape, opt).cast(dtype);
+    }
+
+    if size == 1 {
+        return Tensor::<1>::ones(shape, opt).cast(dtype);
+    }
+
+    let size_i64 = i64::try_from(size).expect("KaiserWindow size doesn't fit in i64 range.");
+    let denominator = if periodic { size } else { size - 1 };
+    let ratio = Tensor::<1, Int>::arange(0..size_i64, &opt.device)
+        .float()
+        .mul_scalar(2.0 / denominator as f64)
+        .sub_scalar(1.0);
+    // Rounding can push `1 - ratio²` slightly below zero at the edges.
+    let window = ratio
+        .square()
+        .neg()
+        .add_scalar(1.0)
+        .clamp_min(0.0)
+        .sqrt()
+        .mul_scalar(beta)
+        .i0();
+    let normalization = Tensor::<1>::full([1], beta, &opt.device)
+        .i0()
+        .expand([size]);
+
+    (window / normalization).cast(dtype)
+}
@@ -1,4 +1,10 @@
+#[allow(unused_imports)]
+use num_traits::float::Float;
+
+use crate::kind::Ordered;
+use crate::ops::QuantileInterpolation;
 use crate::{Int, Tensor};
+use alloc::vec;
 
 pub fn var<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
     let mean = tensor.clone().mean_dim(dim);
@@ -33,7 +39,7 @@ pub fn var_with_mean_n<const D: usize>(
     tensor.sub(mean).square().sum_dim(dim).div_scalar(n as f32)
 }
 
-pub fn median<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
+pub fn median<const D: usize, K: Ordered>(tensor: Tensor<D, K>, dim: usize) -> Tensor<D, K> {
     let total_elem_numbers = tensor.dims()[dim];
     let sorted_tensor = tensor.sort(dim);
 
@@ -48,10 +54,10 @@ pub fn median<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
     sorted_tensor.narrow(dim, median_index, 1)
 }
 
-pub fn median_with_indices<const D: usize>(
-    tensor: Tensor<D>,
+pub fn median_with_indices<const D: usize, K: Ordered>(
+    tensor: Tensor<D, K>,
     dim: usize,
-) -> (Tensor<D>, Tensor<D, Int>) {
+) -> (Tensor<D, K>, Tensor<D, Int>) {
     let total_elem_numbers = tensor.dims()[dim];
     let (sorted_tensor, indices) = tensor.sort_with_indices(dim);
 
@@ -67,3 +73,106 @@ pub fn median_with_indices<const D: usize>(
     let median_indices = indices.narrow(dim, median_index, 1);
     (median_values, median_indices)
 }
+
+pub fn quantile<const D: usize>(
+    tensor: Tensor<D>,
+    q: f64,
+    dim: usize,
+    interpolation: QuantileInterpolation,
+) -> Tensor<D> {
+    assert!(
+        (0.0..=1.0).contains(&q),
+        "Quantile q must be in the range [0, 1], got {q}"
+    );
+
+    let total_elem_numbers = tensor.dims()[dim];
+    let sorted_tensor = tensor.sort(dim);
+
+    // Position of the quantile in the sorted values, following the NumPy/PyTorch definition.
+    let position = q * (total_elem_numbers - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let upper_index = position.ceil() as usize;
+    let fraction = position - lower_index as f64;
+
+    let value_at = |index: usize| sorted_tensor.clone().narrow(dim, index, 1);
+
+    match interpolation {
+        QuantileInterpolation::Lower => value_at(lower_index),
+        QuantileInterpolation::Higher => value_at(upper_index),
+        QuantileInterpolation::Nearest => {
+            // Round half to even, like NumPy and PyTorch.
+            let index = if fraction == 0.5 {
+                if lower_index % 2 == 0 {
+                    lower_index
+                } else {
+                    upper_index
+                }
+            } else {
+                position.round() as usize
+            };
+            value_at(index)
+        }
+        QuantileInterpolation::Midpoint => {
+            (value_at(lower_index) + value_at(upper_index)).div_scalar(2.0)
+        }
+        QuantileInterpolation::Linear => {
+            let lower = value_at(lower_index);
+            if lower_index == upper_index {
+                return lower;
+            }
+            let upper = value_at(upper_index);
+            lower.clone() + (upper - lower).mul_scalar(fraction)
+        }
+    }
+}
+
+pub fn mode_with_indices<const D: usize, K: Ordered>(
+    tensor: Tensor<D, K>,
+    dim: usize,
+) -> (Tensor<D, K>, Tensor<D, Int>) {
+    let device = tensor.device();
+    let (sorted_tensor, indices) = tensor.sort_with_indices(dim);
+
+    // Move the reduced dimension last and flatten the others, so the counts can be computed
+    // with a fixed rank.
+    let sorted_tensor = sorted_tensor.swap_dims(dim, D - 1);
+    let indices = indices.swap_dims(dim, D - 1);
+    let mut dims = sorted_tensor.dims();
+    let n = dims[D - 1];
+    let batch = dims.iter().product::<usize>() / n;
+
+    // The values are sorted, so equal values form contiguous runs. A run starts wherever a value
+    // differs from its predecessor, and the start of the run containing each position is the
+    // running maximum of the run starts up to that position.
+    let values: Tensor<2, K> = sorted_tensor.clone().reshape([batch, n]);
+    let mut is_start = Tensor::<2, Int>::ones([batch, 1], &device);
+    if n > 1 {
+        let changed = values
+            .clone()
+            .narrow(1, 1, n - 1)
+            .not_equal(values.narrow(1, 0, n - 1))
+            .int();
+        is_start = Tensor::cat(vec![is_start, changed], 1);
+    }
+    let positions = Tensor::<1, Int>::arange(0..n as i64, &device)
+        .unsqueeze_dim::<2>(0)
+        .expand([batch, n]);
+    let run_starts = (positions.clone() * is_start).cummax(1);
+
+    // The count of a run is reached at its last position. Break ties with the smallest value,
+    // i.e. the earliest run, so the result does not depend on how a backend resolves ties in
+    // `argmax`.
+    let counts = (positions - run_starts.clone()).add_scalar(1);
+    let scores = counts.mul_scalar(n as i64) + run_starts.clone().neg().add_scalar(n as i64 - 1);
+    let mode_positions: Tensor<2, Int> = run_starts.gather(1, scores.argmax(1));
+
+    dims[D - 1] = 1;
+    let mode_positions: Tensor<D, Int> = mode_positions.reshape(dims);
+
+    let mode_values = sorted_tensor
+        .gather(D - 1, mode_positions.clone())
+        .swap_dims(dim, D - 1);
+    let mode_indices = indices.gather(D - 1, mode_positions).swap_dims(dim, D - 1);
+
+    (mode_values, mode_indices)
+}
@@ -0,0 +1,160 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::tensor::Tensor;
+use crate::{Int, TensorData};
+
+/// Solves the linear assignment problem: finds the assignment of rows to columns with the
+/// minimum total cost, each row and each column being assigned at most once.
+///
+/// This is the Hungarian algorithm with the shortest augmenting paths of Jonker and Volgenant,
+/// which runs in `O(n² m)` on the host, after reading the costs from the device. It is used to
+/// match predictions with targets, for instance in set prediction losses.
+///
+/// # Arguments
+/// - `cost`: the cost of assigning each row to each column, with shape `[n, m]`.
+///
+/// # Returns
+///
+/// The assigned row and column indices, each with `min(n, m)` elements, sorted by row index, so
+/// that row `rows[k]` is assigned to column `columns[k]`.
+///
+/// # Panics
+/// - If a cost is not finite.
+pub fn linear_sum_assignment(cost: Tensor<2>) -> (Tensor<1, Int>, Tensor<1, Int>) {
+    let [n, m] = cost.dims();
+    let (rows, columns) = linear_sum_assignment_batched(cost.reshape([1, n, m]));
+    let num_pairs = n.min(m);
+
+    (rows.reshape([num_pairs]), columns.reshape([num_pairs]))
+}
+
+/// Solves a batch of [linear assignment problems](linear_sum_assignment) of the same size.
+///
+/// The costs of the whole batch are read from the device at once. Problems with fewer rows or
+/// columns, such as images with fewer targets, can be padded with a large constant cost, and the
+/// pairs assigned to the padding discarded afterwards.
+///
+/// # Arguments
+/// - `cost`: the cost of assigning each row to each column, with shape `[batch_size, n, m]`.
+///
+/// # Returns
+///
+/// The assigned row and column indices, each with shape `[batch_size, min(n, m)]`, sorted by row
+/// index for each problem.
+///
+/// # Panics
+/// - If a cost is not finite.
+pub fn linear_sum_assignment_batched(cost: Tensor<3>) -> (Tensor<2, Int>, Tensor<2, Int>) {
+    let [batch_size, n, m] = cost.dims();
+    let device = cost.device();
+    let values = cost.into_data().convert::<f64>().into_vec::<f64>().unwrap();
+    assert!(
+        values.iter().all(|value| value.is_finite()),
+        "The assignment costs must be finite"
+    );
+
+    let num_pairs = n.min(m);
+    let mut rows = Vec::with_capacity(batch_size * num_pairs);
+    let mut columns = Vec::with_capacity(batch_size * num_pairs);
+    if num_pairs > 0 {
+        for problem in values.chunks(n * m) {
+            for (row, column) in assign(problem, n, m) {
+                rows.push(row as i64);
+                columns.push(column as i64);
+            }
+        }
+    }
+
+    (
+        Tensor::from_data(TensorData::new(rows, [batch_size, num_pairs]), &device),
+        Tensor::from_data(TensorData::new(columns, [batch_size, num_pairs]), &device),
+    )
+}
+
+/// Solves one problem with row-major costs, returning the pairs sorted by row.
+fn assign(values: &[f64], n: usize, m: usize) -> Vec<(usize, usize)> {
+    // The algorithm assigns every row, so it runs on the transposed costs when there are more
+    // rows than columns.
+    if n <= m {
+        return hungarian(n, m, |i, j| values[i * m + j]);
+    }
+
+    let mut pairs: Vec<_> = hungarian(m, n, |i, j| values[j * m + i])
+        .into_iter()
+        .map(|(column, row)| (row, column))
+        .collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Assigns each of the `n` rows to one of the `m >= n` columns, returning the pairs sorted by row.
+fn hungarian(n: usize, m: usize, cost: impl Fn(usize, usize) -> f64) -> Vec<(usize, usize)> {
+    // Row and column potentials, and the row matched with each column, 1-indexed with the
+    // column 0 as the root of the augmenting paths.
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut matched = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for row in 1..=n {
+        matched[0] = row;
+        let mut j0 = 0;
+        let mut min_slack = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+
+        // Grow the shortest augmenting path until it reaches a free column.
+        loop {
+            used[j0] = true;
+            let i0 = matched[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost(i0 - 1, j - 1) - u[i0] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[matched[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if matched[j0] == 0 {
+                break;
+            }
+        }
+
+        // Flip the matching along the path.
+        loop {
+            let j1 = way[j0];
+            matched[j0] = matched[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut pairs: Vec<_> = (1..=m)
+        .filter(|&j| matched[j] != 0)
+        .map(|j| (matched[j] - 1, j - 1))
+        .collect();
+    pairs.sort_unstable();
+    pairs
+}
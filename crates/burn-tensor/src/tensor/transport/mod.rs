@@ -0,0 +1,5 @@
+mod hungarian;
+mod sinkhorn;
+
+pub use hungarian::*;
+pub use sinkhorn::*;
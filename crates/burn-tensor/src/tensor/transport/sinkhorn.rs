@@ -0,0 +1,77 @@
+use crate::activation::logsumexp;
+use crate::tensor::Tensor;
+
+/// Computes the entropic optimal transport plans between batches of discrete distributions with
+/// the Sinkhorn algorithm.
+///
+/// The plan `P` minimizes `<P, C> - epsilon * H(P)` under the constraints that its rows sum to
+/// the `source` weights and its columns to the `target` weights, where `H` is the entropy. The
+/// iterations update the dual potentials in the log domain, with `logsumexp` reductions, so
+/// small regularizations don't underflow. Each iteration is composed of differentiable tensor
+/// operations, so the plan can be backpropagated to the costs and the weights.
+///
+/// Reference: "Sinkhorn Distances: Lightspeed Computation of Optimal Transportation Distances"
+/// <https://arxiv.org/abs/1306.0895>
+///
+/// # Arguments
+/// - `cost`: the cost of moving mass from each source point to each target point, with shape
+///   `[batch_size, n, m]`.
+/// - `source`: the positive weights of the source points, with shape `[batch_size, n]`.
+/// - `target`: the positive weights of the target points, with shape `[batch_size, m]`, with the
+///   same total mass as the source weights.
+/// - `epsilon`: the entropic regularization, which must be positive. Smaller values give sparser
+///   plans, closer to the unregularized transport, but need more iterations to converge.
+/// - `num_iterations`: the number of Sinkhorn iterations.
+///
+/// # Returns
+///
+/// The transport plans, with shape `[batch_size, n, m]`. The transport cost is the sum of the
+/// plan multiplied by the cost over the last two dimensions.
+///
+/// # Panics
+/// - If the shapes don't match.
+/// - If `epsilon` is not positive.
+pub fn sinkhorn(
+    cost: Tensor<3>,
+    source: Tensor<2>,
+    target: Tensor<2>,
+    epsilon: f64,
+    num_iterations: usize,
+) -> Tensor<3> {
+    let [batch_size, n, m] = cost.dims();
+    assert_eq!(
+        source.dims(),
+        [batch_size, n],
+        "Source weights must have shape [batch_size, n]"
+    );
+    assert_eq!(
+        target.dims(),
+        [batch_size, m],
+        "Target weights must have shape [batch_size, m]"
+    );
+    assert!(
+        epsilon > 0.0,
+        "The Sinkhorn regularization must be positive, got {epsilon}"
+    );
+
+    // Potentials scaled by 1 / epsilon: P = exp(f_i + g_j - C_ij / epsilon).
+    let log_kernel = cost.div_scalar(-epsilon);
+    let log_source = source.log().reshape([batch_size, n, 1]);
+    let log_target = target.log().reshape([batch_size, 1, m]);
+
+    let mut f = Tensor::<3>::zeros(
+        [batch_size, n, 1],
+        (&log_kernel.device(), log_kernel.dtype()),
+    );
+    let mut g = Tensor::<3>::zeros(
+        [batch_size, 1, m],
+        (&log_kernel.device(), log_kernel.dtype()),
+    );
+
+    for _ in 0..num_iterations {
+        f = log_source.clone() - logsumexp(log_kernel.clone() + g, &[2]);
+        g = log_target.clone() - logsumexp(log_kernel.clone() + f.clone(), &[1]);
+    }
+
+    (log_kernel + f + g).exp()
+}
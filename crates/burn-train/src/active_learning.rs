@@ -0,0 +1,254 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::data::dataloader::DataLoader;
+use burn::tensor::Tensor;
+use serde::{Deserialize, Serialize};
+
+/// Acquisition function scoring how informative the label of an unlabeled item would be, from
+/// the class probabilities predicted by the model. Higher scores are more informative.
+#[derive(Clone, Default, Debug, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Acquisition {
+    /// The [entropy](entropy) of the predicted probabilities.
+    #[default]
+    Entropy,
+    /// One minus the [margin](margin) between the two most probable classes.
+    Margin,
+    /// The mutual information between the prediction and the model weights, estimated by
+    /// [BALD](bald) from stochastic forward passes, e.g. with Monte Carlo dropout.
+    Bald,
+}
+
+impl Acquisition {
+    /// Scores the items from the probabilities of shape `[num_samples, batch_size, num_classes]`
+    /// predicted by `num_samples` stochastic forward passes.
+    ///
+    /// The entropy and the margin are computed on the mean probabilities over the passes, while
+    /// BALD requires at least 2 passes to be informative.
+    pub fn score(&self, probabilities: Tensor<3>) -> Tensor<1> {
+        match self {
+            Acquisition::Entropy => entropy(mean_probabilities(probabilities)),
+            Acquisition::Margin => margin(mean_probabilities(probabilities)),
+            Acquisition::Bald => bald(probabilities),
+        }
+    }
+}
+
+/// The entropy of probabilities of shape `[batch_size, num_classes]`, with shape `[batch_size]`.
+pub fn entropy(probabilities: Tensor<2>) -> Tensor<1> {
+    let [batch_size, _num_classes] = probabilities.dims();
+
+    elementwise_entropy(probabilities)
+        .sum_dim(1)
+        .reshape([batch_size])
+}
+
+/// One minus the difference between the two highest of probabilities of shape
+/// `[batch_size, num_classes]`, with shape `[batch_size]`.
+pub fn margin(probabilities: Tensor<2>) -> Tensor<1> {
+    let [batch_size, num_classes] = probabilities.dims();
+    assert!(
+        num_classes >= 2,
+        "The margin requires at least 2 classes, got {num_classes}."
+    );
+
+    let top = probabilities.topk(2, 1);
+    let first = top.clone().slice([0..batch_size, 0..1]);
+    let second = top.slice([0..batch_size, 1..2]);
+
+    (first - second).neg().add_scalar(1.0).reshape([batch_size])
+}
+
+/// Bayesian active learning by disagreement (BALD) of the probabilities of shape
+/// `[num_samples, batch_size, num_classes]` predicted by stochastic forward passes, with shape
+/// `[batch_size]`.
+///
+/// The score is the entropy of the mean prediction minus the mean entropy of the predictions,
+/// which is high when the passes are individually confident but disagree.
+///
+/// Reference: "Deep Bayesian Active Learning with Image Data" <https://arxiv.org/abs/1703.02910>
+pub fn bald(probabilities: Tensor<3>) -> Tensor<1> {
+    let [_num_samples, batch_size, _num_classes] = probabilities.dims();
+    let mean_entropy = elementwise_entropy(probabilities.clone())
+        .sum_dim(2)
+        .mean_dim(0)
+        .reshape([batch_size]);
+
+    entropy(mean_probabilities(probabilities)) - mean_entropy
+}
+
+fn elementwise_entropy<const D: usize>(probabilities: Tensor<D>) -> Tensor<D> {
+    let log = probabilities.clone().clamp_min(1e-12).log();
+
+    (probabilities * log).neg()
+}
+
+fn mean_probabilities(probabilities: Tensor<3>) -> Tensor<2> {
+    let [_num_samples, batch_size, num_classes] = probabilities.dims();
+
+    probabilities.mean_dim(0).reshape([batch_size, num_classes])
+}
+
+/// Configuration of an active learning query over a pool of unlabeled items.
+#[derive(Config, Debug)]
+pub struct ActiveLearningConfig {
+    /// The number of items to select for labeling.
+    pub num_queries: usize,
+    /// The acquisition function (default: entropy).
+    #[config(default = "Acquisition::Entropy")]
+    pub acquisition: Acquisition,
+    /// The number of stochastic forward passes of each batch (default: 1). Use more passes with
+    /// Monte Carlo dropout, which BALD requires.
+    #[config(default = 1)]
+    pub num_samples: usize,
+}
+
+impl ActiveLearningConfig {
+    /// Scores the unlabeled pool in batches, and selects the most informative items.
+    ///
+    /// The `forward` function returns the class probabilities of a batch, with shape
+    /// `[batch_size, num_classes]`, and is called `num_samples` times per batch. For Monte Carlo
+    /// dropout, it should run the model with its dropout layers active, e.g. with
+    /// `stochastic_inference` enabled.
+    ///
+    /// The items are indexed in the order of the data loader, which must not shuffle the pool.
+    pub fn query<I, F>(&self, pool: Arc<dyn DataLoader<I>>, mut forward: F) -> ActiveLearningQuery
+    where
+        F: FnMut(&I) -> Tensor<2>,
+    {
+        assert!(
+            self.num_samples > 0,
+            "Active learning requires at least 1 forward pass."
+        );
+
+        let mut scores = Vec::new();
+        for batch in pool.iter() {
+            let probabilities = (0..self.num_samples)
+                .map(|_| forward(&batch).detach().unsqueeze_dim(0))
+                .collect();
+            let batch_scores = self.acquisition.score(Tensor::cat(probabilities, 0));
+
+            scores.extend(batch_scores.into_data().iter::<f64>());
+        }
+
+        ActiveLearningQuery::new(scores, self.num_queries)
+    }
+}
+
+/// The result of an [active learning query](ActiveLearningConfig::query).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveLearningQuery {
+    scores: Vec<f64>,
+    selected: Vec<usize>,
+}
+
+impl ActiveLearningQuery {
+    /// Selects the `num_queries` items with the highest scores, the first ones on ties.
+    pub fn new(scores: Vec<f64>, num_queries: usize) -> Self {
+        let mut ranking = (0..scores.len()).collect::<Vec<_>>();
+        ranking.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+        ranking.truncate(num_queries);
+
+        Self {
+            scores,
+            selected: ranking,
+        }
+    }
+
+    /// The score of each item of the pool.
+    pub fn scores(&self) -> &[f64] {
+        &self.scores
+    }
+
+    /// The indices of the selected items in the pool, by decreasing score.
+    pub fn selected(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Writes the indices of the selected items to a file, one per line.
+    pub fn save_selected(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let lines = self
+            .selected
+            .iter()
+            .map(|index| format!("{index}\n"))
+            .collect::<String>();
+
+        std::fs::write(path, lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::tensor::{Device, TensorData, Tolerance};
+
+    #[test]
+    fn test_entropy_and_margin() {
+        let device = Default::default();
+        let probabilities = Tensor::<2>::from_data([[0.5, 0.5, 0.0], [0.7, 0.2, 0.1]], &device);
+
+        entropy(probabilities.clone())
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &TensorData::from([0.693_147_2, 0.801_818_4]),
+                Tolerance::default(),
+            );
+        margin(probabilities)
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([1.0, 0.5]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_bald_measures_disagreement() {
+        let device = Default::default();
+        // The first item is predicted confidently but differently by the two passes, the second
+        // one is uncertain in both.
+        let probabilities = Tensor::<3>::from_data(
+            [[[1.0, 0.0], [0.5, 0.5]], [[0.0, 1.0], [0.5, 0.5]]],
+            &device,
+        );
+
+        bald(probabilities)
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([0.693_147_2, 0.0]), Tolerance::default());
+    }
+
+    #[derive(Clone)]
+    struct ProbabilitiesBatcher;
+
+    impl Batcher<[f32; 2], Tensor<2>> for ProbabilitiesBatcher {
+        fn batch(&self, items: Vec<[f32; 2]>, device: &Device) -> Tensor<2> {
+            let num_items = items.len();
+            let data = TensorData::new(items.concat(), [num_items, 2]);
+
+            Tensor::from_data(data, device)
+        }
+    }
+
+    #[test]
+    fn test_query_selects_the_most_uncertain_items() {
+        let pool = InMemDataset::new(vec![
+            [0.9, 0.1],
+            [0.5, 0.5],
+            [1.0, 0.0],
+            [0.6, 0.4],
+            [0.8, 0.2],
+        ]);
+        let pool = DataLoaderBuilder::new(ProbabilitiesBatcher)
+            .batch_size(2)
+            .build(pool);
+
+        let query = ActiveLearningConfig::new(2)
+            .with_acquisition(Acquisition::Margin)
+            .query(pool, |batch: &Tensor<2>| batch.clone());
+
+        assert_eq!(query.scores().len(), 5);
+        assert_eq!(query.selected(), [1, 3]);
+    }
+}
@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::data::dataloader::DataLoader;
+use burn::module::{AutodiffModule, Module, Param};
+use burn::tensor::{Device, Int, Tensor, activation::log_softmax};
+use burn_optim::{GradientsParams, LBFGSConfig, LineSearchFn};
+use serde::{Deserialize, Serialize};
+
+/// Method used to fit the temperature of a [TemperatureScaling] module.
+#[derive(Clone, Default, Debug, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemperatureFitMethod {
+    /// Minimizes the negative log-likelihood of the log-temperature with LBFGS.
+    #[default]
+    Lbfgs,
+    /// Evaluates the negative log-likelihood on a log-spaced grid of temperatures and keeps the
+    /// best one.
+    GridSearch,
+}
+
+/// Configuration to fit a [TemperatureScaling] module.
+#[derive(Config, Debug)]
+pub struct TemperatureScalingConfig {
+    /// The fitting method (default: LBFGS).
+    #[config(default = "TemperatureFitMethod::Lbfgs")]
+    pub method: TemperatureFitMethod,
+    /// Maximal number of LBFGS iterations, starting from a temperature of 1 (default: 50).
+    #[config(default = 50)]
+    pub max_iter: usize,
+    /// Smallest temperature of the grid search (default: 0.05).
+    #[config(default = 0.05)]
+    pub min_temperature: f64,
+    /// Largest temperature of the grid search (default: 10.0).
+    #[config(default = 10.0)]
+    pub max_temperature: f64,
+    /// Number of temperatures evaluated by the grid search (default: 100).
+    #[config(default = 100)]
+    pub num_steps: usize,
+}
+
+/// Post-hoc calibration of a classifier, by dividing its logits by a single temperature.
+///
+/// The temperature is fitted on held-out data to minimize the negative log-likelihood, which
+/// doesn't change the predicted classes but makes the probabilities match the accuracy.
+///
+/// Reference: "On Calibration of Modern Neural Networks" <https://arxiv.org/abs/1706.04599>
+#[derive(Module, Debug)]
+pub struct TemperatureScaling {
+    /// The log of the temperature, which keeps the temperature positive during the fit.
+    log_temperature: Param<Tensor<1>>,
+}
+
+impl TemperatureScaling {
+    /// Creates the module with the given temperature.
+    pub fn new(temperature: f64, device: &Device) -> Self {
+        assert!(temperature > 0.0, "The temperature must be positive.");
+
+        let log_temperature = Tensor::from_floats([temperature.ln()], device);
+        Self {
+            log_temperature: Param::from_tensor(log_temperature),
+        }
+    }
+
+    /// The temperature.
+    pub fn temperature(&self) -> f64 {
+        self.log_temperature.val().exp().into_scalar()
+    }
+
+    /// Applies the temperature to logits of shape `[batch_size, num_classes]`.
+    pub fn forward(&self, logits: Tensor<2>) -> Tensor<2> {
+        let temperature = self.log_temperature.val().exp().unsqueeze::<2>();
+        logits / temperature
+    }
+
+    /// The mean negative log-likelihood of the targets with the scaled logits.
+    fn loss(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> Tensor<1> {
+        log_softmax(self.forward(logits), 1)
+            .gather(1, targets.unsqueeze_dim(1))
+            .mean()
+            .neg()
+    }
+}
+
+impl TemperatureScalingConfig {
+    /// Fits the temperature on logits of shape `[num_items, num_classes]` and their target
+    /// classes of shape `[num_items]`.
+    pub fn fit(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> TemperatureScaling {
+        let logits = logits.detach();
+
+        match self.method {
+            TemperatureFitMethod::Lbfgs => self.fit_lbfgs(logits, targets),
+            TemperatureFitMethod::GridSearch => self.fit_grid_search(logits, targets),
+        }
+    }
+
+    /// Fits the temperature on a validation data loader.
+    ///
+    /// The `forward` function returns the logits and the target classes of a batch, which are
+    /// collected before fitting the temperature with [fit](Self::fit).
+    pub fn fit_loader<I, F>(
+        &self,
+        dataloader: Arc<dyn DataLoader<I>>,
+        mut forward: F,
+    ) -> TemperatureScaling
+    where
+        F: FnMut(I) -> (Tensor<2>, Tensor<1, Int>),
+    {
+        let (logits, targets): (Vec<_>, Vec<_>) = dataloader
+            .iter()
+            .map(|batch| {
+                let (logits, targets) = forward(batch);
+                (logits.detach(), targets)
+            })
+            .unzip();
+        assert!(!logits.is_empty(), "The data loader must have at least 1 batch.");
+
+        self.fit(Tensor::cat(logits, 0), Tensor::cat(targets, 0))
+    }
+
+    fn fit_lbfgs(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> TemperatureScaling {
+        let device = logits.device().inner().autodiff();
+        let logits = Tensor::<2>::from_data(logits.into_data(), &device);
+        let targets = Tensor::<1, Int>::from_data(targets.into_data(), &device);
+
+        let mut optimizer = LBFGSConfig::new()
+            .with_max_iter(self.max_iter)
+            .with_line_search_fn(LineSearchFn::StrongWolfe)
+            .init();
+        let mut closure = |module: TemperatureScaling| {
+            let loss = module.loss(logits.clone(), targets.clone());
+            let grads = GradientsParams::from_grads(loss.backward(), &module);
+
+            (loss.into_scalar::<f64>(), grads)
+        };
+
+        let module = TemperatureScaling::new(1.0, &device);
+        let (module, _loss) = optimizer.step(1.0, module, &mut closure);
+
+        module.valid()
+    }
+
+    fn fit_grid_search(&self, logits: Tensor<2>, targets: Tensor<1, Int>) -> TemperatureScaling {
+        assert!(
+            0.0 < self.min_temperature && self.min_temperature <= self.max_temperature,
+            "The temperature range must be positive and non-empty."
+        );
+        assert!(self.num_steps > 0, "The grid search requires at least 1 step.");
+
+        let device = logits.device();
+        let (min, max) = (self.min_temperature.ln(), self.max_temperature.ln());
+        let step = match self.num_steps {
+            1 => 0.0,
+            steps => (max - min) / (steps - 1) as f64,
+        };
+
+        let mut best = (f64::INFINITY, 1.0);
+        for i in 0..self.num_steps {
+            let temperature = (min + step * i as f64).exp();
+            let loss = TemperatureScaling::new(temperature, &device)
+                .loss(logits.clone(), targets.clone())
+                .into_scalar::<f64>();
+            if loss < best.0 {
+                best = (loss, temperature);
+            }
+        }
+
+        TemperatureScaling::new(best.1, &device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::{TensorData, Tolerance};
+
+    /// Confident logits whose predictions are only correct 2 times out of 3, so the optimal
+    /// temperature gives a confidence of 2/3: `sigmoid(4 / T) = 2 / 3`, or `T = 4 / ln(2)`.
+    fn overconfident() -> (Tensor<2>, Tensor<1, Int>) {
+        let device = Default::default();
+        let logits = Tensor::from_data(
+            [[4.0, 0.0], [4.0, 0.0], [4.0, 0.0], [0.0, 4.0], [0.0, 4.0], [0.0, 4.0]],
+            &device,
+        );
+        let targets = Tensor::from_data([0, 0, 1, 1, 1, 0], &device);
+
+        (logits, targets)
+    }
+
+    #[test]
+    fn test_temperature_scaling_forward() {
+        let device = Default::default();
+        let module = TemperatureScaling::new(2.0, &device);
+        let logits = Tensor::<2>::from_data([[2.0, -4.0]], &device);
+
+        assert!((module.temperature() - 2.0).abs() < 1e-5);
+        module
+            .forward(logits)
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([[1.0, -2.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_temperature_scaling_fit_grid_search() {
+        let (logits, targets) = overconfident();
+        let config = TemperatureScalingConfig::new()
+            .with_method(TemperatureFitMethod::GridSearch)
+            .with_num_steps(201);
+
+        let module = config.fit(logits, targets);
+
+        let expected = 4.0 / 2f64.ln();
+        assert!((module.temperature() - expected).abs() / expected < 0.05);
+    }
+
+    #[test]
+    fn test_temperature_scaling_fit_lbfgs() {
+        let (logits, targets) = overconfident();
+
+        let module = TemperatureScalingConfig::new().fit(logits, targets);
+
+        let expected = 4.0 / 2f64.ln();
+        assert!((module.temperature() - expected).abs() / expected < 0.01);
+    }
+}
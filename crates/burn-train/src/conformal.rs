@@ -0,0 +1,185 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::tensor::{Bool, Int, Tensor};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of split-conformal prediction.
+///
+/// A fitted model is calibrated on held-out data that wasn't used for training, by computing a
+/// quantile of the nonconformity scores of the calibration items. With exchangeable data, the
+/// prediction sets and intervals then contain the true value with a probability of at least
+/// `1 - alpha`.
+///
+/// Reference: "A Gentle Introduction to Conformal Prediction and Distribution-Free Uncertainty
+/// Quantification" <https://arxiv.org/abs/2107.07511>
+#[derive(Config, Debug)]
+pub struct ConformalConfig {
+    /// The miscoverage rate, between 0 and 1 (default: 0.1).
+    #[config(default = 0.1)]
+    pub alpha: f64,
+}
+
+impl ConformalConfig {
+    /// Calibrates a classifier on predicted probabilities of shape `[num_items, num_classes]` and
+    /// their target classes of shape `[num_items]`.
+    ///
+    /// The nonconformity score of an item is one minus the probability of its target class.
+    pub fn calibrate_classifier(
+        &self,
+        probabilities: Tensor<2>,
+        targets: Tensor<1, Int>,
+    ) -> ConformalClassifier {
+        let [num_items, _num_classes] = probabilities.dims();
+        let scores = probabilities
+            .gather(1, targets.unsqueeze_dim(1))
+            .reshape([num_items])
+            .neg()
+            .add_scalar(1.0);
+
+        ConformalClassifier {
+            threshold: conformal_quantile(scores, self.alpha),
+        }
+    }
+
+    /// Calibrates a regressor on predictions and their targets of the same shape.
+    ///
+    /// The nonconformity score of an item is the absolute error of its prediction, so every
+    /// element of the tensors counts as a calibration item.
+    pub fn calibrate_regressor<const D: usize>(
+        &self,
+        predictions: Tensor<D>,
+        targets: Tensor<D>,
+    ) -> ConformalRegressor {
+        let num_items = predictions.shape().num_elements();
+        let scores = (targets - predictions).abs().reshape([num_items]);
+
+        ConformalRegressor {
+            half_width: conformal_quantile(scores, self.alpha),
+        }
+    }
+}
+
+/// Computes the conformal quantile of nonconformity scores of shape `[num_items]`.
+///
+/// This is the `ceil((num_items + 1) * (1 - alpha))`-th smallest score, the finite-sample
+/// correction of the `1 - alpha` quantile. When there are too few scores for the requested
+/// coverage, the quantile is infinite.
+pub fn conformal_quantile(scores: Tensor<1>, alpha: f64) -> f64 {
+    assert!(
+        0.0 < alpha && alpha < 1.0,
+        "The miscoverage rate must be between 0 and 1."
+    );
+    let [num_items] = scores.dims();
+    assert!(num_items > 0, "Conformal calibration requires at least 1 item.");
+
+    let rank = ((num_items + 1) as f64 * (1.0 - alpha)).ceil() as usize;
+    if rank > num_items {
+        return f64::INFINITY;
+    }
+
+    scores.sort(0).slice([rank - 1..rank]).into_scalar()
+}
+
+/// A classifier calibrated with [split-conformal prediction](ConformalConfig).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConformalClassifier {
+    threshold: f64,
+}
+
+impl ConformalClassifier {
+    /// The conformal quantile of the nonconformity scores.
+    ///
+    /// A class is part of a prediction set when its probability is at least `1 - threshold`.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Builds the prediction sets of probabilities of shape `[batch_size, num_classes]`.
+    ///
+    /// The output has the same shape, and is true for the classes of each prediction set.
+    pub fn prediction_sets(&self, probabilities: Tensor<2>) -> Tensor<2, Bool> {
+        probabilities.greater_equal_elem(1.0 - self.threshold)
+    }
+}
+
+/// A regressor calibrated with [split-conformal prediction](ConformalConfig).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConformalRegressor {
+    half_width: f64,
+}
+
+impl ConformalRegressor {
+    /// The conformal quantile of the absolute errors, which is the half-width of the intervals.
+    pub fn half_width(&self) -> f64 {
+        self.half_width
+    }
+
+    /// Builds the prediction intervals around the predictions, and returns their lower and upper
+    /// bounds.
+    pub fn intervals<const D: usize>(&self, predictions: Tensor<D>) -> (Tensor<D>, Tensor<D>) {
+        let lower = predictions.clone().sub_scalar(self.half_width);
+        let upper = predictions.add_scalar(self.half_width);
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::TensorData;
+
+    #[test]
+    fn test_conformal_quantile() {
+        let device = Default::default();
+        let scores = Tensor::<1>::from_data([9.0, 3.0, 1.0, 7.0, 5.0, 2.0, 8.0, 4.0, 6.0], &device);
+
+        // ceil(10 * 0.8) = 8.
+        assert_eq!(conformal_quantile(scores.clone(), 0.2), 8.0);
+        // ceil(10 * 0.95) = 10 > 9 scores.
+        assert_eq!(conformal_quantile(scores, 0.05), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_conformal_classifier() {
+        let device = Default::default();
+        let probabilities = Tensor::<2>::from_data(
+            [[0.9, 0.1], [0.8, 0.2], [0.3, 0.7], [0.4, 0.6]],
+            &device,
+        );
+        let targets = Tensor::from_data([0, 0, 1, 0], &device);
+
+        // The scores are 0.1, 0.2, 0.3 and 0.6, and ceil(5 * 0.35) = 2.
+        let classifier = ConformalConfig::new()
+            .with_alpha(0.65)
+            .calibrate_classifier(probabilities, targets);
+        assert!((classifier.threshold() - 0.2).abs() < 1e-6);
+
+        let probabilities = Tensor::from_data([[0.85, 0.15], [0.5, 0.5]], &device);
+        let sets = classifier.prediction_sets(probabilities);
+        sets.into_data()
+            .assert_eq(&TensorData::from([[true, false], [false, false]]), false);
+    }
+
+    #[test]
+    fn test_conformal_regressor() {
+        let device = Default::default();
+        let predictions = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let targets = Tensor::<2>::from_data([[1.5, 1.0], [3.0, 6.0]], &device);
+
+        // The absolute errors are 0.5, 1, 0 and 2, and ceil(5 * 0.5) = 3.
+        let regressor = ConformalConfig::new()
+            .with_alpha(0.5)
+            .calibrate_regressor(predictions, targets);
+        assert_eq!(regressor.half_width(), 1.0);
+
+        let (lower, upper) = regressor.intervals(Tensor::<1>::from_data([0.0, 2.0], &device));
+        lower
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, 1.0]), false);
+        upper
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 3.0]), false);
+    }
+}
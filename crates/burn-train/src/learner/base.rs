@@ -5,8 +5,8 @@ use crate::checkpoint::{
 use crate::components::LearningComponentsTypes;
 use crate::metric::store::EventStoreClient;
 use crate::{
-    CloneEarlyStoppingStrategy, InferenceStep, TrainOutput, TrainStep, TrainingModelInput,
-    TrainingModelOutput,
+    CloneEarlyStoppingStrategy, InferenceStep, ModelEma, ModelEmaConfig, ModelEmaRecord,
+    TrainOutput, TrainStep, TrainingModelInput, TrainingModelOutput,
 };
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::tensor::Device;
@@ -24,6 +24,8 @@ pub type LearnerOptimizerRecord<LC> = <<LC as LearningComponentsTypes>::Optimize
 /// The record of the LR scheduler.
 pub type LearnerSchedulerRecord<LC> =
     <<LC as LearningComponentsTypes>::LrScheduler as LrScheduler>::Record;
+/// The record of the [model EMA](ModelEma).
+pub type LearnerEmaRecord<LC> = ModelEmaRecord<<LC as LearningComponentsTypes>::Model>;
 
 /// Learner struct encapsulating all components necessary to train a Neural Network model.
 pub struct Learner<LC: LearningComponentsTypes> {
@@ -31,6 +33,7 @@ pub struct Learner<LC: LearningComponentsTypes> {
     optim: LC::Optimizer,
     lr_scheduler: LC::LrScheduler,
     lr: f64,
+    ema: Option<ModelEma<LC::Model>>,
 }
 
 impl<LC: LearningComponentsTypes> Clone for Learner<LC> {
@@ -40,6 +43,7 @@ impl<LC: LearningComponentsTypes> Clone for Learner<LC> {
             optim: self.optim.clone(),
             lr_scheduler: self.lr_scheduler.clone(),
             lr: self.lr,
+            ema: self.ema.clone(),
         }
     }
 }
@@ -57,14 +61,28 @@ where
             optim,
             lr_scheduler,
             lr: 0.0,
+            ema: None,
         }
     }
 }
 
 impl<LC: LearningComponentsTypes> Learner<LC> {
+    /// Keep an [exponential moving average](ModelEma) of the model weights, updated after each
+    /// optimizer step.
+    ///
+    /// The averaged model replaces the current one for validation, is saved with the
+    /// checkpoints, and is the model returned at the end of the training.
+    pub fn with_ema(mut self, config: ModelEmaConfig) -> Self {
+        self.ema = Some(config.init(&self.model));
+        self
+    }
+
     /// Fork the learner's model to the given device.
     pub fn fork(&mut self, device: &Device) {
         self.model = self.model().fork(device);
+        if let Some(ema) = &mut self.ema {
+            ema.fork(device);
+        }
     }
 
     /// Returns the current model.
@@ -72,6 +90,20 @@ impl<LC: LearningComponentsTypes> Learner<LC> {
         self.model.clone()
     }
 
+    /// Returns the model to evaluate: the [averaged model](ModelEma) when enabled, or the
+    /// current model.
+    pub fn eval_model(&self) -> LC::Model {
+        match &self.ema {
+            Some(ema) => ema.model(),
+            None => self.model(),
+        }
+    }
+
+    /// Returns the [exponential moving average](ModelEma) of the model weights, if enabled.
+    pub fn ema(&self) -> Option<&ModelEma<LC::Model>> {
+        self.ema.as_ref()
+    }
+
     /// Returns the current learning rate.
     pub fn lr_current(&self) -> f64 {
         self.lr
@@ -82,6 +114,11 @@ impl<LC: LearningComponentsTypes> Learner<LC> {
         self.lr = self.lr_scheduler.step();
     }
 
+    /// Overrides the learning rate until the next step of the scheduler.
+    pub(crate) fn set_lr(&mut self, lr: f64) {
+        self.lr = lr;
+    }
+
     /// Runs a step of the model for training, which executes the forward and backward passes.
     ///
     /// # Arguments
@@ -104,6 +141,7 @@ impl<LC: LearningComponentsTypes> Learner<LC> {
     /// * `grads`: The gradients of each parameter in the current model.
     pub fn optimizer_step(&mut self, grads: GradientsParams) {
         self.model = self.model().optimize(&mut self.optim, self.lr, grads);
+        self.update_ema();
     }
 
     /// Optimize the current module with the provided gradients and learning rate.
@@ -115,6 +153,13 @@ impl<LC: LearningComponentsTypes> Learner<LC> {
     /// * `grads`: Multiple gradients associated to each parameter in the current model.
     pub fn optimizer_step_multi(&mut self, grads: MultiGradientsParams) {
         self.model = self.model().optimize_multi(&mut self.optim, self.lr, grads);
+        self.update_ema();
+    }
+
+    fn update_ema(&mut self) {
+        if let Some(ema) = &mut self.ema {
+            ema.update(&self.model);
+        }
     }
 
     /// Load the module state from a [record](LearnerModelRecord<LC>).
@@ -131,6 +176,12 @@ impl<LC: LearningComponentsTypes> Learner<LC> {
     pub fn load_scheduler(&mut self, record: LearnerSchedulerRecord<LC>) {
         self.lr_scheduler = self.lr_scheduler.clone().load_record(record);
     }
+
+    /// Load the state of the learner's [model EMA](ModelEma) as a [record](LearnerEmaRecord<LC>),
+    /// if enabled.
+    pub fn load_ema(&mut self, record: LearnerEmaRecord<LC>) {
+        self.ema = self.ema.take().map(|ema| ema.load_record(record));
+    }
 }
 
 #[derive(new)]
@@ -140,9 +191,19 @@ pub struct LearningCheckpointer<LC: LearningComponentsTypes> {
     optim: AsyncCheckpointer<LearnerOptimizerRecord<LC>>,
     lr_scheduler: AsyncCheckpointer<LearnerSchedulerRecord<LC>>,
     strategy: Box<dyn CheckpointingStrategy>,
+    #[new(default)]
+    ema: Option<AsyncCheckpointer<LearnerEmaRecord<LC>>>,
+    #[new(default)]
+    last_checkpoint: Option<usize>,
 }
 
 impl<LC: LearningComponentsTypes> LearningCheckpointer<LC> {
+    /// Register the checkpointer of the [model EMA](ModelEma), used when the learner has one.
+    pub fn with_ema(mut self, checkpointer: AsyncCheckpointer<LearnerEmaRecord<LC>>) -> Self {
+        self.ema = Some(checkpointer);
+        self
+    }
+
     /// Create checkpoint for the training process.
     pub fn checkpoint(&mut self, learner: &Learner<LC>, epoch: usize, store: &EventStoreClient) {
         let actions = self.strategy.checkpointing(epoch, store);
@@ -150,6 +211,9 @@ impl<LC: LearningComponentsTypes> LearningCheckpointer<LC> {
         for action in actions {
             match action {
                 CheckpointingAction::Delete(epoch) => {
+                    if self.last_checkpoint == Some(epoch) {
+                        self.last_checkpoint = None;
+                    }
                     self.model
                         .delete(epoch)
                         .expect("Can delete model checkpoint.");
@@ -159,6 +223,11 @@ impl<LC: LearningComponentsTypes> LearningCheckpointer<LC> {
                     self.lr_scheduler
                         .delete(epoch)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    if let Some(checkpointer) = &self.ema {
+                        checkpointer
+                            .delete(epoch)
+                            .expect("Can delete model EMA checkpoint.");
+                    }
                 }
                 CheckpointingAction::Save => {
                     self.model
@@ -170,11 +239,22 @@ impl<LC: LearningComponentsTypes> LearningCheckpointer<LC> {
                     self.lr_scheduler
                         .save(epoch, learner.lr_scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    if let (Some(checkpointer), Some(ema)) = (&self.ema, &learner.ema) {
+                        checkpointer
+                            .save(epoch, ema.to_record())
+                            .expect("Can save model EMA checkpoint.");
+                    }
+                    self.last_checkpoint = Some(epoch);
                 }
             }
         }
     }
 
+    /// The epoch of the last checkpoint created by this checkpointer, if it is still available.
+    pub fn last_checkpoint(&self) -> Option<usize> {
+        self.last_checkpoint
+    }
+
     /// Load a training checkpoint.
     pub fn load_checkpoint(
         &self,
@@ -200,6 +280,13 @@ impl<LC: LearningComponentsTypes> LearningCheckpointer<LC> {
             .expect("Can load learning rate scheduler checkpoint.");
         learner.load_scheduler(record);
 
+        if let (Some(checkpointer), Some(_)) = (&self.ema, &learner.ema) {
+            let record = checkpointer
+                .restore(epoch, device)
+                .expect("Can load model EMA checkpoint.");
+            learner.load_ema(record);
+        }
+
         learner
     }
 }
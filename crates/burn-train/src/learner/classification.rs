@@ -1,6 +1,6 @@
 use crate::metric::{
-    AccuracyInput, Adaptor, ConfusionStatsInput, HammingScoreInput, LossInput, PerplexityInput,
-    TopKAccuracyInput, processor::ItemLazy,
+    AccuracyInput, Adaptor, ConfusionMatrixInput, ConfusionStatsInput, HammingScoreInput,
+    LossInput, PerplexityInput, TopKAccuracyInput, processor::ItemLazy,
 };
 use burn_core::tensor::{Device, Int, Tensor, Transaction};
 use burn_flex::FlexDevice;
@@ -10,6 +10,7 @@ use burn_flex::FlexDevice;
 /// Supported metrics:
 /// - Accuracy
 /// - AUROC
+/// - ConfusionMatrix
 /// - TopKAccuracy
 /// - Perplexity
 /// - Precision (via ConfusionStatsInput)
@@ -54,6 +55,12 @@ impl Adaptor<AccuracyInput> for ClassificationOutput {
     }
 }
 
+impl Adaptor<ConfusionMatrixInput> for ClassificationOutput {
+    fn adapt(&self) -> ConfusionMatrixInput {
+        ConfusionMatrixInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
 impl Adaptor<LossInput> for ClassificationOutput {
     fn adapt(&self) -> LossInput {
         LossInput::new(self.loss.clone())
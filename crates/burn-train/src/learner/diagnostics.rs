@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use burn_core as burn;
+
+use crate::metric::{ParameterUpdate, TrainingDiagnostics};
+use burn::module::{Module, ModuleVisitor, Param, ParamId};
+use burn::tensor::Tensor;
+use burn_optim::GradientsParams;
+
+/// Computes the [training diagnostics](TrainingDiagnostics) of the steps of a training loop.
+///
+/// The gradients of the previous batch and the weights before the optimizer step are kept as
+/// flattened tensors, so the diagnostics cost up to twice the memory of the model. All the norms
+/// of a step are read back from the device at once.
+#[derive(Default)]
+pub(crate) struct DiagnosticsCollector {
+    previous_grads: HashMap<ParamId, Tensor<1>>,
+    previous_grad_norm_sq: Option<f64>,
+    weights: HashMap<ParamId, Tensor<1>>,
+    current: Option<TrainingDiagnostics>,
+}
+
+impl DiagnosticsCollector {
+    /// Records the gradients of a batch, before they are accumulated or applied.
+    pub(crate) fn record_gradients<M: Module>(
+        &mut self,
+        model: &M,
+        grads: &GradientsParams,
+        batch_size: usize,
+    ) {
+        let mut visitor = GradientsVisitor {
+            grads,
+            previous: &self.previous_grads,
+            current: HashMap::new(),
+            norm_sq: Vec::new(),
+            dot: Vec::new(),
+        };
+        model.visit(&mut visitor);
+
+        let has_previous = !visitor.dot.is_empty();
+        let totals = [visitor.norm_sq, visitor.dot]
+            .into_iter()
+            .filter(|sums| !sums.is_empty())
+            .map(|sums| Tensor::cat(sums, 0).sum());
+        let values = read_sums(totals);
+        let grad_norm_sq = values.first().copied().unwrap_or_default();
+        let grad_dot_previous = has_previous.then(|| values[1]);
+
+        self.current = Some(TrainingDiagnostics {
+            batch_size,
+            grad_norm_sq,
+            grad_dot_previous,
+            previous_grad_norm_sq: grad_dot_previous.and(self.previous_grad_norm_sq),
+            updates: Vec::new(),
+        });
+        self.previous_grads = visitor.current;
+        self.previous_grad_norm_sq = Some(grad_norm_sq);
+    }
+
+    /// Records the weights before the optimizer step.
+    pub(crate) fn record_weights<M: Module>(&mut self, model: &M) {
+        let mut visitor = WeightsVisitor::default();
+        model.visit(&mut visitor);
+
+        self.weights = visitor.weights;
+    }
+
+    /// Compares the weights after the optimizer step with the recorded ones.
+    pub(crate) fn record_updates<M: Module>(&mut self, model: &M) {
+        let mut visitor = UpdatesVisitor {
+            previous: core::mem::take(&mut self.weights),
+            path: Vec::new(),
+            paths: Vec::new(),
+            sums: Vec::new(),
+        };
+        model.visit(&mut visitor);
+
+        // The sums alternate the update and the weight norms of each parameter.
+        let values = read_sums(visitor.sums.into_iter());
+        let updates = visitor
+            .paths
+            .into_iter()
+            .zip(values.chunks(2))
+            .map(|(path, norms)| ParameterUpdate::new(path, norms[0], norms[1]))
+            .collect();
+
+        if let Some(current) = self.current.as_mut() {
+            current.updates = updates;
+        }
+    }
+
+    /// The diagnostics of the step.
+    pub(crate) fn finish(&mut self) -> Option<TrainingDiagnostics> {
+        self.current.take()
+    }
+}
+
+/// Reads scalar sums back from the device with a single transfer.
+fn read_sums(sums: impl Iterator<Item = Tensor<1>>) -> Vec<f64> {
+    let sums = sums.collect::<Vec<_>>();
+    if sums.is_empty() {
+        return Vec::new();
+    }
+
+    Tensor::cat(sums, 0).into_data().iter::<f64>().collect()
+}
+
+/// Flattens a float parameter, outside of the autodiff graph.
+fn flat_weights<const D: usize>(param: &Param<Tensor<D>>) -> Tensor<1> {
+    let tensor = param.val();
+    let tensor = if tensor.device().is_autodiff() {
+        tensor.inner()
+    } else {
+        tensor
+    };
+    let num_elements = tensor.shape().num_elements();
+
+    tensor.reshape([num_elements])
+}
+
+/// Sums the squared norm of the gradients, and their dot product with the previous gradients.
+struct GradientsVisitor<'a> {
+    grads: &'a GradientsParams,
+    previous: &'a HashMap<ParamId, Tensor<1>>,
+    current: HashMap<ParamId, Tensor<1>>,
+    norm_sq: Vec<Tensor<1>>,
+    dot: Vec<Tensor<1>>,
+}
+
+impl ModuleVisitor for GradientsVisitor<'_> {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let Some(grad) = self.grads.get::<D>(param.id) else {
+            return;
+        };
+        let num_elements = grad.shape().num_elements();
+        let grad = grad.reshape([num_elements]);
+
+        self.norm_sq.push(grad.clone().square().sum());
+        if let Some(previous) = self.previous.get(&param.id) {
+            self.dot.push((grad.clone() * previous.clone()).sum());
+        }
+        self.current.insert(param.id, grad);
+    }
+}
+
+/// Collects the flattened float weights of a model.
+#[derive(Default)]
+struct WeightsVisitor {
+    weights: HashMap<ParamId, Tensor<1>>,
+}
+
+impl ModuleVisitor for WeightsVisitor {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        self.weights.insert(param.id, flat_weights(param));
+    }
+}
+
+/// Sums the squared norm of the update and of the previous weights of each parameter, with its
+/// path in the module.
+struct UpdatesVisitor {
+    previous: HashMap<ParamId, Tensor<1>>,
+    path: Vec<String>,
+    paths: Vec<String>,
+    sums: Vec<Tensor<1>>,
+}
+
+impl ModuleVisitor for UpdatesVisitor {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let Some(previous) = self.previous.remove(&param.id) else {
+            return;
+        };
+        let weights = flat_weights(param);
+
+        self.sums.push((weights - previous.clone()).square().sum());
+        self.sums.push(previous.square().sum());
+        self.paths.push(self.path.join("."));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::Device;
+
+    fn param(id: ParamId, values: [f32; 2]) -> Param<Tensor<1>> {
+        Param::initialized(id, Tensor::from_data(values, &Device::default()))
+    }
+
+    fn grads(id: ParamId, values: [f32; 2]) -> GradientsParams {
+        let mut grads = GradientsParams::new();
+        grads.register(id, Tensor::<1>::from_data(values, &Device::default()));
+        grads
+    }
+
+    #[test]
+    fn should_compare_successive_gradients_and_weights() {
+        let id = ParamId::new();
+        let mut collector = DiagnosticsCollector::default();
+        let model = param(id, [3.0, 4.0]);
+
+        collector.record_gradients(&model, &grads(id, [1.0, 2.0]), 4);
+        let first = collector.finish().unwrap();
+        assert_eq!(first.grad_norm_sq, 5.0);
+        assert_eq!(first.grad_dot_previous, None);
+
+        collector.record_gradients(&model, &grads(id, [3.0, -1.0]), 4);
+        collector.record_weights(&model);
+        collector.record_updates(&param(id, [3.0, 3.0]));
+        let second = collector.finish().unwrap();
+
+        assert_eq!(second.grad_norm_sq, 10.0);
+        assert_eq!(second.grad_dot_previous, Some(1.0));
+        assert_eq!(second.previous_grad_norm_sq, Some(5.0));
+        assert_eq!(
+            second.updates,
+            vec![ParameterUpdate::new(String::new(), 1.0, 25.0)]
+        );
+    }
+}
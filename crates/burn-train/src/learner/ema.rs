@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{Module, ModuleMapper, ModuleVisitor, Param, ParamId};
+use burn::tensor::{Device, Tensor};
+
+/// The record of a [model EMA](ModelEma): the averaged weights and the number of updates.
+pub type ModelEmaRecord<M> = (<M as Module>::Record, usize);
+
+/// Configuration to create a [model EMA](ModelEma).
+#[derive(Config, Debug)]
+pub struct ModelEmaConfig {
+    /// The decay of the average, the weight of the previous average at each update.
+    #[config(default = 0.9999)]
+    pub decay: f64,
+
+    /// The number of updates over which the decay ramps up linearly from 0, so that the average
+    /// forgets the initial weights quickly at the start of the training.
+    #[config(default = 0)]
+    pub warmup_steps: usize,
+}
+
+impl ModelEmaConfig {
+    /// Initialize the average with the weights of the model.
+    pub fn init<M: Module>(&self, model: &M) -> ModelEma<M> {
+        assert!(
+            (0.0..=1.0).contains(&self.decay),
+            "EMA decay should be between 0 and 1, got {}",
+            self.decay
+        );
+
+        ModelEma {
+            model: model.clone(),
+            decay: self.decay,
+            warmup_steps: self.warmup_steps,
+            step: 0,
+        }
+    }
+}
+
+/// Exponential moving average (EMA) of the weights of a model during training.
+///
+/// After each optimizer step, the averaged weights are updated as
+/// `average = decay * average + (1 - decay) * weights`. The averaged model usually generalizes
+/// better than the last weights, and is the one validated and returned by the
+/// [learner](crate::Learner) when enabled with [with_ema](crate::Learner::with_ema).
+///
+/// The running states of the model, like the statistics of batch normalization, are shared with
+/// the model rather than averaged.
+#[derive(Clone, Debug)]
+pub struct ModelEma<M> {
+    model: M,
+    decay: f64,
+    warmup_steps: usize,
+    step: usize,
+}
+
+impl<M: Module> ModelEma<M> {
+    /// The averaged model.
+    pub fn model(&self) -> M {
+        self.model.clone()
+    }
+
+    /// The number of updates of the average.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The decay of the next update, lower than the configured one during the warmup.
+    pub fn current_decay(&self) -> f64 {
+        if self.step < self.warmup_steps {
+            self.decay * self.step as f64 / self.warmup_steps as f64
+        } else {
+            self.decay
+        }
+    }
+
+    /// Update the average with the current weights of the model.
+    pub fn update(&mut self, model: &M) {
+        let mut collector = WeightsCollector::default();
+        model.visit(&mut collector);
+
+        let mut mapper = AverageMapper {
+            weights: collector.weights,
+            decay: self.current_decay(),
+        };
+        self.model = self.model.clone().map(&mut mapper);
+        self.step += 1;
+    }
+
+    /// Fork the averaged model to the given device.
+    pub fn fork(&mut self, device: &Device) {
+        self.model = self.model.clone().fork(device);
+    }
+
+    /// Create a [record](ModelEmaRecord) of the average.
+    pub fn to_record(&self) -> ModelEmaRecord<M> {
+        (self.model.clone().into_record(), self.step)
+    }
+
+    /// Load the average from a [record](ModelEmaRecord).
+    pub fn load_record(mut self, record: ModelEmaRecord<M>) -> Self {
+        let (model, step) = record;
+        self.model = self.model.load_record(model);
+        self.step = step;
+        self
+    }
+}
+
+/// Collects the flattened float weights of a model, outside of the autodiff graph.
+#[derive(Default)]
+struct WeightsCollector {
+    weights: HashMap<ParamId, Tensor<1>>,
+}
+
+impl ModuleVisitor for WeightsCollector {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let tensor = param.val();
+        let tensor = if tensor.device().is_autodiff() {
+            tensor.inner()
+        } else {
+            tensor
+        };
+        let num_elements = tensor.shape().num_elements();
+
+        self.weights
+            .insert(param.id, tensor.reshape([num_elements]));
+    }
+}
+
+/// Moves the float weights of the averaged model towards the collected ones.
+struct AverageMapper {
+    weights: HashMap<ParamId, Tensor<1>>,
+    decay: f64,
+}
+
+impl ModuleMapper for AverageMapper {
+    fn map_float<const D: usize>(&mut self, param: Param<Tensor<D>>) -> Param<Tensor<D>> {
+        let (id, tensor, mapper) = param.consume();
+
+        let tensor = match self.weights.remove(&id) {
+            Some(weights) => {
+                let decay = self.decay;
+                let update = |average: Tensor<D>| {
+                    let weights = weights
+                        .reshape(average.shape())
+                        .to_device(&average.device());
+                    average.mul_scalar(decay) + weights.mul_scalar(1.0 - decay)
+                };
+
+                // The average is never trained, so it is updated outside of the graph.
+                if tensor.device().is_autodiff() {
+                    let is_require_grad = tensor.is_require_grad();
+                    let mut tensor = Tensor::from_inner(update(tensor.inner()));
+                    if is_require_grad {
+                        tensor = tensor.require_grad();
+                    }
+                    tensor
+                } else {
+                    update(tensor)
+                }
+            }
+            None => tensor,
+        };
+
+        Param::from_mapped_value(id, tensor, mapper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::{TensorData, Tolerance};
+
+    fn weights(model: &Param<Tensor<1>>, values: [f32; 2]) -> Param<Tensor<1>> {
+        let tensor = Tensor::from_data(values, &model.device());
+        Param::initialized(model.id, tensor)
+    }
+
+    #[test]
+    fn should_average_weights_after_warmup() {
+        let model = Param::from_tensor(Tensor::<1>::from_data([1.0, 2.0], &Device::default()));
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .with_warmup_steps(2)
+            .init(&model);
+
+        // The first update copies the weights, the second one is still warming up.
+        ema.update(&weights(&model, [4.0, 8.0]));
+        assert_eq!(ema.current_decay(), 0.25);
+        ema.update(&weights(&model, [0.0, 4.0]));
+        assert_eq!(ema.current_decay(), 0.5);
+        ema.update(&weights(&model, [2.0, 0.0]));
+
+        ema.model()
+            .val()
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([1.5, 2.5]), Tolerance::default());
+        assert_eq!(ema.step(), 3);
+    }
+
+    #[test]
+    fn should_restore_average_from_record() {
+        let model = Param::from_tensor(Tensor::<1>::from_data([1.0, 2.0], &Device::default()));
+        let mut ema = ModelEmaConfig::new().with_decay(0.5).init(&model);
+        ema.update(&weights(&model, [3.0, 4.0]));
+
+        let restored = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .init(&model)
+            .load_record(ema.to_record());
+
+        restored
+            .model()
+            .val()
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([2.0, 3.0]), Tolerance::default());
+        assert_eq!(restored.step(), 1);
+        assert_eq!(restored.current_decay(), 0.5);
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use burn_core::module::{Module, ModuleVisitor, Param, ParamId};
+use burn_core::tensor::Tensor;
+use burn_core::tensor::container::TensorContainer;
+use burn_optim::GradientsParams;
+
+use super::{
+    ClientTransport, ClientUpdate, FederatedAlgorithm, FederatedError, ModelState, RoundStart,
+    SecureAggregation,
+};
+use crate::{Learner, LearningComponentsTypes, TrainLoader};
+
+/// A client of a federated learning setup, which trains the global model of the
+/// [server](super::FederatedServer) on its local data.
+///
+/// Each round, the client runs local epochs with its [learner](Learner), using the learner's
+/// optimizer and learning rate scheduler, then sends the delta of its parameters to the server.
+pub struct FederatedClient<LC: LearningComponentsTypes, T> {
+    id: usize,
+    learner: Learner<LC>,
+    dataloader: TrainLoader<LC>,
+    transport: T,
+    local_epochs: usize,
+    secure_aggregation: Option<Arc<dyn SecureAggregation>>,
+}
+
+impl<LC: LearningComponentsTypes, T: ClientTransport> FederatedClient<LC, T> {
+    /// Create a client training on the data of the loader, connected to the server with the
+    /// transport.
+    pub fn new(id: usize, learner: Learner<LC>, dataloader: TrainLoader<LC>, transport: T) -> Self {
+        Self {
+            id,
+            learner,
+            dataloader,
+            transport,
+            local_epochs: 1,
+            secure_aggregation: None,
+        }
+    }
+
+    /// Set the number of local epochs of each round (default: 1).
+    pub fn with_local_epochs(mut self, local_epochs: usize) -> Self {
+        self.local_epochs = local_epochs;
+        self
+    }
+
+    /// Set the secure aggregation hooks used to mask the deltas of the client.
+    pub fn with_secure_aggregation(mut self, hooks: Arc<dyn SecureAggregation>) -> Self {
+        self.secure_aggregation = Some(hooks);
+        self
+    }
+
+    /// Take part in the rounds of the server until the training is done, and return the learner.
+    ///
+    /// # Errors
+    ///
+    /// If the parameters of the global model don't match the ones of the learner's model, or the
+    /// update can't be sent to the server.
+    pub fn run(mut self) -> Result<Learner<LC>, FederatedError> {
+        while let Some(round) = self.transport.receive() {
+            let update = self.round(round)?;
+            self.transport.send(update)?;
+        }
+
+        Ok(self.learner)
+    }
+
+    fn round(&mut self, round: RoundStart) -> Result<ClientUpdate, FederatedError> {
+        self.learner.model = round.model.load_into(self.learner.model())?;
+
+        let proximal = match round.algorithm {
+            FederatedAlgorithm::FedAvg => None,
+            FederatedAlgorithm::FedProx { mu } => {
+                let mut global = GlobalParams::default();
+                self.learner.model.visit(&mut global);
+                Some((mu, global.params))
+            }
+        };
+
+        for _ in 0..self.local_epochs {
+            for item in self.dataloader.iter() {
+                self.learner.lr_step();
+                let output = self.learner.train_step(item);
+
+                let grads = match &proximal {
+                    Some((mu, global)) => {
+                        let mut visitor = ProximalGrads {
+                            grads: output.grads,
+                            global,
+                            mu: *mu,
+                        };
+                        self.learner.model.visit(&mut visitor);
+                        visitor.grads
+                    }
+                    None => output.grads,
+                };
+                self.learner.optimizer_step(grads);
+            }
+        }
+
+        let num_samples = self.dataloader.num_items();
+        let mut delta = ModelState::from_module(&self.learner.model())
+            .delta(&round.model)?
+            .scale(num_samples as f64);
+        if let Some(hooks) = &self.secure_aggregation {
+            hooks.mask(self.id, round.round, &mut delta);
+        }
+
+        Ok(ClientUpdate {
+            round: round.round,
+            client: self.id,
+            num_samples,
+            delta,
+        })
+    }
+}
+
+/// Collects the parameters of the global model, outside of the autodiff graph.
+#[derive(Default)]
+struct GlobalParams {
+    params: TensorContainer<ParamId>,
+}
+
+impl ModuleVisitor for GlobalParams {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        self.params.register(param.id, param.val().inner());
+    }
+}
+
+/// Adds the gradient of the proximal term `mu / 2 * ||w - w_global||^2` to the gradients.
+struct ProximalGrads<'a> {
+    grads: GradientsParams,
+    global: &'a TensorContainer<ParamId>,
+    mu: f64,
+}
+
+impl ModuleVisitor for ProximalGrads<'_> {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let Some(grad) = self.grads.remove::<D>(param.id) else {
+            return;
+        };
+        let Some(global) = self.global.get::<Tensor<D>>(&param.id) else {
+            self.grads.register(param.id, grad);
+            return;
+        };
+
+        let proximal = (param.val().inner() - global).mul_scalar(self.mu);
+        self.grads.register(param.id, grad + proximal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::federated::{ServerTransport, channel_transport};
+    use crate::{InferenceStep, TrainOutput, TrainStep};
+    use burn_core as burn;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::tensor::{Device, TensorData, Tolerance};
+    use burn_optim::SgdConfig;
+
+    /// A model with the loss `||w||^2 / 2`, whose gradient is the weight.
+    #[derive(Module, Debug)]
+    struct TestModel {
+        weight: Param<Tensor<1>>,
+    }
+
+    impl TestModel {
+        fn new(values: [f32; 2], device: &Device) -> Self {
+            Self {
+                weight: Param::from_tensor(Tensor::from_data(values, device)),
+            }
+        }
+    }
+
+    impl core::fmt::Display for TestModel {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "TestModel")
+        }
+    }
+
+    impl TrainStep for TestModel {
+        type Input = ();
+        type Output = ();
+
+        fn step(&self, _item: ()) -> TrainOutput<()> {
+            let weight = self.weight.val();
+            let loss = (weight.clone() * weight).sum().div_scalar(2.0);
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    impl InferenceStep for TestModel {
+        type Input = ();
+        type Output = ();
+
+        fn step(&self, _item: ()) {}
+    }
+
+    #[derive(Clone)]
+    struct UnitBatcher;
+
+    impl Batcher<(), ()> for UnitBatcher {
+        fn batch(&self, _items: Vec<()>, _device: &Device) {}
+    }
+
+    /// Runs a round of a client with two steps of gradient descent with a learning rate of 0.5,
+    /// and returns the delta it sends to the server.
+    fn client_delta(algorithm: FederatedAlgorithm) -> TensorData {
+        let device = Device::default().autodiff();
+        // The client model is initialized separately from the global model.
+        let global = TestModel::new([2.0, -4.0], &device);
+        let learner = Learner::new(
+            TestModel::new([0.0, 0.0], &device),
+            SgdConfig::new().init(),
+            0.5,
+        );
+        let dataloader = DataLoaderBuilder::new(UnitBatcher).build(InMemDataset::new(vec![(); 2]));
+        let (mut server, mut clients) = channel_transport(1);
+
+        server
+            .broadcast(RoundStart {
+                round: 0,
+                model: ModelState::from_module(&global),
+                algorithm,
+            })
+            .unwrap();
+        server.close();
+        FederatedClient::new(0, learner, dataloader, clients.remove(0))
+            .run()
+            .unwrap();
+
+        let update = server.receive().unwrap();
+        assert_eq!(update.round, 0);
+        assert_eq!(update.num_samples, 2);
+        update.delta.get("weight").unwrap().clone()
+    }
+
+    #[test]
+    fn should_send_weighted_delta_of_global_model() {
+        // Each step halves the weight, so the local model ends at a quarter of the global model.
+        let delta = client_delta(FederatedAlgorithm::FedAvg);
+
+        delta.assert_approx_eq::<f32>(&TensorData::from([-3.0, 6.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_keep_local_model_close_to_global_model_with_fedprox() {
+        // The second step is cancelled by the proximal term, so the local model ends at half of
+        // the global model.
+        let delta = client_delta(FederatedAlgorithm::FedProx { mu: 1.0 });
+
+        delta.assert_approx_eq::<f32>(&TensorData::from([-2.0, 4.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_add_proximal_term_to_grads() {
+        let device = Device::default().autodiff();
+        let model = TestModel::new([3.0, 0.0], &device);
+        // The global model is loaded into the local one, as done at the start of a round.
+        let mut global = GlobalParams::default();
+        ModelState::from_module(&TestModel::new([1.0, 2.0], &device))
+            .load_into(model.clone())
+            .unwrap()
+            .visit(&mut global);
+
+        let mut grads = GradientsParams::new();
+        grads.register(
+            model.weight.id,
+            Tensor::<1>::from_data([1.0, 1.0], &device.inner()),
+        );
+        let mut visitor = ProximalGrads {
+            grads,
+            global: &global.params,
+            mu: 0.5,
+        };
+        model.visit(&mut visitor);
+
+        // grad + mu * (w - w_global)
+        let grad = visitor.grads.get::<1>(model.weight.id).unwrap();
+        grad.into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([2.0, 0.0]), Tolerance::default());
+    }
+}
@@ -0,0 +1,90 @@
+mod client;
+mod server;
+mod state;
+mod transport;
+
+pub use client::*;
+pub use server::*;
+pub use state::*;
+pub use transport::*;
+
+use burn_core::tensor::Shape;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The algorithm used to train a model with [federated learning](FederatedServer).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FederatedAlgorithm {
+    /// Federated averaging: the global model is updated with the average of the client deltas,
+    /// weighted by the number of samples of each client.
+    FedAvg,
+    /// FedAvg with a proximal term `mu / 2 * ||w - w_global||^2` added to the local objective of
+    /// the clients, which keeps the local models close to the global model when the data of the
+    /// clients is heterogeneous.
+    FedProx {
+        /// The weight of the proximal term.
+        mu: f64,
+    },
+}
+
+/// The error type for [federated learning](FederatedServer).
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FederatedError {
+    /// A parameter of the model is missing from the model state.
+    #[error("Parameter `{0}` is missing from the model state")]
+    MissingParameter(String),
+
+    /// A parameter of the model state isn't in the model.
+    #[error("Parameter `{0}` of the model state isn't in the model")]
+    UnexpectedParameter(String),
+
+    /// A parameter doesn't have the same shape in the model and the model state.
+    #[error("Parameter `{path}` has shape {expected:?}, but {actual:?} in the model state")]
+    ShapeMismatch {
+        /// The module path of the parameter.
+        path: String,
+        /// The shape of the parameter in the model.
+        expected: Shape,
+        /// The shape of the parameter in the model state.
+        actual: Shape,
+    },
+
+    /// A client sent an update for another round than the current one.
+    #[error("Client {client} sent an update for round {actual} during round {expected}")]
+    WrongRound {
+        /// The id of the client.
+        client: usize,
+        /// The current round.
+        expected: usize,
+        /// The round of the update.
+        actual: usize,
+    },
+
+    /// A client sent more than one update during a round.
+    #[error("Client {client} sent more than one update during round {round}")]
+    DuplicateUpdate {
+        /// The id of the client.
+        client: usize,
+        /// The current round.
+        round: usize,
+    },
+
+    /// The other side of the transport disconnected.
+    #[error("The federated transport is disconnected: {0}")]
+    Disconnected(String),
+}
+
+/// Hooks for secure aggregation protocols, with which the server only learns the sum of the
+/// client deltas.
+///
+/// The deltas are masked by the clients before being sent, for instance with pairwise masks that
+/// cancel out in the sum, and the sum is unmasked by the server before being applied. The deltas
+/// are weighted by the number of samples of their client before being masked, so the masks only
+/// need to cancel out in a plain sum.
+pub trait SecureAggregation: Send + Sync {
+    /// Mask the weighted delta of the client for the given round.
+    fn mask(&self, client: usize, round: usize, delta: &mut ModelState);
+
+    /// Unmask the sum of the masked deltas of the given round.
+    fn unmask(&self, round: usize, sum: &mut ModelState);
+}
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use burn_core::module::Module;
+
+use super::{
+    FederatedAlgorithm, FederatedError, ModelState, RoundStart, SecureAggregation, ServerTransport,
+};
+
+/// The server of a federated learning setup, also called parameter server.
+///
+/// Each round, the server sends the global model to the [clients](super::FederatedClient), which
+/// train it on their local data and send back the delta of their parameters. The global model is
+/// then updated with the average of the deltas, weighted by the number of samples of each client.
+pub struct FederatedServer<M, T> {
+    model: M,
+    transport: T,
+    num_clients: usize,
+    algorithm: FederatedAlgorithm,
+    secure_aggregation: Option<Arc<dyn SecureAggregation>>,
+}
+
+impl<M: Module, T: ServerTransport> FederatedServer<M, T> {
+    /// Create a server training the model with `num_clients` clients connected with the transport.
+    pub fn new(model: M, transport: T, num_clients: usize) -> Self {
+        Self {
+            model,
+            transport,
+            num_clients,
+            algorithm: FederatedAlgorithm::FedAvg,
+            secure_aggregation: None,
+        }
+    }
+
+    /// Set the federated algorithm (default: [FedAvg](FederatedAlgorithm::FedAvg)).
+    pub fn with_algorithm(mut self, algorithm: FederatedAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Set the secure aggregation hooks used to unmask the sum of the client deltas.
+    pub fn with_secure_aggregation(mut self, hooks: Arc<dyn SecureAggregation>) -> Self {
+        self.secure_aggregation = Some(hooks);
+        self
+    }
+
+    /// Run the given number of rounds and return the global model.
+    ///
+    /// # Errors
+    ///
+    /// If the transport disconnected, or a client sent an update for another round, more than one
+    /// update during a round, or parameters that don't match the global model. The clients are
+    /// notified that the training is done in all cases.
+    pub fn run(mut self, num_rounds: usize) -> Result<M, FederatedError> {
+        let result = self.rounds(num_rounds);
+        self.transport.close();
+        result?;
+
+        Ok(self.model)
+    }
+
+    fn rounds(&mut self, num_rounds: usize) -> Result<(), FederatedError> {
+        for round in 0..num_rounds {
+            log::info!("Starting federated round {round}");
+            self.transport.broadcast(RoundStart {
+                round,
+                model: ModelState::from_module(&self.model),
+                algorithm: self.algorithm,
+            })?;
+
+            let mut sum: Option<ModelState> = None;
+            let mut num_samples = 0;
+            let mut received = HashSet::with_capacity(self.num_clients);
+            for _ in 0..self.num_clients {
+                let update = self.transport.receive()?;
+                if update.round != round {
+                    return Err(FederatedError::WrongRound {
+                        client: update.client,
+                        expected: round,
+                        actual: update.round,
+                    });
+                }
+                if !received.insert(update.client) {
+                    return Err(FederatedError::DuplicateUpdate {
+                        client: update.client,
+                        round,
+                    });
+                }
+
+                num_samples += update.num_samples;
+                sum = Some(match sum {
+                    Some(sum) => sum.add(&update.delta)?,
+                    None => update.delta,
+                });
+            }
+
+            let Some(mut sum) = sum else {
+                continue;
+            };
+            if let Some(hooks) = &self.secure_aggregation {
+                hooks.unmask(round, &mut sum);
+            }
+
+            if num_samples > 0 {
+                let delta = sum.scale(1.0 / num_samples as f64);
+                self.model = delta.add_to(self.model.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::federated::{ClientTransport, ClientUpdate, channel_transport};
+    use burn_core::module::Param;
+    use burn_core::tensor::{Device, Tensor, TensorData, Tolerance};
+
+    #[test]
+    fn should_average_client_deltas_weighted_by_samples() {
+        let device = Device::default();
+        let model = Param::from_tensor(Tensor::<1>::from_data([1.0, 2.0], &device));
+        let (transport, clients) = channel_transport(2);
+
+        let clients = clients
+            .into_iter()
+            .enumerate()
+            .map(|(client, mut transport)| {
+                std::thread::spawn(move || {
+                    while let Some(round) = transport.receive() {
+                        // The first client moves by three times the parameters, the second
+                        // doesn't move but has twice as many samples.
+                        let num_samples = client + 1;
+                        let factor = if client == 0 { 3.0 } else { 0.0 };
+                        transport
+                            .send(ClientUpdate {
+                                round: round.round,
+                                client,
+                                num_samples,
+                                delta: round.model.scale(factor * num_samples as f64),
+                            })
+                            .unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let model = FederatedServer::new(model, transport, 2).run(2).unwrap();
+        for client in clients {
+            client.join().unwrap();
+        }
+
+        // Each round adds the parameters once.
+        model
+            .val()
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([4.0, 8.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_reject_duplicate_client_updates() {
+        let device = Device::default();
+        let model = Param::from_tensor(Tensor::<1>::from_data([1.0, 2.0], &device));
+        let (transport, mut clients) = channel_transport(2);
+
+        // The first client answers twice, so the server never hears from the second one.
+        let mut client = clients.remove(0);
+        let client = std::thread::spawn(move || {
+            while let Some(round) = client.receive() {
+                for _ in 0..2 {
+                    let update = ClientUpdate {
+                        round: round.round,
+                        client: 0,
+                        num_samples: 1,
+                        delta: round.model.clone(),
+                    };
+                    if client.send(update).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        let result = FederatedServer::new(model, transport, 2).run(1);
+        client.join().unwrap();
+
+        assert_eq!(
+            result.err(),
+            Some(FederatedError::DuplicateUpdate {
+                client: 0,
+                round: 0
+            })
+        );
+    }
+}
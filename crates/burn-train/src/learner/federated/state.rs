@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+
+use burn_core::module::{Module, ModuleMapper, ModuleVisitor, Param};
+use burn_core::tensor::{Shape, Tensor, TensorData};
+use serde::{Deserialize, Serialize};
+
+use super::FederatedError;
+
+/// The float parameters of a model, or their difference between two models, indexed by module
+/// path (e.g., `encoder.linear.weight`) so that they can be exchanged between the nodes of a
+/// [federated learning](super::FederatedServer) setup.
+///
+/// The arithmetic is done on the host, in single precision, and the results keep the data type
+/// of the parameters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelState {
+    params: HashMap<String, TensorData>,
+}
+
+impl ModelState {
+    /// Read the float parameters of the module.
+    pub fn from_module<M: Module>(module: &M) -> Self {
+        let mut visitor = StateCollector::default();
+        module.visit(&mut visitor);
+        visitor.state
+    }
+
+    /// The number of parameters.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Whether there is no parameter.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the data of the parameter with the given module path.
+    pub fn get(&self, path: &str) -> Option<&TensorData> {
+        self.params.get(path)
+    }
+
+    /// Returns an iterator over the parameters and their module path, used by
+    /// [secure aggregation](super::SecureAggregation) hooks to mask them.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut TensorData)> {
+        self.params
+            .iter_mut()
+            .map(|(path, data)| (path.as_str(), data))
+    }
+
+    /// The difference between these parameters and the `base` ones.
+    ///
+    /// # Errors
+    ///
+    /// If both states don't have the same parameters, with the same shapes.
+    pub fn delta(&self, base: &Self) -> Result<Self, FederatedError> {
+        self.zip(base, |lhs, rhs| lhs - rhs)
+    }
+
+    /// The sum of these parameters and the `other` ones.
+    ///
+    /// # Errors
+    ///
+    /// If both states don't have the same parameters, with the same shapes.
+    pub fn add(&self, other: &Self) -> Result<Self, FederatedError> {
+        self.zip(other, |lhs, rhs| lhs + rhs)
+    }
+
+    /// The parameters multiplied by the factor.
+    pub fn scale(&self, factor: f64) -> Self {
+        let factor = factor as f32;
+        let params = self
+            .params
+            .iter()
+            .map(|(path, data)| {
+                let values = data.iter::<f32>().map(|x| x * factor).collect::<Vec<_>>();
+                let scaled = TensorData::new(values, data.shape.clone()).convert_dtype(data.dtype);
+                (path.clone(), scaled)
+            })
+            .collect();
+
+        Self { params }
+    }
+
+    /// Replace the parameters of the module with these ones.
+    ///
+    /// # Errors
+    ///
+    /// If the module and the state don't have the same parameters, with the same shapes.
+    pub fn load_into<M: Module>(&self, module: M) -> Result<M, FederatedError> {
+        self.apply(module, false)
+    }
+
+    /// Add these parameters, as a delta, to the parameters of the module.
+    ///
+    /// # Errors
+    ///
+    /// If the module and the state don't have the same parameters, with the same shapes.
+    pub fn add_to<M: Module>(&self, module: M) -> Result<M, FederatedError> {
+        self.apply(module, true)
+    }
+
+    fn apply<M: Module>(&self, module: M, add: bool) -> Result<M, FederatedError> {
+        let mut mapper = StateMapper {
+            state: self,
+            add,
+            path: Vec::new(),
+            visited: HashSet::new(),
+            error: None,
+        };
+        let module = module.map(&mut mapper);
+
+        if let Some(error) = mapper.error {
+            return Err(error);
+        }
+        match self
+            .params
+            .keys()
+            .find(|path| !mapper.visited.contains(*path))
+        {
+            Some(path) => Err(FederatedError::UnexpectedParameter(path.clone())),
+            None => Ok(module),
+        }
+    }
+
+    fn zip(&self, other: &Self, func: impl Fn(f32, f32) -> f32) -> Result<Self, FederatedError> {
+        if let Some(path) = other
+            .params
+            .keys()
+            .find(|path| !self.params.contains_key(*path))
+        {
+            return Err(FederatedError::UnexpectedParameter(path.clone()));
+        }
+
+        let params = self
+            .params
+            .iter()
+            .map(|(path, lhs)| {
+                let rhs = other
+                    .params
+                    .get(path)
+                    .ok_or_else(|| FederatedError::MissingParameter(path.clone()))?;
+                if lhs.shape != rhs.shape {
+                    return Err(FederatedError::ShapeMismatch {
+                        path: path.clone(),
+                        expected: lhs.shape.clone(),
+                        actual: rhs.shape.clone(),
+                    });
+                }
+
+                let values = lhs
+                    .iter::<f32>()
+                    .zip(rhs.iter::<f32>())
+                    .map(|(lhs, rhs)| func(lhs, rhs))
+                    .collect::<Vec<_>>();
+                let data = TensorData::new(values, lhs.shape.clone()).convert_dtype(lhs.dtype);
+                Ok((path.clone(), data))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { params })
+    }
+}
+
+#[derive(Default)]
+struct StateCollector {
+    state: ModelState,
+    path: Vec<String>,
+}
+
+impl ModuleVisitor for StateCollector {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        self.state
+            .params
+            .insert(self.path.join("."), param.val().into_data());
+    }
+}
+
+struct StateMapper<'a> {
+    state: &'a ModelState,
+    add: bool,
+    path: Vec<String>,
+    visited: HashSet<String>,
+    error: Option<FederatedError>,
+}
+
+impl<'a> StateMapper<'a> {
+    /// Returns the data of the parameter, or records why it can't be updated.
+    fn lookup(&mut self, shape: &Shape) -> Option<&'a TensorData> {
+        let path = self.path.join(".");
+        let Some(data) = self.state.params.get(&path) else {
+            self.error = Some(FederatedError::MissingParameter(path));
+            return None;
+        };
+        if &data.shape != shape {
+            self.error = Some(FederatedError::ShapeMismatch {
+                path,
+                expected: shape.clone(),
+                actual: data.shape.clone(),
+            });
+            return None;
+        }
+
+        self.visited.insert(path);
+        Some(data)
+    }
+}
+
+impl ModuleMapper for StateMapper<'_> {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, param: Param<Tensor<D>>) -> Param<Tensor<D>> {
+        let (id, tensor, mapper) = param.consume();
+        if self.error.is_some() {
+            return Param::from_mapped_value(id, tensor, mapper);
+        }
+
+        let add = self.add;
+        let tensor = match self.lookup(&tensor.shape()) {
+            Some(data) => {
+                let update = |tensor: Tensor<D>| {
+                    let value = Tensor::from_data(data.clone(), (&tensor.device(), tensor.dtype()));
+                    if add { tensor + value } else { value }
+                };
+
+                // The parameters of the autodiff models are updated outside of the graph.
+                if tensor.device().is_autodiff() {
+                    let is_require_grad = tensor.is_require_grad();
+                    let mut tensor = Tensor::from_inner(update(tensor.inner()));
+                    if is_require_grad {
+                        tensor = tensor.require_grad();
+                    }
+                    tensor
+                } else {
+                    update(tensor)
+                }
+            }
+            None => tensor,
+        };
+
+        Param::from_mapped_value(id, tensor, mapper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::{Device, Tolerance};
+
+    fn params(values: &[&[f32]], device: &Device) -> Vec<Param<Tensor<1>>> {
+        values
+            .iter()
+            .map(|values| {
+                Param::from_tensor(Tensor::from_data(
+                    TensorData::new(values.to_vec(), [values.len()]),
+                    device,
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_apply_average_delta() {
+        let device = Device::default();
+        let base = Param::from_tensor(Tensor::<2>::from_data([[1.0, -2.0], [3.0, 4.0]], &device));
+        let state = ModelState::from_module(&base);
+
+        let first = ModelState::from_module(&state.scale(2.0).load_into(base.clone()).unwrap());
+        let second = ModelState::from_module(&state.scale(4.0).load_into(base.clone()).unwrap());
+
+        // The average of the deltas is twice the parameters.
+        let delta = first
+            .delta(&state)
+            .unwrap()
+            .add(&second.delta(&state).unwrap())
+            .unwrap()
+            .scale(0.5);
+        let model = delta.add_to(base).unwrap();
+
+        model.val().into_data().assert_approx_eq::<f32>(
+            &TensorData::from([[3.0, -6.0], [9.0, 12.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_match_parameters_by_module_path() {
+        let device = Device::default();
+        let global = params(&[&[1.0, 2.0], &[3.0]], &device);
+        // Initialized separately, so the parameter ids differ.
+        let local = params(&[&[0.0, 0.0], &[0.0]], &device);
+        let state = ModelState::from_module(&global);
+
+        assert_eq!(state.get("1"), Some(&TensorData::from([3.0f32])));
+        let local = state.load_into(local).unwrap();
+
+        assert_eq!(ModelState::from_module(&local), state);
+    }
+
+    #[test]
+    fn should_fail_when_parameters_differ() {
+        let device = Device::default();
+        let state = ModelState::from_module(&params(&[&[1.0, 2.0], &[3.0]], &device));
+
+        assert_eq!(
+            state
+                .load_into(params(&[&[0.0, 0.0]], &device))
+                .unwrap_err(),
+            FederatedError::UnexpectedParameter("1".to_string())
+        );
+        assert_eq!(
+            state
+                .load_into(params(&[&[0.0, 0.0], &[0.0], &[0.0]], &device))
+                .unwrap_err(),
+            FederatedError::MissingParameter("2".to_string())
+        );
+        assert_eq!(
+            state
+                .load_into(params(&[&[0.0, 0.0], &[0.0, 0.0]], &device))
+                .unwrap_err(),
+            FederatedError::ShapeMismatch {
+                path: "1".to_string(),
+                expected: Shape::new([2]),
+                actual: Shape::new([1]),
+            }
+        );
+
+        let other = ModelState::from_module(&params(&[&[1.0, 2.0]], &device));
+        assert_eq!(
+            state.delta(&other).unwrap_err(),
+            FederatedError::MissingParameter("1".to_string())
+        );
+        assert_eq!(
+            other.add(&state).unwrap_err(),
+            FederatedError::UnexpectedParameter("1".to_string())
+        );
+    }
+}
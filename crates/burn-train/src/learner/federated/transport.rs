@@ -0,0 +1,134 @@
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use serde::{Deserialize, Serialize};
+
+use super::{FederatedAlgorithm, FederatedError, ModelState};
+
+/// The message sent by the [server](super::FederatedServer) to the clients at the start of a
+/// round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundStart {
+    /// The index of the round.
+    pub round: usize,
+    /// The parameters of the global model.
+    pub model: ModelState,
+    /// The algorithm used to train the model.
+    pub algorithm: FederatedAlgorithm,
+}
+
+/// The message sent by a [client](super::FederatedClient) to the server at the end of a round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientUpdate {
+    /// The index of the round.
+    pub round: usize,
+    /// The id of the client.
+    pub client: usize,
+    /// The number of samples the client trained on.
+    pub num_samples: usize,
+    /// The delta of the client parameters, multiplied by the number of samples and masked by the
+    /// [secure aggregation](super::SecureAggregation) hooks, if any.
+    pub delta: ModelState,
+}
+
+/// The server side of the transport used for [federated learning](super::FederatedServer).
+///
+/// The messages are serializable, so the transport can be implemented on top of any network
+/// protocol.
+pub trait ServerTransport: Send {
+    /// Send the start of a round to all the clients.
+    ///
+    /// # Errors
+    ///
+    /// If a client can't be reached.
+    fn broadcast(&mut self, message: RoundStart) -> Result<(), FederatedError>;
+
+    /// Wait for the next update of a client.
+    ///
+    /// # Errors
+    ///
+    /// If no client can send updates anymore.
+    fn receive(&mut self) -> Result<ClientUpdate, FederatedError>;
+
+    /// Notify the clients that the training is done.
+    fn close(&mut self);
+}
+
+/// The client side of the transport used for [federated learning](super::FederatedServer).
+pub trait ClientTransport: Send {
+    /// Wait for the start of the next round, or return `None` when the training is done.
+    fn receive(&mut self) -> Option<RoundStart>;
+
+    /// Send the update of the client to the server.
+    ///
+    /// # Errors
+    ///
+    /// If the server can't be reached.
+    fn send(&mut self, update: ClientUpdate) -> Result<(), FederatedError>;
+}
+
+/// Creates an in-process transport between a server and `num_clients` clients, for instance to
+/// simulate federated learning on a single machine.
+pub fn channel_transport(
+    num_clients: usize,
+) -> (ChannelServerTransport, Vec<ChannelClientTransport>) {
+    let (update_sender, updates) = channel();
+    let (senders, clients) = (0..num_clients)
+        .map(|_| {
+            let (sender, rounds) = channel();
+            let client = ChannelClientTransport {
+                rounds,
+                updates: update_sender.clone(),
+            };
+            (sender, client)
+        })
+        .unzip();
+
+    let server = ChannelServerTransport { senders, updates };
+    (server, clients)
+}
+
+/// The server side of a [channel transport](channel_transport).
+pub struct ChannelServerTransport {
+    senders: Vec<Sender<RoundStart>>,
+    updates: Receiver<ClientUpdate>,
+}
+
+/// The client side of a [channel transport](channel_transport).
+pub struct ChannelClientTransport {
+    rounds: Receiver<RoundStart>,
+    updates: Sender<ClientUpdate>,
+}
+
+impl ServerTransport for ChannelServerTransport {
+    fn broadcast(&mut self, message: RoundStart) -> Result<(), FederatedError> {
+        for (client, sender) in self.senders.iter().enumerate() {
+            sender
+                .send(message.clone())
+                .map_err(|_| FederatedError::Disconnected(format!("client {client} hung up")))?;
+        }
+
+        Ok(())
+    }
+
+    fn receive(&mut self) -> Result<ClientUpdate, FederatedError> {
+        self.updates
+            .recv()
+            .map_err(|_| FederatedError::Disconnected("all the clients hung up".into()))
+    }
+
+    fn close(&mut self) {
+        self.senders.clear();
+    }
+}
+
+impl ClientTransport for ChannelClientTransport {
+    fn receive(&mut self) -> Option<RoundStart> {
+        self.rounds.recv().ok()
+    }
+
+    fn send(&mut self, update: ClientUpdate) -> Result<(), FederatedError> {
+        self.updates
+            .send(update)
+            .map_err(|_| FederatedError::Disconnected("the server hung up".into()))
+    }
+}
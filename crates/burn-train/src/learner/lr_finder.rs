@@ -0,0 +1,227 @@
+use burn_core as burn;
+
+use crate::metric::{Adaptor, LossInput};
+use crate::{Learner, LearningComponentsTypes, TrainLoader, TrainingModelOutput};
+use burn::config::Config;
+
+/// Configuration of the [learning rate range test](Learner::find_lr).
+#[derive(Config, Debug)]
+pub struct LrFinderConfig {
+    /// The learning rate of the first step.
+    #[config(default = 1e-7)]
+    pub start_lr: f64,
+
+    /// The learning rate of the last step.
+    #[config(default = 10.0)]
+    pub end_lr: f64,
+
+    /// The number of steps of the sweep, which loops over the data loader when it has fewer
+    /// batches.
+    #[config(default = 100)]
+    pub num_steps: usize,
+
+    /// The weight of the previous average in the exponential moving average of the loss.
+    #[config(default = 0.98)]
+    pub smoothing: f64,
+
+    /// The sweep stops when the smoothed loss exceeds the lowest one by this factor.
+    #[config(default = 4.0)]
+    pub divergence_threshold: f64,
+}
+
+impl LrFinderConfig {
+    /// The learning rate of a step, from 0, increasing exponentially from the start to the end
+    /// learning rate.
+    pub fn lr(&self, step: usize) -> f64 {
+        if self.num_steps <= 1 {
+            return self.start_lr;
+        }
+
+        let progress = step as f64 / (self.num_steps - 1) as f64;
+        self.start_lr * (self.end_lr / self.start_lr).powf(progress)
+    }
+}
+
+/// A step of the [learning rate range test](Learner::find_lr).
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct LrFinderPoint {
+    /// The learning rate of the step.
+    pub lr: f64,
+    /// The loss of the batch.
+    pub loss: f64,
+    /// The exponential moving average of the loss, corrected for its zero initialization.
+    pub smoothed_loss: f64,
+}
+
+/// The loss against the learning rate, recorded by the
+/// [learning rate range test](Learner::find_lr).
+#[derive(Debug, Clone, Default)]
+pub struct LrFinderResult {
+    /// The steps of the sweep, by increasing learning rate.
+    pub points: Vec<LrFinderPoint>,
+    /// Whether the sweep stopped early because the loss diverged.
+    pub diverged: bool,
+}
+
+impl LrFinderResult {
+    /// Suggests the learning rate where the smoothed loss decreases the fastest, relative to the
+    /// logarithm of the learning rate, before reaching its minimum.
+    ///
+    /// Returns `None` when the loss never decreases.
+    pub fn suggestion(&self) -> Option<f64> {
+        let (lowest, _) = self
+            .points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.smoothed_loss.total_cmp(&b.smoothed_loss))?;
+
+        self.points[..=lowest]
+            .windows(2)
+            .map(|pair| {
+                let slope = (pair[1].smoothed_loss - pair[0].smoothed_loss)
+                    / (pair[1].lr.ln() - pair[0].lr.ln());
+                (pair[0].lr, slope)
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(lr, _)| lr)
+    }
+
+    /// The learning rate with the lowest smoothed loss.
+    pub fn lowest_loss_lr(&self) -> Option<f64> {
+        self.points
+            .iter()
+            .min_by(|a, b| a.smoothed_loss.total_cmp(&b.smoothed_loss))
+            .map(|point| point.lr)
+    }
+}
+
+/// Smooths the recorded losses and detects the divergence of the sweep.
+struct LrSweep {
+    smoothing: f64,
+    divergence_threshold: f64,
+    average: f64,
+    lowest: f64,
+    result: LrFinderResult,
+}
+
+impl LrSweep {
+    fn new(config: &LrFinderConfig) -> Self {
+        Self {
+            smoothing: config.smoothing,
+            divergence_threshold: config.divergence_threshold,
+            average: 0.0,
+            lowest: f64::INFINITY,
+            result: LrFinderResult::default(),
+        }
+    }
+
+    /// Records the loss of a step, and returns whether the sweep should continue.
+    fn record(&mut self, lr: f64, loss: f64) -> bool {
+        let step = self.result.points.len() as i32 + 1;
+        self.average = self.smoothing * self.average + (1.0 - self.smoothing) * loss;
+        let smoothed_loss = self.average / (1.0 - self.smoothing.powi(step));
+
+        self.result
+            .points
+            .push(LrFinderPoint::new(lr, loss, smoothed_loss));
+
+        if !smoothed_loss.is_finite() || smoothed_loss > self.divergence_threshold * self.lowest {
+            self.result.diverged = true;
+            return false;
+        }
+        self.lowest = self.lowest.min(smoothed_loss);
+
+        true
+    }
+}
+
+impl<LC: LearningComponentsTypes> Learner<LC> {
+    /// Runs a learning rate range test: trains a copy of the learner for a few steps with an
+    /// exponentially increasing learning rate, and records the loss of each step.
+    ///
+    /// The learner itself is left untouched, so the test can run before the training with the
+    /// same model, optimizer and data loader. The learning rate scheduler is ignored during the
+    /// sweep.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The training data loader.
+    /// * `config` - The configuration of the sweep.
+    pub fn find_lr(&self, dataloader: &TrainLoader<LC>, config: &LrFinderConfig) -> LrFinderResult
+    where
+        TrainingModelOutput<LC>: Adaptor<LossInput>,
+    {
+        let mut learner = self.clone();
+        let mut sweep = LrSweep::new(config);
+        let mut step = 0;
+
+        'sweep: while step < config.num_steps {
+            let mut iterator = dataloader.iter();
+            let step_epoch_start = step;
+
+            while let Some(item) = iterator.next() {
+                let lr = config.lr(step);
+                learner.set_lr(lr);
+
+                let output = learner.train_step(item);
+                let loss: LossInput = output.item.adapt();
+                let loss = loss.tensor.mean().into_data().iter::<f64>().next().unwrap();
+                learner.optimizer_step(output.grads);
+                step += 1;
+                log::info!("Learning rate finder step {step}: lr {lr:.3e}, loss {loss:.4}");
+
+                if !sweep.record(lr, loss) || step == config.num_steps {
+                    break 'sweep;
+                }
+            }
+
+            if step == step_epoch_start {
+                log::warn!("The data loader of the learning rate finder is empty.");
+                break;
+            }
+        }
+
+        sweep.result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_increases_exponentially() {
+        let config = LrFinderConfig::new()
+            .with_start_lr(1e-4)
+            .with_end_lr(1.0)
+            .with_num_steps(5);
+
+        assert!((config.lr(0) - 1e-4).abs() < 1e-12);
+        assert!((config.lr(2) - 1e-2).abs() < 1e-12);
+        assert!((config.lr(4) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sweep_detects_divergence_and_suggests_steepest_descent() {
+        let config = LrFinderConfig::new().with_smoothing(0.0);
+        let mut sweep = LrSweep::new(&config);
+
+        for (lr, loss) in [
+            (1e-3, 4.0),
+            (1e-2, 3.5),
+            (1e-1, 2.0),
+            (1.0, 1.5),
+            (10.0, 1.4),
+        ] {
+            assert!(sweep.record(lr, loss));
+        }
+        // 10.0 > 4 * 1.4
+        assert!(!sweep.record(100.0, 10.0));
+
+        let result = sweep.result;
+        assert!(result.diverged);
+        assert_eq!(result.points.len(), 6);
+        assert_eq!(result.lowest_loss_lr(), Some(10.0));
+        assert_eq!(result.suggestion(), Some(1e-2));
+    }
+}
@@ -6,7 +6,14 @@ pub use rl::*;
 mod application_logger;
 mod base;
 mod classification;
+mod diagnostics;
 mod early_stopping;
+mod ema;
+/// Federated learning, with a parameter server aggregating the updates of clients training on
+/// their local data.
+pub mod federated;
+mod lr_finder;
+mod ohem;
 mod regression;
 mod sequence;
 #[cfg(feature = "ddp")]
@@ -19,6 +26,9 @@ pub use application_logger::*;
 pub use base::*;
 pub use classification::*;
 pub use early_stopping::*;
+pub use ema::*;
+pub use lr_finder::*;
+pub use ohem::*;
 pub use regression::*;
 pub use sequence::*;
 #[cfg(feature = "ddp")]
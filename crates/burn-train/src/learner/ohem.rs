@@ -0,0 +1,127 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::tensor::{Int, Tensor};
+
+/// Configuration to create an [online hard example mining](OnlineHardExampleMining) selection.
+#[derive(Config, Debug)]
+pub struct OhemConfig {
+    /// The fraction of the samples of each batch that are kept, by decreasing loss.
+    #[config(default = 0.7)]
+    pub keep_ratio: f64,
+
+    /// The minimum number of samples kept in each batch.
+    #[config(default = 1)]
+    pub min_kept: usize,
+}
+
+impl OhemConfig {
+    /// Initialize the selection.
+    pub fn init(&self) -> OnlineHardExampleMining {
+        assert!(
+            self.keep_ratio > 0.0 && self.keep_ratio <= 1.0,
+            "OHEM keep ratio should be in (0, 1], got {}",
+            self.keep_ratio
+        );
+
+        OnlineHardExampleMining {
+            keep_ratio: self.keep_ratio,
+            min_kept: self.min_kept,
+        }
+    }
+}
+
+/// Online hard example mining (OHEM): only the samples with the highest losses of each batch
+/// contribute to the gradients.
+///
+/// The selection takes the per-sample losses of a batch, as returned by the `forward_no_reduction`
+/// methods of the loss modules, and is applied in the [train step](crate::TrainStep) before the
+/// backward pass. The ranking doesn't track gradients, so only the kept losses are backpropagated.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let losses = self.loss.forward_no_reduction(logits, targets);
+/// let loss = self.ohem.forward(losses);
+/// let grads = loss.backward();
+/// ```
+#[derive(Clone, Debug)]
+pub struct OnlineHardExampleMining {
+    keep_ratio: f64,
+    min_kept: usize,
+}
+
+impl OnlineHardExampleMining {
+    /// The number of samples kept in a batch of the given size.
+    pub fn num_kept(&self, batch_size: usize) -> usize {
+        let kept = (self.keep_ratio * batch_size as f64).ceil() as usize;
+
+        kept.max(self.min_kept).min(batch_size)
+    }
+
+    /// Selects the hardest samples of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `losses` - The loss of each sample, with shape `[batch_size]`.
+    ///
+    /// # Returns
+    ///
+    /// The losses of the kept samples and their indices in the batch, by decreasing loss.
+    pub fn select(&self, losses: Tensor<1>) -> (Tensor<1>, Tensor<1, Int>) {
+        let [batch_size] = losses.dims();
+        let num_kept = self.num_kept(batch_size);
+
+        let (_, indices) = losses.clone().detach().topk_with_indices(num_kept, 0);
+
+        (losses.select(0, indices.clone()), indices)
+    }
+
+    /// The mean loss of the hardest samples of the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - losses: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(&self, losses: Tensor<1>) -> Tensor<1> {
+        let (losses, _) = self.select(losses);
+
+        losses.mean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::{Device, TensorData, Tolerance};
+
+    #[test]
+    fn test_ohem_keeps_the_hardest_samples() {
+        let ohem = OhemConfig::new().with_keep_ratio(0.4).init();
+        let losses = Tensor::<1>::from_data([0.1, 2.0, 0.5, 3.0, 1.0], &Device::default());
+
+        let (kept, indices) = ohem.select(losses.clone());
+        kept.into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([3.0, 2.0]), Tolerance::default());
+        assert_eq!(
+            indices.into_data().iter::<i64>().collect::<Vec<_>>(),
+            [3, 1]
+        );
+
+        ohem.forward(losses)
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([2.5]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_ohem_num_kept() {
+        let ohem = OhemConfig::new()
+            .with_keep_ratio(0.25)
+            .with_min_kept(3)
+            .init();
+
+        assert_eq!(ohem.num_kept(40), 10);
+        assert_eq!(ohem.num_kept(8), 3);
+        assert_eq!(ohem.num_kept(2), 2);
+    }
+}
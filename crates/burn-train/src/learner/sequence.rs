@@ -1,5 +1,6 @@
-use crate::metric::{AccuracyInput, PerplexityInput, TopKAccuracyInput};
-use crate::metric::{Adaptor, CerInput, LossInput, WerInput, processor::ItemLazy};
+use crate::metric::processor::ItemLazy;
+use crate::metric::{AccuracyInput, PerplexityInput, TokenThroughputInput, TopKAccuracyInput};
+use crate::metric::{Adaptor, BleuInput, CerInput, LossInput, RougeLInput, WerInput};
 use burn_core::tensor::{Device, Int, Tensor, Transaction};
 use burn_flex::FlexDevice;
 
@@ -12,6 +13,9 @@ use burn_flex::FlexDevice;
 /// - Loss
 /// - CER
 /// - WER
+/// - BLEU
+/// - ROUGE-L
+/// - Token Throughput
 #[derive(new)]
 pub struct SequenceOutput {
     /// The loss.
@@ -109,6 +113,18 @@ impl Adaptor<WerInput> for SequenceOutput {
     }
 }
 
+impl Adaptor<BleuInput> for SequenceOutput {
+    fn adapt(&self) -> BleuInput {
+        BleuInput::new(self.predicted_tokens(), self.targets.clone())
+    }
+}
+
+impl Adaptor<RougeLInput> for SequenceOutput {
+    fn adapt(&self) -> RougeLInput {
+        RougeLInput::new(self.predicted_tokens(), self.targets.clone())
+    }
+}
+
 impl Adaptor<AccuracyInput> for SequenceOutput {
     fn adapt(&self) -> AccuracyInput {
         AccuracyInput::new(self.flat_logits(), self.flat_targets())
@@ -126,3 +142,10 @@ impl Adaptor<PerplexityInput> for SequenceOutput {
         PerplexityInput::new(self.flat_logits(), self.flat_targets())
     }
 }
+
+impl Adaptor<TokenThroughputInput> for SequenceOutput {
+    fn adapt(&self) -> TokenThroughputInput {
+        let [batch_size, seq_length] = self.targets.dims();
+        TokenThroughputInput::new(batch_size * seq_length)
+    }
+}
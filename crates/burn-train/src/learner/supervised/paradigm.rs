@@ -5,24 +5,29 @@ use crate::checkpoint::{
 use crate::components::{InferenceModelOutput, TrainingModelOutput};
 use crate::learner::EarlyStoppingStrategy;
 use crate::learner::base::Interrupter;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::logger::{FileMetricLogger, MetricLogger, TensorBoardMetricLogger, TensorBoardWriter};
 use crate::metric::processor::{
     AsyncProcessorTraining, FullEventProcessorTraining, MetricsTraining,
 };
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
-use crate::metric::{Adaptor, LossMetric, Metric, Numeric};
+use crate::metric::{
+    Adaptor, DeviceUtilizationMetric, GradientCosineSimilarityMetric, GradientNoiseScaleMetric,
+    LossInput, LossMetric, Metric, Numeric, StepPhase, StepTimeMetric, ThroughputMetric,
+    UpdateRatioMetric,
+};
 use crate::multi::MultiDeviceLearningStrategy;
 use crate::renderer::{MetricsRenderer, default_renderer};
 use crate::single::SingleDeviceTrainingStrategy;
 use crate::{
     ApplicationLoggerInstaller, EarlyStoppingStrategyRef, ExecutionStrategy,
     FileApplicationLoggerInstaller, InferenceModel, InferenceModelInput, InferenceStep,
-    LearnerEvent, LearnerModelRecord, LearnerOptimizerRecord, LearnerSchedulerRecord,
-    LearnerSummaryConfig, LearningCheckpointer, LearningComponentsMarker, LearningComponentsTypes,
-    LearningResult, TrainStep, TrainingComponents, TrainingModelInput, TrainingStrategy,
+    LearnerEmaRecord, LearnerEvent, LearnerModelRecord, LearnerOptimizerRecord,
+    LearnerSchedulerRecord, LearnerSummaryConfig, LearningCheckpointer, LearningComponentsMarker,
+    LearningComponentsTypes, LearningResult, LrFinderConfig, LrFinderResult, TrainStep,
+    TrainingComponents, TrainingModelInput, TrainingStrategy,
 };
 use crate::{Learner, SupervisedLearningStrategy};
-use burn_core::data::dataloader::DataLoader;
+use burn_core::data::dataloader::{CurriculumAware, DataLoader};
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::Device;
@@ -53,12 +58,17 @@ where
         AsyncCheckpointer<LearnerModelRecord<LC>>,
         AsyncCheckpointer<LearnerOptimizerRecord<LC>>,
         AsyncCheckpointer<LearnerSchedulerRecord<LC>>,
+        AsyncCheckpointer<LearnerEmaRecord<LC>>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
     grad_checkpointing: bool,
+    step_timing: bool,
+    diagnostics: bool,
+    histograms: Option<TensorBoardWriter>,
+    curricula: Vec<Arc<dyn CurriculumAware>>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: MetricsTraining<TrainingModelOutput<LC>, InferenceModelOutput<LC>>,
     event_store: LogEventStore,
@@ -101,6 +111,10 @@ where
             directory,
             grad_accumulation: None,
             grad_checkpointing: false,
+            step_timing: false,
+            diagnostics: false,
+            histograms: None,
+            curricula: Vec::new(),
             metrics: MetricsTraining::default(),
             event_store: LogEventStore::default(),
             renderer: None,
@@ -153,6 +167,26 @@ impl<LC: LearningComponentsTypes> SupervisedTraining<LC> {
         self
     }
 
+    /// Log the metrics to TensorBoard event files in the `tensorboard` subdirectory of the
+    /// training directory, in addition to the default file metric logger.
+    ///
+    /// The histograms of the parameters at the end of each training epoch, and of the gradients
+    /// of the first training step of each epoch, are logged next to the training metrics, with
+    /// the epoch as step. They are only recorded by the default single device strategy.
+    ///
+    /// To also log images, register a
+    /// [TensorBoardMetricLogger](crate::logger::TensorBoardMetricLogger) with
+    /// [with_metric_logger](Self::with_metric_logger) after keeping its writers.
+    pub fn with_tensorboard_logger(mut self) -> Self {
+        if !self.event_store.has_loggers() {
+            self.event_store
+                .register_logger(FileMetricLogger::new(self.directory.clone()));
+        }
+        let mut logger = TensorBoardMetricLogger::new(self.directory.join("tensorboard"));
+        self.histograms = Some(logger.writer(&Split::Train));
+        self.with_metric_logger(logger)
+    }
+
     /// Update the checkpointing_strategy.
     pub fn with_checkpointing_strategy<CS: CheckpointingStrategy + 'static>(
         mut self,
@@ -230,6 +264,85 @@ impl<LC: LearningComponentsTypes> SupervisedTraining<LC> {
         self
     }
 
+    /// Registers a part of the data pipeline to notify of the progress of the training, such as
+    /// a dataset or a batcher scheduling the difficulty of the samples.
+    ///
+    /// The [curriculum](CurriculumAware) is notified before each training epoch, and after each
+    /// training step. A [curriculum progress](burn_core::data::dataloader::CurriculumProgress)
+    /// can be shared with the data pipeline to read the progress from there.
+    ///
+    /// # Notes
+    ///
+    /// The step notifications are only sent by the default single device strategy, the epoch
+    /// ones are also sent by the multi-device strategy.
+    pub fn with_curriculum<C: CurriculumAware + 'static>(mut self, curriculum: C) -> Self {
+        self.curricula.push(Arc::new(curriculum));
+        self
+    }
+
+    /// Enables the step timing report, to see where the training time goes.
+    ///
+    /// Each training step is timed in four phases: waiting for the data loader, the forward pass,
+    /// the backward pass, and the optimizer step. The forward pass ends when the first
+    /// [backward](burn_core::tensor::Tensor::backward) pass of the step starts. The following
+    /// training metrics are registered:
+    ///
+    /// - the [step time](StepTimeMetric) of each phase and of the whole step;
+    /// - the [device utilization estimate](DeviceUtilizationMetric);
+    /// - the [throughput](ThroughputMetric) in samples per second.
+    ///
+    /// A breakdown of the epoch is also logged at the end of each training epoch.
+    ///
+    /// # Notes
+    ///
+    /// The device is synchronized at the end of the forward pass, of the backward pass and of the
+    /// optimizer step, so that the asynchronous work is attributed to the right phase. This
+    /// removes some overlap between the phases and slightly slows down the training.
+    ///
+    /// # Panics
+    ///
+    /// The timings are only recorded by the single device strategy, so launching the training
+    /// with the multi-device or the distributed data parallel strategy panics.
+    pub fn with_step_timing(mut self) -> Self {
+        self.step_timing = true;
+        let mut training = self;
+        for phase in StepPhase::ALL {
+            training = training.metric_train_numeric(StepTimeMetric::new(phase));
+        }
+
+        training
+            .metric_train_numeric(DeviceUtilizationMetric::new())
+            .metric_train_numeric(ThroughputMetric::new())
+    }
+
+    /// Enables the training diagnostics, to guide the choice of the batch size and of the
+    /// learning rate.
+    ///
+    /// The gradients of each batch are compared with the gradients of the previous one, and the
+    /// weights after each optimizer step with the weights before it. The following training
+    /// metrics are registered:
+    ///
+    /// - the [gradient noise scale](GradientNoiseScaleMetric), an estimate of the critical batch
+    ///   size;
+    /// - the [cosine similarity](GradientCosineSimilarityMetric) of successive gradients;
+    /// - the [update-to-weight ratio](UpdateRatioMetric) of the whole model.
+    ///
+    /// The update-to-weight ratio of specific layers can be tracked by registering more
+    /// [update ratio metrics](UpdateRatioMetric::matching).
+    ///
+    /// # Notes
+    ///
+    /// The gradients of the previous batch and the weights before the optimizer step are kept,
+    /// which costs up to twice the memory of the model, and a few values are read back from the
+    /// device at each step. The diagnostics are only recorded by the default single device
+    /// strategy.
+    pub fn with_diagnostics(mut self) -> Self {
+        self.diagnostics = true;
+        self.metric_train_numeric(GradientNoiseScaleMetric::new())
+            .metric_train_numeric(GradientCosineSimilarityMetric::new())
+            .metric_train_numeric(UpdateRatioMetric::new())
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -296,7 +409,8 @@ impl<LC: LearningComponentsTypes> SupervisedTraining<LC> {
     }
 
     /// Register a checkpointer that will save the [optimizer](Optimizer), the
-    /// [model](AutodiffModule) and the [scheduler](LrScheduler) to different files.
+    /// [model](AutodiffModule), the [scheduler](LrScheduler) and the
+    /// [model EMA](crate::ModelEma), when enabled, to different files.
     pub fn with_file_checkpointer<FR>(mut self, recorder: FR) -> Self
     where
         FR: FileRecorder + 'static,
@@ -307,12 +421,14 @@ impl<LC: LearningComponentsTypes> SupervisedTraining<LC> {
         let checkpointer_optimizer =
             FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
         let checkpointer_scheduler: FileCheckpointer<FR> =
-            FileCheckpointer::new(recorder, &checkpoint_dir, "scheduler");
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "scheduler");
+        let checkpointer_ema = FileCheckpointer::new(recorder, &checkpoint_dir, "ema");
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_ema),
         ));
 
         self
@@ -331,6 +447,15 @@ impl<LC> SupervisedTraining<LC>
 where
     LC: LearningComponentsTypes + Send + 'static,
 {
+    /// Runs a [learning rate range test](Learner::find_lr) on a copy of the given learner, with
+    /// the training data loader of this training.
+    pub fn find_lr(&self, learner: &Learner<LC>, config: &LrFinderConfig) -> LrFinderResult
+    where
+        TrainingModelOutput<LC>: Adaptor<LossInput>,
+    {
+        learner.find_lr(&self.dataloader_train, config)
+    }
+
     /// Launch this training with the given [Learner](Learner).
     pub fn launch(mut self, learner: Learner<LC>) -> LearningResult<InferenceModel<LC>> {
         if self.tracing_logger.is_some()
@@ -354,13 +479,14 @@ where
             event_store.clone(),
         ));
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
+        let checkpointer = self.checkpointers.map(|(model, optim, scheduler, ema)| {
             LearningCheckpointer::new(
                 model.with_interrupter(self.interrupter.clone()),
                 optim.with_interrupter(self.interrupter.clone()),
                 scheduler.with_interrupter(self.interrupter.clone()),
                 self.checkpointer_strategy,
             )
+            .with_ema(ema.with_interrupter(self.interrupter.clone()))
         });
 
         let summary = if self.summary {
@@ -381,9 +507,28 @@ where
             event_store,
             num_epochs: self.num_epochs,
             grad_accumulation: self.grad_accumulation,
+            step_timing: self.step_timing,
+            diagnostics: self.diagnostics,
+            histograms: self.histograms,
+            curricula: self.curricula,
             summary,
         };
 
+        if self.step_timing
+            && let Some(TrainingStrategy::Default(strategy)) = &self.training_strategy
+        {
+            let single_device = match strategy {
+                ExecutionStrategy::SingleDevice(_) => true,
+                ExecutionStrategy::MultiDevice(devices, _) => devices.len() == 1,
+                #[cfg(feature = "ddp")]
+                ExecutionStrategy::DistributedDataParallel { .. } => false,
+            };
+            assert!(
+                single_device,
+                "The step timing report is only supported by the single device strategy"
+            );
+        }
+
         // Default to single device based on model
         let training_strategy = self.training_strategy.unwrap_or(TrainingStrategy::Default(
             ExecutionStrategy::SingleDevice(autodiff_device(
@@ -438,12 +583,17 @@ where
                 ExecutionStrategy::DistributedDataParallel { devices, runtime } => {
                     use crate::ddp::DdpTrainingStrategy;
 
+                    let grad_checkpointing = self.grad_checkpointing;
+                    let elastic = runtime.elastic().cloned().map(|elastic| {
+                        elastic.map_devices(move |d| autodiff_device(d, grad_checkpointing))
+                    });
                     let ddp = DdpTrainingStrategy::new(
                         devices
                             .into_iter()
-                            .map(|d| autodiff_device(d, self.grad_checkpointing))
+                            .map(|d| autodiff_device(d, grad_checkpointing))
                             .collect(),
                         runtime,
+                        elastic,
                     );
                     ddp.train(
                         learner,
@@ -1,19 +1,26 @@
 use std::sync::Arc;
+#[cfg(feature = "ddp")]
+use std::sync::Mutex;
 
 #[cfg(feature = "ddp")]
 use burn_core::tensor::backend::distributed::{DistributedBackend, DistributedConfig};
-use burn_core::{module::AutodiffModule, prelude::Device};
+use burn_core::{data::dataloader::CurriculumAware, module::AutodiffModule, prelude::Device};
 
 use crate::{
     EarlyStoppingStrategyRef, InferenceModel, Interrupter, Learner, LearnerSummaryConfig,
     LearningCheckpointer, LearningResult, SupervisedTrainingEventProcessor, TrainLoader,
     TrainingModel, ValidLoader,
     components::LearningComponentsTypes,
+    logger::TensorBoardWriter,
     metric::{
         processor::{EventProcessorTraining, LearnerEvent},
         store::EventStoreClient,
     },
 };
+#[cfg(feature = "ddp")]
+use crate::{Membership, Rendezvous};
+#[cfg(feature = "ddp")]
+use thiserror::Error;
 
 /// A reference to an implementation of SupervisedLearningStrategy.
 pub type CustomLearningStrategy<LC> = Arc<dyn SupervisedLearningStrategy<LC>>;
@@ -74,15 +81,124 @@ impl ExecutionStrategy {
 impl<B: DistributedBackend> ExecutionStrategy {
     /// Creates a distributed data parallel (DDP) strategy.
     pub fn ddp(devices: Vec<Device>, config: DistributedConfig) -> Self {
-        let session = DistributedSession {
-            devices: devices.clone(),
-            config,
-        };
+        let session = DistributedSession::new(devices.clone(), config);
         Self::DistributedDataParallel {
             devices,
             runtime: Box::new(session),
         }
     }
+
+    /// Creates an elastic distributed data parallel (DDP) strategy, which keeps training when
+    /// workers fail or devices join, as described by the [elastic config](ElasticConfig).
+    ///
+    /// The strategy trains on the devices of this node only: the distributed session it creates
+    /// only synchronizes the devices of this process. Training across nodes requires an
+    /// [elastic runtime](ElasticRuntime) wrapping a runtime that synchronizes the nodes of the
+    /// group.
+    ///
+    /// # Errors
+    ///
+    /// If the elastic config has a [rendezvous](ElasticConfig::with_rendezvous), which would form
+    /// groups of several nodes.
+    pub fn ddp_elastic(
+        devices: Vec<Device>,
+        config: DistributedConfig,
+        elastic: ElasticConfig,
+    ) -> Result<Self, ElasticError> {
+        if elastic.rendezvous.is_some() {
+            return Err(ElasticError::MultiNode);
+        }
+
+        let session = DistributedSession::new(devices.clone(), config);
+        Ok(Self::DistributedDataParallel {
+            devices,
+            runtime: Box::new(ElasticRuntime::new(session, elastic)),
+        })
+    }
+}
+
+#[cfg(feature = "ddp")]
+/// The error type for elastic distributed data parallel training.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ElasticError {
+    /// The runtime only synchronizes the devices of this process, but the group spans several
+    /// nodes.
+    #[error(
+        "The distributed runtime only synchronizes the devices of this process, training across \
+         nodes requires a runtime synchronizing the nodes of the group"
+    )]
+    MultiNode,
+}
+
+#[cfg(feature = "ddp")]
+/// Provides the devices currently available for training.
+pub type DevicePool = Arc<dyn Fn() -> Vec<Device> + Send + Sync>;
+
+#[cfg(feature = "ddp")]
+/// Configures how distributed data parallel training recovers from worker failures, such as the
+/// preemption of spot instances.
+///
+/// When a worker fails, the other workers are stopped and the process group is re-formed with the
+/// remaining devices, or with the devices of the [pool](DevicePool) when one is provided so that
+/// new devices can join. The training data is re-sharded between the new workers, and training
+/// resumes from the last checkpoint, or from the start when no checkpoint was created.
+///
+/// With a [rendezvous](Rendezvous), the nodes training together also re-form the group when a
+/// node fails or a new node joins, and resume from the latest checkpoint of the group, so the
+/// nodes should share the checkpoint directory.
+/// This requires an [elastic runtime](ElasticRuntime) wrapping a runtime that synchronizes the
+/// nodes of the group.
+#[derive(Clone)]
+pub struct ElasticConfig {
+    pub(crate) max_restarts: usize,
+    pub(crate) min_devices: usize,
+    pub(crate) device_pool: Option<DevicePool>,
+    pub(crate) rendezvous: Option<Arc<dyn Rendezvous>>,
+}
+
+#[cfg(feature = "ddp")]
+impl ElasticConfig {
+    /// Create a new config re-forming the process group at most `max_restarts` times.
+    pub fn new(max_restarts: usize) -> Self {
+        Self {
+            max_restarts,
+            min_devices: 1,
+            device_pool: None,
+            rendezvous: None,
+        }
+    }
+
+    /// Set the minimum number of devices to keep training with (default: 1).
+    pub fn with_min_devices(mut self, min_devices: usize) -> Self {
+        self.min_devices = min_devices;
+        self
+    }
+
+    /// Set the pool queried for the available devices each time the process group is re-formed.
+    pub fn with_device_pool(
+        mut self,
+        device_pool: impl Fn() -> Vec<Device> + Send + Sync + 'static,
+    ) -> Self {
+        self.device_pool = Some(Arc::new(device_pool));
+        self
+    }
+
+    /// Set the rendezvous used to form the group with the other nodes.
+    pub fn with_rendezvous(mut self, rendezvous: impl Rendezvous + 'static) -> Self {
+        self.rendezvous = Some(Arc::new(rendezvous));
+        self
+    }
+
+    /// Apply a mapping to the devices provided by the pool.
+    pub(crate) fn map_devices(
+        mut self,
+        func: impl Fn(Device) -> Device + Send + Sync + 'static,
+    ) -> Self {
+        self.device_pool = self.device_pool.map(|pool| -> DevicePool {
+            Arc::new(move || pool().into_iter().map(&func).collect())
+        });
+        self
+    }
 }
 
 /// How should the learner run the learning for the model
@@ -110,6 +226,31 @@ pub trait DistributedRuntime: Send + Sync + 'static {
 
     /// Cleanup the distributed environment.
     fn close(&self);
+
+    /// Initialize the distributed environment for the devices of this node in a group formed by
+    /// elastic training.
+    ///
+    /// The default implementation ignores the devices and [starts](DistributedRuntime::start) the
+    /// environment as configured, so runtimes supporting membership changes should override it.
+    ///
+    /// # Errors
+    ///
+    /// If the runtime can't synchronize the group, e.g. when it spans several nodes and the
+    /// runtime only synchronizes the devices of this process, as the default implementation.
+    fn start_group(&self, devices: &[Device], membership: &Membership) -> Result<(), ElasticError> {
+        let _ = devices;
+        if membership.num_nodes() > 1 {
+            return Err(ElasticError::MultiNode);
+        }
+
+        self.start();
+        Ok(())
+    }
+
+    /// How to recover from worker failures, if training should survive them.
+    fn elastic(&self) -> Option<&ElasticConfig> {
+        None
+    }
 }
 
 #[cfg(feature = "ddp")]
@@ -120,16 +261,81 @@ pub trait DistributedRuntime: Send + Sync + 'static {
 pub struct DistributedSession<B: DistributedBackend> {
     devices: Vec<Device>,
     config: DistributedConfig,
+    /// The devices of the running group, which change when the group is re-formed.
+    group: Mutex<Vec<Device>>,
+}
+
+#[cfg(feature = "ddp")]
+impl<B: DistributedBackend> DistributedSession {
+    fn new(devices: Vec<Device>, config: DistributedConfig) -> Self {
+        Self {
+            devices,
+            config,
+            group: Mutex::new(Vec::new()),
+        }
+    }
 }
 
 #[cfg(feature = "ddp")]
 impl<B: DistributedBackend> DistributedRuntime for DistributedSession {
     fn start(&self) {
         B::start_communication_server(&self.devices, self.config.clone());
+        *self.group.lock().unwrap() = self.devices.clone();
     }
 
     fn close(&self) {
-        B::close_communication_server(&self.devices[0]);
+        let group = core::mem::take(&mut *self.group.lock().unwrap());
+        if let Some(device) = group.first() {
+            B::close_communication_server(device);
+        }
+    }
+
+    fn start_group(&self, devices: &[Device], membership: &Membership) -> Result<(), ElasticError> {
+        if membership.num_nodes() > 1 {
+            return Err(ElasticError::MultiNode);
+        }
+
+        B::start_communication_server(devices, self.config.clone());
+        *self.group.lock().unwrap() = devices.to_vec();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ddp")]
+/// A [`DistributedRuntime`] recovering from worker failures as described by its
+/// [elastic config](ElasticConfig).
+pub struct ElasticRuntime {
+    runtime: Box<dyn DistributedRuntime>,
+    config: ElasticConfig,
+}
+
+#[cfg(feature = "ddp")]
+impl ElasticRuntime {
+    /// Create an elastic runtime wrapping the given runtime.
+    pub fn new(runtime: impl DistributedRuntime, config: ElasticConfig) -> Self {
+        Self {
+            runtime: Box::new(runtime),
+            config,
+        }
+    }
+}
+
+#[cfg(feature = "ddp")]
+impl DistributedRuntime for ElasticRuntime {
+    fn start(&self) {
+        self.runtime.start();
+    }
+
+    fn close(&self) {
+        self.runtime.close();
+    }
+
+    fn start_group(&self, devices: &[Device], membership: &Membership) -> Result<(), ElasticError> {
+        self.runtime.start_group(devices, membership)
+    }
+
+    fn elastic(&self) -> Option<&ElasticConfig> {
+        Some(&self.config)
     }
 }
 
@@ -150,6 +356,14 @@ pub struct TrainingComponents<LC: LearningComponentsTypes> {
     pub checkpointer: Option<LearningCheckpointer<LC>>,
     /// Enables gradients accumulation.
     pub grad_accumulation: Option<usize>,
+    /// Records the time spent in each phase of the training steps.
+    pub step_timing: bool,
+    /// Records the gradient and weight statistics of the training steps.
+    pub diagnostics: bool,
+    /// Logs the histograms of the parameters and of the gradients of the model at each epoch.
+    pub histograms: Option<TensorBoardWriter>,
+    /// The parts of the data pipeline notified of the progress of the training.
+    pub curricula: Vec<Arc<dyn CurriculumAware>>,
     /// An [Interupter](Interrupter) that allows aborting the training/evaluation process early.
     pub interrupter: Interrupter,
     /// Cloneable reference to an early stopping strategy.
@@ -1,5 +1,7 @@
 mod epoch;
+mod rendezvous;
 mod strategy;
 mod worker;
 
+pub use rendezvous::*;
 pub use strategy::*;
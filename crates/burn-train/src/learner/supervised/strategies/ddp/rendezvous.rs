@@ -0,0 +1,579 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+/// The interval at which the shared directory is checked while a group is formed.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The file listing the time at which the group was formed, followed by its members.
+const MEMBERS: &str = "members";
+
+/// The error type for rendezvous.
+#[derive(Error, Debug)]
+pub enum RendezvousError {
+    /// IO error.
+    #[error("I/O Error: `{0}`")]
+    IOError(std::io::Error),
+
+    /// The group wasn't formed in time.
+    #[error("The group wasn't formed after {0:?}")]
+    Timeout(Duration),
+
+    /// The shared state is invalid.
+    #[error("Invalid rendezvous state: `{0}`")]
+    Invalid(String),
+}
+
+impl From<std::io::Error> for RendezvousError {
+    fn from(error: std::io::Error) -> Self {
+        Self::IOError(error)
+    }
+}
+
+/// The members of a distributed data parallel group, agreed on by the nodes of the group through
+/// a [rendezvous](Rendezvous).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Membership {
+    /// The generation of the group, which increases each time the group is re-formed.
+    pub generation: u64,
+    /// The rank of this node in the group.
+    pub rank: usize,
+    /// The number of devices of each node, ordered by rank.
+    pub devices: Vec<usize>,
+    /// The latest checkpoint reported by the nodes, from which the group resumes.
+    pub checkpoint: Option<usize>,
+}
+
+impl Membership {
+    /// The membership of a group made of this node only.
+    pub fn local(generation: u64, num_devices: usize, checkpoint: Option<usize>) -> Self {
+        Self {
+            generation,
+            rank: 0,
+            devices: vec![num_devices],
+            checkpoint,
+        }
+    }
+
+    /// The number of nodes in the group.
+    pub fn num_nodes(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// The number of devices in the group, over all the nodes.
+    pub fn world_size(&self) -> usize {
+        self.devices.iter().sum()
+    }
+
+    /// The global rank of the first device of this node.
+    pub fn first_device_rank(&self) -> usize {
+        self.devices[..self.rank].iter().sum()
+    }
+}
+
+/// Forms the groups of the nodes, i.e. the processes, taking part in elastic distributed data
+/// parallel training, and detects the membership changes that require re-forming them.
+///
+/// Each node [joins](Rendezvous::join) the next group, trains until the group needs to be
+/// re-formed because a node failed or a new node waits to join, and then joins the next one.
+pub trait Rendezvous: Send + Sync {
+    /// Joins the next group with the number of devices of this node and its latest checkpoint,
+    /// blocking until the group is formed.
+    fn join(
+        &self,
+        num_devices: usize,
+        checkpoint: Option<usize>,
+    ) -> Result<Membership, RendezvousError>;
+
+    /// Keeps this node alive in the group, and returns whether the group must be re-formed.
+    ///
+    /// Called periodically while training.
+    fn poll(&self, membership: &Membership) -> Result<bool, RendezvousError>;
+
+    /// Requests the group to be re-formed, e.g. after a local worker failed.
+    fn restart(&self, membership: &Membership) -> Result<(), RendezvousError>;
+
+    /// Leaves the group once training is completed.
+    fn leave(&self, membership: &Membership) -> Result<(), RendezvousError>;
+}
+
+/// A [rendezvous](Rendezvous) through a directory shared by all the nodes, e.g. on a network file
+/// system.
+///
+/// Each generation of the group has its own sub-directory, where the nodes register themselves.
+/// Once at least the minimum number of nodes are registered, and no other node registered for the
+/// settle duration, the node with the lowest id writes the members of the group. The nodes then
+/// record a heartbeat, and a node whose heartbeat is older than the timeout is considered failed.
+///
+/// The heartbeats, and the time at which the group was formed, are timestamps written in the
+/// files rather than their modification times, which aren't reliable on every shared file system.
+/// The clocks of the nodes should still be synchronized.
+pub struct FileRendezvous {
+    directory: PathBuf,
+    id: String,
+    min_nodes: usize,
+    settle: Duration,
+    timeout: Duration,
+    last_heartbeat: Mutex<Option<Instant>>,
+}
+
+impl FileRendezvous {
+    /// Create a rendezvous in the given shared directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self {
+            directory: directory.into(),
+            id: format!("{}-{}", since_epoch.as_nanos(), std::process::id()),
+            min_nodes: 1,
+            settle: Duration::from_secs(5),
+            timeout: Duration::from_secs(60),
+            last_heartbeat: Mutex::new(None),
+        }
+    }
+
+    /// Set the id of this node (default: the time of creation and the process id).
+    ///
+    /// The ids must be unique, and order the ranks of the nodes.
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the minimum number of nodes to form a group (default: 1).
+    pub fn with_min_nodes(mut self, min_nodes: usize) -> Self {
+        self.min_nodes = min_nodes;
+        self
+    }
+
+    /// Set the duration without new nodes after which a group is formed (default: 5 seconds).
+    pub fn with_settle(mut self, settle: Duration) -> Self {
+        self.settle = settle;
+        self
+    }
+
+    /// Set the duration after which a group that isn't formed, or a node without heartbeat, is
+    /// considered failed (default: 60 seconds).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn generation_dir(&self, generation: u64) -> PathBuf {
+        self.directory.join(generation.to_string())
+    }
+
+    /// The latest generation, which is created when there is none.
+    fn current_generation(&self) -> Result<u64, RendezvousError> {
+        std::fs::create_dir_all(&self.directory)?;
+
+        let mut current = None;
+        for entry in std::fs::read_dir(&self.directory)? {
+            let generation = entry?
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse().ok());
+            current = current.max(generation);
+        }
+
+        match current {
+            Some(generation) => Ok(generation),
+            None => {
+                std::fs::create_dir_all(self.generation_dir(0))?;
+                Ok(0)
+            }
+        }
+    }
+
+    fn heartbeat(&self, membership: &Membership) -> Result<(), RendezvousError> {
+        let mut last_heartbeat = self.last_heartbeat.lock().unwrap();
+        if last_heartbeat.is_some_and(|last| last.elapsed() < self.timeout / 4) {
+            return Ok(());
+        }
+
+        let directory = self.generation_dir(membership.generation);
+        write_atomic(
+            &directory,
+            &format!("alive-{}", self.id),
+            &timestamp().to_string(),
+        )?;
+        *last_heartbeat = Some(Instant::now());
+
+        Ok(())
+    }
+}
+
+impl Rendezvous for FileRendezvous {
+    fn join(
+        &self,
+        num_devices: usize,
+        checkpoint: Option<usize>,
+    ) -> Result<Membership, RendezvousError> {
+        let deadline = Instant::now() + self.timeout;
+        let registration = match checkpoint {
+            Some(epoch) => format!("{num_devices} {epoch}"),
+            None => format!("{num_devices} -"),
+        };
+
+        loop {
+            let generation = self.current_generation()?;
+            let directory = self.generation_dir(generation);
+            let next = self.generation_dir(generation + 1);
+
+            // The group was formed without this node, which waits for the next one.
+            if directory.join(MEMBERS).exists() {
+                std::fs::create_dir_all(&next)?;
+                continue;
+            }
+
+            write_atomic(&directory, &format!("node-{}", self.id), &registration)?;
+
+            let mut registered = 0;
+            let mut settled = Instant::now();
+            loop {
+                if let Some((_, members)) = read_members(&directory)? {
+                    let Some(rank) = members.iter().position(|(id, ..)| *id == self.id) else {
+                        break;
+                    };
+                    *self.last_heartbeat.lock().unwrap() = None;
+                    let membership = Membership {
+                        generation,
+                        rank,
+                        devices: members.iter().map(|(_, devices, _)| *devices).collect(),
+                        checkpoint: members.iter().filter_map(|(.., epoch)| *epoch).max(),
+                    };
+                    self.heartbeat(&membership)?;
+
+                    return Ok(membership);
+                }
+                if next.exists() {
+                    break;
+                }
+
+                let nodes = read_nodes(&directory)?;
+                if nodes.len() != registered {
+                    registered = nodes.len();
+                    settled = Instant::now();
+                }
+                let is_leader = nodes.first().is_some_and(|(id, ..)| *id == self.id);
+                if is_leader && registered >= self.min_nodes && settled.elapsed() >= self.settle {
+                    let members = nodes
+                        .iter()
+                        .map(|(id, devices, epoch)| match epoch {
+                            Some(epoch) => format!("{id} {devices} {epoch}"),
+                            None => format!("{id} {devices} -"),
+                        })
+                        .collect::<Vec<_>>();
+                    let content = format!("{}\n{}", timestamp(), members.join("\n"));
+                    create_atomic(&directory, MEMBERS, &content)?;
+                    continue;
+                }
+
+                if Instant::now() > deadline {
+                    return Err(RendezvousError::Timeout(self.timeout));
+                }
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+
+    fn poll(&self, membership: &Membership) -> Result<bool, RendezvousError> {
+        self.heartbeat(membership)?;
+
+        if self.generation_dir(membership.generation + 1).exists() {
+            return Ok(true);
+        }
+
+        let directory = self.generation_dir(membership.generation);
+        let Some((formed, members)) = read_members(&directory)? else {
+            return Err(RendezvousError::Invalid(format!(
+                "generation {} has no members",
+                membership.generation
+            )));
+        };
+        let now = timestamp();
+        let timeout = self.timeout.as_millis() as u64;
+
+        for (id, ..) in members.iter().filter(|(id, ..)| *id != self.id) {
+            if directory.join(format!("done-{id}")).exists() {
+                continue;
+            }
+
+            let alive = match std::fs::read_to_string(directory.join(format!("alive-{id}"))) {
+                Ok(content) => content.trim().parse().unwrap_or(formed),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => formed,
+                Err(error) => return Err(error.into()),
+            };
+            if now.saturating_sub(alive) > timeout {
+                log::warn!("Node {id} of the distributed data parallel group has no heartbeat");
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn restart(&self, membership: &Membership) -> Result<(), RendezvousError> {
+        std::fs::create_dir_all(self.generation_dir(membership.generation + 1))?;
+        Ok(())
+    }
+
+    fn leave(&self, membership: &Membership) -> Result<(), RendezvousError> {
+        let directory = self.generation_dir(membership.generation);
+        write_atomic(&directory, &format!("done-{}", self.id), "")?;
+        Ok(())
+    }
+}
+
+/// The milliseconds since the Unix epoch.
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Writes the file through a temporary file, so that it's never read partially written.
+fn write_atomic(directory: &Path, name: &str, content: &str) -> Result<(), RendezvousError> {
+    let temporary = directory.join(format!(".{name}.tmp"));
+    std::fs::write(&temporary, content)?;
+    std::fs::rename(temporary, directory.join(name))?;
+    Ok(())
+}
+
+/// Writes the file like [write_atomic], unless it already exists.
+fn create_atomic(directory: &Path, name: &str, content: &str) -> Result<(), RendezvousError> {
+    let temporary = directory.join(format!(".{name}.{}.tmp", timestamp()));
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temporary)?;
+    file.write_all(content.as_bytes())?;
+    core::mem::drop(file);
+
+    let result = std::fs::hard_link(&temporary, directory.join(name));
+    std::fs::remove_file(temporary)?;
+    match result {
+        Err(error) if error.kind() != std::io::ErrorKind::AlreadyExists => Err(error.into()),
+        _ => Ok(()),
+    }
+}
+
+type Node = (String, usize, Option<usize>);
+
+/// Parses a node, formatted as `<id> <devices> <checkpoint or ->`.
+fn parse_node(line: &str) -> Result<Node, RendezvousError> {
+    let invalid = || RendezvousError::Invalid(format!("invalid node `{line}`"));
+    let mut fields = line.split_whitespace();
+    let id = fields.next().ok_or_else(invalid)?;
+    let devices = fields
+        .next()
+        .and_then(|devices| devices.parse().ok())
+        .ok_or_else(invalid)?;
+    let checkpoint = match fields.next() {
+        Some("-") => None,
+        Some(epoch) => Some(epoch.parse().map_err(|_| invalid())?),
+        None => return Err(invalid()),
+    };
+
+    Ok((id.to_string(), devices, checkpoint))
+}
+
+/// The time at which the group was formed and its members, ordered by rank, if it was formed.
+fn read_members(directory: &Path) -> Result<Option<(u64, Vec<Node>)>, RendezvousError> {
+    let content = match std::fs::read_to_string(directory.join(MEMBERS)) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut lines = content.lines();
+    let formed = lines
+        .next()
+        .and_then(|line| line.trim().parse().ok())
+        .ok_or_else(|| RendezvousError::Invalid("invalid formation time".to_string()))?;
+    let members = lines.map(parse_node).collect::<Result<_, _>>()?;
+
+    Ok(Some((formed, members)))
+}
+
+/// The nodes registered for the group, ordered by id.
+fn read_nodes(directory: &Path) -> Result<Vec<Node>, RendezvousError> {
+    let mut nodes = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(id) = name.to_str().and_then(|name| name.strip_prefix("node-")) else {
+            continue;
+        };
+        let registration = std::fs::read_to_string(entry.path())?;
+        nodes.push(parse_node(&format!("{id} {registration}"))?);
+    }
+    nodes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn rendezvous_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "burn-rendezvous-{name}-{}-{}",
+            std::process::id(),
+            timestamp()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn node(directory: &Path, id: &str) -> Arc<FileRendezvous> {
+        node_with_timeout(directory, id, Duration::from_millis(2000))
+    }
+
+    fn node_with_timeout(directory: &Path, id: &str, timeout: Duration) -> Arc<FileRendezvous> {
+        Arc::new(
+            FileRendezvous::new(directory)
+                .with_id(id)
+                .with_min_nodes(2)
+                .with_settle(Duration::from_millis(100))
+                .with_timeout(timeout),
+        )
+    }
+
+    fn join_all(nodes: &[Arc<FileRendezvous>], checkpoints: &[Option<usize>]) -> Vec<Membership> {
+        let handles = nodes
+            .iter()
+            .zip(checkpoints)
+            .enumerate()
+            .map(|(index, (node, checkpoint))| {
+                let node = node.clone();
+                let checkpoint = *checkpoint;
+                std::thread::spawn(move || node.join(index + 1, checkpoint).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_form_group_across_nodes() {
+        let directory = rendezvous_dir("form");
+        let nodes = [node(&directory, "a"), node(&directory, "b")];
+
+        let memberships = join_all(&nodes, &[None, Some(3)]);
+
+        assert_eq!(memberships[0].rank, 0);
+        assert_eq!(memberships[1].rank, 1);
+        for membership in &memberships {
+            assert_eq!(membership.generation, 0);
+            assert_eq!(membership.devices, vec![1, 2]);
+            assert_eq!(membership.checkpoint, Some(3));
+        }
+        assert_eq!(memberships[1].first_device_rank(), 1);
+        assert!(!nodes[0].poll(&memberships[0]).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_reform_group_after_restart() {
+        let directory = rendezvous_dir("restart");
+        let nodes = [node(&directory, "a"), node(&directory, "b")];
+        let memberships = join_all(&nodes, &[None, None]);
+
+        // A worker of the second node failed.
+        nodes[1].restart(&memberships[1]).unwrap();
+        assert!(nodes[0].poll(&memberships[0]).unwrap());
+
+        let memberships = join_all(&nodes, &[Some(1), Some(1)]);
+        for membership in &memberships {
+            assert_eq!(membership.generation, 1);
+            assert_eq!(membership.checkpoint, Some(1));
+        }
+        assert!(!nodes[0].poll(&memberships[0]).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_reform_group_when_node_joins() {
+        let directory = rendezvous_dir("join");
+        let nodes = [node(&directory, "a"), node(&directory, "b")];
+        let memberships = join_all(&nodes, &[None, None]);
+
+        let newcomer = node(&directory, "c");
+        let handle = std::thread::spawn(move || newcomer.join(4, None).unwrap());
+
+        while !nodes[0].poll(&memberships[0]).unwrap() {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+        let memberships = join_all(&nodes, &[None, None]);
+        let newcomer = handle.join().unwrap();
+
+        assert_eq!(newcomer.generation, 1);
+        assert_eq!(newcomer.rank, 2);
+        assert_eq!(newcomer.devices, vec![1, 2, 4]);
+        assert_eq!(memberships[0].devices, vec![1, 2, 4]);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_reform_group_when_node_stops_heartbeat() {
+        let directory = rendezvous_dir("heartbeat");
+        let nodes = [
+            node_with_timeout(&directory, "a", Duration::from_millis(300)),
+            node_with_timeout(&directory, "b", Duration::from_millis(300)),
+        ];
+        let memberships = join_all(&nodes, &[None, None]);
+
+        assert!(!nodes[0].poll(&memberships[0]).unwrap());
+        // The second node doesn't poll anymore, as if its process was killed.
+        std::thread::sleep(Duration::from_millis(500));
+        assert!(nodes[0].poll(&memberships[0]).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_read_heartbeat_time_from_file() {
+        let directory = rendezvous_dir("heartbeat-time");
+        let nodes = [node(&directory, "a"), node(&directory, "b")];
+        let memberships = join_all(&nodes, &[None, None]);
+
+        // A freshly modified heartbeat file holding an old timestamp is stale.
+        let generation = directory.join(memberships[0].generation.to_string());
+        std::fs::write(generation.join("alive-b"), "0").unwrap();
+        assert!(nodes[0].poll(&memberships[0]).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_not_reform_group_when_node_leaves() {
+        let directory = rendezvous_dir("leave");
+        let nodes = [
+            node_with_timeout(&directory, "a", Duration::from_millis(300)),
+            node_with_timeout(&directory, "b", Duration::from_millis(300)),
+        ];
+        let memberships = join_all(&nodes, &[None, None]);
+
+        nodes[1].leave(&memberships[1]).unwrap();
+        std::thread::sleep(Duration::from_millis(500));
+        assert!(!nodes[0].poll(&memberships[0]).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
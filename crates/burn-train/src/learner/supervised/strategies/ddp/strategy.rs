@@ -1,16 +1,22 @@
 use core::panic;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 use crate::ddp::worker::DdpWorker;
 use crate::metric::store::EventStoreClient;
 use crate::{
-    DistributedRuntime, EarlyStoppingStrategyRef, Interrupter, Learner, LearningComponentsTypes,
-    SupervisedLearningStrategy, SupervisedTrainingEventProcessor, TrainLoader, TrainingComponents,
-    TrainingModel, ValidLoader,
+    DistributedRuntime, EarlyStoppingStrategyRef, ElasticConfig, Interrupter, Learner,
+    LearningComponentsTypes, Membership, SupervisedLearningStrategy,
+    SupervisedTrainingEventProcessor, TrainLoader, TrainingComponents, TrainingModel, ValidLoader,
 };
+use burn_core::data::dataloader::DataLoader;
 use burn_core::data::dataloader::split::split_dataloader;
 use burn_core::tensor::Device;
 
+/// The interval at which the workers are checked for failures.
+const MONITOR_INTERVAL: Duration = Duration::from_millis(10);
+
 #[derive(Clone)]
 pub(crate) struct WorkerComponents {
     /// The total number of epochs
@@ -32,13 +38,114 @@ pub(crate) struct WorkerComponents {
 pub struct DdpTrainingStrategy<LC: LearningComponentsTypes> {
     devices: Vec<Device>,
     runtime: Box<dyn DistributedRuntime>,
+    elastic: Option<ElasticConfig>,
 }
 impl<LC: LearningComponentsTypes> DdpTrainingStrategy<LC> {
-    pub fn new(devices: Vec<Device>, runtime: Box<dyn DistributedRuntime>) -> Self {
-        Self { devices, runtime }
+    pub fn new(
+        devices: Vec<Device>,
+        runtime: Box<dyn DistributedRuntime>,
+        elastic: Option<ElasticConfig>,
+    ) -> Self {
+        Self {
+            devices,
+            runtime,
+            elastic,
+        }
+    }
+
+    /// Joins the next group with the given devices, through the rendezvous of the elastic config
+    /// if there is one.
+    fn join(&self, devices: &[Device], checkpoint: Option<usize>, generation: u64) -> Membership {
+        match self
+            .elastic
+            .as_ref()
+            .and_then(|elastic| elastic.rendezvous.as_ref())
+        {
+            Some(rendezvous) => rendezvous
+                .join(devices.len(), checkpoint)
+                .expect("Can form the distributed data parallel group"),
+            None => Membership::local(generation, devices.len(), checkpoint),
+        }
     }
 }
 
+/// Why the workers of a group were stopped before completing.
+#[derive(Debug, PartialEq, Eq)]
+enum Restart {
+    /// The worker with the given index failed.
+    Failed(usize),
+    /// The group must be re-formed with other nodes.
+    Reformed,
+}
+
+/// Waits for the workers to finish, stopping them all as soon as one of them fails or the group
+/// must be re-formed, as reported by `poll`.
+///
+/// Returns the result of each worker, and why they were stopped, if they didn't complete.
+#[allow(clippy::type_complexity)]
+fn monitor<T>(
+    workers: Vec<JoinHandle<T>>,
+    interrupter: &Interrupter,
+    attempt: &Interrupter,
+    mut poll: impl FnMut() -> bool,
+    on_restart: impl Fn(),
+) -> (Vec<std::thread::Result<T>>, Option<Restart>) {
+    let mut workers = workers.into_iter().map(Some).collect::<Vec<_>>();
+    let mut results = workers.iter().map(|_| None).collect::<Vec<_>>();
+    let mut restart = None;
+
+    while workers.iter().any(Option::is_some) {
+        if interrupter.should_stop() && !attempt.should_stop() {
+            attempt.stop(interrupter.get_message().as_deref());
+        }
+        if restart.is_none() && !attempt.should_stop() && poll() {
+            log::warn!("The distributed data parallel group changed");
+            restart = Some(Restart::Reformed);
+            on_restart();
+            attempt.stop(Some("Distributed data parallel group changed"));
+        }
+
+        for (index, worker) in workers.iter_mut().enumerate() {
+            if !worker.as_ref().is_some_and(|worker| worker.is_finished()) {
+                continue;
+            }
+
+            let result = worker.take().unwrap().join();
+            if result.is_err() && restart.is_none() {
+                log::warn!("Distributed data parallel worker {index} failed");
+                restart = Some(Restart::Failed(index));
+                // Closing the runtime releases the workers waiting on a collective operation.
+                on_restart();
+                attempt.stop(Some("Distributed data parallel worker failed"));
+            }
+            results[index] = Some(result);
+        }
+
+        std::thread::sleep(MONITOR_INTERVAL);
+    }
+
+    (results.into_iter().map(Option::unwrap).collect(), restart)
+}
+
+/// The part of the training data of this node, proportional to its number of devices.
+fn node_shard<O>(
+    dataloader: Arc<dyn DataLoader<O>>,
+    membership: &Membership,
+) -> Arc<dyn DataLoader<O>> {
+    let world_size = membership.world_size();
+    let num_devices = membership.devices[membership.rank];
+    if num_devices == world_size {
+        return dataloader;
+    }
+
+    let num_items = dataloader.num_items();
+    let first = membership.first_device_rank();
+    let start = num_items * first / world_size;
+    let end = num_items * (first + num_devices) / world_size;
+
+    dataloader.slice(start, end)
+}
+
 impl<LC> SupervisedLearningStrategy<LC> for DdpTrainingStrategy<LC>
 where
     LC: LearningComponentsTypes + Send + 'static,
@@ -51,20 +158,13 @@ where
         dataloader_valid: ValidLoader<LC>,
         starting_epoch: usize,
     ) -> (TrainingModel<LC>, SupervisedTrainingEventProcessor<LC>) {
-        // The reference model is always on the first device provided.
-        let main_device = self.devices.first().unwrap();
-        // One worker per device, so we use a fixed device strategy
-        // for each (worker) data loader. This matches the expected device on the worker, so we
-        // don't have to move the data between devices.
-        let mut dataloaders_train = split_dataloader(dataloader_train, &self.devices);
-        let dataloader_valid = dataloader_valid.to_device(&main_device.clone().inner());
-
-        let main_device = self.devices[0].clone();
-        let peer_count = self.devices.len();
         let event_processor = Arc::new(Mutex::new(training_components.event_processor));
+        let checkpointer = training_components
+            .checkpointer
+            .map(|checkpointer| Arc::new(Mutex::new(checkpointer)));
 
         let interrupter = training_components.interrupter;
-        let worker_components = WorkerComponents {
+        let mut worker_components = WorkerComponents {
             num_epochs: training_components.num_epochs,
             grad_accumulation: training_components.grad_accumulation,
             interrupter: interrupter.clone(),
@@ -72,55 +172,158 @@ where
             event_store: training_components.event_store,
         };
 
-        self.runtime.start();
-
-        // Start worker for main device
-        // First training dataloader corresponds to main device
-        let main_handle = DdpWorker::<LC>::start(
-            main_device.clone(),
-            learner.clone(),
-            event_processor.clone(),
-            worker_components.clone(),
-            training_components.checkpointer,
-            dataloaders_train.remove(0),
-            Some(dataloader_valid),
-            starting_epoch,
-            peer_count,
-            true,
-        );
+        let mut devices = self.devices.clone();
+        let mut learner = learner;
+        let mut starting_epoch = starting_epoch;
+        let mut restarts = 0;
+
+        let model = loop {
+            // The latest checkpoint of this node, which the group agrees on to resume.
+            let checkpoint = checkpointer
+                .as_ref()
+                .and_then(|checkpointer| {
+                    let checkpointer = checkpointer.lock().unwrap_or_else(PoisonError::into_inner);
+                    checkpointer.last_checkpoint()
+                })
+                .or(starting_epoch.checked_sub(1).filter(|epoch| *epoch > 0));
+            let membership = self.join(&devices, checkpoint, restarts as u64);
+
+            // Roll back to the last consistent state.
+            if let Some(epoch) = membership.checkpoint
+                && epoch + 1 != starting_epoch
+            {
+                match &checkpointer {
+                    Some(checkpointer) => {
+                        let checkpointer =
+                            checkpointer.lock().unwrap_or_else(PoisonError::into_inner);
+                        learner = checkpointer.load_checkpoint(learner, &devices[0], epoch);
+                        starting_epoch = epoch + 1;
+                    }
+                    None => log::warn!(
+                        "Can't resume from the checkpoint of epoch {epoch} without a checkpointer"
+                    ),
+                }
+            }
 
-        // Spawn other workers for the other devices, starting with peer id 1
-        let mut secondary_workers = vec![];
-        for device in &self.devices[1..] {
-            let handle = DdpWorker::<LC>::start(
-                device.clone(),
+            // The reference model is always on the first device provided.
+            let main_device = devices[0].clone();
+            // One worker per device, so we use a fixed device strategy
+            // for each (worker) data loader. This matches the expected device on the worker, so we
+            // don't have to move the data between devices.
+            let mut dataloaders_train =
+                split_dataloader(node_shard(dataloader_train.clone(), &membership), &devices);
+            let dataloader_valid = dataloader_valid.to_device(&main_device.clone().inner());
+            let peer_count = devices.len();
+
+            // Each attempt has its own interrupter, so that a failure only stops its workers.
+            let attempt = Interrupter::new();
+            worker_components.interrupter = attempt.clone();
+
+            match &self.elastic {
+                Some(_) => {
+                    if let Err(error) = self.runtime.start_group(&devices, &membership) {
+                        panic!("Can't start the distributed data parallel group: {error}");
+                    }
+                }
+                None => self.runtime.start(),
+            }
+
+            // Start worker for main device
+            // First training dataloader corresponds to main device
+            let mut workers = vec![DdpWorker::<LC>::start(
+                main_device,
                 learner.clone(),
                 event_processor.clone(),
                 worker_components.clone(),
-                None,
+                checkpointer.clone(),
                 dataloaders_train.remove(0),
-                None,
+                Some(dataloader_valid),
                 starting_epoch,
                 peer_count,
-                false,
-            );
+                true,
+            )];
 
-            secondary_workers.push(handle);
-        }
+            // Spawn other workers for the other devices, starting with peer id 1
+            for device in &devices[1..] {
+                workers.push(DdpWorker::<LC>::start(
+                    device.clone(),
+                    learner.clone(),
+                    event_processor.clone(),
+                    worker_components.clone(),
+                    None,
+                    dataloaders_train.remove(0),
+                    None,
+                    starting_epoch,
+                    peer_count,
+                    false,
+                ));
+            }
 
-        // Wait for all devices to finish
-        for worker in secondary_workers {
-            worker
-                .join()
-                .expect("Distributed data parallel worker failed");
-        }
+            let rendezvous = self
+                .elastic
+                .as_ref()
+                .and_then(|elastic| elastic.rendezvous.clone());
+            let poll = || match &rendezvous {
+                Some(rendezvous) => rendezvous.poll(&membership).unwrap_or_else(|error| {
+                    log::warn!("Can't poll the distributed data parallel group: {error}");
+                    false
+                }),
+                None => false,
+            };
+
+            // Wait for all devices to finish
+            let (mut results, restart) = monitor(workers, &interrupter, &attempt, poll, || {
+                self.runtime.close()
+            });
+
+            let Some(restart) = restart else {
+                self.runtime.close();
+                if let Some(rendezvous) = &rendezvous
+                    && let Err(error) = rendezvous.leave(&membership)
+                {
+                    log::warn!("Can't leave the distributed data parallel group: {error}");
+                }
 
-        self.runtime.close();
+                // Main worker had the event processor
+                break results
+                    .remove(0)
+                    .expect("Distributed data parallel main worker failed");
+            };
 
-        // Main worker had the event processor
-        let model = main_handle
-            .join()
-            .expect("Distributed data parallel main worker failed");
+            let elastic = match &self.elastic {
+                Some(elastic) if restarts < elastic.max_restarts => elastic,
+                _ => panic!("Distributed data parallel worker failed"),
+            };
+            restarts += 1;
+
+            if let Restart::Failed(failed) = restart {
+                if let Some(rendezvous) = &rendezvous {
+                    rendezvous
+                        .restart(&membership)
+                        .expect("Can re-form the distributed data parallel group");
+                }
+                if elastic.device_pool.is_none() {
+                    devices.remove(failed);
+                }
+            }
+            if let Some(pool) = &elastic.device_pool {
+                devices = pool();
+            }
+            if devices.len() < elastic.min_devices.max(1) {
+                panic!(
+                    "Distributed data parallel training needs at least {} devices, {} are available",
+                    elastic.min_devices.max(1),
+                    devices.len()
+                );
+            }
+            event_processor.clear_poison();
+
+            log::warn!(
+                "Re-forming the distributed data parallel group with {} local devices (restart \
+                 {restarts})",
+                devices.len()
+            );
+        };
 
         if interrupter.should_stop() {
             let reason = interrupter
@@ -128,6 +331,7 @@ where
                 .unwrap_or(String::from("Reason unknown"));
             log::info!("Training interrupted: {reason}");
         }
+        drop(checkpointer);
         let Ok(event_processor) = Arc::try_unwrap(event_processor) else {
             panic!("Event processor still held!");
         };
@@ -137,3 +341,127 @@ where
         (model, event_processor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElasticError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Spawns a worker running until its attempt is stopped, or failing after some iterations.
+    fn worker(attempt: &Interrupter, fail_after: Option<usize>) -> JoinHandle<usize> {
+        let attempt = attempt.clone();
+        std::thread::spawn(move || {
+            let mut iteration = 0;
+            while !attempt.should_stop() {
+                if fail_after == Some(iteration) {
+                    panic!("Worker failed");
+                }
+                iteration += 1;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            iteration
+        })
+    }
+
+    /// Counts the times it's started.
+    #[derive(Default)]
+    struct CountingRuntime {
+        starts: AtomicUsize,
+    }
+
+    impl DistributedRuntime for CountingRuntime {
+        fn start(&self) {
+            self.starts.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn close(&self) {}
+    }
+
+    #[test]
+    fn should_reject_multi_node_group_without_node_synchronization() {
+        let runtime = CountingRuntime::default();
+        let devices = [Device::default()];
+
+        let group = Membership {
+            generation: 0,
+            rank: 1,
+            devices: vec![1, 1],
+            checkpoint: None,
+        };
+        assert_eq!(
+            runtime.start_group(&devices, &group),
+            Err(ElasticError::MultiNode)
+        );
+        assert_eq!(runtime.starts.load(Ordering::Relaxed), 0);
+
+        let local = Membership::local(0, 1, None);
+        assert_eq!(runtime.start_group(&devices, &local), Ok(()));
+        assert_eq!(runtime.starts.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn should_stop_workers_to_restart_when_worker_fails() {
+        let interrupter = Interrupter::new();
+        let attempt = Interrupter::new();
+        let restarts = AtomicUsize::new(0);
+        let workers = vec![
+            worker(&attempt, None),
+            worker(&attempt, Some(5)),
+            worker(&attempt, None),
+        ];
+
+        let (results, restart) = monitor(
+            workers,
+            &interrupter,
+            &attempt,
+            || false,
+            || {
+                restarts.fetch_add(1, Ordering::Relaxed);
+            },
+        );
+
+        assert_eq!(restart, Some(Restart::Failed(1)));
+        assert_eq!(restarts.load(Ordering::Relaxed), 1);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert!(!interrupter.should_stop());
+    }
+
+    #[test]
+    fn should_stop_workers_to_restart_when_group_changes() {
+        let interrupter = Interrupter::new();
+        let attempt = Interrupter::new();
+        let mut polls = 0;
+        let workers = vec![worker(&attempt, None), worker(&attempt, None)];
+
+        let (results, restart) = monitor(
+            workers,
+            &interrupter,
+            &attempt,
+            || {
+                polls += 1;
+                polls > 3
+            },
+            || {},
+        );
+
+        assert_eq!(restart, Some(Restart::Reformed));
+        assert!(results.iter().all(|result| result.is_ok()));
+    }
+
+    #[test]
+    fn should_complete_without_restart_when_interrupted() {
+        let interrupter = Interrupter::new();
+        let attempt = Interrupter::new();
+        let workers = vec![worker(&attempt, None), worker(&attempt, None)];
+        interrupter.stop(Some("Stopped"));
+
+        let (results, restart) = monitor(workers, &interrupter, &attempt, || false, || {});
+
+        assert_eq!(restart, None);
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert_eq!(attempt.get_message().as_deref(), Some("Stopped"));
+    }
+}
@@ -19,7 +19,7 @@ where
     learner: Learner<LC>,
     event_processor: Arc<Mutex<SupervisedTrainingEventProcessor<LC>>>,
     components: WorkerComponents,
-    checkpointer: Option<LearningCheckpointer<LC>>,
+    checkpointer: Option<Arc<Mutex<LearningCheckpointer<LC>>>>,
     dataloader_train: TrainLoader<LC>,
     dataloader_valid: Option<ValidLoader<LC>>,
     starting_epoch: usize,
@@ -38,7 +38,7 @@ where
         learner: Learner<LC>,
         event_processor: Arc<Mutex<SupervisedTrainingEventProcessor<LC>>>,
         components: WorkerComponents,
-        checkpointer: Option<LearningCheckpointer<LC>>,
+        checkpointer: Option<Arc<Mutex<LearningCheckpointer<LC>>>>,
         dataloader_train: TrainLoader<LC>,
         dataloader_valid: Option<ValidLoader<LC>>,
         starting_epoch: usize,
@@ -97,14 +97,15 @@ where
             if let Some(runner) = &epoch_valid {
                 let mut event_processor = self.event_processor.lock().unwrap();
                 runner.run(
-                    &self.learner.model(),
+                    &self.learner.eval_model(),
                     &training_progress,
                     &mut event_processor,
                     &interrupter,
                 );
             }
 
-            if let Some(checkpointer) = &mut self.checkpointer {
+            if let Some(checkpointer) = &self.checkpointer {
+                let mut checkpointer = checkpointer.lock().unwrap();
                 checkpointer.checkpoint(&self.learner, epoch, &self.components.event_store);
             }
 
@@ -115,6 +116,6 @@ where
             }
         }
 
-        self.learner.model()
+        self.learner.eval_model()
     }
 }
@@ -6,3 +6,5 @@ pub(crate) mod multi;
 pub(crate) mod single;
 
 pub use base::*;
+#[cfg(feature = "ddp")]
+pub use ddp::{FileRendezvous, Membership, Rendezvous, RendezvousError};
@@ -47,6 +47,9 @@ impl<LC: LearningComponentsTypes> SupervisedLearningStrategy<LC> for MultiDevice
 
         for training_progress in TrainingLoop::new(starting_epoch, training_components.num_epochs) {
             let epoch = training_progress.items_processed;
+            for curriculum in training_components.curricula.iter() {
+                curriculum.on_epoch_start(epoch, training_components.num_epochs);
+            }
             epoch_train.run(
                 &mut learner,
                 &training_progress,
@@ -89,6 +92,6 @@ impl<LC: LearningComponentsTypes> SupervisedLearningStrategy<LC> for MultiDevice
             }
         }
 
-        (learner.model(), event_processor)
+        (learner.eval_model(), event_processor)
     }
 }
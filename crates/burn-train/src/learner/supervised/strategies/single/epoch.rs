@@ -1,12 +1,20 @@
 use crate::learner::base::Interrupter;
+use crate::learner::diagnostics::DiagnosticsCollector;
+use crate::logger::TensorBoardWriter;
+use crate::metric::StepTiming;
 use crate::metric::processor::{EventProcessorTraining, LearnerEvent, TrainingItem};
 use crate::{
     InferenceStep, Learner, LearningComponentsTypes, SupervisedTrainingEventProcessor, TrainLoader,
-    ValidLoader,
+    TrainOutput, TrainingModelInput, TrainingModelOutput, ValidLoader,
 };
-use burn_core::data::dataloader::Progress;
+use burn_core::data::dataloader::{CurriculumAware, Progress};
 use burn_core::module::AutodiffModule;
+use burn_core::tensor::{Device, observe_backward};
 use burn_optim::GradientsAccumulator;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// A validation epoch.
 #[derive(new)]
@@ -19,6 +27,18 @@ pub struct SingleDeviceValidEpoch<LC: LearningComponentsTypes> {
 pub struct SingleDeviceTrainEpoch<LC: LearningComponentsTypes> {
     dataloader: TrainLoader<LC>,
     grad_accumulation: Option<usize>,
+    /// The device synchronized to time the training steps, when step timing is enabled.
+    #[new(default)]
+    timing_device: Option<Device>,
+    /// Whether the gradient and weight statistics of the training steps are recorded.
+    #[new(default)]
+    diagnostics: bool,
+    /// The writer logging the parameter and gradient histograms at each epoch.
+    #[new(default)]
+    histograms: Option<TensorBoardWriter>,
+    /// The parts of the data pipeline notified after each training step.
+    #[new(default)]
+    curricula: Vec<Arc<dyn CurriculumAware>>,
 }
 
 impl<LC: LearningComponentsTypes> SingleDeviceValidEpoch<LC> {
@@ -37,7 +57,7 @@ impl<LC: LearningComponentsTypes> SingleDeviceValidEpoch<LC> {
     ) {
         let epoch = global_progress.items_processed;
         log::info!("Executing validation step for epoch {}", epoch);
-        let model = learner.model().valid();
+        let model = learner.eval_model().valid();
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
@@ -66,6 +86,80 @@ impl<LC: LearningComponentsTypes> SingleDeviceValidEpoch<LC> {
 }
 
 impl<LC: LearningComponentsTypes> SingleDeviceTrainEpoch<LC> {
+    /// Records the time spent in each phase of the training steps, synchronizing the given
+    /// device at the end of the compute phases.
+    pub fn with_step_timing(mut self, device: Device) -> Self {
+        self.timing_device = Some(device);
+        self
+    }
+
+    /// Records the gradient and weight statistics of the training steps.
+    pub fn with_diagnostics(mut self) -> Self {
+        self.diagnostics = true;
+        self
+    }
+
+    /// Logs the histograms of the parameters at the end of each epoch, and of the gradients of
+    /// the first training step of each epoch.
+    pub fn with_histograms(mut self, writer: TensorBoardWriter) -> Self {
+        self.histograms = Some(writer);
+        self
+    }
+
+    /// Notifies the given curricula after each training step.
+    pub fn with_curricula(mut self, curricula: Vec<Arc<dyn CurriculumAware>>) -> Self {
+        self.curricula = curricula;
+        self
+    }
+
+    /// Waits for the pending work of the timed device, and returns the time elapsed since `start`.
+    fn phase_time(&self, start: Instant) -> Duration {
+        if let Some(device) = &self.timing_device {
+            sync_timed_device(device);
+        }
+
+        start.elapsed()
+    }
+
+    /// Runs the training step, and returns the time spent in its forward and backward passes.
+    ///
+    /// The forward pass ends when the first backward pass starts. Steps without a backward pass
+    /// are attributed to the forward pass.
+    fn timed_train_step(
+        &self,
+        learner: &Learner<LC>,
+        item: TrainingModelInput<LC>,
+    ) -> (TrainOutput<TrainingModelOutput<LC>>, Duration, Duration) {
+        let start = Instant::now();
+        let Some(device) = self.timing_device.clone() else {
+            let item = learner.train_step(item);
+            return (item, start.elapsed(), Duration::ZERO);
+        };
+
+        let backward_start = Rc::new(Cell::new(None));
+        let item = {
+            let backward_start = backward_start.clone();
+            observe_backward(
+                move || {
+                    if backward_start.get().is_none() {
+                        sync_timed_device(&device);
+                        backward_start.set(Some(Instant::now()));
+                    }
+                },
+                || learner.train_step(item),
+            )
+        };
+        let end = self.phase_time(start);
+
+        match backward_start.get() {
+            Some(backward_start) => {
+                let forward = backward_start.duration_since(start);
+                (item, forward, end.saturating_sub(forward))
+            }
+            None => (item, end, Duration::ZERO),
+        }
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -93,15 +187,36 @@ impl<LC: LearningComponentsTypes> SingleDeviceTrainEpoch<LC> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        let mut epoch_timing = StepTiming::default();
+        let mut diagnostics = self.diagnostics.then(DiagnosticsCollector::default);
+        let mut items_processed = 0;
+
+        loop {
+            let start = Instant::now();
+            let Some(item) = iterator.next() else {
+                break;
+            };
+            let data = start.elapsed();
 
-        while let Some(item) = iterator.next() {
             iteration += 1;
             learner.lr_step();
             log::info!("Iteration {iteration}");
 
             let progress = iterator.progress();
-            let item = learner.train_step(item);
+            let (item, forward, backward) = self.timed_train_step(learner, item);
 
+            let batch_size = progress.items_processed.saturating_sub(items_processed);
+            items_processed = progress.items_processed;
+            if let Some(diagnostics) = diagnostics.as_mut() {
+                diagnostics.record_gradients(&learner.model(), &item.grads, batch_size);
+            }
+            if let Some(writer) = &self.histograms
+                && iteration == 1
+            {
+                writer.add_gradient_histograms(&learner.model(), &item.grads, epoch);
+            }
+
+            let start = Instant::now();
             match self.grad_accumulation {
                 Some(accumulation) => {
                     accumulator.accumulate(&learner.model(), item.grads);
@@ -110,12 +225,34 @@ impl<LC: LearningComponentsTypes> SingleDeviceTrainEpoch<LC> {
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
 
+                        if let Some(diagnostics) = diagnostics.as_mut() {
+                            diagnostics.record_weights(&learner.model());
+                        }
                         learner.optimizer_step(grads);
                         accumulation_current = 0;
                     }
                 }
-                None => learner.optimizer_step(item.grads),
+                None => {
+                    if let Some(diagnostics) = diagnostics.as_mut() {
+                        diagnostics.record_weights(&learner.model());
+                    }
+                    learner.optimizer_step(item.grads)
+                }
             }
+            let optimizer = self.phase_time(start);
+
+            // The updates are read back after the step is timed, to leave the optimizer phase
+            // undisturbed.
+            let step_diagnostics = diagnostics.as_mut().and_then(|diagnostics| {
+                diagnostics.record_updates(&learner.model());
+                diagnostics.finish()
+            });
+
+            let step_timing = self.timing_device.as_ref().map(|_| {
+                let timing = StepTiming::new(data, forward, backward, optimizer);
+                epoch_timing += timing;
+                timing
+            });
 
             let item = TrainingItem::new(
                 item.item,
@@ -123,14 +260,32 @@ impl<LC: LearningComponentsTypes> SingleDeviceTrainEpoch<LC> {
                 global_progress.clone(),
                 Some(iteration),
                 Some(learner.lr_current()),
-            );
+            )
+            .with_step_timing(step_timing)
+            .with_diagnostics(step_diagnostics);
 
             processor.process_train(LearnerEvent::ProcessedItem(item));
+            for curriculum in self.curricula.iter() {
+                curriculum.on_step(epoch, iteration);
+            }
 
             if interrupter.should_stop() {
                 break;
             }
         }
+
+        if let Some(writer) = &self.histograms {
+            writer.add_parameter_histograms(&learner.model(), epoch);
+        }
+        if self.timing_device.is_some() && iteration > 0 {
+            log::info!("Training epoch {epoch} time breakdown: {epoch_timing}");
+        }
         processor.process_train(LearnerEvent::EndEpoch(epoch));
     }
 }
+
+fn sync_timed_device(device: &Device) {
+    if let Err(err) = device.sync() {
+        log::warn!("Failed to synchronize the device to time the training step: {err}");
+    }
+}
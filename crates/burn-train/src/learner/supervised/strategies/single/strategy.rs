@@ -57,13 +57,27 @@ impl<LC: LearningComponentsTypes> SupervisedLearningStrategy<LC> for SingleDevic
         let mut checkpointer = training_components.checkpointer;
         let mut early_stopping = training_components.early_stopping;
 
-        let epoch_train: SingleDeviceTrainEpoch<LC> =
+        let mut epoch_train: SingleDeviceTrainEpoch<LC> =
             SingleDeviceTrainEpoch::new(dataloader_train, training_components.grad_accumulation);
+        if training_components.step_timing {
+            epoch_train = epoch_train.with_step_timing(self.device.clone());
+        }
+        if training_components.diagnostics {
+            epoch_train = epoch_train.with_diagnostics();
+        }
+        if let Some(writer) = training_components.histograms {
+            epoch_train = epoch_train.with_histograms(writer);
+        }
+        let curricula = training_components.curricula;
+        epoch_train = epoch_train.with_curricula(curricula.clone());
         let epoch_valid: SingleDeviceValidEpoch<LC> =
             SingleDeviceValidEpoch::new(dataloader_valid.clone());
 
         for training_progress in TrainingLoop::new(starting_epoch, training_components.num_epochs) {
             let epoch = training_progress.items_processed;
+            for curriculum in curricula.iter() {
+                curriculum.on_epoch_start(epoch, training_components.num_epochs);
+            }
             epoch_train.run(
                 &mut learner,
                 &training_progress,
@@ -98,6 +112,6 @@ impl<LC: LearningComponentsTypes> SupervisedLearningStrategy<LC> for SingleDevic
             }
         }
 
-        (learner.model(), event_processor)
+        (learner.eval_model(), event_processor)
     }
 }
@@ -6,6 +6,15 @@
 #[macro_use]
 extern crate derive_new;
 
+/// The active learning module.
+pub mod active_learning;
+
+/// The calibration module.
+pub mod calibration;
+
+/// The conformal prediction module.
+pub mod conformal;
+
 /// The checkpoint module.
 pub mod checkpoint;
 
@@ -0,0 +1,211 @@
+//! Minimal encoders for the few messages of TensorBoard event files, to avoid depending on
+//! protobuf and image crates.
+
+use std::io::{self, Write};
+
+/// Reversed polynomial of CRC-32 (IEEE), used by PNG.
+const CRC32_POLY: u32 = 0xEDB8_8320;
+/// Reversed polynomial of CRC-32C (Castagnoli), used by TFRecord.
+const CRC32C_POLY: u32 = 0x82F6_3B78;
+
+/// Largest block of a stored (uncompressed) deflate stream.
+const MAX_STORED_BLOCK: usize = u16::MAX as usize;
+
+/// Protobuf message encoder, supporting the field types of the TensorBoard event messages.
+#[derive(Default)]
+pub(crate) struct ProtoMessage {
+    buf: Vec<u8>,
+}
+
+impl ProtoMessage {
+    pub(crate) fn double(mut self, field: u32, value: f64) -> Self {
+        self.key(field, 1);
+        self.buf.extend(value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn float(mut self, field: u32, value: f32) -> Self {
+        self.key(field, 5);
+        self.buf.extend(value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn int64(mut self, field: u32, value: i64) -> Self {
+        self.key(field, 0);
+        self.varint(value as u64);
+        self
+    }
+
+    pub(crate) fn bytes(mut self, field: u32, value: &[u8]) -> Self {
+        self.key(field, 2);
+        self.varint(value.len() as u64);
+        self.buf.extend(value);
+        self
+    }
+
+    pub(crate) fn string(self, field: u32, value: &str) -> Self {
+        self.bytes(field, value.as_bytes())
+    }
+
+    pub(crate) fn message(self, field: u32, value: ProtoMessage) -> Self {
+        self.bytes(field, &value.buf)
+    }
+
+    pub(crate) fn packed_doubles(mut self, field: u32, values: &[f64]) -> Self {
+        self.key(field, 2);
+        self.varint(8 * values.len() as u64);
+        for value in values {
+            self.buf.extend(value.to_le_bytes());
+        }
+        self
+    }
+
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+}
+
+/// Writes a TFRecord: the length, its masked CRC, the data and its masked CRC.
+pub(crate) fn write_record(writer: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    let len = (data.len() as u64).to_le_bytes();
+    writer.write_all(&len)?;
+    writer.write_all(&masked_crc32c(&len).to_le_bytes())?;
+    writer.write_all(data)?;
+    writer.write_all(&masked_crc32c(data).to_le_bytes())
+}
+
+/// Encodes 8-bit pixels in row-major, interleaved channel order as a PNG image, without
+/// compression.
+pub(crate) fn encode_png(pixels: &[u8], height: usize, width: usize, channels: usize) -> Vec<u8> {
+    let color_type = match channels {
+        1 => 0,
+        2 => 4,
+        3 => 2,
+        4 => 6,
+        _ => panic!("PNG images should have 1 to 4 channels, got {channels}"),
+    };
+    assert!(
+        height > 0 && width > 0,
+        "PNG images should not be empty, got [{height}, {width}]"
+    );
+
+    // Each row starts with its filter type, none here.
+    let mut raw = Vec::with_capacity(height * (width * channels + 1));
+    for row in pixels.chunks_exact(width * channels) {
+        raw.push(0);
+        raw.extend(row);
+    }
+
+    // Zlib stream of stored deflate blocks.
+    let mut zlib = vec![0x78, 0x01];
+    let num_blocks = raw.len().div_ceil(MAX_STORED_BLOCK);
+    for (i, block) in raw.chunks(MAX_STORED_BLOCK).enumerate() {
+        let len = block.len() as u16;
+        zlib.push((i + 1 == num_blocks) as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, color_type, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &zlib);
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..], CRC32_POLY);
+    png.extend(crc.to_be_bytes());
+}
+
+fn masked_crc32c(data: &[u8]) -> u32 {
+    crc32(data, CRC32C_POLY)
+        .rotate_right(15)
+        .wrapping_add(0xA282_EAD8)
+}
+
+fn crc32(data: &[u8], poly: u32) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_crc_check_values() {
+        assert_eq!(crc32(b"123456789", CRC32_POLY), 0xCBF4_3926);
+        assert_eq!(crc32(b"123456789", CRC32C_POLY), 0xE306_9283);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn should_encode_proto_fields() {
+        let message = ProtoMessage::default()
+            .int64(2, 300)
+            .string(1, "ab")
+            .message(5, ProtoMessage::default().float(2, 1.0));
+
+        assert_eq!(
+            message.into_bytes(),
+            [
+                0x10, 0xAC, 0x02, // step = 300
+                0x0A, 0x02, b'a', b'b', // tag = "ab"
+                0x2A, 0x05, 0x15, 0x00, 0x00, 0x80, 0x3F, // { simple_value = 1.0 }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_frame_record() {
+        let mut record = Vec::new();
+        write_record(&mut record, b"abc").unwrap();
+
+        assert_eq!(record.len(), 8 + 4 + 3 + 4);
+        assert_eq!(&record[..8], &3u64.to_le_bytes());
+        assert_eq!(&record[12..15], b"abc");
+    }
+}
@@ -0,0 +1,191 @@
+use super::TensorBoardWriter;
+use crate::logger::{InMemoryMetricLogger, MetricLogger};
+use crate::metric::{
+    MetricAttributes, MetricDefinition, MetricId, NumericEntry,
+    store::{EpochSummary, MetricsUpdate, Split},
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// Metric logger writing the numeric metrics as TensorBoard scalars.
+///
+/// Each split is written to its own subdirectory, so that TensorBoard overlays the training and
+/// validation curves of each metric. Every update is logged at the iteration of its split, counted
+/// from the start of the training, and the epoch means are logged under `epoch/<metric>` at the
+/// end of each epoch.
+///
+/// The metrics are also kept in memory, so that the checkpointing and early stopping strategies
+/// can read them without a [file logger](crate::logger::FileMetricLogger). Histograms and images
+/// are logged with the [writers](TensorBoardMetricLogger::writer) of the splits.
+pub struct TensorBoardMetricLogger {
+    directory: PathBuf,
+    writers: HashMap<PathBuf, TensorBoardWriter>,
+    iterations: HashMap<PathBuf, usize>,
+    metric_definitions: HashMap<MetricId, MetricDefinition>,
+    numeric: InMemoryMetricLogger,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The log directory of the run, to be passed to `tensorboard --logdir`.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard metric logger.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            writers: HashMap::new(),
+            iterations: HashMap::new(),
+            metric_definitions: HashMap::new(),
+            numeric: InMemoryMetricLogger::new(),
+        }
+    }
+
+    /// The writer of a split, to log histograms and images next to its metrics.
+    pub fn writer(&mut self, split: &Split) -> TensorBoardWriter {
+        let directory = split_directory(split);
+        self.writers
+            .entry(directory.clone())
+            .or_insert_with(|| TensorBoardWriter::new(self.directory.join(directory)))
+            .clone()
+    }
+}
+
+fn split_directory(split: &Split) -> PathBuf {
+    match split {
+        Split::Test(Some(tag)) => Path::new(&split.to_string()).join(tag.trim().replace(' ', "-")),
+        other => PathBuf::from(other.to_string()),
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, update: MetricsUpdate, epoch: usize, split: &Split) {
+        let iteration = self.iterations.entry(split_directory(split)).or_default();
+        *iteration += 1;
+        let iteration = *iteration;
+
+        let writer = self.writer(split);
+        for numeric_update in update.entries_numeric.iter() {
+            let name = &self.metric_definitions[&numeric_update.entry.metric_id].name;
+            writer.add_scalar(name, numeric_update.numeric_entry.current(), iteration);
+        }
+
+        self.numeric.log(update, epoch, split);
+    }
+
+    fn read_numeric(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        split: &Split,
+    ) -> Result<Vec<NumericEntry>, String> {
+        self.numeric.read_numeric(name, epoch, split)
+    }
+
+    fn log_metric_definition(&mut self, definition: MetricDefinition) {
+        self.metric_definitions
+            .insert(definition.metric_id.clone(), definition.clone());
+        self.numeric.log_metric_definition(definition);
+    }
+
+    fn log_epoch_summary(&mut self, summary: EpochSummary) {
+        let writer = self.writer(&summary.split);
+        let names: Vec<String> = self
+            .metric_definitions
+            .values()
+            .filter(|definition| matches!(definition.attributes, MetricAttributes::Numeric(_)))
+            .map(|definition| definition.name.clone())
+            .collect();
+
+        for name in names {
+            let entries = self
+                .numeric
+                .read_numeric(&name, summary.epoch_number, &summary.split)
+                .unwrap_or_default();
+            if let Some(mean) = epoch_mean(&entries) {
+                writer.add_scalar(&format!("epoch/{name}"), mean, summary.epoch_number);
+            }
+        }
+
+        writer.flush();
+        self.numeric.log_epoch_summary(summary);
+    }
+}
+
+/// The mean of the entries of an epoch, weighted by the number of elements of each entry.
+fn epoch_mean(entries: &[NumericEntry]) -> Option<f64> {
+    let (sum, count) = entries
+        .iter()
+        .map(|entry| match entry {
+            NumericEntry::Value(value) => (*value, 1),
+            NumericEntry::Aggregated {
+                aggregated_value,
+                count,
+            } => (aggregated_value * *count as f64, *count),
+        })
+        .reduce(|(sum, count), (value, n)| (sum + value, count + n))?;
+
+    Some(sum / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{
+        MetricEntry, NumericAttributes, SerializedEntry, store::NumericMetricUpdate,
+    };
+    use std::sync::Arc;
+
+    const NAME: &str = "Loss";
+
+    fn update(value: f64) -> MetricsUpdate {
+        let entry = MetricEntry::new(
+            MetricId::new(Arc::new(NAME.into())),
+            SerializedEntry::new(value.to_string(), value.to_string()),
+        );
+        let numeric = NumericMetricUpdate::new(
+            entry,
+            NumericEntry::Value(value),
+            NumericEntry::Value(value),
+        );
+        MetricsUpdate::new(vec![], vec![numeric])
+    }
+
+    #[test]
+    fn should_write_events_and_keep_metrics_readable() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-tensorboard-{}", std::process::id()));
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.log_metric_definition(MetricDefinition {
+            metric_id: MetricId::new(Arc::new(NAME.into())),
+            name: NAME.into(),
+            description: None,
+            attributes: MetricAttributes::Numeric(NumericAttributes {
+                unit: None,
+                higher_is_better: false,
+            }),
+        });
+
+        logger.log(update(2.0), 1, &Split::Train);
+        logger.log(update(4.0), 1, &Split::Train);
+        logger.log_epoch_summary(EpochSummary::new(1, Split::Train));
+
+        let entries = logger.read_numeric(NAME, 1, &Split::Train).unwrap();
+        assert_eq!(epoch_mean(&entries), Some(3.0));
+
+        let files: Vec<_> = std::fs::read_dir(directory.join("train"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        assert!(std::fs::metadata(&files[0]).unwrap().len() > 0);
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+}
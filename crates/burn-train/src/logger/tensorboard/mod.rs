@@ -0,0 +1,6 @@
+mod encoding;
+mod metric;
+mod writer;
+
+pub use metric::*;
+pub use writer::*;
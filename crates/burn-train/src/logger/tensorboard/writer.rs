@@ -0,0 +1,231 @@
+use super::encoding::{ProtoMessage, encode_png, write_record};
+use burn_core::module::{AutodiffModule, Module, ModuleVisitor, Param};
+use burn_core::tensor::Tensor;
+use burn_optim::GradientsParams;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Number of buckets of the histograms.
+const NUM_BUCKETS: usize = 30;
+
+/// Writes TensorBoard summaries to a `tfevents` file, to be displayed with
+/// `tensorboard --logdir <directory>`.
+///
+/// The writer is a handle to the file: clones write to the same file, so a clone can be kept to
+/// log histograms and images from the training step while the
+/// [metric logger](super::TensorBoardMetricLogger) logs the metrics.
+#[derive(Clone)]
+pub struct TensorBoardWriter {
+    file: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl TensorBoardWriter {
+    /// Create a new event file in the given directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory, created if it doesn't exist.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard writer.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory).ok();
+
+        let name = format!(
+            "events.out.tfevents.{}.burn.{}",
+            wall_time() as u64,
+            std::process::id()
+        );
+        let path = directory.join(name);
+        let file = File::create(&path).unwrap_or_else(|err| {
+            panic!(
+                "Should be able to create the event file '{}': {}",
+                path.display(),
+                err
+            )
+        });
+
+        let writer = Self {
+            file: Arc::new(Mutex::new(BufWriter::new(file))),
+        };
+        writer.write_event(0, |event| event.string(3, "brain.Event:2"));
+        writer
+    }
+
+    /// Logs a scalar value.
+    pub fn add_scalar(&self, tag: &str, value: f64, step: usize) {
+        self.write_summary(step, |summary| {
+            summary.string(1, tag).float(2, value as f32)
+        });
+    }
+
+    /// Logs the histogram of the given values. Non-finite values are ignored.
+    pub fn add_histogram(&self, tag: &str, values: impl IntoIterator<Item = f64>, step: usize) {
+        let values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let (limits, counts) = if values.is_empty() {
+            (Vec::new(), Vec::new())
+        } else if min == max {
+            (vec![max], vec![values.len() as f64])
+        } else {
+            let width = (max - min) / NUM_BUCKETS as f64;
+            let mut counts = vec![0.0; NUM_BUCKETS];
+            for value in &values {
+                let bucket = ((value - min) / width) as usize;
+                counts[bucket.min(NUM_BUCKETS - 1)] += 1.0;
+            }
+            let mut limits: Vec<f64> = (1..NUM_BUCKETS).map(|i| min + width * i as f64).collect();
+            limits.push(max);
+            (limits, counts)
+        };
+
+        let histogram = ProtoMessage::default()
+            .double(1, if values.is_empty() { 0.0 } else { min })
+            .double(2, if values.is_empty() { 0.0 } else { max })
+            .double(3, values.len() as f64)
+            .double(4, values.iter().sum())
+            .double(5, values.iter().map(|v| v * v).sum())
+            .packed_doubles(6, &limits)
+            .packed_doubles(7, &counts);
+
+        self.write_summary(step, |summary| summary.string(1, tag).message(5, histogram));
+    }
+
+    /// Logs the histogram of the values of a tensor.
+    pub fn add_tensor_histogram<const D: usize>(&self, tag: &str, tensor: Tensor<D>, step: usize) {
+        let data = tensor.into_data();
+        self.add_histogram(tag, data.iter::<f64>(), step);
+    }
+
+    /// Logs an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The name of the image.
+    /// * `image` - The image as a `[channels, height, width]` tensor with values in `[0, 1]`, and
+    ///   1 (grayscale), 3 (RGB) or 4 (RGBA) channels.
+    /// * `step` - The step of the image.
+    pub fn add_image(&self, tag: &str, image: Tensor<3>, step: usize) {
+        let [channels, height, width] = image.dims();
+        let pixels: Vec<u8> = image
+            .clamp(0.0, 1.0)
+            .mul_scalar(255.0)
+            .round()
+            .permute([1, 2, 0])
+            .into_data()
+            .iter::<f32>()
+            .map(|value| value as u8)
+            .collect();
+
+        let image = ProtoMessage::default()
+            .int64(1, height as i64)
+            .int64(2, width as i64)
+            .int64(3, channels as i64)
+            .bytes(4, &encode_png(&pixels, height, width, channels));
+
+        self.write_summary(step, |summary| summary.string(1, tag).message(4, image));
+    }
+
+    /// Logs the histogram of each float parameter of a module, named after its path in the
+    /// module.
+    pub fn add_parameter_histograms<M: Module>(&self, module: &M, step: usize) {
+        let mut visitor = HistogramVisitor {
+            writer: self,
+            step,
+            path: Vec::new(),
+            grads: None,
+        };
+        module.visit(&mut visitor);
+    }
+
+    /// Logs the histogram of the gradients of each float parameter of a module, named after the
+    /// path of the parameter with a `.grad` suffix.
+    pub fn add_gradient_histograms<M: AutodiffModule>(
+        &self,
+        module: &M,
+        grads: &GradientsParams,
+        step: usize,
+    ) {
+        let mut visitor = HistogramVisitor {
+            writer: self,
+            step,
+            path: Vec::new(),
+            grads: Some(grads),
+        };
+        module.visit(&mut visitor);
+    }
+
+    /// Flushes the buffered events to the file.
+    pub fn flush(&self) {
+        self.file
+            .lock()
+            .unwrap()
+            .flush()
+            .expect("Can flush the event file.");
+    }
+
+    fn write_summary(&self, step: usize, value: impl FnOnce(ProtoMessage) -> ProtoMessage) {
+        let summary = ProtoMessage::default().message(1, value(ProtoMessage::default()));
+        self.write_event(step, |event| event.message(5, summary));
+    }
+
+    fn write_event(&self, step: usize, what: impl FnOnce(ProtoMessage) -> ProtoMessage) {
+        let event = ProtoMessage::default()
+            .double(1, wall_time())
+            .int64(2, step as i64);
+        let event = what(event).into_bytes();
+
+        let mut file = self.file.lock().unwrap();
+        write_record(&mut *file, &event).expect("Can log an event.");
+    }
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs_f64())
+        .unwrap_or_default()
+}
+
+/// Logs the histograms of the parameters, or of their gradients, named after their path.
+struct HistogramVisitor<'a> {
+    writer: &'a TensorBoardWriter,
+    step: usize,
+    path: Vec<String>,
+    grads: Option<&'a GradientsParams>,
+}
+
+impl ModuleVisitor for HistogramVisitor<'_> {
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let tag = self.path.join(".");
+
+        match self.grads {
+            None => self
+                .writer
+                .add_tensor_histogram(&tag, param.val(), self.step),
+            Some(grads) => {
+                if let Some(grad) = grads.get::<D>(param.id) {
+                    self.writer
+                        .add_tensor_histogram(&format!("{tag}.grad"), grad, self.step);
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,10 @@
-use core::f64;
-
 use super::MetricMetadata;
-use super::state::{FormatOptions, NumericMetricState};
+use super::ranking::{RankingCurve, RankingState, mean_defined, previous_points};
 use crate::metric::{
-    ClassReduction, ConfusionStatsInput, Metric, MetricName, Numeric, SerializedEntry,
+    ClassReduction, ConfusionStatsInput, Metric, MetricAttributes, MetricName, Numeric,
+    NumericAttributes, NumericEntry, SerializedEntry,
 };
-use burn_core::tensor::{Bool, Tensor};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// The Area Under the Receiver Operating Characteristic Curve (AUROC, also
 /// referred to as [ROC AUC](https://en.wikipedia.org/wiki/Receiver_operating_characteristic)).
@@ -14,11 +12,20 @@ use std::sync::Arc;
 /// Supports binary, multiclass and multi-label classification through a
 /// One-vs-Rest decomposition, aggregated with the configured
 /// [class reduction](ClassReduction).
+///
+/// The scores and labels are accumulated on the device across batches, so the
+/// [running value](Numeric::running_value) ranks all the predictions since the last
+/// [clear](Metric::clear) instead of averaging per-batch values. Each update only ranks its own
+/// batch, and the accumulated predictions are only sorted when the running value is read.
 #[derive(Clone)]
 pub struct AurocMetric {
     name: MetricName,
-    state: NumericMetricState,
+    state: RankingState,
     class_reduction: ClassReduction,
+    /// AUROC of the last batch, as a percentage.
+    current: f64,
+    /// AUROC of the accumulated predictions, as a percentage, computed when first read.
+    running: OnceLock<f64>,
 }
 
 impl Default for AurocMetric {
@@ -36,6 +43,8 @@ impl AurocMetric {
             state,
             class_reduction,
             name,
+            current: f64::NAN,
+            running: OnceLock::new(),
         }
     }
 
@@ -65,55 +74,22 @@ impl AurocMetric {
         Self::new(class_reduction)
     }
 
-    fn pairwise_auc(scores: Tensor<2>, targets: Tensor<2>) -> Tensor<1> {
-        let [n, c] = scores.dims();
-
-        let si = scores.clone().reshape([n, 1, c]);
-        let sj = scores.reshape([1, n, c]);
-
-        let yi = targets.clone().reshape([n, 1, c]);
-        let yj = targets.reshape([1, n, c]);
-
-        let valid: Tensor<3> = yi * (1.0 - yj);
-
-        let reduce = |t: Tensor<3>| t.sum_dim(0).sum_dim(1).squeeze_dims::<1>(&[0, 1]);
-
-        let num_pairs = reduce(valid.clone());
-        let correct_pairs = reduce(si.clone().greater(sj.clone()).float() * valid.clone());
-        let tied_pairs = reduce(si.equal(sj).float() * valid);
-
-        (correct_pairs + 0.5 * tied_pairs) / num_pairs
-    }
-
-    fn compute_auc(&self, predictions: &Tensor<2>, targets: &Tensor<2, Bool>) -> f64 {
-        let [n, c] = predictions.dims();
-
-        let (scores, targets) = match self.class_reduction {
-            ClassReduction::Macro => (predictions.clone(), targets.clone().float()),
-            ClassReduction::Micro => (
-                predictions.clone().reshape([n * c, 1]),
-                targets.clone().float().reshape([n * c, 1]),
-            ),
-        };
-
-        let auc = Self::pairwise_auc(scores, targets);
-
-        let keep = auc
-            .clone()
-            .is_nan()
-            .bool_not()
-            .argwhere()
-            .squeeze_dim::<1>(1);
-
-        if keep.dims()[0] == 0 {
-            log::warn!(
-                "AUROC is undefined (no class has both positive and negative samples in the \
-                 batch); reporting 0.5 (chance level)."
-            );
-            return 0.5;
-        }
-
-        auc.select(0, keep).mean().into_scalar()
+    /// The area under the ROC curve with the trapezoidal rule, ties counting as half a correctly
+    /// ranked pair as in the Mann-Whitney U statistic.
+    ///
+    /// Returns `None` when no column has both positive and negative samples.
+    fn compute_auc(curve: RankingCurve) -> Option<f64> {
+        let (positives, negatives) = curve.totals();
+
+        let tpr = curve.true_positives / positives;
+        let fpr = curve.false_positives / negatives;
+        // Columns without positives or negatives divide by zero, and are undefined (NaN).
+        let auc = ((fpr.clone() - previous_points(fpr)) * (tpr.clone() + previous_points(tpr)))
+            .sum_dim(0)
+            .squeeze_dim::<1>(0)
+            / 2.0;
+
+        mean_defined(auc)
     }
 }
 
@@ -125,33 +101,58 @@ impl Metric for AurocMetric {
         input: &ConfusionStatsInput,
         _metadata: &MetricMetadata,
     ) -> SerializedEntry {
-        let [sample_size, _] = input.predictions.dims();
-
-        let metric = self.compute_auc(&input.predictions, &input.targets);
-
-        self.state.update(
-            100.0 * metric,
-            sample_size,
-            FormatOptions::new(self.name()).unit("%").precision(2),
-        )
+        self.state.update(input);
+        self.running = OnceLock::new();
+        // A single batch often lacks one of the labels, so falling back to chance level isn't
+        // worth a warning here.
+        self.current = match RankingCurve::of_batch(input, self.class_reduction) {
+            Some(curve) => 100.0 * Self::compute_auc(curve).unwrap_or(0.5),
+            None => f64::NAN,
+        };
+        self.state.serialize(self.current)
     }
 
     fn clear(&mut self) {
-        self.state.reset()
+        self.state.reset();
+        self.current = f64::NAN;
+        self.running = OnceLock::new();
     }
 
     fn name(&self) -> MetricName {
         self.name.clone()
     }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("%".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
 }
 
 impl Numeric for AurocMetric {
-    fn value(&self) -> super::NumericEntry {
-        self.state.current_value()
+    fn value(&self) -> NumericEntry {
+        self.state.batch_entry(self.current)
     }
 
-    fn running_value(&self) -> super::NumericEntry {
-        self.state.running_value()
+    fn running_value(&self) -> NumericEntry {
+        let running = self.running.get_or_init(|| {
+            let Some(curve) = self.state.curve(self.class_reduction) else {
+                return f64::NAN;
+            };
+
+            100.0
+                * Self::compute_auc(curve).unwrap_or_else(|| {
+                    log::warn!(
+                        "AUROC is undefined (no class has both positive and negative samples); \
+                         reporting 0.5 (chance level)."
+                    );
+                    0.5
+                })
+        });
+
+        self.state.entry(*running)
     }
 }
 
@@ -159,7 +160,7 @@ impl Numeric for AurocMetric {
 mod tests {
     use super::*;
     use crate::metric::ClassReduction::{self, *};
-    use burn_core::tensor::{TensorData, Tolerance};
+    use burn_core::tensor::{Tensor, TensorData, Tolerance};
     use rstest::rstest;
 
     /// Inputs and expected AUROC computed with an independent reference
@@ -237,6 +238,7 @@ mod tests {
     #[case::binary_micro(Data::Binary, Micro, 0.75)]
     #[case::multiclass_macro(Data::Multiclass, Macro, 0.5666666666666667)]
     #[case::multiclass_micro(Data::Multiclass, Micro, 0.6458333333333333)]
+    #[case::multiclass_class(Data::Multiclass, Class(0), 0.5)]
     #[case::multilabel_macro(Data::Multilabel, Macro, 0.2907407407407407)]
     #[case::multilabel_micro(Data::Multilabel, Micro, 0.3611111111111111)]
     fn test_auroc(
@@ -325,6 +327,54 @@ mod tests {
         assert_eq!(metric.value().current(), 50.0);
     }
 
+    #[test]
+    fn test_auroc_accumulates_across_batches() {
+        let device = Default::default();
+        let mut metric = AurocMetric::binary();
+
+        // Each batch only has one label, but the epoch ranks all the pairs.
+        let negatives = ConfusionStatsInput::new(
+            Tensor::from_data([[0.34], [0.64], [0.12], [0.19]], &device),
+            Tensor::from_data([[0], [0], [0], [0]], &device),
+        );
+        let positives = ConfusionStatsInput::new(
+            Tensor::from_data([[0.53], [0.38]], &device),
+            Tensor::from_data([[1], [1]], &device),
+        );
+
+        let _entry = metric.update(&negatives, &MetricMetadata::fake());
+        assert_eq!(metric.running_value().current(), 50.0);
+        let _entry = metric.update(&positives, &MetricMetadata::fake());
+        // The last batch alone has no negatives.
+        assert_eq!(metric.value().current(), 50.0);
+        TensorData::from([metric.running_value().current()])
+            .assert_approx_eq::<f64>(&TensorData::from([75.0]), Tolerance::default());
+        assert!(matches!(
+            metric.running_value(),
+            NumericEntry::Aggregated { count: 6, .. }
+        ));
+
+        metric.clear();
+        assert!(metric.value().current().is_nan());
+        assert!(metric.running_value().current().is_nan());
+    }
+
+    #[test]
+    fn test_auroc_counts_ties_as_half() {
+        let device = Default::default();
+        let mut metric = AurocMetric::binary();
+
+        // Two of the six positive-negative pairs are tied: (4 + 0.5 * 2) / 6.
+        let input = ConfusionStatsInput::new(
+            Tensor::from_data([[0.5], [0.5], [0.2], [0.8], [0.5]], &device),
+            Tensor::from_data([[1], [0], [0], [1], [1]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        TensorData::from([metric.value().current()])
+            .assert_approx_eq::<f64>(&TensorData::from([100.0 * 5.0 / 6.0]), Tolerance::default());
+    }
+
     #[test]
     fn test_auroc_reduction_changes_name() {
         let macro_metric = AurocMetric::new(Macro);
@@ -0,0 +1,308 @@
+use super::MetricMetadata;
+use super::ranking::{RankingCurve, RankingState, mean_defined, previous_points};
+use crate::metric::{
+    ClassReduction, ConfusionStatsInput, Metric, MetricAttributes, MetricName, Numeric,
+    NumericAttributes, NumericEntry, SerializedEntry,
+};
+use std::sync::{Arc, OnceLock};
+
+/// The average precision (AP), a summary of the area under the precision-recall curve (PR AUC).
+///
+/// AP is the mean of the precisions at each threshold, weighted by the increase in recall from
+/// the previous threshold:
+///
+/// AP = Σ_k (R_k - R_{k-1}) * P_k
+///
+/// as in scikit-learn's `average_precision_score`, which avoids the optimism of interpolating the
+/// curve linearly. Supports binary, multiclass and multi-label classification through a
+/// One-vs-Rest decomposition, aggregated with the configured [class reduction](ClassReduction).
+///
+/// The scores and labels are accumulated on the device across batches, so the
+/// [running value](Numeric::running_value) ranks all the predictions since the last
+/// [clear](Metric::clear) instead of averaging per-batch values. Each update only ranks its own
+/// batch, and the accumulated predictions are only sorted when the running value is read.
+#[derive(Clone)]
+pub struct AveragePrecisionMetric {
+    name: MetricName,
+    state: RankingState,
+    class_reduction: ClassReduction,
+    /// Average precision of the last batch, as a percentage.
+    current: f64,
+    /// Average precision of the accumulated predictions, as a percentage, computed when first
+    /// read.
+    running: OnceLock<f64>,
+}
+
+impl Default for AveragePrecisionMetric {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl AveragePrecisionMetric {
+    fn new(class_reduction: ClassReduction) -> Self {
+        let state = Default::default();
+        let name = Arc::new(format!("Average Precision [{:?}]", class_reduction));
+
+        Self {
+            state,
+            class_reduction,
+            name,
+            current: f64::NAN,
+            running: OnceLock::new(),
+        }
+    }
+
+    /// Average precision metric for binary classification.
+    #[allow(dead_code)]
+    pub fn binary() -> Self {
+        Self::new(ClassReduction::default())
+    }
+
+    /// Average precision metric for multiclass classification.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_reduction` - [Class reduction](ClassReduction) type.
+    #[allow(dead_code)]
+    pub fn multiclass(class_reduction: ClassReduction) -> Self {
+        Self::new(class_reduction)
+    }
+
+    /// Average precision metric for multi-label classification.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_reduction` - [Class reduction](ClassReduction) type.
+    #[allow(dead_code)]
+    pub fn multilabel(class_reduction: ClassReduction) -> Self {
+        Self::new(class_reduction)
+    }
+
+    /// Returns `None` when no column has positive samples.
+    fn compute_average_precision(curve: RankingCurve) -> Option<f64> {
+        let (positives, _negatives) = curve.totals();
+
+        let predicted = curve.true_positives.clone() + curve.false_positives;
+        let precision = curve.true_positives.clone() / predicted;
+        // Columns without positives divide by zero, and are undefined (NaN).
+        let recall = curve.true_positives / positives;
+        let average_precision = ((recall.clone() - previous_points(recall)) * precision)
+            .sum_dim(0)
+            .squeeze_dim::<1>(0);
+
+        mean_defined(average_precision)
+    }
+}
+
+impl Metric for AveragePrecisionMetric {
+    type Input = ConfusionStatsInput;
+
+    fn update(
+        &mut self,
+        input: &ConfusionStatsInput,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        self.state.update(input);
+        self.running = OnceLock::new();
+        // A single batch often lacks positives, so its undefined value isn't worth a warning here.
+        self.current = RankingCurve::of_batch(input, self.class_reduction)
+            .and_then(Self::compute_average_precision)
+            .map_or(f64::NAN, |average_precision| 100.0 * average_precision);
+        self.state.serialize(self.current)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.current = f64::NAN;
+        self.running = OnceLock::new();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("%".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for AveragePrecisionMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.batch_entry(self.current)
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        let running = self.running.get_or_init(|| {
+            let Some(curve) = self.state.curve(self.class_reduction) else {
+                return f64::NAN;
+            };
+
+            Self::compute_average_precision(curve).map_or_else(
+                || {
+                    log::warn!("Average precision is undefined (no class has positive samples).");
+                    f64::NAN
+                },
+                |average_precision| 100.0 * average_precision,
+            )
+        });
+
+        self.state.entry(*running)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::ClassReduction::{self, *};
+    use burn_core::tensor::{Tensor, TensorData, Tolerance};
+    use rstest::rstest;
+
+    /// Expected values computed with an independent step-wise reference equivalent to
+    /// scikit-learn's `average_precision_score` (One-vs-Rest, macro/micro).
+    #[derive(Clone, Copy)]
+    enum Data {
+        Binary,
+        Multiclass,
+        Multilabel,
+    }
+
+    fn input(data: Data) -> ConfusionStatsInput {
+        let dev = Default::default();
+        match data {
+            Data::Binary => ConfusionStatsInput::new(
+                Tensor::from_data([[0.34], [0.64], [0.12], [0.19], [0.53], [0.38]], &dev),
+                Tensor::from_data([[0], [0], [0], [0], [1], [1]], &dev),
+            ),
+            Data::Multiclass => ConfusionStatsInput::new(
+                Tensor::from_data(
+                    [
+                        [0.79, 0.41, 0.16],
+                        [0.25, 0.93, 0.78],
+                        [0.61, 0.09, 0.21],
+                        [0.9, 0.31, 0.33],
+                        [0.16, 0.82, 0.57],
+                        [0.57, 0.18, 0.63],
+                    ],
+                    &dev,
+                ),
+                Tensor::from_data(
+                    [
+                        [1, 0, 0],
+                        [1, 0, 0],
+                        [1, 0, 0],
+                        [0, 0, 1],
+                        [0, 1, 0],
+                        [1, 0, 0],
+                    ],
+                    &dev,
+                ),
+            ),
+            Data::Multilabel => ConfusionStatsInput::new(
+                Tensor::from_data(
+                    [
+                        [0.11, 0.57, 0.9],
+                        [0.13, 0.66, 0.37],
+                        [0.71, 0.85, 0.6],
+                        [0.29, 0.69, 0.49],
+                        [0.68, 0.45, 0.25],
+                        [0.33, 0.36, 0.31],
+                    ],
+                    &dev,
+                ),
+                Tensor::from_data(
+                    [
+                        [1, 1, 1],
+                        [0, 0, 1],
+                        [0, 1, 0],
+                        [1, 1, 0],
+                        [0, 1, 1],
+                        [1, 1, 1],
+                    ],
+                    &dev,
+                ),
+            ),
+        }
+    }
+
+    #[rstest]
+    #[case::binary_macro(Data::Binary, Macro, 0.5833333333333333)]
+    #[case::binary_micro(Data::Binary, Micro, 0.5833333333333333)]
+    #[case::multiclass_macro(Data::Multiclass, Macro, 0.4763888888888889)]
+    #[case::multiclass_micro(Data::Multiclass, Micro, 0.4370721870721871)]
+    #[case::multiclass_class(Data::Multiclass, Class(0), 0.6791666666666667)]
+    #[case::multilabel_macro(Data::Multilabel, Macro, 0.670925925925926)]
+    #[case::multilabel_micro(Data::Multilabel, Micro, 0.6798219141969142)]
+    #[case::multilabel_class(Data::Multilabel, Class(1), 0.8766666666666667)]
+    fn test_average_precision(
+        #[case] data: Data,
+        #[case] class_reduction: ClassReduction,
+        #[case] expected: f64,
+    ) {
+        let mut metric = AveragePrecisionMetric::new(class_reduction);
+
+        let _entry = metric.update(&input(data), &MetricMetadata::fake());
+
+        TensorData::from([metric.value().current()])
+            .assert_approx_eq::<f64>(&TensorData::from([expected * 100.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_average_precision_accumulates_across_batches() {
+        let device = Default::default();
+        let mut metric = AveragePrecisionMetric::binary();
+
+        let first = ConfusionStatsInput::new(
+            Tensor::from_data([[0.34], [0.64], [0.12]], &device),
+            Tensor::from_data([[0], [0], [0]], &device),
+        );
+        let second = ConfusionStatsInput::new(
+            Tensor::from_data([[0.19], [0.53], [0.38]], &device),
+            Tensor::from_data([[0], [1], [1]], &device),
+        );
+
+        let _entry = metric.update(&first, &MetricMetadata::fake());
+        assert!(metric.running_value().current().is_nan());
+        let _entry = metric.update(&second, &MetricMetadata::fake());
+        TensorData::from([metric.running_value().current()]).assert_approx_eq::<f64>(
+            &TensorData::from([0.5833333333333333 * 100.0]),
+            Tolerance::default(),
+        );
+        // The last batch alone ranks both positives first.
+        assert_eq!(metric.value().current(), 100.0);
+    }
+
+    #[test]
+    fn test_average_precision_groups_tied_scores() {
+        let device = Default::default();
+        let mut metric = AveragePrecisionMetric::binary();
+
+        // The three samples scored 0.5 form a single threshold: 1/3 * 1 + 2/3 * 3/4.
+        let input = ConfusionStatsInput::new(
+            Tensor::from_data([[0.5], [0.5], [0.2], [0.8], [0.5]], &device),
+            Tensor::from_data([[1], [0], [0], [1], [1]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        TensorData::from([metric.value().current()])
+            .assert_approx_eq::<f64>(&TensorData::from([100.0 * 5.0 / 6.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_average_precision_perfect_ranking() {
+        let device = Default::default();
+        let mut metric = AveragePrecisionMetric::multiclass(Macro);
+
+        let input = ConfusionStatsInput::new(
+            Tensor::from_data([[0.1, 0.9], [0.8, 0.2], [0.7, 0.3], [0.4, 0.6]], &device),
+            Tensor::from_data([[0, 1], [1, 0], [1, 0], [0, 1]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(metric.value().current(), 100.0);
+    }
+}
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use burn_core::data::dataloader::Progress;
 use burn_optim::LearningRate;
 
+use super::{StepTiming, TrainingDiagnostics};
+
 /// Metric metadata that can be used when computing metrics.
 pub struct MetricMetadata {
     /// The current progress.
@@ -16,6 +18,14 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The time spent in each phase of the training step, when the
+    /// [step timing report](crate::SupervisedTraining::with_step_timing) is enabled.
+    pub step_timing: Option<StepTiming>,
+
+    /// The gradient and weight statistics of the training step, when the
+    /// [training diagnostics](crate::SupervisedTraining::with_diagnostics) are enabled.
+    pub diagnostics: Option<Arc<TrainingDiagnostics>>,
 }
 
 impl MetricMetadata {
@@ -33,6 +43,8 @@ impl MetricMetadata {
             },
             iteration: Some(0),
             lr: None,
+            step_timing: None,
+            diagnostics: None,
         }
     }
 }
@@ -1,5 +1,4 @@
-use super::state::{FormatOptions, NumericMetricState};
-use super::{MetricMetadata, SerializedEntry};
+use super::{MetricMetadata, SerializedEntry, format_float};
 use crate::metric::{
     Metric, MetricAttributes, MetricName, Numeric, NumericAttributes, NumericEntry,
 };
@@ -48,16 +47,12 @@ pub enum BleuSmoothing {
 /// convention used by [`CharErrorRate`](super::CharErrorRate) and
 /// [`WordErrorRate`](super::WordErrorRate).
 ///
-/// # Batch-level scoring
+/// # Corpus-level scoring
 ///
-/// Within each batch the metric accumulates n-gram counts across all
-/// sentences and computes a single corpus-style BLEU score, following the
-/// same pattern CER/WER use for edit-distance aggregation.
-///
-/// Epoch-level (running) aggregation averages these batch scores, which is
-/// slightly inaccurate compared to true corpus BLEU. Correct corpus-level
-/// accumulation would require a custom metric state; a TODO is left for
-/// future work.
+/// The metric accumulates the n-gram counts and the lengths of all the
+/// sentences seen since the last [clear](Metric::clear), so the epoch
+/// (running) value is the true corpus BLEU, not an average of sentence or
+/// batch scores. The batch value is the corpus BLEU of the batch alone.
 ///
 /// # References
 ///
@@ -69,7 +64,13 @@ pub enum BleuSmoothing {
 #[derive(Clone)]
 pub struct BleuScore {
     name: MetricName,
-    state: NumericMetricState,
+    /// Statistics of all the sentences since the last clear.
+    corpus: BleuStatistics,
+    /// The number of sentences since the last clear.
+    num_sentences: usize,
+    /// BLEU of the last batch.
+    current: f64,
+    current_count: usize,
     max_n: usize,
     pad_token: Option<usize>,
     smoothing: BleuSmoothing,
@@ -102,7 +103,10 @@ impl BleuScore {
         assert!(max_n >= 1, "max_n must be at least 1");
         Self {
             name: Arc::new(format!("BLEU-{max_n}")),
-            state: NumericMetricState::default(),
+            corpus: BleuStatistics::new(max_n),
+            num_sentences: 0,
+            current: f64::NAN,
+            current_count: 0,
             max_n,
             pad_token: None,
             smoothing: BleuSmoothing::default(),
@@ -131,6 +135,67 @@ impl BleuScore {
     }
 }
 
+/// N-gram statistics accumulated over sentences, to compute a corpus-level BLEU score.
+#[derive(Clone, Debug, PartialEq)]
+struct BleuStatistics {
+    /// Clipped n-gram matches, for the orders 1 to `max_n`.
+    clipped_counts: Vec<usize>,
+    /// Candidate n-grams, for the orders 1 to `max_n`.
+    total_counts: Vec<usize>,
+    candidate_len: usize,
+    reference_len: usize,
+}
+
+impl BleuStatistics {
+    fn new(max_n: usize) -> Self {
+        Self {
+            clipped_counts: vec![0; max_n],
+            total_counts: vec![0; max_n],
+            candidate_len: 0,
+            reference_len: 0,
+        }
+    }
+
+    /// Adds the statistics of a candidate sentence and its reference.
+    fn add_sentence(&mut self, candidate: &[i32], reference: &[i32]) {
+        self.candidate_len += candidate.len();
+        self.reference_len += reference.len();
+
+        for n in 1..=self.clipped_counts.len() {
+            let cand_ngrams = ngram_counts(candidate, n);
+            let ref_ngrams = ngram_counts(reference, n);
+
+            for (ngram, &count) in &cand_ngrams {
+                let ref_count = ref_ngrams.get(ngram).copied().unwrap_or(0);
+                self.clipped_counts[n - 1] += count.min(ref_count);
+                self.total_counts[n - 1] += count;
+            }
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (count, other) in self.clipped_counts.iter_mut().zip(&other.clipped_counts) {
+            *count += other;
+        }
+        for (count, other) in self.total_counts.iter_mut().zip(&other.total_counts) {
+            *count += other;
+        }
+        self.candidate_len += other.candidate_len;
+        self.reference_len += other.reference_len;
+    }
+
+    fn score(&self, smoothing: &BleuSmoothing) -> f64 {
+        corpus_bleu(
+            &self.clipped_counts,
+            &self.total_counts,
+            self.candidate_len,
+            self.reference_len,
+            self.clipped_counts.len(),
+            smoothing,
+        )
+    }
+}
+
 /// Extracts n-grams of order `n` from a slice and returns their counts.
 fn ngram_counts(tokens: &[i32], n: usize) -> HashMap<Vec<i32>, usize> {
     let mut counts = HashMap::new();
@@ -226,10 +291,7 @@ impl Metric for BleuScore {
         let pad_token = self.pad_token.map(|p| p as i32);
 
         // Accumulate n-gram counts across the batch (corpus-style).
-        let mut clipped_counts = vec![0usize; self.max_n];
-        let mut total_counts = vec![0usize; self.max_n];
-        let mut total_candidate_len = 0usize;
-        let mut total_reference_len = 0usize;
+        let mut batch = BleuStatistics::new(self.max_n);
 
         for i in 0..batch_size {
             let start = i * seq_len;
@@ -260,43 +322,28 @@ impl Metric for BleuScore {
                 None => target_seq,
             };
 
-            total_candidate_len += output_seq.len();
-            total_reference_len += target_seq.len();
-
-            for n in 1..=self.max_n {
-                let cand_ngrams = ngram_counts(output_seq, n);
-                let ref_ngrams = ngram_counts(target_seq, n);
-
-                for (ngram, &count) in &cand_ngrams {
-                    let ref_count = ref_ngrams.get(ngram).copied().unwrap_or(0);
-                    clipped_counts[n - 1] += count.min(ref_count);
-                    total_counts[n - 1] += count;
-                }
-            }
+            batch.add_sentence(output_seq, target_seq);
         }
 
-        let value = corpus_bleu(
-            &clipped_counts,
-            &total_counts,
-            total_candidate_len,
-            total_reference_len,
-            self.max_n,
-            &self.smoothing,
+        self.corpus.merge(&batch);
+        self.num_sentences += batch_size;
+        self.current = batch.score(&self.smoothing);
+        self.current_count = batch_size;
+
+        let formatted = format!(
+            "epoch {} % - batch {} %",
+            format_float(self.running_value().current(), 2),
+            format_float(self.current, 2)
         );
 
-        // TODO: Epoch-level aggregation averages batch BLEU scores, which is
-        // slightly inaccurate compared to true corpus BLEU. Correct
-        // accumulation would require a custom metric state that tracks raw
-        // n-gram counts across batches.
-        self.state.update(
-            value,
-            batch_size,
-            FormatOptions::new(self.name()).unit("%").precision(2),
-        )
+        SerializedEntry::new(formatted, self.value().serialize())
     }
 
     fn clear(&mut self) {
-        self.state.reset();
+        self.corpus = BleuStatistics::new(self.max_n);
+        self.num_sentences = 0;
+        self.current = f64::NAN;
+        self.current_count = 0;
     }
 
     fn name(&self) -> MetricName {
@@ -314,11 +361,22 @@ impl Metric for BleuScore {
 
 impl Numeric for BleuScore {
     fn value(&self) -> NumericEntry {
-        self.state.current_value()
+        NumericEntry::Aggregated {
+            aggregated_value: self.current,
+            count: self.current_count,
+        }
     }
 
     fn running_value(&self) -> NumericEntry {
-        self.state.running_value()
+        let value = match self.num_sentences {
+            0 => f64::NAN,
+            _ => self.corpus.score(&self.smoothing),
+        };
+
+        NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_sentences,
+        }
     }
 }
 
@@ -423,6 +481,27 @@ mod tests {
         assert!((metric.value().current() - 50.0).abs() < 1e-6);
     }
 
+    /// The running value is the corpus BLEU of all the batches, not the mean of the batch scores.
+    #[test]
+    fn test_bleu_running_value_is_corpus_level() {
+        let device = Default::default();
+        let mut metric = BleuScore::with_max_n(1);
+
+        // Batch 1: clipped = 3, total = 3, BLEU-1 = 100.
+        let preds = Tensor::from_data([[1, 2, 3]], &device);
+        let tgts = Tensor::from_data([[1, 2, 3]], &device);
+        metric.update(&BleuInput::new(preds, tgts), &MetricMetadata::fake());
+
+        // Batch 2: clipped = 1, total = 5, BLEU-1 = 20.
+        let preds = Tensor::from_data([[1, 6, 7, 8, 9]], &device);
+        let tgts = Tensor::from_data([[1, 2, 3, 4, 5]], &device);
+        metric.update(&BleuInput::new(preds, tgts), &MetricMetadata::fake());
+
+        // Corpus: clipped = 4, total = 8, the mean of the batch scores would be 60.
+        assert!((metric.value().current() - 20.0).abs() < 1e-6);
+        assert!((metric.running_value().current() - 50.0).abs() < 1e-6);
+    }
+
     /// `clear()` must reset the running statistics.
     #[test]
     fn test_clear_resets_state() {
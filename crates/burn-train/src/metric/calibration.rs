@@ -0,0 +1,335 @@
+use super::{MetricMetadata, NumericEntry, SerializedEntry, format_float};
+use crate::metric::{Metric, MetricAttributes, MetricName, Numeric, NumericAttributes};
+use burn_core::tensor::{IndexingUpdateOp, Int, Tensor};
+
+/// The number of confidence bins used by default by the calibration metrics.
+const DEFAULT_NUM_BINS: usize = 15;
+
+/// The [calibration metrics](ExpectedCalibrationErrorMetric) input type.
+#[derive(new, Debug, Clone)]
+pub struct CalibrationInput {
+    /// Predicted probabilities of shape [batch_size, num_classes].
+    probabilities: Tensor<2>,
+    /// Target classes of shape [batch_size].
+    targets: Tensor<1, Int>,
+}
+
+/// Per-bin sums of the top-1 predictions, accumulated across batches.
+///
+/// The confidence of a prediction is its highest probability, and bin `b` holds the
+/// predictions with a confidence in `[b / num_bins, (b + 1) / num_bins)`.
+#[derive(Clone)]
+struct CalibrationState {
+    num_bins: usize,
+    /// Number of predictions in each bin.
+    counts: Vec<f64>,
+    /// Sum of the confidences in each bin.
+    confidences: Vec<f64>,
+    /// Number of correct predictions in each bin.
+    correct: Vec<f64>,
+    /// Total number of predictions.
+    num_items: usize,
+}
+
+impl CalibrationState {
+    fn new(num_bins: usize) -> Self {
+        assert!(num_bins > 0, "Calibration metrics require at least 1 bin.");
+
+        Self {
+            num_bins,
+            counts: vec![0.0; num_bins],
+            confidences: vec![0.0; num_bins],
+            correct: vec![0.0; num_bins],
+            num_items: 0,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.num_bins);
+    }
+
+    fn update(&mut self, input: &CalibrationInput) {
+        let [batch_size, _num_classes] = input.probabilities.dims();
+        let device = input.probabilities.device();
+
+        let (confidences, predictions) = input.probabilities.clone().max_dim_with_indices(1);
+        let confidences = confidences.reshape([batch_size]);
+        let correct = predictions
+            .reshape([batch_size])
+            .equal(input.targets.clone())
+            .float();
+        let bins = confidences
+            .clone()
+            .mul_scalar(self.num_bins as f64)
+            .int()
+            .clamp(0, self.num_bins as i64 - 1);
+
+        // The bin sums are computed on the device, only `3 * num_bins` values are read back.
+        let values = Tensor::stack::<2>(vec![confidences.ones_like(), confidences, correct], 0);
+        let sums = Tensor::<2>::zeros([3, self.num_bins], &device)
+            .scatter(
+                1,
+                bins.unsqueeze_dim::<2>(0).expand([3, batch_size]),
+                values,
+                IndexingUpdateOp::Add,
+            )
+            .into_data()
+            .iter::<f64>()
+            .collect::<Vec<_>>();
+
+        let (counts, rest) = sums.split_at(self.num_bins);
+        let (confidences, correct) = rest.split_at(self.num_bins);
+        for bin in 0..self.num_bins {
+            self.counts[bin] += counts[bin];
+            self.confidences[bin] += confidences[bin];
+            self.correct[bin] += correct[bin];
+        }
+        self.num_items += batch_size;
+    }
+
+    /// The weight and the gap between the accuracy and the mean confidence of each non-empty bin.
+    fn gaps(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        (0..self.num_bins)
+            .filter(|&bin| self.counts[bin] > 0.0)
+            .map(|bin| {
+                let count = self.counts[bin];
+                let gap = (self.correct[bin] - self.confidences[bin]).abs() / count;
+                (count / self.num_items as f64, gap)
+            })
+    }
+
+    fn expected_error(&self) -> f64 {
+        if self.num_items == 0 {
+            return f64::NAN;
+        }
+
+        self.gaps().map(|(weight, gap)| weight * gap).sum()
+    }
+
+    fn max_error(&self) -> f64 {
+        if self.num_items == 0 {
+            return f64::NAN;
+        }
+
+        self.gaps().map(|(_, gap)| gap).fold(0.0, f64::max)
+    }
+
+    fn serialize(&self, value: f64) -> SerializedEntry {
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn entry(&self, value: f64) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_items,
+        }
+    }
+}
+
+/// The expected calibration error (ECE) metric.
+///
+/// The predictions are grouped into equal-width bins by confidence, and the ECE is the mean
+/// absolute gap between the accuracy and the mean confidence of each bin, weighted by the number
+/// of predictions in the bin:
+///
+/// ECE = Σ_b (|B_b| / N) * |acc(B_b) - conf(B_b)|
+///
+/// The bins are accumulated across batches, so the value covers all the predictions since the
+/// last [clear](Metric::clear).
+#[derive(Clone)]
+pub struct ExpectedCalibrationErrorMetric {
+    name: MetricName,
+    state: CalibrationState,
+}
+
+impl Default for ExpectedCalibrationErrorMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExpectedCalibrationErrorMetric {
+    /// Creates the metric with 15 confidence bins.
+    pub fn new() -> Self {
+        Self {
+            name: MetricName::new("ECE".to_string()),
+            state: CalibrationState::new(DEFAULT_NUM_BINS),
+        }
+    }
+
+    /// Sets the number of confidence bins.
+    pub fn with_num_bins(mut self, num_bins: usize) -> Self {
+        self.state = CalibrationState::new(num_bins);
+        self
+    }
+}
+
+impl Metric for ExpectedCalibrationErrorMetric {
+    type Input = CalibrationInput;
+
+    fn update(&mut self, input: &CalibrationInput, _metadata: &MetricMetadata) -> SerializedEntry {
+        self.state.update(input);
+        self.state.serialize(self.state.expected_error())
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: false,
+        }
+        .into()
+    }
+}
+
+impl Numeric for ExpectedCalibrationErrorMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.entry(self.state.expected_error())
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+/// The maximum calibration error (MCE) metric.
+///
+/// The predictions are grouped into equal-width bins by confidence, and the MCE is the largest
+/// absolute gap between the accuracy and the mean confidence of a non-empty bin:
+///
+/// MCE = max_b |acc(B_b) - conf(B_b)|
+///
+/// The bins are accumulated across batches, so the value covers all the predictions since the
+/// last [clear](Metric::clear).
+#[derive(Clone)]
+pub struct MaxCalibrationErrorMetric {
+    name: MetricName,
+    state: CalibrationState,
+}
+
+impl Default for MaxCalibrationErrorMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaxCalibrationErrorMetric {
+    /// Creates the metric with 15 confidence bins.
+    pub fn new() -> Self {
+        Self {
+            name: MetricName::new("MCE".to_string()),
+            state: CalibrationState::new(DEFAULT_NUM_BINS),
+        }
+    }
+
+    /// Sets the number of confidence bins.
+    pub fn with_num_bins(mut self, num_bins: usize) -> Self {
+        self.state = CalibrationState::new(num_bins);
+        self
+    }
+}
+
+impl Metric for MaxCalibrationErrorMetric {
+    type Input = CalibrationInput;
+
+    fn update(&mut self, input: &CalibrationInput, _metadata: &MetricMetadata) -> SerializedEntry {
+        self.state.update(input);
+        self.state.serialize(self.state.max_error())
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: false,
+        }
+        .into()
+    }
+}
+
+impl Numeric for MaxCalibrationErrorMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.entry(self.state.max_error())
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(probabilities: [[f32; 2]; 4], targets: [i64; 4]) -> CalibrationInput {
+        let device = Default::default();
+        CalibrationInput::new(
+            Tensor::from_data(probabilities, &device),
+            Tensor::from_data(targets, &device),
+        )
+    }
+
+    #[test]
+    fn test_calibration_error_perfect() {
+        let mut ece = ExpectedCalibrationErrorMetric::new().with_num_bins(10);
+        let mut mce = MaxCalibrationErrorMetric::new().with_num_bins(10);
+        // Confidence 1.0 and always correct.
+        let input = input([[1.0, 0.0], [0.0, 1.0], [1.0, 0.0], [0.0, 1.0]], [0, 1, 0, 1]);
+
+        let _entry = ece.update(&input, &MetricMetadata::fake());
+        let _entry = mce.update(&input, &MetricMetadata::fake());
+
+        assert!(ece.value().current().abs() < 1e-6);
+        assert!(mce.value().current().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_calibration_error_overconfident() {
+        let mut ece = ExpectedCalibrationErrorMetric::new().with_num_bins(10);
+        let mut mce = MaxCalibrationErrorMetric::new().with_num_bins(10);
+        // One bin with confidence 0.9 and accuracy 0.5, one with confidence 0.6 and accuracy 1.0.
+        let input = input([[0.9, 0.1], [0.9, 0.1], [0.4, 0.6], [0.4, 0.6]], [0, 1, 1, 1]);
+
+        let _entry = ece.update(&input, &MetricMetadata::fake());
+        let _entry = mce.update(&input, &MetricMetadata::fake());
+
+        assert!((ece.value().current() - (0.5 * 0.4 + 0.5 * 0.4)).abs() < 1e-6);
+        assert!((mce.value().current() - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_calibration_error_accumulates_batches() {
+        let mut metric = ExpectedCalibrationErrorMetric::new().with_num_bins(10);
+        let first = input([[0.9, 0.1], [0.9, 0.1], [0.9, 0.1], [0.9, 0.1]], [0, 0, 0, 0]);
+        let second = input([[0.9, 0.1], [0.9, 0.1], [0.9, 0.1], [0.9, 0.1]], [1, 1, 1, 1]);
+
+        let _entry = metric.update(&first, &MetricMetadata::fake());
+        let _entry = metric.update(&second, &MetricMetadata::fake());
+
+        // One bin with confidence 0.9 and accuracy 0.5.
+        assert!((metric.value().current() - 0.4).abs() < 1e-6);
+
+        metric.clear();
+        assert!(metric.value().current().is_nan());
+    }
+}
@@ -30,4 +30,6 @@ pub enum ClassReduction {
     /// Computes the statistics independently for each class before averaging
     #[default]
     Macro,
+    /// Computes the statistics of a single class, to follow each class with its own metric
+    Class(usize),
 }
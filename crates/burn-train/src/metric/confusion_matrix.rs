@@ -0,0 +1,291 @@
+use core::fmt::Display;
+
+use super::{MetricMetadata, SerializedEntry};
+use crate::metric::{Metric, MetricName};
+use burn_core::tensor::{IndexingUpdateOp, Int, Tensor};
+
+/// The number of most frequent confusions shown in the summary of the matrix.
+const NUM_SUMMARY_CONFUSIONS: usize = 3;
+
+/// The [confusion matrix metric](ConfusionMatrixMetric) input type.
+#[derive(new, Debug, Clone)]
+pub struct ConfusionMatrixInput {
+    /// Class logits or probabilities of shape [batch_size, num_classes].
+    outputs: Tensor<2>,
+    /// Target classes of shape [batch_size].
+    targets: Tensor<1, Int>,
+}
+
+/// The number of samples of each target class (rows) predicted as each class (columns).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfusionMatrix {
+    num_classes: usize,
+    counts: Vec<u64>,
+}
+
+impl ConfusionMatrix {
+    /// Creates an empty matrix.
+    pub fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            counts: vec![0; num_classes * num_classes],
+        }
+    }
+
+    /// The number of classes.
+    pub fn num_classes(&self) -> usize {
+        self.num_classes
+    }
+
+    /// The number of samples of the `target` class predicted as the `predicted` class.
+    pub fn count(&self, target: usize, predicted: usize) -> u64 {
+        self.counts[target * self.num_classes + predicted]
+    }
+
+    /// The counts of the samples of the `target` class, by predicted class.
+    pub fn row(&self, target: usize) -> &[u64] {
+        &self.counts[target * self.num_classes..(target + 1) * self.num_classes]
+    }
+
+    /// The total number of samples.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// The fraction of the samples predicted as their target class.
+    pub fn accuracy(&self) -> f64 {
+        let correct: u64 = (0..self.num_classes).map(|c| self.count(c, c)).sum();
+        correct as f64 / self.total() as f64
+    }
+
+    /// The `k` most frequent confusions as `(target, predicted, count)`, from the most frequent.
+    pub fn top_confusions(&self, k: usize) -> Vec<(usize, usize, u64)> {
+        let mut confusions = (0..self.num_classes)
+            .flat_map(|target| (0..self.num_classes).map(move |predicted| (target, predicted)))
+            .filter(|(target, predicted)| target != predicted)
+            .map(|(target, predicted)| (target, predicted, self.count(target, predicted)))
+            .filter(|(_, _, count)| *count > 0)
+            .collect::<Vec<_>>();
+        // The sort is stable, so ties keep the row-major order.
+        confusions.sort_by(|a, b| b.2.cmp(&a.2));
+        confusions.truncate(k);
+
+        confusions
+    }
+
+    fn add(&mut self, counts: impl Iterator<Item = f64>) {
+        for (count, added) in self.counts.iter_mut().zip(counts) {
+            *count += added as u64;
+        }
+    }
+
+    /// A one line summary: the accuracy and the most frequent confusions.
+    fn summary(&self) -> String {
+        let confusions = self
+            .top_confusions(NUM_SUMMARY_CONFUSIONS)
+            .into_iter()
+            .map(|(target, predicted, count)| format!("{target}→{predicted} ({count})"))
+            .collect::<Vec<_>>();
+
+        match confusions.is_empty() {
+            true => format!("accuracy {:.2} %", 100.0 * self.accuracy()),
+            false => format!(
+                "accuracy {:.2} % - top confusions {}",
+                100.0 * self.accuracy(),
+                confusions.join(", ")
+            ),
+        }
+    }
+
+    /// The rows of the matrix, as nested arrays: `[[a, b], [c, d]]`.
+    fn serialize(&self) -> String {
+        let rows = (0..self.num_classes)
+            .map(|target| {
+                let row = self.row(target).iter().map(u64::to_string);
+                format!("[{}]", row.collect::<Vec<_>>().join(", "))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", rows.join(", "))
+    }
+}
+
+impl Display for ConfusionMatrix {
+    /// Renders the matrix as a table, one row per target class.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let width = self
+            .counts
+            .iter()
+            .map(|count| count.to_string().len())
+            .chain([self.num_classes.to_string().len(), "target\\pred".len()])
+            .max()
+            .unwrap_or(1);
+
+        write!(f, "{:>width$}", "target\\pred")?;
+        for predicted in 0..self.num_classes {
+            write!(f, " {predicted:>width$}")?;
+        }
+        for target in 0..self.num_classes {
+            write!(f, "\n{target:>width$}")?;
+            for count in self.row(target) {
+                write!(f, " {count:>width$}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The confusion matrix of a multiclass classifier, to diagnose which classes it confuses.
+///
+/// The predicted class of each sample is its highest output, and the counts are accumulated
+/// across batches, so the matrix covers all the predictions since the last
+/// [clear](Metric::clear). The entries show a summary of the matrix and save the whole matrix,
+/// and the matrix is logged as a table when it's cleared at the end of each epoch.
+#[derive(Clone)]
+pub struct ConfusionMatrixMetric {
+    name: MetricName,
+    matrix: Option<ConfusionMatrix>,
+}
+
+impl Default for ConfusionMatrixMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfusionMatrixMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: MetricName::new("Confusion Matrix".to_string()),
+            matrix: None,
+        }
+    }
+
+    /// The matrix accumulated since the last [clear](Metric::clear), if any.
+    pub fn matrix(&self) -> Option<&ConfusionMatrix> {
+        self.matrix.as_ref()
+    }
+}
+
+impl Metric for ConfusionMatrixMetric {
+    type Input = ConfusionMatrixInput;
+
+    fn update(
+        &mut self,
+        input: &ConfusionMatrixInput,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        let [batch_size, num_classes] = input.outputs.dims();
+        let device = input.outputs.device();
+
+        let matrix = self
+            .matrix
+            .get_or_insert_with(|| ConfusionMatrix::new(num_classes));
+        assert_eq!(
+            matrix.num_classes(),
+            num_classes,
+            "The number of classes changed during the epoch"
+        );
+
+        let predictions = input.outputs.clone().argmax(1).reshape([batch_size]);
+        let cells = input.targets.clone().mul_scalar(num_classes as i64) + predictions;
+
+        // The counts are computed on the device, only `num_classes²` values are read back.
+        let counts = Tensor::<1>::zeros([num_classes * num_classes], &device)
+            .scatter(
+                0,
+                cells,
+                Tensor::ones([batch_size], &device),
+                IndexingUpdateOp::Add,
+            )
+            .into_data();
+        matrix.add(counts.iter::<f64>());
+
+        SerializedEntry::new(matrix.summary(), matrix.serialize())
+    }
+
+    fn clear(&mut self) {
+        if let Some(matrix) = self.matrix.take()
+            && matrix.total() > 0
+        {
+            log::info!("{}:\n{matrix}", self.name);
+        }
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(outputs: [[f32; 3]; 4], targets: [i64; 4]) -> ConfusionMatrixInput {
+        let device = Default::default();
+        ConfusionMatrixInput::new(
+            Tensor::from_data(outputs, &device),
+            Tensor::from_data(targets, &device),
+        )
+    }
+
+    #[test]
+    fn test_confusion_matrix_accumulates_across_batches() {
+        let mut metric = ConfusionMatrixMetric::new();
+
+        let _entry = metric.update(
+            &input(
+                [
+                    [0.9, 0.05, 0.05],
+                    [0.1, 0.8, 0.1],
+                    [0.2, 0.7, 0.1],
+                    [0.1, 0.2, 0.7],
+                ],
+                [0, 1, 2, 2],
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &input(
+                [
+                    [0.3, 0.6, 0.1],
+                    [0.1, 0.1, 0.8],
+                    [0.5, 0.3, 0.2],
+                    [0.2, 0.6, 0.2],
+                ],
+                [0, 2, 0, 2],
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        let matrix = metric.matrix().unwrap();
+        assert_eq!(matrix.row(0), &[2, 1, 0]);
+        assert_eq!(matrix.row(1), &[0, 1, 0]);
+        assert_eq!(matrix.row(2), &[0, 2, 2]);
+        assert_eq!(matrix.total(), 8);
+        assert_eq!(matrix.accuracy(), 5.0 / 8.0);
+        assert_eq!(matrix.top_confusions(5), vec![(2, 1, 2), (0, 1, 1)]);
+
+        assert_eq!(
+            entry.formatted,
+            "accuracy 62.50 % - top confusions 2→1 (2), 0→1 (1)"
+        );
+        assert_eq!(entry.serialized, "[[2, 1, 0], [0, 1, 0], [0, 2, 2]]");
+
+        metric.clear();
+        assert!(metric.matrix().is_none());
+    }
+
+    #[test]
+    fn test_confusion_matrix_display() {
+        let mut matrix = ConfusionMatrix::new(2);
+        matrix.add([12.0, 3.0, 0.0, 7.0].into_iter());
+
+        assert_eq!(
+            matrix.to_string(),
+            "target\\pred           0           1\n          0          12           3\n          1           0           7"
+        );
+    }
+}
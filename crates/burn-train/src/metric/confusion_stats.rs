@@ -78,10 +78,11 @@ impl ConfusionStats {
 
     /// sum over samples
     fn aggregate(sample_class_mask: Tensor<2, Bool>, class_reduction: ClassReduction) -> Tensor<1> {
-        use ClassReduction::{Macro, Micro};
+        use ClassReduction::{Class, Macro, Micro};
         match class_reduction {
             Micro => sample_class_mask.float().sum(),
             Macro => sample_class_mask.float().sum_dim(0).squeeze_dim(0),
+            Class(class) => sample_class_mask.narrow(1, class, 1).float().sum(),
         }
     }
 
@@ -0,0 +1,402 @@
+use std::sync::Arc;
+
+use super::state::{FormatOptions, NumericMetricState, RatioMetricState};
+use super::{MetricMetadata, NumericEntry, SerializedEntry};
+use crate::metric::{Metric, MetricAttributes, MetricName, Numeric, NumericAttributes};
+use burn_core::module::path_matches;
+
+/// Gradient and weight statistics of a training step, to guide the choice of the batch size and
+/// of the learning rate.
+///
+/// The statistics are only recorded when the
+/// [training diagnostics](crate::SupervisedTraining::with_diagnostics) are enabled.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrainingDiagnostics {
+    /// The number of samples of the batch.
+    pub batch_size: usize,
+    /// The squared L2 norm of the gradients of the batch.
+    pub grad_norm_sq: f64,
+    /// The dot product of the gradients of the batch with the gradients of the previous batch,
+    /// except for the first step.
+    pub grad_dot_previous: Option<f64>,
+    /// The squared L2 norm of the gradients of the previous batch, except for the first step.
+    pub previous_grad_norm_sq: Option<f64>,
+    /// The update of each parameter by the optimizer, empty when the step only accumulated the
+    /// gradients.
+    pub updates: Vec<ParameterUpdate>,
+}
+
+/// The update of a parameter by an optimizer step.
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ParameterUpdate {
+    /// The path of the parameter in the module, such as `encoder.layers.0.linear.weight`.
+    pub path: String,
+    /// The squared L2 norm of the change of the weights.
+    pub update_norm_sq: f64,
+    /// The squared L2 norm of the weights before the update.
+    pub weight_norm_sq: f64,
+}
+
+impl TrainingDiagnostics {
+    /// The cosine similarity of the gradients of the batch with the gradients of the previous
+    /// batch, if any.
+    pub fn grad_cosine_similarity(&self) -> Option<f64> {
+        let dot = self.grad_dot_previous?;
+        let previous_norm_sq = self.previous_grad_norm_sq?;
+
+        Some(dot / (self.grad_norm_sq * previous_norm_sq).sqrt())
+    }
+
+    /// Unbiased estimates of the squared norm of the true gradient `|G|²` and of the trace of the
+    /// per-sample gradient covariance `tr(Σ)`, from the gradients of two successive batches.
+    ///
+    /// The gradients of two independent batches `g1` and `g2` of size `B` satisfy
+    /// `E[g1·g2] = |G|²` and `E[|g|²] = |G|² + tr(Σ) / B`.
+    pub fn grad_noise_estimates(&self) -> Option<(f64, f64)> {
+        let dot = self.grad_dot_previous?;
+        let previous_norm_sq = self.previous_grad_norm_sq?;
+        let mean_norm_sq = (self.grad_norm_sq + previous_norm_sq) / 2.0;
+
+        Some((dot, self.batch_size as f64 * (mean_norm_sq - dot)))
+    }
+}
+
+/// The gradient noise scale `B_noise = tr(Σ) / |G|²`, an estimate of the critical batch size.
+///
+/// Batch sizes well below the noise scale are dominated by the gradient noise and gain nearly
+/// linearly from larger batches, while batch sizes well above it waste compute. The estimate
+/// compares the gradients of successive batches, so it drifts when the learning rate moves the
+/// weights a lot between two steps. Each batch estimate is very noisy: the running value is the
+/// ratio of the summed estimates of `tr(Σ)` and `|G|²`, as recommended by
+/// [McCandlish et al. (2018)](https://arxiv.org/abs/1812.06162).
+///
+/// Requires the [training diagnostics](crate::SupervisedTraining::with_diagnostics), the entries
+/// are empty otherwise.
+#[derive(Clone)]
+pub struct GradientNoiseScaleMetric {
+    name: MetricName,
+    state: RatioMetricState,
+}
+
+impl Default for GradientNoiseScaleMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GradientNoiseScaleMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Gradient Noise Scale".to_string()),
+            state: RatioMetricState::new(1.0),
+        }
+    }
+}
+
+impl Metric for GradientNoiseScaleMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let estimates = metadata
+            .diagnostics
+            .as_ref()
+            .and_then(|diagnostics| diagnostics.grad_noise_estimates());
+        let Some((grad_sq, trace)) = estimates else {
+            return SerializedEntry::new("Unavailable".to_string(), String::new());
+        };
+
+        self.state.update(trace, grad_sq, "samples")
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("samples".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for GradientNoiseScaleMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// The cosine similarity of the gradients of successive batches.
+///
+/// Values close to 1 mean the steps keep the same direction, and the learning rate could be
+/// increased, while negative values mean the steps oscillate, and the learning rate is likely too
+/// high. Values close to 0 are typical of noisy gradients, with small batches.
+///
+/// Requires the [training diagnostics](crate::SupervisedTraining::with_diagnostics), the entries
+/// are empty otherwise.
+#[derive(Clone)]
+pub struct GradientCosineSimilarityMetric {
+    name: MetricName,
+    state: NumericMetricState,
+}
+
+impl Default for GradientCosineSimilarityMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GradientCosineSimilarityMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Gradient Cosine Similarity".to_string()),
+            state: Default::default(),
+        }
+    }
+}
+
+impl Metric for GradientCosineSimilarityMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let similarity = metadata
+            .diagnostics
+            .as_ref()
+            .and_then(|diagnostics| diagnostics.grad_cosine_similarity());
+        let Some(similarity) = similarity else {
+            return SerializedEntry::new("Unavailable".to_string(), String::new());
+        };
+
+        self.state
+            .update(similarity, 1, FormatOptions::new(self.name()).precision(3))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for GradientCosineSimilarityMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// The update-to-weight ratio `|Δw| / |w|` of the optimizer steps, in percentage.
+///
+/// A common rule of thumb is a ratio around 0.1 % per step: much lower ratios mean the layers
+/// barely learn and the learning rate could be increased, while much higher ratios mean the
+/// training is likely unstable. Tracking the ratio of each layer with
+/// [matching](Self::matching) shows the layers that learn too slowly or too fast, for example
+/// to tune a layer-wise learning rate decay.
+///
+/// Requires the [training diagnostics](crate::SupervisedTraining::with_diagnostics), the entries
+/// are empty otherwise, and for the steps that only accumulate gradients.
+#[derive(Clone)]
+pub struct UpdateRatioMetric {
+    name: MetricName,
+    pattern: Option<String>,
+    state: NumericMetricState,
+}
+
+impl Default for UpdateRatioMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UpdateRatioMetric {
+    /// Creates the metric for all the parameters of the model.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Update Ratio".to_string()),
+            pattern: None,
+            state: Default::default(),
+        }
+    }
+
+    /// Creates the metric for the parameters whose path matches a pattern, where `*` matches any
+    /// sequence of characters, such as `encoder.layers.0.*` or `*.bias`.
+    pub fn matching(pattern: &str) -> Self {
+        Self {
+            name: Arc::new(format!("Update Ratio [{pattern}]")),
+            pattern: Some(pattern.to_string()),
+            state: Default::default(),
+        }
+    }
+
+    fn ratio(&self, updates: &[ParameterUpdate]) -> Option<f64> {
+        let (update_norm_sq, weight_norm_sq) = updates
+            .iter()
+            .filter(|update| match &self.pattern {
+                Some(pattern) => path_matches(pattern, &update.path),
+                None => true,
+            })
+            .fold((0.0, 0.0), |(update_sq, weight_sq), update| {
+                (
+                    update_sq + update.update_norm_sq,
+                    weight_sq + update.weight_norm_sq,
+                )
+            });
+
+        match weight_norm_sq > 0.0 {
+            true => Some((update_norm_sq / weight_norm_sq).sqrt()),
+            false => None,
+        }
+    }
+}
+
+impl Metric for UpdateRatioMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let ratio = metadata
+            .diagnostics
+            .as_ref()
+            .and_then(|diagnostics| self.ratio(&diagnostics.updates));
+        let Some(ratio) = ratio else {
+            return SerializedEntry::new("Unavailable".to_string(), String::new());
+        };
+
+        self.state.update(
+            100.0 * ratio,
+            1,
+            FormatOptions::new(self.name()).unit("%").precision(4),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("%".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for UpdateRatioMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(diagnostics: TrainingDiagnostics) -> MetricMetadata {
+        let mut metadata = MetricMetadata::fake();
+        metadata.diagnostics = Some(Arc::new(diagnostics));
+        metadata
+    }
+
+    fn successive(grad_norm_sq: f64, dot: f64, previous_norm_sq: f64) -> TrainingDiagnostics {
+        TrainingDiagnostics {
+            batch_size: 8,
+            grad_norm_sq,
+            grad_dot_previous: Some(dot),
+            previous_grad_norm_sq: Some(previous_norm_sq),
+            updates: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_gradient_noise_scale() {
+        let mut metric = GradientNoiseScaleMetric::new();
+
+        // |G|² = 2, tr(Σ) = 8 * (3 - 2) = 8.
+        let _entry = metric.update(&(), &metadata(successive(2.0, 2.0, 4.0)));
+        assert_eq!(metric.value().current(), 4.0);
+
+        // |G|² = 1, tr(Σ) = 8 * (2 - 1) = 8.
+        let _entry = metric.update(&(), &metadata(successive(3.0, 1.0, 1.0)));
+        assert_eq!(metric.value().current(), 8.0);
+        assert_eq!(metric.running_value().current(), 16.0 / 3.0);
+    }
+
+    #[test]
+    fn test_gradient_noise_scale_skips_first_step() {
+        let mut metric = GradientNoiseScaleMetric::new();
+        let first = TrainingDiagnostics {
+            batch_size: 8,
+            grad_norm_sq: 2.0,
+            ..Default::default()
+        };
+
+        let entry = metric.update(&(), &metadata(first));
+
+        assert_eq!(entry.formatted, "Unavailable");
+        assert!(metric.value().current().is_nan());
+    }
+
+    #[test]
+    fn test_gradient_cosine_similarity() {
+        let mut metric = GradientCosineSimilarityMetric::new();
+
+        let _entry = metric.update(&(), &metadata(successive(4.0, -3.0, 9.0)));
+
+        assert_eq!(metric.value().current(), -0.5);
+    }
+
+    #[test]
+    fn test_update_ratio_matching_layers() {
+        let diagnostics = TrainingDiagnostics {
+            updates: vec![
+                ParameterUpdate::new("encoder.weight".to_string(), 9.0, 400.0),
+                ParameterUpdate::new("encoder.bias".to_string(), 0.0, 225.0),
+                ParameterUpdate::new("decoder.weight".to_string(), 1.0, 10000.0),
+            ],
+            ..Default::default()
+        };
+        let mut all = UpdateRatioMetric::new();
+        let mut encoder = UpdateRatioMetric::matching("encoder.*");
+        let mut decoder = UpdateRatioMetric::matching("decoder.*");
+
+        let _entry = all.update(&(), &metadata(diagnostics.clone()));
+        let _entry = encoder.update(&(), &metadata(diagnostics.clone()));
+        let _entry = decoder.update(&(), &metadata(diagnostics));
+
+        assert_eq!(all.value().current(), 100.0 * (10.0f64 / 10625.0).sqrt());
+        assert_eq!(encoder.value().current(), 100.0 * 3.0 / 25.0);
+        assert_eq!(decoder.value().current(), 1.0);
+    }
+}
@@ -99,9 +99,9 @@ impl FBetaScoreMetric {
     }
 
     fn class_average(&self, mut aggregated_metric: Tensor<1>) -> f64 {
-        use ClassReduction::{Macro, Micro};
+        use ClassReduction::{Class, Macro, Micro};
         let avg_tensor = match self.config.class_reduction {
-            Micro => aggregated_metric,
+            Micro | Class(_) => aggregated_metric,
             Macro => {
                 if aggregated_metric.clone().contains_nan().any().into_scalar() {
                     let nan_mask = aggregated_metric.clone().is_nan();
@@ -197,6 +197,7 @@ mod tests {
     #[case::multiclass_b2_micro_k2(2.0, Micro, 2, 5.0*4.0/(4.0*5.0 + 10.0))]
     #[case::multiclass_b2_macro_k1(2.0, Macro, 1, (0.5 + 5.0/(4.0 + 2.0) + 5.0/(8.0 + 1.0))/3.0)]
     #[case::multiclass_b2_macro_k2(2.0, Macro, 2, (5.0/(4.0 + 2.0) + 5.0/(4.0 + 4.0) + 0.5)/3.0)]
+    #[case::multiclass_b1_class_k1(1.0, Class(2), 1, 2.0/(1.0 + 2.0))]
     fn test_multiclass_fscore(
         #[case] beta: f64,
         #[case] class_reduction: ClassReduction,
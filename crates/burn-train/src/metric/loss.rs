@@ -18,7 +18,7 @@ pub struct LossMetric {
 /// The [loss metric](LossMetric) input type.
 #[derive(new)]
 pub struct LossInput {
-    tensor: Tensor<1>,
+    pub(crate) tensor: Tensor<1>,
 }
 
 impl Default for LossMetric {
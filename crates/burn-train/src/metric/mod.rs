@@ -33,10 +33,14 @@ pub use memory_use::*;
 // Training metrics
 mod acc;
 mod auroc;
+mod average_precision;
 mod base;
 mod bleu;
+mod calibration;
 mod cer;
+mod confusion_matrix;
 mod confusion_stats;
+mod diagnostics;
 mod fbetascore;
 mod hamming;
 mod iteration;
@@ -44,17 +48,23 @@ mod learning_rate;
 mod loss;
 mod perplexity;
 mod precision;
+mod ranking;
 mod recall;
 mod rouge;
+mod throughput;
 mod top_k_acc;
 mod wer;
 
 pub use acc::*;
 pub use auroc::*;
+pub use average_precision::*;
 pub use base::*;
 pub use bleu::*;
+pub use calibration::*;
 pub use cer::*;
+pub use confusion_matrix::*;
 pub use confusion_stats::ConfusionStatsInput;
+pub use diagnostics::*;
 pub use fbetascore::*;
 pub use hamming::*;
 pub use iteration::*;
@@ -64,6 +74,7 @@ pub use perplexity::*;
 pub use precision::*;
 pub use recall::*;
 pub use rouge::*;
+pub use throughput::*;
 pub use top_k_acc::*;
 pub use wer::*;
 
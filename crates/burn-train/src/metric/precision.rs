@@ -75,19 +75,16 @@ impl PrecisionMetric {
     /// * `class_reduction` - [Class reduction](ClassReduction) type.
     #[allow(dead_code)]
     pub fn multilabel(threshold: f64, class_reduction: ClassReduction) -> Self {
-        Self {
-            config: ClassificationMetricConfig {
-                decision_rule: DecisionRule::Threshold(threshold),
-                class_reduction,
-            },
-            ..Default::default()
-        }
+        Self::new(ClassificationMetricConfig {
+            decision_rule: DecisionRule::Threshold(threshold),
+            class_reduction,
+        })
     }
 
     fn class_average(&self, mut aggregated_metric: Tensor<1>) -> f64 {
-        use ClassReduction::{Macro, Micro};
+        use ClassReduction::{Class, Macro, Micro};
         let avg_tensor = match self.config.class_reduction {
-            Micro => aggregated_metric,
+            Micro | Class(_) => aggregated_metric,
             Macro => {
                 if aggregated_metric.clone().contains_nan().any().into_scalar() {
                     let nan_mask = aggregated_metric.clone().is_nan();
@@ -173,6 +170,8 @@ mod tests {
     #[case::multiclass_micro_k2(Micro, 2, 4.0/10.0)]
     #[case::multiclass_macro_k1(Macro, 1, (0.5 + 0.5 + 1.0)/3.0)]
     #[case::multiclass_macro_k2(Macro, 2, (0.5 + 1.0/4.0 + 0.5)/3.0)]
+    #[case::multiclass_class_k1(Class(0), 1, 0.5)]
+    #[case::multiclass_class_k2(Class(1), 2, 1.0/4.0)]
     fn test_multiclass_precision(
         #[case] class_reduction: ClassReduction,
         #[case] top_k: usize,
@@ -1,8 +1,10 @@
 use burn_core::data::dataloader::Progress;
 use burn_optim::LearningRate;
+use std::sync::Arc;
 
 use crate::{
     LearnerSummary,
+    metric::{StepTiming, TrainingDiagnostics},
     renderer::{EvaluationName, MetricsRenderer},
 };
 
@@ -75,6 +77,28 @@ pub struct TrainingItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The time spent in each phase of the training step, if recorded.
+    #[new(default)]
+    pub step_timing: Option<StepTiming>,
+
+    /// The gradient and weight statistics of the training step, if recorded.
+    #[new(default)]
+    pub diagnostics: Option<Arc<TrainingDiagnostics>>,
+}
+
+impl<T> TrainingItem<T> {
+    /// Attaches the time spent in each phase of the training step.
+    pub fn with_step_timing(mut self, step_timing: Option<StepTiming>) -> Self {
+        self.step_timing = step_timing;
+        self
+    }
+
+    /// Attaches the gradient and weight statistics of the training step.
+    pub fn with_diagnostics(mut self, diagnostics: Option<TrainingDiagnostics>) -> Self {
+        self.diagnostics = diagnostics.map(Arc::new);
+        self
+    }
 }
 
 impl<T: ItemLazy> ItemLazy for TrainingItem<T> {
@@ -85,6 +109,8 @@ impl<T: ItemLazy> ItemLazy for TrainingItem<T> {
             global_progress: self.global_progress,
             iteration: self.iteration,
             lr: self.lr,
+            step_timing: self.step_timing,
+            diagnostics: self.diagnostics,
         }
     }
 }
@@ -261,6 +261,8 @@ impl<T> From<&TrainingItem<T>> for MetricMetadata {
             global_progress: item.global_progress.clone(),
             iteration: item.iteration,
             lr: item.lr,
+            step_timing: item.step_timing,
+            diagnostics: item.diagnostics.clone(),
         }
     }
 }
@@ -272,6 +274,8 @@ impl<T> From<&EvaluationItem<T>> for MetricMetadata {
             global_progress: item.progress.clone(),
             iteration: item.iteration,
             lr: None,
+            step_timing: None,
+            diagnostics: None,
         }
     }
 }
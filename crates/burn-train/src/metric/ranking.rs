@@ -0,0 +1,183 @@
+use crate::metric::{ClassReduction, ConfusionStatsInput, NumericEntry, SerializedEntry};
+use burn_core::tensor::{Bool, Int, Tensor};
+
+use super::format_float;
+
+/// Score and label pairs accumulated on the device across batches, for the metrics that rank
+/// all the predictions of an epoch ([AUROC](super::AurocMetric) and
+/// [average precision](super::AveragePrecisionMetric)).
+#[derive(Clone, Default)]
+pub(crate) struct RankingState {
+    scores: Vec<Tensor<2>>,
+    targets: Vec<Tensor<2>>,
+    num_items: usize,
+    /// Number of items of the last batch.
+    batch_size: usize,
+}
+
+/// Cumulative true and false positive counts of each column, when thresholding at each score
+/// sorted in descending order.
+///
+/// Tied scores can't be separated by a threshold, so every position of a group of tied scores
+/// holds the counts of the last position of the group.
+pub(crate) struct RankingCurve {
+    /// True positives at each threshold, of shape [num_items, num_columns].
+    pub true_positives: Tensor<2>,
+    /// False positives at each threshold, of shape [num_items, num_columns].
+    pub false_positives: Tensor<2>,
+}
+
+impl RankingState {
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub(crate) fn update(&mut self, input: &ConfusionStatsInput) {
+        let [batch_size, _num_classes] = input.predictions.dims();
+
+        self.scores.push(input.predictions.clone());
+        self.targets.push(input.targets.clone().float());
+        self.num_items += batch_size;
+        self.batch_size = batch_size;
+    }
+
+    /// The ranking curve of all the accumulated predictions, with one column per class for the
+    /// macro reduction, and a single column otherwise.
+    ///
+    /// Returns `None` when nothing was accumulated.
+    pub(crate) fn curve(&self, class_reduction: ClassReduction) -> Option<RankingCurve> {
+        if self.num_items == 0 {
+            return None;
+        }
+
+        let scores = Tensor::cat(self.scores.clone(), 0);
+        let targets = Tensor::cat(self.targets.clone(), 0);
+
+        Some(RankingCurve::reduced(scores, targets, class_reduction))
+    }
+
+    /// The entry of a value over all the accumulated predictions.
+    pub(crate) fn entry(&self, value: f64) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_items,
+        }
+    }
+
+    /// The entry of a value over the predictions of the last batch.
+    pub(crate) fn batch_entry(&self, value: f64) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.batch_size,
+        }
+    }
+
+    pub(crate) fn serialize(&self, value: f64) -> SerializedEntry {
+        let serialized = self.batch_entry(value).serialize();
+
+        SerializedEntry::new(format!("{} %", format_float(value, 2)), serialized)
+    }
+}
+
+impl RankingCurve {
+    /// The ranking curve of the predictions of a single batch, or `None` when it is empty.
+    pub(crate) fn of_batch(
+        input: &ConfusionStatsInput,
+        class_reduction: ClassReduction,
+    ) -> Option<Self> {
+        let [batch_size, _num_classes] = input.predictions.dims();
+        if batch_size == 0 {
+            return None;
+        }
+
+        Some(Self::reduced(
+            input.predictions.clone(),
+            input.targets.clone().float(),
+            class_reduction,
+        ))
+    }
+
+    fn reduced(scores: Tensor<2>, targets: Tensor<2>, class_reduction: ClassReduction) -> Self {
+        let [n, c] = scores.dims();
+
+        let (scores, targets) = match class_reduction {
+            ClassReduction::Macro => (scores, targets),
+            ClassReduction::Micro => (scores.reshape([n * c, 1]), targets.reshape([n * c, 1])),
+            ClassReduction::Class(class) => {
+                (scores.narrow(1, class, 1), targets.narrow(1, class, 1))
+            }
+        };
+
+        Self::new(scores, targets)
+    }
+
+    fn new(scores: Tensor<2>, targets: Tensor<2>) -> Self {
+        let [n, c] = scores.dims();
+        let device = scores.device();
+
+        let (scores, indices) = scores.sort_descending_with_indices(0);
+        let targets = targets.gather(0, indices);
+        let true_positives = targets.clone().cumsum(0);
+        let false_positives = (1.0 - targets).cumsum(0);
+
+        // The last position of each group of tied scores.
+        let group_end = Tensor::cat(
+            vec![
+                scores
+                    .clone()
+                    .slice([0..n - 1])
+                    .not_equal(scores.slice([1..n])),
+                Tensor::<2, Bool>::ones([1, c], &device),
+            ],
+            0,
+        );
+        // Positions that don't end a group take the index of the next one that does.
+        let ends = Tensor::<1, Int>::arange(0..n as i64, &device)
+            .reshape([n, 1])
+            .expand([n, c])
+            .mask_fill(group_end.bool_not(), n as i64)
+            .flip([0])
+            .cummin(0)
+            .flip([0]);
+
+        Self {
+            true_positives: true_positives.gather(0, ends.clone()),
+            false_positives: false_positives.gather(0, ends),
+        }
+    }
+
+    /// The total number of positives and negatives of each column, of shape [1, num_columns].
+    pub(crate) fn totals(&self) -> (Tensor<2>, Tensor<2>) {
+        let [n, _] = self.true_positives.dims();
+
+        (
+            self.true_positives.clone().slice([n - 1..n]),
+            self.false_positives.clone().slice([n - 1..n]),
+        )
+    }
+}
+
+/// Prepends a row of zeros and drops the last row, to pair each point of a curve with the
+/// previous one.
+pub(crate) fn previous_points(points: Tensor<2>) -> Tensor<2> {
+    let [n, c] = points.dims();
+    let zeros = Tensor::zeros([1, c], &points.device());
+
+    Tensor::cat(vec![zeros, points.slice([0..n - 1])], 0)
+}
+
+/// Averages the defined (non-NaN) per-column values, or returns `None` when none is defined.
+pub(crate) fn mean_defined(values: Tensor<1>) -> Option<f64> {
+    let keep = values
+        .clone()
+        .is_nan()
+        .bool_not()
+        .argwhere()
+        .squeeze_dim::<1>(1);
+
+    if keep.dims()[0] == 0 {
+        return None;
+    }
+
+    Some(values.select(0, keep).mean().into_scalar())
+}
@@ -82,9 +82,9 @@ impl RecallMetric {
     }
 
     fn class_average(&self, mut aggregated_metric: Tensor<1>) -> f64 {
-        use ClassReduction::{Macro, Micro};
+        use ClassReduction::{Class, Macro, Micro};
         let avg_tensor = match self.config.class_reduction {
-            Micro => aggregated_metric,
+            Micro | Class(_) => aggregated_metric,
             Macro => {
                 if aggregated_metric.clone().contains_nan().any().into_scalar() {
                     let nan_mask = aggregated_metric.clone().is_nan();
@@ -168,6 +168,8 @@ mod tests {
     #[case::multiclass_micro_k2(Micro, 2, 4.0/5.0)]
     #[case::multiclass_macro_k1(Macro, 1, (0.5 + 1.0 + 0.5)/3.0)]
     #[case::multiclass_macro_k2(Macro, 2, (1.0 + 1.0 + 0.5)/3.0)]
+    #[case::multiclass_class_k1(Class(1), 1, 1.0)]
+    #[case::multiclass_class_k2(Class(2), 2, 0.5)]
     fn test_multiclass_recall(
         #[case] class_reduction: ClassReduction,
         #[case] top_k: usize,
@@ -142,3 +142,67 @@ impl Default for NumericMetricState {
         Self::new()
     }
 }
+
+/// A ratio of two quantities accumulated across updates, such as samples per second.
+///
+/// The running value is the ratio of the sums, not the mean of the ratios, so that long steps
+/// weigh more than short ones.
+#[derive(Clone)]
+pub(crate) struct RatioMetricState {
+    scale: f64,
+    numerator: f64,
+    denominator: f64,
+    current: f64,
+    count: usize,
+}
+
+impl RatioMetricState {
+    pub(crate) fn new(scale: f64) -> Self {
+        Self {
+            scale,
+            numerator: 0.0,
+            denominator: 0.0,
+            current: f64::NAN,
+            count: 0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new(self.scale);
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        numerator: f64,
+        denominator: f64,
+        unit: &str,
+    ) -> SerializedEntry {
+        self.numerator += numerator;
+        self.denominator += denominator;
+        self.current = self.scale * numerator / denominator;
+        self.count += 1;
+
+        let running = self.running_value().current();
+        let formatted = format!(
+            "epoch {} {unit} - batch {} {unit}",
+            format_float(running, 2),
+            format_float(self.current, 2)
+        );
+
+        SerializedEntry::new(formatted, self.current_value().serialize())
+    }
+
+    pub(crate) fn current_value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current,
+            count: 1,
+        }
+    }
+
+    pub(crate) fn running_value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.scale * self.numerator / self.denominator,
+            count: self.count,
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::state::{FormatOptions, NumericMetricState, RatioMetricState};
+use super::{MetricMetadata, NumericEntry, SerializedEntry, format_float};
+use crate::metric::{Metric, MetricAttributes, MetricName, Numeric, NumericAttributes};
+
+/// The time spent in each phase of a training step.
+///
+/// The timings are only recorded when the [step timing report](crate::SupervisedTraining::with_step_timing)
+/// is enabled, which synchronizes the device at the end of the forward pass, of the backward pass
+/// and of the optimizer step so that the asynchronous work is attributed to the right phase.
+#[derive(new, Clone, Copy, Debug, Default, PartialEq)]
+pub struct StepTiming {
+    /// Time spent waiting for the data loader to provide the batch.
+    pub data: Duration,
+    /// Time spent in the forward pass, until the backward pass starts.
+    pub forward: Duration,
+    /// Time spent in the backward pass, until the training step returns.
+    pub backward: Duration,
+    /// Time spent in the optimizer step.
+    pub optimizer: Duration,
+}
+
+impl StepTiming {
+    /// The total time of the step.
+    pub fn total(&self) -> Duration {
+        self.data + self.forward + self.backward + self.optimizer
+    }
+
+    /// The time of the given phase of the step.
+    pub fn phase(&self, phase: StepPhase) -> Duration {
+        match phase {
+            StepPhase::Data => self.data,
+            StepPhase::Forward => self.forward,
+            StepPhase::Backward => self.backward,
+            StepPhase::Optimizer => self.optimizer,
+            StepPhase::Total => self.total(),
+        }
+    }
+}
+
+impl core::ops::AddAssign for StepTiming {
+    fn add_assign(&mut self, other: Self) {
+        self.data += other.data;
+        self.forward += other.forward;
+        self.backward += other.backward;
+        self.optimizer += other.optimizer;
+    }
+}
+
+impl core::fmt::Display for StepTiming {
+    /// Shows the total time and the share of each phase: `total 2.00 s - Data 25.00 % (500.00 ms), ...`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let total = self.total().as_secs_f64();
+        write!(f, "total {}", format_duration(total))?;
+
+        for phase in [
+            StepPhase::Data,
+            StepPhase::Forward,
+            StepPhase::Backward,
+            StepPhase::Optimizer,
+        ] {
+            let time = self.phase(phase).as_secs_f64();
+            write!(
+                f,
+                " - {phase} {} % ({})",
+                format_float(100.0 * time / total, 2),
+                format_duration(time)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration(seconds: f64) -> String {
+    match seconds < 1.0 {
+        true => format!("{} ms", format_float(seconds * 1e3, 2)),
+        false => format!("{} s", format_float(seconds, 2)),
+    }
+}
+
+/// A phase of a training step, timed by the [step time metric](StepTimeMetric).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepPhase {
+    /// Waiting for the data loader.
+    Data,
+    /// The forward pass.
+    Forward,
+    /// The backward pass.
+    Backward,
+    /// The optimizer step.
+    Optimizer,
+    /// The whole step.
+    Total,
+}
+
+impl StepPhase {
+    /// All the phases, in the order they happen, followed by the whole step.
+    pub const ALL: [StepPhase; 5] = [
+        StepPhase::Data,
+        StepPhase::Forward,
+        StepPhase::Backward,
+        StepPhase::Optimizer,
+        StepPhase::Total,
+    ];
+}
+
+impl core::fmt::Display for StepPhase {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StepPhase::Data => write!(f, "Data"),
+            StepPhase::Forward => write!(f, "Forward"),
+            StepPhase::Backward => write!(f, "Backward"),
+            StepPhase::Optimizer => write!(f, "Optimizer"),
+            StepPhase::Total => write!(f, "Total"),
+        }
+    }
+}
+
+/// The time spent in a phase of each training step, in milliseconds.
+///
+/// Requires the [step timing report](crate::SupervisedTraining::with_step_timing), the entries
+/// are empty otherwise.
+#[derive(Clone)]
+pub struct StepTimeMetric {
+    name: MetricName,
+    phase: StepPhase,
+    state: NumericMetricState,
+}
+
+impl StepTimeMetric {
+    /// Creates the metric for the given phase.
+    pub fn new(phase: StepPhase) -> Self {
+        Self {
+            name: Arc::new(format!("Step Time [{phase}]")),
+            phase,
+            state: Default::default(),
+        }
+    }
+}
+
+impl Metric for StepTimeMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let Some(timing) = metadata.step_timing else {
+            return SerializedEntry::new("Unavailable".to_string(), String::new());
+        };
+
+        let millis = timing.phase(self.phase).as_secs_f64() * 1e3;
+        self.state.update(
+            millis,
+            1,
+            FormatOptions::new(self.name()).unit("ms").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("ms".to_string()),
+            higher_is_better: false,
+        }
+        .into()
+    }
+}
+
+impl Numeric for StepTimeMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// An estimate of the device utilization: the fraction of the training steps spent computing
+/// (forward and backward passes and optimizer step) rather than waiting for data, in percentage.
+///
+/// A low utilization means the training is input bound, and would benefit from more data loader
+/// workers or cheaper data transforms. Requires the
+/// [step timing report](crate::SupervisedTraining::with_step_timing), the entries are empty
+/// otherwise.
+#[derive(Clone)]
+pub struct DeviceUtilizationMetric {
+    name: MetricName,
+    state: RatioMetricState,
+}
+
+impl Default for DeviceUtilizationMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceUtilizationMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Device Utilization (estimate)".to_string()),
+            state: RatioMetricState::new(100.0),
+        }
+    }
+}
+
+impl Metric for DeviceUtilizationMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let Some(timing) = metadata.step_timing else {
+            return SerializedEntry::new("Unavailable".to_string(), String::new());
+        };
+
+        let compute = timing.forward + timing.backward + timing.optimizer;
+        self.state
+            .update(compute.as_secs_f64(), timing.total().as_secs_f64(), "%")
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("%".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for DeviceUtilizationMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// The number of samples processed per second.
+///
+/// The samples are counted from the progress of the data loader. The time of each step is the
+/// recorded [step timing](StepTiming) when available, and the time since the previous update
+/// otherwise, in which case the first step of each epoch isn't counted.
+#[derive(Clone)]
+pub struct ThroughputMetric {
+    name: MetricName,
+    state: RatioMetricState,
+    timer: StepTimer,
+    items_processed: usize,
+}
+
+impl Default for ThroughputMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThroughputMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Throughput".to_string()),
+            state: RatioMetricState::new(1.0),
+            timer: StepTimer::default(),
+            items_processed: 0,
+        }
+    }
+}
+
+impl Metric for ThroughputMetric {
+    type Input = ();
+
+    fn update(&mut self, _: &(), metadata: &MetricMetadata) -> SerializedEntry {
+        let items_processed = metadata.progress.items_processed;
+        let num_samples = items_processed.saturating_sub(self.items_processed);
+        self.items_processed = items_processed;
+
+        match self.timer.elapsed(metadata) {
+            Some(seconds) => self
+                .state
+                .update(num_samples as f64, seconds, "samples/sec"),
+            None => SerializedEntry::new("Unavailable".to_string(), String::new()),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.timer = StepTimer::default();
+        self.items_processed = 0;
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("samples/sec".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for ThroughputMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// The [token throughput metric](TokenThroughputMetric) input type.
+#[derive(new, Debug, Clone)]
+pub struct TokenThroughputInput {
+    /// The number of tokens in the batch.
+    num_tokens: usize,
+}
+
+/// The number of tokens processed per second, for sequence models.
+///
+/// The time of each step is the recorded [step timing](StepTiming) when available, and the time
+/// since the previous update otherwise, in which case the first step of each epoch isn't counted.
+#[derive(Clone)]
+pub struct TokenThroughputMetric {
+    name: MetricName,
+    state: RatioMetricState,
+    timer: StepTimer,
+}
+
+impl Default for TokenThroughputMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenThroughputMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            name: Arc::new("Token Throughput".to_string()),
+            state: RatioMetricState::new(1.0),
+            timer: StepTimer::default(),
+        }
+    }
+}
+
+impl Metric for TokenThroughputMetric {
+    type Input = TokenThroughputInput;
+
+    fn update(
+        &mut self,
+        input: &TokenThroughputInput,
+        metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        match self.timer.elapsed(metadata) {
+            Some(seconds) => self
+                .state
+                .update(input.num_tokens as f64, seconds, "tokens/sec"),
+            None => SerializedEntry::new("Unavailable".to_string(), String::new()),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.timer = StepTimer::default();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: Some("tokens/sec".to_string()),
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for TokenThroughputMetric {
+    fn value(&self) -> NumericEntry {
+        self.state.current_value()
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.state.running_value()
+    }
+}
+
+/// The duration of each step: the recorded [step timing](StepTiming) when available, or the
+/// wall-clock time since the previous step.
+#[derive(Clone, Default)]
+struct StepTimer {
+    last: Option<Instant>,
+}
+
+impl StepTimer {
+    fn elapsed(&mut self, metadata: &MetricMetadata) -> Option<f64> {
+        let now = Instant::now();
+        let last = self.last.replace(now);
+
+        match metadata.step_timing {
+            Some(timing) => Some(timing.total().as_secs_f64()),
+            None => last.map(|last| now.duration_since(last).as_secs_f64()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::Progress;
+
+    fn metadata(items_processed: usize, timing: Option<StepTiming>) -> MetricMetadata {
+        let mut metadata = MetricMetadata::fake();
+        metadata.progress = Progress::new(items_processed, 100);
+        metadata.step_timing = timing;
+        metadata
+    }
+
+    fn timing(data: u64, forward: u64, backward: u64, optimizer: u64) -> Option<StepTiming> {
+        Some(StepTiming::new(
+            Duration::from_millis(data),
+            Duration::from_millis(forward),
+            Duration::from_millis(backward),
+            Duration::from_millis(optimizer),
+        ))
+    }
+
+    #[test]
+    fn test_step_time_metric() {
+        let mut forward = StepTimeMetric::new(StepPhase::Forward);
+        let mut backward = StepTimeMetric::new(StepPhase::Backward);
+
+        for (items, timing) in [(8, timing(10, 10, 20, 10)), (16, timing(10, 20, 30, 10))] {
+            let _entry = forward.update(&(), &metadata(items, timing));
+            let _entry = backward.update(&(), &metadata(items, timing));
+        }
+
+        assert_eq!(forward.value().current(), 20.0);
+        assert_eq!(forward.running_value().current(), 15.0);
+        assert_eq!(backward.value().current(), 30.0);
+        assert_eq!(backward.running_value().current(), 25.0);
+    }
+
+    #[test]
+    fn test_step_timing_display() {
+        let timing = timing(500, 400, 800, 300).unwrap();
+
+        assert_eq!(
+            timing.to_string(),
+            "total 2.00 s - Data 25.00 % (500.00 ms) - Forward 20.00 % (400.00 ms) - Backward 40.00 % (800.00 ms) - Optimizer 15.00 % (300.00 ms)"
+        );
+    }
+
+    #[test]
+    fn test_step_time_metric_without_timing() {
+        let mut metric = StepTimeMetric::new(StepPhase::Total);
+
+        let entry = metric.update(&(), &metadata(8, None));
+
+        assert_eq!(entry.formatted, "Unavailable");
+        assert!(metric.value().current().is_nan());
+    }
+
+    #[test]
+    fn test_device_utilization_weighs_steps_by_duration() {
+        let mut metric = DeviceUtilizationMetric::new();
+
+        let _entry = metric.update(&(), &metadata(8, timing(0, 30, 50, 20)));
+        let _entry = metric.update(&(), &metadata(16, timing(200, 20, 30, 50)));
+
+        assert_eq!(metric.value().current(), 100.0 * 100.0 / 300.0);
+        assert_eq!(metric.running_value().current(), 50.0);
+    }
+
+    #[test]
+    fn test_throughput_counts_samples_from_progress() {
+        let mut metric = ThroughputMetric::new();
+
+        let _entry = metric.update(&(), &metadata(8, timing(50, 40, 60, 50)));
+        let _entry = metric.update(&(), &metadata(16, timing(0, 20, 30, 50)));
+
+        assert_eq!(metric.value().current(), 80.0);
+        assert!((metric.running_value().current() - 16.0 / 0.3).abs() < 1e-9);
+
+        metric.clear();
+        let _entry = metric.update(&(), &metadata(4, timing(0, 15, 25, 0)));
+        assert_eq!(metric.value().current(), 100.0);
+    }
+
+    #[test]
+    fn test_token_throughput_without_timing_skips_first_step() {
+        let mut metric = TokenThroughputMetric::new();
+
+        let entry = metric.update(&TokenThroughputInput::new(512), &metadata(8, None));
+
+        assert_eq!(entry.formatted, "Unavailable");
+        assert!(metric.value().current().is_nan());
+
+        let _entry = metric.update(&TokenThroughputInput::new(512), &metadata(16, None));
+        assert!(metric.value().current() > 0.0);
+    }
+}
@@ -359,23 +359,25 @@ impl Lpips {
     ///
     /// * `input` - First image tensor of shape `[batch, 3, H, W]`
     /// * `target` - Second image tensor of shape `[batch, 3, H, W]`
-    /// * `reduction` - How to reduce the output (Mean, Sum, or Auto)
+    /// * `reduction` - How to reduce the output (Mean, Sum, Auto, or None)
     ///
     /// # Returns
     ///
-    /// Scalar tensor of shape `[1]`.
+    /// Scalar tensor of shape `[1]`, or per-sample distance tensor of shape `[batch]` with
+    /// `Reduction::None`.
     ///
     /// # Shapes
     ///
     /// - input: `[batch, 3, H, W]`
     /// - target: `[batch, 3, H, W]`
-    /// - output: `[1]`
+    /// - output: `[1]`, or `[batch]` with `Reduction::None`
     pub fn forward(&self, input: Tensor<4>, target: Tensor<4>, reduction: Reduction) -> Tensor<1> {
         let distance = self.forward_no_reduction(input, target);
 
         match reduction {
             Reduction::Mean | Reduction::Auto | Reduction::BatchMean => distance.mean(),
             Reduction::Sum => distance.sum(),
+            Reduction::None => distance,
         }
     }
 
@@ -0,0 +1,731 @@
+use std::collections::BTreeMap;
+
+use crate::metric::{
+    Metric, MetricAttributes, MetricMetadata, MetricName, Numeric, NumericAttributes,
+    NumericEntry, SerializedEntry, format_float,
+};
+use burn_core::tensor::{Int, Tensor};
+
+/// Upper bound of the area of small objects, in squared pixels.
+const SMALL_AREA: f64 = 32.0 * 32.0;
+/// Upper bound of the area of medium objects, in squared pixels.
+const MEDIUM_AREA: f64 = 96.0 * 96.0;
+/// Number of recall points used to interpolate the precision-recall curve.
+const NUM_RECALL_POINTS: usize = 101;
+
+/// Detections predicted for a single image.
+#[derive(new, Debug, Clone)]
+pub struct DetectionPredictions {
+    /// Predicted boxes with shape `[N, 4]`, as `(x_min, y_min, x_max, y_max)` in pixels.
+    boxes: Tensor<2>,
+    /// Confidence score of each box, with shape `[N]`.
+    scores: Tensor<1>,
+    /// Predicted class of each box, with shape `[N]`.
+    labels: Tensor<1, Int>,
+}
+
+/// Ground truth objects of a single image.
+#[derive(new, Debug, Clone)]
+pub struct DetectionTargets {
+    /// Ground truth boxes with shape `[M, 4]`, as `(x_min, y_min, x_max, y_max)` in pixels.
+    boxes: Tensor<2>,
+    /// Class of each box, with shape `[M]`.
+    labels: Tensor<1, Int>,
+}
+
+/// Input type for the [MeanAveragePrecisionMetric].
+pub struct MeanAveragePrecisionInput {
+    predictions: Vec<DetectionPredictions>,
+    targets: Vec<DetectionTargets>,
+}
+
+impl MeanAveragePrecisionInput {
+    /// Creates a new input from the predictions and the ground truth of a batch of images.
+    ///
+    /// # Panics
+    /// - If `predictions` and `targets` don't have the same number of images.
+    pub fn new(predictions: Vec<DetectionPredictions>, targets: Vec<DetectionTargets>) -> Self {
+        assert_eq!(
+            predictions.len(),
+            targets.len(),
+            "Predictions and targets must have the same number of images."
+        );
+        Self {
+            predictions,
+            targets,
+        }
+    }
+}
+
+/// Object size range used to break down the average precision, following the COCO definition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ObjectArea {
+    /// All objects.
+    #[default]
+    All,
+    /// Objects with an area up to 32² pixels.
+    Small,
+    /// Objects with an area between 32² and 96² pixels.
+    Medium,
+    /// Objects with an area of at least 96² pixels.
+    Large,
+}
+
+impl ObjectArea {
+    const ALL: [ObjectArea; 4] = [Self::All, Self::Small, Self::Medium, Self::Large];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn contains(self, area: f64) -> bool {
+        let (min, max) = match self {
+            Self::All => (0.0, f64::INFINITY),
+            Self::Small => (0.0, SMALL_AREA),
+            Self::Medium => (SMALL_AREA, MEDIUM_AREA),
+            Self::Large => (MEDIUM_AREA, f64::INFINITY),
+        };
+        (min..=max).contains(&area)
+    }
+}
+
+/// Configuration for the [MeanAveragePrecisionMetric].
+#[derive(Debug, Clone)]
+pub struct MeanAveragePrecisionConfig {
+    /// IoU thresholds over which the average precision is averaged.
+    pub iou_thresholds: Vec<f64>,
+    /// Maximum number of detections per image and class, keeping the highest scores.
+    pub max_detections: usize,
+    /// Object size range of the reported value.
+    pub area: ObjectArea,
+}
+
+impl Default for MeanAveragePrecisionConfig {
+    fn default() -> Self {
+        Self {
+            iou_thresholds: (0..10).map(|i| 0.5 + 0.05 * i as f64).collect(),
+            max_detections: 100,
+            area: ObjectArea::All,
+        }
+    }
+}
+
+/// The average precision values computed over all the images seen by the metric.
+///
+/// Values are `None` when no ground truth object falls in the corresponding range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeanAveragePrecisionSummary {
+    /// Mean average precision over the configured IoU thresholds.
+    pub map: Option<f64>,
+    /// Mean average precision at an IoU threshold of 0.5, if it is one of the thresholds.
+    pub map_50: Option<f64>,
+    /// Mean average precision at an IoU threshold of 0.75, if it is one of the thresholds.
+    pub map_75: Option<f64>,
+    /// Mean average precision over the configured IoU thresholds, for small objects.
+    pub map_small: Option<f64>,
+    /// Mean average precision over the configured IoU thresholds, for medium objects.
+    pub map_medium: Option<f64>,
+    /// Mean average precision over the configured IoU thresholds, for large objects.
+    pub map_large: Option<f64>,
+    /// Average precision over the configured IoU thresholds of each class with ground truth.
+    pub per_class: BTreeMap<i64, f64>,
+}
+
+/// The COCO-style mean average precision (mAP) for object detection.
+///
+/// Predictions are matched greedily to the ground truth of the same image and class by
+/// decreasing score, for each IoU threshold. The average precision of a class is the area
+/// under its precision-recall curve, interpolated at 101 recall points, and the reported value
+/// is its mean over the classes with ground truth and the IoU thresholds.
+///
+/// Matching is done when the images are received, so the metric keeps the scores and outcomes of
+/// the detections of the epoch rather than the images themselves.
+#[derive(Clone)]
+pub struct MeanAveragePrecisionMetric {
+    name: MetricName,
+    config: MeanAveragePrecisionConfig,
+    state: MeanAveragePrecisionState,
+}
+
+impl Default for MeanAveragePrecisionMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeanAveragePrecisionMetric {
+    /// Creates the metric, averaged over the IoU thresholds 0.50:0.05:0.95 for all objects.
+    pub fn new() -> Self {
+        Self::with_config(MeanAveragePrecisionConfig::default())
+    }
+
+    /// Creates the metric with a custom config.
+    ///
+    /// # Panics
+    /// - If no IoU threshold is given.
+    pub fn with_config(config: MeanAveragePrecisionConfig) -> Self {
+        assert!(
+            !config.iou_thresholds.is_empty(),
+            "Mean average precision requires at least one IoU threshold."
+        );
+
+        let thresholds = match config.iou_thresholds.as_slice() {
+            [threshold] => format!("{threshold:.2}"),
+            [first, .., last] => format!("[{first:.2}:{last:.2}]"),
+            [] => unreachable!(),
+        };
+        let name = match config.area {
+            ObjectArea::All => format!("mAP@{thresholds}"),
+            area => format!("mAP@{thresholds} ({area:?})"),
+        };
+
+        Self {
+            name: MetricName::new(name),
+            state: MeanAveragePrecisionState::default(),
+            config,
+        }
+    }
+
+    /// Computes the full breakdown of the average precision over the images seen so far.
+    pub fn summary(&self) -> MeanAveragePrecisionSummary {
+        let all_thresholds = 0..self.config.iou_thresholds.len();
+        let at_threshold = |value: f64| {
+            self.config
+                .iou_thresholds
+                .iter()
+                .position(|threshold| (threshold - value).abs() < 1e-9)
+                .and_then(|t| self.state.mean_ap(ObjectArea::All, t..t + 1))
+        };
+
+        MeanAveragePrecisionSummary {
+            map: self.state.mean_ap(ObjectArea::All, all_thresholds.clone()),
+            map_50: at_threshold(0.5),
+            map_75: at_threshold(0.75),
+            map_small: self.state.mean_ap(ObjectArea::Small, all_thresholds.clone()),
+            map_medium: self.state.mean_ap(ObjectArea::Medium, all_thresholds.clone()),
+            map_large: self.state.mean_ap(ObjectArea::Large, all_thresholds.clone()),
+            per_class: self
+                .state
+                .classes
+                .iter()
+                .filter_map(|(label, class)| {
+                    class
+                        .average_precision(ObjectArea::All, all_thresholds.clone())
+                        .map(|ap| (*label, ap))
+                })
+                .collect(),
+        }
+    }
+
+    fn current(&self) -> f64 {
+        self.state
+            .mean_ap(self.config.area, 0..self.config.iou_thresholds.len())
+            .unwrap_or(0.0)
+    }
+}
+
+impl Metric for MeanAveragePrecisionMetric {
+    type Input = MeanAveragePrecisionInput;
+
+    fn update(
+        &mut self,
+        input: &MeanAveragePrecisionInput,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        for (predictions, targets) in input.predictions.iter().zip(input.targets.iter()) {
+            let detections = Detection::from_predictions(predictions);
+            let objects = Detection::from_targets(targets);
+            self.state.update(detections, objects, &self.config);
+        }
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.state.num_images,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for MeanAveragePrecisionMetric {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.state.num_images,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+/// A box with its class and score, read back from the input tensors.
+#[derive(Debug, Clone, Copy)]
+struct Detection {
+    bbox: [f64; 4],
+    label: i64,
+    score: f64,
+}
+
+impl Detection {
+    fn from_predictions(predictions: &DetectionPredictions) -> Vec<Self> {
+        let scores = predictions.scores.to_data().iter::<f64>().collect::<Vec<_>>();
+        Self::from_tensors(&predictions.boxes, &predictions.labels, Some(scores))
+    }
+
+    fn from_targets(targets: &DetectionTargets) -> Vec<Self> {
+        Self::from_tensors(&targets.boxes, &targets.labels, None)
+    }
+
+    fn from_tensors(
+        boxes: &Tensor<2>,
+        labels: &Tensor<1, Int>,
+        scores: Option<Vec<f64>>,
+    ) -> Vec<Self> {
+        let [num_boxes, coordinates] = boxes.dims();
+        assert_eq!(coordinates, 4, "Boxes must have the shape [N, 4].");
+        assert_eq!(
+            labels.dims()[0],
+            num_boxes,
+            "Each box must have exactly one label."
+        );
+
+        let coordinates = boxes.to_data().iter::<f64>().collect::<Vec<_>>();
+        let labels = labels.to_data().iter::<i64>().collect::<Vec<_>>();
+        let scores = scores.unwrap_or_else(|| vec![1.0; num_boxes]);
+        assert_eq!(scores.len(), num_boxes, "Each box must have exactly one score.");
+
+        (0..num_boxes)
+            .map(|i| Detection {
+                bbox: [
+                    coordinates[4 * i],
+                    coordinates[4 * i + 1],
+                    coordinates[4 * i + 2],
+                    coordinates[4 * i + 3],
+                ],
+                label: labels[i],
+                score: scores[i],
+            })
+            .collect()
+    }
+
+    fn area(&self) -> f64 {
+        let [x_min, y_min, x_max, y_max] = self.bbox;
+        (x_max - x_min).max(0.0) * (y_max - y_min).max(0.0)
+    }
+
+    fn iou(&self, other: &Self) -> f64 {
+        let width = self.bbox[2].min(other.bbox[2]) - self.bbox[0].max(other.bbox[0]);
+        let height = self.bbox[3].min(other.bbox[3]) - self.bbox[1].max(other.bbox[1]);
+        let intersection = width.max(0.0) * height.max(0.0);
+        let union = self.area() + other.area() - intersection;
+
+        if union > 0.0 {
+            intersection / union
+        } else {
+            0.0
+        }
+    }
+}
+
+/// How a detection is counted at a given IoU threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    TruePositive,
+    FalsePositive,
+    /// Matched to an object outside of the area range, or unmatched and outside of it.
+    Ignored,
+}
+
+/// The matched detections of a class, for one area range.
+#[derive(Debug, Clone, Default)]
+struct AreaRecords {
+    /// Number of ground truth objects in the area range.
+    num_objects: usize,
+    scores: Vec<f64>,
+    /// For each IoU threshold, the outcome of each detection.
+    outcomes: Vec<Vec<Outcome>>,
+}
+
+impl AreaRecords {
+    /// Area under the interpolated precision-recall curve at the given IoU threshold.
+    fn average_precision(&self, threshold: usize) -> Option<f64> {
+        if self.num_objects == 0 {
+            return None;
+        }
+
+        let mut order = (0..self.scores.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| self.scores[b].total_cmp(&self.scores[a]));
+
+        let (mut tp, mut fp) = (0usize, 0usize);
+        let mut recall = Vec::with_capacity(order.len());
+        let mut precision = Vec::with_capacity(order.len());
+        for i in order {
+            match self.outcomes[threshold][i] {
+                Outcome::TruePositive => tp += 1,
+                Outcome::FalsePositive => fp += 1,
+                Outcome::Ignored => continue,
+            }
+            recall.push(tp as f64 / self.num_objects as f64);
+            precision.push(tp as f64 / (tp + fp) as f64);
+        }
+
+        // Make the precision monotonically decreasing, as in the COCO evaluation.
+        for i in (1..precision.len()).rev() {
+            precision[i - 1] = precision[i - 1].max(precision[i]);
+        }
+
+        let sum = (0..NUM_RECALL_POINTS)
+            .map(|r| {
+                let r = r as f64 / (NUM_RECALL_POINTS - 1) as f64;
+                let index = recall.partition_point(|&value| value < r);
+                precision.get(index).copied().unwrap_or(0.0)
+            })
+            .sum::<f64>();
+
+        Some(sum / NUM_RECALL_POINTS as f64)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ClassRecords {
+    areas: [AreaRecords; 4],
+}
+
+impl ClassRecords {
+    /// Average precision over a range of IoU thresholds.
+    fn average_precision(
+        &self,
+        area: ObjectArea,
+        thresholds: core::ops::Range<usize>,
+    ) -> Option<f64> {
+        let records = &self.areas[area.index()];
+        let count = thresholds.len() as f64;
+
+        thresholds
+            .map(|t| records.average_precision(t))
+            .sum::<Option<f64>>()
+            .map(|sum| sum / count)
+    }
+}
+
+/// Accumulated detection outcomes for each class.
+#[derive(Debug, Clone, Default)]
+struct MeanAveragePrecisionState {
+    num_images: usize,
+    classes: BTreeMap<i64, ClassRecords>,
+}
+
+impl MeanAveragePrecisionState {
+    fn reset(&mut self) {
+        self.num_images = 0;
+        self.classes.clear();
+    }
+
+    /// Matches the detections of an image to its ground truth objects.
+    fn update(
+        &mut self,
+        detections: Vec<Detection>,
+        objects: Vec<Detection>,
+        config: &MeanAveragePrecisionConfig,
+    ) {
+        self.num_images += 1;
+
+        let mut labels = detections
+            .iter()
+            .chain(objects.iter())
+            .map(|d| d.label)
+            .collect::<Vec<_>>();
+        labels.sort_unstable();
+        labels.dedup();
+
+        for label in labels {
+            let mut class_detections = detections
+                .iter()
+                .filter(|d| d.label == label)
+                .collect::<Vec<_>>();
+            class_detections.sort_by(|a, b| b.score.total_cmp(&a.score));
+            class_detections.truncate(config.max_detections);
+            let class_objects = objects
+                .iter()
+                .filter(|o| o.label == label)
+                .collect::<Vec<_>>();
+
+            let ious = class_detections
+                .iter()
+                .map(|d| class_objects.iter().map(|o| d.iou(o)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            let class = self.classes.entry(label).or_default();
+            for area in ObjectArea::ALL {
+                let ignored = class_objects
+                    .iter()
+                    .map(|o| !area.contains(o.area()))
+                    .collect::<Vec<_>>();
+                // Objects in the area range are matched first.
+                let mut order = (0..class_objects.len()).collect::<Vec<_>>();
+                order.sort_by_key(|&o| ignored[o]);
+
+                let records = &mut class.areas[area.index()];
+                records.num_objects += ignored.iter().filter(|&&ignored| !ignored).count();
+                records
+                    .scores
+                    .extend(class_detections.iter().map(|d| d.score));
+                records
+                    .outcomes
+                    .resize_with(config.iou_thresholds.len(), Vec::new);
+
+                let thresholds = config.iou_thresholds.iter();
+                for (threshold, outcomes) in thresholds.zip(&mut records.outcomes) {
+                    let mut matched = vec![false; class_objects.len()];
+
+                    for (d, detection) in class_detections.iter().enumerate() {
+                        let mut best_iou = threshold.min(1.0 - 1e-10);
+                        let mut best = None;
+
+                        for &o in order.iter() {
+                            if matched[o] {
+                                continue;
+                            }
+                            // Don't trade a match in the area range for one outside of it.
+                            if best.is_some_and(|b: usize| !ignored[b]) && ignored[o] {
+                                break;
+                            }
+                            if ious[d][o] < best_iou {
+                                continue;
+                            }
+                            best_iou = ious[d][o];
+                            best = Some(o);
+                        }
+
+                        outcomes.push(match best {
+                            Some(o) => {
+                                matched[o] = true;
+                                if ignored[o] {
+                                    Outcome::Ignored
+                                } else {
+                                    Outcome::TruePositive
+                                }
+                            }
+                            None if !area.contains(detection.area()) => Outcome::Ignored,
+                            None => Outcome::FalsePositive,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Mean of the average precision of the classes with ground truth in the area range.
+    fn mean_ap(&self, area: ObjectArea, thresholds: core::ops::Range<usize>) -> Option<f64> {
+        let values = self
+            .classes
+            .values()
+            .filter_map(|class| class.average_precision(area, thresholds.clone()))
+            .collect::<Vec<_>>();
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::Device;
+
+    fn predictions(
+        boxes: &[[f32; 4]],
+        scores: &[f32],
+        labels: &[i64],
+        device: &Device,
+    ) -> DetectionPredictions {
+        DetectionPredictions::new(
+            boxes_tensor(boxes, device),
+            Tensor::from_data(scores, device),
+            Tensor::from_data(labels, device),
+        )
+    }
+
+    fn targets(boxes: &[[f32; 4]], labels: &[i64], device: &Device) -> DetectionTargets {
+        DetectionTargets::new(
+            boxes_tensor(boxes, device),
+            Tensor::from_data(labels, device),
+        )
+    }
+
+    fn boxes_tensor(boxes: &[[f32; 4]], device: &Device) -> Tensor<2> {
+        let values = boxes.iter().flatten().copied().collect::<Vec<_>>();
+        Tensor::<1>::from_data(values.as_slice(), device).reshape([boxes.len(), 4])
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("Average precision should be defined");
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_map_perfect_detections() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::new();
+        let input = MeanAveragePrecisionInput::new(
+            vec![predictions(&[[0., 0., 10., 10.]], &[0.9], &[0], &device)],
+            vec![targets(&[[0., 0., 10., 10.]], &[0], &device)],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_close(Some(metric.value().current()), 1.0);
+        let summary = metric.summary();
+        assert_close(summary.map_50, 1.0);
+        assert_close(summary.map_small, 1.0);
+        assert_eq!(summary.map_medium, None);
+        assert_eq!(summary.map_large, None);
+    }
+
+    #[test]
+    fn test_map_missed_object_and_false_positive() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::new();
+        let input = MeanAveragePrecisionInput::new(
+            vec![predictions(
+                &[[0., 0., 10., 10.], [50., 50., 60., 60.]],
+                &[0.9, 0.8],
+                &[0, 0],
+                &device,
+            )],
+            vec![targets(
+                &[[0., 0., 10., 10.], [20., 20., 30., 30.]],
+                &[0, 0],
+                &device,
+            )],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Precision is 1 up to a recall of 0.5, i.e. at 51 of the 101 recall points.
+        assert_close(metric.summary().map, 51.0 / 101.0);
+    }
+
+    #[test]
+    fn test_map_iou_threshold_sweep() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::new();
+        // IoU of 0.77, matched at the thresholds 0.50 to 0.75.
+        let input = MeanAveragePrecisionInput::new(
+            vec![predictions(&[[0., 0., 10., 7.7]], &[0.9], &[0], &device)],
+            vec![targets(&[[0., 0., 10., 10.]], &[0], &device)],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        let summary = metric.summary();
+        assert_close(summary.map, 0.6);
+        assert_close(summary.map_50, 1.0);
+        assert_close(summary.map_75, 1.0);
+    }
+
+    #[test]
+    fn test_map_per_class() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::new();
+        let input = MeanAveragePrecisionInput::new(
+            vec![predictions(&[[0., 0., 10., 10.]], &[0.9], &[0], &device)],
+            vec![targets(
+                &[[0., 0., 10., 10.], [20., 20., 30., 30.]],
+                &[0, 1],
+                &device,
+            )],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        let summary = metric.summary();
+        assert_eq!(summary.per_class, BTreeMap::from([(0, 1.0), (1, 0.0)]));
+        assert_close(summary.map, 0.5);
+    }
+
+    #[test]
+    fn test_map_area_breakdown() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::with_config(MeanAveragePrecisionConfig {
+            area: ObjectArea::Large,
+            ..Default::default()
+        });
+        let input = MeanAveragePrecisionInput::new(
+            vec![predictions(&[[0., 0., 10., 10.]], &[0.9], &[0], &device)],
+            vec![targets(
+                &[[0., 0., 10., 10.], [0., 0., 100., 100.]],
+                &[0, 0],
+                &device,
+            )],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        let summary = metric.summary();
+        assert_close(summary.map, 51.0 / 101.0);
+        assert_close(summary.map_small, 1.0);
+        assert_eq!(summary.map_medium, None);
+        assert_close(summary.map_large, 0.0);
+        assert_close(Some(metric.value().current()), 0.0);
+    }
+
+    #[test]
+    fn test_map_accumulates_over_batches() {
+        let device = Default::default();
+        let images = [
+            (
+                predictions(&[[0., 0., 10., 10.]], &[0.3], &[0], &device),
+                targets(&[[0., 0., 10., 10.]], &[0], &device),
+            ),
+            (
+                predictions(&[[40., 40., 50., 50.]], &[0.9], &[0], &device),
+                targets(&[[0., 0., 10., 10.]], &[0], &device),
+            ),
+        ];
+
+        let mut streaming = MeanAveragePrecisionMetric::new();
+        for (prediction, target) in images.iter().cloned() {
+            let input = MeanAveragePrecisionInput::new(vec![prediction], vec![target]);
+            let _entry = streaming.update(&input, &MetricMetadata::fake());
+        }
+
+        let mut batched = MeanAveragePrecisionMetric::new();
+        let (predictions, targets) = images.into_iter().unzip();
+        let input = MeanAveragePrecisionInput::new(predictions, targets);
+        let _entry = batched.update(&input, &MetricMetadata::fake());
+
+        // The false positive has a higher score than the true positive in the other image.
+        assert_close(streaming.summary().map, 0.5 * 51.0 / 101.0);
+        assert_eq!(streaming.summary(), batched.summary());
+
+        streaming.clear();
+        assert_eq!(streaming.summary().map, None);
+    }
+}
@@ -3,8 +3,10 @@ mod dice;
 mod dists;
 mod fid;
 mod lpips;
+mod mean_ap;
 mod ms_ssim;
 mod psnr;
+mod segmentation;
 mod ssim;
 
 pub use afine::*;
@@ -12,6 +14,8 @@ pub use dice::*;
 pub use dists::*;
 pub use fid::*;
 pub use lpips::*;
+pub use mean_ap::*;
 pub use ms_ssim::*;
 pub use psnr::*;
+pub use segmentation::*;
 pub use ssim::*;
@@ -0,0 +1,261 @@
+use super::confusion::{SegmentationInput, SegmentationMetricConfig, mean_score};
+use crate::metric::{
+    Metric, MetricAttributes, MetricMetadata, MetricName, Numeric, NumericAttributes,
+    NumericEntry, SerializedEntry, format_float,
+};
+use burn_core::tensor::{Int, Tensor, module::max_pool2d, s};
+
+/// The boundary F-score for semantic segmentation, from `[B, H, W]` class index maps.
+///
+/// The boundary of a class is the set of its pixels that have a neighbor of another class. A
+/// boundary pixel of the outputs is a match when a boundary pixel of the targets is within
+/// `tolerance` pixels (chessboard distance), and conversely. The precision and recall of the
+/// boundary pixels are counted over all the images seen since the last [clear](Metric::clear),
+/// and the score is the mean F1 over the classes that have a boundary in the outputs or the
+/// targets.
+///
+/// The boundaries and the match counts are computed on the device, only the per-class counts
+/// are read back.
+#[derive(Clone)]
+pub struct BoundaryFScoreMetric {
+    name: MetricName,
+    config: SegmentationMetricConfig,
+    tolerance: usize,
+    /// Accumulated `[4, C]` counts of output boundary pixels, matched output boundary pixels,
+    /// target boundary pixels and matched target boundary pixels.
+    counts: Option<Tensor<2, Int>>,
+    num_items: usize,
+}
+
+impl BoundaryFScoreMetric {
+    /// Creates the metric for `num_classes` classes, with a tolerance of 2 pixels.
+    pub fn new(num_classes: usize) -> Self {
+        Self::with_config(SegmentationMetricConfig::new(num_classes))
+    }
+
+    /// Creates the metric with a custom config, with a tolerance of 2 pixels.
+    pub fn with_config(config: SegmentationMetricConfig) -> Self {
+        assert!(config.num_classes > 0, "Segmentation metrics require at least 1 class.");
+        assert!(
+            config.include_background || config.num_classes > 1,
+            "Segmentation metrics require at least 2 classes when excluding background."
+        );
+
+        Self {
+            name: MetricName::new("Boundary F-Score".to_string()),
+            config,
+            tolerance: 2,
+            counts: None,
+            num_items: 0,
+        }
+    }
+
+    /// Sets the maximum distance, in pixels, between matching boundary pixels.
+    pub fn with_tolerance(mut self, tolerance: usize) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// The boundary F-score of each class, or `None` for the classes without boundaries.
+    ///
+    /// The background class is skipped when it's excluded by the config.
+    pub fn per_class(&self) -> Vec<Option<f64>> {
+        let first = if self.config.include_background { 0 } else { 1 };
+        let num_classes = self.config.num_classes;
+        let counts = match &self.counts {
+            Some(counts) => counts.to_data().iter::<i64>().collect::<Vec<_>>(),
+            None => return vec![None; num_classes - first],
+        };
+
+        (first..num_classes)
+            .map(|class| {
+                let [outputs, outputs_matched, targets, targets_matched] =
+                    [0, 1, 2, 3].map(|row| counts[row * num_classes + class] as f64);
+                if outputs + targets == 0.0 {
+                    return None;
+                }
+
+                let precision = if outputs > 0.0 {
+                    outputs_matched / outputs
+                } else {
+                    0.0
+                };
+                let recall = if targets > 0.0 {
+                    targets_matched / targets
+                } else {
+                    0.0
+                };
+                if precision + recall == 0.0 {
+                    return Some(0.0);
+                }
+
+                Some(2.0 * precision * recall / (precision + recall))
+            })
+            .collect()
+    }
+
+    fn current(&self) -> f64 {
+        mean_score(&self.per_class())
+    }
+
+    /// The `[B, C, H, W]` boundary masks of each class.
+    fn boundaries(&self, labels: Tensor<3, Int>) -> Tensor<4> {
+        let num_classes = self.config.num_classes;
+        // Ignored pixels have the extra class `C`, which is dropped after the erosion.
+        let masks = labels
+            .one_hot::<4>(num_classes + 1)
+            .float()
+            .permute([0, 3, 1, 2]);
+        // Padded pixels are skipped by the pooling, so the image border isn't a boundary.
+        let eroded = max_pool2d(masks.clone().neg(), [3, 3], [1, 1], [1, 1], [1, 1], false).neg();
+
+        (masks - eroded).slice(s![.., 0..num_classes])
+    }
+
+    fn dilate(&self, boundaries: Tensor<4>) -> Tensor<4> {
+        let size = 2 * self.tolerance + 1;
+        let padding = self.tolerance;
+
+        max_pool2d(
+            boundaries,
+            [size, size],
+            [1, 1],
+            [padding, padding],
+            [1, 1],
+            false,
+        )
+    }
+}
+
+impl Metric for BoundaryFScoreMetric {
+    type Input = SegmentationInput<3>;
+
+    fn update(
+        &mut self,
+        input: &SegmentationInput<3>,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        assert_eq!(
+            input.outputs.dims(),
+            input.targets.dims(),
+            "Outputs and targets must have the same shape."
+        );
+
+        let mut outputs = input.outputs.clone();
+        let mut targets = input.targets.clone();
+        if let Some(ignore_index) = self.config.ignore_index {
+            let ignored = targets.clone().equal_elem(ignore_index as i64);
+            let void = self.config.num_classes as i64;
+            outputs = outputs.mask_fill(ignored.clone(), void);
+            targets = targets.mask_fill(ignored, void);
+        }
+
+        let outputs = self.boundaries(outputs);
+        let targets = self.boundaries(targets);
+        let outputs_matched = outputs.clone() * self.dilate(targets.clone());
+        let targets_matched = targets.clone() * self.dilate(outputs.clone());
+
+        let batch_size = input.outputs.dims()[0];
+        let counts = Tensor::cat(vec![outputs, outputs_matched, targets, targets_matched], 0)
+            .sum_dims(&[2, 3])
+            .reshape([4, batch_size, self.config.num_classes])
+            .sum_dim(1)
+            .reshape([4, self.config.num_classes])
+            .int();
+
+        self.counts = Some(match self.counts.take() {
+            Some(acc) => acc + counts,
+            None => counts,
+        });
+        self.num_items += batch_size;
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.counts = None;
+        self.num_items = 0;
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for BoundaryFScoreMetric {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.num_items,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Columns 0 to `split - 1` are class 0, the others are class 1.
+    fn split_labels(split: usize) -> Tensor<3, Int> {
+        let row = core::array::from_fn::<i64, 4, _>(|col| (col >= split) as i64);
+        Tensor::from_data([[row; 4]], &Default::default())
+    }
+
+    #[test]
+    fn test_boundary_fscore_perfect() {
+        let mut metric = BoundaryFScoreMetric::new(2).with_tolerance(0);
+        let input = SegmentationInput::new(split_labels(2), split_labels(2));
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(metric.per_class(), vec![Some(1.0), Some(1.0)]);
+        assert_eq!(metric.value().current(), 1.0);
+    }
+
+    #[test]
+    fn test_boundary_fscore_tolerance() {
+        let input = SegmentationInput::new(split_labels(3), split_labels(2));
+
+        // The boundaries are one pixel apart.
+        let mut metric = BoundaryFScoreMetric::new(2).with_tolerance(0);
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(metric.per_class(), vec![Some(0.0), Some(0.0)]);
+
+        let mut metric = BoundaryFScoreMetric::new(2).with_tolerance(1);
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(metric.per_class(), vec![Some(1.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_boundary_fscore_missing_boundary() {
+        let mut metric = BoundaryFScoreMetric::new(2).with_tolerance(1);
+        let input = SegmentationInput::new(split_labels(4), split_labels(2));
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // The outputs are all class 0, so no boundary is predicted.
+        assert_eq!(metric.per_class(), vec![Some(0.0), Some(0.0)]);
+
+        metric.clear();
+        assert_eq!(metric.per_class(), vec![None, None]);
+    }
+}
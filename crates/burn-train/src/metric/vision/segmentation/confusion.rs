@@ -0,0 +1,218 @@
+use burn_core::tensor::{IndexingUpdateOp, Int, Tensor};
+
+/// Input type for the semantic segmentation metrics, e.g. [MeanIoUMetric](super::MeanIoUMetric).
+///
+/// # Type Parameters
+/// - `D`: Number of dimensions, including the batch dimension (default 3, for `[B, H, W]`).
+#[derive(new, Debug, Clone)]
+pub struct SegmentationInput<const D: usize = 3> {
+    /// Predicted class index of each pixel, with shape `[B, ...]`.
+    pub outputs: Tensor<D, Int>,
+    /// Ground truth class index of each pixel, with the same shape as the outputs.
+    pub targets: Tensor<D, Int>,
+}
+
+impl<const D: usize> SegmentationInput<D> {
+    /// Creates the input from the class scores (logits or probabilities) of each pixel, with shape
+    /// `[B, C, ...]`, predicting the class with the highest score.
+    ///
+    /// # Panics
+    ///
+    /// When the scores don't have exactly one more dimension than the targets.
+    pub fn from_logits<const L: usize>(logits: Tensor<L>, targets: Tensor<D, Int>) -> Self {
+        assert_eq!(
+            L,
+            D + 1,
+            "The logits must have a class dimension after the batch dimension."
+        );
+
+        Self::new(logits.argmax(1).squeeze_dim::<D>(1), targets)
+    }
+}
+
+/// Configuration for the semantic segmentation metrics.
+#[derive(Debug, Clone, Copy)]
+pub struct SegmentationMetricConfig {
+    /// The number of classes.
+    pub num_classes: usize,
+    /// Target class index of the pixels that are excluded from the metric, e.g. `255` for void.
+    pub ignore_index: Option<usize>,
+    /// Whether the first class (index 0) is included in the mean over classes.
+    pub include_background: bool,
+}
+
+impl SegmentationMetricConfig {
+    /// Creates a config for `num_classes` classes, without ignored pixels and with background.
+    pub fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            ignore_index: None,
+            include_background: true,
+        }
+    }
+
+    /// Excludes the pixels with the given target class index.
+    pub fn with_ignore_index(mut self, ignore_index: usize) -> Self {
+        self.ignore_index = Some(ignore_index);
+        self
+    }
+
+    /// Sets whether the first class is included in the mean over classes.
+    pub fn with_include_background(mut self, include_background: bool) -> Self {
+        self.include_background = include_background;
+        self
+    }
+}
+
+/// Pixel counts of each class, used to compute the per-class scores.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ClassCounts {
+    pub true_positive: u64,
+    pub false_positive: u64,
+    pub false_negative: u64,
+}
+
+impl ClassCounts {
+    /// The intersection over union, `TP / (TP + FP + FN)`.
+    pub fn iou(&self) -> f64 {
+        self.true_positive as f64
+            / (self.true_positive + self.false_positive + self.false_negative) as f64
+    }
+}
+
+/// Confusion matrix accumulated on the device across batches.
+///
+/// Only the `[C, C]` matrix is read back when a score is computed, so the pixel labels never
+/// leave the device.
+#[derive(Clone)]
+pub(crate) struct ConfusionState {
+    config: SegmentationMetricConfig,
+    /// Accumulated confusion matrix, indexed by `[target, output]`.
+    matrix: Option<Tensor<2, Int>>,
+    /// The number of accumulated items.
+    pub num_items: usize,
+}
+
+impl ConfusionState {
+    pub fn new(config: SegmentationMetricConfig) -> Self {
+        assert!(config.num_classes > 0, "Segmentation metrics require at least 1 class.");
+        assert!(
+            config.include_background || config.num_classes > 1,
+            "Segmentation metrics require at least 2 classes when excluding background."
+        );
+
+        Self {
+            config,
+            matrix: None,
+            num_items: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.matrix = None;
+        self.num_items = 0;
+    }
+
+    pub fn update<const D: usize>(&mut self, input: &SegmentationInput<D>) {
+        assert_eq!(
+            input.outputs.dims(),
+            input.targets.dims(),
+            "Outputs and targets must have the same shape."
+        );
+
+        let batch = confusion_matrix(
+            input.outputs.clone(),
+            input.targets.clone(),
+            self.config.num_classes,
+            self.config.ignore_index,
+        );
+
+        self.matrix = Some(match self.matrix.take() {
+            Some(matrix) => matrix + batch,
+            None => batch,
+        });
+        self.num_items += input.outputs.dims()[0];
+    }
+
+    /// The pixel counts of each class included in the mean.
+    ///
+    /// Classes that appear neither in the outputs nor in the targets are `None`.
+    pub fn class_counts(&self) -> Vec<Option<ClassCounts>> {
+        let num_classes = self.config.num_classes;
+        let first = if self.config.include_background { 0 } else { 1 };
+        let Some(matrix) = self.read_matrix() else {
+            return vec![None; num_classes - first];
+        };
+
+        (first..num_classes)
+            .map(|class| counts_of(&matrix, num_classes, class))
+            .collect()
+    }
+
+    /// The pixel counts of a single class, whether or not it's included in the mean.
+    ///
+    /// Returns `None` when the class appears neither in the outputs nor in the targets.
+    pub fn class_count(&self, class: usize) -> Option<ClassCounts> {
+        counts_of(&self.read_matrix()?, self.config.num_classes, class)
+    }
+
+    fn read_matrix(&self) -> Option<Vec<i64>> {
+        let matrix = self.matrix.as_ref()?;
+
+        Some(matrix.to_data().iter::<i64>().collect())
+    }
+}
+
+/// The pixel counts of a class, from the flattened `[target, output]` confusion matrix.
+fn counts_of(matrix: &[i64], num_classes: usize, class: usize) -> Option<ClassCounts> {
+    let true_positive = matrix[class * num_classes + class] as u64;
+    let targets = (0..num_classes)
+        .map(|output| matrix[class * num_classes + output] as u64)
+        .sum::<u64>();
+    let outputs = (0..num_classes)
+        .map(|target| matrix[target * num_classes + class] as u64)
+        .sum::<u64>();
+
+    (targets + outputs > 0).then_some(ClassCounts {
+        true_positive,
+        false_positive: outputs - true_positive,
+        false_negative: targets - true_positive,
+    })
+}
+
+/// The mean of the per-class scores, ignoring absent classes.
+pub(crate) fn mean_score(scores: &[Option<f64>]) -> f64 {
+    let present = scores.iter().flatten().copied().collect::<Vec<_>>();
+    if present.is_empty() {
+        return f64::NAN;
+    }
+
+    present.iter().sum::<f64>() / present.len() as f64
+}
+
+/// Counts the pixels of each `(target, output)` class pair with a scatter-add on the device.
+fn confusion_matrix<const D: usize>(
+    outputs: Tensor<D, Int>,
+    targets: Tensor<D, Int>,
+    num_classes: usize,
+    ignore_index: Option<usize>,
+) -> Tensor<2, Int> {
+    let device = outputs.device();
+    let num_pixels = outputs.shape().num_elements();
+    let num_bins = num_classes * num_classes;
+
+    let outputs = outputs.reshape([num_pixels]);
+    let targets = targets.reshape([num_pixels]);
+    let mut bins = targets.clone().mul_scalar(num_classes as i64).add(outputs);
+
+    // Ignored pixels are counted in an extra bin, which is dropped.
+    if let Some(ignore_index) = ignore_index {
+        bins = bins.mask_fill(targets.equal_elem(ignore_index as i64), num_bins as i64);
+    }
+
+    let values = bins.ones_like();
+    Tensor::<1, Int>::zeros([num_bins + 1], &device)
+        .scatter(0, bins, values, IndexingUpdateOp::Add)
+        .slice([0..num_bins])
+        .reshape([num_classes, num_classes])
+}
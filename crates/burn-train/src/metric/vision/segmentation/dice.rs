@@ -0,0 +1,152 @@
+use super::confusion::{ConfusionState, SegmentationInput, SegmentationMetricConfig, mean_score};
+use crate::metric::{
+    Metric, MetricAttributes, MetricMetadata, MetricName, Numeric, NumericAttributes,
+    NumericEntry, SerializedEntry, format_float,
+};
+
+/// The mean Dice score for semantic segmentation, from class index maps.
+///
+/// The Dice score of a class is `2 * TP / (2 * TP + FP + FN)`, counted over all the pixels seen
+/// since the last [clear](Metric::clear), and the mean is taken over the classes that appear in
+/// the outputs or the targets. Unlike [DiceMetric](crate::metric::vision::DiceMetric), which
+/// averages the score of one-hot masks per batch, the confusion matrix is accumulated on the
+/// device across batches.
+///
+/// # Type Parameters
+/// - `D`: Number of dimensions of the inputs, including the batch dimension (default 3).
+#[derive(Clone)]
+pub struct MeanDiceMetric<const D: usize = 3> {
+    name: MetricName,
+    state: ConfusionState,
+}
+
+impl<const D: usize> MeanDiceMetric<D> {
+    /// Creates the metric for `num_classes` classes.
+    pub fn new(num_classes: usize) -> Self {
+        Self::with_config(SegmentationMetricConfig::new(num_classes))
+    }
+
+    /// Creates the metric with a custom config.
+    pub fn with_config(config: SegmentationMetricConfig) -> Self {
+        Self {
+            name: MetricName::new("Mean Dice".to_string()),
+            state: ConfusionState::new(config),
+        }
+    }
+
+    /// The Dice score of each class, or `None` for the classes that haven't been seen.
+    ///
+    /// The background class is skipped when it's excluded by the config.
+    pub fn per_class(&self) -> Vec<Option<f64>> {
+        self.state
+            .class_counts()
+            .into_iter()
+            .map(|counts| {
+                counts.map(|counts| {
+                    let true_positive = 2 * counts.true_positive;
+                    true_positive as f64
+                        / (true_positive + counts.false_positive + counts.false_negative) as f64
+                })
+            })
+            .collect()
+    }
+
+    fn current(&self) -> f64 {
+        mean_score(&self.per_class())
+    }
+}
+
+impl<const D: usize> Metric for MeanDiceMetric<D> {
+    type Input = SegmentationInput<D>;
+
+    fn update(
+        &mut self,
+        input: &SegmentationInput<D>,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        self.state.update(input);
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.state.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl<const D: usize> Numeric for MeanDiceMetric<D> {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.state.num_items,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_mean_dice_partial_overlap() {
+        let device = Default::default();
+        let mut metric = MeanDiceMetric::<3>::new(2);
+        let input = SegmentationInput::new(
+            Tensor::from_data([[[1, 1], [0, 0]]], &device),
+            Tensor::from_data([[[1, 0], [1, 0]]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Each class has 1 true positive, 1 false positive and 1 false negative.
+        assert_eq!(metric.per_class(), vec![Some(0.5), Some(0.5)]);
+        assert_eq!(metric.value().current(), 0.5);
+    }
+
+    #[test]
+    fn test_mean_dice_without_background() {
+        let device = Default::default();
+        let config = SegmentationMetricConfig::new(3).with_include_background(false);
+        let mut metric = MeanDiceMetric::<3>::with_config(config);
+        let input = SegmentationInput::new(
+            Tensor::from_data([[[0, 1], [2, 2]]], &device),
+            Tensor::from_data([[[0, 1], [2, 1]]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Class 1: 2 * 1 / (2 * 1 + 0 + 1). Class 2: 2 * 1 / (2 * 1 + 1 + 0).
+        assert_eq!(metric.per_class(), vec![Some(2.0 / 3.0), Some(2.0 / 3.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "require at least 2 classes when excluding background")]
+    fn test_mean_dice_background_only() {
+        let config = SegmentationMetricConfig::new(1).with_include_background(false);
+        let _metric = MeanDiceMetric::<3>::with_config(config);
+    }
+}
@@ -0,0 +1,298 @@
+use super::confusion::{ConfusionState, SegmentationInput, SegmentationMetricConfig, mean_score};
+use crate::metric::{
+    Metric, MetricAttributes, MetricMetadata, MetricName, Numeric, NumericAttributes,
+    NumericEntry, SerializedEntry, format_float,
+};
+
+/// The mean intersection over union (mIoU) for semantic segmentation.
+///
+/// The IoU of a class is `TP / (TP + FP + FN)`, counted over all the pixels seen since the last
+/// [clear](Metric::clear), and the mIoU is the mean over the classes that appear in the outputs
+/// or the targets. The confusion matrix is accumulated on the device.
+///
+/// # Type Parameters
+/// - `D`: Number of dimensions of the inputs, including the batch dimension (default 3).
+#[derive(Clone)]
+pub struct MeanIoUMetric<const D: usize = 3> {
+    name: MetricName,
+    state: ConfusionState,
+}
+
+impl<const D: usize> MeanIoUMetric<D> {
+    /// Creates the metric for `num_classes` classes.
+    pub fn new(num_classes: usize) -> Self {
+        Self::with_config(SegmentationMetricConfig::new(num_classes))
+    }
+
+    /// Creates the metric with a custom config.
+    pub fn with_config(config: SegmentationMetricConfig) -> Self {
+        Self {
+            name: MetricName::new("Mean IoU".to_string()),
+            state: ConfusionState::new(config),
+        }
+    }
+
+    /// The IoU of each class, or `None` for the classes that haven't been seen.
+    ///
+    /// The background class is skipped when it's excluded by the config.
+    pub fn per_class(&self) -> Vec<Option<f64>> {
+        self.state
+            .class_counts()
+            .into_iter()
+            .map(|counts| counts.map(|counts| counts.iou()))
+            .collect()
+    }
+
+    fn current(&self) -> f64 {
+        mean_score(&self.per_class())
+    }
+}
+
+impl<const D: usize> Metric for MeanIoUMetric<D> {
+    type Input = SegmentationInput<D>;
+
+    fn update(
+        &mut self,
+        input: &SegmentationInput<D>,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        self.state.update(input);
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.state.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl<const D: usize> Numeric for MeanIoUMetric<D> {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.state.num_items,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+/// The intersection over union (IoU) of a single class for semantic segmentation, to track the
+/// classes that lag behind the [mean IoU](MeanIoUMetric).
+///
+/// The IoU is `TP / (TP + FP + FN)`, counted over all the pixels seen since the last
+/// [clear](Metric::clear), and is undefined (NaN) while the class appears neither in the outputs
+/// nor in the targets. The confusion matrix is accumulated on the device.
+///
+/// # Type Parameters
+/// - `D`: Number of dimensions of the inputs, including the batch dimension (default 3).
+#[derive(Clone)]
+pub struct ClassIoUMetric<const D: usize = 3> {
+    name: MetricName,
+    class: usize,
+    state: ConfusionState,
+}
+
+impl<const D: usize> ClassIoUMetric<D> {
+    /// Creates the metric for the given class, out of `num_classes` classes.
+    pub fn new(num_classes: usize, class: usize) -> Self {
+        Self::with_config(SegmentationMetricConfig::new(num_classes), class)
+    }
+
+    /// Creates the metric for the given class with a custom config.
+    ///
+    /// The class is tracked even when it's the background class excluded by the config.
+    pub fn with_config(config: SegmentationMetricConfig, class: usize) -> Self {
+        assert!(
+            class < config.num_classes,
+            "The class {class} is out of range for {} classes.",
+            config.num_classes
+        );
+
+        Self {
+            name: MetricName::new(format!("IoU [class {class}]")),
+            class,
+            state: ConfusionState::new(config),
+        }
+    }
+
+    fn current(&self) -> f64 {
+        self.state
+            .class_count(self.class)
+            .map_or(f64::NAN, |counts| counts.iou())
+    }
+}
+
+impl<const D: usize> Metric for ClassIoUMetric<D> {
+    type Input = SegmentationInput<D>;
+
+    fn update(
+        &mut self,
+        input: &SegmentationInput<D>,
+        _metadata: &MetricMetadata,
+    ) -> SerializedEntry {
+        self.state.update(input);
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.state.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl<const D: usize> Numeric for ClassIoUMetric<D> {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.state.num_items,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_mean_iou_perfect() {
+        let device = Default::default();
+        let mut metric = MeanIoUMetric::<3>::new(3);
+        let labels = Tensor::from_data([[[0, 1], [2, 2]]], &device);
+        let input = SegmentationInput::new(labels.clone(), labels);
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(metric.per_class(), vec![Some(1.0), Some(1.0), Some(1.0)]);
+        assert!((metric.value().current() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mean_iou_partial_overlap() {
+        let device = Default::default();
+        let mut metric = MeanIoUMetric::<3>::new(2);
+        let input = SegmentationInput::new(
+            Tensor::from_data([[[1, 1], [0, 0]]], &device),
+            Tensor::from_data([[[1, 0], [1, 0]]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Each class has 1 true positive, 1 false positive and 1 false negative.
+        assert_eq!(metric.per_class(), vec![Some(1.0 / 3.0), Some(1.0 / 3.0)]);
+        assert!((metric.value().current() - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mean_iou_accumulates_batches() {
+        let device = Default::default();
+        let mut metric = MeanIoUMetric::<3>::new(2);
+        let first = SegmentationInput::new(
+            Tensor::from_data([[[1, 1]]], &device),
+            Tensor::from_data([[[1, 1]]], &device),
+        );
+        let second = SegmentationInput::new(
+            Tensor::from_data([[[1, 0]]], &device),
+            Tensor::from_data([[[1, 1]]], &device),
+        );
+
+        let _entry = metric.update(&first, &MetricMetadata::fake());
+        let _entry = metric.update(&second, &MetricMetadata::fake());
+
+        // Class 0 is never a target: 0 / 1. Class 1: 3 / 4.
+        assert_eq!(metric.per_class(), vec![Some(0.0), Some(0.75)]);
+        assert_eq!(metric.value().current(), 0.375);
+
+        metric.clear();
+        assert_eq!(metric.per_class(), vec![None, None]);
+    }
+
+    #[test]
+    fn test_mean_iou_ignore_index_and_absent_classes() {
+        let device = Default::default();
+        let config = SegmentationMetricConfig::new(3)
+            .with_ignore_index(255)
+            .with_include_background(false);
+        let mut metric = MeanIoUMetric::<3>::with_config(config);
+        let input = SegmentationInput::new(
+            Tensor::from_data([[[1, 1], [0, 1]]], &device),
+            Tensor::from_data([[[1, 255], [0, 255]]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(metric.per_class(), vec![Some(1.0), None]);
+        assert_eq!(metric.value().current(), 1.0);
+    }
+
+    #[test]
+    fn test_class_iou_from_logits() {
+        let device = Default::default();
+        let config = SegmentationMetricConfig::new(3).with_ignore_index(255);
+        let mut background = ClassIoUMetric::<3>::with_config(config, 0);
+        let mut absent = ClassIoUMetric::<3>::with_config(config, 2);
+        // Predicts the classes [[1, 0], [0, 1]].
+        let logits = Tensor::<4>::from_data(
+            [[
+                [[0.1, 0.8], [0.7, 0.2]],
+                [[0.9, 0.1], [0.2, 0.6]],
+                [[0.0, 0.1], [0.1, 0.2]],
+            ]],
+            &device,
+        );
+        let targets = Tensor::from_data([[[1, 0], [1, 255]]], &device);
+        let input = SegmentationInput::from_logits(logits, targets);
+
+        let _entry = background.update(&input, &MetricMetadata::fake());
+        let _entry = absent.update(&input, &MetricMetadata::fake());
+
+        // Class 0: 1 true positive and 1 false positive, the ignored pixel isn't counted.
+        assert_eq!(background.value().current(), 0.5);
+        assert!(absent.value().current().is_nan());
+    }
+}
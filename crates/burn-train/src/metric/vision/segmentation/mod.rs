@@ -0,0 +1,11 @@
+mod boundary;
+mod confusion;
+mod dice;
+mod iou;
+mod panoptic;
+
+pub use boundary::*;
+pub use confusion::{SegmentationInput, SegmentationMetricConfig};
+pub use dice::*;
+pub use iou::*;
+pub use panoptic::*;
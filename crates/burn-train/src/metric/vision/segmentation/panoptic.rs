@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+
+use crate::metric::{
+    Metric, MetricAttributes, MetricMetadata, MetricName, Numeric, NumericAttributes,
+    NumericEntry, SerializedEntry, format_float,
+};
+use burn_core::tensor::{Int, Tensor};
+
+/// Input type for the [PanopticQualityMetric].
+#[derive(new, Debug, Clone)]
+pub struct PanopticInput {
+    /// Predicted segment id of each pixel, with shape `[B, H, W]`.
+    pub outputs: Tensor<3, Int>,
+    /// Ground truth segment id of each pixel, with shape `[B, H, W]`.
+    pub targets: Tensor<3, Int>,
+}
+
+/// Configuration for the [PanopticQualityMetric].
+#[derive(Debug, Clone, Copy)]
+pub struct PanopticQualityConfig {
+    /// The number of classes.
+    pub num_classes: usize,
+    /// Segment ids are `class * label_divisor + instance`.
+    pub label_divisor: i64,
+    /// Segment id of the unlabeled pixels, which are excluded from the metric.
+    pub void_id: Option<i64>,
+}
+
+impl PanopticQualityConfig {
+    /// Creates a config for `num_classes` classes, with a label divisor of 1000 and no void id.
+    pub fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            label_divisor: 1000,
+            void_id: None,
+        }
+    }
+
+    /// Sets the divisor used to get the class of a segment id.
+    pub fn with_label_divisor(mut self, label_divisor: i64) -> Self {
+        self.label_divisor = label_divisor;
+        self
+    }
+
+    /// Sets the segment id of the unlabeled pixels.
+    pub fn with_void_id(mut self, void_id: i64) -> Self {
+        self.void_id = Some(void_id);
+        self
+    }
+}
+
+/// The panoptic quality (PQ), segmentation quality (SQ) and recognition quality (RQ).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanopticQuality {
+    /// Mean panoptic quality over the classes.
+    pub pq: f64,
+    /// Mean segmentation quality over the classes.
+    pub sq: f64,
+    /// Mean recognition quality over the classes.
+    pub rq: f64,
+}
+
+/// The panoptic quality metric, from `[B, H, W]` maps of segment ids.
+///
+/// A predicted segment matches a ground truth segment of the same class when their IoU is
+/// greater than 0.5, which makes the matching unique. For each class,
+/// `PQ = Σ IoU(TP) / (|TP| + |FP| / 2 + |FN| / 2)`, which is the product of the mean IoU of the
+/// matches (SQ) and of the F1 score of the segments (RQ). The metric is the mean PQ over the
+/// classes seen since the last [clear](Metric::clear).
+///
+/// As in COCO, void pixels are excluded from the IoU, and unmatched predicted segments that are
+/// mostly void aren't false positives. The segment overlaps of each image are counted on the
+/// host, only the per-class sums are accumulated.
+#[derive(Clone)]
+pub struct PanopticQualityMetric {
+    name: MetricName,
+    config: PanopticQualityConfig,
+    classes: Vec<ClassQuality>,
+    num_items: usize,
+}
+
+/// The accumulated matches of a class.
+#[derive(Debug, Clone, Copy, Default)]
+struct ClassQuality {
+    iou_sum: f64,
+    true_positive: u64,
+    false_positive: u64,
+    false_negative: u64,
+}
+
+impl ClassQuality {
+    fn is_present(&self) -> bool {
+        self.true_positive + self.false_positive + self.false_negative > 0
+    }
+
+    fn segmentation_quality(&self) -> f64 {
+        if self.true_positive == 0 {
+            return 0.0;
+        }
+
+        self.iou_sum / self.true_positive as f64
+    }
+
+    fn recognition_quality(&self) -> f64 {
+        let tp = self.true_positive as f64;
+        tp / (tp + 0.5 * self.false_positive as f64 + 0.5 * self.false_negative as f64)
+    }
+
+    fn panoptic_quality(&self) -> f64 {
+        self.segmentation_quality() * self.recognition_quality()
+    }
+}
+
+impl PanopticQualityMetric {
+    /// Creates the metric for `num_classes` classes.
+    pub fn new(num_classes: usize) -> Self {
+        Self::with_config(PanopticQualityConfig::new(num_classes))
+    }
+
+    /// Creates the metric with a custom config.
+    pub fn with_config(config: PanopticQualityConfig) -> Self {
+        assert!(config.label_divisor > 0, "The label divisor must be positive.");
+
+        Self {
+            name: MetricName::new("Panoptic Quality".to_string()),
+            classes: vec![ClassQuality::default(); config.num_classes],
+            config,
+            num_items: 0,
+        }
+    }
+
+    /// The panoptic quality of each class, or `None` for the classes that haven't been seen.
+    pub fn per_class(&self) -> Vec<Option<f64>> {
+        self.classes
+            .iter()
+            .map(|class| class.is_present().then(|| class.panoptic_quality()))
+            .collect()
+    }
+
+    /// The mean panoptic, segmentation and recognition quality over the classes seen.
+    pub fn summary(&self) -> PanopticQuality {
+        let present = self
+            .classes
+            .iter()
+            .filter(|class| class.is_present())
+            .collect::<Vec<_>>();
+        if present.is_empty() {
+            return PanopticQuality {
+                pq: f64::NAN,
+                sq: f64::NAN,
+                rq: f64::NAN,
+            };
+        }
+
+        let mean = |score: fn(&ClassQuality) -> f64| {
+            present.iter().map(|&class| score(class)).sum::<f64>() / present.len() as f64
+        };
+
+        PanopticQuality {
+            pq: mean(ClassQuality::panoptic_quality),
+            sq: mean(ClassQuality::segmentation_quality),
+            rq: mean(ClassQuality::recognition_quality),
+        }
+    }
+
+    fn update_image(&mut self, outputs: &[i64], targets: &[i64]) {
+        let void = self.config.void_id;
+        let mut output_areas = HashMap::<i64, u64>::new();
+        let mut target_areas = HashMap::<i64, u64>::new();
+        let mut intersections = HashMap::<(i64, i64), u64>::new();
+        // Pixels of each predicted segment that are void in the targets.
+        let mut void_areas = HashMap::<i64, u64>::new();
+
+        for (&output, &target) in outputs.iter().zip(targets) {
+            let output_void = Some(output) == void;
+            let target_void = Some(target) == void;
+
+            if !target_void {
+                *target_areas.entry(target).or_default() += 1;
+            }
+            if output_void {
+                continue;
+            }
+
+            *output_areas.entry(output).or_default() += 1;
+            if target_void {
+                *void_areas.entry(output).or_default() += 1;
+            } else {
+                *intersections.entry((output, target)).or_default() += 1;
+            }
+        }
+
+        let mut matched_outputs = Vec::new();
+        let mut matched_targets = Vec::new();
+        for (&(output, target), &intersection) in intersections.iter() {
+            let class = self.class_of(output);
+            if class != self.class_of(target) {
+                continue;
+            }
+
+            let union = output_areas[&output] + target_areas[&target]
+                - intersection
+                - void_areas.get(&output).copied().unwrap_or(0);
+            let iou = intersection as f64 / union as f64;
+            if iou > 0.5 {
+                let quality = &mut self.classes[class];
+                quality.iou_sum += iou;
+                quality.true_positive += 1;
+                matched_outputs.push(output);
+                matched_targets.push(target);
+            }
+        }
+
+        for &target in target_areas.keys() {
+            if !matched_targets.contains(&target) {
+                let class = self.class_of(target);
+                self.classes[class].false_negative += 1;
+            }
+        }
+
+        for (&output, &area) in output_areas.iter() {
+            let void_area = void_areas.get(&output).copied().unwrap_or(0);
+            if !matched_outputs.contains(&output) && void_area * 2 <= area {
+                let class = self.class_of(output);
+                self.classes[class].false_positive += 1;
+            }
+        }
+    }
+
+    fn class_of(&self, segment: i64) -> usize {
+        let class = segment.div_euclid(self.config.label_divisor);
+        assert!(
+            segment >= 0 && (class as usize) < self.config.num_classes,
+            "Segment id {segment} has class {class}, expected a class lower than {}",
+            self.config.num_classes
+        );
+
+        class as usize
+    }
+
+    fn current(&self) -> f64 {
+        self.summary().pq
+    }
+}
+
+impl Metric for PanopticQualityMetric {
+    type Input = PanopticInput;
+
+    fn update(&mut self, input: &PanopticInput, _metadata: &MetricMetadata) -> SerializedEntry {
+        let [batch_size, height, width] = input.outputs.dims();
+        assert_eq!(
+            input.outputs.dims(),
+            input.targets.dims(),
+            "Outputs and targets must have the same shape."
+        );
+
+        let outputs = input.outputs.to_data().iter::<i64>().collect::<Vec<_>>();
+        let targets = input.targets.to_data().iter::<i64>().collect::<Vec<_>>();
+        let image_size = height * width;
+        for (outputs, targets) in outputs.chunks(image_size).zip(targets.chunks(image_size)) {
+            self.update_image(outputs, targets);
+        }
+        self.num_items += batch_size;
+
+        let value = self.current();
+        let serialized = NumericEntry::Aggregated {
+            aggregated_value: value,
+            count: self.num_items,
+        }
+        .serialize();
+
+        SerializedEntry::new(format_float(value, 4), serialized)
+    }
+
+    fn clear(&mut self) {
+        self.classes = vec![ClassQuality::default(); self.config.num_classes];
+        self.num_items = 0;
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: true,
+        }
+        .into()
+    }
+}
+
+impl Numeric for PanopticQualityMetric {
+    fn value(&self) -> NumericEntry {
+        NumericEntry::Aggregated {
+            aggregated_value: self.current(),
+            count: self.num_items,
+        }
+    }
+
+    fn running_value(&self) -> NumericEntry {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(outputs: [[i64; 4]; 2], targets: [[i64; 4]; 2]) -> PanopticInput {
+        let device = Default::default();
+        PanopticInput::new(
+            Tensor::from_data([outputs], &device),
+            Tensor::from_data([targets], &device),
+        )
+    }
+
+    #[test]
+    fn test_panoptic_quality_perfect() {
+        let config = PanopticQualityConfig::new(2).with_label_divisor(10);
+        let mut metric = PanopticQualityMetric::with_config(config);
+        let segments = [[0, 0, 10, 11], [0, 0, 10, 11]];
+
+        let _entry = metric.update(&input(segments, segments), &MetricMetadata::fake());
+
+        assert_eq!(metric.per_class(), vec![Some(1.0), Some(1.0)]);
+        assert_eq!(
+            metric.summary(),
+            PanopticQuality {
+                pq: 1.0,
+                sq: 1.0,
+                rq: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_panoptic_quality_partial_match() {
+        let config = PanopticQualityConfig::new(2).with_label_divisor(10);
+        let mut metric = PanopticQualityMetric::with_config(config);
+        // Segment 10 covers 3 of the 4 pixels of the target segment 10, and the target segment
+        // 11 is missed.
+        let outputs = [[0, 0, 10, 10], [0, 0, 10, 0]];
+        let targets = [[0, 0, 10, 10], [0, 0, 10, 11]];
+
+        let _entry = metric.update(&input(outputs, targets), &MetricMetadata::fake());
+
+        // Class 0: IoU 4/5. Class 1: IoU 3/3 with one false negative.
+        let per_class = metric.per_class();
+        assert!((per_class[0].unwrap() - 0.8).abs() < 1e-9);
+        assert!((per_class[1].unwrap() - 1.0 / 1.5).abs() < 1e-9);
+        let summary = metric.summary();
+        assert!((summary.sq - 0.9).abs() < 1e-9);
+        assert!((summary.rq - (1.0 + 1.0 / 1.5) / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_panoptic_quality_void() {
+        let config = PanopticQualityConfig::new(2)
+            .with_label_divisor(10)
+            .with_void_id(-1);
+        let mut metric = PanopticQualityMetric::with_config(config);
+        // The predicted segment 11 only covers void pixels, so it isn't a false positive.
+        let outputs = [[10, 10, 11, 11], [10, 10, 11, 11]];
+        let targets = [[10, 10, -1, -1], [10, 10, -1, -1]];
+
+        let _entry = metric.update(&input(outputs, targets), &MetricMetadata::fake());
+
+        assert_eq!(metric.per_class(), vec![None, Some(1.0)]);
+        assert_eq!(metric.value().current(), 1.0);
+
+        metric.clear();
+        assert_eq!(metric.per_class(), vec![None, None]);
+    }
+}
@@ -0,0 +1,219 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::tensor::{Shape, TensorData};
+
+use crate::{BilateralFilterOptions, BilateralMethod};
+
+/// Largest window radius filtered exactly with [`BilateralMethod::Auto`].
+const AUTO_EXACT_MAX_RADIUS: usize = 5;
+
+/// Binomial approximation of a Gaussian kernel of unit standard deviation, used to blur the
+/// bilateral grid.
+const GRID_KERNEL: [f32; 5] = [1.0 / 16.0, 4.0 / 16.0, 6.0 / 16.0, 4.0 / 16.0, 1.0 / 16.0];
+
+/// Empty cells around the bilateral grid, so that the blur never reaches its borders.
+const GRID_PADDING: usize = GRID_KERNEL.len() / 2;
+
+/// Smooths each image of a `[batches, height, width]` tensor with a bilateral filter on CPU, like
+/// `opencv`'s `bilateralFilter`.
+///
+/// Each pixel is the average of its window, weighted by a Gaussian of the distance to the pixel
+/// and a Gaussian of the difference with its value. The window is clipped at the borders of the
+/// image.
+pub fn bilateral_filter(images: TensorData, options: BilateralFilterOptions) -> TensorData {
+    let [batch_size, height, width] = images.shape.dims();
+    let dtype = images.dtype;
+    assert!(
+        options.sigma_color > 0.0 && options.sigma_space > 0.0,
+        "Bilateral filter expects positive sigmas, got {} and {}",
+        options.sigma_color,
+        options.sigma_space
+    );
+
+    let radius = if options.diameter > 0 {
+        options.diameter / 2
+    } else {
+        (options.sigma_space * 1.5).round() as usize
+    };
+    let use_grid = match options.method {
+        BilateralMethod::Auto => radius > AUTO_EXACT_MAX_RADIUS,
+        BilateralMethod::Exact => false,
+        BilateralMethod::Grid => true,
+    };
+
+    let images: Vec<f32> = images.convert::<f32>().to_vec().unwrap();
+    let mut output = Vec::with_capacity(images.len());
+    for image in images.chunks_exact((height * width).max(1)) {
+        if use_grid {
+            output.extend(bilateral_grid(image, height, width, options));
+        } else {
+            output.extend(bilateral_exact(image, height, width, radius, options));
+        }
+    }
+
+    TensorData::new(output, Shape::new([batch_size, height, width])).convert_dtype(dtype)
+}
+
+/// Sums over the circular window of `radius` pixels around each pixel.
+fn bilateral_exact(
+    image: &[f32],
+    height: usize,
+    width: usize,
+    radius: usize,
+    options: BilateralFilterOptions,
+) -> Vec<f32> {
+    let color_coeff = -0.5 / (options.sigma_color * options.sigma_color);
+    let space_coeff = -0.5 / (options.sigma_space * options.sigma_space);
+    let radius = radius as isize;
+
+    // Offsets of the window and their spatial weights.
+    let window: Vec<(isize, isize, f32)> = (-radius..=radius)
+        .flat_map(|dy| (-radius..=radius).map(move |dx| (dy, dx)))
+        .filter(|(dy, dx)| dy * dy + dx * dx <= radius * radius)
+        .map(|(dy, dx)| (dy, dx, ((dy * dy + dx * dx) as f32 * space_coeff).exp()))
+        .collect();
+
+    let mut output = Vec::with_capacity(image.len());
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            let center = image[y as usize * width + x as usize];
+            let mut sum = 0.0;
+            let mut norm = 0.0;
+
+            for &(dy, dx, space_weight) in &window {
+                let (y, x) = (y + dy, x + dx);
+                if !(0..height as isize).contains(&y) || !(0..width as isize).contains(&x) {
+                    continue;
+                }
+                let value = image[y as usize * width + x as usize];
+                let diff = value - center;
+                let weight = space_weight * (diff * diff * color_coeff).exp();
+                sum += weight * value;
+                norm += weight;
+            }
+
+            output.push(sum / norm);
+        }
+    }
+    output
+}
+
+/// Approximates the filter with the bilateral grid of Paris and Durand.
+///
+/// The pixels are accumulated in a 3D grid downsampled by `sigma_space` in space and by
+/// `sigma_color` in value, the grid is blurred, and each pixel is read back at its position
+/// and value. The cost doesn't depend on the window size, and the window is a Gaussian rather
+/// than a disk.
+fn bilateral_grid(
+    image: &[f32],
+    height: usize,
+    width: usize,
+    options: BilateralFilterOptions,
+) -> Vec<f32> {
+    let (min, max) = image
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+    let sigma_space = options.sigma_space;
+    let sigma_color = options.sigma_color;
+    let coords = |y: usize, x: usize, value: f32| {
+        [
+            y as f32 / sigma_space + GRID_PADDING as f32,
+            x as f32 / sigma_space + GRID_PADDING as f32,
+            (value - min) / sigma_color + GRID_PADDING as f32,
+        ]
+    };
+
+    let dims = [
+        ((height - 1) as f32 / sigma_space) as usize + 1 + 2 * GRID_PADDING,
+        ((width - 1) as f32 / sigma_space) as usize + 1 + 2 * GRID_PADDING,
+        ((max - min) / sigma_color) as usize + 1 + 2 * GRID_PADDING,
+    ];
+    let strides = [dims[1] * dims[2], dims[2], 1];
+
+    // Each cell holds the sum of the values and the number of pixels accumulated in it.
+    let mut grid = vec![[0.0f32; 2]; dims.iter().product()];
+    for y in 0..height {
+        for x in 0..width {
+            let value = image[y * width + x];
+            let cell: usize = coords(y, x, value)
+                .iter()
+                .zip(strides)
+                .map(|(coord, stride)| coord.round() as usize * stride)
+                .sum();
+            grid[cell][0] += value;
+            grid[cell][1] += 1.0;
+        }
+    }
+
+    for (size, stride) in dims.into_iter().zip(strides) {
+        blur_axis(&mut grid, size, stride);
+    }
+
+    let mut output = Vec::with_capacity(image.len());
+    for y in 0..height {
+        for x in 0..width {
+            let value = image[y * width + x];
+            let [sum, norm] = interpolate(&grid, dims, strides, coords(y, x, value));
+            output.push(sum / norm);
+        }
+    }
+    output
+}
+
+/// Convolves each line of the grid along an axis with the grid kernel.
+fn blur_axis(grid: &mut [[f32; 2]], size: usize, stride: usize) {
+    let mut line = vec![[0.0f32; 2]; size];
+
+    for outer in 0..grid.len() / (size * stride) {
+        for inner in 0..stride {
+            let start = outer * size * stride + inner;
+            for (i, cell) in line.iter_mut().enumerate() {
+                *cell = grid[start + i * stride];
+            }
+
+            for i in 0..size {
+                let mut blurred = [0.0; 2];
+                for (k, weight) in GRID_KERNEL.iter().enumerate() {
+                    let Some(j) = (i + k).checked_sub(GRID_PADDING).filter(|&j| j < size) else {
+                        continue;
+                    };
+                    blurred[0] += weight * line[j][0];
+                    blurred[1] += weight * line[j][1];
+                }
+                grid[start + i * stride] = blurred;
+            }
+        }
+    }
+}
+
+/// Reads the grid at a fractional position, with trilinear interpolation.
+fn interpolate(
+    grid: &[[f32; 2]],
+    dims: [usize; 3],
+    strides: [usize; 3],
+    coords: [f32; 3],
+) -> [f32; 2] {
+    let mut result = [0.0; 2];
+
+    for corner in 0..8 {
+        let mut weight = 1.0;
+        let mut index = 0;
+        for (axis, ((coord, size), stride)) in coords.iter().zip(dims).zip(strides).enumerate() {
+            let offset = (corner >> (2 - axis)) & 1;
+            let base = coord.floor();
+            let fraction = coord - base;
+            weight *= if offset == 1 {
+                fraction
+            } else {
+                1.0 - fraction
+            };
+            index += (base as usize + offset).min(size - 1) * stride;
+        }
+
+        result[0] += weight * grid[index][0];
+        result[1] += weight * grid[index][1];
+    }
+    result
+}
@@ -0,0 +1,325 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::backend::Backend;
+use burn_core::tensor::{TensorData, cast::ToElement};
+
+use crate::{Contour, ContourApproximation, ContourRetrieval, Point};
+
+/// Offsets `(dy, dx)` of the 8 neighbors of a pixel, in counterclockwise order starting from the
+/// right one (with the `y` axis pointing down).
+const NEIGHBORS: [(isize, isize); 8] = [
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Finds the contours of the objects of a `[height, width]` boolean mask on CPU, with the border
+/// following algorithm of Suzuki and Abe, as in `opencv`'s `findContours`.
+///
+/// The outer borders of the objects and the borders of their holes are followed in raster order,
+/// with 8-connectivity for the objects. Each border records its parent: the hole an outer border
+/// is in, or the outer border of the object a hole is in.
+pub fn find_contours<B: Backend>(
+    mask: TensorData,
+    mode: ContourRetrieval,
+    approximation: ContourApproximation,
+) -> Vec<Contour> {
+    // Labels of the padded image: 0 for the background, 1 for the unvisited object pixels, and
+    // `±nbd` for the pixels of border `nbd`, negative when the border is on their right.
+    let [height, width] = mask.shape.dims();
+    let mask = mask.into_vec::<B::BoolElem>().unwrap();
+    let stride = width + 2;
+    let mut labels = vec![0isize; (height + 2) * stride];
+    for (y, row) in mask.chunks_exact(width.max(1)).enumerate() {
+        for (x, value) in row.iter().enumerate() {
+            labels[(y + 1) * stride + x + 1] = value.to_bool() as isize;
+        }
+    }
+
+    // Border 1 is the frame of the image, which behaves like a hole.
+    let mut borders = vec![Border {
+        is_hole: true,
+        parent: 0,
+        points: Vec::new(),
+    }];
+
+    for y in 1..=height {
+        let mut last_border = 1;
+        for x in 1..=width {
+            let index = y * stride + x;
+            let label = labels[index];
+            if label == 0 {
+                continue;
+            }
+
+            let start = if label == 1 && labels[index - 1] == 0 {
+                Some((false, 4))
+            } else if label >= 1 && labels[index + 1] == 0 {
+                if label > 1 {
+                    last_border = label as usize;
+                }
+                Some((true, 0))
+            } else {
+                None
+            };
+
+            if let Some((is_hole, from)) = start {
+                let nbd = borders.len() + 1;
+                let last = &borders[last_border - 1];
+                let parent = if is_hole == last.is_hole {
+                    last.parent
+                } else {
+                    last_border
+                };
+                let points = follow_border(&mut labels, stride, (y, x), from, nbd as isize);
+                borders.push(Border {
+                    is_hole,
+                    parent,
+                    points,
+                });
+            }
+
+            let label = labels[index];
+            if label != 1 {
+                last_border = label.unsigned_abs();
+            }
+        }
+    }
+
+    select_contours(borders, mode, approximation)
+}
+
+/// A border found by [find_contours], identified by its number `nbd` starting from 2.
+struct Border {
+    is_hole: bool,
+    /// Number of the parent border, 1 being the frame and 0 its absence.
+    parent: usize,
+    points: Vec<Point>,
+}
+
+/// Follows the border starting at `start`, coming from its neighbor `from`, and labels its
+/// pixels with `nbd`. Returns the points of the border, without the padding.
+fn follow_border(
+    labels: &mut [isize],
+    stride: usize,
+    start: (usize, usize),
+    from: usize,
+    nbd: isize,
+) -> Vec<Point> {
+    let offset = |(y, x): (usize, usize), direction: usize| {
+        let (dy, dx) = NEIGHBORS[direction % 8];
+        ((y as isize + dy) as usize, (x as isize + dx) as usize)
+    };
+    let index = |(y, x): (usize, usize)| y * stride + x;
+    let point = |(y, x): (usize, usize)| Point::new(x - 1, y - 1);
+
+    // Look clockwise around the start for the first object pixel.
+    let Some(first) = (0..8)
+        .map(|k| (from + 8 - k) % 8)
+        .find(|&direction| labels[index(offset(start, direction))] != 0)
+    else {
+        labels[index(start)] = -nbd;
+        return vec![point(start)];
+    };
+
+    let first_pixel = offset(start, first);
+    let mut points = Vec::new();
+    let mut previous = first;
+    let mut current = start;
+    loop {
+        points.push(point(current));
+
+        // Look counterclockwise around the current pixel, starting after the previous one.
+        let mut right_is_background = false;
+        let (direction, next) = (1..=8)
+            .map(|k| {
+                let direction = (previous + k) % 8;
+                (direction, offset(current, direction))
+            })
+            .find(|&(direction, next)| {
+                let found = labels[index(next)] != 0;
+                right_is_background |= !found && direction == 0;
+                found
+            })
+            .expect("The previous pixel should be an object pixel");
+
+        let label = &mut labels[index(current)];
+        if right_is_background {
+            *label = -nbd;
+        } else if *label == 1 {
+            *label = nbd;
+        }
+
+        if next == start && current == first_pixel {
+            break;
+        }
+        // The current pixel, seen from the next one.
+        previous = (direction + 4) % 8;
+        current = next;
+    }
+
+    points
+}
+
+/// Keeps the borders of the retrieval mode, and converts them to contours.
+fn select_contours(
+    borders: Vec<Border>,
+    mode: ContourRetrieval,
+    approximation: ContourApproximation,
+) -> Vec<Contour> {
+    // Index of the contour of each border, the first border being the frame.
+    let mut indices = vec![None; borders.len()];
+    let mut contours = Vec::new();
+
+    for (i, border) in borders.into_iter().enumerate().skip(1) {
+        let keep = match mode {
+            ContourRetrieval::External => !border.is_hole && border.parent == 1,
+            ContourRetrieval::List | ContourRetrieval::Tree => true,
+        };
+        if !keep {
+            continue;
+        }
+
+        let parent = match mode {
+            ContourRetrieval::Tree => indices[border.parent - 1],
+            _ => None,
+        };
+        let points = match approximation {
+            ContourApproximation::None => border.points,
+            ContourApproximation::Simple => compress_segments(border.points),
+        };
+
+        indices[i] = Some(contours.len());
+        contours.push(Contour {
+            points,
+            is_hole: border.is_hole,
+            parent,
+        });
+    }
+
+    contours
+}
+
+/// Keeps the end points of the horizontal, vertical and diagonal segments of a closed contour.
+fn compress_segments(points: Vec<Point>) -> Vec<Point> {
+    let n = points.len();
+    if n < 3 {
+        return points;
+    }
+
+    let step = |a: Point, b: Point| (b.x as isize - a.x as isize, b.y as isize - a.y as isize);
+    (0..n)
+        .filter(|&i| {
+            let previous = points[(i + n - 1) % n];
+            let next = points[(i + 1) % n];
+            step(previous, points[i]) != step(points[i], next)
+        })
+        .map(|i| points[i])
+        .collect()
+}
+
+/// Approximates a closed polygon with fewer vertices, with the Ramer-Douglas-Peucker algorithm.
+///
+/// The polygon is split at the vertex farthest from the first one, and each half is simplified
+/// so that no vertex is farther than `epsilon` from the approximation.
+pub fn approx_poly_dp(points: &[Point], epsilon: f64) -> Vec<Point> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+
+    let first = points[0];
+    let (split, _) = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, distance(first, *p)))
+        .fold((0, 0.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+    if split == 0 {
+        return vec![first];
+    }
+
+    let mut closed = points.to_vec();
+    closed.push(first);
+
+    let mut keep = vec![false; n + 1];
+    keep[0] = true;
+    keep[split] = true;
+    simplify(&closed, 0, split, epsilon, &mut keep);
+    simplify(&closed, split, n, epsilon, &mut keep);
+
+    (0..n).filter(|&i| keep[i]).map(|i| points[i]).collect()
+}
+
+/// Marks the vertices of `points[start..=end]` kept by the Ramer-Douglas-Peucker algorithm.
+fn simplify(points: &[Point], start: usize, end: usize, epsilon: f64, keep: &mut [bool]) {
+    let mut ranges = vec![(start, end)];
+    while let Some((start, end)) = ranges.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| (i, line_distance(points[i], points[start], points[end])))
+            .fold(None, |best: Option<(usize, f64)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            });
+
+        if let Some((i, d)) = farthest
+            && d > epsilon
+        {
+            keep[i] = true;
+            ranges.push((start, i));
+            ranges.push((i, end));
+        }
+    }
+}
+
+/// The area enclosed by a closed polygon, with the shoelace formula.
+pub fn contour_area(points: &[Point]) -> f64 {
+    let n = points.len();
+    let twice_area: f64 = (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.x as f64 * b.y as f64 - b.x as f64 * a.y as f64
+        })
+        .sum();
+
+    twice_area.abs() / 2.0
+}
+
+/// The length of a polygon, closed or not.
+pub fn arc_length(points: &[Point], closed: bool) -> f64 {
+    let n = points.len();
+    let open_length: f64 = points.windows(2).map(|p| distance(p[0], p[1])).sum();
+
+    if closed && n > 1 {
+        open_length + distance(points[n - 1], points[0])
+    } else {
+        open_length
+    }
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    let (dx, dy) = (a.x as f64 - b.x as f64, a.y as f64 - b.y as f64);
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// The distance from `p` to the line going through `a` and `b`.
+fn line_distance(p: Point, a: Point, b: Point) -> f64 {
+    let length = distance(a, b);
+    if length == 0.0 {
+        return distance(p, a);
+    }
+
+    let (dx, dy) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let cross = dx * (p.y as f64 - a.y as f64) - dy * (p.x as f64 - a.x as f64);
+    cross.abs() / length
+}
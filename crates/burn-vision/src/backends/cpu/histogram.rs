@@ -0,0 +1,115 @@
+use alloc::vec::Vec;
+use burn_core::tensor::TensorData;
+
+/// Matches the histogram of each channel of `[batches, channels, height, width]` images to the
+/// same channel of the reference images on CPU, like `scikit-image`'s `match_histograms`.
+///
+/// The reference has the same number of channels, and either one image per image or a single
+/// image for the whole batch.
+pub fn match_histograms(images: TensorData, reference: TensorData) -> TensorData {
+    let shape = images.shape.clone();
+    let dtype = images.dtype;
+    let [batch_size, channels, height, width] = shape.dims();
+    let [ref_batch_size, ref_channels, ref_height, ref_width] = reference.shape.dims();
+    assert!(
+        ref_channels == channels && (ref_batch_size == batch_size || ref_batch_size == 1),
+        "Histogram matching expects a reference with {channels} channels and {batch_size} or 1 \
+         images, got {:?}",
+        reference.shape
+    );
+
+    let images: Vec<f32> = images.convert::<f32>().to_vec().unwrap();
+    let reference: Vec<f32> = reference.convert::<f32>().to_vec().unwrap();
+    let size = height * width;
+    let ref_size = ref_height * ref_width;
+
+    let mut output = Vec::with_capacity(images.len());
+    for b in 0..batch_size {
+        let ref_b = if ref_batch_size == 1 { 0 } else { b };
+        for c in 0..channels {
+            let start = (b * channels + c) * size;
+            let ref_start = (ref_b * channels + c) * ref_size;
+            output.extend(match_channel(
+                &images[start..start + size],
+                &reference[ref_start..ref_start + ref_size],
+            ));
+        }
+    }
+
+    TensorData::new(output, shape).convert_dtype(dtype)
+}
+
+/// Maps each value to the reference value at the same quantile, interpolating linearly between
+/// the distinct reference values.
+fn match_channel(values: &[f32], reference: &[f32]) -> Vec<f32> {
+    if reference.is_empty() {
+        return values.to_vec();
+    }
+
+    let source = Quantiles::new(values);
+    let reference = Quantiles::new(reference);
+
+    let mapped: Vec<f32> = source
+        .quantiles
+        .iter()
+        .map(|&quantile| reference.interpolate(quantile))
+        .collect();
+
+    values
+        .iter()
+        .map(|value| {
+            let index = source
+                .values
+                .partition_point(|v| v.total_cmp(value).is_lt());
+            mapped[index]
+        })
+        .collect()
+}
+
+/// The distinct values of a channel in increasing order, with the fraction of values lower or
+/// equal to each of them.
+struct Quantiles {
+    values: Vec<f32>,
+    quantiles: Vec<f32>,
+}
+
+impl Quantiles {
+    fn new(values: &[f32]) -> Self {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f32::total_cmp);
+
+        let mut distinct = Vec::new();
+        let mut quantiles = Vec::new();
+        for (i, &value) in sorted.iter().enumerate() {
+            if distinct
+                .last()
+                .is_some_and(|last: &f32| last.total_cmp(&value).is_eq())
+            {
+                *quantiles.last_mut().unwrap() = (i + 1) as f32 / sorted.len() as f32;
+            } else {
+                distinct.push(value);
+                quantiles.push((i + 1) as f32 / sorted.len() as f32);
+            }
+        }
+
+        Self {
+            values: distinct,
+            quantiles,
+        }
+    }
+
+    /// The value at a quantile, clamped to the lowest and highest values outside their quantiles.
+    fn interpolate(&self, quantile: f32) -> f32 {
+        let upper = self.quantiles.partition_point(|&q| q < quantile);
+        if upper == 0 {
+            return self.values[0];
+        }
+        if upper == self.values.len() {
+            return self.values[upper - 1];
+        }
+
+        let (q0, q1) = (self.quantiles[upper - 1], self.quantiles[upper]);
+        let (v0, v1) = (self.values[upper - 1], self.values[upper]);
+        v0 + (v1 - v0) * (quantile - q0) / (q1 - q0)
+    }
+}
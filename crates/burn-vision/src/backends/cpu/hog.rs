@@ -0,0 +1,212 @@
+use crate::HogOptions;
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::tensor::{Shape, TensorData};
+use macerator::{Scalar, Simd, Vector, vload_unaligned, vstore_unaligned};
+
+/// Small constant of the L2-Hys block normalization, as in `skimage`.
+const EPSILON: f32 = 1e-5;
+
+/// Maximum value of a normalized block component before renormalization.
+const CLIP: f32 = 0.2;
+
+/// Computes the HOG descriptors of a batch of grayscale images on CPU.
+///
+/// The gradients are computed with SIMD, and the histograms and block normalization in scalar
+/// code.
+pub fn hog(images: TensorData, options: HogOptions) -> TensorData {
+    let [batch_size, height, width] = images.shape.dims();
+    let dtype = images.dtype;
+    let layout = HogLayout::new(height, width, options);
+
+    let images: Vec<f32> = images.convert::<f32>().to_vec().unwrap();
+    let image_size = height * width;
+    let descriptor_size = layout.descriptor_size(options);
+
+    let mut gx = vec![0.0; image_size];
+    let mut gy = vec![0.0; image_size];
+    let mut output = Vec::with_capacity(batch_size * descriptor_size);
+
+    for image in images.chunks_exact(image_size) {
+        gradients(image, &mut gx, &mut gy, height, width);
+        let histograms = cell_histograms(&gx, &gy, width, &layout, options);
+        normalize_blocks(&histograms, &layout, options, &mut output);
+    }
+
+    TensorData::new(output, Shape::new([batch_size, descriptor_size])).convert_dtype(dtype)
+}
+
+/// Number of cells and blocks along each dimension.
+struct HogLayout {
+    cells: [usize; 2],
+    blocks: [usize; 2],
+}
+
+impl HogLayout {
+    fn new(height: usize, width: usize, options: HogOptions) -> Self {
+        assert!(
+            options.num_bins > 0,
+            "HOG expects at least one orientation bin"
+        );
+        let dims = [height, width];
+        let mut cells = [0; 2];
+        let mut blocks = [0; 2];
+
+        for i in 0..2 {
+            assert!(
+                options.cell_size[i] > 0
+                    && options.block_size[i] > 0
+                    && options.block_stride[i] > 0,
+                "HOG expects non-zero cell sizes, block sizes and block strides"
+            );
+            cells[i] = dims[i] / options.cell_size[i];
+            assert!(
+                cells[i] >= options.block_size[i],
+                "HOG expects the image ({height}x{width}) to fit at least one block of {:?} cells of {:?} pixels",
+                options.block_size,
+                options.cell_size
+            );
+            blocks[i] = (cells[i] - options.block_size[i]) / options.block_stride[i] + 1;
+        }
+
+        Self { cells, blocks }
+    }
+
+    fn descriptor_size(&self, options: HogOptions) -> usize {
+        let [block_height, block_width] = options.block_size;
+
+        self.blocks[0] * self.blocks[1] * block_height * block_width * options.num_bins
+    }
+}
+
+/// Orientation histograms of the cells, as `[cells_y, cells_x, bins]`, averaged over each cell.
+///
+/// The pixels of the last incomplete row and column of cells are ignored.
+fn cell_histograms(
+    gx: &[f32],
+    gy: &[f32],
+    width: usize,
+    layout: &HogLayout,
+    options: HogOptions,
+) -> Vec<f32> {
+    let [cells_y, cells_x] = layout.cells;
+    let [cell_height, cell_width] = options.cell_size;
+    let num_bins = options.num_bins;
+    let range = if options.signed { 360.0 } else { 180.0 };
+    let bin_width = range / num_bins as f32;
+
+    let mut histograms = vec![0.0; cells_y * cells_x * num_bins];
+
+    for y in 0..cells_y * cell_height {
+        for x in 0..cells_x * cell_width {
+            let index = y * width + x;
+            let (dx, dy) = (gx[index], gy[index]);
+            let magnitude = dx.hypot(dy);
+            let orientation = dy.atan2(dx).to_degrees().rem_euclid(range);
+            let bin = ((orientation / bin_width) as usize).min(num_bins - 1);
+
+            let cell = (y / cell_height) * cells_x + x / cell_width;
+            histograms[cell * num_bins + bin] += magnitude;
+        }
+    }
+
+    let cell_area = (cell_height * cell_width) as f32;
+    histograms.iter_mut().for_each(|value| *value /= cell_area);
+
+    histograms
+}
+
+/// Groups the cell histograms into blocks, normalized with L2-Hys, and appends them to `output`.
+fn normalize_blocks(
+    histograms: &[f32],
+    layout: &HogLayout,
+    options: HogOptions,
+    output: &mut Vec<f32>,
+) {
+    let [blocks_y, blocks_x] = layout.blocks;
+    let [_, cells_x] = layout.cells;
+    let [block_height, block_width] = options.block_size;
+    let [stride_y, stride_x] = options.block_stride;
+    let num_bins = options.num_bins;
+
+    let mut block = Vec::with_capacity(block_height * block_width * num_bins);
+
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            block.clear();
+            for cy in by * stride_y..by * stride_y + block_height {
+                for cx in bx * stride_x..bx * stride_x + block_width {
+                    let cell = (cy * cells_x + cx) * num_bins;
+                    block.extend_from_slice(&histograms[cell..cell + num_bins]);
+                }
+            }
+
+            normalize_l2(&mut block);
+            block.iter_mut().for_each(|value| *value = value.min(CLIP));
+            normalize_l2(&mut block);
+
+            output.extend_from_slice(&block);
+        }
+    }
+}
+
+fn normalize_l2(values: &mut [f32]) {
+    let norm = (values.iter().map(|v| v * v).sum::<f32>() + EPSILON * EPSILON).sqrt();
+    values.iter_mut().for_each(|value| *value /= norm);
+}
+
+/// Central differences along the rows and the columns, zero on the borders.
+#[inline(always)]
+#[macerator::with_simd]
+fn gradients<'a, S: Simd>(
+    image: &'a [f32],
+    gx: &'a mut [f32],
+    gy: &'a mut [f32],
+    height: usize,
+    width: usize,
+) where
+    'a: 'a,
+{
+    let lanes = f32::lanes::<S>();
+    gx.fill(0.0);
+    gy.fill(0.0);
+
+    // Vertical gradients: difference of the rows below and above.
+    for row in 1..height.saturating_sub(1) {
+        let above = &image[(row - 1) * width..row * width];
+        let below = &image[(row + 1) * width..(row + 2) * width];
+        let out = &mut gy[row * width..(row + 1) * width];
+
+        let mut x = 0;
+        while x + lanes <= width {
+            unsafe {
+                let a: Vector<S, f32> = vload_unaligned(above.as_ptr().add(x));
+                let b: Vector<S, f32> = vload_unaligned(below.as_ptr().add(x));
+                vstore_unaligned(out.as_mut_ptr().add(x), b - a);
+            }
+            x += lanes;
+        }
+        for x in x..width {
+            out[x] = below[x] - above[x];
+        }
+    }
+
+    // Horizontal gradients: difference of the pixels to the right and to the left.
+    for row in 0..height {
+        let pixels = &image[row * width..(row + 1) * width];
+        let out = &mut gx[row * width..(row + 1) * width];
+
+        let mut x = 1;
+        while x + lanes < width {
+            unsafe {
+                let left: Vector<S, f32> = vload_unaligned(pixels.as_ptr().add(x - 1));
+                let right: Vector<S, f32> = vload_unaligned(pixels.as_ptr().add(x + 1));
+                vstore_unaligned(out.as_mut_ptr().add(x), right - left);
+            }
+            x += lanes;
+        }
+        for x in x..width.saturating_sub(1) {
+            out[x] = pixels[x + 1] - pixels[x - 1];
+        }
+    }
+}
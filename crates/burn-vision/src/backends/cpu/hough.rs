@@ -0,0 +1,422 @@
+use core::f64::consts::PI;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::backend::Backend;
+use burn_core::tensor::{Shape, TensorData, cast::ToElement};
+
+use crate::{HoughCirclesOptions, HoughLinesOptions, HoughLinesPOptions};
+
+/// Fixed point precision of the probabilistic line walk.
+const SHIFT: i32 = 16;
+
+/// Finds the lines of a binary edge image with the standard Hough transform on CPU.
+///
+/// This follows `opencv`'s `HoughLines`: each edge pixel votes for the `(rho, theta)` cells of
+/// the lines going through it, and the cells that are local maxima with more than
+/// `options.threshold` votes are returned, by decreasing number of votes.
+///
+/// Returns the lines as `[num_lines, 2]` data of `(rho, theta)`.
+pub fn hough_lines<B: Backend>(image: TensorData, options: HoughLinesOptions) -> TensorData {
+    let (height, width, edges) = edge_mask::<B>(image);
+    let accumulator = LineAccumulator::new(height, width, options.rho, options.theta, true);
+    let num_rho = accumulator.num_rho;
+    // The accumulator has a border of one cell, so that the neighbors of each cell exist.
+    let stride = num_rho + 2;
+
+    let mut votes = vec![0u32; (accumulator.num_angle + 2) * stride];
+    for (y, row) in edges.chunks_exact(width).enumerate() {
+        for x in row
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| **edge)
+            .map(|(x, _)| x)
+        {
+            for n in 0..accumulator.num_angle {
+                let r = accumulator.rho_index(n, x, y);
+                votes[(n + 1) * stride + r + 1] += 1;
+            }
+        }
+    }
+
+    let mut peaks = Vec::new();
+    for n in 0..accumulator.num_angle {
+        for r in 0..num_rho {
+            let index = (n + 1) * stride + r + 1;
+            let count = votes[index];
+            if count as usize > options.threshold
+                && count > votes[index - 1]
+                && count >= votes[index + 1]
+                && count > votes[index - stride]
+                && count >= votes[index + stride]
+            {
+                peaks.push((count, n, r));
+            }
+        }
+    }
+    // Stable sort, so that ties keep the accumulator order.
+    peaks.sort_by(|a, b| b.0.cmp(&a.0));
+    if options.max_lines > 0 {
+        peaks.truncate(options.max_lines);
+    }
+
+    let lines: Vec<f32> = peaks
+        .iter()
+        .flat_map(|&(_, n, r)| {
+            let rho = (r as f64 - ((num_rho - 1) / 2) as f64) * options.rho as f64;
+            [rho as f32, accumulator.angle(n) as f32]
+        })
+        .collect();
+    let num_lines = peaks.len();
+
+    TensorData::new(lines, Shape::new([num_lines, 2]))
+}
+
+/// Finds the line segments of a binary edge image with the progressive probabilistic Hough
+/// transform on CPU.
+///
+/// This follows `opencv`'s `HoughLinesP`, including the order in which the edge pixels are
+/// sampled, so the results are deterministic. Each sampled pixel votes for its lines, and when
+/// one of them reaches `options.threshold` votes, the segment is followed along the line while
+/// the gaps are at most `options.max_line_gap` pixels long. The pixels of the segment are then
+/// removed, and their votes withdrawn if the segment is at least `options.min_line_length` long.
+///
+/// Returns the segments as `[num_segments, 4]` data of `(x1, y1, x2, y2)`.
+pub fn hough_lines_p<B: Backend>(image: TensorData, options: HoughLinesPOptions) -> TensorData {
+    let (height, width, mut edges) = edge_mask::<B>(image);
+    let accumulator = LineAccumulator::new(height, width, options.rho, options.theta, false);
+    let (num_angle, num_rho) = (accumulator.num_angle, accumulator.num_rho);
+    let threshold = options.threshold as i32;
+    let max_gap = options.max_line_gap as i32;
+    let min_length = options.min_line_length as i32;
+
+    let mut votes = vec![0i32; num_angle * num_rho];
+    let mut points: Vec<(usize, usize)> = edges
+        .iter()
+        .enumerate()
+        .filter(|(_, edge)| **edge)
+        .map(|(i, _)| (i % width, i / width))
+        .collect();
+
+    let mut rng = Rng::new(u64::MAX);
+    let mut segments: Vec<i64> = Vec::new();
+
+    for count in (1..=points.len()).rev() {
+        // Sample a point, and remove it from the remaining ones.
+        let index = rng.uniform(count as u32) as usize;
+        let (x, y) = points[index];
+        points[index] = points[count - 1];
+
+        // The point may already belong to a segment.
+        if !edges[y * width + x] {
+            continue;
+        }
+
+        let mut max_votes = threshold - 1;
+        let mut max_n = 0;
+        for n in 0..num_angle {
+            let vote = &mut votes[n * num_rho + accumulator.rho_index(n, x, y)];
+            *vote += 1;
+            if max_votes < *vote {
+                max_votes = *vote;
+                max_n = n;
+            }
+        }
+        if max_votes < threshold {
+            continue;
+        }
+
+        // Walk along the line in both directions, with the major axis in integer steps and the
+        // minor axis in fixed point.
+        let a = -accumulator.sin[max_n];
+        let b = accumulator.cos[max_n];
+        let x_major = a.abs() > b.abs();
+        let (start, step) = if x_major {
+            let dy = (b * (1 << SHIFT) as f32 / a.abs()).round_ties_even() as i32;
+            let start = (x as i32, ((y as i32) << SHIFT) + (1 << (SHIFT - 1)));
+            (start, (if a > 0.0 { 1 } else { -1 }, dy))
+        } else {
+            let dx = (a * (1 << SHIFT) as f32 / b.abs()).round_ties_even() as i32;
+            let start = (((x as i32) << SHIFT) + (1 << (SHIFT - 1)), y as i32);
+            (start, (dx, if b > 0.0 { 1 } else { -1 }))
+        };
+        let pixel = |(px, py): (i32, i32)| {
+            if x_major {
+                (px, py >> SHIFT)
+            } else {
+                (px >> SHIFT, py)
+            }
+        };
+        let directions = [step, (-step.0, -step.1)];
+
+        let mut line_end = [(x as i32, y as i32); 2];
+        for (end, &(dx, dy)) in line_end.iter_mut().zip(&directions) {
+            let mut gap = 0;
+            let mut position = start;
+            loop {
+                let (px, py) = pixel(position);
+                if px < 0 || px >= width as i32 || py < 0 || py >= height as i32 {
+                    break;
+                }
+                if edges[py as usize * width + px as usize] {
+                    gap = 0;
+                    *end = (px, py);
+                } else {
+                    gap += 1;
+                    if gap > max_gap {
+                        break;
+                    }
+                }
+                position = (position.0 + dx, position.1 + dy);
+            }
+        }
+
+        let good_line = (line_end[1].0 - line_end[0].0).abs() >= min_length
+            || (line_end[1].1 - line_end[0].1).abs() >= min_length;
+
+        for (&end, &(dx, dy)) in line_end.iter().zip(&directions) {
+            let mut position = start;
+            loop {
+                let (px, py) = pixel(position);
+                let edge = &mut edges[py as usize * width + px as usize];
+                if *edge {
+                    if good_line {
+                        for n in 0..num_angle {
+                            votes[n * num_rho
+                                + accumulator.rho_index(n, px as usize, py as usize)] -= 1;
+                        }
+                    }
+                    *edge = false;
+                }
+                if (px, py) == end {
+                    break;
+                }
+                position = (position.0 + dx, position.1 + dy);
+            }
+        }
+
+        if good_line {
+            let [(x1, y1), (x2, y2)] = line_end;
+            segments.extend([x1 as i64, y1 as i64, x2 as i64, y2 as i64]);
+            if options.max_lines > 0 && segments.len() / 4 >= options.max_lines {
+                break;
+            }
+        }
+    }
+
+    let num_segments = segments.len() / 4;
+    TensorData::new(segments, Shape::new([num_segments, 4]))
+}
+
+/// Finds the circles of a binary edge image with the Hough transform on CPU.
+///
+/// Each edge pixel votes for the centers of the circles of each radius going through it, and
+/// the score of a circle is the fraction of its perimeter pixels that are edges. The circles
+/// that are local maxima in position and radius, with a score of at least `options.threshold`,
+/// are kept by decreasing score, skipping those closer than `options.min_dist` to a better one.
+///
+/// Returns the circles as `[num_circles, 3]` data of `(x, y, radius)`.
+pub fn hough_circles<B: Backend>(image: TensorData, options: HoughCirclesOptions) -> TensorData {
+    let (height, width, edges) = edge_mask::<B>(image);
+    let HoughCirclesOptions {
+        min_radius,
+        max_radius,
+        ..
+    } = options;
+    assert!(
+        0 < min_radius && min_radius <= max_radius,
+        "Hough circles expects a radius range with 0 < min_radius <= max_radius, got \
+         {min_radius}..={max_radius}"
+    );
+
+    let num_radii = max_radius - min_radius + 1;
+    let plane = height * width;
+    let rings: Vec<Vec<(isize, isize)>> = (min_radius..=max_radius).map(ring).collect();
+
+    let mut votes = vec![0u32; num_radii * plane];
+    for (y, row) in edges.chunks_exact(width).enumerate() {
+        for x in row
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| **edge)
+            .map(|(x, _)| x)
+        {
+            for (ring, votes) in rings.iter().zip(votes.chunks_exact_mut(plane)) {
+                for &(dx, dy) in ring {
+                    let (cx, cy) = (x as isize + dx, y as isize + dy);
+                    if 0 <= cx && cx < width as isize && 0 <= cy && cy < height as isize {
+                        votes[cy as usize * width + cx as usize] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let scores: Vec<f32> = votes
+        .chunks_exact(plane)
+        .zip(&rings)
+        .flat_map(|(votes, ring)| votes.iter().map(|&v| v as f32 / ring.len() as f32))
+        .collect();
+
+    let mut candidates = Vec::new();
+    for r in 0..num_radii {
+        for y in 0..height {
+            for x in 0..width {
+                let index = r * plane + y * width + x;
+                let score = scores[index];
+                if score >= options.threshold
+                    && is_local_maximum(&scores, [num_radii, height, width], [r, y, x])
+                {
+                    candidates.push((score, index));
+                }
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut circles: Vec<[f32; 3]> = Vec::new();
+    for (_, index) in candidates {
+        let (r, y, x) = (index / plane, index % plane / width, index % width);
+        let circle = [x as f32, y as f32, (min_radius + r) as f32];
+        let far_enough = circles.iter().all(|kept| {
+            let (dx, dy) = (kept[0] - circle[0], kept[1] - circle[1]);
+            (dx * dx + dy * dy).sqrt() >= options.min_dist
+        });
+        if far_enough {
+            circles.push(circle);
+            if options.max_circles > 0 && circles.len() >= options.max_circles {
+                break;
+            }
+        }
+    }
+
+    let num_circles = circles.len();
+    TensorData::new(circles.concat(), Shape::new([num_circles, 3]))
+}
+
+/// Reads a `[height, width]` boolean image.
+fn edge_mask<B: Backend>(image: TensorData) -> (usize, usize, Vec<bool>) {
+    let [height, width] = image.shape.dims();
+    let edges = image
+        .into_vec::<B::BoolElem>()
+        .unwrap()
+        .iter()
+        .map(|edge| edge.to_bool())
+        .collect();
+
+    (height, width, edges)
+}
+
+/// The `(rho, theta)` quantization of the lines of an image.
+struct LineAccumulator {
+    num_angle: usize,
+    num_rho: usize,
+    /// Cosine of each angle, divided by the rho resolution.
+    cos: Vec<f32>,
+    /// Sine of each angle, divided by the rho resolution.
+    sin: Vec<f32>,
+    theta: f64,
+}
+
+impl LineAccumulator {
+    fn new(height: usize, width: usize, rho: f32, theta: f32, drop_last_angle: bool) -> Self {
+        assert!(
+            rho > 0.0 && theta > 0.0,
+            "Hough lines expects positive resolutions, got rho = {rho} and theta = {theta}"
+        );
+        let theta = theta as f64;
+
+        let mut num_angle = ((PI / theta).round_ties_even() as usize).max(1);
+        // Angles `0` and `π` give the same lines.
+        if drop_last_angle
+            && num_angle > 1
+            && (PI - (num_angle - 1) as f64 * theta).abs() < theta / 2.0
+        {
+            num_angle -= 1;
+        }
+        let num_rho = ((((width + height) * 2 + 1) as f32 / rho).round_ties_even() as usize).max(1);
+
+        let angles = (0..num_angle).map(|n| n as f64 * theta);
+        let irho = 1.0 / rho as f64;
+
+        Self {
+            num_angle,
+            num_rho,
+            cos: angles.clone().map(|a| (a.cos() * irho) as f32).collect(),
+            sin: angles.map(|a| (a.sin() * irho) as f32).collect(),
+            theta,
+        }
+    }
+
+    /// The rho index of the line of angle `n` going through `(x, y)`.
+    #[inline(always)]
+    fn rho_index(&self, n: usize, x: usize, y: usize) -> usize {
+        let r = (x as f32 * self.cos[n] + y as f32 * self.sin[n]).round_ties_even() as isize;
+        (r + (self.num_rho as isize - 1) / 2) as usize
+    }
+
+    fn angle(&self, n: usize) -> f64 {
+        n as f64 * self.theta
+    }
+}
+
+/// The offsets of the pixels at distance `radius` from the center, once rounded.
+fn ring(radius: usize) -> Vec<(isize, isize)> {
+    let r = radius as isize;
+    let mut ring = Vec::new();
+    for dy in -r - 1..=r + 1 {
+        for dx in -r - 1..=r + 1 {
+            let distance = ((dx * dx + dy * dy) as f64).sqrt().round() as usize;
+            if distance == radius {
+                ring.push((dx, dy));
+            }
+        }
+    }
+    ring
+}
+
+/// Whether a score is at least as high as its neighbors in position and radius, and strictly
+/// higher than the neighbors before it, so that plateaus give a single maximum.
+fn is_local_maximum(scores: &[f32], dims: [usize; 3], position: [usize; 3]) -> bool {
+    let [num_radii, height, width] = dims;
+    let [r, y, x] = position;
+    let index = (r * height + y) * width + x;
+    let score = scores[index];
+
+    for nr in r.saturating_sub(1)..(r + 2).min(num_radii) {
+        for ny in y.saturating_sub(1)..(y + 2).min(height) {
+            for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                let neighbor = (nr * height + ny) * width + nx;
+                let other = scores[neighbor];
+                if other > score || (neighbor < index && other == score) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// The random number generator of `opencv`, a multiply-with-carry generator, used to sample the
+/// points of the probabilistic Hough transform in the same order.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u32 {
+        self.state = (self.state as u32 as u64)
+            .wrapping_mul(4164903690)
+            .wrapping_add(self.state >> 32);
+        self.state as u32
+    }
+
+    /// A random integer in `0..end`.
+    fn uniform(&mut self, end: u32) -> u32 {
+        self.next() % end
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::tensor::{Shape, TensorData};
+use macerator::{Scalar, Simd, Vector, vload_unaligned, vstore_unaligned};
+
+/// Computes the integral images of a batch of images on CPU.
+///
+/// The sums are accumulated in double precision, so that the bottom-right values of large images
+/// stay exact, and converted back to the input type at the end.
+pub fn integral_image(images: TensorData) -> TensorData {
+    let [batch_size, height, width] = images.shape.dims();
+    let dtype = images.dtype;
+
+    let images: Vec<f64> = images.convert::<f64>().to_vec().unwrap();
+    let stride = width + 1;
+    let output_size = (height + 1) * stride;
+
+    let mut output = vec![0.0; batch_size * output_size];
+    let mut row_sums = vec![0.0; width];
+
+    for (image, output) in images
+        .chunks_exact(height * width)
+        .zip(output.chunks_exact_mut(output_size))
+    {
+        for (y, row) in image.chunks_exact(width).enumerate() {
+            let mut sum = 0.0;
+            for (row_sum, value) in row_sums.iter_mut().zip(row) {
+                sum += value;
+                *row_sum = sum;
+            }
+
+            // The first row and column of the output are left to zero.
+            let (above, below) = output.split_at_mut((y + 1) * stride);
+            accumulate_row(&above[y * stride + 1..], &row_sums, &mut below[1..stride]);
+        }
+    }
+
+    TensorData::new(output, Shape::new([batch_size, height + 1, width + 1])).convert_dtype(dtype)
+}
+
+/// Adds the prefix sums of a row to the integral image row above it.
+#[inline(always)]
+#[macerator::with_simd]
+fn accumulate_row<'a, S: Simd>(above: &'a [f64], row_sums: &'a [f64], out: &'a mut [f64])
+where
+    'a: 'a,
+{
+    let lanes = f64::lanes::<S>();
+    let width = out.len();
+
+    let mut x = 0;
+    while x + lanes <= width {
+        unsafe {
+            let a: Vector<S, f64> = vload_unaligned(above.as_ptr().add(x));
+            let s: Vector<S, f64> = vload_unaligned(row_sums.as_ptr().add(x));
+            vstore_unaligned(out.as_mut_ptr().add(x), a + s);
+        }
+        x += lanes;
+    }
+    for x in x..width {
+        out[x] = above[x] + row_sums[x];
+    }
+}
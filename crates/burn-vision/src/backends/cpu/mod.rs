@@ -1,10 +1,26 @@
 mod base;
+mod bilateral;
 mod connected_components;
+mod contours;
+mod histogram;
+mod hog;
+mod hough;
+mod integral;
 mod morphology;
 mod nms;
 mod ops;
+mod photometric;
+mod segmentation;
 
 pub use base::*;
+pub use bilateral::*;
 pub use connected_components::*;
+pub use contours::*;
+pub use histogram::*;
+pub use hog::*;
+pub use hough::*;
+pub use integral::*;
 pub use morphology::*;
 pub use nms::*;
+pub use photometric::*;
+pub use segmentation::*;
@@ -7,6 +7,7 @@ use burn_core::tensor::{
 };
 use filter::{MaxOp, MinOp, MorphOperator, VecMorphOperator};
 use filter_engine::{ColFilter, Filter, Filter2D, FilterEngine, RowFilter};
+use half::{bf16, f16, slice::HalfFloatSliceExt};
 use macerator::{Simd, VOrd};
 
 use crate::{BorderType, MorphOptions, Point, Size};
@@ -45,39 +46,27 @@ pub fn morph<B: Backend>(
     let [kh, kw] = kernel.shape.dims();
 
     let kernel = kernel.into_vec::<B::BoolElem>().unwrap();
-    let is_rect = kernel.iter().all(|it| it.to_bool());
     let anchor = opts.anchor.unwrap_or(Point::new(kw / 2, kh / 2));
+    assert!(
+        anchor.x < kw && anchor.y < kh,
+        "Morphology anchor {anchor:?} should be inside the [{kh}, {kw}] kernel"
+    );
     let iter = opts.iterations;
     let btype = opts.border_type;
     let bvalue = opts.border_value;
 
-    let size = Size::new(kw, kh);
-    let kernel = if is_rect {
-        MorphKernel::Rect { size, anchor }
-    } else {
-        MorphKernel::Other {
-            kernel,
-            size,
-            anchor,
-        }
-    };
+    let kernel = morph_kernel(kernel, Size::new(kw, kh), anchor);
 
     let shape = input.shape.clone();
+    let dtype = input.dtype;
     let data = input;
-    match data.dtype {
+    match dtype {
         DType::F64 => morph_typed::<B, f64>(data, shape, kernel, op, iter, btype, bvalue),
         DType::F32 | DType::Flex32 => {
             morph_typed::<B, f32>(data, shape, kernel, op, iter, btype, bvalue)
         }
-        DType::F16 | DType::BF16 => morph_typed::<B, f32>(
-            data.convert::<f32>(),
-            shape,
-            kernel,
-            op,
-            iter,
-            btype,
-            bvalue,
-        ),
+        DType::F16 => morph_half::<B, f16>(data, shape, kernel, op, iter, btype, bvalue),
+        DType::BF16 => morph_half::<B, bf16>(data, shape, kernel, op, iter, btype, bvalue),
         DType::I64 => morph_typed::<B, i64>(data, shape, kernel, op, iter, btype, bvalue),
         DType::I32 => morph_typed::<B, i32>(data, shape, kernel, op, iter, btype, bvalue),
         DType::I16 => morph_typed::<B, i16>(data, shape, kernel, op, iter, btype, bvalue),
@@ -97,6 +86,37 @@ pub fn morph<B: Backend>(
     }
 }
 
+/// Picks the separable fast path when the set elements of the kernel form a rectangle containing
+/// the anchor, and the generic 2D filter otherwise. The unset rows and columns around the rectangle
+/// don't contribute to the result, so they're trimmed and the anchor is moved accordingly.
+fn morph_kernel<B: Element>(kernel: Vec<B>, size: Size, anchor: Point) -> MorphKernel<B> {
+    let is_set = |x: usize, y: usize| kernel[y * size.width + x].to_bool();
+    let rows = (0..size.height).filter(|&y| (0..size.width).any(|x| is_set(x, y)));
+    let cols = (0..size.width).filter(|&x| (0..size.height).any(|y| is_set(x, y)));
+
+    if let (Some(y0), Some(y1), Some(x0), Some(x1)) = (
+        rows.clone().min(),
+        rows.max(),
+        cols.clone().min(),
+        cols.max(),
+    ) {
+        let is_rect = (y0..=y1).all(|y| (x0..=x1).all(|x| is_set(x, y)));
+        let contains_anchor = (x0..=x1).contains(&anchor.x) && (y0..=y1).contains(&anchor.y);
+        if is_rect && contains_anchor {
+            return MorphKernel::Rect {
+                size: Size::new(x1 - x0 + 1, y1 - y0 + 1),
+                anchor: Point::new(anchor.x - x0, anchor.y - y0),
+            };
+        }
+    }
+
+    MorphKernel::Other {
+        kernel,
+        size,
+        anchor,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn morph_typed<B: Backend, T: VOrd + MinMax + Element + ElementLimits>(
     mut input: TensorData,
@@ -113,6 +133,40 @@ fn morph_typed<B: Backend, T: VOrd + MinMax + Element + ElementLimits>(
     input
 }
 
+/// Half precision floats don't have SIMD min/max, so the image is widened to `f32` for the filter
+/// and narrowed back at the end. Both conversions use the vectorized `half` slice conversions.
+#[allow(clippy::too_many_arguments)]
+fn morph_half<B: Backend, H: Element>(
+    input: TensorData,
+    shape: Shape,
+    kernel: MorphKernel<B::BoolElem>,
+    op: MorphOp,
+    iter: usize,
+    btype: BorderType,
+    bvalue: Option<Vec<Scalar>>,
+) -> TensorData
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut data = input.into_vec::<H>().unwrap();
+    let mut widened = vec![0.0; data.len()];
+    data.convert_to_f32_slice(&mut widened);
+
+    let bvalue = border_value(btype, bvalue, op, &shape);
+    run_morph(
+        &mut widened,
+        shape.clone(),
+        kernel,
+        op,
+        iter,
+        btype,
+        &bvalue,
+    );
+
+    data.convert_from_f32_slice(&widened);
+    TensorData::new(data, shape)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn morph_bool<B: Backend>(
     mut input: TensorData,
@@ -139,7 +193,15 @@ fn border_value<T: Element + ElementLimits>(
 ) -> Vec<T> {
     let [_, _, ch] = shape.dims();
     match (btype, bvalue) {
-        (BorderType::Constant, Some(value)) => value.into_iter().map(|v| v.elem::<T>()).collect(),
+        (BorderType::Constant, Some(value)) if value.len() == 1 => vec![value[0].elem::<T>(); ch],
+        (BorderType::Constant, Some(value)) => {
+            assert_eq!(
+                value.len(),
+                ch,
+                "Morphology border value should have one value per channel"
+            );
+            value.into_iter().map(|v| v.elem::<T>()).collect()
+        }
         (BorderType::Constant, None) => match op {
             MorphOp::Erode => vec![T::MAX; ch],
             MorphOp::Dilate => vec![T::MIN; ch],
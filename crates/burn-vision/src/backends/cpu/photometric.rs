@@ -0,0 +1,126 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::tensor::{Shape, TensorData};
+
+use crate::PhotometricOptions;
+
+/// Weights of the red, green and blue channels in the grayscale image, as in `torchvision`.
+pub(crate) const GRAYSCALE_WEIGHTS: [f32; 3] = [0.2989, 0.587, 0.114];
+
+/// Jitters the colors of `[batches, 3, height, width]` RGB images with the
+/// `[brightness, contrast, saturation, hue]` factors of each image, then normalizes and pads
+/// them on CPU.
+pub fn photometric_augment(
+    images: TensorData,
+    factors: TensorData,
+    options: PhotometricOptions,
+) -> TensorData {
+    let dtype = images.dtype;
+    let [batch_size, channels, height, width] = images.shape.dims();
+    check_photometric_shapes(channels, batch_size, &factors.shape);
+
+    let [(top, bottom), (left, right)] = options.padding;
+    let (out_height, out_width) = (height + top + bottom, width + left + right);
+
+    let images: Vec<f32> = images.convert::<f32>().to_vec().unwrap();
+    let factors: Vec<f32> = factors.convert::<f32>().to_vec().unwrap();
+    let size = height * width;
+    let out_size = out_height * out_width;
+
+    let mut output = vec![options.pad_value; batch_size * 3 * out_size];
+    for b in 0..batch_size {
+        let image = &images[b * 3 * size..(b + 1) * 3 * size];
+        let factors: [f32; 4] = factors[b * 4..(b + 1) * 4].try_into().unwrap();
+        let pixel = |i: usize| [image[i], image[size + i], image[2 * size + i]];
+
+        let mean_gray = (0..size)
+            .map(|i| grayscale(adjust_brightness(pixel(i), factors[0])))
+            .sum::<f32>()
+            / size.max(1) as f32;
+
+        let output = &mut output[b * 3 * out_size..(b + 1) * 3 * out_size];
+        for y in 0..height {
+            for x in 0..width {
+                let rgb = jitter_pixel(pixel(y * width + x), factors, mean_gray);
+                let out_index = (y + top) * out_width + x + left;
+                for (c, value) in rgb.into_iter().enumerate() {
+                    output[c * out_size + out_index] = (value - options.mean[c]) / options.std[c];
+                }
+            }
+        }
+    }
+
+    TensorData::new(output, Shape::new([batch_size, 3, out_height, out_width])).convert_dtype(dtype)
+}
+
+pub(crate) fn check_photometric_shapes(channels: usize, batch_size: usize, factors: &Shape) {
+    assert_eq!(
+        channels, 3,
+        "Photometric augmentation expects RGB images, got {channels} channels"
+    );
+    assert!(
+        factors.dims::<2>() == [batch_size, 4],
+        "Photometric augmentation expects [{batch_size}, 4] factors, got {factors:?}"
+    );
+}
+
+/// Applies the brightness, contrast, saturation and hue factors to a pixel, in that order,
+/// `mean_gray` being the mean grayscale value of the image after the brightness adjustment.
+fn jitter_pixel(rgb: [f32; 3], factors: [f32; 4], mean_gray: f32) -> [f32; 3] {
+    let [brightness, contrast, saturation, hue] = factors;
+
+    let rgb = adjust_brightness(rgb, brightness);
+    let rgb = rgb.map(|v| blend(v, mean_gray, contrast));
+    let gray = grayscale(rgb);
+    let rgb = rgb.map(|v| blend(v, gray, saturation));
+
+    shift_hue(rgb, hue)
+}
+
+fn adjust_brightness(rgb: [f32; 3], brightness: f32) -> [f32; 3] {
+    rgb.map(|v| (v * brightness).clamp(0.0, 1.0))
+}
+
+fn grayscale(rgb: [f32; 3]) -> f32 {
+    rgb.iter().zip(GRAYSCALE_WEIGHTS).map(|(v, w)| v * w).sum()
+}
+
+/// Interpolates from `other` (factor 0) to `value` (factor 1), and beyond for larger factors.
+fn blend(value: f32, other: f32, factor: f32) -> f32 {
+    (factor * value + (1.0 - factor) * other).clamp(0.0, 1.0)
+}
+
+/// Rotates the hue of a pixel by `shift` turns, through the HSV color space.
+fn shift_hue([r, g, b]: [f32; 3], shift: f32) -> [f32; 3] {
+    let value = r.max(g).max(b);
+    let chroma = value - r.min(g).min(b);
+    if chroma == 0.0 {
+        // Grays have no hue.
+        return [r, g, b];
+    }
+
+    let saturation = chroma / value;
+    let sector = if value == r {
+        (g - b) / chroma
+    } else if value == g {
+        2.0 + (b - r) / chroma
+    } else {
+        4.0 + (r - g) / chroma
+    };
+    let hue = (sector / 6.0 + shift).rem_euclid(1.0) * 6.0;
+
+    let i = hue.floor();
+    let f = hue - i;
+    let p = value * (1.0 - saturation);
+    let q = value * (1.0 - saturation * f);
+    let t = value * (1.0 - saturation * (1.0 - f));
+
+    match i as usize % 6 {
+        0 => [value, t, p],
+        1 => [q, value, p],
+        2 => [p, value, t],
+        3 => [p, q, value],
+        4 => [t, p, value],
+        _ => [value, p, q],
+    }
+}
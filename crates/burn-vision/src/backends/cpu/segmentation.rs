@@ -0,0 +1,204 @@
+use core::cmp::Ordering;
+
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core::tensor::{Shape, TensorData};
+
+use crate::{Connectivity, FloodFillOptions, Point};
+
+/// Label of the watershed lines between two basins, as in `opencv`.
+const WATERSHED_LINE: i64 = -1;
+
+/// Label of the pixels waiting in the watershed queue.
+const IN_QUEUE: i64 = -2;
+
+/// Fills the connected region of a `[height, width]` image around `seed` with `new_value` on CPU,
+/// like `opencv`'s `floodFill`.
+///
+/// A neighbor of a filled pixel is filled when its value is within `[-lo_diff, up_diff]` of the
+/// value of the filled pixel, or of the seed with `options.fixed_range`.
+///
+/// Returns the filled image and the `[height, width]` mask of the filled pixels.
+pub fn flood_fill(
+    image: TensorData,
+    seed: Point,
+    new_value: f32,
+    options: FloodFillOptions,
+) -> (TensorData, TensorData) {
+    let [height, width] = image.shape.dims();
+    let dtype = image.dtype;
+    assert!(
+        seed.x < width && seed.y < height,
+        "Flood fill expects a seed inside the [{height}, {width}] image, got {seed:?}"
+    );
+
+    let values: Vec<f32> = image.convert::<f32>().to_vec().unwrap();
+    let mut filled = vec![false; height * width];
+    let mut queue = VecDeque::new();
+
+    let seed_index = seed.y * width + seed.x;
+    filled[seed_index] = true;
+    queue.push_back(seed_index);
+
+    while let Some(index) = queue.pop_front() {
+        let reference = if options.fixed_range {
+            values[seed_index]
+        } else {
+            values[index]
+        };
+        let range = reference - options.lo_diff..=reference + options.up_diff;
+
+        for neighbor in neighbors(index, height, width, options.connectivity) {
+            if !filled[neighbor] && range.contains(&values[neighbor]) {
+                filled[neighbor] = true;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    let output: Vec<f32> = values
+        .iter()
+        .zip(&filled)
+        .map(|(&value, &filled)| if filled { new_value } else { value })
+        .collect();
+    let shape = Shape::new([height, width]);
+
+    (
+        TensorData::new(output, shape.clone()).convert_dtype(dtype),
+        TensorData::new(filled, shape),
+    )
+}
+
+/// Segments a `[height, width]` elevation image from the labeled `markers` with Meyer's flooding
+/// algorithm on CPU, like `opencv`'s `watershed`.
+///
+/// The unlabeled pixels next to a basin are flooded by increasing elevation, in the order they
+/// were reached for equal elevations. A pixel next to two different basins becomes a watershed
+/// line (`-1`) and stops the flooding.
+///
+/// Returns the labels, with the dtype of the markers.
+pub fn watershed(image: TensorData, markers: TensorData) -> TensorData {
+    let [height, width] = image.shape.dims();
+    assert_eq!(
+        markers.shape.dims::<2>(),
+        [height, width],
+        "Watershed expects markers of the size of the image"
+    );
+    let dtype = markers.dtype;
+
+    let elevation: Vec<f32> = image.convert::<f32>().to_vec().unwrap();
+    let mut labels: Vec<i64> = markers
+        .convert::<i64>()
+        .to_vec::<i64>()
+        .unwrap()
+        .into_iter()
+        .map(|label| label.max(0))
+        .collect();
+
+    let mut queue = BinaryHeap::new();
+    let mut order = 0;
+    let mut push = |queue: &mut BinaryHeap<Queued>, labels: &mut [i64], index: usize| {
+        labels[index] = IN_QUEUE;
+        queue.push(Queued {
+            elevation: elevation[index],
+            order,
+            index,
+        });
+        order += 1;
+    };
+
+    for index in 0..labels.len() {
+        if labels[index] == 0
+            && neighbors(index, height, width, Connectivity::Four).any(|other| labels[other] > 0)
+        {
+            push(&mut queue, &mut labels, index);
+        }
+    }
+
+    while let Some(Queued { index, .. }) = queue.pop() {
+        let mut label = 0;
+        for other in neighbors(index, height, width, Connectivity::Four) {
+            let other = labels[other];
+            if other > 0 {
+                if label == 0 {
+                    label = other;
+                } else if label != other {
+                    label = WATERSHED_LINE;
+                }
+            }
+        }
+        labels[index] = label;
+
+        if label == WATERSHED_LINE {
+            continue;
+        }
+        for other in neighbors(index, height, width, Connectivity::Four) {
+            if labels[other] == 0 {
+                push(&mut queue, &mut labels, other);
+            }
+        }
+    }
+
+    TensorData::new(labels, Shape::new([height, width])).convert_dtype(dtype)
+}
+
+/// A pixel waiting in the watershed queue. The queue pops the lowest pixel first, and the oldest
+/// one among equally low pixels.
+struct Queued {
+    elevation: f32,
+    order: usize,
+    index: usize,
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .elevation
+            .total_cmp(&self.elevation)
+            .then(other.order.cmp(&self.order))
+    }
+}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Queued {}
+
+/// Indices of the neighbors of a pixel inside the image.
+fn neighbors(
+    index: usize,
+    height: usize,
+    width: usize,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = usize> {
+    let (y, x) = ((index / width) as isize, (index % width) as isize);
+    let offsets: &[(isize, isize)] = match connectivity {
+        Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+        Connectivity::Eight => &[
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ],
+    };
+
+    offsets.iter().filter_map(move |&(dy, dx)| {
+        let (y, x) = (y + dy, x + dx);
+        let inside = (0..height as isize).contains(&y) && (0..width as isize).contains(&x);
+        inside.then_some(y as usize * width + x as usize)
+    })
+}
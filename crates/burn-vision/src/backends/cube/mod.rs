@@ -1,2 +1,3 @@
 mod connected_components;
 mod ops;
+mod photometric;
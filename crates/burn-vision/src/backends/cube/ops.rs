@@ -1,16 +1,17 @@
 use crate::{
     BoolVisionOps, ConnectedStatsOptions, Connectivity, FloatVisionOps, IntVisionOps,
-    VisionBackend, backends::cpu, dispatch_int_dtype,
+    PhotometricOptions, VisionBackend, backends::cpu, dispatch_int_dtype,
 };
 use burn_cubecl::{BoolElement, CubeBackend, CubeRuntime, FloatElement, IntElement};
 
 use burn_core::backend::{
-    ops::{BoolTensorOps, IntTensorOps},
-    tensor::{BoolTensor, IntTensor},
+    TensorMetadata,
+    ops::{BoolTensorOps, FloatTensorOps, IntTensorOps},
+    tensor::{BoolTensor, FloatTensor, IntTensor},
 };
-use burn_core::tensor::{Element, IntDType};
+use burn_core::tensor::{Element, IntDType, Shape, Slice, TensorData};
 
-use super::connected_components::hardware_accelerated;
+use super::{connected_components::hardware_accelerated, photometric::photometric_augment};
 
 impl<R, F, I, BT> BoolVisionOps for CubeBackend<R, F, I, BT>
 where
@@ -90,6 +91,81 @@ where
     I: IntElement,
     BT: BoolElement,
 {
+    fn integral_image(images: FloatTensor<Self>) -> FloatTensor<Self> {
+        // Two scans on the device, instead of a round trip to the CPU implementation.
+        let [batch_size, height, width] = images.shape().dims();
+        let device = Self::float_device(&images);
+        let dtype = images.dtype().into();
+
+        let sums = Self::float_cumsum(Self::float_cumsum(images, 1), 2);
+        let output = Self::float_zeros(
+            Shape::new([batch_size, height + 1, width + 1]),
+            &device,
+            dtype,
+        );
+        let inner = Slice::new(1, None, 1);
+
+        Self::float_slice_assign(output, &[Slice::full(), inner, inner], sums)
+    }
+
+    fn photometric_augment(
+        images: FloatTensor<Self>,
+        factors: FloatTensor<Self>,
+        options: PhotometricOptions,
+    ) -> FloatTensor<Self> {
+        let [batch_size, channels, height, width] = images.shape().dims();
+        cpu::check_photometric_shapes(channels, batch_size, &factors.shape());
+        let device = Self::float_device(&images);
+        let dtype = images.dtype().into();
+
+        // The kernel is instantiated for the float element of the backend.
+        let images = Self::float_cast(images, F::dtype().into());
+        let factors = Self::float_cast(factors, F::dtype().into());
+
+        // The contrast needs the mean grayscale value of each image, reduced before the fused pass.
+        let brightness = Self::float_reshape(
+            Self::float_slice(factors.clone(), &[Slice::full(), Slice::new(0, Some(1), 1)]),
+            Shape::new([batch_size, 1, 1, 1]),
+        );
+        let brightened = Self::float_clamp(
+            Self::float_mul(images.clone(), brightness),
+            0f32.into(),
+            1f32.into(),
+        );
+        let weights = Self::float_from_data(
+            TensorData::new(cpu::GRAYSCALE_WEIGHTS.to_vec(), [1, 3, 1, 1])
+                .convert_dtype(F::dtype()),
+            &device,
+        );
+        let gray = Self::float_sum_dim(Self::float_mul(brightened, weights), 1);
+        let mean_gray = Self::float_reshape(
+            Self::float_mean_dim(Self::float_mean_dim(gray, 2), 3),
+            Shape::new([batch_size]),
+        );
+
+        let normalization = Self::float_from_data(
+            TensorData::new([options.mean, options.std].concat(), [6]).convert_dtype(F::dtype()),
+            &device,
+        );
+
+        let [(top, bottom), (left, right)] = options.padding;
+        let output = Self::float_full(
+            Shape::new([batch_size, 3, top + height + bottom, left + width + right]),
+            options.pad_value.into(),
+            &device,
+            F::dtype().into(),
+        );
+        let output = photometric_augment::<R, F>(
+            images,
+            factors,
+            mean_gray,
+            normalization,
+            output,
+            [top, left],
+        );
+
+        Self::float_cast(output, dtype)
+    }
 }
 impl<R, F, I, BT> VisionBackend for CubeBackend<R, F, I, BT>
 where
@@ -106,7 +182,7 @@ mod fusion {
     use burn_core::tensor::Shape;
     use burn_fusion::{
         Fusion, FusionBackend, FusionRuntime,
-        stream::{StreamId, Operation},
+        stream::{Operation, StreamId},
     };
     use burn_ir::{CustomOpIr, HandleContainer, OperationIr, OperationOutput, TensorIr};
 
@@ -252,6 +328,59 @@ mod fusion {
         }
     }
     impl<B: FusionBackend + IntVisionOps> IntVisionOps for Fusion<B> {}
-    impl<B: FusionBackend + FloatVisionOps> FloatVisionOps for Fusion<B> {}
+    impl<B: FusionBackend + FloatVisionOps> FloatVisionOps for Fusion<B> {
+        fn photometric_augment(
+            images: FloatTensor<Self>,
+            factors: FloatTensor<Self>,
+            options: PhotometricOptions,
+        ) -> FloatTensor<Self> {
+            let [batch_size, _, height, width] = images.shape.dims();
+            let [(top, bottom), (left, right)] = options.padding;
+            let client = images.client.clone();
+
+            #[derive(derive_new::new, Clone, Debug)]
+            struct Photometric<B> {
+                desc: CustomOpIr,
+                options: PhotometricOptions,
+                _b: core::marker::PhantomData<B>,
+            }
+
+            impl<B1: FusionBackend + FloatVisionOps> Operation<B1::FusionRuntime> for Photometric<B1> {
+                fn execute(
+                    &self,
+                    handles: &mut HandleContainer<
+                        <B1::FusionRuntime as FusionRuntime>::FusionHandle,
+                    >,
+                ) {
+                    let ([images, factors], [output]) = self.desc.as_fixed();
+                    let images = handles.get_float_tensor::<B1>(images);
+                    let factors = handles.get_float_tensor::<B1>(factors);
+                    let augmented = B1::photometric_augment(images, factors, self.options);
+
+                    handles.register_float_tensor::<B1>(&output.id, augmented);
+                }
+            }
+
+            let streams = StreamId::current();
+            let out = TensorIr::uninit(
+                client.create_empty_handle(),
+                Shape::new([batch_size, 3, top + height + bottom, left + width + right]),
+                images.dtype,
+            );
+
+            let desc = CustomOpIr::new(
+                "photometric_augment",
+                &[images.into_ir(), factors.into_ir()],
+                &[out],
+            );
+            client
+                .register(
+                    streams,
+                    OperationIr::Custom(desc.clone()),
+                    Photometric::<B>::new(desc, options),
+                )
+                .output()
+        }
+    }
     impl<B: FusionBackend + VisionBackend> VisionBackend for Fusion<B> {}
 }
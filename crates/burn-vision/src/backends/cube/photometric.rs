@@ -0,0 +1,166 @@
+//! Fused photometric augmentation: color jitter, normalization and padding in a single pass
+//! over the pixels.
+
+use burn_cubecl::{CubeRuntime, FloatElement, tensor::CubeTensor};
+use cubecl::prelude::*;
+
+/// Jitters one pixel per thread, and writes it normalized at its padded position.
+///
+/// `mean_gray` holds the mean grayscale value of each image after the brightness adjustment, and
+/// `normalization` the mean then the standard deviation of each channel.
+#[cube(launch_unchecked)]
+fn photometric_kernel<F: Float>(
+    images: &Tensor<F>,
+    factors: &Tensor<F>,
+    mean_gray: &Tensor<F>,
+    normalization: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] top: usize,
+    #[comptime] left: usize,
+) {
+    let height = images.shape(2);
+    let width = images.shape(3);
+    if ABSOLUTE_POS >= images.shape(0) * height * width {
+        terminate!();
+    }
+
+    let x = ABSOLUTE_POS % width;
+    let y = (ABSOLUTE_POS / width) % height;
+    let n = ABSOLUTE_POS / (width * height);
+
+    let factor = n * factors.stride(0);
+    let brightness = factors[factor];
+    let contrast = factors[factor + factors.stride(1)];
+    let saturation = factors[factor + 2 * factors.stride(1)];
+    let hue = factors[factor + 3 * factors.stride(1)];
+
+    let pixel = n * images.stride(0) + y * images.stride(2) + x * images.stride(3);
+    let channel = images.stride(1);
+    let zero = F::new(0.0);
+    let one = F::new(1.0);
+    let r = clamp(images[pixel] * brightness, zero, one);
+    let g = clamp(images[pixel + channel] * brightness, zero, one);
+    let b = clamp(images[pixel + 2 * channel] * brightness, zero, one);
+
+    let mean = mean_gray[n * mean_gray.stride(0)];
+    let r = blend::<F>(r, mean, contrast);
+    let g = blend::<F>(g, mean, contrast);
+    let b = blend::<F>(b, mean, contrast);
+
+    let gray = grayscale::<F>(r, g, b);
+    let r = blend::<F>(r, gray, saturation);
+    let g = blend::<F>(g, gray, saturation);
+    let b = blend::<F>(b, gray, saturation);
+
+    let (r, g, b) = shift_hue::<F>(r, g, b, hue);
+
+    let out = n * output.stride(0) + (y + top) * output.stride(2) + (x + left) * output.stride(3);
+    let out_channel = output.stride(1);
+    output[out] = (r - normalization[0]) / normalization[3];
+    output[out + out_channel] = (g - normalization[1]) / normalization[4];
+    output[out + 2 * out_channel] = (b - normalization[2]) / normalization[5];
+}
+
+#[cube]
+fn grayscale<F: Float>(r: F, g: F, b: F) -> F {
+    r * F::new(0.2989) + g * F::new(0.587) + b * F::new(0.114)
+}
+
+#[cube]
+fn blend<F: Float>(value: F, other: F, factor: F) -> F {
+    clamp(
+        factor * value + (F::new(1.0) - factor) * other,
+        F::new(0.0),
+        F::new(1.0),
+    )
+}
+
+/// Rotates the hue of a pixel through the HSV color space, without branching.
+#[cube]
+fn shift_hue<F: Float>(r: F, g: F, b: F, shift: F) -> (F, F, F) {
+    let value = select(r > g, r, g);
+    let value = select(value > b, value, b);
+    let min = select(r < g, r, g);
+    let min = select(min < b, min, b);
+    let chroma = value - min;
+
+    // Grays have no hue, and are kept as they are.
+    let is_gray = chroma == F::new(0.0);
+    let safe_chroma = select(is_gray, F::new(1.0), chroma);
+    let saturation = chroma / select(is_gray, F::new(1.0), value);
+
+    let sector = select(
+        value == r,
+        (g - b) / safe_chroma,
+        select(
+            value == g,
+            F::new(2.0) + (b - r) / safe_chroma,
+            F::new(4.0) + (r - g) / safe_chroma,
+        ),
+    );
+    let hue = sector / F::new(6.0) + shift;
+    let hue = (hue - hue.floor()) * F::new(6.0);
+
+    let i = hue.floor();
+    let f = hue - i;
+    let p = value * (F::new(1.0) - saturation);
+    let q = value * (F::new(1.0) - saturation * f);
+    let t = value * (F::new(1.0) - saturation * (F::new(1.0) - f));
+
+    let i = u32::cast_from(i) % 6;
+    let r_out = select(
+        i == 0 || i == 5,
+        value,
+        select(i == 1, q, select(i == 4, t, p)),
+    );
+    let g_out = select(
+        i == 1 || i == 2,
+        value,
+        select(i == 0, t, select(i == 3, q, p)),
+    );
+    let b_out = select(
+        i == 3 || i == 4,
+        value,
+        select(i == 2, t, select(i == 5, q, p)),
+    );
+
+    (
+        select(is_gray, r, r_out),
+        select(is_gray, g, g_out),
+        select(is_gray, b, b_out),
+    )
+}
+
+/// Launches the fused kernel on `[batches, 3, height, width]` images, writing into `output`,
+/// which is already filled with the padding value.
+pub(crate) fn photometric_augment<R: CubeRuntime, F: FloatElement>(
+    images: CubeTensor<R>,
+    factors: CubeTensor<R>,
+    mean_gray: CubeTensor<R>,
+    normalization: CubeTensor<R>,
+    output: CubeTensor<R>,
+    [top, left]: [usize; 2],
+) -> CubeTensor<R> {
+    let client = images.client.clone();
+    let num_pixels = images.meta.shape().num_elements() / 3;
+
+    let cube_dim = CubeDim::new_1d(256);
+    let cube_count = CubeCount::new_1d(num_pixels.div_ceil(256) as u32);
+
+    unsafe {
+        photometric_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            images.into_tensor_arg(),
+            factors.into_tensor_arg(),
+            mean_gray.into_tensor_arg(),
+            normalization.into_tensor_arg(),
+            output.clone().into_tensor_arg(),
+            top,
+            left,
+        )
+    };
+
+    output
+}
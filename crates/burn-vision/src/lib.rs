@@ -7,6 +7,18 @@
 //! - `connected_components`
 //! - `connected_components_with_stats`
 //! - `nms` (Non-Maximum Suppression)
+//! - `hog` (Histogram of Oriented Gradients)
+//! - `hough_lines`, `hough_lines_p` and `hough_circles` (Hough transforms)
+//! - `integral_image`
+//! - `box_filter`
+//! - `bilateral_filter` and `guided_filter` (edge-preserving smoothing)
+//! - `pyr_down` and `pyr_up` (Gaussian and Laplacian pyramids)
+//! - `find_contours` and `approx_poly_dp` (contour extraction and polygon approximation)
+//! - `flood_fill`
+//! - `watershed`
+//! - `match_histograms` and `transfer_color` (color distribution transfer)
+//! - `photometric_augment` (color jitter, normalization and padding)
+//! - `AugmentPolicy` (RandAugment, TrivialAugment and AutoAugment policies)
 //!
 
 #![warn(missing_docs)]
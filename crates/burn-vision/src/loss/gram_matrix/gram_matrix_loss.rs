@@ -129,16 +129,18 @@ impl GramMatrixLoss<B> {
     /// - `reduction` - Specifies how to reduce the batch losses.
     ///   - `Reduction::Mean` or `Reduction::Auto`: Returns the mean of batch losses.
     ///   - `Reduction::Sum`: Returns the sum of batch losses.
+    ///   - `Reduction::None`: Returns the batch losses.
     ///
     /// # Returns
     ///
-    /// A scalar tensor containing the reduced loss value.
+    /// A scalar tensor containing the reduced loss value, or the batch losses with
+    /// `Reduction::None`.
     ///
     /// # Shapes
     ///
     /// - predictions: `[batch_size, 3, height, width]`
     /// - targets: `[batch_size, 3, height, width]`
-    /// - output: `[1]`
+    /// - output: `[1]`, or `[batch_size]` with `Reduction::None`
     ///
     /// # Panics
     ///
@@ -171,6 +173,7 @@ impl GramMatrixLoss<B> {
         match reduction {
             Reduction::Mean | Reduction::Auto => unreduced_loss.mean(),
             Reduction::Sum => unreduced_loss.sum(),
+            Reduction::None => unreduced_loss,
             other => panic!("{other:?} reduction is not supported"),
         }
     }
@@ -5,8 +5,13 @@ use crate::{
 use bon::Builder;
 
 use burn_core as burn; // for backend_extension
-use burn_core::backend::{Backend, backend_extension, tensor::IntTensor};
-use burn_core::tensor::{Int, IntDType, Scalar, Tensor, read_sync};
+use burn_core::backend::{
+    Backend, TensorMetadata, backend_extension, get_device_settings,
+    tensor::{FloatTensor, IntTensor},
+};
+use burn_core::tensor::{
+    BoolDType, FloatDType, Int, IntDType, Scalar, Shape, Slice, Tensor, TensorData, read_sync,
+};
 
 /// Connected components connectivity
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -42,7 +47,7 @@ pub struct MorphOptions {
     /// Border type. Default: constant based on operation
     #[builder(default)]
     pub border_type: BorderType,
-    /// Value of each channel for constant border type
+    /// Value of each channel for constant border type, or a single value for all channels
     pub border_value: Option<Vec<Scalar>>,
 }
 
@@ -158,6 +163,260 @@ impl Default for NmsOptions {
     }
 }
 
+/// Histogram of oriented gradients options.
+///
+/// The defaults are the parameters of Dalal and Triggs: 9 unsigned orientation bins, cells of
+/// 8x8 pixels and blocks of 2x2 cells overlapping by one cell.
+#[derive(Clone, Copy, Debug)]
+pub struct HogOptions {
+    /// Number of orientation bins of each cell histogram (default: 9).
+    pub num_bins: usize,
+    /// Size of a cell in pixels, as `[height, width]` (default: `[8, 8]`).
+    pub cell_size: [usize; 2],
+    /// Size of a normalization block in cells, as `[height, width]` (default: `[2, 2]`).
+    pub block_size: [usize; 2],
+    /// Step between two blocks in cells, as `[height, width]` (default: `[1, 1]`).
+    pub block_stride: [usize; 2],
+    /// Whether the orientations cover `[0, 360)` degrees instead of `[0, 180)` (default: false).
+    pub signed: bool,
+}
+
+impl Default for HogOptions {
+    fn default() -> Self {
+        Self {
+            num_bins: 9,
+            cell_size: [8, 8],
+            block_size: [2, 2],
+            block_stride: [1, 1],
+            signed: false,
+        }
+    }
+}
+
+/// Standard Hough line transform options.
+#[derive(Clone, Copy, Debug)]
+pub struct HoughLinesOptions {
+    /// Distance resolution of the accumulator in pixels (default: 1.0).
+    pub rho: f32,
+    /// Angle resolution of the accumulator in radians (default: 1 degree).
+    pub theta: f32,
+    /// Lines need more than this number of votes to be returned (default: 100).
+    pub threshold: usize,
+    /// Maximum number of lines to return (0 = unlimited, default: 0).
+    pub max_lines: usize,
+}
+
+impl Default for HoughLinesOptions {
+    fn default() -> Self {
+        Self {
+            rho: 1.0,
+            theta: core::f32::consts::PI / 180.0,
+            threshold: 100,
+            max_lines: 0,
+        }
+    }
+}
+
+/// Probabilistic Hough line transform options.
+#[derive(Clone, Copy, Debug)]
+pub struct HoughLinesPOptions {
+    /// Distance resolution of the accumulator in pixels (default: 1.0).
+    pub rho: f32,
+    /// Angle resolution of the accumulator in radians (default: 1 degree).
+    pub theta: f32,
+    /// Number of votes for a line to be followed (default: 50).
+    pub threshold: usize,
+    /// Segments shorter than this length in pixels along their major axis are rejected
+    /// (default: 0).
+    pub min_line_length: usize,
+    /// Maximum gap in pixels between two points of the same segment (default: 0).
+    pub max_line_gap: usize,
+    /// Maximum number of segments to return (0 = unlimited, default: 0).
+    pub max_lines: usize,
+}
+
+impl Default for HoughLinesPOptions {
+    fn default() -> Self {
+        Self {
+            rho: 1.0,
+            theta: core::f32::consts::PI / 180.0,
+            threshold: 50,
+            min_line_length: 0,
+            max_line_gap: 0,
+            max_lines: 0,
+        }
+    }
+}
+
+/// Hough circle transform options.
+#[derive(Clone, Copy, Debug)]
+pub struct HoughCirclesOptions {
+    /// Smallest radius searched, in pixels (default: 5).
+    pub min_radius: usize,
+    /// Largest radius searched, in pixels (default: 50).
+    pub max_radius: usize,
+    /// Minimum fraction of the perimeter of a circle covered by edges (default: 0.5).
+    pub threshold: f32,
+    /// Minimum distance between the centers of two circles (default: 10.0).
+    pub min_dist: f32,
+    /// Maximum number of circles to return (0 = unlimited, default: 0).
+    pub max_circles: usize,
+}
+
+impl Default for HoughCirclesOptions {
+    fn default() -> Self {
+        Self {
+            min_radius: 5,
+            max_radius: 50,
+            threshold: 0.5,
+            min_dist: 10.0,
+            max_circles: 0,
+        }
+    }
+}
+
+/// Flood fill options.
+#[derive(Clone, Copy, Debug)]
+pub struct FloodFillOptions {
+    /// Maximum difference below the reference value for a pixel to be filled (default: 0.0).
+    pub lo_diff: f32,
+    /// Maximum difference above the reference value for a pixel to be filled (default: 0.0).
+    pub up_diff: f32,
+    /// Neighbors a pixel is filled from (default: [`Connectivity::Four`]).
+    pub connectivity: Connectivity,
+    /// Whether the reference value is the seed value rather than the value of the filled neighbor
+    /// (default: false).
+    pub fixed_range: bool,
+}
+
+impl Default for FloodFillOptions {
+    fn default() -> Self {
+        Self {
+            lo_diff: 0.0,
+            up_diff: 0.0,
+            connectivity: Connectivity::Four,
+            fixed_range: false,
+        }
+    }
+}
+
+/// How the bilateral filter is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum BilateralMethod {
+    /// Exact for windows of up to 11 pixels across, bilateral grid for larger ones.
+    #[default]
+    Auto,
+    /// Sums over the whole window of each pixel, in `O(diameter²)` per pixel.
+    Exact,
+    /// Bilateral grid approximation of Paris and Durand, whose cost doesn't depend on the window
+    /// size. The window is a Gaussian of `sigma_space` and `diameter` is ignored.
+    Grid,
+}
+
+/// Bilateral filter options.
+#[derive(Clone, Copy, Debug)]
+pub struct BilateralFilterOptions {
+    /// Diameter of the window of each pixel, or 0 to derive it from `sigma_space` (default: 0).
+    pub diameter: usize,
+    /// Standard deviation of the weights over the value differences (default: 0.1).
+    pub sigma_color: f32,
+    /// Standard deviation of the weights over the distances, in pixels (default: 3.0).
+    pub sigma_space: f32,
+    /// How the filter is computed (default: [`BilateralMethod::Auto`]).
+    pub method: BilateralMethod,
+}
+
+impl Default for BilateralFilterOptions {
+    fn default() -> Self {
+        Self {
+            diameter: 0,
+            sigma_color: 0.1,
+            sigma_space: 3.0,
+            method: BilateralMethod::Auto,
+        }
+    }
+}
+
+/// Photometric augmentation options, applied after the color jitter.
+#[derive(Clone, Copy, Debug)]
+pub struct PhotometricOptions {
+    /// Mean of each channel, subtracted from the jittered images (default: `[0.0; 3]`).
+    pub mean: [f32; 3],
+    /// Standard deviation of each channel, dividing the centered images (default: `[1.0; 3]`).
+    pub std: [f32; 3],
+    /// Padding of the images, as `[(top, bottom), (left, right)]` (default: no padding).
+    pub padding: [(usize, usize); 2],
+    /// Value of the padded pixels, after normalization (default: 0.0).
+    pub pad_value: f32,
+}
+
+impl Default for PhotometricOptions {
+    fn default() -> Self {
+        Self {
+            mean: [0.0; 3],
+            std: [1.0; 3],
+            padding: [(0, 0); 2],
+            pad_value: 0.0,
+        }
+    }
+}
+
+/// Which contours `find_contours` should retrieve, and how they are related.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ContourRetrieval {
+    /// Only the outer borders of the objects that are not inside a hole.
+    External,
+    /// All the borders, without hierarchy.
+    List,
+    /// All the borders, with the hierarchy of the objects and holes.
+    #[default]
+    Tree,
+}
+
+/// How `find_contours` should store the points of a contour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ContourApproximation {
+    /// Every pixel of the border.
+    None,
+    /// Only the end points of the horizontal, vertical and diagonal segments of the border.
+    #[default]
+    Simple,
+}
+
+/// A contour found by `find_contours`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Contour {
+    /// Pixels of the border, in order.
+    pub points: Vec<Point>,
+    /// Whether the contour is the border of a hole rather than the outer border of an object.
+    pub is_hole: bool,
+    /// Index of the parent contour: the outer border of the object a hole is in, or the hole an
+    /// object is in. Always `None` unless retrieved with [`ContourRetrieval::Tree`].
+    pub parent: Option<usize>,
+}
+
+impl Contour {
+    /// The area enclosed by the contour, as a polygon through its points.
+    pub fn area(&self) -> f64 {
+        cpu::contour_area(&self.points)
+    }
+
+    /// The length of the closed contour.
+    pub fn perimeter(&self) -> f64 {
+        cpu::arc_length(&self.points, true)
+    }
+
+    /// Approximates the contour with fewer points, so that no point of the contour is farther
+    /// than `epsilon` from the approximation (Ramer-Douglas-Peucker).
+    pub fn approx_poly_dp(&self, epsilon: f64) -> Contour {
+        Contour {
+            points: cpu::approx_poly_dp(&self.points, epsilon),
+            is_hole: self.is_hole,
+            parent: self.parent,
+        }
+    }
+}
+
 #[cfg(feature = "flex")]
 use burn_core::backend::Flex;
 
@@ -283,6 +542,102 @@ pub trait BoolVisionOps: Backend {
 
         Self::bool_from_data(morph::<Self>(input, kernel, MorphOp::Dilate, opts), &device)
     }
+
+    /// Finds the lines of a binary edge image with the standard Hough transform.
+    ///
+    /// Each edge pixel votes for the lines `x * cos(theta) + y * sin(theta) = rho` going through
+    /// it, in an accumulator of `(rho, theta)` cells. The cells that are local maxima with more
+    /// than `threshold` votes are returned by decreasing number of votes, as in `opencv`.
+    ///
+    /// # Arguments
+    /// * `image` - Edges as \[height, width\] tensor
+    /// * `options` - Hough options (resolutions, vote threshold and maximum number of lines)
+    ///
+    /// # Returns
+    /// Lines as \[N, 2\] tensor of `(rho, theta)`, with `theta` in `[0, π)`
+    fn hough_lines(
+        image: BoolTensor<Self>,
+        options: HoughLinesOptions,
+        out_dtype: FloatDType,
+    ) -> FloatTensor<Self> {
+        let device = Self::bool_device(&image);
+        let image = read_sync(Self::bool_into_data(image)).expect("Should read data");
+        let lines = cpu::hough_lines::<Self>(image, options).convert_dtype(out_dtype.into());
+
+        Self::float_from_data(lines, &device)
+    }
+
+    /// Finds the line segments of a binary edge image with the progressive probabilistic Hough
+    /// transform.
+    ///
+    /// Edge pixels are sampled in a random order, with the same deterministic generator as
+    /// `opencv`, and vote for their lines. When a line reaches `threshold` votes, its segment is
+    /// followed through gaps of at most `max_line_gap` pixels, and kept if it is at least
+    /// `min_line_length` long. The pixels of the segment no longer vote for other lines.
+    ///
+    /// # Arguments
+    /// * `image` - Edges as \[height, width\] tensor
+    /// * `options` - Hough options (resolutions, vote threshold, segment length and gaps)
+    ///
+    /// # Returns
+    /// Segments as \[N, 4\] tensor of `(x1, y1, x2, y2)`
+    fn hough_lines_p(
+        image: BoolTensor<Self>,
+        options: HoughLinesPOptions,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        let device = Self::bool_device(&image);
+        let image = read_sync(Self::bool_into_data(image)).expect("Should read data");
+        let segments = cpu::hough_lines_p::<Self>(image, options).convert_dtype(out_dtype.into());
+
+        Self::int_from_data(segments, &device)
+    }
+
+    /// Finds the circles of a binary edge image with the Hough transform.
+    ///
+    /// Each edge pixel votes for the centers of the circles of each radius in
+    /// `min_radius..=max_radius` going through it. A circle is scored by the fraction of its
+    /// perimeter covered by edges, and the local maxima in position and radius with a score of at
+    /// least `threshold` are returned by decreasing score, skipping the circles whose center is
+    /// closer than `min_dist` to a better one.
+    ///
+    /// # Arguments
+    /// * `image` - Edges as \[height, width\] tensor
+    /// * `options` - Hough options (radius range, score threshold, distance between centers)
+    ///
+    /// # Returns
+    /// Circles as \[N, 3\] tensor of `(x, y, radius)`
+    fn hough_circles(
+        image: BoolTensor<Self>,
+        options: HoughCirclesOptions,
+        out_dtype: FloatDType,
+    ) -> FloatTensor<Self> {
+        let device = Self::bool_device(&image);
+        let image = read_sync(Self::bool_into_data(image)).expect("Should read data");
+        let circles = cpu::hough_circles::<Self>(image, options).convert_dtype(out_dtype.into());
+
+        Self::float_from_data(circles, &device)
+    }
+
+    /// Finds the contours of the objects of a binary mask with the border following algorithm of
+    /// Suzuki and Abe, like `opencv`'s `findContours`.
+    ///
+    /// Objects are 8-connected and their holes 4-connected. The contours are returned in raster
+    /// order of their first pixel, and with [`ContourRetrieval::Tree`] each contour records its
+    /// parent.
+    ///
+    /// # Arguments
+    /// * `mask` - Mask as \[height, width\] tensor
+    /// * `mode` - Which contours to retrieve
+    /// * `approximation` - Which points of the contours to keep
+    fn find_contours(
+        mask: BoolTensor<Self>,
+        mode: ContourRetrieval,
+        approximation: ContourApproximation,
+    ) -> Vec<Contour> {
+        let mask = read_sync(Self::bool_into_data(mask)).expect("Should read data");
+        cpu::find_contours::<Self>(mask, mode, approximation)
+    }
 }
 
 #[backend_extension(
@@ -390,4 +745,480 @@ pub trait FloatVisionOps: Backend {
             None => Self::int_zeros([0].into(), &device, out_dtype),
         }
     }
+
+    /// Computes the histogram of oriented gradients (HOG) descriptor of each image.
+    ///
+    /// The gradients are central differences, and the magnitude of each pixel is added to the
+    /// orientation bin of its cell. The cell histograms are averaged over the cell, grouped into
+    /// overlapping blocks, and each block is normalized with the L2-Hys scheme (L2 normalization,
+    /// clipping at 0.2 and renormalization). This matches `skimage.feature.hog` with
+    /// `block_norm="L2-Hys"` for a block stride of one cell.
+    ///
+    /// # Arguments
+    /// * `images` - Grayscale images as \[batches, height, width\] tensor
+    /// * `options` - HOG options (bins, cell size, block size and stride)
+    ///
+    /// # Returns
+    /// Descriptors as \[batches, blocks_y * blocks_x * block_height * block_width * bins\] tensor,
+    /// ordered by block row, block column, cell row, cell column and bin
+    fn hog(images: FloatTensor<Self>, options: HogOptions) -> FloatTensor<Self> {
+        let device = Self::float_device(&images);
+        let images = read_sync(Self::float_into_data(images)).expect("Should read data");
+
+        Self::float_from_data(cpu::hog(images, options), &device)
+    }
+
+    /// Computes the integral image (summed-area table) of each image.
+    ///
+    /// Each value is the sum of the pixels above and to the left of it, excluded. As in
+    /// `opencv`, the output has an extra first row and column of zeros, so that the sum of the
+    /// pixels in rows `y0..y1` and columns `x0..x1` is
+    /// `out[y1, x1] - out[y0, x1] - out[y1, x0] + out[y0, x0]`.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    ///
+    /// # Returns
+    /// Integral images as \[batches, height + 1, width + 1\] tensor
+    fn integral_image(images: FloatTensor<Self>) -> FloatTensor<Self> {
+        let device = Self::float_device(&images);
+        let images = read_sync(Self::float_into_data(images)).expect("Should read data");
+
+        Self::float_from_data(cpu::integral_image(images), &device)
+    }
+
+    /// Sums or averages each image over a window centered on each pixel, in constant time per
+    /// pixel with the [integral image](FloatVisionOps::integral_image).
+    ///
+    /// The window is anchored at `kernel_size / 2` like in `opencv`, and clipped at the borders of
+    /// the image, so the mean near the borders is taken over the pixels inside the image only.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    /// * `kernel_size` - Size of the window, as `[height, width]`
+    /// * `normalize` - Whether to average instead of sum the pixels of each window
+    ///
+    /// # Returns
+    /// Filtered images with the same shape as the input
+    fn box_filter(
+        images: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        normalize: bool,
+    ) -> FloatTensor<Self> {
+        let shape = images.shape();
+        let [_, height, width] = shape.dims();
+        let [kernel_height, kernel_width] = kernel_size;
+        assert!(
+            kernel_height > 0 && kernel_width > 0,
+            "Box filter expects a non-empty kernel, got {kernel_size:?}"
+        );
+
+        let device = Self::float_device(&images);
+        let dtype = images.dtype();
+        let indices = |indices: Vec<i64>| index_tensor::<Self>(indices, &device);
+
+        let (top, bottom) = box_window_bounds(height, kernel_height);
+        let (left, right) = box_window_bounds(width, kernel_width);
+
+        let integral = Self::integral_image(images);
+        let rows_top = Self::float_select(integral.clone(), 1, indices(top.clone()));
+        let rows_bottom = Self::float_select(integral, 1, indices(bottom.clone()));
+        let left_indices = indices(left.clone());
+        let right_indices = indices(right.clone());
+
+        let sums = Self::float_add(
+            Self::float_sub(
+                Self::float_select(rows_bottom.clone(), 2, right_indices.clone()),
+                Self::float_select(rows_bottom, 2, left_indices.clone()),
+            ),
+            Self::float_sub(
+                Self::float_select(rows_top.clone(), 2, left_indices),
+                Self::float_select(rows_top, 2, right_indices),
+            ),
+        );
+
+        if !normalize {
+            return sums;
+        }
+
+        let mut areas = Vec::with_capacity(height * width);
+        for (top, bottom) in top.iter().zip(&bottom) {
+            for (left, right) in left.iter().zip(&right) {
+                areas.push(((bottom - top) * (right - left)) as f32);
+            }
+        }
+        let areas = Self::float_from_data(
+            TensorData::new(areas, Shape::new([1, height, width])).convert_dtype(dtype),
+            &device,
+        );
+
+        Self::float_div(sums, Self::float_expand(areas, shape))
+    }
+
+    /// Smooths each image while preserving its edges with a bilateral filter, like `opencv`'s
+    /// `bilateralFilter`.
+    ///
+    /// Each pixel is the average of its window, weighted by a Gaussian of the distance to the
+    /// pixel (`sigma_space`) and a Gaussian of the difference with its value (`sigma_color`), so
+    /// that pixels across an edge barely contribute. The window is a disk of `diameter` pixels,
+    /// or of `3 * sigma_space` pixels when 0, clipped at the borders of the image. Large windows
+    /// are approximated with a bilateral grid, see [`BilateralMethod`].
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    /// * `options` - Bilateral filter options (window, sigmas, method)
+    ///
+    /// # Returns
+    /// Filtered images with the same shape as the input
+    fn bilateral_filter(
+        images: FloatTensor<Self>,
+        options: BilateralFilterOptions,
+    ) -> FloatTensor<Self> {
+        let device = Self::float_device(&images);
+        let images = read_sync(Self::float_into_data(images)).expect("Should read data");
+
+        Self::float_from_data(cpu::bilateral_filter(images, options), &device)
+    }
+
+    /// Smooths each image while preserving the edges of its guide, with the guided filter of He
+    /// et al.
+    ///
+    /// Over the window of each pixel, the image is fitted as a linear transform
+    /// `a * guide + b` of the guide, and the output is the transform averaged over the windows
+    /// covering the pixel. `eps` regularizes `a`: the larger it is, the more the regions where
+    /// the guide varies less than `sqrt(eps)` are smoothed.
+    ///
+    /// The means are computed with [box filters](FloatVisionOps::box_filter) of
+    /// `2 * radius + 1` pixels, so the cost doesn't depend on the radius and the windows are
+    /// clipped at the borders of the image.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    /// * `guide` - Guide images with the same shape, usually the images themselves
+    /// * `radius` - Radius of the window
+    /// * `eps` - Regularization of the fit
+    ///
+    /// # Returns
+    /// Filtered images with the same shape as the input
+    fn guided_filter(
+        images: FloatTensor<Self>,
+        guide: FloatTensor<Self>,
+        radius: usize,
+        eps: f32,
+    ) -> FloatTensor<Self> {
+        assert_eq!(
+            images.shape(),
+            guide.shape(),
+            "Guided filter expects a guide of the shape of the images"
+        );
+
+        let kernel_size = [2 * radius + 1; 2];
+        let mean = |images| Self::box_filter(images, kernel_size, true);
+
+        let mean_guide = mean(guide.clone());
+        let mean_images = mean(images.clone());
+        let var_guide = Self::float_sub(
+            mean(Self::float_mul(guide.clone(), guide.clone())),
+            Self::float_mul(mean_guide.clone(), mean_guide.clone()),
+        );
+        let cov = Self::float_sub(
+            mean(Self::float_mul(guide.clone(), images)),
+            Self::float_mul(mean_guide.clone(), mean_images.clone()),
+        );
+
+        let a = Self::float_div(cov, Self::float_add_scalar(var_guide, eps.into()));
+        let b = Self::float_sub(mean_images, Self::float_mul(a.clone(), mean_guide));
+
+        Self::float_add(Self::float_mul(mean(a), guide), mean(b))
+    }
+
+    /// Matches the histogram of each channel of each image to the histogram of the same channel
+    /// of a reference image, like `scikit-image`'s `match_histograms`.
+    ///
+    /// Each value is mapped to the reference value at the same quantile, interpolated linearly
+    /// between the distinct reference values, so equal values stay equal and the order of the
+    /// values is kept.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, channels, height, width\] tensor
+    /// * `reference` - Reference images as \[batches, channels, height, width\] tensor, or
+    ///   \[1, channels, height, width\] to match all the images to the same reference. The size
+    ///   of the reference images can differ from the size of the images.
+    ///
+    /// # Returns
+    /// Matched images with the same shape as the input
+    fn match_histograms(
+        images: FloatTensor<Self>,
+        reference: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let device = Self::float_device(&images);
+        let images = read_sync(Self::float_into_data(images)).expect("Should read data");
+        let reference = read_sync(Self::float_into_data(reference)).expect("Should read data");
+
+        Self::float_from_data(cpu::match_histograms(images, reference), &device)
+    }
+
+    /// Jitters the colors of RGB images, then normalizes and pads them, to augment a batch on
+    /// the device it was decoded to.
+    ///
+    /// As in `torchvision`'s `ColorJitter`, the factors of each image are applied in order:
+    /// - the brightness scales the values,
+    /// - the contrast blends the image with the mean of its grayscale image,
+    /// - the saturation blends each pixel with its grayscale value,
+    /// - the hue rotates the hue of each pixel in the HSV color space, by a fraction of a turn.
+    ///
+    /// The values are clamped to `[0, 1]` after each blend. The neutral factors are
+    /// `[1.0, 1.0, 1.0, 0.0]`, and random ones can be drawn uniformly around them for each image.
+    ///
+    /// # Arguments
+    /// * `images` - RGB images with values in `[0, 1]`, as \[batches, 3, height, width\] tensor
+    /// * `factors` - Brightness, contrast, saturation and hue factors of each image, as
+    ///   \[batches, 4\] tensor
+    /// * `options` - Normalization and padding options
+    ///
+    /// # Returns
+    /// Augmented images as \[batches, 3, top + height + bottom, left + width + right\] tensor
+    fn photometric_augment(
+        images: FloatTensor<Self>,
+        factors: FloatTensor<Self>,
+        options: PhotometricOptions,
+    ) -> FloatTensor<Self> {
+        let device = Self::float_device(&images);
+        let images = read_sync(Self::float_into_data(images)).expect("Should read data");
+        let factors = read_sync(Self::float_into_data(factors)).expect("Should read data");
+
+        Self::float_from_data(cpu::photometric_augment(images, factors, options), &device)
+    }
+
+    /// Blurs and downsamples each image, to build the next level of a Gaussian pyramid.
+    ///
+    /// As in `opencv`, the images are convolved with the 5x5 Gaussian kernel
+    /// `[1, 4, 6, 4, 1]ᵀ · [1, 4, 6, 4, 1] / 256`, with reflected borders (`BORDER_REFLECT_101`),
+    /// and every other row and column is dropped. The kernel is separable, so it is applied as a
+    /// vertical then a horizontal pass, which only compute the kept rows and columns.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    ///
+    /// # Returns
+    /// Downsampled images as \[batches, (height + 1) / 2, (width + 1) / 2\] tensor
+    fn pyr_down(images: FloatTensor<Self>) -> FloatTensor<Self> {
+        let [_, height, width] = images.shape().dims();
+        assert!(
+            height > 0 && width > 0,
+            "Pyramid downsampling expects non-empty images"
+        );
+
+        let images = pyr_down_pass::<Self>(images, 1, height);
+        pyr_down_pass::<Self>(images, 2, width)
+    }
+
+    /// Upsamples and blurs each image, to go back to the previous level of a Gaussian pyramid.
+    ///
+    /// As in `opencv`, the images are upsampled by inserting zero rows and columns, and convolved
+    /// with four times the [pyr_down](FloatVisionOps::pyr_down) kernel. The kernel is applied as
+    /// two separable passes, each interleaving the `[1, 6, 1] / 8` and `[4, 4] / 8` filters of
+    /// the even and odd positions.
+    ///
+    /// # Arguments
+    /// * `images` - Images as \[batches, height, width\] tensor
+    /// * `size` - Size of the output, as `[height, width]`. Each dimension must be twice the
+    ///   input one, or one less, to go back to an odd sized level.
+    ///
+    /// # Returns
+    /// Upsampled images as \[batches, size\[0\], size\[1\]\] tensor
+    fn pyr_up(images: FloatTensor<Self>, size: [usize; 2]) -> FloatTensor<Self> {
+        let [_, height, width] = images.shape().dims();
+        assert!(
+            height > 0 && width > 0,
+            "Pyramid upsampling expects non-empty images"
+        );
+        assert!(
+            size[0].div_ceil(2) == height && size[1].div_ceil(2) == width,
+            "Pyramid upsampling of [{height}, {width}] images expects an output size of twice \
+             their size, or one less, got {size:?}"
+        );
+
+        let images = pyr_up_pass::<Self>(images, 1, size[0]);
+        pyr_up_pass::<Self>(images, 2, size[1])
+    }
+
+    /// Fills the connected region of an image around `seed` with `new_value`, like `opencv`'s
+    /// `floodFill`.
+    ///
+    /// Starting from the seed, each neighbor of a filled pixel is filled when its value is between
+    /// `reference - lo_diff` and `reference + up_diff`, the reference being the value of the
+    /// filled pixel, or the seed value with `fixed_range`.
+    ///
+    /// # Arguments
+    /// * `image` - Image as \[height, width\] tensor
+    /// * `seed` - First pixel to fill
+    /// * `new_value` - Value of the filled pixels
+    /// * `options` - Flood fill options (tolerances, connectivity, range)
+    /// * `mask_dtype` - Dtype of the mask
+    ///
+    /// # Returns
+    /// The filled image, and the mask of the filled pixels as \[height, width\] tensor
+    fn flood_fill(
+        image: FloatTensor<Self>,
+        seed: Point,
+        new_value: f32,
+        options: FloodFillOptions,
+        mask_dtype: BoolDType,
+    ) -> (FloatTensor<Self>, BoolTensor<Self>) {
+        let device = Self::float_device(&image);
+        let image = read_sync(Self::float_into_data(image)).expect("Should read data");
+        let (image, mask) = cpu::flood_fill(image, seed, new_value, options);
+
+        (
+            Self::float_from_data(image, &device),
+            Self::bool_from_data(mask.convert_dtype(mask_dtype.into()), &device),
+        )
+    }
+
+    /// Segments an image into the basins of the labeled markers with the watershed algorithm,
+    /// like `opencv`'s `watershed`.
+    ///
+    /// The unlabeled pixels are flooded from the markers by increasing value of the image, which
+    /// is usually a gradient magnitude. The pixels where two basins meet are labeled `-1`.
+    ///
+    /// # Arguments
+    /// * `image` - Elevation as \[height, width\] tensor
+    /// * `markers` - Labels as \[height, width\] tensor, positive for the markers and 0 (or
+    ///   negative) for the pixels to segment
+    ///
+    /// # Returns
+    /// Labels as \[height, width\] tensor, with `-1` on the watershed lines
+    fn watershed(image: FloatTensor<Self>, markers: IntTensor<Self>) -> IntTensor<Self> {
+        let device = Self::float_device(&image);
+        let image = read_sync(Self::float_into_data(image)).expect("Should read data");
+        let markers = read_sync(Self::int_into_data(markers)).expect("Should read data");
+
+        Self::int_from_data(cpu::watershed(image, markers), &device)
+    }
+}
+
+/// First and last (excluded) integral image index of the window of each position, anchored at
+/// `kernel_size / 2` and clipped to `0..size`.
+fn box_window_bounds(size: usize, kernel_size: usize) -> (Vec<i64>, Vec<i64>) {
+    let anchor = (kernel_size / 2) as i64;
+    let size = size as i64;
+
+    (0..size)
+        .map(|i| {
+            let start = (i - anchor).clamp(0, size);
+            let end = (i - anchor + kernel_size as i64).clamp(0, size);
+            (start, end)
+        })
+        .unzip()
+}
+
+/// Binomial approximation of a Gaussian kernel used by the image pyramids.
+const PYRAMID_KERNEL: [f64; 5] = [1.0, 4.0, 6.0, 4.0, 1.0];
+
+/// Creates an index tensor with the int dtype of the device.
+fn index_tensor<B: Backend>(indices: Vec<i64>, device: &B::Device) -> IntTensor<B> {
+    let int_dtype = get_device_settings::<B>(device).int_dtype;
+    let len = indices.len();
+    B::int_from_data(
+        TensorData::new(indices, Shape::new([len])).convert_dtype(int_dtype.into()),
+        device,
+    )
+}
+
+/// Reflects an index into `0..size`, without repeating the border (`BORDER_REFLECT_101`).
+fn reflect_101(index: i64, size: usize) -> i64 {
+    if size == 1 {
+        return 0;
+    }
+
+    let period = 2 * (size as i64 - 1);
+    let index = index.rem_euclid(period);
+    if index < size as i64 {
+        index
+    } else {
+        period - index
+    }
+}
+
+/// Sums the slices of `images` selected along `dim` by each tap, weighted by the tap weight.
+fn weighted_taps<B: Backend>(
+    images: FloatTensor<B>,
+    dim: usize,
+    taps: impl IntoIterator<Item = (Vec<i64>, f64)>,
+) -> FloatTensor<B> {
+    let device = B::float_device(&images);
+
+    taps.into_iter()
+        .map(|(indices, weight)| {
+            let selected =
+                B::float_select(images.clone(), dim, index_tensor::<B>(indices, &device));
+            B::float_mul_scalar(selected, weight.into())
+        })
+        .reduce(B::float_add)
+        .expect("Should have at least one tap")
+}
+
+/// Applies the pyramid kernel along `dim`, only at the even positions.
+fn pyr_down_pass<B: Backend>(images: FloatTensor<B>, dim: usize, size: usize) -> FloatTensor<B> {
+    let output_size = size.div_ceil(2) as i64;
+    let taps = PYRAMID_KERNEL.iter().enumerate().map(|(k, weight)| {
+        let indices = (0..output_size)
+            .map(|i| reflect_101(2 * i + k as i64 - 2, size))
+            .collect();
+        (indices, weight / 16.0)
+    });
+
+    weighted_taps::<B>(images, dim, taps)
+}
+
+/// Upsamples `images` along `dim` to `output_size`, interleaving the filtered even and odd
+/// positions.
+///
+/// Like `opencv`, the source is reflected at the start and replicated at the end.
+fn pyr_up_pass<B: Backend>(
+    images: FloatTensor<B>,
+    dim: usize,
+    output_size: usize,
+) -> FloatTensor<B> {
+    let dims: [usize; 3] = images.shape().dims();
+    let size = dims[dim];
+    let last = size as i64 - 1;
+
+    let current: Vec<i64> = (0..size as i64).collect();
+    let previous: Vec<i64> = current
+        .iter()
+        .map(|&i| if i == 0 { last.min(1) } else { i - 1 })
+        .collect();
+    let next: Vec<i64> = current.iter().map(|&i| (i + 1).min(last)).collect();
+
+    let even = weighted_taps::<B>(
+        images.clone(),
+        dim,
+        [
+            (previous, 1.0 / 8.0),
+            (current.clone(), 6.0 / 8.0),
+            (next.clone(), 1.0 / 8.0),
+        ],
+    );
+    let odd = weighted_taps::<B>(images, dim, [(current, 0.5), (next, 0.5)]);
+
+    // Stack the even and odd positions on a new axis after `dim`, then merge them.
+    let mut split = [1; 4];
+    split[..=dim].copy_from_slice(&dims[..=dim]);
+    split[dim + 2..].copy_from_slice(&dims[dim + 1..]);
+    let mut merged = dims;
+    merged[dim] *= 2;
+
+    let even = B::float_reshape(even, Shape::new(split));
+    let odd = B::float_reshape(odd, Shape::new(split));
+    let output = B::float_reshape(B::float_cat(vec![even, odd], dim + 1), Shape::new(merged));
+
+    if output_size == merged[dim] {
+        return output;
+    }
+
+    let mut slices = [Slice::full(); 3];
+    slices[dim] = Slice::new(0, Some(output_size as isize), 1);
+    B::float_slice(output, &slices)
 }
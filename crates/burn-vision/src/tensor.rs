@@ -2,8 +2,10 @@ use burn_core::backend::Dispatch;
 use burn_core::tensor::{Bool, DType, Float, Int, Tensor};
 
 use crate::{
-    BoolVisionOps, ConnectedStats, ConnectedStatsOptions, Connectivity, FloatVisionOps,
-    IntVisionOps, MorphOptions, NmsOptions,
+    BilateralFilterOptions, BoolVisionOps, ConnectedStats, ConnectedStatsOptions, Connectivity,
+    Contour, ContourApproximation, ContourRetrieval, FloatVisionOps, FloodFillOptions, HogOptions,
+    HoughCirclesOptions, HoughLinesOptions, HoughLinesPOptions, IntVisionOps, MorphOptions,
+    NmsOptions, PhotometricOptions, Point,
 };
 
 /// Connected components tensor extensions
@@ -26,7 +28,74 @@ pub trait ConnectedComponents {
     ) -> (Tensor<2, Int>, ConnectedStats);
 }
 
+/// Hough transform tensor operations
+pub trait HoughTransform {
+    /// Finds the lines of a binary edge image with the standard Hough transform.
+    ///
+    /// `self` - The edge image tensor in the format [height, width]
+    ///
+    /// Returns the lines as a \[N, 2\] tensor of `(rho, theta)`, by decreasing number of votes.
+    fn hough_lines(self, options: HoughLinesOptions) -> Tensor<2, Float>;
+
+    /// Finds the line segments of a binary edge image with the progressive probabilistic Hough
+    /// transform.
+    ///
+    /// `self` - The edge image tensor in the format [height, width]
+    ///
+    /// Returns the segments as a \[N, 4\] tensor of `(x1, y1, x2, y2)`.
+    fn hough_lines_p(self, options: HoughLinesPOptions) -> Tensor<2, Int>;
+
+    /// Finds the circles of a binary edge image with the Hough transform.
+    ///
+    /// `self` - The edge image tensor in the format [height, width]
+    ///
+    /// Returns the circles as a \[N, 3\] tensor of `(x, y, radius)`, by decreasing score.
+    fn hough_circles(self, options: HoughCirclesOptions) -> Tensor<2, Float>;
+}
+
+/// Contour tensor operations
+pub trait FindContours {
+    /// Finds the contours of the objects of a mask, with the border following algorithm of
+    /// Suzuki and Abe. Nonzero pixels are objects.
+    ///
+    /// `self` - The mask tensor in the format [height, width]
+    ///
+    /// Returns the contours in raster order of their first pixel.
+    fn find_contours(
+        self,
+        mode: ContourRetrieval,
+        approximation: ContourApproximation,
+    ) -> Vec<Contour>;
+}
+
+/// Segmentation tensor operations
+pub trait Segmentation {
+    /// Fills the connected region of an image around `seed` with `new_value`.
+    ///
+    /// `self` - The image tensor in the format [height, width]
+    ///
+    /// Returns the filled image, and the mask of the filled pixels.
+    fn flood_fill(
+        self,
+        seed: Point,
+        new_value: f32,
+        options: FloodFillOptions,
+    ) -> (Tensor<2>, Tensor<2, Bool>);
+
+    /// Segments an image into the basins of the labeled markers with the watershed algorithm.
+    ///
+    /// `self` - The elevation tensor in the format [height, width], usually a gradient magnitude
+    /// `markers` - The labels of the markers in the format [height, width], positive for the
+    /// markers and 0 for the pixels to segment
+    ///
+    /// Returns the labels of the basins, with `-1` where two basins meet.
+    fn watershed(self, markers: Tensor<2, Int>) -> Tensor<2, Int>;
+}
+
 /// Morphology tensor operations
+///
+/// Kernels whose set elements form a rectangle containing the anchor use a separable row/column
+/// filter, other kernels a generic 2D filter. The anchor is honored by both.
 pub trait Morphology {
     /// Erodes this tensor using the specified kernel.
     /// Assumes NHWC layout.
@@ -54,6 +123,154 @@ pub trait Nms {
     fn nms(self, scores: Tensor<1, Float>, opts: NmsOptions) -> Tensor<1, Int>;
 }
 
+/// Histogram of oriented gradients tensor operations
+pub trait Hog {
+    /// Computes the histogram of oriented gradients (HOG) descriptor of each grayscale image.
+    ///
+    /// The gradient orientations of the pixels are accumulated, weighted by their magnitude, in
+    /// a histogram per cell. Overlapping blocks of cells are then normalized with L2-Hys and
+    /// concatenated into one descriptor per image.
+    ///
+    /// # Arguments
+    /// * `self` - Grayscale images as \[batches, height, width\] tensor
+    /// * `options` - HOG options (bins, cell size, block size and stride)
+    ///
+    /// # Returns
+    /// Descriptors as \[batches, blocks_y * blocks_x * block_height * block_width * bins\] tensor
+    fn hog(self, options: HogOptions) -> Tensor<2, Float>;
+}
+
+/// Integral image tensor operations
+pub trait IntegralImage {
+    /// Computes the integral image (summed-area table) of each image, with an extra first row and
+    /// column of zeros as in `opencv`.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    ///
+    /// # Returns
+    /// Integral images as \[batches, height + 1, width + 1\] tensor
+    fn integral_image(self) -> Self;
+
+    /// Sums or averages each image over a window of `kernel_size` pixels centered on each pixel,
+    /// in constant time per pixel. The window is clipped at the borders of the image.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    /// * `kernel_size` - Size of the window, as `[height, width]`
+    /// * `normalize` - Whether to average instead of sum the pixels of each window
+    fn box_filter(self, kernel_size: [usize; 2], normalize: bool) -> Self;
+}
+
+/// Edge-preserving smoothing tensor operations
+pub trait EdgePreservingFilter {
+    /// Smooths each image while preserving its edges, weighting the pixels of the window by their
+    /// distance and by their difference with the center value, like `opencv`'s
+    /// `bilateralFilter`.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    /// * `options` - Bilateral filter options (window, sigmas, method)
+    fn bilateral_filter(self, options: BilateralFilterOptions) -> Self;
+
+    /// Smooths each image while preserving the edges of `guide`, by fitting the image as a local
+    /// linear transform of the guide over windows of `2 * radius + 1` pixels.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    /// * `guide` - Guide images with the same shape, usually the images themselves
+    /// * `radius` - Radius of the window
+    /// * `eps` - Regularization of the fit, larger values smooth more
+    fn guided_filter(self, guide: Self, radius: usize, eps: f32) -> Self;
+}
+
+/// Color distribution tensor operations
+pub trait ColorTransfer {
+    /// Matches the histogram of each channel of each image to the same channel of the reference,
+    /// like `scikit-image`'s `match_histograms`.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, channels, height, width\] tensor
+    /// * `reference` - Reference images with the same number of channels, and either one image
+    ///   per image or a single image for the whole batch
+    fn match_histograms(self, reference: Self) -> Self;
+
+    /// Transfers the mean and the standard deviation of each channel of the reference to the
+    /// same channel of each image, as in the color transfer of Reinhard et al. The images are
+    /// usually in a decorrelated color space like Lab.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, channels, height, width\] tensor
+    /// * `reference` - Reference images with the same number of channels, and either one image
+    ///   per image or a single image for the whole batch
+    fn transfer_color(self, reference: Self) -> Self;
+}
+
+/// Photometric augmentation tensor operations
+pub trait PhotometricAugmentation {
+    /// Jitters the brightness, contrast, saturation and hue of RGB images, then normalizes and
+    /// pads them, in a single pass on GPU backends. See
+    /// [FloatVisionOps::photometric_augment] for the order of the adjustments.
+    ///
+    /// # Arguments
+    /// * `self` - RGB images with values in `[0, 1]`, as \[batches, 3, height, width\] tensor
+    /// * `factors` - Brightness, contrast, saturation and hue factors of each image, as
+    ///   \[batches, 4\] tensor. `[1.0, 1.0, 1.0, 0.0]` leaves an image unchanged.
+    /// * `options` - Normalization and padding options
+    fn photometric_augment(self, factors: Tensor<2>, options: PhotometricOptions) -> Self;
+}
+
+/// Image pyramid tensor operations
+pub trait ImagePyramid: Sized {
+    /// Blurs and downsamples each image by a factor of two, with the 5x5 Gaussian kernel of
+    /// `opencv`'s `pyrDown`.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    ///
+    /// # Returns
+    /// Images as \[batches, (height + 1) / 2, (width + 1) / 2\] tensor
+    fn pyr_down(self) -> Self;
+
+    /// Upsamples and blurs each image by a factor of two, like `opencv`'s `pyrUp`.
+    ///
+    /// # Arguments
+    /// * `self` - Images as \[batches, height, width\] tensor
+    /// * `size` - Size of the output, as `[height, width]`. Each dimension must be twice the
+    ///   input one, or one less.
+    fn pyr_up(self, size: [usize; 2]) -> Self;
+
+    /// Builds the Gaussian pyramid of each image, by downsampling it `max_level` times.
+    ///
+    /// Returns the `max_level + 1` levels, starting with the images themselves.
+    fn gaussian_pyramid(self, max_level: usize) -> Vec<Self>;
+
+    /// Builds the Laplacian pyramid of each image, with `max_level + 1` levels.
+    ///
+    /// Each level is the difference between a level of the
+    /// [Gaussian pyramid](ImagePyramid::gaussian_pyramid) and the upsampled next one, except the
+    /// last level which is the coarsest Gaussian level. The images are recovered with
+    /// [collapse_laplacian_pyramid].
+    fn laplacian_pyramid(self, max_level: usize) -> Vec<Self>;
+}
+
+/// Recovers the images from their [Laplacian pyramid](ImagePyramid::laplacian_pyramid), by
+/// upsampling each level and adding it to the previous one, from the coarsest level.
+///
+/// Blending the Laplacian pyramids of several images level by level before collapsing them
+/// blends their details at each scale.
+pub fn collapse_laplacian_pyramid(pyramid: Vec<Tensor<3>>) -> Tensor<3> {
+    let mut levels = pyramid.into_iter().rev();
+    let coarsest = levels
+        .next()
+        .expect("Laplacian pyramid should have at least one level");
+
+    levels.fold(coarsest, |images, level| {
+        let [_, height, width] = level.dims();
+        images.pyr_up([height, width]) + level
+    })
+}
+
 impl ConnectedComponents for Tensor<2, Bool> {
     fn connected_components(self, connectivity: Connectivity) -> Tensor<2, Int> {
         let settings = self.device().settings();
@@ -89,6 +306,89 @@ impl ConnectedComponents for Tensor<2, Bool> {
     }
 }
 
+impl HoughTransform for Tensor<2, Bool> {
+    fn hough_lines(self, options: HoughLinesOptions) -> Tensor<2, Float> {
+        let settings = self.device().settings();
+        Tensor::from_primitive(<Dispatch as BoolVisionOps>::hough_lines(
+            self.into_primitive(),
+            options,
+            settings.float_dtype,
+        ))
+    }
+
+    fn hough_lines_p(self, options: HoughLinesPOptions) -> Tensor<2, Int> {
+        let settings = self.device().settings();
+        Tensor::from_primitive(<Dispatch as BoolVisionOps>::hough_lines_p(
+            self.into_primitive(),
+            options,
+            settings.int_dtype,
+        ))
+    }
+
+    fn hough_circles(self, options: HoughCirclesOptions) -> Tensor<2, Float> {
+        let settings = self.device().settings();
+        Tensor::from_primitive(<Dispatch as BoolVisionOps>::hough_circles(
+            self.into_primitive(),
+            options,
+            settings.float_dtype,
+        ))
+    }
+}
+
+impl FindContours for Tensor<2, Bool> {
+    fn find_contours(
+        self,
+        mode: ContourRetrieval,
+        approximation: ContourApproximation,
+    ) -> Vec<Contour> {
+        <Dispatch as BoolVisionOps>::find_contours(self.into_primitive(), mode, approximation)
+    }
+}
+
+impl FindContours for Tensor<2, Int> {
+    fn find_contours(
+        self,
+        mode: ContourRetrieval,
+        approximation: ContourApproximation,
+    ) -> Vec<Contour> {
+        self.not_equal_elem(0).find_contours(mode, approximation)
+    }
+}
+
+impl Segmentation for Tensor<2> {
+    fn flood_fill(
+        self,
+        seed: Point,
+        new_value: f32,
+        options: FloodFillOptions,
+    ) -> (Tensor<2>, Tensor<2, Bool>) {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        let settings = self.device().settings();
+        let (image, mask) = <Dispatch as FloatVisionOps>::flood_fill(
+            self.into_primitive(),
+            seed,
+            new_value,
+            options,
+            settings.bool_dtype,
+        );
+        (Tensor::from_primitive(image), Tensor::from_primitive(mask))
+    }
+
+    fn watershed(self, markers: Tensor<2, Int>) -> Tensor<2, Int> {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::watershed(
+            self.into_primitive(),
+            markers.into_primitive(),
+        ))
+    }
+}
+
 impl Morphology for Tensor<3, Float> {
     fn erode(self, kernel: Tensor<2, Bool>, opts: MorphOptions) -> Self {
         if matches!(self.dtype(), DType::QFloat(_)) {
@@ -169,3 +469,163 @@ impl Nms for Tensor<2> {
         ))
     }
 }
+
+impl Hog for Tensor<3> {
+    fn hog(self, options: HogOptions) -> Tensor<2> {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::hog(
+            self.into_primitive(),
+            options,
+        ))
+    }
+}
+
+impl IntegralImage for Tensor<3> {
+    fn integral_image(self) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::integral_image(
+            self.into_primitive(),
+        ))
+    }
+
+    fn box_filter(self, kernel_size: [usize; 2], normalize: bool) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::box_filter(
+            self.into_primitive(),
+            kernel_size,
+            normalize,
+        ))
+    }
+}
+
+impl EdgePreservingFilter for Tensor<3> {
+    fn bilateral_filter(self, options: BilateralFilterOptions) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::bilateral_filter(
+            self.into_primitive(),
+            options,
+        ))
+    }
+
+    fn guided_filter(self, guide: Self, radius: usize, eps: f32) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::guided_filter(
+            self.into_primitive(),
+            guide.into_primitive(),
+            radius,
+            eps,
+        ))
+    }
+}
+
+impl ColorTransfer for Tensor<4> {
+    fn match_histograms(self, reference: Self) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::match_histograms(
+            self.into_primitive(),
+            reference.into_primitive(),
+        ))
+    }
+
+    fn transfer_color(self, reference: Self) -> Self {
+        let [batches, channels, _, _] = self.dims();
+        let [ref_batches, ref_channels, _, _] = reference.dims();
+        assert!(
+            ref_channels == channels && (ref_batches == batches || ref_batches == 1),
+            "Color transfer expects a reference with {channels} channels and {batches} or 1 \
+             images, got {:?}",
+            reference.dims()
+        );
+
+        // Mean and standard deviation of each channel, as [batches, channels, 1, 1] tensors.
+        let moments = |images: Self| {
+            let mean = images.clone().mean_dims(&[2, 3]);
+            let var = (images - mean.clone()).square().mean_dims(&[2, 3]);
+            (mean, var.sqrt())
+        };
+        let (mean, std) = moments(self.clone());
+        let (ref_mean, ref_std) = moments(reference);
+
+        // Flat channels have no spread to scale, and are moved to the reference mean.
+        let scale = ref_std / std.clamp_min(f32::EPSILON);
+        (self - mean) * scale + ref_mean
+    }
+}
+
+impl PhotometricAugmentation for Tensor<4> {
+    fn photometric_augment(self, factors: Tensor<2>, options: PhotometricOptions) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::photometric_augment(
+            self.into_primitive(),
+            factors.into_primitive(),
+            options,
+        ))
+    }
+}
+
+impl ImagePyramid for Tensor<3> {
+    fn pyr_down(self) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::pyr_down(
+            self.into_primitive(),
+        ))
+    }
+
+    fn pyr_up(self, size: [usize; 2]) -> Self {
+        if matches!(self.dtype(), DType::QFloat(_)) {
+            unimplemented!("Quantized float is not supported");
+        }
+
+        Tensor::from_primitive(<Dispatch as FloatVisionOps>::pyr_up(
+            self.into_primitive(),
+            size,
+        ))
+    }
+
+    fn gaussian_pyramid(self, max_level: usize) -> Vec<Self> {
+        let mut pyramid = Vec::with_capacity(max_level + 1);
+        pyramid.push(self);
+        for _ in 0..max_level {
+            let next = pyramid.last().unwrap().clone().pyr_down();
+            pyramid.push(next);
+        }
+        pyramid
+    }
+
+    fn laplacian_pyramid(self, max_level: usize) -> Vec<Self> {
+        let gaussian = self.gaussian_pyramid(max_level);
+        let mut pyramid: Vec<Self> = gaussian
+            .windows(2)
+            .map(|levels| {
+                let [_, height, width] = levels[0].dims();
+                levels[0].clone() - levels[1].clone().pyr_up([height, width])
+            })
+            .collect();
+        pyramid.extend(gaussian.last().cloned());
+        pyramid
+    }
+}
@@ -0,0 +1,614 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn::config::Config;
+use burn::tensor::{Tensor, TensorData};
+use rand::{RngExt, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use super::Transform2D;
+
+/// Weights of the red, green and blue channels in the grayscale image, as in `torchvision`.
+const GRAYSCALE_WEIGHTS: [f32; 3] = [0.2989, 0.587, 0.114];
+
+/// An image augmentation operation, with its magnitude.
+///
+/// The operations act on `[channels, height, width]` images with values in `[0, 1]`, as the
+/// operations of the same name in `torchvision`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AugmentOp {
+    /// Leaves the image unchanged.
+    Identity,
+    /// Shears the image horizontally by a factor.
+    ShearX(f32),
+    /// Shears the image vertically by a factor.
+    ShearY(f32),
+    /// Translates the image horizontally, in pixels.
+    TranslateX(f32),
+    /// Translates the image vertically, in pixels.
+    TranslateY(f32),
+    /// Rotates the image around its center, in degrees.
+    Rotate(f32),
+    /// Scales the values by a factor.
+    Brightness(f32),
+    /// Blends the image with its grayscale image, 0 giving the grayscale image and 1 the image.
+    Color(f32),
+    /// Blends the image with the mean of its grayscale image.
+    Contrast(f32),
+    /// Blends the image with a smoothed image, 0 giving the smoothed image and 1 the image.
+    Sharpness(f32),
+    /// Keeps the given number of bits of the 8-bit values.
+    Posterize(u8),
+    /// Inverts the values above a threshold.
+    Solarize(f32),
+    /// Stretches the values of each channel to `[0, 1]`.
+    AutoContrast,
+    /// Equalizes the histogram of the 8-bit values of each channel.
+    Equalize,
+    /// Inverts the values.
+    Invert,
+}
+
+impl AugmentOp {
+    /// Applies the operation to an image.
+    ///
+    /// * `image` - Image tensor with shape (channels, height, width), and values in `[0, 1]`
+    pub fn apply(self, image: Tensor<3>) -> Tensor<3> {
+        match self {
+            AugmentOp::Identity => image,
+            AugmentOp::ShearX(shear) => warp(image, Transform2D::shear(shear, 0.0, 0.0, 0.0)),
+            AugmentOp::ShearY(shear) => warp(image, Transform2D::shear(0.0, shear, 0.0, 0.0)),
+            AugmentOp::TranslateX(tx) => warp(image, Transform2D::translation(tx, 0.0)),
+            AugmentOp::TranslateY(ty) => warp(image, Transform2D::translation(0.0, ty)),
+            AugmentOp::Rotate(degrees) => {
+                warp(image, Transform2D::rotation(degrees.to_radians(), 0.0, 0.0))
+            }
+            AugmentOp::Brightness(factor) => image.mul_scalar(factor).clamp(0.0, 1.0),
+            AugmentOp::Color(factor) => {
+                if image.dims()[0] != 3 {
+                    return image;
+                }
+                let gray = grayscale(image.clone());
+                blend(image, gray, factor)
+            }
+            AugmentOp::Contrast(factor) => {
+                let mean = if image.dims()[0] == 3 {
+                    grayscale(image.clone()).mean()
+                } else {
+                    image.clone().mean()
+                };
+                blend(image, mean.reshape([1, 1, 1]), factor)
+            }
+            AugmentOp::Sharpness(factor) => {
+                let smoothed = smooth(image.clone());
+                blend(image, smoothed, factor)
+            }
+            AugmentOp::Posterize(bits) => {
+                let step = (1 << (8 - bits.min(8))) as f32;
+                image
+                    .mul_scalar(255.0)
+                    .round()
+                    .div_scalar(step)
+                    .floor()
+                    .mul_scalar(step / 255.0)
+            }
+            AugmentOp::Solarize(threshold) => {
+                let mask = image.clone().greater_equal_elem(threshold);
+                image.clone().mask_where(mask, image.neg().add_scalar(1.0))
+            }
+            AugmentOp::AutoContrast => auto_contrast(image),
+            AugmentOp::Equalize => equalize(image),
+            AugmentOp::Invert => image.neg().add_scalar(1.0),
+        }
+    }
+}
+
+/// Automatic augmentation policy, drawing the operations applied to each image.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AugmentPolicy {
+    /// RandAugment of Cubuk et al.: `num_ops` operations drawn uniformly, with the fixed
+    /// `magnitude` out of `num_magnitude_bins`, and a random sign for the signed operations.
+    RandAugment {
+        /// Number of operations applied to each image.
+        num_ops: usize,
+        /// Magnitude of the operations, lower than `num_magnitude_bins`.
+        magnitude: usize,
+        /// Number of magnitudes of the operations.
+        num_magnitude_bins: usize,
+    },
+    /// TrivialAugment Wide of Müller and Hutter: a single operation drawn uniformly, with a
+    /// magnitude drawn uniformly, over wider ranges than RandAugment.
+    TrivialAugment {
+        /// Number of magnitudes of the operations.
+        num_magnitude_bins: usize,
+    },
+    /// AutoAugment of Cubuk et al., with the policy learned on ImageNet: one of 25 pairs of
+    /// operations, each applied with its own probability and magnitude.
+    AutoAugment,
+}
+
+impl Default for AugmentPolicy {
+    fn default() -> Self {
+        Self::RandAugment {
+            num_ops: 2,
+            magnitude: 9,
+            num_magnitude_bins: 31,
+        }
+    }
+}
+
+impl AugmentPolicy {
+    /// Draws the operations applied to an image.
+    ///
+    /// * `rng` - Random generator of the image
+    /// * `size` - Size of the image, as `[height, width]`, to scale the translations
+    pub fn sample(&self, rng: &mut StdRng, size: [usize; 2]) -> Vec<AugmentOp> {
+        match *self {
+            AugmentPolicy::RandAugment {
+                num_ops,
+                magnitude,
+                num_magnitude_bins,
+            } => {
+                assert!(
+                    magnitude < num_magnitude_bins,
+                    "RandAugment expects a magnitude lower than {num_magnitude_bins}, got \
+                     {magnitude}"
+                );
+                (0..num_ops)
+                    .map(|_| {
+                        let kind = RAND_AUGMENT_OPS[rng.random_range(0..RAND_AUGMENT_OPS.len())];
+                        let bin = MagnitudeBin::new(magnitude, num_magnitude_bins);
+                        kind.op(bin, Ranges::STANDARD, size, rng.random_bool(0.5))
+                    })
+                    .collect()
+            }
+            AugmentPolicy::TrivialAugment { num_magnitude_bins } => {
+                let kind = RAND_AUGMENT_OPS[rng.random_range(0..RAND_AUGMENT_OPS.len())];
+                let bin =
+                    MagnitudeBin::new(rng.random_range(0..num_magnitude_bins), num_magnitude_bins);
+                vec![kind.op(bin, Ranges::WIDE, size, rng.random_bool(0.5))]
+            }
+            AugmentPolicy::AutoAugment => {
+                let sub_policy = IMAGENET_POLICY[rng.random_range(0..IMAGENET_POLICY.len())];
+                let mut ops = Vec::new();
+                for (kind, probability, magnitude) in sub_policy {
+                    if rng.random::<f32>() < probability {
+                        let bin = MagnitudeBin::new(magnitude, 10);
+                        ops.push(kind.op(bin, Ranges::STANDARD, size, rng.random_bool(0.5)));
+                    }
+                }
+                ops
+            }
+        }
+    }
+
+    /// Augments an image with operations drawn from the random generator.
+    ///
+    /// * `image` - Image tensor with shape (channels, height, width), and values in `[0, 1]`
+    pub fn augment(&self, image: Tensor<3>, rng: &mut StdRng) -> Tensor<3> {
+        let [_, height, width] = image.dims();
+        self.sample(rng, [height, width])
+            .into_iter()
+            .fold(image, |image, op| op.apply(image))
+    }
+}
+
+/// Configuration of the reproducible augmentation of a dataset with a [policy](AugmentPolicy).
+///
+/// The operations of each sample are drawn from a generator seeded with the seed and the index
+/// of the sample, so they don't depend on the order or the worker the samples are loaded in.
+/// Changing the seed at each epoch, e.g. to `seed + epoch`, draws new operations.
+#[derive(Config, Debug)]
+pub struct AugmentConfig {
+    /// The augmentation policy.
+    pub policy: AugmentPolicy,
+    /// Seed of the random generators of the samples.
+    #[config(default = 0)]
+    pub seed: u64,
+}
+
+impl AugmentConfig {
+    /// The random generator of a sample.
+    pub fn rng(&self, index: usize) -> StdRng {
+        StdRng::seed_from_u64(mix(self.seed ^ mix(index as u64)))
+    }
+
+    /// Augments the image of a sample.
+    ///
+    /// * `image` - Image tensor with shape (channels, height, width), and values in `[0, 1]`
+    /// * `index` - Index of the sample in the dataset
+    pub fn augment(&self, image: Tensor<3>, index: usize) -> Tensor<3> {
+        self.policy.augment(image, &mut self.rng(index))
+    }
+}
+
+/// SplitMix64 finalizer, so that close seeds and indices give unrelated generators.
+fn mix(value: u64) -> u64 {
+    let value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    let value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+/// The operations of the policies, without their magnitude.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OpKind {
+    Identity,
+    ShearX,
+    ShearY,
+    TranslateX,
+    TranslateY,
+    Rotate,
+    Brightness,
+    Color,
+    Contrast,
+    Sharpness,
+    Posterize,
+    Solarize,
+    AutoContrast,
+    Equalize,
+    Invert,
+}
+
+/// The operations drawn by RandAugment and TrivialAugment.
+const RAND_AUGMENT_OPS: [OpKind; 14] = [
+    OpKind::Identity,
+    OpKind::ShearX,
+    OpKind::ShearY,
+    OpKind::TranslateX,
+    OpKind::TranslateY,
+    OpKind::Rotate,
+    OpKind::Brightness,
+    OpKind::Color,
+    OpKind::Contrast,
+    OpKind::Sharpness,
+    OpKind::Posterize,
+    OpKind::Solarize,
+    OpKind::AutoContrast,
+    OpKind::Equalize,
+];
+
+/// The sub-policies of AutoAugment learned on ImageNet, as (operation, probability, magnitude)
+/// pairs with 10 magnitudes.
+const IMAGENET_POLICY: [[(OpKind, f32, usize); 2]; 25] = {
+    use OpKind::*;
+    [
+        [(Posterize, 0.4, 8), (Rotate, 0.6, 9)],
+        [(Solarize, 0.6, 5), (AutoContrast, 0.6, 0)],
+        [(Equalize, 0.8, 0), (Equalize, 0.6, 0)],
+        [(Posterize, 0.6, 7), (Posterize, 0.6, 6)],
+        [(Equalize, 0.4, 0), (Solarize, 0.2, 4)],
+        [(Equalize, 0.4, 0), (Rotate, 0.8, 8)],
+        [(Solarize, 0.6, 3), (Equalize, 0.6, 0)],
+        [(Posterize, 0.8, 5), (Equalize, 1.0, 0)],
+        [(Rotate, 0.2, 3), (Solarize, 0.6, 8)],
+        [(Equalize, 0.6, 0), (Posterize, 0.4, 6)],
+        [(Rotate, 0.8, 8), (Color, 0.4, 0)],
+        [(Rotate, 0.4, 9), (Equalize, 0.6, 0)],
+        [(Equalize, 0.0, 0), (Equalize, 0.8, 0)],
+        [(Invert, 0.6, 0), (Equalize, 1.0, 0)],
+        [(Color, 0.6, 4), (Contrast, 1.0, 8)],
+        [(Rotate, 0.8, 8), (Color, 1.0, 2)],
+        [(Color, 0.8, 8), (Solarize, 0.8, 7)],
+        [(Sharpness, 0.4, 7), (Invert, 0.6, 0)],
+        [(ShearX, 0.6, 5), (Equalize, 1.0, 0)],
+        [(Color, 0.4, 0), (Equalize, 0.6, 0)],
+        [(Equalize, 0.4, 0), (Solarize, 0.2, 4)],
+        [(Solarize, 0.6, 5), (AutoContrast, 0.6, 0)],
+        [(Invert, 0.6, 0), (Equalize, 1.0, 0)],
+        [(Color, 0.6, 4), (Contrast, 1.0, 8)],
+        [(Equalize, 0.8, 0), (Equalize, 0.6, 0)],
+    ]
+};
+
+/// A magnitude out of a number of magnitudes.
+#[derive(Clone, Copy)]
+struct MagnitudeBin {
+    bin: usize,
+    num_bins: usize,
+}
+
+impl MagnitudeBin {
+    fn new(bin: usize, num_bins: usize) -> Self {
+        Self { bin, num_bins }
+    }
+
+    /// The magnitude linearly spaced between `start` (first bin) and `end` (last bin).
+    fn linspace(self, start: f32, end: f32) -> f32 {
+        if self.num_bins <= 1 {
+            return start;
+        }
+        start + (end - start) * self.bin as f32 / (self.num_bins - 1) as f32
+    }
+
+    /// The number of bits kept by posterize, from 8 bits down to `8 - max_removed_bits`.
+    fn bits(self, max_removed_bits: f32) -> u8 {
+        if self.num_bins <= 1 {
+            return 8;
+        }
+        let removed = (self.bin as f32 / ((self.num_bins - 1) as f32 / max_removed_bits)).round();
+        8 - removed as u8
+    }
+}
+
+/// The largest magnitudes of the operations.
+struct Ranges {
+    shear: f32,
+    /// Translation, in pixels when `translate_relative` is false, else in fraction of the size.
+    translate: f32,
+    translate_relative: bool,
+    rotate: f32,
+    enhance: f32,
+    posterize_removed_bits: f32,
+}
+
+impl Ranges {
+    /// The ranges of RandAugment and AutoAugment.
+    const STANDARD: Self = Self {
+        shear: 0.3,
+        translate: 150.0 / 331.0,
+        translate_relative: true,
+        rotate: 30.0,
+        enhance: 0.9,
+        posterize_removed_bits: 4.0,
+    };
+
+    /// The wider ranges of TrivialAugment.
+    const WIDE: Self = Self {
+        shear: 0.99,
+        translate: 32.0,
+        translate_relative: false,
+        rotate: 135.0,
+        enhance: 0.99,
+        posterize_removed_bits: 6.0,
+    };
+}
+
+impl OpKind {
+    fn op(
+        self,
+        bin: MagnitudeBin,
+        ranges: Ranges,
+        [height, width]: [usize; 2],
+        negate: bool,
+    ) -> AugmentOp {
+        let sign = if negate { -1.0 } else { 1.0 };
+        let translate = |size: usize| {
+            let range = if ranges.translate_relative {
+                ranges.translate * size as f32
+            } else {
+                ranges.translate
+            };
+            sign * bin.linspace(0.0, range)
+        };
+        let enhance = 1.0 + sign * bin.linspace(0.0, ranges.enhance);
+
+        match self {
+            OpKind::Identity => AugmentOp::Identity,
+            OpKind::ShearX => AugmentOp::ShearX(sign * bin.linspace(0.0, ranges.shear)),
+            OpKind::ShearY => AugmentOp::ShearY(sign * bin.linspace(0.0, ranges.shear)),
+            OpKind::TranslateX => AugmentOp::TranslateX(translate(width)),
+            OpKind::TranslateY => AugmentOp::TranslateY(translate(height)),
+            OpKind::Rotate => AugmentOp::Rotate(sign * bin.linspace(0.0, ranges.rotate)),
+            OpKind::Brightness => AugmentOp::Brightness(enhance),
+            OpKind::Color => AugmentOp::Color(enhance),
+            OpKind::Contrast => AugmentOp::Contrast(enhance),
+            OpKind::Sharpness => AugmentOp::Sharpness(enhance),
+            OpKind::Posterize => AugmentOp::Posterize(bin.bits(ranges.posterize_removed_bits)),
+            OpKind::Solarize => AugmentOp::Solarize(bin.linspace(1.0, 0.0)),
+            OpKind::AutoContrast => AugmentOp::AutoContrast,
+            OpKind::Equalize => AugmentOp::Equalize,
+            OpKind::Invert => AugmentOp::Invert,
+        }
+    }
+}
+
+/// Applies a transform expressed in pixels around the center of the image.
+fn warp(image: Tensor<3>, transform: Transform2D) -> Tensor<3> {
+    let [_, height, width] = image.dims();
+    // Half sizes, mapping the normalized coordinates of the sampling grid to pixels.
+    let half = |size: usize| (size.max(2) - 1) as f32 / 2.0;
+    let (sx, sy) = (half(width), half(height));
+
+    let transform = Transform2D::composed([
+        Transform2D::scale(1.0 / sx, 1.0 / sy, 0.0, 0.0),
+        transform,
+        Transform2D::scale(sx, sy, 0.0, 0.0),
+    ]);
+    transform.transform(image.unsqueeze_dim(0)).squeeze_dim(0)
+}
+
+fn grayscale(image: Tensor<3>) -> Tensor<3> {
+    let [r, g, b] = GRAYSCALE_WEIGHTS;
+    image.clone().narrow(0, 0, 1).mul_scalar(r)
+        + image.clone().narrow(0, 1, 1).mul_scalar(g)
+        + image.narrow(0, 2, 1).mul_scalar(b)
+}
+
+/// Interpolates from `other` (factor 0) to `image` (factor 1), and beyond for larger factors.
+fn blend(image: Tensor<3>, other: Tensor<3>, factor: f32) -> Tensor<3> {
+    (image.mul_scalar(factor) + other.mul_scalar(1.0 - factor)).clamp(0.0, 1.0)
+}
+
+/// Smooths the inner pixels with the 3x3 kernel of `PIL`'s `SMOOTH` filter, keeping the borders.
+fn smooth(image: Tensor<3>) -> Tensor<3> {
+    let [channels, height, width] = image.dims();
+    if height <= 2 || width <= 2 {
+        return image;
+    }
+
+    let shifted = |dy: usize, dx: usize| {
+        image
+            .clone()
+            .slice([0..channels, dy..dy + height - 2, dx..dx + width - 2])
+    };
+    let mut sum = shifted(1, 1).mul_scalar(4.0);
+    for dy in 0..3 {
+        for dx in 0..3 {
+            sum = sum + shifted(dy, dx);
+        }
+    }
+
+    image.clone().slice_assign(
+        [0..channels, 1..height - 1, 1..width - 1],
+        sum.div_scalar(13.0),
+    )
+}
+
+fn auto_contrast(image: Tensor<3>) -> Tensor<3> {
+    let [channels, height, width] = image.dims();
+    let flat = image.clone().reshape([channels, height * width]);
+    let min = flat.clone().min_dim(1).reshape([channels, 1, 1]);
+    let max = flat.max_dim(1).reshape([channels, 1, 1]);
+
+    // Flat channels are kept as they are.
+    let range = max - min.clone();
+    let is_flat = range.clone().lower_equal_elem(0.0);
+    let min = min.mask_fill(is_flat.clone(), 0.0);
+    let range = range.mask_fill(is_flat, 1.0);
+
+    ((image - min) / range).clamp(0.0, 1.0)
+}
+
+/// Equalizes the histogram of the 8-bit values of each channel on CPU, as `PIL`'s `equalize`.
+fn equalize(image: Tensor<3>) -> Tensor<3> {
+    let device = image.device();
+    let [channels, height, width] = image.dims();
+    let values: Vec<f32> = image.into_data().convert::<f32>().to_vec().unwrap();
+
+    let output: Vec<f32> = values
+        .chunks((height * width).max(1))
+        .flat_map(equalize_channel)
+        .collect();
+
+    Tensor::from_data(TensorData::new(output, [channels, height, width]), &device)
+}
+
+fn equalize_channel(values: &[f32]) -> Vec<f32> {
+    let levels: Vec<usize> = values
+        .iter()
+        .map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as usize)
+        .collect();
+    let mut histogram = [0usize; 256];
+    for &level in &levels {
+        histogram[level] += 1;
+    }
+
+    // The most frequent levels are spread evenly, except the last one.
+    let last = histogram
+        .iter()
+        .rev()
+        .find(|&&count| count > 0)
+        .copied()
+        .unwrap_or(0);
+    let step = (levels.len() - last) / 255;
+    if step == 0 {
+        return values.to_vec();
+    }
+
+    let mut lut = [0usize; 256];
+    let mut cumulative = step / 2;
+    for (level, count) in histogram.iter().enumerate() {
+        lut[level] = (cumulative / step).min(255);
+        cumulative += count;
+    }
+
+    levels
+        .into_iter()
+        .map(|level| lut[level] as f32 / 255.0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::tensor::Tolerance;
+
+    #[test]
+    fn augment_is_reproducible_per_sample() {
+        let config = AugmentConfig::new(AugmentPolicy::default()).with_seed(7);
+        let sample = |index| config.policy.sample(&mut config.rng(index), [32, 32]);
+
+        assert_eq!(sample(3), sample(3));
+        assert_eq!(sample(3).len(), 2);
+        assert!((0..16).any(|index| sample(index) != sample(3)));
+    }
+
+    #[test]
+    fn trivial_augment_draws_one_op() {
+        let policy = AugmentPolicy::TrivialAugment {
+            num_magnitude_bins: 31,
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..8 {
+            assert_eq!(policy.sample(&mut rng, [8, 8]).len(), 1);
+        }
+    }
+
+    #[test]
+    fn magnitudes_follow_torchvision_bins() {
+        let bin = MagnitudeBin::new(9, 31);
+        let op = |kind: OpKind, negate| kind.op(bin, Ranges::STANDARD, [100, 331], negate);
+
+        assert!(
+            matches!(op(OpKind::ShearX, false), AugmentOp::ShearX(v) if (v - 0.09).abs() < 1e-6)
+        );
+        assert!(matches!(op(OpKind::Rotate, true), AugmentOp::Rotate(v) if (v + 9.0).abs() < 1e-5));
+        assert!(
+            matches!(op(OpKind::Solarize, true), AugmentOp::Solarize(v) if (v - 0.7).abs() < 1e-6)
+        );
+        assert!(
+            matches!(op(OpKind::TranslateX, false), AugmentOp::TranslateX(v) if (v - 45.0).abs() < 1e-4)
+        );
+        assert_eq!(op(OpKind::Posterize, false), AugmentOp::Posterize(7));
+        assert_eq!(
+            OpKind::Posterize.op(bin, Ranges::WIDE, [8, 8], false),
+            AugmentOp::Posterize(6)
+        );
+    }
+
+    #[test]
+    fn pixel_ops() {
+        let image = Tensor::<3>::from([[[0.0, 0.25], [0.5, 1.0]]]);
+
+        let solarized = AugmentOp::Solarize(0.5).apply(image.clone());
+        let expected = Tensor::<3>::from([[[0.0, 0.25], [0.5, 0.0]]]);
+        solarized
+            .to_data()
+            .assert_approx_eq(&expected.to_data(), Tolerance::<f32>::balanced());
+
+        let posterized = AugmentOp::Posterize(1).apply(image.clone());
+        let expected = Tensor::<3>::from([[[0.0, 0.0], [128.0 / 255.0, 128.0 / 255.0]]]);
+        posterized
+            .to_data()
+            .assert_approx_eq(&expected.to_data(), Tolerance::<f32>::balanced());
+
+        let stretched =
+            AugmentOp::AutoContrast.apply(image.clone().mul_scalar(0.5).add_scalar(0.2));
+        stretched
+            .to_data()
+            .assert_approx_eq(&image.to_data(), Tolerance::<f32>::balanced());
+    }
+
+    #[test]
+    fn equalize_spreads_levels() {
+        let image = Tensor::<3>::from_data(
+            TensorData::new(
+                (0..512).map(|i| (i % 2) as f32 * 0.5).collect::<Vec<_>>(),
+                [1, 16, 32],
+            ),
+            &Default::default(),
+        );
+
+        let equalized = AugmentOp::Equalize
+            .apply(image)
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap();
+
+        // Level 0 stays black, and the last level starts after the 256 black pixels.
+        assert_eq!(equalized[0], 0.0);
+        assert_eq!(equalized[1], 1.0);
+    }
+}
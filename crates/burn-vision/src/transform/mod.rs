@@ -1,3 +1,5 @@
+mod augment;
 mod transform2d;
 
+pub use augment::*;
 pub use transform2d::*;
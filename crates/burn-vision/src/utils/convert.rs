@@ -0,0 +1,255 @@
+//! Conversions between `image` crate images and tensors
+
+use std::fmt;
+
+use burn_core::tensor::{Device, Element, Tensor, TensorData};
+use image::{
+    DynamicImage, GrayAlphaImage, GrayImage, ImageBuffer, Pixel, Primitive, RgbImage, RgbaImage,
+};
+use num_traits::ToPrimitive;
+
+/// Where the channel dimension is placed in image tensors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChannelLayout {
+    /// dims: (channels, height, width), or (batch_size, channels, height, width) for batches
+    #[default]
+    ChannelsFirst,
+    /// dims: (height, width, channels), or (batch_size, height, width, channels) for batches
+    ChannelsLast,
+}
+
+/// How pixel values are mapped to tensor values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PixelScaling {
+    /// Pixel values are divided by the maximum value of their subpixel type, so that they lie
+    /// between 0 and 1
+    #[default]
+    UnitRange,
+    /// Pixel values are kept as is (e.g. between 0 and 255 for 8-bit images)
+    Raw,
+}
+
+/// How to convert between images and tensors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImageConversionOptions {
+    /// Where the channel dimension is placed
+    pub layout: ChannelLayout,
+    /// How pixel values are scaled
+    pub scaling: PixelScaling,
+}
+
+impl ImageConversionOptions {
+    /// Create conversion options with the given layout and scaling
+    pub fn new(layout: ChannelLayout, scaling: PixelScaling) -> Self {
+        Self { layout, scaling }
+    }
+}
+
+/// Error returned when a tensor can't be converted to an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageConversionError {
+    /// Images can only be created with 1 (luma), 2 (luma + alpha), 3 (RGB) or 4 (RGBA) channels
+    UnsupportedChannels(usize),
+    /// The image dimensions don't fit in `u32`
+    DimensionsTooLarge {
+        /// Image height
+        height: usize,
+        /// Image width
+        width: usize,
+    },
+}
+
+impl fmt::Display for ImageConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedChannels(channels) => write!(
+                f,
+                "Can't convert a tensor with {channels} channels to an image, expected 1, 2, 3 or 4"
+            ),
+            Self::DimensionsTooLarge { height, width } => {
+                write!(f, "Image dimensions {height}x{width} are too large")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageConversionError {}
+
+/// Convert an image to a float tensor
+///
+/// * `image` - The image to convert, in any of the `image` crate color types
+/// * `opts` - The tensor layout and pixel scaling
+/// * `device` - The device to create the tensor on
+///
+/// Returns a tensor with shape (channels, height, width) or (height, width, channels),
+/// depending on `opts.layout`.
+pub fn image_to_tensor(
+    image: &DynamicImage,
+    opts: ImageConversionOptions,
+    device: &Device,
+) -> Tensor<3> {
+    match image {
+        DynamicImage::ImageLuma8(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageLumaA8(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgb8(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgba8(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageLuma16(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageLumaA16(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgb16(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgba16(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgb32F(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        DynamicImage::ImageRgba32F(buffer) => image_buffer_to_tensor(buffer, opts, device),
+        image => image_buffer_to_tensor(&image.to_rgba32f(), opts, device),
+    }
+}
+
+/// Convert an image buffer to a float tensor
+///
+/// * `buffer` - The image buffer to convert
+/// * `opts` - The tensor layout and pixel scaling
+/// * `device` - The device to create the tensor on
+///
+/// Returns a tensor with shape (channels, height, width) or (height, width, channels),
+/// depending on `opts.layout`.
+pub fn image_buffer_to_tensor<P>(
+    buffer: &ImageBuffer<P, Vec<P::Subpixel>>,
+    opts: ImageConversionOptions,
+    device: &Device,
+) -> Tensor<3>
+where
+    P: Pixel,
+    P::Subpixel: Element,
+{
+    let height = buffer.height() as usize;
+    let width = buffer.width() as usize;
+    let channels = P::CHANNEL_COUNT as usize;
+
+    let data = TensorData::new(buffer.as_raw().clone(), [height, width, channels]);
+    let tensor = Tensor::<3>::from_data(data, device);
+
+    let tensor = match opts.scaling {
+        PixelScaling::UnitRange => tensor.div_scalar(max_value::<P::Subpixel>()),
+        PixelScaling::Raw => tensor,
+    };
+
+    match opts.layout {
+        ChannelLayout::ChannelsFirst => tensor.permute([2, 0, 1]),
+        ChannelLayout::ChannelsLast => tensor,
+    }
+}
+
+/// Convert a batch of images to a float tensor
+///
+/// * `images` - The images to convert, which must all have the same size
+/// * `opts` - The tensor layout and pixel scaling
+/// * `device` - The device to create the tensor on
+///
+/// The images are converted to 8-bit RGB, unless they all share the same color type.
+/// Returns a tensor with shape (batch_size, channels, height, width) or
+/// (batch_size, height, width, channels), depending on `opts.layout`.
+///
+/// # Panics
+///
+/// If `images` is empty or if the images don't all have the same size.
+pub fn images_to_tensor(
+    images: &[DynamicImage],
+    opts: ImageConversionOptions,
+    device: &Device,
+) -> Tensor<4> {
+    assert!(!images.is_empty(), "Can't convert an empty batch of images");
+
+    let first = &images[0];
+    let same_color = images.iter().all(|image| image.color() == first.color());
+    let tensors = images
+        .iter()
+        .map(|image| {
+            assert_eq!(
+                (image.width(), image.height()),
+                (first.width(), first.height()),
+                "All images in a batch should have the same size"
+            );
+            if same_color {
+                image_to_tensor(image, opts, device)
+            } else {
+                image_to_tensor(&DynamicImage::from(image.to_rgb8()), opts, device)
+            }
+        })
+        .collect();
+
+    Tensor::stack(tensors, 0)
+}
+
+/// Convert a float tensor to an 8-bit image
+///
+/// * `tensor` - Image with shape (channels, height, width) or (height, width, channels)
+/// * `opts` - The tensor layout and pixel scaling
+///
+/// Values are rounded and clamped to the 8-bit range after scaling. The color type is
+/// picked from the number of channels: luma, luma with alpha, RGB or RGBA.
+pub fn tensor_to_image(
+    tensor: Tensor<3>,
+    opts: ImageConversionOptions,
+) -> Result<DynamicImage, ImageConversionError> {
+    let mut images = tensor_to_images(tensor.unsqueeze::<4>(), opts)?;
+
+    Ok(images.remove(0))
+}
+
+/// Convert a float tensor of a batch of images to 8-bit images
+///
+/// * `tensor` - Image batch with shape (batch_size, channels, height, width) or
+///   (batch_size, height, width, channels)
+/// * `opts` - The tensor layout and pixel scaling
+///
+/// Values are rounded and clamped to the 8-bit range after scaling. The color type is
+/// picked from the number of channels: luma, luma with alpha, RGB or RGBA.
+pub fn tensor_to_images(
+    tensor: Tensor<4>,
+    opts: ImageConversionOptions,
+) -> Result<Vec<DynamicImage>, ImageConversionError> {
+    let tensor = match opts.layout {
+        ChannelLayout::ChannelsFirst => tensor.permute([0, 2, 3, 1]),
+        ChannelLayout::ChannelsLast => tensor,
+    };
+    let [batch_size, height, width, channels] = tensor.dims();
+    if !(1..=4).contains(&channels) {
+        return Err(ImageConversionError::UnsupportedChannels(channels));
+    }
+    let (Ok(height_u32), Ok(width_u32)) = (u32::try_from(height), u32::try_from(width)) else {
+        return Err(ImageConversionError::DimensionsTooLarge { height, width });
+    };
+
+    let tensor = match opts.scaling {
+        PixelScaling::UnitRange => tensor.mul_scalar(u8::MAX as f32),
+        PixelScaling::Raw => tensor,
+    };
+    let values = tensor
+        .round()
+        .clamp(0, u8::MAX)
+        .into_data()
+        .convert::<u8>()
+        .into_vec::<u8>()
+        .unwrap();
+
+    let image_size = height * width * channels;
+    let images = (0..batch_size)
+        .map(|i| {
+            let pixels = values[i * image_size..(i + 1) * image_size].to_vec();
+            match channels {
+                1 => GrayImage::from_raw(width_u32, height_u32, pixels).map(DynamicImage::from),
+                2 => {
+                    GrayAlphaImage::from_raw(width_u32, height_u32, pixels).map(DynamicImage::from)
+                }
+                3 => RgbImage::from_raw(width_u32, height_u32, pixels).map(DynamicImage::from),
+                _ => RgbaImage::from_raw(width_u32, height_u32, pixels).map(DynamicImage::from),
+            }
+            .expect("Pixel buffer should match the image dimensions")
+        })
+        .collect();
+
+    Ok(images)
+}
+
+fn max_value<T: Primitive>() -> f32 {
+    T::DEFAULT_MAX_VALUE.to_f32().unwrap_or(1.0)
+}
@@ -1,3 +1,5 @@
+mod convert;
 mod save;
 
+pub use convert::*;
 pub use save::*;
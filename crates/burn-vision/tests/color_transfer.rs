@@ -0,0 +1,45 @@
+use burn_core::tensor::Tolerance;
+use burn_vision::ColorTransfer;
+type FT = f32;
+
+mod common;
+use common::*;
+
+#[test]
+fn should_match_histograms_per_channel() {
+    let images = Tensor::<4>::from([[[[0.0, 1.0], [1.0, 2.0]], [[4.0, 3.0], [2.0, 1.0]]]]);
+    let reference = Tensor::<4>::from([[[[10.0, 20.0], [30.0, 40.0]], [[5.0, 5.0], [7.0, 7.0]]]]);
+
+    let output = images.match_histograms(reference);
+
+    let expected = Tensor::<4>::from([[[[10.0, 30.0], [30.0, 40.0]], [[7.0, 6.0], [5.0, 5.0]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_match_batch_to_single_reference() {
+    let images = Tensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0]]], [[[3.0, 3.0, 0.0, 0.0]]]]);
+    let reference = Tensor::<4>::from([[[[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]]]]);
+
+    let output = images.match_histograms(reference);
+
+    let expected = Tensor::<4>::from([[[[2.0, 4.0, 6.0, 8.0]]], [[[8.0, 8.0, 4.0, 4.0]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_transfer_channel_moments() {
+    let images = Tensor::<4>::from([[[[0.0, 2.0]], [[3.0, 3.0]]]]);
+    let reference = Tensor::<4>::from([[[[10.0, 14.0]], [[-1.0, 1.0]]]]);
+
+    let output = images.transfer_color(reference);
+
+    let expected = Tensor::<4>::from([[[[10.0, 14.0]], [[0.0, 0.0]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
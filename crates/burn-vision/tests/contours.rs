@@ -0,0 +1,124 @@
+use burn_core::tensor::{Shape, TensorData};
+use burn_vision::{Contour, ContourApproximation, ContourRetrieval, FindContours, Point};
+
+mod common;
+use common::*;
+
+/// A square ring of width 1 in a 9x9 image, with a single pixel in its hole.
+fn nested_mask() -> TestTensorBool<2> {
+    let mask: Vec<bool> = (0..9)
+        .flat_map(|y| (0..9).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let ring = (1..=7).contains(&x)
+                && (1..=7).contains(&y)
+                && (x == 1 || x == 7 || y == 1 || y == 7);
+            ring || (x, y) == (4, 4)
+        })
+        .collect();
+    let device = TestDevice::default().into();
+    TestTensorBool::<2>::from_data(TensorData::new(mask, Shape::new([9, 9])), &device)
+}
+
+fn points(points: &[(usize, usize)]) -> Vec<Point> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+fn outer_border() -> Vec<Point> {
+    points(&[(1, 1), (1, 7), (7, 7), (7, 1)])
+}
+
+fn hole_border() -> Vec<Point> {
+    points(&[
+        (1, 2),
+        (2, 1),
+        (6, 1),
+        (7, 2),
+        (7, 6),
+        (6, 7),
+        (2, 7),
+        (1, 6),
+    ])
+}
+
+#[test]
+fn should_find_contour_tree() {
+    let contours =
+        nested_mask().find_contours(ContourRetrieval::Tree, ContourApproximation::Simple);
+
+    let expected = vec![
+        Contour {
+            points: outer_border(),
+            is_hole: false,
+            parent: None,
+        },
+        Contour {
+            points: hole_border(),
+            is_hole: true,
+            parent: Some(0),
+        },
+        Contour {
+            points: points(&[(4, 4)]),
+            is_hole: false,
+            parent: Some(1),
+        },
+    ];
+    assert_eq!(contours, expected);
+}
+
+#[test]
+fn should_find_external_contours_only() {
+    let contours =
+        nested_mask().find_contours(ContourRetrieval::External, ContourApproximation::Simple);
+
+    let expected = vec![Contour {
+        points: outer_border(),
+        is_hole: false,
+        parent: None,
+    }];
+    assert_eq!(contours, expected);
+}
+
+#[test]
+fn should_list_contours_without_hierarchy() {
+    let contours =
+        nested_mask().find_contours(ContourRetrieval::List, ContourApproximation::Simple);
+
+    assert_eq!(contours.len(), 3);
+    assert!(contours.iter().all(|contour| contour.parent.is_none()));
+}
+
+#[test]
+fn should_keep_every_border_pixel_of_int_mask() {
+    let mask = TestTensorInt::<2>::from([[0, 0, 0, 0], [0, 3, 3, 0], [0, 3, 3, 0], [0, 0, 0, 0]]);
+
+    let contours = mask.find_contours(ContourRetrieval::Tree, ContourApproximation::None);
+
+    assert_eq!(contours.len(), 1);
+    assert_eq!(
+        contours[0].points,
+        points(&[(1, 1), (1, 2), (2, 2), (2, 1)])
+    );
+}
+
+#[test]
+fn should_measure_contours() {
+    let contours =
+        nested_mask().find_contours(ContourRetrieval::Tree, ContourApproximation::Simple);
+
+    assert_eq!(contours[0].area(), 36.0);
+    assert_eq!(contours[0].perimeter(), 24.0);
+    assert_eq!(contours[1].area(), 34.0);
+    assert!((contours[1].perimeter() - (16.0 + 4.0 * 2f64.sqrt())).abs() < 1e-9);
+    assert_eq!(contours[2].area(), 0.0);
+}
+
+#[test]
+fn should_approximate_polygon() {
+    let contours =
+        nested_mask().find_contours(ContourRetrieval::Tree, ContourApproximation::Simple);
+
+    let approx = contours[1].approx_poly_dp(1.0);
+
+    assert!(approx.is_hole);
+    assert_eq!(approx.points, points(&[(1, 2), (6, 1), (7, 6), (2, 7)]));
+}
@@ -0,0 +1,95 @@
+use burn_core::tensor::{Shape, TensorData, Tolerance};
+use burn_vision::{BilateralFilterOptions, BilateralMethod, EdgePreservingFilter};
+type FT = f32;
+
+mod common;
+use common::*;
+
+fn image(f: impl Fn(usize, usize) -> f32) -> Tensor<3> {
+    let values: Vec<f32> = (0..8)
+        .flat_map(|y| (0..8).map(move |x| (y, x)))
+        .map(|(y, x)| f(y, x))
+        .collect();
+    let device = TestDevice::default().into();
+    Tensor::<3>::from_data(TensorData::new(values, Shape::new([1, 8, 8])), &device)
+}
+
+#[test]
+fn should_average_window_with_spatial_weights() {
+    let images = Tensor::<3>::from([[[0.0, 3.0, 0.0]]]);
+    let options = BilateralFilterOptions {
+        diameter: 3,
+        sigma_color: 1e6,
+        sigma_space: 1.0,
+        method: BilateralMethod::Exact,
+    };
+
+    let output = images.bilateral_filter(options);
+
+    let expected = Tensor::<3>::from([[[1.132622, 1.355588, 1.132622]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_preserve_edges_with_bilateral_filter() {
+    let images = image(|_, x| if x < 4 { 0.0 } else { 1.0 });
+
+    for method in [BilateralMethod::Exact, BilateralMethod::Grid] {
+        let options = BilateralFilterOptions {
+            sigma_color: 0.1,
+            sigma_space: 4.0,
+            method,
+            ..Default::default()
+        };
+
+        let output = images.clone().bilateral_filter(options);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&images.clone().into_data(), Tolerance::absolute(1e-5));
+    }
+}
+
+#[test]
+fn should_smooth_noise_with_bilateral_grid() {
+    let images = image(|y, x| 0.02 * ((x + y) % 2) as f32);
+    let options = BilateralFilterOptions {
+        sigma_color: 1.0,
+        sigma_space: 2.0,
+        method: BilateralMethod::Grid,
+        ..Default::default()
+    };
+
+    let output = images.bilateral_filter(options);
+
+    let expected = Tensor::<3>::full([1, 8, 8], 0.01, &output.device());
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-3));
+}
+
+#[test]
+fn should_average_twice_with_flat_guide() {
+    let images = Tensor::<3>::from([[[0.0, 3.0, 0.0]]]);
+    let guide = Tensor::<3>::ones([1, 1, 3], &images.device());
+
+    let output = images.guided_filter(guide, 1, 0.01);
+
+    let expected = Tensor::<3>::from([[[1.25, 4.0 / 3.0, 1.25]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_keep_self_guided_ramp() {
+    let images = image(|y, x| (y + x) as f32 / 14.0);
+
+    let output = images.clone().guided_filter(images.clone(), 2, 0.0);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&images.into_data(), Tolerance::absolute(1e-4));
+}
@@ -0,0 +1,150 @@
+use burn_core::tensor::Tolerance;
+use burn_vision::{Hog, HogOptions};
+type FT = f32;
+
+mod common;
+use common::*;
+
+#[test]
+fn should_compute_hog_descriptor() {
+    let images = Tensor::<3>::from([[
+        [0.0, 0.1, 0.4, 0.9, 0.5, 0.3, 0.3, 0.5],
+        [0.3, 0.5, 0.9, 0.4, 0.1, 0.0, 0.1, 0.4],
+        [0.1, 0.4, 0.9, 0.5, 0.3, 0.3, 0.5, 0.9],
+        [0.5, 0.9, 0.4, 0.1, 0.0, 0.1, 0.4, 0.9],
+        [0.4, 0.9, 0.5, 0.3, 0.3, 0.5, 0.9, 0.4],
+        [0.9, 0.4, 0.1, 0.0, 0.1, 0.4, 0.9, 0.5],
+        [0.9, 0.5, 0.3, 0.3, 0.5, 0.9, 0.4, 0.1],
+        [0.4, 0.1, 0.0, 0.1, 0.4, 0.9, 0.5, 0.3],
+    ]]);
+    let options = HogOptions {
+        num_bins: 3,
+        cell_size: [4, 4],
+        block_size: [2, 2],
+        block_stride: [1, 1],
+        signed: false,
+    };
+
+    let output = images.hog(options);
+
+    let expected = Tensor::<2>::from([[
+        0.37189, 0.37141, 0.0, 0.37189, 0.24415, 0.0, 0.37189, 0.33308, 0.0, 0.37189, 0.37189, 0.0,
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
+
+#[test]
+fn should_compute_signed_hog_descriptor_with_overlapping_blocks() {
+    let images = Tensor::<3>::from([[
+        [
+            0.0 / 6.0,
+            2.0 / 6.0,
+            4.0 / 6.0,
+            6.0 / 6.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+        ],
+        [
+            1.0 / 6.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+            0.0 / 6.0,
+            2.0 / 6.0,
+            4.0 / 6.0,
+        ],
+        [
+            4.0 / 6.0,
+            6.0 / 6.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+            0.0 / 6.0,
+        ],
+        [
+            2.0 / 6.0,
+            4.0 / 6.0,
+            6.0 / 6.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+        ],
+        [
+            2.0 / 6.0,
+            4.0 / 6.0,
+            6.0 / 6.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+        ],
+        [
+            4.0 / 6.0,
+            6.0 / 6.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+            0.0 / 6.0,
+        ],
+    ]]);
+    let options = HogOptions {
+        num_bins: 3,
+        cell_size: [2, 2],
+        block_size: [2, 2],
+        block_stride: [1, 1],
+        signed: true,
+    };
+
+    let output = images.hog(options);
+
+    let expected = Tensor::<2>::from([[
+        0.40983, 0.0, 0.0, 0.34544, 0.40983, 0.0, 0.08636, 0.27309, 0.40983, 0.35607, 0.40983, 0.0,
+        0.32777, 0.34363, 0.0, 0.34363, 0.24583, 0.24583, 0.33786, 0.34363, 0.0, 0.34363, 0.25913,
+        0.34363, 0.08943, 0.28281, 0.3795, 0.36874, 0.3795, 0.0, 0.3795, 0.2683, 0.0, 0.35773,
+        0.3795, 0.0, 0.32202, 0.33734, 0.0, 0.33734, 0.24698, 0.33734, 0.31241, 0.33734, 0.0,
+        0.33734, 0.23431, 0.33734,
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
+
+#[test]
+fn should_compute_hog_descriptor_of_each_image() {
+    let image = Tensor::<3>::from([[
+        [0.0, 0.1, 0.4, 0.9, 0.5, 0.3, 0.3, 0.5],
+        [0.3, 0.5, 0.9, 0.4, 0.1, 0.0, 0.1, 0.4],
+        [0.1, 0.4, 0.9, 0.5, 0.3, 0.3, 0.5, 0.9],
+        [0.5, 0.9, 0.4, 0.1, 0.0, 0.1, 0.4, 0.9],
+        [0.4, 0.9, 0.5, 0.3, 0.3, 0.5, 0.9, 0.4],
+        [0.9, 0.4, 0.1, 0.0, 0.1, 0.4, 0.9, 0.5],
+        [0.9, 0.5, 0.3, 0.3, 0.5, 0.9, 0.4, 0.1],
+        [0.4, 0.1, 0.0, 0.1, 0.4, 0.9, 0.5, 0.3],
+    ]]);
+    let images = Tensor::cat(vec![image.clone(), image.zeros_like(), image], 0);
+    let options = HogOptions {
+        num_bins: 3,
+        cell_size: [4, 4],
+        ..Default::default()
+    };
+
+    let output = images.hog(options);
+
+    let [first, zeros, last]: [Tensor<2>; 3] = output.chunk(3, 0).try_into().unwrap();
+    last.into_data()
+        .assert_approx_eq::<FT>(&first.clone().into_data(), Tolerance::absolute(1e-6));
+    zeros
+        .into_data()
+        .assert_approx_eq::<FT>(&first.zeros_like().into_data(), Tolerance::absolute(1e-6));
+}
+
+#[test]
+fn should_compute_hog_descriptor_shape() {
+    let device = TestDevice::default().into();
+    let images = Tensor::<3>::ones([2, 64, 36], &device);
+
+    let output = images.hog(HogOptions::default());
+
+    // 8x4 cells of 8x8 pixels, so 7x3 blocks of 2x2 cells with 9 bins.
+    assert_eq!(output.dims(), [2, 7 * 3 * 2 * 2 * 9]);
+}
@@ -0,0 +1,118 @@
+use burn_core::tensor::{Shape, TensorData, Tolerance};
+use burn_vision::{HoughCirclesOptions, HoughLinesOptions, HoughLinesPOptions, HoughTransform};
+type FT = f32;
+
+mod common;
+use common::*;
+
+fn edge_image(
+    height: usize,
+    width: usize,
+    points: impl IntoIterator<Item = (usize, usize)>,
+) -> TestTensorBool<2> {
+    let mut edges = vec![false; height * width];
+    for (x, y) in points {
+        edges[y * width + x] = true;
+    }
+    let device = TestDevice::default().into();
+    TestTensorBool::<2>::from_data(TensorData::new(edges, Shape::new([height, width])), &device)
+}
+
+/// The pixels at distance `radius` from the center, once rounded.
+fn ring(cx: usize, cy: usize, radius: i64) -> Vec<(usize, usize)> {
+    let range = -radius - 1..=radius + 1;
+    range
+        .clone()
+        .flat_map(|dy| range.clone().map(move |dx| (dx, dy)))
+        .filter(|(dx, dy)| (((dx * dx + dy * dy) as f64).sqrt().round() as i64) == radius)
+        .map(|(dx, dy)| ((cx as i64 + dx) as usize, (cy as i64 + dy) as usize))
+        .collect()
+}
+
+#[test]
+fn should_find_lines_by_decreasing_votes() {
+    let horizontal = (0..100).map(|x| (x, 30));
+    let vertical = (0..100).map(|y| (70, y));
+    let image = edge_image(100, 100, horizontal.chain(vertical));
+
+    let options = HoughLinesOptions {
+        threshold: 50,
+        max_lines: 2,
+        ..Default::default()
+    };
+    let output = image.hough_lines(options);
+
+    let expected = Tensor::<2>::from([[70.0, 0.0], [30.0, core::f32::consts::FRAC_PI_2]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_not_find_lines_below_threshold() {
+    let image = edge_image(40, 40, (0..30).map(|x| (x, 10)));
+
+    let options = HoughLinesOptions {
+        threshold: 30,
+        ..Default::default()
+    };
+    let output = image.hough_lines(options);
+
+    assert_eq!(output.dims(), [0, 2]);
+}
+
+#[test]
+fn should_find_segments_longer_than_min_length() {
+    let horizontal = (10..=50).map(|x| (x, 20));
+    let vertical = (5..=40).map(|y| (55, y));
+    let short = (5..=30).map(|x| (x, 40));
+    let image = edge_image(48, 64, horizontal.chain(vertical).chain(short));
+
+    let options = HoughLinesPOptions {
+        threshold: 20,
+        min_line_length: 30,
+        max_line_gap: 2,
+        ..Default::default()
+    };
+    let output = image.hough_lines_p(options);
+
+    let expected = TestTensorInt::<2>::from([[10, 20, 50, 20], [55, 40, 55, 5]]);
+    output.into_data().assert_eq(&expected.into_data(), false);
+}
+
+#[test]
+fn should_bridge_gaps_in_segments() {
+    let dashed = (0..60).filter(|x| x % 4 != 3).map(|x| (x, 12));
+    let image = edge_image(24, 64, dashed);
+
+    let options = HoughLinesPOptions {
+        threshold: 10,
+        min_line_length: 40,
+        max_line_gap: 1,
+        ..Default::default()
+    };
+    let output = image.hough_lines_p(options);
+
+    let expected = TestTensorInt::<2>::from([[0, 12, 58, 12]]);
+    output.into_data().assert_eq(&expected.into_data(), false);
+}
+
+#[test]
+fn should_find_circles_of_different_radii() {
+    let points = ring(12, 15, 8).into_iter().chain(ring(28, 28, 5));
+    let image = edge_image(40, 40, points);
+
+    let options = HoughCirclesOptions {
+        min_radius: 4,
+        max_radius: 10,
+        threshold: 0.8,
+        min_dist: 5.0,
+        max_circles: 0,
+    };
+    let output = image.hough_circles(options);
+
+    let expected = Tensor::<2>::from([[28.0, 28.0, 5.0], [12.0, 15.0, 8.0]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
@@ -0,0 +1,97 @@
+use burn_core::tensor::TensorData;
+use burn_vision::utils::{
+    ChannelLayout, ImageConversionError, ImageConversionOptions, PixelScaling, image_to_tensor,
+    images_to_tensor, tensor_to_image, tensor_to_images,
+};
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, RgbImage};
+
+mod common;
+use common::*;
+
+fn rgb_image() -> DynamicImage {
+    // 2x1 image: a red pixel and a blue pixel
+    DynamicImage::from(RgbImage::from_raw(2, 1, vec![255, 0, 0, 0, 0, 255]).unwrap())
+}
+
+#[test]
+fn should_convert_image_to_chw_tensor() {
+    let device = Default::default();
+    let tensor = image_to_tensor(&rgb_image(), ImageConversionOptions::default(), &device);
+
+    let expected = TensorData::from([[[1.0f32, 0.0]], [[0.0, 0.0]], [[0.0, 1.0]]]);
+    tensor.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_convert_image_to_raw_hwc_tensor() {
+    let device = Default::default();
+    let opts = ImageConversionOptions::new(ChannelLayout::ChannelsLast, PixelScaling::Raw);
+    let tensor = image_to_tensor(&rgb_image(), opts, &device);
+
+    let expected = TensorData::from([[[255.0f32, 0.0, 0.0], [0.0, 0.0, 255.0]]]);
+    tensor.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_scale_16_bit_image_to_unit_range() {
+    let device = Default::default();
+    let image = ImageBuffer::<Luma<u16>, _>::from_raw(2, 1, vec![0u16, u16::MAX]).unwrap();
+    let tensor = image_to_tensor(
+        &DynamicImage::from(image),
+        ImageConversionOptions::default(),
+        &device,
+    );
+
+    let expected = TensorData::from([[[0.0f32, 1.0]]]);
+    tensor.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_round_trip_image() {
+    let device = Default::default();
+    let image = rgb_image();
+    let opts = ImageConversionOptions::default();
+
+    let tensor = image_to_tensor(&image, opts, &device);
+    let output = tensor_to_image(tensor, opts).unwrap();
+
+    assert_eq!(output, image);
+}
+
+#[test]
+fn should_convert_batch_of_images() {
+    let device = Default::default();
+    let gray = DynamicImage::from(GrayImage::from_raw(2, 1, vec![0, 255]).unwrap());
+    let opts = ImageConversionOptions::default();
+
+    let tensor = images_to_tensor(&[rgb_image(), gray], opts, &device);
+    assert_eq!(tensor.dims(), [2, 3, 1, 2]);
+
+    let images = tensor_to_images(tensor, opts).unwrap();
+    assert_eq!(images[0], rgb_image());
+    assert_eq!(
+        images[1],
+        DynamicImage::from(RgbImage::from_raw(2, 1, vec![0, 0, 0, 255, 255, 255]).unwrap())
+    );
+}
+
+#[test]
+fn should_clamp_out_of_range_values() {
+    let device = Default::default();
+    let tensor = Tensor::<3>::from_data([[[-0.5f32, 0.5, 2.0]]], &device);
+    let opts = ImageConversionOptions::new(ChannelLayout::ChannelsFirst, PixelScaling::UnitRange);
+
+    let image = tensor_to_image(tensor, opts).unwrap();
+
+    assert_eq!(image.to_luma8().into_raw(), vec![0, 128, 255]);
+}
+
+#[test]
+fn should_reject_unsupported_channels() {
+    let device = Default::default();
+    let tensor = Tensor::<3>::zeros([5, 2, 2], &device);
+
+    let result = tensor_to_image(tensor, ImageConversionOptions::default());
+
+    assert_eq!(result, Err(ImageConversionError::UnsupportedChannels(5)));
+}
@@ -0,0 +1,101 @@
+use burn_core::tensor::Tolerance;
+use burn_vision::IntegralImage;
+type FT = f32;
+
+mod common;
+use common::*;
+
+fn test_images() -> Tensor<3> {
+    Tensor::<3>::from([
+        [
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ],
+        [
+            [2.0, 4.0, 6.0, 8.0],
+            [10.0, 12.0, 14.0, 16.0],
+            [18.0, 20.0, 22.0, 24.0],
+        ],
+    ])
+}
+
+#[test]
+fn should_compute_integral_image() {
+    let output = test_images().integral_image();
+
+    let expected = Tensor::<3>::from([
+        [
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 3.0, 6.0, 10.0],
+            [0.0, 6.0, 14.0, 24.0, 36.0],
+            [0.0, 15.0, 33.0, 54.0, 78.0],
+        ],
+        [
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 6.0, 12.0, 20.0],
+            [0.0, 12.0, 28.0, 48.0, 72.0],
+            [0.0, 30.0, 66.0, 108.0, 156.0],
+        ],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_sum_box_filter_clipped_at_borders() {
+    let output = test_images().box_filter([3, 3], false);
+
+    let expected = Tensor::<3>::from([
+        [
+            [14.0, 24.0, 30.0, 22.0],
+            [33.0, 54.0, 63.0, 45.0],
+            [30.0, 48.0, 54.0, 38.0],
+        ],
+        [
+            [28.0, 48.0, 60.0, 44.0],
+            [66.0, 108.0, 126.0, 90.0],
+            [60.0, 96.0, 108.0, 76.0],
+        ],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
+
+#[test]
+fn should_average_box_filter_over_pixels_inside_image() {
+    let output = test_images().box_filter([3, 3], true);
+
+    let expected = Tensor::<3>::from([
+        [
+            [3.5, 4.0, 5.0, 5.5],
+            [5.5, 6.0, 7.0, 7.5],
+            [7.5, 8.0, 9.0, 9.5],
+        ],
+        [
+            [7.0, 8.0, 10.0, 11.0],
+            [11.0, 12.0, 14.0, 15.0],
+            [15.0, 16.0, 18.0, 19.0],
+        ],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
+
+#[test]
+fn should_anchor_even_box_filter_kernel() {
+    let output = test_images().narrow(0, 0, 1).box_filter([2, 4], false);
+
+    // Rows `y - 1..y + 1` and columns `x - 2..x + 2`.
+    let expected = Tensor::<3>::from([[
+        [3.0, 6.0, 10.0, 9.0],
+        [14.0, 24.0, 36.0, 30.0],
+        [30.0, 48.0, 68.0, 54.0],
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
@@ -1,4 +1,4 @@
-use burn_core::tensor::Tolerance;
+use burn_core::tensor::{DType, FloatDType, IntDType, Tolerance};
 use burn_vision::{
     BorderType, KernelShape, MorphOptions, Morphology, Point, Size, create_structuring_element,
 };
@@ -296,6 +296,70 @@ fn should_support_dilate_rgb_anchor_cross() {
         .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
 }
 
+#[test]
+fn should_trim_padded_rect_kernel_with_anchor() {
+    let device = TestDevice::default().into();
+    let tensor = test_image("morphology/Base_2.png", &device, false);
+    let mut kernel = [[false; 5]; 5];
+    for row in &mut kernel[1..=2] {
+        row[2..=4].fill(true);
+    }
+    let kernel = TestTensorBool::<2>::from(kernel);
+    let rect = create_structuring_element(KernelShape::Rect, Size::new(3, 2), None, &device);
+
+    let output = tensor.clone().dilate(
+        kernel,
+        MorphOptions::builder().anchor(Point::new(4, 2)).build(),
+    );
+    let expected = tensor.dilate(
+        rect,
+        MorphOptions::builder().anchor(Point::new(2, 1)).build(),
+    );
+
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
+}
+
+#[test]
+fn should_support_dilate_int_anchor_arbitrary_kernel() {
+    let tensor = TestTensorInt::<3>::from([
+        [[0], [0], [0], [0]],
+        [[0], [1], [0], [0]],
+        [[0], [0], [0], [0]],
+    ]);
+    let kernel = TestTensorBool::<2>::from([[true, false], [true, true]]);
+
+    let output = tensor.dilate(
+        kernel,
+        MorphOptions::builder().anchor(Point::new(0, 0)).build(),
+    );
+    let expected = TestTensorInt::<3>::from([
+        [[1], [1], [0], [0]],
+        [[0], [1], [0], [0]],
+        [[0], [0], [0], [0]],
+    ]);
+
+    output.into_data().assert_eq(&expected.into_data(), false);
+}
+
+#[test]
+fn should_broadcast_border_value_to_channels() {
+    let tensor = TestTensorInt::<3>::from([[[5, 6, 7]]]);
+    let device = TestDevice::default().into();
+    let kernel = create_structuring_element(KernelShape::Rect, Size::new(3, 3), None, &device);
+
+    let output = tensor.erode(
+        kernel,
+        MorphOptions::builder()
+            .border_value(vec![2i64.into()])
+            .build(),
+    );
+    let expected = TestTensorInt::<3>::from([[[2, 2, 2]]]);
+
+    output.into_data().assert_eq(&expected.into_data(), false);
+}
+
 #[test]
 fn should_support_dilate_boolean_rect() {
     let device = TestDevice::default().into();
@@ -364,6 +428,68 @@ fn should_support_dilate_int_cross() {
         .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
 }
 
+#[test]
+fn should_support_dilate_u16() {
+    let device = TestDevice::default().into();
+    let tensor =
+        TestTensorInt::<3>::from([[[0], [4095], [0]], [[0], [0], [0]], [[1000], [0], [0]]])
+            .cast(IntDType::U16);
+    let kernel = create_structuring_element(KernelShape::Rect, Size::new(3, 3), None, &device);
+
+    let output = tensor.dilate(kernel, MorphOptions::default());
+    let expected = TestTensorInt::<3>::from([
+        [[4095], [4095], [4095]],
+        [[4095], [4095], [4095]],
+        [[1000], [1000], [0]],
+    ])
+    .cast(IntDType::U16);
+
+    output.into_data().assert_eq(&expected.into_data(), true);
+}
+
+#[test]
+fn should_support_erode_i16() {
+    let device = TestDevice::default().into();
+    let tensor = TestTensorInt::<3>::from([[[-300], [5], [5]], [[5], [5], [5]], [[5], [5], [7]]])
+        .cast(IntDType::I16);
+    let kernel = create_structuring_element(KernelShape::Rect, Size::new(3, 3), None, &device);
+
+    let output = tensor.erode(kernel, MorphOptions::default());
+    let expected = TestTensorInt::<3>::from([
+        [[-300], [-300], [5]],
+        [[-300], [-300], [5]],
+        [[5], [5], [5]],
+    ])
+    .cast(IntDType::I16);
+
+    output.into_data().assert_eq(&expected.into_data(), true);
+}
+
+#[test]
+fn should_support_dilate_f16() {
+    let device = TestDevice::default().into();
+    let tensor = Tensor::<3>::from([
+        [[0.0], [1.5], [0.0]],
+        [[0.0], [0.0], [0.0]],
+        [[0.5], [0.0], [0.0]],
+    ])
+    .cast(FloatDType::F16);
+    let kernel = create_structuring_element(KernelShape::Rect, Size::new(3, 3), None, &device);
+
+    let output = tensor.dilate(kernel, MorphOptions::default());
+    let expected = Tensor::<3>::from([
+        [[1.5], [1.5], [1.5]],
+        [[1.5], [1.5], [1.5]],
+        [[0.5], [0.5], [0.0]],
+    ]);
+
+    assert_eq!(output.dtype(), DType::F16);
+    output
+        .cast(FloatDType::F32)
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
+}
+
 #[test]
 fn should_support_erode_luma() {
     let device = TestDevice::default().into();
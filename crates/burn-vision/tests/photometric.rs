@@ -0,0 +1,81 @@
+use burn_core::tensor::Tolerance;
+use burn_vision::{PhotometricAugmentation, PhotometricOptions};
+type FT = f32;
+
+mod common;
+use common::*;
+
+#[test]
+fn should_normalize_and_pad_with_neutral_factors() {
+    let images = Tensor::<4>::from([[[[0.0, 1.0]], [[0.5, 0.25]], [[1.0, 0.75]]]]);
+    let factors = Tensor::<2>::from([[1.0, 1.0, 1.0, 0.0]]);
+    let options = PhotometricOptions {
+        mean: [0.5; 3],
+        std: [0.5, 0.25, 0.5],
+        padding: [(1, 0), (0, 1)],
+        pad_value: -9.0,
+    };
+
+    let output = images.photometric_augment(factors, options);
+
+    let expected = Tensor::<4>::from([[
+        [[-9.0, -9.0, -9.0], [-1.0, 1.0, -9.0]],
+        [[-9.0, -9.0, -9.0], [0.0, -1.0, -9.0]],
+        [[-9.0, -9.0, -9.0], [1.0, 0.5, -9.0]],
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_jitter_each_image_with_its_factors() {
+    let image = [[[0.2, 0.8]], [[0.4, 0.6]], [[0.6, 0.4]]];
+    let images = Tensor::<4>::from([image, image, image]);
+    let factors = Tensor::<2>::from([
+        [1.5, 0.5, 0.0, 0.0],
+        [1.0, 2.0, 1.0, 0.0],
+        [1.0, 1.0, 2.0, 0.0],
+    ]);
+
+    let output = images.photometric_augment(factors, PhotometricOptions::default());
+
+    let expected = Tensor::<4>::from([
+        [
+            [[0.63222, 0.80778]],
+            [[0.63222, 0.80778]],
+            [[0.63222, 0.80778]],
+        ],
+        [[[0.0, 1.0]], [[0.30005, 0.70005]], [[0.70005, 0.30005]]],
+        [
+            [[0.03702, 0.96308]],
+            [[0.43702, 0.56308]],
+            [[0.83702, 0.16308]],
+        ],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+}
+
+#[test]
+fn should_rotate_hue_and_keep_grays() {
+    let images = Tensor::<4>::from([[[[1.0, 1.0, 0.3]], [[0.0, 0.5, 0.3]], [[0.0, 0.0, 0.3]]]]);
+
+    let third = images.clone().photometric_augment(
+        Tensor::<2>::from([[1.0, 1.0, 1.0, 1.0 / 3.0]]),
+        PhotometricOptions::default(),
+    );
+    let half = images.photometric_augment(
+        Tensor::<2>::from([[1.0, 1.0, 1.0, 0.5]]),
+        PhotometricOptions::default(),
+    );
+
+    let expected = Tensor::<4>::from([[[[0.0, 0.0, 0.3]], [[1.0, 1.0, 0.3]], [[0.0, 0.5, 0.3]]]]);
+    third
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+    let expected = Tensor::<4>::from([[[[0.0, 0.0, 0.3]], [[1.0, 0.5, 0.3]], [[1.0, 1.0, 0.3]]]]);
+    half.into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
@@ -0,0 +1,70 @@
+use burn_core::tensor::{Distribution, Tolerance};
+use burn_vision::{ImagePyramid, collapse_laplacian_pyramid};
+type FT = f32;
+
+mod common;
+use common::*;
+
+fn test_image() -> Tensor<3> {
+    Tensor::<3>::from([[
+        [0.0, 7.0, 3.0, 10.0, 6.0],
+        [4.0, 0.0, 7.0, 3.0, 10.0],
+        [8.0, 4.0, 0.0, 7.0, 3.0],
+        [1.0, 8.0, 4.0, 0.0, 7.0],
+    ]])
+}
+
+#[test]
+fn should_blur_and_downsample_with_reflected_borders() {
+    let output = test_image().pyr_down();
+
+    let expected =
+        Tensor::<3>::from([[[3.265625, 4.7109375, 6.5], [4.2421875, 3.92578125, 4.8125]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_upsample_to_odd_size() {
+    let input = Tensor::<3>::from([[[3.265625, 4.7109375, 6.5], [4.2421875, 3.92578125, 4.8125]]]);
+
+    let output = input.pyr_up([4, 5]);
+
+    let expected = Tensor::<3>::from([[
+        [3.760986, 4.012207, 4.584473, 5.296387, 5.882690],
+        [3.895020, 4.036133, 4.415039, 4.987305, 5.489014],
+        [4.096069, 4.072021, 4.160889, 4.523682, 4.898499],
+        [4.163086, 4.083984, 4.076172, 4.369141, 4.701660],
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+}
+
+#[test]
+fn should_build_gaussian_pyramid_levels() {
+    let images = Tensor::<3>::ones([2, 13, 20], &Default::default());
+
+    let pyramid = images.gaussian_pyramid(3);
+
+    let sizes: Vec<_> = pyramid.iter().map(|level| level.dims()).collect();
+    assert_eq!(sizes, [[2, 13, 20], [2, 7, 10], [2, 4, 5], [2, 2, 3]]);
+    // The kernel is normalized, so constant images stay constant.
+    pyramid[3].clone().into_data().assert_approx_eq::<FT>(
+        &Tensor::<3>::ones([2, 2, 3], &Default::default()).into_data(),
+        Tolerance::absolute(1e-6),
+    );
+}
+
+#[test]
+fn should_collapse_laplacian_pyramid_to_images() {
+    let images = Tensor::<3>::random([2, 15, 22], Distribution::Default, &Default::default());
+
+    let pyramid = images.clone().laplacian_pyramid(3);
+    let output = collapse_laplacian_pyramid(pyramid);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&images.into_data(), Tolerance::absolute(1e-5));
+}
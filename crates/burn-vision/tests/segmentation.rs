@@ -0,0 +1,83 @@
+use burn_core::tensor::Tolerance;
+use burn_vision::{FloodFillOptions, Point, Segmentation};
+type FT = f32;
+
+mod common;
+use common::*;
+
+fn test_image() -> Tensor<2> {
+    Tensor::<2>::from([
+        [1.0, 2.0, 3.0, 9.0, 1.0],
+        [1.0, 1.0, 4.0, 9.0, 1.0],
+        [9.0, 9.0, 5.0, 9.0, 1.0],
+        [1.0, 1.0, 1.0, 9.0, 1.0],
+    ])
+}
+
+#[test]
+fn should_flood_fill_from_neighbors() {
+    let options = FloodFillOptions {
+        lo_diff: 1.0,
+        up_diff: 1.0,
+        ..Default::default()
+    };
+
+    let (output, mask) = test_image().flood_fill(Point::new(0, 0), 0.0, options);
+
+    let expected = Tensor::<2>::from([
+        [0.0, 0.0, 0.0, 9.0, 1.0],
+        [0.0, 0.0, 0.0, 9.0, 1.0],
+        [9.0, 9.0, 0.0, 9.0, 1.0],
+        [1.0, 1.0, 1.0, 9.0, 1.0],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
+    let expected_mask = TestTensorBool::<2>::from([
+        [true, true, true, false, false],
+        [true, true, true, false, false],
+        [false, false, true, false, false],
+        [false, false, false, false, false],
+    ]);
+    mask.into_data()
+        .assert_eq(&expected_mask.into_data(), false);
+}
+
+#[test]
+fn should_flood_fill_within_seed_range() {
+    let options = FloodFillOptions {
+        lo_diff: 1.0,
+        up_diff: 1.0,
+        fixed_range: true,
+        ..Default::default()
+    };
+
+    let (output, _) = test_image().flood_fill(Point::new(0, 0), 0.0, options);
+
+    let expected = Tensor::<2>::from([
+        [0.0, 0.0, 3.0, 9.0, 1.0],
+        [0.0, 0.0, 4.0, 9.0, 1.0],
+        [9.0, 9.0, 5.0, 9.0, 1.0],
+        [1.0, 1.0, 1.0, 9.0, 1.0],
+    ]);
+    output
+        .into_data()
+        .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-6));
+}
+
+#[test]
+fn should_split_basins_at_ridge() {
+    let image = Tensor::<2>::from([[0.0, 1.0, 2.0, 5.0, 2.0, 1.0, 0.0]; 5]);
+    let markers = TestTensorInt::<2>::from([
+        [0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0],
+        [1, 0, 0, 0, 0, 0, 2],
+        [0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0],
+    ]);
+
+    let output = image.watershed(markers);
+
+    let expected = TestTensorInt::<2>::from([[1, 1, 1, -1, 2, 2, 2]; 5]);
+    output.into_data().assert_eq(&expected.into_data(), false);
+}
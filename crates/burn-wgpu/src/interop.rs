@@ -0,0 +1,454 @@
+//! Interoperability with buffers and textures owned by an existing wgpu application.
+//!
+//! The functions in this module copy data between wgpu resources and tensors with commands
+//! recorded on the GPU, so the data never goes through the host. The burn device must be
+//! initialized with [`init_device`](crate::init_device) using the same wgpu device and queue as
+//! the resources, so that the copies are ordered with the tensor operations.
+
+use core::fmt;
+
+use burn_backend::{DType, Shape};
+use burn_cubecl::kernel::into_contiguous;
+use burn_std::{Metadata, strides};
+use cubecl::Runtime;
+use cubecl::wgpu::wgpu;
+
+use crate::{CubeTensor, WgpuDevice, WgpuRuntime};
+
+/// Error returned when a wgpu resource can't be copied to or from a tensor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InteropError {
+    /// The texture format has no matching tensor data type.
+    UnsupportedFormat(wgpu::TextureFormat),
+    /// The tensor doesn't match the size, format or layout of the resource.
+    Mismatch(String),
+}
+
+impl fmt::Display for InteropError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(f, "Texture format {format:?} can't be used as a tensor")
+            }
+            Self::Mismatch(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl core::error::Error for InteropError {}
+
+/// The GPU context owning the wgpu resources.
+///
+/// The device and queue must be the ones given to [`init_device`](crate::init_device) when the
+/// burn device was initialized.
+#[derive(Clone, Copy)]
+pub struct WgpuContext<'a> {
+    /// The wgpu device.
+    pub device: &'a wgpu::Device,
+    /// The wgpu queue.
+    pub queue: &'a wgpu::Queue,
+}
+
+/// Copy the content of a wgpu buffer into a new tensor.
+///
+/// * `buffer` - The source buffer, which must have the `COPY_SRC` usage.
+/// * `offset` - The offset of the tensor data in the buffer, in bytes.
+/// * `shape` - The shape of the tensor.
+/// * `dtype` - The data type of the values in the buffer.
+///
+/// The values are read in row-major order.
+pub fn buffer_to_tensor(
+    context: WgpuContext<'_>,
+    buffer: &wgpu::Buffer,
+    offset: u64,
+    shape: Shape,
+    dtype: DType,
+    device: &WgpuDevice,
+) -> Result<CubeTensor<WgpuRuntime>, InteropError> {
+    let size = (shape.num_elements() * dtype.size()) as u64;
+    check_buffer_copy(buffer, offset, size)?;
+
+    let client = WgpuRuntime::client(device);
+    let handle = client.empty(size as usize);
+    // Submit the pending tensor operations, which may still use the reused memory.
+    client.flush();
+    let resource = client.get_resource(handle.clone().binding());
+    let resource = resource.resource();
+
+    let mut encoder = context
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("burn buffer import"),
+        });
+    encoder.copy_buffer_to_buffer(buffer, offset, &resource.buffer, resource.offset, size);
+    context.queue.submit([encoder.finish()]);
+
+    Ok(CubeTensor::new_contiguous(
+        client,
+        device.clone(),
+        shape,
+        handle,
+        dtype,
+    ))
+}
+
+/// Copy the values of a tensor into a wgpu buffer.
+///
+/// * `buffer` - The destination buffer, which must have the `COPY_DST` usage.
+/// * `offset` - The offset where the tensor data is written in the buffer, in bytes.
+///
+/// The values are written in row-major order.
+pub fn tensor_to_buffer(
+    context: WgpuContext<'_>,
+    tensor: CubeTensor<WgpuRuntime>,
+    buffer: &wgpu::Buffer,
+    offset: u64,
+) -> Result<(), InteropError> {
+    let tensor = into_contiguous(tensor);
+    let size = (tensor.meta.shape().num_elements() * tensor.dtype.size()) as u64;
+    check_buffer_copy(buffer, offset, size)?;
+
+    // Submit the pending tensor operations before recording the copy on the same queue.
+    tensor.client.flush();
+    let resource = tensor.client.get_resource(tensor.handle.clone().binding());
+    let resource = resource.resource();
+
+    let mut encoder = context
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("burn buffer export"),
+        });
+    encoder.copy_buffer_to_buffer(&resource.buffer, resource.offset, buffer, offset, size);
+    context.queue.submit([encoder.finish()]);
+
+    Ok(())
+}
+
+/// Copy the content of a 2D texture into a new tensor.
+///
+/// * `texture` - The source texture, which must have the `COPY_SRC` usage.
+///
+/// Returns a tensor with shape (height, width, channels), where the data type and the number of
+/// channels are given by the texture format. Normalized formats keep their raw integer values,
+/// e.g. `Rgba8Unorm` textures are copied to `u8` tensors. Only the first mip level is copied, and
+/// 3D textures and texture arrays with more than one layer are rejected.
+pub fn texture_to_tensor(
+    context: WgpuContext<'_>,
+    texture: &wgpu::Texture,
+    device: &WgpuDevice,
+) -> Result<CubeTensor<WgpuRuntime>, InteropError> {
+    check_single_layer(texture)?;
+    let (dtype, channels) = texture_format_dtype(texture.format())?;
+    let wgpu::Extent3d { width, height, .. } = texture.size();
+    let (width, height) = (width as usize, height as usize);
+    let texel_size = dtype.size() * channels;
+
+    // Texture copies need rows aligned to `COPY_BYTES_PER_ROW_ALIGNMENT`, so the rows are padded
+    // and the tensor is a strided view over the padded buffer.
+    let bytes_per_row =
+        (width * texel_size).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize);
+    let row_stride = bytes_per_row / dtype.size();
+
+    let client = WgpuRuntime::client(device);
+    let handle = client.empty(bytes_per_row * height);
+    // Submit the pending tensor operations, which may still use the reused memory.
+    client.flush();
+    let resource = client.get_resource(handle.clone().binding());
+    let resource = resource.resource();
+
+    let mut encoder = context
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("burn texture import"),
+        });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::TexelCopyBufferInfo {
+            buffer: &resource.buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: resource.offset,
+                bytes_per_row: Some(bytes_per_row as u32),
+                rows_per_image: Some(height as u32),
+            },
+        },
+        texture.size(),
+    );
+    context.queue.submit([encoder.finish()]);
+
+    let shape = Shape::new([height, width, channels]);
+    let strides = strides![row_stride, channels, 1];
+
+    Ok(CubeTensor::new(
+        client,
+        handle,
+        Metadata::new(shape, strides),
+        device.clone(),
+        dtype,
+    ))
+}
+
+/// Copy the values of a tensor into a 2D texture.
+///
+/// * `tensor` - Image with shape (height, width, channels), matching the size and the format of
+///   the texture.
+/// * `texture` - The destination texture, which must have the `COPY_DST` usage.
+///
+/// Only the first mip level is written, and 3D textures and texture arrays with more than one
+/// layer are rejected.
+pub fn tensor_to_texture(
+    context: WgpuContext<'_>,
+    tensor: CubeTensor<WgpuRuntime>,
+    texture: &wgpu::Texture,
+) -> Result<(), InteropError> {
+    check_single_layer(texture)?;
+    let (dtype, channels) = texture_format_dtype(texture.format())?;
+    let size = texture.size();
+    let (width, height) = (size.width as usize, size.height as usize);
+
+    if tensor.dtype != dtype || tensor.meta.shape().as_slice() != [height, width, channels] {
+        return Err(InteropError::Mismatch(format!(
+            "Expected a {dtype:?} tensor of shape {:?} for texture format {:?}, got a {:?} tensor of shape {:?}",
+            [height, width, channels],
+            texture.format(),
+            tensor.dtype,
+            tensor.meta.shape(),
+        )));
+    }
+
+    let tensor = into_contiguous(tensor);
+    let bytes_per_row = (width * channels * dtype.size()) as u32;
+
+    // Submit the pending tensor operations before recording the copy on the same queue.
+    tensor.client.flush();
+    let resource = tensor.client.get_resource(tensor.handle.clone().binding());
+    let resource = resource.resource();
+
+    let mut encoder = context
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("burn texture export"),
+        });
+
+    if bytes_per_row.is_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT) {
+        encoder.copy_buffer_to_texture(
+            wgpu::TexelCopyBufferInfo {
+                buffer: &resource.buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: resource.offset,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(height as u32),
+                },
+            },
+            texture.as_image_copy(),
+            size,
+        );
+    } else {
+        // Unaligned rows can't be copied at once, but a single row has no alignment requirement.
+        for row in 0..height as u32 {
+            encoder.copy_buffer_to_texture(
+                wgpu::TexelCopyBufferInfo {
+                    buffer: &resource.buffer,
+                    layout: wgpu::TexelCopyBufferLayout {
+                        offset: resource.offset + (row * bytes_per_row) as u64,
+                        bytes_per_row: None,
+                        rows_per_image: None,
+                    },
+                },
+                wgpu::TexelCopyTextureInfo {
+                    texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x: 0, y: row, z: 0 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::Extent3d {
+                    width: size.width,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+    }
+    context.queue.submit([encoder.finish()]);
+
+    Ok(())
+}
+
+/// The tensor data type and the number of channels of a texture format.
+fn texture_format_dtype(format: wgpu::TextureFormat) -> Result<(DType, usize), InteropError> {
+    use wgpu::TextureFormat as F;
+
+    let layout = match format {
+        F::R8Unorm | F::R8Uint => (DType::U8, 1),
+        F::Rg8Unorm | F::Rg8Uint => (DType::U8, 2),
+        F::Rgba8Unorm | F::Rgba8UnormSrgb | F::Rgba8Uint | F::Bgra8Unorm | F::Bgra8UnormSrgb => {
+            (DType::U8, 4)
+        }
+        F::R16Float => (DType::F16, 1),
+        F::Rg16Float => (DType::F16, 2),
+        F::Rgba16Float => (DType::F16, 4),
+        F::R32Float => (DType::F32, 1),
+        F::Rg32Float => (DType::F32, 2),
+        F::Rgba32Float => (DType::F32, 4),
+        F::R32Uint => (DType::U32, 1),
+        F::Rg32Uint => (DType::U32, 2),
+        F::Rgba32Uint => (DType::U32, 4),
+        F::R32Sint => (DType::I32, 1),
+        F::Rg32Sint => (DType::I32, 2),
+        F::Rgba32Sint => (DType::I32, 4),
+        format => return Err(InteropError::UnsupportedFormat(format)),
+    };
+
+    Ok(layout)
+}
+
+fn check_single_layer(texture: &wgpu::Texture) -> Result<(), InteropError> {
+    let layers = texture.size().depth_or_array_layers;
+    if texture.dimension() != wgpu::TextureDimension::D2 || layers != 1 {
+        return Err(InteropError::Mismatch(format!(
+            "Only single layer 2D textures can be copied, got a {:?} texture with {layers} layers",
+            texture.dimension()
+        )));
+    }
+
+    Ok(())
+}
+
+fn check_buffer_copy(buffer: &wgpu::Buffer, offset: u64, size: u64) -> Result<(), InteropError> {
+    if !offset.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT)
+        || !size.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT)
+    {
+        return Err(InteropError::Mismatch(format!(
+            "Buffer copies need an offset and a size aligned to {} bytes, got offset {offset} and size {size}",
+            wgpu::COPY_BUFFER_ALIGNMENT
+        )));
+    }
+    if offset + size > buffer.size() {
+        return Err(InteropError::Mismatch(format!(
+            "Buffer of {} bytes is too small to hold {size} bytes at offset {offset}",
+            buffer.size()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Wgpu, WgpuSetup, init_device};
+    use burn_backend::{TensorData, ops::FloatTensorOps};
+    use burn_std::future::block_on;
+
+    fn setup() -> (WgpuSetup, WgpuDevice) {
+        let instance = wgpu::Instance::default();
+        let adapter = block_on(instance.request_adapter(&Default::default())).unwrap();
+        let (device, queue) = block_on(adapter.request_device(&Default::default())).unwrap();
+        let setup = WgpuSetup {
+            backend: adapter.get_info().backend,
+            instance,
+            adapter,
+            device,
+            queue,
+        };
+        let burn_device = init_device(setup.clone(), Default::default());
+
+        (setup, burn_device)
+    }
+
+    fn context(setup: &WgpuSetup) -> WgpuContext<'_> {
+        WgpuContext {
+            device: &setup.device,
+            queue: &setup.queue,
+        }
+    }
+
+    fn create_texture(setup: &WgpuSetup, width: u32, height: u32, layers: u32) -> wgpu::Texture {
+        setup.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: layers,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R32Float,
+            usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        })
+    }
+
+    #[test]
+    fn should_round_trip_buffer() {
+        let (setup, device) = setup();
+        let data = TensorData::new((0..12).map(|i| i as f32).collect(), [3, 4]);
+        let tensor = Wgpu::float_from_data(data.clone(), &device);
+        let buffer = setup.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 64,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        tensor_to_buffer(context(&setup), tensor, &buffer, 16).unwrap();
+        let output = buffer_to_tensor(
+            context(&setup),
+            &buffer,
+            16,
+            Shape::new([3, 4]),
+            DType::F32,
+            &device,
+        )
+        .unwrap();
+
+        block_on(Wgpu::float_into_data(output))
+            .unwrap()
+            .assert_eq(&data, true);
+    }
+
+    #[test]
+    fn should_round_trip_texture() {
+        let (setup, device) = setup();
+        // Rows of 5 `f32` values aren't aligned, so the texture is written row by row.
+        let data = TensorData::new((0..15).map(|i| i as f32).collect(), [3, 5, 1]);
+        let tensor = Wgpu::float_from_data(data.clone(), &device);
+        let texture = create_texture(&setup, 5, 3, 1);
+
+        tensor_to_texture(context(&setup), tensor, &texture).unwrap();
+        let output = texture_to_tensor(context(&setup), &texture, &device).unwrap();
+
+        assert_eq!(output.meta.shape().as_slice(), [3, 5, 1]);
+        block_on(Wgpu::float_into_data(output))
+            .unwrap()
+            .assert_eq(&data, true);
+    }
+
+    #[test]
+    fn should_reject_texture_arrays() {
+        let (setup, device) = setup();
+        let texture = create_texture(&setup, 4, 4, 2);
+        let tensor = Wgpu::float_from_data(TensorData::zeros::<f32, _>([4, 4, 1]), &device);
+
+        assert!(matches!(
+            texture_to_tensor(context(&setup), &texture, &device),
+            Err(InteropError::Mismatch(_))
+        ));
+        assert!(matches!(
+            tensor_to_texture(context(&setup), tensor, &texture),
+            Err(InteropError::Mismatch(_))
+        ));
+    }
+
+    #[test]
+    fn should_reject_mismatched_tensor_shape() {
+        let (setup, device) = setup();
+        let texture = create_texture(&setup, 4, 4, 1);
+        let tensor = Wgpu::float_from_data(TensorData::zeros::<f32, _>([4, 3, 1]), &device);
+
+        assert!(matches!(
+            tensor_to_texture(context(&setup), tensor, &texture),
+            Err(InteropError::Mismatch(_))
+        ));
+    }
+}
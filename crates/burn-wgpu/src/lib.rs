@@ -2,6 +2,8 @@
 
 extern crate alloc;
 
+pub mod interop;
+
 #[cfg(feature = "template")]
 pub use burn_cubecl::{
     kernel::{KernelMetadata, into_contiguous},
@@ -173,6 +173,10 @@ pub mod cubecl {
     pub use cubecl::*;
 }
 
+/// Autotune budgets for the CubeCL backends.
+#[cfg(all(feature = "cubecl", feature = "std"))]
+pub use burn_std::autotune;
+
 #[cfg(feature = "vision")]
 /// Vision module.
 pub mod vision {